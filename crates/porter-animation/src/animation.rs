@@ -1,15 +1,28 @@
+use std::collections::HashMap;
 use std::path::Path;
 
+use porter_cast::CastOptions;
+
 use porter_math::Axis;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_model::Model;
+use porter_model::Skeleton;
 
 use crate::AnimationError;
 use crate::AnimationFileType;
+use crate::BoneMap;
 use crate::Curve;
 use crate::CurveAttribute;
 use crate::CurveDataType;
 use crate::CurveModeOverride;
+use crate::Joint;
 use crate::KeyframeValue;
+use crate::animation_file_type_bvh;
 use crate::animation_file_type_cast;
+use crate::blender_script;
+use crate::retarget;
 
 /// A 3d animation.
 #[derive(Debug, Clone)]
@@ -43,12 +56,41 @@ impl Animation {
         &self,
         path: P,
         file_type: AnimationFileType,
+    ) -> Result<(), AnimationError> {
+        self.save_with_options(path, file_type, CastOptions::default())
+    }
+
+    /// Saves the animation to the given file path in the given animation format, using the given cast options.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AnimationFileType,
+        options: CastOptions,
     ) -> Result<(), AnimationError> {
         match file_type {
-            AnimationFileType::Cast => animation_file_type_cast::to_cast(path, self),
+            AnimationFileType::Cast => animation_file_type_cast::to_cast(path, self, options),
         }
     }
 
+    /// Saves the animation to the given file path in bvh format, using the given joints as the skeleton hierarchy.
+    pub fn save_bvh<P: AsRef<Path>>(
+        &self,
+        path: P,
+        joints: &[(Option<String>, Joint)],
+    ) -> Result<(), AnimationError> {
+        animation_file_type_bvh::to_bvh(path, self, joints)
+    }
+
+    /// Saves a blender script next to the given file path, which imports the animation saved in the given formats.
+    /// Only the first format is used, no script is written if there are none.
+    pub fn save_blender_script<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_types: &[AnimationFileType],
+    ) -> Result<(), AnimationError> {
+        blender_script::to_blender_script(path, self, file_types)
+    }
+
     /// Attempts to find a curve with the given name and attribute.
     pub fn find<N: AsRef<str>>(&self, name: N, attribute: CurveAttribute) -> Option<&Curve> {
         self.curves.get(self.index(name, attribute)?)
@@ -72,6 +114,16 @@ impl Animation {
             .position(|curve| curve.name() == name.as_ref() && curve.attribute() == attribute)
     }
 
+    /// Samples the value of the curve with the given name and attribute at the given frame.
+    pub fn sample<N: AsRef<str>>(
+        &self,
+        name: N,
+        attribute: CurveAttribute,
+        frame: u32,
+    ) -> Option<KeyframeValue> {
+        self.find(name, attribute)?.sample(frame)
+    }
+
     /// Returns the most common curve data type.
     pub fn average_data_type(&self) -> CurveDataType {
         let mut data_types: [usize; 3] = [0, 0, 0];
@@ -98,9 +150,7 @@ impl Animation {
         let mut result = 0;
 
         for curve in &self.curves {
-            for keyframe in curve.keyframes() {
-                result = result.max(keyframe.time);
-            }
+            result = result.max(curve.largest_frame_time());
         }
 
         // Frame count is the length of the animation in frames
@@ -121,12 +171,127 @@ impl Animation {
     pub fn scale(&mut self, factor: f32) {
         for curve in &mut self.curves {
             if matches!(curve.attribute(), CurveAttribute::Translate) {
-                for keyframe in curve.keyframes_mut() {
-                    if let KeyframeValue::Vector3(vector) = &mut keyframe.value {
-                        *vector *= factor;
-                    }
+                curve.scale_vectors(factor);
+            }
+        }
+    }
+
+    /// Appends the given clip to the end of this animation's timeline, adding a notification marker where it starts.
+    ///
+    /// Curves are matched by node name and attribute, so both clips should target the same skeleton.
+    pub fn concat<N: Into<String>>(&mut self, marker: N, other: Animation) {
+        let offset = if self.curves.is_empty() {
+            self.framerate = other.framerate;
+            self.up_axis = other.up_axis;
+
+            0
+        } else {
+            self.frame_count()
+        };
+
+        let time_scale = if other.framerate > 0.0 {
+            self.framerate / other.framerate
+        } else {
+            1.0
+        };
+
+        let mut marker_curve =
+            Curve::new(marker, CurveAttribute::Notetrack, CurveDataType::Absolute);
+
+        marker_curve.insert(offset, ());
+
+        self.curves.push(marker_curve);
+
+        for curve in other.curves {
+            let index = match self.index(curve.name(), curve.attribute()) {
+                Some(index) => index,
+                None => {
+                    self.curves.push(Curve::new(
+                        curve.name(),
+                        curve.attribute(),
+                        curve.data_type(),
+                    ));
+                    self.curves.len() - 1
                 }
+            };
+
+            let target = &mut self.curves[index];
+
+            for keyframe in curve.iter() {
+                let time = (keyframe.time as f32 * time_scale).round() as u32;
+
+                target.insert(offset + time, keyframe.value);
             }
         }
+
+        for curve_override in other.curve_mode_overrides {
+            if !self
+                .curve_mode_overrides
+                .iter()
+                .any(|x| x.name == curve_override.name)
+            {
+                self.curve_mode_overrides.push(curve_override);
+            }
+        }
+    }
+
+    /// Remaps this animation from the source skeleton to the target skeleton, returning the retargeted animation.
+    ///
+    /// Bones are matched with the bone map first, then by name, ignoring case and namespaces. Absolute curves are
+    /// corrected for the difference between the rest poses, and curves without a matching bone are dropped.
+    pub fn retarget(&self, source: &Skeleton, target: &Skeleton, map: &BoneMap) -> Animation {
+        retarget::retarget(self, source, target, map)
+    }
+
+    /// Poses the model at the given frame of this animation, deforming the meshes to match.
+    ///
+    /// Absolute curves replace the local transform of a bone, other curves are applied on top of its rest pose.
+    pub fn pose_model(&self, model: &mut Model, frame: u32) {
+        let rest: HashMap<String, (Vector3, Quaternion)> = model
+            .skeleton
+            .bones
+            .iter()
+            .filter_map(|bone| {
+                Some((
+                    bone.name.clone()?,
+                    (bone.local_position, bone.local_rotation),
+                ))
+            })
+            .collect();
+
+        model.pose(|name| {
+            let Some((rest_position, rest_rotation)) = rest.get(name) else {
+                return (None, None);
+            };
+
+            let position = self
+                .find(name, CurveAttribute::Translate)
+                .and_then(|curve| {
+                    let value = Vector3::try_from(curve.sample(frame)?).ok()?;
+
+                    Some(match curve.data_type() {
+                        CurveDataType::Absolute => value,
+                        _ => *rest_position + value,
+                    })
+                });
+
+            let rotation = self.find(name, CurveAttribute::Rotation).and_then(|curve| {
+                let value = Quaternion::try_from(curve.sample(frame)?).ok()?;
+
+                Some(match curve.data_type() {
+                    CurveDataType::Absolute => value,
+                    _ => (*rest_rotation * value).normalized(),
+                })
+            });
+
+            (position, rotation)
+        });
+    }
+
+    /// Stores every curve in a compact quantized form, reducing the memory used while the animation is held for export.
+    pub fn compact(&mut self) {
+        for curve in &mut self.curves {
+            curve.compact();
+        }
     }
 }
@@ -0,0 +1,186 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use porter_math::Angles;
+
+use porter_utils::BufferWriteExt;
+
+use crate::Animation;
+use crate::AnimationError;
+use crate::AnimationSampler;
+use crate::Joint;
+
+/// Utility to write the given level of indentation.
+macro_rules! write_indent {
+    ($bvh:ident, $depth:expr) => {
+        for _ in 0..$depth {
+            write!($bvh, "\t")?;
+        }
+    };
+}
+
+/// The name of the root joint added when the hierarchy has more than one root.
+const SYNTHETIC_ROOT: &str = "porter_root";
+
+/// Writes a joint and its children to the hierarchy, recording the order joints were written in.
+fn write_joint<W: Write>(
+    bvh: &mut W,
+    joints: &[(Option<String>, Joint)],
+    children: &[Vec<usize>],
+    index: usize,
+    depth: usize,
+    order: &mut Vec<usize>,
+) -> Result<(), AnimationError> {
+    let (name, joint) = &joints[index];
+
+    order.push(index);
+
+    write_indent!(bvh, depth);
+
+    if depth == 0 {
+        write!(bvh, "ROOT ")?;
+    } else {
+        write!(bvh, "JOINT ")?;
+    }
+
+    match name {
+        Some(name) => writeln!(bvh, "{}", name.replace(' ', "_"))?,
+        None => writeln!(bvh, "porter_bone_{index}")?,
+    }
+
+    write_indent!(bvh, depth);
+    writeln!(bvh, "{{")?;
+
+    write_indent!(bvh, depth + 1);
+    writeln!(
+        bvh,
+        "OFFSET {:.6} {:.6} {:.6}",
+        joint.local_position.x, joint.local_position.y, joint.local_position.z
+    )?;
+
+    write_indent!(bvh, depth + 1);
+    writeln!(
+        bvh,
+        "CHANNELS 6 Xposition Yposition Zposition Zrotation Yrotation Xrotation"
+    )?;
+
+    if children[index].is_empty() {
+        write_indent!(bvh, depth + 1);
+        writeln!(bvh, "End Site")?;
+        write_indent!(bvh, depth + 1);
+        writeln!(bvh, "{{")?;
+        write_indent!(bvh, depth + 2);
+        writeln!(bvh, "OFFSET 0.000000 0.000000 0.000000")?;
+        write_indent!(bvh, depth + 1);
+        writeln!(bvh, "}}")?;
+    } else {
+        for child in &children[index] {
+            write_joint(bvh, joints, children, *child, depth + 1, order)?;
+        }
+    }
+
+    write_indent!(bvh, depth);
+    writeln!(bvh, "}}")?;
+
+    Ok(())
+}
+
+/// Writes an animation in bvh format to the given path, using the given joints as the hierarchy.
+pub fn to_bvh<P: AsRef<Path>>(
+    path: P,
+    animation: &Animation,
+    joints: &[(Option<String>, Joint)],
+) -> Result<(), AnimationError> {
+    let mut bvh = File::create(path.as_ref().with_extension("bvh"))?.buffer_write();
+
+    let mut roots: Vec<usize> = Vec::new();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); joints.len()];
+
+    for (index, (_, joint)) in joints.iter().enumerate() {
+        if joint.parent > -1 && (joint.parent as usize) < joints.len() {
+            children[joint.parent as usize].push(index);
+        } else {
+            roots.push(index);
+        }
+    }
+
+    let mut order: Vec<usize> = Vec::with_capacity(joints.len());
+
+    writeln!(bvh, "HIERARCHY")?;
+
+    // Most importers only read the first hierarchy, so extra roots are joined under a root with an identity transform.
+    let synthetic_root = roots.len() > 1;
+
+    if synthetic_root {
+        writeln!(bvh, "ROOT {SYNTHETIC_ROOT}")?;
+        writeln!(bvh, "{{")?;
+        writeln!(bvh, "\tOFFSET 0.000000 0.000000 0.000000")?;
+        writeln!(
+            bvh,
+            "\tCHANNELS 6 Xposition Yposition Zposition Zrotation Yrotation Xrotation"
+        )?;
+    }
+
+    for root in roots {
+        write_joint(
+            &mut bvh,
+            joints,
+            &children,
+            root,
+            synthetic_root as usize,
+            &mut order,
+        )?;
+    }
+
+    if synthetic_root {
+        writeln!(bvh, "}}")?;
+    }
+
+    let mut sampler = AnimationSampler::new(animation.clone());
+
+    for (name, joint) in joints {
+        sampler.push_joint(name.clone(), *joint);
+    }
+
+    let frame_count = sampler.frame_count();
+
+    writeln!(bvh, "MOTION")?;
+    writeln!(bvh, "Frames: {frame_count}")?;
+    writeln!(bvh, "Frame Time: {:.6}", 1.0 / animation.framerate.max(1.0))?;
+
+    for _ in 0..frame_count {
+        sampler.step()?;
+
+        if synthetic_root {
+            write!(bvh, "0.000000 0.000000 0.000000 0.000000 0.000000 0.000000")?;
+        }
+
+        for (i, index) in order.iter().enumerate() {
+            let Some(joint) = sampler.evaluate_joint_index(*index) else {
+                return Err(AnimationError::InvalidJointName);
+            };
+
+            let rotation = joint.local_rotation.to_euler(Angles::Degrees);
+
+            if i > 0 || synthetic_root {
+                write!(bvh, " ")?;
+            }
+
+            write!(
+                bvh,
+                "{:.6} {:.6} {:.6} {:.6} {:.6} {:.6}",
+                joint.local_position.x,
+                joint.local_position.y,
+                joint.local_position.z,
+                rotation.z,
+                rotation.y,
+                rotation.x
+            )?;
+        }
+
+        writeln!(bvh)?;
+    }
+
+    Ok(())
+}
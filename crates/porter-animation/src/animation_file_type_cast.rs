@@ -4,6 +4,7 @@ use std::path::Path;
 use porter_cast::CastFile;
 use porter_cast::CastId;
 use porter_cast::CastNode;
+use porter_cast::CastOptions;
 use porter_cast::CastPropertyId;
 
 use porter_math::Axis;
@@ -17,8 +18,12 @@ use crate::CurveDataType;
 use crate::KeyframeValue;
 
 /// Writes an animation in cast format to the given path.
-pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), AnimationError> {
-    let mut root = CastNode::root();
+pub fn to_cast<P: AsRef<Path>>(
+    path: P,
+    animation: &Animation,
+    options: CastOptions,
+) -> Result<(), AnimationError> {
+    let mut root = CastNode::root_with_hash_mode(options.hash_mode);
 
     let meta_node = root.create(CastId::Metadata);
 
@@ -101,11 +106,9 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
                 curve_node.create_property(CastPropertyId::Integer32, "kb")
             };
 
-            let keyframes = curve.keyframes();
+            keyframe_buffer.try_reserve_exact(curve.len())?;
 
-            keyframe_buffer.try_reserve_exact(keyframes.len())?;
-
-            for keyframe in keyframes {
+            for keyframe in curve.iter() {
                 if largest_frame_time <= 0xFF {
                     keyframe_buffer.push(keyframe.time as u8);
                 } else if largest_frame_time <= 0xFFFF {
@@ -126,9 +129,9 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
 
             let keyvalue_buffer = curve_node.create_property(property_type, "kv");
 
-            keyvalue_buffer.try_reserve_exact(keyframes.len())?;
+            keyvalue_buffer.try_reserve_exact(curve.len())?;
 
-            for keyframe in keyframes {
+            for keyframe in curve.iter() {
                 match keyframe.value {
                     KeyframeValue::Bool(bool) => {
                         keyvalue_buffer.push(bool);
@@ -161,13 +164,11 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
             .create_property(CastPropertyId::String, "n")
             .push(curve.name());
 
-        let keyframes = curve.keyframes();
-
         let key_buffer = track_node.create_property(CastPropertyId::Integer32, "kb");
 
-        key_buffer.try_reserve_exact(keyframes.len())?;
+        key_buffer.try_reserve_exact(curve.len())?;
 
-        for key in keyframes {
+        for key in curve.iter() {
             key_buffer.push(key.time);
         }
     }
@@ -212,7 +213,7 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
 
     let writer = File::create(path.as_ref().with_extension("cast"))?.buffer_write();
 
-    let mut file = CastFile::new();
+    let mut file = CastFile::with_options(options);
 
     file.push(root);
     file.write(writer)?;
@@ -76,30 +76,29 @@ impl AnimationSampler {
         }
 
         for curve in &self.animation.curves {
-            debug_assert!(curve.keyframes().is_sorted_by_key(|curve| curve.time));
+            debug_assert!(curve.iter().is_sorted_by_key(|keyframe| keyframe.time));
 
-            if curve.is_empty() {
-                continue;
-            }
+            // Keyframes are walked one at a time, so compact curves are never fully decompressed.
+            let mut keyframes = curve.iter();
 
-            let mut keyframe: Keyframe = curve
-                .keyframes() // We must have at least one keyframe because we made sure it's not empty.
-                .last()
-                .cloned()
-                .unwrap();
+            let Some(mut previous) = keyframes.next() else {
+                continue;
+            };
 
-            for window in curve.keyframes().windows(2) {
-                let keyframe0 = window[0];
-                let keyframe1 = window[1];
+            let mut keyframe: Option<Keyframe> = None;
 
-                if !(keyframe0.time..keyframe1.time).contains(&self.frame_current) {
-                    continue;
+            for next in keyframes {
+                if (previous.time..next.time).contains(&self.frame_current) {
+                    keyframe = Some(previous.lerp(&next, self.frame_current));
+                    break;
                 }
 
-                keyframe = keyframe0.lerp(&keyframe1, self.frame_current);
-                break;
+                previous = next;
             }
 
+            // Frames past the last keyframe hold the last keyframe.
+            let keyframe = keyframe.unwrap_or(previous);
+
             self.frame_cache.insert(
                 SampleKey::new(curve.name(), curve.attribute()),
                 keyframe.value,
@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use porter_utils::AsPythonString;
+use porter_utils::BufferWriteExt;
+
+use crate::Animation;
+use crate::AnimationError;
+use crate::AnimationFileType;
+
+/// Writes a blender python script that imports the animation saved with the first of the given file types.
+pub fn to_blender_script<P: AsRef<Path>>(
+    path: P,
+    animation: &Animation,
+    file_types: &[AnimationFileType],
+) -> Result<(), AnimationError> {
+    let path = path.as_ref();
+
+    let Some(&file_type) = file_types.first() else {
+        return Ok(());
+    };
+
+    let file_name = path.with_extension(file_type);
+    let file_name = file_name
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+
+    let import = match file_type {
+        AnimationFileType::Cast => "bpy.ops.import_scene.cast(filepath=path)",
+    };
+
+    let mut script = File::create(path.with_extension("py"))?.buffer_write();
+
+    writeln!(
+        script,
+        "# Exported by PorterLib\n# Please credit DTZxPorter for use of this asset!\n"
+    )?;
+
+    writeln!(
+        script,
+        "import os\nimport bpy\n\ndirectory = os.path.dirname(os.path.abspath(__file__))\npath = os.path.join(directory, {})\n",
+        file_name.as_python_string()
+    )?;
+
+    writeln!(
+        script,
+        "scene = bpy.context.scene\nscene.render.fps = {}\nscene.frame_start = 0\nscene.frame_end = {}\n\n{import}",
+        (animation.framerate.round() as u32).max(1),
+        animation.frame_count().saturating_sub(1)
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use crate::Keyframe;
+use crate::KeyframeValue;
+
+/// The largest value of a quantized quaternion component.
+const QUATERNION_COMPONENT_MAX: f32 = 0x7FFF as f32;
+/// The largest value of a quantized vector component.
+const VECTOR_COMPONENT_MAX: f32 = 0xFFFF as f32;
+
+/// Keyframe times, stored in the smallest integer that fits the curve.
+#[derive(Debug, Clone)]
+enum CompactTimes {
+    Short(Vec<u16>),
+    Integer(Vec<u32>),
+}
+
+/// Keyframe values, quantized where the attribute allows it.
+#[derive(Debug, Clone)]
+enum CompactValues {
+    /// Smallest three quaternions, 15 bits per component with the dropped component index in the top bits.
+    Quaternion(Vec<[u16; 3]>),
+    /// Vectors quantized to 16 bits per component within the bounds of the curve.
+    Vector3 {
+        min: Vector3,
+        extent: Vector3,
+        values: Vec<[u16; 3]>,
+    },
+    Bool(Vec<bool>),
+    Float(Vec<f32>),
+    None,
+}
+
+/// A compact representation of a curve's keyframes, which decompresses keyframes on demand.
+#[derive(Debug, Clone)]
+pub(crate) struct CompactKeyframes {
+    times: CompactTimes,
+    values: CompactValues,
+}
+
+impl CompactKeyframes {
+    /// Compresses the given keyframes, or returns `None` if the values are not all of the same type.
+    pub fn compress(keyframes: &[Keyframe]) -> Option<Self> {
+        let largest_frame_time = keyframes
+            .iter()
+            .map(|keyframe| keyframe.time)
+            .max()
+            .unwrap_or_default();
+
+        let times = if largest_frame_time <= 0xFFFF {
+            CompactTimes::Short(keyframes.iter().map(|x| x.time as u16).collect())
+        } else {
+            CompactTimes::Integer(keyframes.iter().map(|x| x.time).collect())
+        };
+
+        let values = match keyframes.first().map(|x| x.value) {
+            Some(KeyframeValue::Quaternion(_)) => CompactValues::Quaternion(
+                keyframes
+                    .iter()
+                    .map(|x| Quaternion::try_from(x.value).ok().map(compress_quaternion))
+                    .collect::<Option<_>>()?,
+            ),
+            Some(KeyframeValue::Vector3(_)) => {
+                let vectors: Vec<Vector3> = keyframes
+                    .iter()
+                    .map(|x| Vector3::try_from(x.value).ok())
+                    .collect::<Option<_>>()?;
+
+                compress_vectors(&vectors)
+            }
+            Some(KeyframeValue::Bool(_)) => CompactValues::Bool(
+                keyframes
+                    .iter()
+                    .map(|x| bool::try_from(x.value).ok())
+                    .collect::<Option<_>>()?,
+            ),
+            Some(KeyframeValue::Float(_)) => CompactValues::Float(
+                keyframes
+                    .iter()
+                    .map(|x| f32::try_from(x.value).ok())
+                    .collect::<Option<_>>()?,
+            ),
+            Some(KeyframeValue::None) | None => {
+                if !keyframes
+                    .iter()
+                    .all(|x| matches!(x.value, KeyframeValue::None))
+                {
+                    return None;
+                }
+
+                CompactValues::None
+            }
+        };
+
+        Some(Self { times, values })
+    }
+
+    /// Decompresses every keyframe.
+    pub fn decompress(&self) -> Vec<Keyframe> {
+        (0..self.len()).filter_map(|i| self.keyframe(i)).collect()
+    }
+
+    /// Decompresses the keyframe at the given index.
+    pub fn keyframe(&self, index: usize) -> Option<Keyframe> {
+        let time = match &self.times {
+            CompactTimes::Short(times) => *times.get(index)? as u32,
+            CompactTimes::Integer(times) => *times.get(index)?,
+        };
+
+        let value = match &self.values {
+            CompactValues::Quaternion(values) => {
+                KeyframeValue::Quaternion(decompress_quaternion(*values.get(index)?))
+            }
+            CompactValues::Vector3 {
+                min,
+                extent,
+                values,
+            } => {
+                let value = values.get(index)?;
+
+                KeyframeValue::Vector3(Vector3::new(
+                    min.x + (value[0] as f32 / VECTOR_COMPONENT_MAX) * extent.x,
+                    min.y + (value[1] as f32 / VECTOR_COMPONENT_MAX) * extent.y,
+                    min.z + (value[2] as f32 / VECTOR_COMPONENT_MAX) * extent.z,
+                ))
+            }
+            CompactValues::Bool(values) => KeyframeValue::Bool(*values.get(index)?),
+            CompactValues::Float(values) => KeyframeValue::Float(*values.get(index)?),
+            CompactValues::None => KeyframeValue::None,
+        };
+
+        Some(Keyframe { value, time })
+    }
+
+    /// Returns the largest frame time without decompressing.
+    pub fn largest_frame_time(&self) -> u32 {
+        match &self.times {
+            CompactTimes::Short(times) => times.iter().copied().max().unwrap_or_default() as u32,
+            CompactTimes::Integer(times) => times.iter().copied().max().unwrap_or_default(),
+        }
+    }
+
+    /// Scales vector values by the given factor without decompressing.
+    pub fn scale(&mut self, factor: f32) {
+        if let CompactValues::Vector3 { min, extent, .. } = &mut self.values {
+            *min *= factor;
+            *extent *= factor;
+        }
+    }
+
+    /// Returns the number of keyframes.
+    pub fn len(&self) -> usize {
+        match &self.times {
+            CompactTimes::Short(times) => times.len(),
+            CompactTimes::Integer(times) => times.len(),
+        }
+    }
+}
+
+/// Packs a quaternion using the smallest three method.
+fn compress_quaternion(quaternion: Quaternion) -> [u16; 3] {
+    let mut components = [quaternion.x, quaternion.y, quaternion.z, quaternion.w];
+
+    let length = components.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if length > 0.0 {
+        components.iter_mut().for_each(|x| *x /= length);
+    }
+
+    let largest = (0..4)
+        .max_by(|a, b| components[*a].abs().total_cmp(&components[*b].abs()))
+        .unwrap_or(3);
+
+    // q and -q are the same rotation, so the dropped component can always be made positive.
+    if components[largest] < 0.0 {
+        components.iter_mut().for_each(|x| *x = -*x);
+    }
+
+    let mut result = [0u16; 3];
+
+    for (packed, component) in result.iter_mut().zip(
+        components
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != largest)
+            .map(|(_, x)| *x),
+    ) {
+        let normalized = ((component + FRAC_1_SQRT_2) / (FRAC_1_SQRT_2 * 2.0)).clamp(0.0, 1.0);
+
+        *packed = (normalized * QUATERNION_COMPONENT_MAX).round() as u16;
+    }
+
+    result[0] |= ((largest as u16) & 0x2) << 14;
+    result[1] |= ((largest as u16) & 0x1) << 15;
+
+    result
+}
+
+/// Unpacks a quaternion packed using the smallest three method.
+fn decompress_quaternion(packed: [u16; 3]) -> Quaternion {
+    let largest = (((packed[0] >> 15) << 1) | (packed[1] >> 15)) as usize;
+
+    let smallest = packed.map(|x| {
+        ((x & 0x7FFF) as f32 / QUATERNION_COMPONENT_MAX) * (FRAC_1_SQRT_2 * 2.0) - FRAC_1_SQRT_2
+    });
+
+    let remaining = (1.0 - smallest.iter().map(|x| x * x).sum::<f32>())
+        .max(0.0)
+        .sqrt();
+
+    let mut components = [0.0; 4];
+    let mut smallest = smallest.iter();
+
+    for (i, component) in components.iter_mut().enumerate() {
+        *component = if i == largest {
+            remaining
+        } else {
+            smallest.next().copied().unwrap_or_default()
+        };
+    }
+
+    Quaternion::new(components[0], components[1], components[2], components[3])
+}
+
+/// Quantizes vectors within the bounds of all of the given vectors.
+fn compress_vectors(vectors: &[Vector3]) -> CompactValues {
+    let mut min = Vector3::new(f32::MAX, f32::MAX, f32::MAX);
+    let mut max = Vector3::new(f32::MIN, f32::MIN, f32::MIN);
+
+    for vector in vectors {
+        min = Vector3::new(
+            min.x.min(vector.x),
+            min.y.min(vector.y),
+            min.z.min(vector.z),
+        );
+        max = Vector3::new(
+            max.x.max(vector.x),
+            max.y.max(vector.y),
+            max.z.max(vector.z),
+        );
+    }
+
+    if vectors.is_empty() {
+        min = Vector3::zero();
+        max = Vector3::zero();
+    }
+
+    let extent = max - min;
+
+    let quantize = |value: f32, min: f32, extent: f32| {
+        if extent > 0.0 {
+            (((value - min) / extent).clamp(0.0, 1.0) * VECTOR_COMPONENT_MAX).round() as u16
+        } else {
+            0
+        }
+    };
+
+    let values = vectors
+        .iter()
+        .map(|vector| {
+            [
+                quantize(vector.x, min.x, extent.x),
+                quantize(vector.y, min.y, extent.y),
+                quantize(vector.z, min.z, extent.z),
+            ]
+        })
+        .collect();
+
+    CompactValues::Vector3 {
+        min,
+        extent,
+        values,
+    }
+}
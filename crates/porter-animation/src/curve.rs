@@ -1,6 +1,9 @@
+use std::sync::OnceLock;
+
 use crate::AnimationError;
 use crate::Keyframe;
 use crate::KeyframeValue;
+use crate::compact_keyframes::CompactKeyframes;
 
 /// The attribute of the node a curve is animating.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -37,6 +40,8 @@ pub struct Curve {
     attribute: CurveAttribute,
     data_type: CurveDataType,
     keyframes: Vec<Keyframe>,
+    compact: Option<CompactKeyframes>,
+    decompressed: OnceLock<Vec<Keyframe>>,
 }
 
 impl Curve {
@@ -51,6 +56,8 @@ impl Curve {
             attribute,
             data_type,
             keyframes: Vec::new(),
+            compact: None,
+            decompressed: OnceLock::new(),
         }
     }
 
@@ -75,17 +82,115 @@ impl Curve {
     }
 
     /// Returns the keyframes of this curve.
+    ///
+    /// A compact curve is decompressed and cached on first access, prefer `iter` to avoid holding the keyframes.
     pub fn keyframes(&self) -> &[Keyframe] {
-        &self.keyframes
+        match &self.compact {
+            Some(compact) => self.decompressed.get_or_init(|| compact.decompress()),
+            None => &self.keyframes,
+        }
     }
 
-    /// Returns the keyframes mutable of this curve.
+    /// Returns the keyframes mutable of this curve, decompressing it if it's compact.
     pub fn keyframes_mut(&mut self) -> &mut [Keyframe] {
+        self.decompress();
+
         &mut self.keyframes
     }
 
+    /// Returns the keyframe at the given index, decompressing only that keyframe if the curve is compact.
+    pub fn keyframe(&self, index: usize) -> Option<Keyframe> {
+        match &self.compact {
+            Some(compact) => match self.decompressed.get() {
+                Some(keyframes) => keyframes.get(index).copied(),
+                None => compact.keyframe(index),
+            },
+            None => self.keyframes.get(index).copied(),
+        }
+    }
+
+    /// Iterates over the keyframes of this curve, decompressing them one at a time if the curve is compact.
+    pub fn iter(&self) -> impl Iterator<Item = Keyframe> + '_ {
+        (0..self.len()).filter_map(|index| self.keyframe(index))
+    }
+
+    /// Samples the value of this curve at the given time, interpolating between the surrounding keyframes.
+    pub fn sample(&self, time: u32) -> Option<KeyframeValue> {
+        let mut previous: Option<Keyframe> = None;
+
+        for keyframe in self.iter() {
+            if keyframe.time == time {
+                return Some(keyframe.value);
+            }
+
+            if keyframe.time > time {
+                return match previous {
+                    Some(previous) if previous.time < time => {
+                        Some(previous.lerp(&keyframe, time).value)
+                    }
+                    _ => Some(keyframe.value),
+                };
+            }
+
+            previous = Some(keyframe);
+        }
+
+        previous.map(|keyframe| keyframe.value)
+    }
+
+    /// Stores the keyframes in a compact quantized form, which uses much less memory for large animations.
+    ///
+    /// Rotations and vectors lose a small amount of precision. Does nothing if the keyframe values are not all the same type.
+    pub fn compact(&mut self) {
+        if self.compact.is_some() {
+            return;
+        }
+
+        if let Some(compact) = CompactKeyframes::compress(&self.keyframes) {
+            self.compact = Some(compact);
+            self.keyframes = Vec::new();
+        }
+    }
+
+    /// Restores the keyframes from the compact form, if the curve is compact.
+    pub fn decompress(&mut self) {
+        let Some(compact) = self.compact.take() else {
+            return;
+        };
+
+        self.keyframes = match self.decompressed.take() {
+            Some(keyframes) => keyframes,
+            None => compact.decompress(),
+        };
+    }
+
+    /// Returns whether or not the keyframes are stored in the compact form.
+    pub fn is_compact(&self) -> bool {
+        self.compact.is_some()
+    }
+
+    /// Scales vector keyframes by the given factor, without decompressing a compact curve.
+    pub(crate) fn scale_vectors(&mut self, factor: f32) {
+        if let Some(compact) = &mut self.compact {
+            compact.scale(factor);
+
+            self.decompressed = OnceLock::new();
+            return;
+        }
+
+        for keyframe in &mut self.keyframes {
+            if let KeyframeValue::Vector3(vector) = &mut keyframe.value {
+                *vector *= factor;
+            }
+        }
+    }
+
     /// Returns the largest frame time in this curve.
     pub fn largest_frame_time(&self) -> u32 {
+        if let Some(compact) = &self.compact {
+            return compact.largest_frame_time();
+        }
+
         let mut result = 0;
 
         for keyframe in self.keyframes() {
@@ -97,6 +202,7 @@ impl Curve {
 
     /// Sorts the curve's keyframes in order by time.
     pub fn sort(&mut self) {
+        self.decompress();
         self.keyframes.sort_by_key(|keyframe| keyframe.time);
     }
 
@@ -113,17 +219,21 @@ impl Curve {
             CurveAttribute::BlendShape => matches!(value, KeyframeValue::Float(_)),
         });
 
+        self.decompress();
         self.keyframes.push(Keyframe { time, value });
     }
 
     /// Returns the number of keyframes in this curve.
     pub fn len(&self) -> usize {
-        self.keyframes.len()
+        match &self.compact {
+            Some(compact) => compact.len(),
+            None => self.keyframes.len(),
+        }
     }
 
     /// Returns whether or not this curve has any keyframes.
     pub fn is_empty(&self) -> bool {
-        self.keyframes.is_empty()
+        self.len() == 0
     }
 
     /// Tries to reserve capacity for at least `additional` more keyframes to be inserted into the given `Curve`.
@@ -134,6 +244,8 @@ impl Curve {
     /// # Errors
     /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
     pub fn try_reserve(&mut self, additional: usize) -> Result<(), AnimationError> {
+        self.decompress();
+
         Ok(self.keyframes.try_reserve(additional)?)
     }
 
@@ -148,6 +260,8 @@ impl Curve {
     /// # Errors
     /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
     pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), AnimationError> {
+        self.decompress();
+
         Ok(self.keyframes.try_reserve_exact(additional)?)
     }
 }
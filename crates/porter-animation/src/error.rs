@@ -1,3 +1,5 @@
+use porter_utils::ReportError;
+
 /// Errors that can occur in the animation crate.
 #[derive(Debug)]
 pub enum AnimationError {
@@ -18,3 +20,25 @@ impl From<std::collections::TryReserveError> for AnimationError {
         Self::TryReserveError(value)
     }
 }
+
+impl ReportError for AnimationError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::IoError(e) => e.error_code(),
+            Self::TryReserveError(e) => e.error_code(),
+            Self::InvalidKeyframeValue => "ANIM-001",
+            Self::InvalidJointName => "ANIM-002",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::IoError(e) => e.error_message(),
+            Self::TryReserveError(e) => e.error_message(),
+            Self::InvalidKeyframeValue => {
+                String::from("The animation has a keyframe with the wrong type of value.")
+            }
+            Self::InvalidJointName => String::from("The animation targets a joint without a name."),
+        }
+    }
+}
@@ -10,6 +10,7 @@ mod ik_compiler;
 mod ik_solver;
 mod joint;
 mod keyframe;
+mod retarget;
 
 pub use animation::*;
 pub use animation_file_type::*;
@@ -21,5 +22,9 @@ pub use ik_compiler::*;
 pub use ik_solver::*;
 pub use joint::*;
 pub use keyframe::*;
+pub use retarget::*;
 
+pub(crate) mod animation_file_type_bvh;
 pub(crate) mod animation_file_type_cast;
+pub(crate) mod blender_script;
+pub(crate) mod compact_keyframes;
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use porter_math::Vector3;
+
+use porter_model::Bone;
+use porter_model::Skeleton;
+
+use crate::Animation;
+use crate::Curve;
+use crate::CurveAttribute;
+use crate::CurveDataType;
+use crate::CurveModeOverride;
+use crate::KeyframeValue;
+
+/// A user supplied map of source bone names to target bone names, used when retargeting animations.
+#[derive(Debug, Clone, Default)]
+pub struct BoneMap {
+    bones: HashMap<String, String>,
+}
+
+impl BoneMap {
+    /// Constructs a new empty bone map.
+    pub fn new() -> Self {
+        Self {
+            bones: HashMap::new(),
+        }
+    }
+
+    /// Maps the given source bone to the given target bone.
+    pub fn map<S: Into<String>, T: Into<String>>(mut self, source: S, target: T) -> Self {
+        self.insert(source, target);
+        self
+    }
+
+    /// Maps the given source bone to the given target bone.
+    pub fn insert<S: Into<String>, T: Into<String>>(&mut self, source: S, target: T) {
+        self.bones.insert(source.into(), target.into());
+    }
+
+    /// Gets the target bone for the given source bone, if it was mapped.
+    pub fn get<S: AsRef<str>>(&self, source: S) -> Option<&str> {
+        self.bones.get(source.as_ref()).map(String::as_str)
+    }
+
+    /// The number of mapped bones.
+    pub fn len(&self) -> usize {
+        self.bones.len()
+    }
+
+    /// Whether or not no bones have been mapped.
+    pub fn is_empty(&self) -> bool {
+        self.bones.is_empty()
+    }
+}
+
+/// Resolves the names of target bones from source bones.
+struct BoneResolver<'a> {
+    target: &'a Skeleton,
+    map: &'a BoneMap,
+    normalized: HashMap<String, &'a str>,
+}
+
+impl<'a> BoneResolver<'a> {
+    /// Constructs a new resolver for the given target skeleton and bone map.
+    fn new(target: &'a Skeleton, map: &'a BoneMap) -> Self {
+        let mut normalized = HashMap::with_capacity(target.bones.len());
+
+        for name in target.bones.iter().filter_map(|bone| bone.name.as_deref()) {
+            normalized.entry(normalize_name(name)).or_insert(name);
+        }
+
+        Self {
+            target,
+            map,
+            normalized,
+        }
+    }
+
+    /// Finds the target bone for the given source bone, using the bone map first, then an exact or normalized name match.
+    fn resolve(&self, name: &str) -> Option<&'a Bone> {
+        self.resolve_with_priority(name).map(|(bone, _)| bone)
+    }
+
+    /// Finds the target bone for the given source bone, along with how closely it matched, lower is closer.
+    fn resolve_with_priority(&self, name: &str) -> Option<(&'a Bone, u8)> {
+        if let Some(mapped) = self.map.get(name) {
+            return self.target.find(mapped).map(|bone| (bone, 0));
+        }
+
+        if let Some(bone) = self.target.find(name) {
+            return Some((bone, 1));
+        }
+
+        self.normalized
+            .get(&normalize_name(name))
+            .and_then(|name| self.target.find(name))
+            .map(|bone| (bone, 2))
+    }
+}
+
+/// Normalizes a bone name for matching, ignoring case, namespaces, and separators.
+fn normalize_name(name: &str) -> String {
+    let name = name.rsplit(':').next().unwrap_or(name);
+
+    name.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Remaps the curves of an animation from the source skeleton to the target skeleton.
+pub(crate) fn retarget(
+    animation: &Animation,
+    source: &Skeleton,
+    target: &Skeleton,
+    map: &BoneMap,
+) -> Animation {
+    let resolver = BoneResolver::new(target, map);
+
+    let mut result = Animation::new(animation.framerate, animation.looping);
+
+    result.up_axis = animation.up_axis;
+
+    let targets: Vec<_> = animation
+        .curves
+        .iter()
+        .map(|curve| match curve.attribute() {
+            CurveAttribute::Notetrack | CurveAttribute::BlendShape => None,
+            _ => resolver
+                .resolve_with_priority(curve.name())
+                .filter(|(bone, _)| bone.name.is_some()),
+        })
+        .collect();
+
+    // Several source curves can resolve to the same target bone, only the closest match is kept.
+    let mut chosen: HashMap<(&str, CurveAttribute), (u8, usize)> = HashMap::new();
+
+    for (index, (curve, target)) in animation.curves.iter().zip(&targets).enumerate() {
+        let Some((bone, priority)) = target else {
+            continue;
+        };
+
+        let key = (bone.name.as_deref().unwrap_or_default(), curve.attribute());
+
+        chosen
+            .entry(key)
+            .and_modify(|best| {
+                if *priority < best.0 {
+                    *best = (*priority, index);
+                }
+            })
+            .or_insert((*priority, index));
+    }
+
+    for (index, (curve, target)) in animation.curves.iter().zip(&targets).enumerate() {
+        match curve.attribute() {
+            CurveAttribute::Notetrack | CurveAttribute::BlendShape => {
+                result.curves.push(curve.clone());
+                continue;
+            }
+            _ => {}
+        }
+
+        let Some((target_bone, _)) = target else {
+            continue;
+        };
+
+        let Some(target_name) = target_bone.name.as_deref() else {
+            continue;
+        };
+
+        if chosen
+            .get(&(target_name, curve.attribute()))
+            .is_some_and(|(_, chosen)| *chosen != index)
+        {
+            continue;
+        }
+
+        let mut retargeted = Curve::new(target_name, curve.attribute(), curve.data_type());
+
+        let source_bone = source.find(curve.name());
+        let data_type = effective_data_type(animation, source, curve);
+
+        for keyframe in curve.iter() {
+            let value = match source_bone {
+                Some(source_bone) => retarget_value(
+                    keyframe.value,
+                    curve.attribute(),
+                    data_type,
+                    source_bone,
+                    target_bone,
+                ),
+                None => keyframe.value,
+            };
+
+            retargeted.insert(keyframe.time, value);
+        }
+
+        result.curves.push(retargeted);
+    }
+
+    for curve_mode_override in &animation.curve_mode_overrides {
+        let Some(name) = resolver
+            .resolve(&curve_mode_override.name)
+            .and_then(|bone| bone.name.as_deref())
+        else {
+            continue;
+        };
+
+        if result
+            .curve_mode_overrides
+            .iter()
+            .any(|existing| existing.name == name)
+        {
+            continue;
+        }
+
+        result.curve_mode_overrides.push(CurveModeOverride {
+            name: name.to_string(),
+            ..curve_mode_override.clone()
+        });
+    }
+
+    result
+}
+
+/// Resolves the data type a curve is played back with, from the closest override on its bone or one of its parents.
+fn effective_data_type(animation: &Animation, source: &Skeleton, curve: &Curve) -> CurveDataType {
+    let applies = |curve_mode_override: &CurveModeOverride| match curve.attribute() {
+        CurveAttribute::Translate => curve_mode_override.override_translate,
+        CurveAttribute::Rotation => curve_mode_override.override_rotation,
+        CurveAttribute::Scale => curve_mode_override.override_scale,
+        _ => false,
+    };
+
+    let mut name = Some(curve.name());
+
+    // Bounded by the bone count, so a malformed hierarchy can't loop forever.
+    for _ in 0..=source.bones.len() {
+        let Some(bone_name) = name else {
+            break;
+        };
+
+        if let Some(curve_mode_override) =
+            animation
+                .curve_mode_overrides
+                .iter()
+                .find(|curve_mode_override| {
+                    curve_mode_override.name == bone_name && applies(curve_mode_override)
+                })
+        {
+            return curve_mode_override.data_type;
+        }
+
+        name = source
+            .find(bone_name)
+            .and_then(|bone| usize::try_from(bone.parent).ok())
+            .and_then(|parent| source.bones.get(parent))
+            .and_then(|bone| bone.name.as_deref());
+    }
+
+    curve.data_type()
+}
+
+/// Corrects a keyframe value for the difference between the source and target rest poses.
+fn retarget_value(
+    value: KeyframeValue,
+    attribute: CurveAttribute,
+    data_type: CurveDataType,
+    source: &Bone,
+    target: &Bone,
+) -> KeyframeValue {
+    match (attribute, value) {
+        (CurveAttribute::Rotation, KeyframeValue::Quaternion(rotation)) => {
+            if data_type != CurveDataType::Absolute {
+                return value;
+            }
+
+            // Apply the rotation relative to the source rest pose on top of the target rest pose.
+            let delta = source.local_rotation.inverse() * rotation;
+
+            KeyframeValue::Quaternion((target.local_rotation * delta).normalized())
+        }
+        (CurveAttribute::Translate, KeyframeValue::Vector3(translation)) => {
+            let ratio = translation_ratio(source, target);
+
+            if data_type != CurveDataType::Absolute {
+                return KeyframeValue::Vector3(translation * ratio);
+            }
+
+            KeyframeValue::Vector3(
+                target.local_position + (translation - source.local_position) * ratio,
+            )
+        }
+        (CurveAttribute::Scale, KeyframeValue::Vector3(scale)) => {
+            if data_type != CurveDataType::Absolute {
+                return value;
+            }
+
+            KeyframeValue::Vector3(target.local_scale * (scale / nonzero(source.local_scale)))
+        }
+        _ => value,
+    }
+}
+
+/// The ratio of the target bone length to the source bone length, used to scale translations between rigs.
+fn translation_ratio(source: &Bone, target: &Bone) -> f32 {
+    let source_length = source.local_position.length();
+    let target_length = target.local_position.length();
+
+    if source_length <= f32::EPSILON || target_length <= f32::EPSILON {
+        1.0
+    } else {
+        target_length / source_length
+    }
+}
+
+/// Replaces zero components of a scale with one, so that it can be divided by.
+fn nonzero(scale: Vector3) -> Vector3 {
+    let component = |value: f32| {
+        if value.abs() <= f32::EPSILON {
+            1.0
+        } else {
+            value
+        }
+    };
+
+    Vector3::new(component(scale.x), component(scale.y), component(scale.z))
+}
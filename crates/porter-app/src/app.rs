@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
 use iced::theme::Palette;
 
@@ -10,10 +11,22 @@ use iced::Subscription;
 use iced::Task;
 use iced::Theme;
 
+use porter_utils::DirectoryCache;
+use porter_utils::ErrorReport;
+use porter_utils::NameDatabase;
+
 use crate::AppState;
 use crate::AssetPreview;
+use crate::AssetPreviewMerge;
 use crate::ColumnStatus;
 use crate::Controller;
+use crate::ExportConflictPolicy;
+use crate::ExportConflicts;
+use crate::ExportFailures;
+use crate::ExportManifest;
+use crate::ExportMode;
+use crate::ExportQueue;
+use crate::ExportStatistics;
 use crate::MainMessage;
 use crate::MainWindow;
 use crate::Message;
@@ -25,7 +38,11 @@ use crate::components::ContentMessage;
 use crate::components::HeaderMessage;
 use crate::components::PreviewMessage;
 use crate::components::SearchBarMessage;
+use crate::export_failures_summary;
 use crate::palette;
+use crate::preflight_output_directory;
+use crate::widgets;
+use crate::write_export_failures_log;
 
 /// Entry point for the iced application.
 pub struct App {
@@ -38,10 +55,29 @@ pub struct App {
 impl App {
     /// Constructs a new app entry point.
     pub fn new(state: AppState) -> (Self, Task<Message>) {
+        widgets::set_reduced_motion(state.settings.reduced_motion());
+
         let (main_window, main_window_task) = MainWindow::create();
-        let (splash_window, splash_window_task) = SplashWindow::create();
 
-        let task = Task::batch([main_window_task, splash_window_task]).discard();
+        let (splash_window, task) = if state.settings.skip_splash() {
+            let task = main_window_task
+                .discard()
+                .chain(Task::done(Message::from(MainMessage::Show)));
+
+            (SplashWindow::skipped(), task)
+        } else {
+            let (splash_window, splash_window_task) = SplashWindow::create();
+
+            let task = Task::batch([main_window_task, splash_window_task]).discard();
+
+            (splash_window, task)
+        };
+
+        let task = if state.files_dropped.is_empty() {
+            task
+        } else {
+            task.chain(Task::done(Message::LoadFilesDropped))
+        };
 
         let ui = Self {
             state,
@@ -100,12 +136,21 @@ impl App {
             PreviewUpdate(request_id, asset) => self.on_preview_update(request_id, asset),
             ExportSelected => self.on_export_selected(),
             ExportAll => self.on_export_all(),
+            ExportCombined => self.on_export_combined(),
+            ExportMerged => self.on_export_merged(),
+            ExportConcatenated => self.on_export_concatenated(),
+            ExportOverwrite(assets, mode) => self.on_export_overwrite(assets, mode),
             ExportCancel => self.on_export_cancel(),
             LoadFiles(files) => self.on_load_files(files),
             LoadFilesDropped => self.on_load_files_dropped(),
+            LoadFilesForwarded(files) => self.on_load_files_forwarded(files),
             LoadGame => self.on_load_game(),
             Sort(index) => self.on_sort(index),
             CheckReload => self.on_check_reload(),
+            NamesReload => self.on_names_reload(),
+            NamesUpdate(result) => self.on_names_update(result),
+            AssetsFetch => self.on_assets_fetch(),
+            AssetsFetchUpdate(page, result) => self.on_assets_fetch_update(page, result),
         }
     }
 
@@ -270,20 +315,78 @@ impl App {
 
     /// Occurs when the global controller is initialized.
     fn on_controller(&mut self, controller: Controller) -> Task<Message> {
+        if let Some(single_instance) = self.state.single_instance.take() {
+            single_instance.listen(controller.clone());
+        }
+
         self.state.controller = controller;
 
-        Task::none()
+        if self.state.settings.name_databases().is_empty() {
+            return Task::none();
+        }
+
+        self.on_names_reload()
     }
 
     /// Occurs when progress has been made by the asset manager.
     fn on_progress_update(&mut self, finished: bool, progress: u32) -> Task<Message> {
         if finished {
+            if self.state.exporting {
+                self.state.export_queue.finish(self.state.export_canceled);
+            }
+
+            if let Some((started, statistics)) = self.state.export_started.take() {
+                let exported = statistics.take();
+
+                let game = self
+                    .state
+                    .settings
+                    .profile()
+                    .unwrap_or(self.state.name)
+                    .to_owned();
+
+                self.state
+                    .usage_statistics
+                    .record_export(&game, &exported, started.elapsed());
+                self.state.usage_statistics.save(self.state.name);
+            }
+
             self.state.loading = false;
             self.state.exporting = false;
             self.state.export_canceled = false;
             self.state.progress = 0;
 
-            return self.on_check_reload();
+            let mut tasks = vec![self.on_check_reload()];
+
+            let failures = self.state.export_failures.take();
+
+            if !failures.is_empty() {
+                write_export_failures_log(self.state.name, &failures);
+
+                tasks.push(Task::done(Message::from(MainMessage::Warning(
+                    export_failures_summary(&failures),
+                ))));
+            }
+
+            if let Some(manifest) = self.state.export_manifest.take()
+                && let Err(e) = manifest.save()
+            {
+                tasks.push(Task::done(Message::from(MainMessage::Warning(format!(
+                    "Failed to write the export manifest: {}",
+                    e
+                )))));
+            }
+
+            let conflicts = self.state.export_conflicts.take();
+
+            if !conflicts.is_empty() {
+                tasks.push(Task::done(Message::from(MainMessage::ExportConflicts(
+                    conflicts,
+                    self.state.export_mode,
+                ))));
+            }
+
+            return Task::batch(tasks);
         } else {
             self.state.progress = progress.clamp(0, 100);
         }
@@ -292,14 +395,37 @@ impl App {
     }
 
     /// Occurs when a preview request has been completed by the asset manager.
-    fn on_preview_update(&mut self, request_id: u64, asset: AssetPreview) -> Task<Message> {
-        if self.state.asset_preview_id.is_none()
+    fn on_preview_update(
+        &mut self,
+        request_id: u64,
+        asset: Result<AssetPreview, ErrorReport>,
+    ) -> Task<Message> {
+        let asset = if let Some(merge) = &mut self.state.asset_preview_merge {
+            let Some(asset) = merge.insert(request_id, asset) else {
+                return Task::none();
+            };
+
+            asset
+        } else if self.state.asset_preview_id.is_none()
             || self.state.asset_preview_id.is_some_and(|x| x != request_id)
         {
             return Task::none();
-        }
+        } else {
+            asset
+        };
+
+        let merged = self.state.asset_preview_merge.take().is_some();
 
         self.state.asset_preview_id = None;
+        self.state.preview_visibility = if merged {
+            None
+        } else {
+            self.state
+                .assets_selected
+                .first()
+                .map(|index| (*index, Vec::new()))
+        };
+        self.state.preview_frame = self.state.assets_selected.first().map(|index| (*index, 0));
 
         self.on_preview_proxy(PreviewMessage::Update(asset))
     }
@@ -308,9 +434,18 @@ impl App {
     fn on_load_update(&mut self, result: Result<(), String>) -> Task<Message> {
         self.state.loading = false;
         self.state.progress = 0;
+        self.state.preview_visibility = None;
+        self.state.preview_frame = None;
         self.state.reset_item_range();
 
         let icon = self.state.asset_manager.display_icon();
+        let profile = self.state.asset_manager.settings_profile();
+
+        // Games without a profile must not keep the overrides of the previous game.
+        if self.state.settings.profile() != profile.as_deref() {
+            self.state.settings.set_profile(profile);
+            self.state.settings.save(self.state.name);
+        }
 
         if let Err(e) = result {
             self.state.last_load = None;
@@ -389,17 +524,37 @@ impl App {
 
         let manager = self.state.asset_manager.clone();
         let controller = self.state.controller.clone();
-        let settings = self.state.settings.clone();
+        let mut settings = self.state.settings.clone();
         let request_id = self
             .state
             .asset_preview_id
             .map(|id| id + 1)
             .unwrap_or_default();
 
-        self.state.asset_preview_id = Some(request_id);
-
         let raw = self.state.modifier_keys.alt();
 
+        settings.set_asset_variant(self.state.asset_variant.clone());
+
+        let assets: Vec<usize> = self.state.assets_selected.iter().copied().collect();
+
+        if !raw && assets.len() > 1 && self.state.asset_manager.assets_mergeable(&assets) {
+            let request_ids = request_id..request_id + assets.len() as u64;
+
+            self.state.asset_preview_id = Some(request_ids.end - 1);
+            self.state.asset_preview_merge = Some(AssetPreviewMerge::new(request_ids.clone()));
+
+            porter_threads::spawn(move || {
+                for (request_id, index) in request_ids.zip(assets) {
+                    manager.preview(settings.clone(), index, raw, request_id, controller.clone());
+                }
+            });
+
+            return Task::none();
+        }
+
+        self.state.asset_preview_id = Some(request_id);
+        self.state.asset_preview_merge = None;
+
         porter_threads::spawn(move || {
             manager.preview(settings, index, raw, request_id, controller);
         });
@@ -409,46 +564,127 @@ impl App {
 
     /// Occurs when the user requests to export selected assets.
     fn on_export_selected(&mut self) -> Task<Message> {
-        if self.state.is_busy() {
-            return Task::none();
-        }
+        let assets: Vec<usize> = self.state.assets_selected.iter().copied().collect();
+        let policy = self.state.settings.export_conflict_policy();
 
-        let manager = self.state.asset_manager.clone();
-        let controller = self.state.controller.clone();
-        let settings = self.state.settings.clone();
+        self.export_assets(assets, ExportMode::Separate, policy)
+    }
+
+    /// Occurs when the user requests to export all assets.
+    fn on_export_all(&mut self) -> Task<Message> {
+        let assets: Vec<usize> = (0..self.state.asset_manager.assets_visible()).collect();
+        let policy = self.state.settings.export_conflict_policy();
+
+        self.export_assets(assets, ExportMode::Separate, policy)
+    }
+
+    /// Occurs when the user requests to export selected sounds as a single file.
+    fn on_export_combined(&mut self) -> Task<Message> {
         let assets: Vec<usize> = self.state.assets_selected.iter().copied().collect();
+        let policy = self.state.settings.export_conflict_policy();
 
-        self.state.exporting = true;
-        self.state.export_canceled = false;
-        self.state.progress = 0;
+        self.export_assets(assets, ExportMode::Combined, policy)
+    }
 
-        porter_threads::spawn(move || {
-            manager.export(settings, assets, controller);
-        });
+    /// Occurs when the user requests to export selected models as a single merged model.
+    fn on_export_merged(&mut self) -> Task<Message> {
+        let assets: Vec<usize> = self.state.assets_selected.iter().copied().collect();
+        let policy = self.state.settings.export_conflict_policy();
 
-        Task::none()
+        self.export_assets(assets, ExportMode::Merged, policy)
     }
 
-    /// Occurs when the user requests to export all assets.
-    fn on_export_all(&mut self) -> Task<Message> {
+    /// Occurs when the user requests to export selected animations as a single animation timeline.
+    fn on_export_concatenated(&mut self) -> Task<Message> {
+        let assets: Vec<usize> = self.state.assets_selected.iter().copied().collect();
+        let policy = self.state.settings.export_conflict_policy();
+
+        self.export_assets(assets, ExportMode::Concatenated, policy)
+    }
+
+    /// Occurs when the user chooses to overwrite the files which already existed during the last export, which is exported again in the same mode.
+    fn on_export_overwrite(&mut self, assets: Vec<usize>, mode: ExportMode) -> Task<Message> {
+        self.export_assets(assets, mode, ExportConflictPolicy::Overwrite)
+    }
+
+    /// Starts exporting the given assets in the given mode, such as combining sounds into a single file.
+    fn export_assets(
+        &mut self,
+        assets: Vec<usize>,
+        mode: ExportMode,
+        policy: ExportConflictPolicy,
+    ) -> Task<Message> {
         if self.state.is_busy() {
             return Task::none();
         }
 
         let manager = self.state.asset_manager.clone();
         let controller = self.state.controller.clone();
-        let settings = self.state.settings.clone();
-        let assets: Vec<usize> = (0..self.state.asset_manager.assets_visible()).collect();
+        let mut settings = self.state.settings.clone();
+
+        if let Err(failure) = preflight_output_directory(settings.output_directory()) {
+            return Task::done(Message::from(MainMessage::Warning(format!(
+                "{}: {}\n\n{}",
+                failure.kind.description(),
+                failure.path.display(),
+                failure.kind.suggestion()
+            ))));
+        }
 
         self.state.exporting = true;
         self.state.export_canceled = false;
         self.state.progress = 0;
 
+        self.state.export_started = if settings.usage_statistics() {
+            let statistics = ExportStatistics::new(manager.clone());
+
+            settings.set_export_statistics(statistics.clone());
+
+            Some((Instant::now(), statistics))
+        } else {
+            None
+        };
+
+        settings.set_preview_visibility(self.state.preview_visibility.clone());
+        settings.set_preview_frame(self.state.preview_frame);
+        settings.set_export_mode(mode);
+        settings.set_asset_variant(self.state.asset_variant.clone());
+        settings.set_export_conflict_policy(policy);
+
+        self.state.export_conflicts = ExportConflicts::default();
+        self.state.export_mode = mode;
+
+        settings.set_export_conflicts(self.state.export_conflicts.clone());
+
+        self.state.export_failures = ExportFailures::default();
+
+        settings.set_export_failures(self.state.export_failures.clone());
+
+        self.state.export_queue = ExportQueue::new(&assets);
+
+        settings.set_export_queue(self.state.export_queue.clone());
+        settings.set_directory_cache(DirectoryCache::new());
+
+        self.state.export_manifest = ExportManifest::attach(&mut settings);
+
+        let missing = settings.missing_export_formats();
+
         porter_threads::spawn(move || {
             manager.export(settings, assets, controller);
         });
 
-        Task::none()
+        if missing.is_empty() {
+            return Task::none();
+        }
+
+        Task::done(Message::from(MainMessage::Warning(format!(
+            "No export formats are selected for: {}.\n\nThese assets will be skipped, choose their formats in the settings to export them.",
+            missing
+                .iter()
+                .map(|asset_type| asset_type.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))))
     }
 
     /// Occurs when the user requests to cancel an export.
@@ -477,6 +713,8 @@ impl App {
         self.state.progress = 0;
         self.state.last_load = Some(files.clone());
         self.state.assets_selected.clear();
+        self.state.assets_anchor = None;
+        self.state.export_queue = ExportQueue::default();
 
         porter_threads::spawn(move || {
             controller.load_update(manager.load_files(settings, files));
@@ -485,6 +723,19 @@ impl App {
         Task::none()
     }
 
+    /// Occurs when another instance forwarded files to load.
+    fn on_load_files_forwarded(&mut self, files: Vec<PathBuf>) -> Task<Message> {
+        let focus = window::gain_focus(self.main_window.id);
+
+        if self.state.is_busy() || files.is_empty() {
+            return focus;
+        }
+
+        self.state.files_dropped = files;
+
+        Task::batch([focus, self.on_load_files_dropped()])
+    }
+
     /// Occurs when the last file has been received from the drop queue.
     fn on_load_files_dropped(&mut self) -> Task<Message> {
         let mut files = std::mem::take(&mut self.state.files_dropped);
@@ -547,6 +798,8 @@ impl App {
         self.state.progress = 0;
         self.state.last_load = Some(Vec::new());
         self.state.assets_selected.clear();
+        self.state.assets_anchor = None;
+        self.state.export_queue = ExportQueue::default();
 
         porter_threads::spawn(move || {
             controller.load_update(manager.load_game(settings));
@@ -577,6 +830,8 @@ impl App {
             }
         }
 
+        self.state.export_queue = ExportQueue::default();
+
         Task::none()
     }
 
@@ -601,4 +856,106 @@ impl App {
 
         Task::none()
     }
+
+    /// Occurs when the user name databases need to be loaded, or reloaded.
+    fn on_names_reload(&mut self) -> Task<Message> {
+        let manager = self.state.asset_manager.clone();
+        let controller = self.state.controller.clone();
+        let files = self.state.settings.name_databases().to_vec();
+
+        porter_threads::spawn(move || {
+            let mut database = NameDatabase::new();
+            let mut errors = Vec::new();
+
+            for file in files {
+                let result = if file
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+                {
+                    NameDatabase::load_csv(&file)
+                } else {
+                    NameDatabase::load(&file)
+                };
+
+                match result {
+                    Ok(names) => database.extend_from(names),
+                    Err(e) => errors.push(format!("{}: {}", file.display(), e)),
+                }
+            }
+
+            manager.names_update(database);
+
+            if errors.is_empty() {
+                controller.names_update(Ok(()));
+            } else {
+                controller.names_update(Err(format!(
+                    "Failed to load name databases:\n{}",
+                    errors.join("\n")
+                )));
+            }
+        });
+
+        Task::none()
+    }
+
+    /// Occurs when the user name databases have been loaded by the asset manager.
+    fn on_names_update(&mut self, result: Result<(), String>) -> Task<Message> {
+        let refresh = Task::done(Message::from(SearchBarMessage::Submit));
+
+        if let Err(e) = result {
+            Task::batch([refresh, Task::done(Message::from(MainMessage::Warning(e)))])
+        } else {
+            refresh
+        }
+    }
+
+    /// Occurs when the visible rows have changed, fetches any pages of assets which haven't been listed yet.
+    fn on_assets_fetch(&mut self) -> Task<Message> {
+        if self.state.loading {
+            return Task::none();
+        }
+
+        let Some(page_size) = self.state.asset_manager.assets_page_size() else {
+            return Task::none();
+        };
+
+        let range = self.state.item_range.clone();
+
+        if range.is_empty() {
+            return Task::none();
+        }
+
+        let page_size = page_size.max(1);
+
+        for page in (range.start / page_size)..=((range.end - 1) / page_size) {
+            if self.state.assets_fetching.contains(&page)
+                || self.state.asset_manager.assets_page_fetched(page)
+            {
+                continue;
+            }
+
+            self.state.assets_fetching.insert(page);
+
+            let manager = self.state.asset_manager.clone();
+            let controller = self.state.controller.clone();
+            let settings = self.state.settings.clone();
+
+            porter_threads::spawn(move || {
+                controller.assets_fetch_update(page, manager.assets_page_fetch(settings, page));
+            });
+        }
+
+        Task::none()
+    }
+
+    /// Occurs when a page of assets has been fetched by the asset manager.
+    fn on_assets_fetch_update(&mut self, page: usize, result: Result<(), String>) -> Task<Message> {
+        self.state.assets_fetching.remove(&page);
+
+        if let Err(e) = result {
+            Task::done(Message::from(MainMessage::Warning(e)))
+        } else {
+            Task::none()
+        }
+    }
 }
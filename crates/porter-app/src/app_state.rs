@@ -2,6 +2,7 @@ use std::collections::BTreeSet;
 use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use iced::advanced::graphics;
 
@@ -12,6 +13,8 @@ use iced::Error;
 use iced::Font;
 use iced::Pixels;
 
+use porter_console::console;
+
 use porter_threads::initialize_thread_pool;
 
 use porter_utils::StringCaseExt;
@@ -22,10 +25,20 @@ use rfd::MessageDialog;
 use crate::App;
 use crate::AssetColumn;
 use crate::AssetManager;
+use crate::AssetPreviewMerge;
 use crate::Controller;
 use crate::Executor;
+use crate::ExportConflicts;
+use crate::ExportFailures;
+use crate::ExportManifest;
+use crate::ExportMode;
+use crate::ExportQueue;
+use crate::ExportStatistics;
+use crate::HeadlessOptions;
 use crate::Settings;
+use crate::SingleInstance;
 use crate::Sort;
+use crate::UsageStatistics;
 use crate::palette;
 use crate::panic_hook;
 use crate::strings;
@@ -39,19 +52,33 @@ pub struct AppState {
     pub(crate) file_filters: Vec<(&'static str, Vec<&'static str>)>,
     pub(crate) last_load: Option<Vec<PathBuf>>,
     pub(crate) files_dropped: Vec<PathBuf>,
+    pub(crate) single_instance: Option<SingleInstance>,
     pub(crate) controller: Controller,
     pub(crate) loading: bool,
     pub(crate) exporting: bool,
     pub(crate) progress: u32,
     pub(crate) export_canceled: bool,
+    pub(crate) export_conflicts: ExportConflicts,
+    pub(crate) export_mode: ExportMode,
+    pub(crate) export_failures: ExportFailures,
+    pub(crate) export_queue: ExportQueue,
+    pub(crate) export_manifest: Option<ExportManifest>,
+    pub(crate) export_started: Option<(Instant, ExportStatistics)>,
+    pub(crate) usage_statistics: UsageStatistics,
     pub(crate) reload_required: bool,
     pub(crate) modifier_keys: Modifiers,
     pub(crate) settings: Settings,
     pub(crate) item_range: Range<usize>,
+    pub(crate) assets_fetching: BTreeSet<usize>,
     pub(crate) asset_manager: Arc<dyn AssetManager + 'static>,
     pub(crate) asset_columns: Vec<AssetColumn>,
     pub(crate) asset_preview_id: Option<u64>,
+    pub(crate) asset_preview_merge: Option<AssetPreviewMerge>,
+    pub(crate) preview_visibility: Option<(usize, Vec<bool>)>,
+    pub(crate) preview_frame: Option<(usize, usize)>,
+    pub(crate) asset_variant: Option<String>,
     pub(crate) assets_selected: BTreeSet<usize>,
+    pub(crate) assets_anchor: Option<usize>,
 }
 
 impl AppState {
@@ -64,19 +91,33 @@ impl AppState {
             file_filters: Vec::new(),
             last_load: None,
             files_dropped: Vec::new(),
+            single_instance: None,
             controller: Controller::new(),
             loading: false,
             exporting: false,
             progress: 0,
             export_canceled: false,
+            export_conflicts: ExportConflicts::default(),
+            export_mode: ExportMode::Separate,
+            export_failures: ExportFailures::default(),
+            export_queue: ExportQueue::default(),
+            export_manifest: None,
+            export_started: None,
+            usage_statistics: UsageStatistics::default(),
             reload_required: false,
             modifier_keys: Modifiers::empty(),
             settings: Settings::default(),
             item_range: 0..0,
+            assets_fetching: BTreeSet::new(),
             asset_manager: Arc::new(asset_manager),
             asset_columns: Vec::new(),
             asset_preview_id: None,
+            asset_preview_merge: None,
+            preview_visibility: None,
+            preview_frame: None,
+            asset_variant: None,
             assets_selected: BTreeSet::new(),
+            assets_anchor: None,
         }
     }
 
@@ -129,6 +170,29 @@ impl AppState {
             .column("Info", 250, Some(palette::TEXT_COLOR_SECONDARY), None)
     }
 
+    /// Adds the audio metadata columns (duration, sample rate, channels) to the asset virtual list.
+    pub fn audio_columns(self) -> Self {
+        self.column("Duration", 100, None, Some(Sort::None))
+            .column("Sample Rate", 110, None, Some(Sort::None))
+            .column("Channels", 90, None, Some(Sort::None))
+    }
+
+    /// Adds the image metadata columns (resolution, format, mipmaps) to the asset virtual list.
+    pub fn image_columns(self) -> Self {
+        self.column("Resolution", 110, None, Some(Sort::None))
+            .column("Format", 170, None, Some(Sort::None))
+            .column("Mips", 60, None, Some(Sort::None))
+    }
+
+    /// Adds the model metadata columns (bones, vertices, faces, lods, materials) to the asset virtual list.
+    pub fn model_columns(self) -> Self {
+        self.column("Bones", 70, None, Some(Sort::None))
+            .column("Vertices", 90, None, Some(Sort::None))
+            .column("Faces", 90, None, Some(Sort::None))
+            .column("LODs", 60, None, Some(Sort::None))
+            .column("Materials", 90, None, Some(Sort::None))
+    }
+
     /// Adds a file filter to the load files dialog.
     pub fn file_filter(mut self, title: &'static str, extensions: Vec<&'static str>) -> Self {
         self.file_filters.push((title, extensions));
@@ -136,13 +200,49 @@ impl AppState {
     }
 
     /// Runs the app until the main window is closed.
+    ///
+    /// When launched with `--headless`, assets are exported from the command line instead, see [`HeadlessOptions::parse`].
     pub fn run(mut self) {
         // Install global panic hook, as early as possible.
         panic_hook::install(self.name, self.version);
 
+        match HeadlessOptions::from_env() {
+            Ok(None) => (),
+            Ok(Some(options)) => {
+                if let Err(e) = self.run_headless(options) {
+                    console!(header = "Error", { color = porter_console::Color::Red, "{}", e });
+
+                    std::process::exit(1);
+                }
+
+                return;
+            }
+            Err(e) => {
+                console!(header = "Error", { color = porter_console::Color::Red, "{}", e });
+
+                std::process::exit(2);
+            }
+        }
+
+        // Files passed on launch, such as from a file association, are loaded like dropped files.
+        self.files_dropped = startup_files();
+
+        // When another instance is already running, it loads the files instead.
+        match SingleInstance::acquire(self.name, &self.files_dropped) {
+            Ok(single_instance) => self.single_instance = single_instance,
+            Err(_) => return,
+        }
+
         // Load user settings if possible.
         self.settings = Settings::load(self.name);
 
+        if self.settings.usage_statistics() {
+            self.usage_statistics = UsageStatistics::load(self.name);
+        }
+
+        // The adapter must be chosen before the GPU is first used.
+        porter_gpu::set_gpu_adapter_preference(self.settings.gpu_adapter());
+
         // Initialize global rayon thread pool.
         initialize_thread_pool();
 
@@ -177,6 +277,15 @@ impl AppState {
     }
 }
 
+/// Collects the files passed as arguments to the process.
+fn startup_files() -> Vec<PathBuf> {
+    std::env::args_os()
+        .skip(1)
+        .map(PathBuf::from)
+        .filter(|path| path.is_file())
+        .collect()
+}
+
 /// Handles errors that occur during initialization.
 fn handle_error(name: &'static str, original: Error) {
     let Error::GraphicsCreationFailed(original) = original else {
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use porter_utils::AsHexString;
+
+use crate::Sort;
+use crate::system;
+
+/// A typed value of a column in the asset list, formatted for display by the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetColumnValue {
+    /// Text shown as is.
+    Text(String),
+    /// A size in bytes, shown in human readable units.
+    Size(u64),
+    /// An offset into a file or memory, shown in hex.
+    Offset(u64),
+    /// A count of something, shown with digit grouping.
+    Count(u64),
+}
+
+impl AssetColumnValue {
+    /// The numeric value, if this isn't text.
+    pub const fn number(&self) -> Option<u64> {
+        match self {
+            Self::Text(_) => None,
+            Self::Size(value) | Self::Offset(value) | Self::Count(value) => Some(*value),
+        }
+    }
+
+    /// Compares two values for sorting, numbers sort by value and before text, text sorts ignoring case.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self.number(), other.number()) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => {
+                let (Self::Text(left), Self::Text(right)) = (self, other) else {
+                    return Ordering::Equal;
+                };
+
+                left.to_lowercase()
+                    .cmp(&right.to_lowercase())
+                    .then_with(|| left.cmp(right))
+            }
+        }
+    }
+}
+
+impl fmt::Display for AssetColumnValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (decimal, grouping) = system::number_separators();
+
+        match self {
+            Self::Text(value) => write!(f, "{}", value),
+            Self::Size(value) => {
+                const UNITS: [&str; 6] = ["B", "KB", "MB", "GB", "TB", "PB"];
+
+                let unit = (value.max(&1).ilog2() / 10).min(UNITS.len() as u32 - 1);
+
+                if unit == 0 {
+                    return write!(f, "{} {}", value, UNITS[0]);
+                }
+
+                let size = *value as f64 / (1u64 << (unit * 10)) as f64;
+
+                write!(
+                    f,
+                    "{} {}",
+                    format!("{:.2}", size).replace('.', &decimal.to_string()),
+                    UNITS[unit as usize]
+                )
+            }
+            Self::Offset(value) => write!(f, "{}", value.as_hex_string_width(8)),
+            Self::Count(value) => {
+                let digits = value.to_string();
+
+                for (index, digit) in digits.chars().enumerate() {
+                    if index > 0 && (digits.len() - index) % 3 == 0 {
+                        write!(f, "{}", grouping)?;
+                    }
+
+                    write!(f, "{}", digit)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<String> for AssetColumnValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for AssetColumnValue {
+    fn from(value: &str) -> Self {
+        Self::Text(String::from(value))
+    }
+}
+
+/// Sorts assets by the typed value of a column in the given direction, so that numbers sort by value instead of as text.
+pub fn sort_by_column_value<F>(assets: &mut [usize], sort: Sort, value: F)
+where
+    F: Fn(usize) -> AssetColumnValue,
+{
+    if matches!(sort, Sort::None) {
+        return;
+    }
+
+    let mut keyed: Vec<(AssetColumnValue, usize)> =
+        assets.iter().map(|asset| (value(*asset), *asset)).collect();
+
+    keyed.sort_by(|(left, _), (right, _)| match sort {
+        Sort::Descending => right.compare(left),
+        _ => left.compare(right),
+    });
+
+    for (asset, (_, sorted)) in assets.iter_mut().zip(keyed) {
+        *asset = sorted;
+    }
+}
@@ -2,8 +2,13 @@ use std::path::PathBuf;
 
 use iced::Color;
 
+use porter_utils::NameDatabase;
+use porter_utils::NameHasher;
+
+use crate::AssetColumnValue;
 use crate::ColumnStatus;
 use crate::Controller;
+use crate::ExportAssetType;
 use crate::Icon;
 use crate::SearchTerm;
 use crate::Settings;
@@ -23,6 +28,17 @@ pub trait AssetManager: Send + Sync + 'static {
     /// Gets information about the specific asset, in the form of column data.
     fn assets_info(&self, index: usize) -> Vec<(String, Option<Color>)>;
 
+    /// Gets information about the specific asset, in the form of typed column data that the app formats for display.
+    ///
+    /// Override this to show sizes, offsets, and counts in the user's locale, `assets_info` should then return the
+    /// same values formatted with [`ToString`], as it's used for searching, copying, and row colors.
+    fn assets_column_values(&self, index: usize) -> Vec<(AssetColumnValue, Option<Color>)> {
+        self.assets_info(index)
+            .into_iter()
+            .map(|(value, color)| (AssetColumnValue::Text(value), color))
+            .collect()
+    }
+
     /// The number of visible assets, whether they are search results, or just loaded.
     fn assets_visible(&self) -> usize;
 
@@ -34,10 +50,51 @@ pub trait AssetManager: Send + Sync + 'static {
         self.assets_visible() == 0
     }
 
+    /// The number of assets in a page, for games with asset tables too large to list upfront.
+    ///
+    /// When this returns a size, `assets_visible` should return the full number of assets and `assets_info` may return
+    /// placeholder rows, pages are fetched with `assets_page_fetch` as their rows are scrolled into view. Pages index
+    /// the visible assets, so they follow the current search and sort.
+    fn assets_page_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Whether or not the given page of assets has been fetched.
+    fn assets_page_fetched(&self, page: usize) -> bool {
+        let _ = page;
+
+        true
+    }
+
+    /// Fetches the given page of assets, this is called from a background thread and the asset list is refreshed once it returns.
+    fn assets_page_fetch(&self, settings: Settings, page: usize) -> Result<(), String> {
+        let _ = settings;
+        let _ = page;
+
+        Ok(())
+    }
+
     /// Search for assets, or reset the search term.
+    ///
+    /// Match assets with [`SearchTerm::matches_asset`] so column filters such as `status:loaded` are matched against
+    /// `asset_search_metadata`, or with [`SearchTerm::matches`] giving each asset its column data with
+    /// [`crate::SearchAsset::columns`] when [`SearchTerm::filters_columns`] is true.
     fn search(&self, term: Option<SearchTerm>);
 
+    /// Gets the searchable value of the given column for the specific asset, used to match column filters such as `status:loaded`.
+    ///
+    /// Defaults to the column data from `assets_info`, override this to search values which differ from what is displayed,
+    /// or to avoid formatting every column of every asset. This is only called for columns used by a filter.
+    fn asset_search_metadata(&self, index: usize, column: usize) -> Option<String> {
+        self.assets_info(index)
+            .into_iter()
+            .nth(column)
+            .map(|(value, _)| value)
+    }
+
     /// Sort assets based on column status, returns the new column sort statuses.
+    ///
+    /// Use [`crate::sort_by_column_value`] to sort sizes, offsets, and counts by value.
     fn sort(&self, column: Option<usize>, statuses: Vec<ColumnStatus>) -> Vec<ColumnStatus> {
         let _ = column;
         let _ = statuses;
@@ -60,13 +117,94 @@ pub trait AssetManager: Send + Sync + 'static {
         None
     }
 
+    /// Optional name of the settings profile to use for the loaded game.
+    ///
+    /// This profile is refreshed whenever a call to `load_files` or `load_game` finishes,
+    /// and allows users to keep different export settings for each game.
+    fn settings_profile(&self) -> Option<String> {
+        None
+    }
+
+    /// Called when the user provided name databases have been loaded, or reloaded.
+    ///
+    /// Names should be resolved from the database without reloading assets, the asset list is
+    /// refreshed once this call returns so that hash and name columns reflect the new database.
+    fn names_update(&self, database: NameDatabase) {
+        let _ = database;
+    }
+
+    /// Gets the name hash of the specific asset, and the hasher used to create it, to allow guessing unresolved names.
+    fn asset_name_hash(&self, index: usize) -> Option<(u64, NameHasher)> {
+        let _ = index;
+
+        None
+    }
+
+    /// Gets the language variants of the specific asset, when variants are grouped into a single row.
+    ///
+    /// The selected variant is given to `preview` and `export` through [`Settings::asset_variant`].
+    fn asset_variants(&self, index: usize) -> Vec<String> {
+        let _ = index;
+
+        Vec::new()
+    }
+
+    /// Gets the type and file name, without an extension, that the specific asset is exported with.
+    ///
+    /// The name may include folders, relative to the output directory for the asset type. It's used to show the
+    /// output paths of the selected asset without exporting it.
+    fn asset_export_name(&self, index: usize) -> Option<(ExportAssetType, String)> {
+        let _ = index;
+
+        None
+    }
+
+    /// Whether or not the given sound assets can be exported together as a single file.
+    fn assets_combinable(&self, assets: &[usize]) -> bool {
+        let _ = assets;
+
+        false
+    }
+
+    /// Whether or not the given model assets can be previewed and exported together as a single merged model.
+    fn assets_mergeable(&self, assets: &[usize]) -> bool {
+        let _ = assets;
+
+        false
+    }
+
+    /// Whether or not the given animation assets can be exported, in order, as a single animation timeline.
+    fn assets_concatenable(&self, assets: &[usize]) -> bool {
+        let _ = assets;
+
+        false
+    }
+
+    /// Gets the type and stored size in bytes of the specific asset, used to estimate the size of an export.
+    ///
+    /// This is called from a background thread, and the estimate is hidden if no asset has a known size.
+    fn asset_size(&self, index: usize) -> Option<(ExportAssetType, u64)> {
+        let _ = index;
+
+        None
+    }
+
     /// Request one or more assets be exported.
+    ///
+    /// Each asset is a job in the export queue, progress per asset is shown in the status column by calling
+    /// [`Settings::export_job_started`], [`Settings::export_job_finished`], and [`Settings::export_job_failed`].
+    /// Use [`crate::export_parallel`] to export assets on the export worker threads with progress and cancel handled.
+    /// Report the files written for each asset with [`Settings::record_exported_files`] to list them in the export manifest.
+    /// Save images with [`Settings::save_image`] and animations with [`Settings::save_animation`] so the export settings are applied,
+    /// and save models with the options from [`Settings::model_save_options`].
     fn export(&self, settings: Settings, assets: Vec<usize>, controller: Controller);
 
     /// Cancels an active export.
     fn export_cancel(&self);
 
     /// Request the given assets data for preview, optionally forcing a raw file preview.
+    ///
+    /// Send the result with [`Controller::preview_update`], giving an error report when the asset fails to load.
     fn preview(
         &self,
         settings: Settings,
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use iced::Color;
+
+use porter_audio::AudioInfo;
+
+use porter_model::ModelInfo;
+
+use porter_texture::ImageInfo;
+
+use crate::SearchAsset;
+
+/// Metadata about an asset that is displayed in the asset columns, and used to filter searches.
+pub trait AssetMetadata {
+    /// Formats the metadata as column data, in the same order as the matching columns.
+    fn columns(&self) -> Vec<(String, Option<Color>)>;
+
+    /// Adds the metadata to the searchable data for an asset.
+    fn search(&self, asset: SearchAsset) -> SearchAsset;
+}
+
+/// A thread safe cache of asset metadata, so that metadata is computed lazily, once per asset.
+#[derive(Debug, Default)]
+pub struct AssetMetadataCache<T> {
+    inner: RwLock<HashMap<u64, Option<T>>>,
+}
+
+impl<T: Clone> AssetMetadataCache<T> {
+    /// Constructs a new empty metadata cache.
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Gets the cached metadata for the given key, or computes and caches it, including failures.
+    pub fn get_or_insert_with<F: FnOnce() -> Option<T>>(&self, key: u64, metadata: F) -> Option<T> {
+        if let Ok(inner) = self.inner.read()
+            && let Some(cached) = inner.get(&key)
+        {
+            return cached.clone();
+        }
+
+        let metadata = metadata();
+
+        if let Ok(mut inner) = self.inner.write() {
+            inner.insert(key, metadata.clone());
+        }
+
+        metadata
+    }
+
+    /// Removes the cached metadata for the given key.
+    pub fn invalidate(&self, key: u64) {
+        if let Ok(mut inner) = self.inner.write() {
+            inner.remove(&key);
+        }
+    }
+
+    /// Removes all of the cached metadata, such as when assets are reloaded.
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.write() {
+            inner.clear();
+        }
+    }
+}
+
+impl AssetMetadata for AudioInfo {
+    fn columns(&self) -> Vec<(String, Option<Color>)> {
+        let duration = match self.duration() {
+            Some(duration) => format!(
+                "{}:{:02}.{:03}",
+                duration.as_secs() / 60,
+                duration.as_secs() % 60,
+                duration.subsec_millis()
+            ),
+            None => String::from("N/A"),
+        };
+
+        vec![
+            (duration, None),
+            (format!("{} Hz", self.sample_rate), None),
+            (self.channels.to_string(), None),
+        ]
+    }
+
+    fn search(&self, asset: SearchAsset) -> SearchAsset {
+        asset
+            .channels(self.channels)
+            .sample_rate(self.sample_rate)
+            .duration(
+                self.duration()
+                    .map(|duration| duration.as_secs() as u32)
+                    .unwrap_or_default(),
+            )
+    }
+}
+
+impl AssetMetadata for ImageInfo {
+    fn columns(&self) -> Vec<(String, Option<Color>)> {
+        vec![
+            (format!("{}x{}", self.width, self.height), None),
+            (format!("{:?}", self.format), None),
+            (self.mipmaps.to_string(), None),
+        ]
+    }
+
+    fn search(&self, asset: SearchAsset) -> SearchAsset {
+        asset
+            .width(self.width)
+            .height(self.height)
+            .mipmaps(self.mipmaps)
+    }
+}
+
+impl AssetMetadata for ModelInfo {
+    fn columns(&self) -> Vec<(String, Option<Color>)> {
+        vec![
+            (self.bones.to_string(), None),
+            (self.vertices.to_string(), None),
+            (self.faces.to_string(), None),
+            (self.lods.to_string(), None),
+            (self.materials.to_string(), None),
+        ]
+    }
+
+    fn search(&self, asset: SearchAsset) -> SearchAsset {
+        asset
+            .bone_count(self.bones)
+            .mesh_count(self.meshes)
+            .vertex_count(self.vertices)
+            .face_count(self.faces)
+            .lod_count(self.lods)
+            .material_count(self.materials)
+    }
+}
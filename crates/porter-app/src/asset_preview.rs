@@ -1,3 +1,5 @@
+use porter_animation::Animation;
+
 use porter_model::MaterialTextureRefUsage;
 use porter_model::Model;
 
@@ -5,21 +7,23 @@ use porter_texture::Image;
 
 use porter_audio::Audio;
 
+use crate::AudioTranscript;
+
 /// The result of an assets data to be previewed.
 #[derive(Debug, Clone)]
 pub enum AssetPreview {
     /// This asset type doesn't support preview.
     NotSupported,
-    /// An error occured while previewing this asset.
-    PreviewError,
     /// A raw file asset for preview.
     RawFile(String, Vec<u8>),
     /// An image asset for preview.
     Image(String, Image),
     /// A model asset for preview.
     Model(String, Model, Vec<Option<Image>>),
+    /// An animation asset for preview, played on the model being previewed.
+    Animation(String, Animation),
     /// A material asset for preview.
-    Material(String, Vec<(MaterialTextureRefUsage, Image)>),
-    /// A audio asset for preview.
-    Audio(String, Audio),
+    Material(String, Vec<(String, MaterialTextureRefUsage, Image)>),
+    /// A audio asset for preview, with an optional transcript.
+    Audio(String, Audio, Option<AudioTranscript>),
 }
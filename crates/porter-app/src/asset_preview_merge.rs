@@ -0,0 +1,86 @@
+use std::ops::Range;
+
+use porter_model::Model;
+
+use porter_utils::ErrorReport;
+
+use crate::AssetPreview;
+
+/// Collects the previews of several selected model assets, and merges them into a single preview.
+#[derive(Debug)]
+pub struct AssetPreviewMerge {
+    request_ids: Range<u64>,
+    previews: Vec<Option<Result<AssetPreview, ErrorReport>>>,
+}
+
+impl AssetPreviewMerge {
+    /// Constructs a new merge for the previews of the given request ids, one per asset, in order.
+    pub fn new(request_ids: Range<u64>) -> Self {
+        Self {
+            previews: vec![None; (request_ids.end - request_ids.start) as usize],
+            request_ids,
+        }
+    }
+
+    /// Returns the number of previews that have arrived, and the total number of previews.
+    pub fn progress(&self) -> (usize, usize) {
+        let received = self
+            .previews
+            .iter()
+            .filter(|preview| preview.is_some())
+            .count();
+
+        (received, self.previews.len())
+    }
+
+    /// Stores the preview for the given request id, returning the merged preview once every preview has arrived.
+    pub fn insert(
+        &mut self,
+        request_id: u64,
+        asset: Result<AssetPreview, ErrorReport>,
+    ) -> Option<Result<AssetPreview, ErrorReport>> {
+        let index = request_id.checked_sub(self.request_ids.start)? as usize;
+
+        *self.previews.get_mut(index)? = Some(asset);
+
+        if self.previews.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(merge_previews(self.previews.drain(..).flatten()))
+    }
+}
+
+/// Merges every model preview, in order, falling back to the first preview or error when there are no models.
+fn merge_previews<I: Iterator<Item = Result<AssetPreview, ErrorReport>>>(
+    previews: I,
+) -> Result<AssetPreview, ErrorReport> {
+    let mut names: Vec<String> = Vec::new();
+    let mut merged: Option<(Model, Vec<_>)> = None;
+    let mut first: Option<Result<AssetPreview, ErrorReport>> = None;
+
+    for preview in previews {
+        let Ok(AssetPreview::Model(name, model, images)) = preview else {
+            first.get_or_insert(preview);
+            continue;
+        };
+
+        names.push(name);
+
+        match &mut merged {
+            Some((merged, merged_images)) => {
+                // Images match the materials, which are appended in the same order.
+                merged_images.resize(merged.materials.len(), None);
+                merged_images.extend(images);
+
+                merged.merge(model);
+            }
+            None => merged = Some((model, images)),
+        }
+    }
+
+    match merged {
+        Some((model, images)) => Ok(AssetPreview::Model(names.join(" + "), model, images)),
+        None => first.unwrap_or(Ok(AssetPreview::NotSupported)),
+    }
+}
@@ -5,17 +5,36 @@ use std::time::Duration;
 use porter_audio::Audio;
 use porter_audio::AudioFormat;
 
+use porter_utils::ReportError;
+
 /// A cross platform audio player.
 pub struct AudioPlayer {
     state: State,
 }
 
 /// Errors that occur in the audio player.
+#[derive(Debug)]
 pub enum AudioPlayerError {
     Unsupported,
     Error,
 }
 
+impl ReportError for AudioPlayerError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::Unsupported => "PLY-001",
+            Self::Error => "PLY-002",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::Unsupported => String::from("This audio can't be played."),
+            Self::Error => String::from("The audio device failed to play this audio."),
+        }
+    }
+}
+
 impl AudioPlayer {
     /// Loads the given audio asset into a new audio player.
     ///
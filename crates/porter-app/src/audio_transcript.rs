@@ -0,0 +1,115 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use serde_json::json;
+
+use crate::Settings;
+
+/// Sidecar file types a transcript can be exported to.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFileType {
+    Txt,
+    Json,
+}
+
+impl TranscriptFileType {
+    /// The file extension used for this sidecar file type.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Txt => "txt",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// A transcript, and any extra metadata, attached to an audio asset such as a line of dialogue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AudioTranscript {
+    text: String,
+    metadata: Vec<(String, String)>,
+}
+
+impl AudioTranscript {
+    /// Constructs a new transcript with the given text.
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            metadata: Vec::new(),
+        }
+    }
+
+    /// Adds a metadata entry, such as the speaker or language of the line.
+    pub fn metadata<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// The transcript text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The metadata entries, in the order they were added.
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// Writes the transcript next to the exported audio file, replacing its extension with the sidecar extension.
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        audio_path: P,
+        file_type: TranscriptFileType,
+    ) -> Result<PathBuf, std::io::Error> {
+        let path = audio_path.as_ref().with_extension(file_type.extension());
+
+        let buffer = match file_type {
+            TranscriptFileType::Txt => {
+                let mut buffer = String::with_capacity(self.text.len());
+
+                for (key, value) in &self.metadata {
+                    buffer.push_str(&format!("{}: {}\n", key, value));
+                }
+
+                if !self.metadata.is_empty() {
+                    buffer.push('\n');
+                }
+
+                buffer.push_str(&self.text);
+                buffer.push('\n');
+                buffer.into_bytes()
+            }
+            TranscriptFileType::Json => {
+                let metadata: serde_json::Map<String, serde_json::Value> = self
+                    .metadata
+                    .iter()
+                    .map(|(key, value)| (key.clone(), json!(value)))
+                    .collect();
+
+                serde_json::to_vec_pretty(&json!({
+                    "text": self.text,
+                    "metadata": metadata,
+                }))?
+            }
+        };
+
+        std::fs::write(&path, buffer)?;
+
+        Ok(path)
+    }
+
+    /// Writes the transcript sidecars enabled in the settings next to the exported audio file.
+    pub fn save_sidecars<P: AsRef<Path>>(
+        &self,
+        settings: &Settings,
+        audio_path: P,
+    ) -> Result<Vec<PathBuf>, std::io::Error> {
+        settings
+            .transcript_file_types()
+            .into_iter()
+            .map(|file_type| self.save(audio_path.as_ref(), file_type))
+            .collect()
+    }
+}
@@ -23,36 +23,81 @@ impl About {
 
     /// Handles rendering for the about component.
     pub fn view(&self, state: &AppState) -> Element<'_, Message> {
-        container(
-            column([
-                text(strings::PORTER_THANKS)
-                    .size(20.0)
-                    .color(palette::TEXT_COLOR_WARN)
-                    .into(),
-                vertical_space().height(20.0).into(),
-                text(strings::PORTER_BUG_REPORT)
-                    .size(18.0)
-                    .color(palette::TEXT_COLOR_SECONDARY)
-                    .into(),
-                vertical_space().height(20.0).into(),
-                text(strings::PORTER_DISCLAIMER)
-                    .color(palette::TEXT_COLOR_MUTED)
-                    .into(),
-                text(format!(
-                    "\"{}\" {}.",
-                    state.name.to_uppercase(),
-                    strings::PORTER_COPYRIGHT
-                ))
+        let mut about = column([
+            text(strings::PORTER_THANKS)
+                .size(20.0)
+                .color(palette::TEXT_COLOR_WARN)
+                .into(),
+            vertical_space().height(20.0).into(),
+            text(strings::PORTER_BUG_REPORT)
+                .size(18.0)
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(20.0).into(),
+            text(strings::PORTER_DISCLAIMER)
                 .color(palette::TEXT_COLOR_MUTED)
                 .into(),
-            ])
-            .spacing(8.0)
-            .align_x(Alignment::Center),
-        )
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .align_x(Alignment::Center)
-        .align_y(Alignment::Center)
-        .into()
+            text(format!(
+                "\"{}\" {}.",
+                state.name.to_uppercase(),
+                strings::PORTER_COPYRIGHT
+            ))
+            .color(palette::TEXT_COLOR_MUTED)
+            .into(),
+        ]);
+
+        if state.settings.usage_statistics() {
+            about = about.extend([
+                vertical_space().height(20.0).into(),
+                self.view_statistics(state),
+            ]);
+        }
+
+        container(about.spacing(8.0).align_x(Alignment::Center))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    /// Handles rendering the local usage statistics.
+    fn view_statistics(&self, state: &AppState) -> Element<'_, Message> {
+        let statistics = &state.usage_statistics;
+        let minutes = statistics.export_time().as_secs() / 60;
+
+        let mut result = column([text(format!(
+            "{} assets exported in {}h {}m",
+            statistics.total_exported(),
+            minutes / 60,
+            minutes % 60
+        ))
+        .size(18.0)
+        .color(palette::TEXT_COLOR_SECONDARY)
+        .into()]);
+
+        let summary = |counts: Vec<(&str, u64)>| {
+            counts
+                .into_iter()
+                .take(5)
+                .map(|(name, count)| format!("{name}: {count}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let types = summary(statistics.exported_by_type());
+
+        if !types.is_empty() {
+            result = result.push(text(types).color(palette::TEXT_COLOR_MUTED));
+        }
+
+        let games = summary(statistics.exported_by_game());
+
+        if !games.is_empty() {
+            result = result
+                .push(text(format!("Most exported: {games}")).color(palette::TEXT_COLOR_MUTED));
+        }
+
+        result.spacing(4.0).align_x(Alignment::Center).into()
     }
 }
@@ -1,20 +1,37 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+
 use iced::widget::container;
 use iced::widget::row;
 use iced::widget::stack;
 use iced::widget::text;
+use iced::widget::tooltip::Position;
 
 use iced::Alignment;
 use iced::Element;
 use iced::Length;
 use iced::Task;
 
+use porter_utils::AsHumanBytes;
+
 use crate::AppState;
+use crate::ExportAssetType;
 use crate::MainMessage;
 use crate::Message;
+use crate::palette;
 use crate::widgets;
 
+use super::GLOBAL_PROFILE;
+use super::NameGuessMessage;
+use super::SettingsMessage;
+
 /// Controls component handler.
-pub struct Controls;
+pub struct Controls {
+    estimate_selection: BTreeSet<usize>,
+    estimate_id: u64,
+    estimate: Option<HashMap<ExportAssetType, u64>>,
+}
 
 #[derive(Debug, Clone)]
 pub enum ControlsMessage {
@@ -22,17 +39,28 @@ pub enum ControlsMessage {
     LoadFile,
     ExportSelected,
     ExportAll,
+    ExportCombined,
+    ExportMerged,
+    ExportConcatenated,
     ExportCancel,
+    NameGuess,
+    SelectVariant(String),
+    SelectProfile(String),
+    EstimateUpdate(u64, Option<HashMap<ExportAssetType, u64>>),
 }
 
 impl Controls {
     /// Creates a new controls component.
     pub fn new() -> Self {
-        Self
+        Self {
+            estimate_selection: BTreeSet::new(),
+            estimate_id: 0,
+            estimate: None,
+        }
     }
 
     /// Handles updates for the controls component.
-    pub fn update(&mut self, _state: &mut AppState, message: ControlsMessage) -> Task<Message> {
+    pub fn update(&mut self, state: &mut AppState, message: ControlsMessage) -> Task<Message> {
         use ControlsMessage::*;
 
         match message {
@@ -40,8 +68,68 @@ impl Controls {
             LoadFile => Task::done(Message::from(MainMessage::LoadFile)),
             ExportSelected => Task::done(Message::ExportSelected),
             ExportAll => Task::done(Message::ExportAll),
+            ExportCombined => Task::done(Message::ExportCombined),
+            ExportMerged => Task::done(Message::ExportMerged),
+            ExportConcatenated => Task::done(Message::ExportConcatenated),
             ExportCancel => Task::done(Message::ExportCancel),
+            NameGuess => Task::done(Message::from(NameGuessMessage::Toggle)),
+            SelectVariant(variant) => {
+                state.asset_variant = Some(variant);
+
+                Task::done(Message::PreviewRequest)
+            }
+            SelectProfile(profile) => {
+                let profile = (profile != GLOBAL_PROFILE).then_some(profile);
+
+                Task::done(Message::from(SettingsMessage::Save(
+                    state
+                        .settings
+                        .update(|settings| settings.set_profile(profile)),
+                )))
+            }
+            EstimateUpdate(request_id, size) => {
+                if request_id == self.estimate_id {
+                    self.estimate = size;
+                }
+
+                Task::none()
+            }
+        }
+    }
+
+    /// Sizes the selected assets in the background whenever the selection changes, to estimate the export size.
+    pub fn estimate(&mut self, state: &AppState) -> Task<Message> {
+        if self.estimate_selection == state.assets_selected {
+            return Task::none();
+        }
+
+        self.estimate_selection = state.assets_selected.clone();
+        self.estimate_id = self.estimate_id.wrapping_add(1);
+        self.estimate = None;
+
+        if self.estimate_selection.is_empty() {
+            return Task::none();
         }
+
+        let request_id = self.estimate_id;
+        let assets: Vec<usize> = self.estimate_selection.iter().copied().collect();
+        let manager = state.asset_manager.clone();
+        let controller = state.controller.clone();
+
+        porter_threads::spawn(move || {
+            let mut sizes: HashMap<ExportAssetType, u64> = HashMap::new();
+
+            for (asset_type, size) in assets
+                .into_iter()
+                .filter_map(|index| manager.asset_size(index))
+            {
+                *sizes.entry(asset_type).or_default() += size;
+            }
+
+            controller.export_estimate_update(request_id, (!sizes.is_empty()).then_some(sizes));
+        });
+
+        Task::none()
     }
 
     /// Handles rendering for the controls component.
@@ -74,25 +162,140 @@ impl Controls {
             );
         }
 
-        row = row
-            .push(
-                widgets::button("Export Selected")
+        let formats = export_formats(state);
+
+        row = row.push(widgets::tooltip(
+            widgets::button("Export Selected")
+                .padding([6.0, 10.0])
+                .on_press_maybe(if state.assets_selected.is_empty() || state.is_busy() {
+                    None
+                } else {
+                    Some(Message::from(ControlsMessage::ExportSelected))
+                }),
+            text(formats.clone()),
+            Position::Bottom,
+        ));
+
+        if let Some(sizes) = &self.estimate
+            && !state.assets_selected.is_empty()
+        {
+            // Apply the file types at render time so that the estimate follows settings changes.
+            let estimate: u64 = sizes
+                .iter()
+                .map(|(asset_type, size)| state.settings.estimate_export_size(*asset_type, *size))
+                .sum();
+
+            row = row.push(
+                text(format!("~{}", estimate.as_human_bytes()))
+                    .color(palette::TEXT_COLOR_SECONDARY),
+            );
+        }
+
+        row = row.push(widgets::tooltip(
+            widgets::button("Export All")
+                .padding([6.0, 10.0])
+                .on_press_maybe(if state.asset_manager.assets_empty() || state.is_busy() {
+                    None
+                } else {
+                    Some(Message::from(ControlsMessage::ExportAll))
+                }),
+            text(formats),
+            Position::Bottom,
+        ));
+
+        let profiles = state.settings.profiles();
+
+        if !profiles.is_empty() {
+            let mut options = vec![String::from(GLOBAL_PROFILE)];
+
+            options.extend(profiles);
+
+            row = row.push(
+                widgets::pick_list(
+                    options,
+                    Some(
+                        state
+                            .settings
+                            .profile()
+                            .unwrap_or(GLOBAL_PROFILE)
+                            .to_string(),
+                    ),
+                    |profile| Message::from(ControlsMessage::SelectProfile(profile)),
+                )
+                .width(Length::Fixed(150.0)),
+            );
+        }
+
+        let assets: Vec<usize> = state.assets_selected.iter().copied().collect();
+
+        if assets.len() > 1 && state.asset_manager.assets_combinable(&assets) {
+            row = row.push(
+                widgets::button("Export as Single File")
+                    .padding([6.0, 10.0])
+                    .on_press_maybe(if state.is_busy() {
+                        None
+                    } else {
+                        Some(Message::from(ControlsMessage::ExportCombined))
+                    }),
+            );
+        }
+
+        if assets.len() > 1 && state.asset_manager.assets_mergeable(&assets) {
+            row = row.push(
+                widgets::button("Export Merged")
                     .padding([6.0, 10.0])
-                    .on_press_maybe(if state.assets_selected.is_empty() || state.is_busy() {
+                    .on_press_maybe(if state.is_busy() {
                         None
                     } else {
-                        Some(Message::from(ControlsMessage::ExportSelected))
+                        Some(Message::from(ControlsMessage::ExportMerged))
                     }),
-            )
-            .push(
-                widgets::button("Export All")
+            );
+        }
+
+        if assets.len() > 1 && state.asset_manager.assets_concatenable(&assets) {
+            row = row.push(
+                widgets::button("Export Merged Animation")
                     .padding([6.0, 10.0])
-                    .on_press_maybe(if state.asset_manager.assets_empty() || state.is_busy() {
+                    .on_press_maybe(if state.is_busy() {
                         None
                     } else {
-                        Some(Message::from(ControlsMessage::ExportAll))
+                        Some(Message::from(ControlsMessage::ExportConcatenated))
                     }),
             );
+        }
+
+        let variants = state
+            .assets_selected
+            .first()
+            .map(|index| state.asset_manager.asset_variants(*index))
+            .unwrap_or_default();
+
+        if !variants.is_empty() {
+            let selected = state
+                .asset_variant
+                .clone()
+                .filter(|variant| variants.contains(variant));
+
+            row = row.push(
+                widgets::pick_list(variants, selected, |variant| {
+                    Message::from(ControlsMessage::SelectVariant(variant))
+                })
+                .placeholder("Language")
+                .width(Length::Fixed(150.0)),
+            );
+        }
+
+        if state
+            .assets_selected
+            .first()
+            .is_some_and(|index| state.asset_manager.asset_name_hash(*index).is_some())
+        {
+            row = row.push(
+                widgets::button("Guess Name")
+                    .padding([6.0, 10.0])
+                    .on_press(Message::from(ControlsMessage::NameGuess)),
+            );
+        }
 
         if state.exporting {
             row = row
@@ -136,3 +339,30 @@ impl Controls {
             .into()
     }
 }
+
+/// Describes the file types each asset type is exported to with the active profile.
+fn export_formats(state: &AppState) -> String {
+    let settings = &state.settings;
+
+    format!(
+        "Profile: {}\nModels: {}\nAnimations: {}\nImages: {}\nAudio: {}",
+        settings.profile().unwrap_or(GLOBAL_PROFILE),
+        join_file_types(&settings.model_file_types()),
+        join_file_types(&settings.anim_file_types()),
+        join_file_types(&[settings.image_file_type()]),
+        join_file_types(&settings.audio_file_types()),
+    )
+}
+
+/// Joins the extensions of the given file types, or none if empty.
+fn join_file_types<T: AsRef<OsStr>>(file_types: &[T]) -> String {
+    if file_types.is_empty() {
+        return String::from("None");
+    }
+
+    file_types
+        .iter()
+        .map(|file_type| file_type.as_ref().to_string_lossy().to_uppercase())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -5,6 +5,7 @@ use iced::widget::image::Handle;
 use iced::widget::row;
 use iced::widget::stack;
 use iced::widget::text;
+use iced::widget::tooltip::Position;
 
 use iced::Alignment;
 use iced::Background;
@@ -104,9 +105,31 @@ impl Header {
             .align_x(Alignment::Center)
             .align_y(Alignment::Center)
             .into(),
-            widgets::switch_button([about_button, settings_button]).into(),
         ]);
 
+        let missing = state.settings.missing_export_formats();
+
+        if !missing.is_empty() {
+            row = row.push(widgets::tooltip(
+                text("!")
+                    .size(18.0)
+                    .color(palette::TEXT_COLOR_WARN)
+                    .width(16.0)
+                    .align_x(Alignment::Center),
+                text(format!(
+                    "No export formats are selected for: {}",
+                    missing
+                        .iter()
+                        .map(|asset_type| asset_type.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+                Position::Bottom,
+            ));
+        }
+
+        row = row.push(widgets::switch_button([about_button, settings_button]));
+
         if let Some(handle) = &self.icon {
             row = row.extend([
                 horizontal_space().width(4.0).into(),
@@ -2,6 +2,7 @@ mod about;
 mod content;
 mod controls;
 mod header;
+mod name_guess;
 mod preview;
 mod search_bar;
 mod settings;
@@ -12,6 +13,7 @@ pub use about::*;
 pub use content::*;
 pub use controls::*;
 pub use header::*;
+pub use name_guess::*;
 pub use preview::*;
 pub use search_bar::*;
 pub use settings::*;
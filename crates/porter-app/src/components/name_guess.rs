@@ -0,0 +1,258 @@
+use iced::widget::container;
+use iced::widget::row;
+use iced::widget::text;
+
+use iced::Alignment;
+use iced::Element;
+use iced::Length;
+use iced::Task;
+
+use porter_utils::AtomicCancel;
+use porter_utils::NameGuesser;
+use porter_utils::NameHasher;
+
+use crate::AppState;
+use crate::Message;
+use crate::palette;
+use crate::widgets;
+
+/// The maximum number of words joined together when expanding a wordlist.
+const WORDLIST_DEPTH: usize = 3;
+
+/// The maximum length of a brute forced name.
+const BRUTE_FORCE_LENGTH: usize = 6;
+
+/// Name guess component handler.
+pub struct NameGuess {
+    /// Whether or not to show the name guess panel.
+    pub show: bool,
+    mode: NameGuessMode,
+    input: String,
+    result: Option<NameGuessResult>,
+    running: bool,
+    cancel: AtomicCancel,
+}
+
+/// The method used to guess a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameGuessMode {
+    Wordlist,
+    BruteForce,
+}
+
+/// The outcome of a finished guess.
+#[derive(Debug, Clone)]
+enum NameGuessResult {
+    Found(String),
+    NotFound,
+    Cancelled,
+}
+
+/// Messages produced by the name guess component.
+#[derive(Debug, Clone)]
+pub enum NameGuessMessage {
+    Toggle,
+    Mode(NameGuessMode),
+    Input(String),
+    Start,
+    Cancel,
+    Result(Option<String>),
+}
+
+impl NameGuess {
+    /// Creates a new name guess component.
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            mode: NameGuessMode::Wordlist,
+            input: String::new(),
+            result: None,
+            running: false,
+            cancel: AtomicCancel::new(),
+        }
+    }
+
+    /// Handles updates for the name guess component.
+    pub fn update(&mut self, state: &mut AppState, message: NameGuessMessage) -> Task<Message> {
+        use NameGuessMessage::*;
+
+        match message {
+            Toggle => self.on_toggle(),
+            Mode(mode) => self.on_mode(mode),
+            Input(input) => self.on_input(input),
+            Start => self.on_start(state),
+            Cancel => self.on_cancel(),
+            Result(result) => self.on_result(result),
+        }
+    }
+
+    /// Handles rendering the name guess component.
+    pub fn view(&self, state: &AppState) -> Element<'_, Message> {
+        let hash = selected_name_hash(state).map(|(hash, _)| hash);
+
+        let result = match &self.result {
+            _ if self.running => String::from("Guessing..."),
+            Some(NameGuessResult::Found(name)) => name.clone(),
+            Some(NameGuessResult::NotFound) => String::from("No match found"),
+            Some(NameGuessResult::Cancelled) => String::from("Cancelled"),
+            None => String::new(),
+        };
+
+        container(
+            row([
+                text(match hash {
+                    Some(hash) => format!("Hash: {hash:#x}"),
+                    None => String::from("Select a single hashed asset"),
+                })
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+                widgets::pick_list(
+                    vec!["Wordlist", "Brute Force"],
+                    Some(match self.mode {
+                        NameGuessMode::Wordlist => "Wordlist",
+                        NameGuessMode::BruteForce => "Brute Force",
+                    }),
+                    |mode| {
+                        Message::from(NameGuessMessage::Mode(match mode {
+                            "Brute Force" => NameGuessMode::BruteForce,
+                            _ => NameGuessMode::Wordlist,
+                        }))
+                    },
+                )
+                .into(),
+                widgets::text_input(
+                    match self.mode {
+                        NameGuessMode::Wordlist => "Words separated by spaces...",
+                        NameGuessMode::BruteForce => "Optional prefix...",
+                    },
+                    &self.input,
+                )
+                .on_input_maybe(if self.running {
+                    None
+                } else {
+                    Some(|input| Message::from(NameGuessMessage::Input(input)))
+                })
+                .width(Length::Fixed(300.0))
+                .into(),
+                if self.running {
+                    widgets::button("Cancel")
+                        .on_press(Message::from(NameGuessMessage::Cancel))
+                        .into()
+                } else {
+                    widgets::button("Guess")
+                        .on_press_maybe(hash.map(|_| Message::from(NameGuessMessage::Start)))
+                        .into()
+                },
+                widgets::text_input("", &result)
+                    .on_input(|_| Message::Noop)
+                    .width(Length::Fill)
+                    .into(),
+            ])
+            .align_y(Alignment::Center)
+            .spacing(4.0),
+        )
+        .width(Length::Fill)
+        .height(Length::Shrink)
+        .padding([0.0, 8.0])
+        .into()
+    }
+
+    /// Toggles the name guess panel.
+    fn on_toggle(&mut self) -> Task<Message> {
+        self.show = !self.show;
+
+        if !self.show {
+            self.cancel.cancel();
+        }
+
+        Task::none()
+    }
+
+    /// Changes the guess mode.
+    fn on_mode(&mut self, mode: NameGuessMode) -> Task<Message> {
+        self.mode = mode;
+
+        Task::none()
+    }
+
+    /// Modifies the guess input.
+    fn on_input(&mut self, input: String) -> Task<Message> {
+        self.input = input;
+
+        Task::none()
+    }
+
+    /// Starts guessing the name of the selected asset.
+    fn on_start(&mut self, state: &mut AppState) -> Task<Message> {
+        if self.running {
+            return Task::none();
+        }
+
+        let Some((hash, hasher)) = selected_name_hash(state) else {
+            return Task::none();
+        };
+
+        let controller = state.controller.clone();
+        let mode = self.mode;
+        let input = self.input.clone();
+
+        self.running = true;
+        self.result = None;
+        self.cancel = AtomicCancel::new();
+
+        let cancel = self.cancel.clone();
+
+        porter_threads::spawn(move || {
+            let guesser = NameGuesser::new(hasher);
+
+            let result = match mode {
+                NameGuessMode::Wordlist => {
+                    let words: Vec<_> = input
+                        .split(|x: char| x.is_whitespace() || x == ',')
+                        .filter(|word| !word.is_empty())
+                        .collect();
+
+                    guesser.guess_wordlist(hash, &words, WORDLIST_DEPTH, &cancel)
+                }
+                NameGuessMode::BruteForce => guesser
+                    .prefix(input.trim())
+                    .max_length(BRUTE_FORCE_LENGTH)
+                    .guess_brute_force(hash, &cancel),
+            };
+
+            controller.name_guess_update(result);
+        });
+
+        Task::none()
+    }
+
+    /// Cancels the active guess.
+    fn on_cancel(&mut self) -> Task<Message> {
+        self.cancel.cancel();
+
+        Task::none()
+    }
+
+    /// Occurs when a guess has finished.
+    fn on_result(&mut self, result: Option<String>) -> Task<Message> {
+        self.running = false;
+        self.result = Some(match result {
+            Some(name) => NameGuessResult::Found(name),
+            None if self.cancel.is_cancelled() => NameGuessResult::Cancelled,
+            None => NameGuessResult::NotFound,
+        });
+
+        Task::none()
+    }
+}
+
+/// Gets the name hash of the selected asset, if exactly one asset is selected.
+fn selected_name_hash(state: &AppState) -> Option<(u64, NameHasher)> {
+    if state.assets_selected.len() != 1 {
+        return None;
+    }
+
+    let index = state.assets_selected.first()?;
+
+    state.asset_manager.asset_name_hash(*index)
+}
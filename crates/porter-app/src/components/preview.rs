@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::time::Duration;
 
 use iced::border::Radius;
@@ -22,12 +23,19 @@ use iced::Length;
 use iced::Task;
 use iced::Theme;
 
+use porter_audio::AudioPeaks;
+
+use porter_utils::ErrorReport;
+use porter_utils::ReportError;
+
 use porter_viewport::PreviewError;
+use porter_viewport::ViewportRenderer;
 
 use crate::AppState;
 use crate::AssetPreview;
 use crate::AudioPlayer;
 use crate::AudioPlayerError;
+use crate::AudioTranscript;
 use crate::Message;
 use crate::fonts;
 use crate::palette;
@@ -39,15 +47,29 @@ use super::ContentMessage;
 const HEADER_HEIGHT: f32 = 30.0;
 /// Text to display when not supported.
 const TEXT_UNSUPPORTED: &str = "<This asset can't be represented as text>";
+/// Maximum width of the error detail text before it wraps.
+const ERROR_DETAIL_WIDTH: f32 = 480.0;
 
 /// A list of preview controls to render over the previewer.
 const PREVIEW_CONTROLS: &[(&str, &str)] = &[
     ("Toggle Bones:", "[B]"),
-    ("Toggle Wireframe:", "[W]"),
+    ("Toggle Bone Axes:", "[X]"),
+    ("Cycle Wireframe:", "[W]"),
+    ("Toggle Backface Culling:", "[C]"),
     ("Toggle Shaded:", "[M]"),
     ("Toggle Grid:", "[G]"),
     ("Reset View:", "[R]"),
-    ("Cycle Image:", "[N]"),
+    ("Frame Selected:", "[F]"),
+    ("Select Mesh/Bone:", "[Click]"),
+    ("Hide Selected Mesh:", "[H]"),
+    ("Show All Meshes:", "[V]"),
+    ("Cycle Image:", "[N] [Shift+N]"),
+    ("Toggle Unpremultiply:", "[A]"),
+    ("Cycle UV Checker:", "[U]"),
+    ("Cycle Render Mode:", "[I]"),
+    ("Play/Pause Animation:", "[Space]"),
+    ("Step Animation Frame:", "[,] [.]"),
+    ("Inspect Pixel:", "[Hover]"),
 ];
 
 /// Preview component handler.
@@ -58,10 +80,13 @@ pub struct Preview {
     raw_name: String,
     audio_player: Option<AudioPlayer>,
     audio_player_seek: Option<f64>,
-    error: bool,
+    audio_peaks: Option<AudioPeaks>,
+    audio_transcript: Option<AudioTranscript>,
+    error: Option<ErrorReport>,
     unsupported: bool,
-    viewport_state: widgets::ViewportState,
+    viewport_state: Option<widgets::ViewportState>,
     scroll_id: scrollable::Id,
+    export_paths: Vec<(PathBuf, Option<PathBuf>)>,
 }
 
 /// Currently active preview tab.
@@ -87,7 +112,8 @@ pub enum PreviewMessage {
     SeekCommit,
     Play,
     Pause,
-    Update(AssetPreview),
+    Update(Result<AssetPreview, ErrorReport>),
+    CopyError,
     Request,
     SyncSettings,
 }
@@ -102,10 +128,13 @@ impl Preview {
             raw_name: String::new(),
             audio_player: None,
             audio_player_seek: None,
-            error: false,
+            audio_peaks: None,
+            audio_transcript: None,
+            error: None,
             unsupported: false,
-            viewport_state: widgets::ViewportState::new(),
+            viewport_state: widgets::ViewportState::try_new().ok(),
             scroll_id: scrollable::Id::unique(),
+            export_paths: Vec::new(),
         }
     }
 
@@ -126,6 +155,7 @@ impl Preview {
             Play => self.on_play(state),
             Pause => self.on_pause(state),
             Update(asset) => self.on_preview_update(state, asset),
+            CopyError => self.on_copy_error(state),
             Request => self.on_preview_request(state),
             SyncSettings => self.on_sync_settings(state),
         }
@@ -177,6 +207,10 @@ impl Preview {
 
         let content = match &self.tab {
             PreviewTab::Viewport => self.view_viewport(state),
+            // Stale text would be misleading while the next asset loads.
+            PreviewTab::Text | PreviewTab::Binary if state.asset_preview_id.is_some() => {
+                self.view_loading(state)
+            }
             PreviewTab::Text => self.view_text(state),
             PreviewTab::Binary => self.view_binary(state),
             PreviewTab::Audio => self.view_audio(state),
@@ -331,7 +365,7 @@ impl Preview {
                             container(
                                 text(format!(
                                     "Name: {}",
-                                    if self.error {
+                                    if self.error.is_some() {
                                         "<failed to load>"
                                     } else {
                                         &self.raw_name
@@ -339,7 +373,7 @@ impl Preview {
                                 ))
                                 .width(Length::Shrink)
                                 .height(Length::Shrink)
-                                .color(if self.error {
+                                .color(if self.error.is_some() {
                                     palette::TEXT_COLOR_WARN
                                 } else {
                                     palette::TEXT_COLOR_DEFAULT
@@ -350,7 +384,7 @@ impl Preview {
                             .width(Length::Fill)
                             .height(Length::Shrink)
                             .into(),
-                            text(if self.error {
+                            text(if self.error.is_some() {
                                 String::from("(Error)")
                             } else if self.unsupported {
                                 String::from("(Unsupported)")
@@ -392,11 +426,47 @@ impl Preview {
             None
         };
 
+        let export_paths: Option<Container<_>> = if self.export_paths.is_empty() {
+            None
+        } else {
+            let mut paths: Column<_> = Column::with_capacity(self.export_paths.len() + 1);
+
+            paths = paths.push(text("Exports to:").size(12.0));
+
+            for (path, resolved) in &self.export_paths {
+                let path = match resolved {
+                    Some(resolved) if resolved == path => {
+                        text(path.display().to_string()).color(palette::TEXT_COLOR_SECONDARY)
+                    }
+                    Some(resolved) => {
+                        text(format!("{} (renamed, file exists)", resolved.display()))
+                            .color(palette::TEXT_COLOR_WARN)
+                    }
+                    None => text(format!("{} (skipped, file exists)", path.display()))
+                        .color(palette::TEXT_COLOR_WARN),
+                };
+
+                paths = paths.push(path.size(12.0).wrapping(text::Wrapping::None));
+            }
+
+            Some(
+                container(
+                    container(paths)
+                        .clip(true)
+                        .width(Length::Fill)
+                        .padding([2.0, 4.0])
+                        .style(preview_footer_style),
+                )
+                .style(preview_content_style),
+            )
+        };
+
         let view = if embedded {
             column(
                 [Element::from(header), Element::from(content)]
                     .into_iter()
                     .chain(footer.map(Into::into))
+                    .chain(export_paths.map(Into::into))
                     .chain([Element::from(tab_row)]),
             )
             .spacing(1.0)
@@ -405,6 +475,7 @@ impl Preview {
                 [Element::from(content)]
                     .into_iter()
                     .chain(footer.map(Into::into))
+                    .chain(export_paths.map(Into::into))
                     .chain([Element::from(tab_row)]),
             )
             .spacing(1.0)
@@ -418,8 +489,17 @@ impl Preview {
 
     /// Handles rendering the viewport tab.
     fn view_viewport(&self, state: &AppState) -> Element<'_, Message> {
+        let Some(viewport_state) = &self.viewport_state else {
+            return container(
+                text("Preview is unavailable, no compatible GPU was found.")
+                    .color(palette::TEXT_COLOR_SECONDARY),
+            )
+            .center(Length::Fill)
+            .into();
+        };
+
         let viewport = Element::from(widgets::viewport(
-            &self.viewport_state,
+            viewport_state,
             // Keep the viewport state in sync with the external state.
             |action| Message::from(PreviewMessage::ViewportAction(action)),
         ));
@@ -429,36 +509,65 @@ impl Preview {
             .height(Length::Shrink)
             .spacing(2.0);
 
-        if self.error || self.unsupported {
-            columns = columns.push(
-                row([
-                    text("Status")
-                        .size(16.0)
-                        .width(75.0)
-                        .font(fonts::MONOSPACE_BOLD_FONT)
-                        .color(palette::TEXT_COLOR_INFO)
-                        .into(),
-                    text(":")
-                        .size(16.0)
-                        .color(palette::TEXT_COLOR_INFO)
-                        .font(fonts::MONOSPACE_BOLD_FONT)
+        if self.error.is_some() || self.unsupported {
+            let mut status = vec![(
+                "Status",
+                if self.error.is_some() {
+                    String::from("<failed to load>")
+                } else {
+                    String::from("<not supported for preview>")
+                },
+            )];
+
+            if let Some(report) = &self.error {
+                status.push(("Error", format!("{} ({})", report.message, report.code)));
+                status.push(("Detail", report.detail.clone()));
+            }
+
+            for (status_header, status_value) in status {
+                columns = columns.push(
+                    row([
+                        text(status_header)
+                            .size(16.0)
+                            .width(75.0)
+                            .font(fonts::MONOSPACE_BOLD_FONT)
+                            .color(palette::TEXT_COLOR_INFO)
+                            .into(),
+                        text(":")
+                            .size(16.0)
+                            .color(palette::TEXT_COLOR_INFO)
+                            .font(fonts::MONOSPACE_BOLD_FONT)
+                            .into(),
+                        container(
+                            text(status_value)
+                                .size(16.0)
+                                .color(if status_header == "Detail" {
+                                    palette::TEXT_COLOR_WARN
+                                } else {
+                                    palette::TEXT_COLOR_DEFAULT
+                                })
+                                .font(fonts::MONOSPACE_BOLD_FONT),
+                        )
+                        .max_width(ERROR_DETAIL_WIDTH)
                         .into(),
-                    text(if self.error {
-                        "<failed to load>"
-                    } else {
-                        "<not supported for preview>"
-                    })
-                    .size(16.0)
-                    .color(palette::TEXT_COLOR_DEFAULT)
-                    .font(fonts::MONOSPACE_BOLD_FONT)
-                    .into(),
-                ])
-                .width(Length::Shrink)
-                .padding(2.0)
-                .spacing(8.0),
-            );
+                    ])
+                    .width(Length::Shrink)
+                    .padding(2.0)
+                    .spacing(8.0),
+                );
+            }
+
+            if self.error.is_some() {
+                columns = columns.push(
+                    container(
+                        widgets::button(text("Copy Details").size(14.0))
+                            .on_press(Message::from(PreviewMessage::CopyError)),
+                    )
+                    .padding(2.0),
+                );
+            }
         } else {
-            let renderer = self.viewport_state.renderer();
+            let renderer = viewport_state.renderer();
 
             for (stat_header, stat_value) in renderer.statistics() {
                 columns = columns.push(
@@ -487,15 +596,39 @@ impl Preview {
             }
         }
 
-        let columns = container(
-            container(columns)
-                .width(Length::Shrink)
-                .padding(4.0)
-                .style(preview_overlay_style),
-        )
-        .width(Length::Fill)
-        .height(Length::FillPortion(1))
-        .padding(4.0);
+        let mut columns = column([container(columns)
+            .width(Length::Shrink)
+            .padding(4.0)
+            .style(preview_overlay_style)
+            .into()])
+        .spacing(4.0);
+
+        if self.error.is_none()
+            && !self.unsupported
+            && let Some((index, labels)) = viewport_state.renderer().material_images()
+            && labels.len() > 1
+        {
+            let selected = labels.get(index).cloned();
+
+            columns = columns.push(
+                widgets::pick_list(labels.clone(), selected, move |selected| {
+                    let index = labels
+                        .iter()
+                        .position(|label| *label == selected)
+                        .unwrap_or_default();
+
+                    Message::from(PreviewMessage::ViewportAction(
+                        widgets::ViewportAction::SelectMaterial(index),
+                    ))
+                })
+                .width(Length::Shrink),
+            );
+        }
+
+        let columns = container(columns)
+            .width(Length::Fill)
+            .height(Length::FillPortion(1))
+            .padding(4.0);
 
         let mut controls: Column<_> = Column::with_capacity(PREVIEW_CONTROLS.len())
             .width(Length::Shrink)
@@ -540,13 +673,7 @@ impl Preview {
         };
 
         let overlay = if state.asset_preview_id.is_some() {
-            let loading = container(widgets::spinner())
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .align_x(Alignment::Center)
-                .align_y(Alignment::Center);
-
-            column([columns.into(), loading.into(), controls])
+            column([columns.into(), self.view_loading(state), controls])
                 .width(Length::Fill)
                 .height(Length::Fill)
         } else {
@@ -558,6 +685,35 @@ impl Preview {
         stack([viewport, overlay.into()]).into()
     }
 
+    /// Handles rendering the loading indicator while the preview asset is being built.
+    fn view_loading(&self, state: &AppState) -> Element<'_, Message> {
+        let status = match &state.asset_preview_merge {
+            Some(merge) => {
+                let (received, total) = merge.progress();
+
+                format!("Loading preview... ({} of {})", received, total)
+            }
+            None => String::from("Loading preview..."),
+        };
+
+        container(
+            column([
+                widgets::spinner().into(),
+                container(text(status).size(14.0).color(palette::TEXT_COLOR_SECONDARY))
+                    .padding([2.0, 6.0])
+                    .style(preview_overlay_style)
+                    .into(),
+            ])
+            .spacing(8.0)
+            .align_x(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Alignment::Center)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
     /// Handles rendering the text tab.
     fn view_text(&self, _state: &AppState) -> Element<'_, Message> {
         widgets::scrollable(
@@ -611,22 +767,37 @@ impl Preview {
 
         let waveform: Element<'_, Message> =
             widgets::waveform(is_playing, seek_seed, Message::Noop)
+                .peaks(
+                    self.audio_peaks.as_ref(),
+                    (seek_position / seek_duration.max(f64::EPSILON)) as f32,
+                )
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into();
 
         let content: Element<'_, Message> = if state.asset_preview_id.is_some() {
-            let loading = container(widgets::spinner())
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .align_x(Alignment::Center)
-                .align_y(Alignment::Center);
-
-            stack([waveform, loading.into()]).into()
+            stack([waveform, self.view_loading(state)]).into()
         } else {
             stack([waveform]).into()
         };
 
+        let content: Element<'_, Message> = match &self.audio_transcript {
+            Some(transcript) => column([
+                content,
+                container(scrollable(
+                    text(transcript.text())
+                        .width(Length::Fill)
+                        .color(palette::TEXT_COLOR_SECONDARY),
+                ))
+                .width(Length::Fill)
+                .max_height(120.0)
+                .padding([0.0, 8.0])
+                .into(),
+            ])
+            .into(),
+            None => content,
+        };
+
         column([
             content,
             row([
@@ -687,11 +858,16 @@ impl Preview {
         state: &mut AppState,
         action: widgets::ViewportAction,
     ) -> Task<Message> {
-        self.viewport_state.perform(
-            action,
-            state.settings.far_clip() as f32,
-            state.settings.preview_controls(),
-        );
+        if let Some(viewport_state) = &mut self.viewport_state {
+            viewport_state.perform(
+                action,
+                state.settings.far_clip() as f32,
+                state.settings.preview_controls(),
+            );
+        }
+
+        self.sync_selection(state);
+        self.sync_export_paths(state);
 
         Task::none()
     }
@@ -769,29 +945,43 @@ impl Preview {
     }
 
     /// Occurs when the asset manager has a new asset to preview.
-    fn on_preview_update(&mut self, state: &mut AppState, asset: AssetPreview) -> Task<Message> {
-        match asset {
-            AssetPreview::NotSupported => {
+    fn on_preview_update(
+        &mut self,
+        state: &mut AppState,
+        asset: Result<AssetPreview, ErrorReport>,
+    ) -> Task<Message> {
+        self.audio_transcript = None;
+
+        let asset = match asset {
+            Ok(asset) => asset,
+            Err(report) => {
                 self.raw_text = text_editor::Content::new();
                 self.raw_binary = None;
                 self.raw_name = String::new();
                 self.audio_player = None;
 
-                self.error = false;
-                self.unsupported = true;
-                self.viewport_state.renderer_mut().clear_preview();
+                self.error = Some(report);
+                self.unsupported = false;
+                self.clear_viewport();
 
                 self.tab = PreviewTab::Viewport;
+
+                self.sync_selection(state);
+
+                return Task::none();
             }
-            AssetPreview::PreviewError => {
+        };
+
+        match asset {
+            AssetPreview::NotSupported => {
                 self.raw_text = text_editor::Content::new();
                 self.raw_binary = None;
                 self.raw_name = String::new();
                 self.audio_player = None;
 
-                self.error = true;
-                self.unsupported = false;
-                self.viewport_state.renderer_mut().clear_preview();
+                self.error = None;
+                self.unsupported = true;
+                self.clear_viewport();
 
                 self.tab = PreviewTab::Viewport;
             }
@@ -818,9 +1008,9 @@ impl Preview {
                 self.raw_binary = Some(raw_file);
                 self.raw_name = name;
 
-                self.error = false;
+                self.error = None;
                 self.unsupported = false;
-                self.viewport_state.renderer_mut().clear_preview();
+                self.clear_viewport();
                 self.audio_player = None;
 
                 return scrollable::scroll_to(
@@ -835,20 +1025,19 @@ impl Preview {
                 self.audio_player = None;
 
                 if let Err(e) = self
-                    .viewport_state
                     .renderer_mut()
-                    .set_preview_image(name, image)
+                    .and_then(|renderer| renderer.set_preview_image(name, image))
                 {
                     if matches!(e, PreviewError::Unsupported) {
                         self.unsupported = true;
-                        self.error = false;
+                        self.error = None;
                     } else {
                         self.unsupported = false;
-                        self.error = true;
+                        self.error = Some(e.report());
                     }
                 } else {
                     self.unsupported = false;
-                    self.error = false;
+                    self.error = None;
                 }
 
                 self.tab = PreviewTab::Viewport;
@@ -860,20 +1049,19 @@ impl Preview {
                 self.audio_player = None;
 
                 if let Err(e) = self
-                    .viewport_state
                     .renderer_mut()
-                    .set_preview_material(name, material)
+                    .and_then(|renderer| renderer.set_preview_material(name, material))
                 {
                     if matches!(e, PreviewError::Unsupported) {
                         self.unsupported = true;
-                        self.error = false;
+                        self.error = None;
                     } else {
                         self.unsupported = false;
-                        self.error = true;
+                        self.error = Some(e.report());
                     }
                 } else {
                     self.unsupported = false;
-                    self.error = false;
+                    self.error = None;
                 }
 
                 self.tab = PreviewTab::Viewport;
@@ -886,38 +1074,64 @@ impl Preview {
 
                 let srgb = cfg!(feature = "srgb-preview");
 
+                self.sync_camera(state);
+
+                if let Err(e) = self.renderer_mut().and_then(|renderer| {
+                    renderer.set_preview_model(name, model, images, srgb, false)
+                }) {
+                    if matches!(e, PreviewError::Unsupported) {
+                        self.unsupported = true;
+                        self.error = None;
+                    } else {
+                        self.unsupported = false;
+                        self.error = Some(e.report());
+                    }
+                } else {
+                    self.unsupported = false;
+                    self.error = None;
+                }
+
+                self.tab = PreviewTab::Viewport;
+            }
+            AssetPreview::Animation(name, animation) => {
+                self.raw_text = text_editor::Content::new();
+                self.raw_binary = None;
+                self.raw_name = String::new();
+                self.audio_player = None;
+
                 if let Err(e) = self
-                    .viewport_state
                     .renderer_mut()
-                    .set_preview_model(name, model, images, srgb, false)
+                    .and_then(|renderer| renderer.set_preview_animation(name, animation))
                 {
                     if matches!(e, PreviewError::Unsupported) {
                         self.unsupported = true;
-                        self.error = false;
+                        self.error = None;
                     } else {
                         self.unsupported = false;
-                        self.error = true;
+                        self.error = Some(e.report());
                     }
                 } else {
                     self.unsupported = false;
-                    self.error = false;
+                    self.error = None;
                 }
 
                 self.tab = PreviewTab::Viewport;
             }
-            AssetPreview::Audio(name, audio) => {
+            AssetPreview::Audio(name, audio, transcript) => {
                 self.raw_text = text_editor::Content::new();
                 self.raw_binary = None;
                 self.raw_name = name;
+                self.audio_transcript = transcript;
+                self.audio_peaks = AudioPeaks::from_audio(&audio).ok();
 
                 match AudioPlayer::load(audio) {
                     Err(e) => {
                         if matches!(e, AudioPlayerError::Unsupported) {
                             self.unsupported = true;
-                            self.error = false;
+                            self.error = None;
                         } else {
                             self.unsupported = false;
-                            self.error = true;
+                            self.error = Some(e.report());
                         }
                     }
                     Ok(mut audio_player) => {
@@ -927,7 +1141,7 @@ impl Preview {
                         self.audio_player = Some(audio_player);
 
                         self.unsupported = false;
-                        self.error = false;
+                        self.error = None;
                     }
                 }
 
@@ -935,9 +1149,23 @@ impl Preview {
             }
         }
 
+        self.sync_selection(state);
+
         Task::none()
     }
 
+    /// Occurs when the user wants to copy the details of a preview error to the clipboard.
+    fn on_copy_error(&mut self, _: &mut AppState) -> Task<Message> {
+        let Some(report) = &self.error else {
+            return Task::none();
+        };
+
+        iced::clipboard::write(format!(
+            "{} ({})\n{}",
+            report.message, report.code, report.detail
+        ))
+    }
+
     /// Occurs when we want to request a preview asset, but need to check if the previewer is open.
     fn on_preview_request(&mut self, _: &mut AppState) -> Task<Message> {
         Task::done(Message::PreviewRequest)
@@ -949,12 +1177,68 @@ impl Preview {
             audio_player.volume(state.settings.volume());
         }
 
-        self.viewport_state
-            .renderer_mut()
-            .far_clip(state.settings.far_clip() as f32);
+        self.sync_camera(state);
+        self.sync_export_paths(state);
 
         Task::none()
     }
+
+    /// Syncs the camera, background, and image budget settings of the viewport renderer.
+    fn sync_camera(&mut self, state: &AppState) {
+        if let Ok(renderer) = self.renderer_mut() {
+            renderer.set_background(state.settings.preview_background());
+            renderer.far_clip(state.settings.far_clip() as f32);
+            renderer.near_clip(state.settings.near_clip());
+            renderer.fov(state.settings.fov() as f32);
+            renderer.image_budget(state.settings.preview_image_budget() as u64 * 1024 * 1024);
+        }
+    }
+
+    /// Syncs the visible meshes and selected frame of the preview, so that exports can respect them.
+    fn sync_selection(&self, state: &mut AppState) {
+        let renderer = self
+            .viewport_state
+            .as_ref()
+            .map(|viewport_state| viewport_state.renderer());
+
+        if let Some((_, visible)) = &mut state.preview_visibility {
+            *visible = renderer
+                .map(|renderer| renderer.visible_meshes())
+                .unwrap_or_default();
+        }
+
+        if let Some((_, frame)) = &mut state.preview_frame {
+            *frame = renderer
+                .and_then(|renderer| renderer.image_frame())
+                .map(|(frame, _)| frame)
+                .unwrap_or_default();
+        }
+    }
+
+    /// Syncs the paths the selected asset would be exported to with the current settings.
+    fn sync_export_paths(&mut self, state: &AppState) {
+        self.export_paths = state
+            .assets_selected
+            .first()
+            .and_then(|index| state.asset_manager.asset_export_name(*index))
+            .map(|(asset_type, name)| state.settings.dry_run_export_paths(asset_type, &name))
+            .unwrap_or_default();
+    }
+
+    /// Gets the viewport renderer, if a GPU was available to create it.
+    fn renderer_mut(&mut self) -> Result<&mut ViewportRenderer, PreviewError> {
+        self.viewport_state
+            .as_mut()
+            .map(|viewport_state| viewport_state.renderer_mut())
+            .ok_or(PreviewError::GPUUnavailable)
+    }
+
+    /// Clears the asset being previewed in the viewport.
+    fn clear_viewport(&mut self) {
+        if let Ok(renderer) = self.renderer_mut() {
+            renderer.clear_preview();
+        }
+    }
 }
 
 /// Style for the preview header.
@@ -9,6 +9,8 @@ use iced::Length;
 use iced::Task;
 
 use crate::AppState;
+use crate::ExportAssetType;
+use crate::ExportQueue;
 use crate::Message;
 use crate::SearchTerm;
 use crate::palette;
@@ -23,6 +25,8 @@ const SEARCH_REALTIME_MAX: usize = 500_000;
 pub struct SearchBar {
     search: String,
     search_id: text_input::Id,
+    search_error: Option<String>,
+    asset_types: Vec<ExportAssetType>,
 }
 
 /// Messages produced by the search bar component.
@@ -32,6 +36,7 @@ pub enum SearchBarMessage {
     Clear,
     Submit,
     Find,
+    ToggleAssetType(ExportAssetType),
 }
 
 impl SearchBar {
@@ -40,6 +45,8 @@ impl SearchBar {
         Self {
             search: String::new(),
             search_id: text_input::Id::unique(),
+            search_error: None,
+            asset_types: Vec::new(),
         }
     }
 
@@ -52,6 +59,7 @@ impl SearchBar {
             Clear => self.on_search_clear(state),
             Submit => self.on_search_submit(state),
             Find => self.on_search_find(state),
+            ToggleAssetType(asset_type) => self.on_toggle_asset_type(state, asset_type),
         }
     }
 
@@ -90,11 +98,24 @@ impl SearchBar {
             },
         ));
 
+        row = row.push(widgets::switch_button(filter_asset_types().map(
+            |asset_type| {
+                (
+                    asset_type.name(),
+                    (!state.is_busy())
+                        .then_some(Message::from(SearchBarMessage::ToggleAssetType(asset_type))),
+                    self.asset_types.contains(&asset_type),
+                )
+            },
+        )));
+
         container(
             row.push(
                 text(if state.loading {
                     String::from("Loading...")
-                } else if self.search.is_empty() {
+                } else if let Some(error) = &self.search_error {
+                    error.clone()
+                } else if self.search.is_empty() && self.asset_types.is_empty() {
                     format!("{} assets loaded", state.asset_manager.assets_visible())
                 } else {
                     format!(
@@ -105,7 +126,11 @@ impl SearchBar {
                 })
                 .width(Length::Fill)
                 .align_x(Alignment::End)
-                .color(palette::TEXT_COLOR_SECONDARY),
+                .color(if self.search_error.is_some() && !state.loading {
+                    palette::TEXT_COLOR_WARN
+                } else {
+                    palette::TEXT_COLOR_SECONDARY
+                }),
             )
             .align_y(Alignment::Center)
             .spacing(4.0),
@@ -130,8 +155,17 @@ impl SearchBar {
     /// Clears any search results.
     fn on_search_clear(&mut self, state: &mut AppState) -> Task<Message> {
         self.search = String::new();
+        self.search_error = None;
+
+        if !self.asset_types.is_empty() {
+            return self.on_search_submit(state);
+        }
 
         state.assets_selected.clear();
+        state.assets_anchor = None;
+        state.export_queue = ExportQueue::default();
+        state.preview_visibility = None;
+        state.preview_frame = None;
 
         state.asset_manager.search(None);
         state.reset_item_range();
@@ -141,20 +175,57 @@ impl SearchBar {
 
     /// Submits the search term to filter assets.
     fn on_search_submit(&mut self, state: &mut AppState) -> Task<Message> {
-        if self.search.is_empty() {
+        if self.search.is_empty() && self.asset_types.is_empty() {
             return self.on_search_clear(state);
         }
 
+        // Keep the last results while the search term is invalid, as it's likely still being typed.
+        let headers: Vec<&str> = state
+            .asset_columns
+            .iter()
+            .map(|column| column.header)
+            .collect();
+
+        let search = match SearchTerm::compile(&self.search) {
+            Ok(search) => search.resolve_columns(&headers),
+            Err(e) => {
+                self.search_error = Some(e);
+
+                return Task::none();
+            }
+        };
+
+        self.search_error = None;
+
         state.assets_selected.clear();
+        state.assets_anchor = None;
+        state.export_queue = ExportQueue::default();
+        state.preview_visibility = None;
+        state.preview_frame = None;
 
         state
             .asset_manager
-            .search(Some(SearchTerm::compile(&self.search)));
+            .search(Some(search.asset_types(self.asset_types.clone())));
         state.reset_item_range();
 
         Task::done(Message::from(VirtualListMessage::ScrollReset))
     }
 
+    /// Toggles filtering by the given asset type, combined with the search term.
+    fn on_toggle_asset_type(
+        &mut self,
+        state: &mut AppState,
+        asset_type: ExportAssetType,
+    ) -> Task<Message> {
+        if let Some(index) = self.asset_types.iter().position(|x| *x == asset_type) {
+            self.asset_types.remove(index);
+        } else {
+            self.asset_types.push(asset_type);
+        }
+
+        self.on_search_submit(state)
+    }
+
     /// Focuses and selects all search text.
     fn on_search_find(&mut self, _: &mut AppState) -> Task<Message> {
         Task::batch([
@@ -163,3 +234,16 @@ impl SearchBar {
         ])
     }
 }
+
+/// The asset types which can be filtered on, for the asset types the app supports.
+fn filter_asset_types() -> impl Iterator<Item = ExportAssetType> {
+    ExportAssetType::ALL
+        .into_iter()
+        .filter(|asset_type| match asset_type {
+            ExportAssetType::Model | ExportAssetType::Image => true,
+            ExportAssetType::Material => cfg!(feature = "materials"),
+            ExportAssetType::Animation => cfg!(feature = "animations"),
+            ExportAssetType::Sound => cfg!(feature = "sounds"),
+            ExportAssetType::RawFile => cfg!(feature = "raw-files"),
+        })
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use iced::widget::Column;
 use iced::widget::row;
 use iced::widget::text;
@@ -10,22 +12,62 @@ use iced::Task;
 
 use directories::ProjectDirs;
 
+use porter_gpu::GPUAdapterPreference;
+
 use porter_model::ModelFileType;
+use porter_texture::ImageChannelRemap;
+use porter_texture::ImageColorProfile;
+use porter_texture::ImageCompressionQuality;
 use porter_texture::ImageFileType;
+use porter_viewport::PreviewBackground;
 use porter_viewport::PreviewControlScheme;
 
 use crate::AppState;
+use crate::ExportAssetType;
+use crate::ExportConflictPolicy;
+use crate::ExportFolders;
+use crate::ExportLayout;
+use crate::ImageAlphaProcessing;
+use crate::ImageCompression;
+use crate::ImageFrameExport;
+use crate::ImageMipmapExport;
 use crate::MainMessage;
 use crate::Message;
+use crate::RowColor;
+use crate::RowColorRule;
+use crate::UsageStatistics;
 use crate::palette;
 use crate::system;
 use crate::widgets;
 
 use super::PreviewMessage;
 
+/// The name of the profile which edits the global settings.
+pub(crate) const GLOBAL_PROFILE: &str = "Global";
+
+/// The names of the GPU adapter choices that aren't a specific adapter.
+const GPU_ADAPTER_AUTOMATIC: &str = "Automatic";
+const GPU_ADAPTER_LOW_POWER: &str = "Low Power";
+const GPU_ADAPTER_SOFTWARE: &str = "Software";
+
+/// The channel remap used when splitting packed channels is first enabled.
+const DEFAULT_CHANNEL_REMAP: &str = "R->AO, G->Roughness, B->Metalness";
+
+/// The maximum number of settings changes that can be undone.
+const UNDO_LIMIT: usize = 50;
+
 /// Settings component handler.
 pub struct Settings {
     custom_scale: Option<String>,
+    channel_remap: Option<String>,
+    export_folders: Option<ExportFolders>,
+    gpu_adapters: Vec<String>,
+    row_color_column: Option<String>,
+    row_color_value: String,
+    row_color: RowColor,
+    undo: Vec<crate::Settings>,
+    redo: Vec<crate::Settings>,
+    session: Option<crate::Settings>,
 }
 
 /// Messages produced by the settings component.
@@ -33,16 +75,48 @@ pub struct Settings {
 pub enum SettingsMessage {
     Save(crate::Settings),
     PickExportFolder,
+    PickNameDatabase,
     OpenExportFolder,
     OpenConfigFolder,
     ApplyCustomScale,
     ScaleInput(String),
+    ApplyChannelRemap,
+    ChannelRemapInput(String),
+    ApplyExportFolders,
+    ExportFolderInput(ExportAssetType, String),
+    RowColorColumn(String),
+    RowColorValue(String),
+    RowColorPick(RowColor),
+    AddRowColorRule,
+    Undo,
+    Redo,
+    RevertSession,
+    ResetDefaults,
 }
 
 impl Settings {
     /// Creates a new settings component.
     pub fn new() -> Self {
-        Self { custom_scale: None }
+        let mut gpu_adapters = vec![
+            String::from(GPU_ADAPTER_AUTOMATIC),
+            String::from(GPU_ADAPTER_LOW_POWER),
+            String::from(GPU_ADAPTER_SOFTWARE),
+        ];
+
+        gpu_adapters.extend(porter_gpu::gpu_adapters());
+
+        Self {
+            custom_scale: None,
+            channel_remap: None,
+            export_folders: None,
+            gpu_adapters,
+            row_color_column: None,
+            row_color_value: String::new(),
+            row_color: RowColor::Green,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            session: None,
+        }
     }
 
     /// Handles updates for the settings component.
@@ -52,10 +126,25 @@ impl Settings {
         match message {
             Save(settings) => self.on_save(state, settings),
             PickExportFolder => self.on_pick_export_folder(state),
+            PickNameDatabase => self.on_pick_name_database(state),
             OpenExportFolder => self.on_open_export_folder(state),
             OpenConfigFolder => self.on_open_config_folder(state),
             ApplyCustomScale => self.on_apply_custom_scale(state),
             ScaleInput(input) => self.on_scale_input(state, input),
+            ApplyChannelRemap => self.on_apply_channel_remap(state),
+            ChannelRemapInput(input) => self.on_channel_remap_input(state, input),
+            ApplyExportFolders => self.on_apply_export_folders(state),
+            ExportFolderInput(asset_type, input) => {
+                self.on_export_folder_input(state, asset_type, input)
+            }
+            RowColorColumn(column) => self.on_row_color_column(column),
+            RowColorValue(value) => self.on_row_color_value(value),
+            RowColorPick(color) => self.on_row_color_pick(color),
+            AddRowColorRule => self.on_add_row_color_rule(state),
+            Undo => self.on_undo(state),
+            Redo => self.on_redo(state),
+            RevertSession => self.on_revert_session(state),
+            ResetDefaults => self.on_reset_defaults(state),
         }
     }
 
@@ -67,6 +156,10 @@ impl Settings {
         let save_message =
             |settings: crate::Settings| Message::from(SettingsMessage::Save(settings));
 
+        let mut profiles = vec![String::from(GLOBAL_PROFILE)];
+
+        profiles.extend(state.settings.profiles());
+
         let mut settings: Column<_> = Column::with_capacity(64);
 
         settings = settings.extend([
@@ -75,6 +168,99 @@ impl Settings {
                 .color(palette::TEXT_COLOR_DEFAULT)
                 .into(),
             vertical_space().height(2.0).into(),
+            text("Undo the changes made to settings during this session, or reset them to defaults:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::button("Undo")
+                    .on_press_maybe(
+                        (!self.undo.is_empty()).then(|| Message::from(SettingsMessage::Undo)),
+                    )
+                    .into(),
+                widgets::button("Redo")
+                    .on_press_maybe(
+                        (!self.redo.is_empty()).then(|| Message::from(SettingsMessage::Redo)),
+                    )
+                    .into(),
+                widgets::button("Revert Session Changes")
+                    .on_press_maybe(
+                        self.session
+                            .is_some()
+                            .then(|| Message::from(SettingsMessage::RevertSession)),
+                    )
+                    .into(),
+                widgets::button("Reset to Defaults")
+                    .on_press(Message::from(SettingsMessage::ResetDefaults))
+                    .into(),
+            ])
+            .spacing(4.0)
+            .align_y(Alignment::Center)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose the settings profile used for export formats:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::pick_list(
+                    profiles,
+                    Some(
+                        state
+                            .settings
+                            .profile()
+                            .unwrap_or(GLOBAL_PROFILE)
+                            .to_string(),
+                    ),
+                    move |selected| {
+                        let profile = (selected != GLOBAL_PROFILE).then_some(selected);
+
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_profile(profile)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(250.0))
+                .into(),
+                widgets::button("Reset Profile")
+                    .on_press_maybe(state.settings.profile_has_overrides().then(|| {
+                        save_message(state.settings.update(|settings| settings.reset_profile()))
+                    }))
+                    .into(),
+            ])
+            .spacing(4.0)
+            .align_y(Alignment::Center)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to skip the splash screen, and disable decorative animations:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::checkbox("Skip splash screen", state.settings.skip_splash())
+                    .on_toggle(move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_skip_splash(value)),
+                        )
+                    })
+                    .into(),
+                widgets::checkbox("Reduce motion", state.settings.reduced_motion())
+                    .on_toggle(move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_reduced_motion(value)),
+                        )
+                    })
+                    .into(),
+            ])
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
             text("Choose what asset types to load and display:")
                 .color(palette::TEXT_COLOR_SECONDARY)
                 .into(),
@@ -166,6 +352,17 @@ impl Settings {
             ));
         }
 
+        settings = settings.extend([
+            vertical_space().height(2.0).into(),
+            text(
+                "Choose rules which color rows in the asset list, the first matching rule is used:",
+            )
+            .color(palette::TEXT_COLOR_SECONDARY)
+            .into(),
+            vertical_space().height(0.0).into(),
+            self.view_row_color_rules(state),
+        ]);
+
         settings =
             settings.extend([
                 vertical_space().height(2.0).into(),
@@ -191,6 +388,65 @@ impl Settings {
                 .spacing(4.0)
                 .into(),
                 vertical_space().height(2.0).into(),
+                text("Choose how exported files are laid out in the exported files directory:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::pick_list(
+                    vec!["Default", "Unity", "Type Folders"],
+                    match state.settings.export_layout() {
+                        ExportLayout::Default => Some("Default"),
+                        ExportLayout::Unity => Some("Unity"),
+                        ExportLayout::TypeFolders => Some("Type Folders"),
+                    },
+                    move |selected| {
+                        let layout = match selected {
+                            "Unity" => ExportLayout::Unity,
+                            "Type Folders" => ExportLayout::TypeFolders,
+                            _ => ExportLayout::Default,
+                        };
+
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_export_layout(layout)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(150.0))
+                .into(),
+                self.view_export_folders(state),
+                vertical_space().height(2.0).into(),
+                text("Choose how exported files which already exist are handled:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::pick_list(
+                    vec!["Overwrite", "Skip", "Rename", "Ask"],
+                    match state.settings.export_conflict_policy() {
+                        ExportConflictPolicy::Overwrite => Some("Overwrite"),
+                        ExportConflictPolicy::Skip => Some("Skip"),
+                        ExportConflictPolicy::Rename => Some("Rename"),
+                        ExportConflictPolicy::Ask => Some("Ask"),
+                    },
+                    move |selected| {
+                        let policy = match selected {
+                            "Skip" => ExportConflictPolicy::Skip,
+                            "Rename" => ExportConflictPolicy::Rename,
+                            "Ask" => ExportConflictPolicy::Ask,
+                            _ => ExportConflictPolicy::Overwrite,
+                        };
+
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_export_conflict_policy(policy)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(150.0))
+                .into(),
+                vertical_space().height(2.0).into(),
                 text("Choose whether or not to automatically scale assets (Recommended):")
                     .color(palette::TEXT_COLOR_SECONDARY)
                     .into(),
@@ -328,6 +584,47 @@ impl Settings {
                         }))
                     })
                     .into(),
+                widgets::checkbox("Debug Text", model_format_enabled(ModelFileType::Debug))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_model_file_type(ModelFileType::Debug, value)
+                        }))
+                    })
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose whether or not meshes hidden in preview are excluded from export:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox(
+                    "Respect preview visibility",
+                    state.settings.export_visible_meshes(),
+                )
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_export_visible_meshes(value)),
+                    )
+                })
+                .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose whether or not unnamed meshes are named after their material:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox(
+                    "Name meshes by material",
+                    state.settings.material_mesh_names(),
+                )
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_material_mesh_names(value)),
+                    )
+                })
+                .into(),
                 vertical_space().height(4.0).into(),
                 text("Settings - Images")
                     .size(20.0)
@@ -388,124 +685,531 @@ impl Settings {
             }
         }
 
-        #[cfg(feature = "normal-maps-convertible")]
-        {
-            use crate::ImageNormalMapProcessing;
-
-            settings =
-                settings.extend([
-                    vertical_space().height(2.0).into(),
-                    text("Choose a normal map conversion method:")
-                        .color(palette::TEXT_COLOR_SECONDARY)
-                        .into(),
-                    vertical_space().height(0.0).into(),
-                    widgets::pick_list(
-                        vec!["None", "OpenGL", "DirectX"],
-                        match state.settings.image_normal_map_processing() {
-                            ImageNormalMapProcessing::None => Some("None"),
-                            ImageNormalMapProcessing::OpenGl => Some("OpenGL"),
-                            ImageNormalMapProcessing::DirectX => Some("DirectX"),
-                        },
-                        move |selected| {
-                            let format = match selected {
-                                "None" => ImageNormalMapProcessing::None,
-                                "OpenGL" => ImageNormalMapProcessing::OpenGl,
-                                "DirectX" => ImageNormalMapProcessing::DirectX,
-                                _ => ImageNormalMapProcessing::None,
-                            };
-
-                            save_message(state.settings.update(|settings| {
-                                settings.set_image_normal_map_processing(format)
-                            }))
-                        },
-                    )
-                    .width(Length::Fixed(150.0))
-                    .into(),
-                    vertical_space().height(4.0).into(),
-                ]);
-        }
-
-        #[cfg(not(feature = "normal-maps-convertible"))]
-        {
-            settings = settings.push(vertical_space().height(4.0));
-        }
-
-        #[cfg(feature = "animations")]
-        {
-            use porter_animation::AnimationFileType;
-
-            let anim_formats = state.settings.anim_file_types();
-            let anim_format_enabled = |format: AnimationFileType| anim_formats.contains(&format);
-
-            settings = settings.extend([
-                text("Settings - Animations")
-                    .size(20.0)
-                    .color(palette::TEXT_COLOR_DEFAULT)
-                    .into(),
-                vertical_space().height(2.0).into(),
-                text("Choose what animation file types to export to:")
-                    .color(palette::TEXT_COLOR_SECONDARY)
-                    .into(),
-                vertical_space().height(0.0).into(),
-                widgets::checkbox("Cast", anim_format_enabled(AnimationFileType::Cast))
-                    .on_toggle(move |value| {
-                        save_message(state.settings.update(|settings| {
-                            settings.set_anim_file_type(AnimationFileType::Cast, value)
-                        }))
-                    })
-                    .into(),
-                vertical_space().height(4.0).into(),
-            ]);
-        }
-
-        #[cfg(all(feature = "sounds", feature = "sounds-convertible"))]
-        {
-            use porter_audio::AudioFileType;
-
-            let audio_formats = state.settings.audio_file_types();
-            let audio_format_enabled = |format: AudioFileType| audio_formats.contains(&format);
-
-            settings = settings.extend([
-                text("Settings - Audio")
-                    .size(20.0)
-                    .color(palette::TEXT_COLOR_DEFAULT)
-                    .into(),
-                vertical_space().height(2.0).into(),
-                text("Choose what audio file types to export to:")
-                    .color(palette::TEXT_COLOR_SECONDARY)
-                    .into(),
-                vertical_space().height(0.0).into(),
-                widgets::checkbox("Wav", audio_format_enabled(AudioFileType::Wav))
-                    .on_toggle(move |value| {
-                        save_message(state.settings.update(|settings| {
-                            settings.set_audio_file_type(AudioFileType::Wav, value)
-                        }))
-                    })
-                    .into(),
-                widgets::checkbox("Flac", audio_format_enabled(AudioFileType::Flac))
-                    .on_toggle(move |value| {
-                        save_message(state.settings.update(|settings| {
-                            settings.set_audio_file_type(AudioFileType::Flac, value)
-                        }))
-                    })
-                    .into(),
-                vertical_space().height(4.0).into(),
-            ]);
-        }
-
         settings = settings.extend([
-            text("Settings - Preview")
-                .size(20.0)
-                .color(palette::TEXT_COLOR_DEFAULT)
-                .into(),
             vertical_space().height(2.0).into(),
-            text("Change the preview control scheme:")
+            text("Choose whether or not to use Unreal Engine texture naming and ORM packing:")
                 .color(palette::TEXT_COLOR_SECONDARY)
                 .into(),
             vertical_space().height(0.0).into(),
-            widgets::pick_list(
-                vec!["Simple", "Autodesk Maya", "Blender"],
-                match state.settings.preview_controls() {
+            widgets::checkbox("Unreal Engine preset", state.settings.image_unreal_preset())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_unreal_preset(value)),
+                    )
+                })
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to export the alpha channel as a separate mask image:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Export alpha mask", state.settings.image_alpha_mask())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_alpha_mask(value)),
+                    )
+                })
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to split packed channels into separate grayscale images:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::checkbox(
+                    "Split channels:",
+                    state.settings.image_channel_remap().is_some(),
+                )
+                .on_toggle(move |value| {
+                    save_message(state.settings.update(|settings| {
+                        settings.set_image_channel_remap(
+                            value
+                                .then(|| DEFAULT_CHANNEL_REMAP.parse().ok())
+                                .flatten(),
+                        )
+                    }))
+                })
+                .into(),
+                widgets::text_input(
+                    DEFAULT_CHANNEL_REMAP,
+                    &self.channel_remap.clone().unwrap_or_else(|| {
+                        state
+                            .settings
+                            .image_channel_remap()
+                            .map(|remap| remap.to_string())
+                            .unwrap_or_default()
+                    }),
+                )
+                .on_input_maybe(if state.settings.image_channel_remap().is_some() {
+                    Some(|input| Message::from(SettingsMessage::ChannelRemapInput(input)))
+                } else {
+                    None
+                })
+                .width(Length::Fixed(400.0))
+                .into(),
+                widgets::button("Apply")
+                    .on_press_maybe(if state.settings.image_channel_remap().is_some() {
+                        Some(Message::from(SettingsMessage::ApplyChannelRemap))
+                    } else {
+                        None
+                    })
+                    .into(),
+            ])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose how the color space is embedded, for apps that manage color such as Photoshop:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                vec!["None", "sRGB chunks", "ICC profile"],
+                match state.settings.image_color_profile() {
+                    ImageColorProfile::None => Some("None"),
+                    ImageColorProfile::Chunks => Some("sRGB chunks"),
+                    ImageColorProfile::Icc => Some("ICC profile"),
+                },
+                move |selected| {
+                    let profile = match selected {
+                        "None" => ImageColorProfile::None,
+                        "ICC profile" => ImageColorProfile::Icc,
+                        _ => ImageColorProfile::Chunks,
+                    };
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_color_profile(profile)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose an alpha conversion, for images that look too dark or too bright:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                vec!["None", "Premultiply", "Unpremultiply"],
+                match state.settings.image_alpha_processing() {
+                    ImageAlphaProcessing::None => Some("None"),
+                    ImageAlphaProcessing::Premultiply => Some("Premultiply"),
+                    ImageAlphaProcessing::Unpremultiply => Some("Unpremultiply"),
+                },
+                move |selected| {
+                    let processing = match selected {
+                        "Premultiply" => ImageAlphaProcessing::Premultiply,
+                        "Unpremultiply" => ImageAlphaProcessing::Unpremultiply,
+                        _ => ImageAlphaProcessing::None,
+                    };
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_alpha_processing(processing)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the memory limit for images being decoded at once while exporting:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::slider(
+                    512..=16384,
+                    state.settings.image_decode_budget(),
+                    move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_image_decode_budget(value)),
+                        )
+                    },
+                )
+                .width(400.0)
+                .step(512u32)
+                .into(),
+                text(format!("{} MB", state.settings.image_decode_budget()))
+                    .width(100.0)
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose which mipmaps to export, only the highest mip gives smaller files:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                vec!["Source", "Highest mip only", "Generate (DDS)"],
+                match state.settings.image_mipmap_export() {
+                    ImageMipmapExport::Source => Some("Source"),
+                    ImageMipmapExport::HighestOnly => Some("Highest mip only"),
+                    ImageMipmapExport::Generate => Some("Generate (DDS)"),
+                },
+                move |selected| {
+                    let export = match selected {
+                        "Highest mip only" => ImageMipmapExport::HighestOnly,
+                        "Generate (DDS)" => ImageMipmapExport::Generate,
+                        _ => ImageMipmapExport::Source,
+                    };
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_mipmap_export(export)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose the block compression and quality used for dds images:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::pick_list(
+                    vec!["None", "BC1", "BC3", "BC7"],
+                    match state.settings.image_compression() {
+                        ImageCompression::None => Some("None"),
+                        ImageCompression::Bc1 => Some("BC1"),
+                        ImageCompression::Bc3 => Some("BC3"),
+                        ImageCompression::Bc7 => Some("BC7"),
+                    },
+                    move |selected| {
+                        let compression = match selected {
+                            "BC1" => ImageCompression::Bc1,
+                            "BC3" => ImageCompression::Bc3,
+                            "BC7" => ImageCompression::Bc7,
+                            _ => ImageCompression::None,
+                        };
+
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_image_compression(compression)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(150.0))
+                .into(),
+                widgets::pick_list(
+                    vec!["Fast", "Normal", "High"],
+                    match state.settings.image_compression_quality() {
+                        ImageCompressionQuality::Fast => Some("Fast"),
+                        ImageCompressionQuality::Normal => Some("Normal"),
+                        ImageCompressionQuality::High => Some("High"),
+                    },
+                    move |selected| {
+                        let quality = match selected {
+                            "Fast" => ImageCompressionQuality::Fast,
+                            "High" => ImageCompressionQuality::High,
+                            _ => ImageCompressionQuality::Normal,
+                        };
+
+                        save_message(state.settings.update(|settings| {
+                            settings.set_image_compression_quality(quality)
+                        }))
+                    },
+                )
+                .width(Length::Fixed(150.0))
+                .into(),
+            ])
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose which frames of image arrays and flipbooks to export:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                vec!["All", "Previewed", "Range"],
+                match state.settings.image_frame_export() {
+                    ImageFrameExport::All => Some("All"),
+                    ImageFrameExport::Previewed => Some("Previewed"),
+                    ImageFrameExport::Range => Some("Range"),
+                },
+                move |selected| {
+                    let export = match selected {
+                        "Previewed" => ImageFrameExport::Previewed,
+                        "Range" => ImageFrameExport::Range,
+                        _ => ImageFrameExport::All,
+                    };
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_frame_export(export)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .into(),
+        ]);
+
+        if state.settings.image_frame_export() == ImageFrameExport::Range {
+            let (first, last) = state.settings.image_frame_range();
+
+            settings = settings.extend([
+                vertical_space().height(0.0).into(),
+                row([
+                    widgets::slider(0..=255, first, move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_image_frame_range(value, last)),
+                        )
+                    })
+                    .width(400.0)
+                    .into(),
+                    text(format!("First frame: {}", first))
+                        .width(150.0)
+                        .color(palette::TEXT_COLOR_SECONDARY)
+                        .into(),
+                ])
+                .width(Length::Shrink)
+                .spacing(8.0)
+                .into(),
+                row([
+                    widgets::slider(0..=255, last, move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_image_frame_range(first, value)),
+                        )
+                    })
+                    .width(400.0)
+                    .into(),
+                    text(format!("Last frame: {}", last))
+                        .width(150.0)
+                        .color(palette::TEXT_COLOR_SECONDARY)
+                        .into(),
+                ])
+                .width(Length::Shrink)
+                .spacing(8.0)
+                .into(),
+            ]);
+        }
+
+        settings = settings.extend([
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to export image arrays and flipbooks as a sprite sheet:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Export sprite sheets", state.settings.image_sprite_sheet())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_image_sprite_sheet(value)),
+                    )
+                })
+                .into(),
+        ]);
+
+        if state.settings.image_sprite_sheet() {
+            let columns = state.settings.image_sprite_sheet_columns();
+
+            settings = settings.extend([
+                vertical_space().height(0.0).into(),
+                row([
+                    widgets::slider(1..=64, columns, move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_image_sprite_sheet_columns(value)),
+                        )
+                    })
+                    .width(400.0)
+                    .into(),
+                    text(format!("Columns: {}", columns))
+                        .width(150.0)
+                        .color(palette::TEXT_COLOR_SECONDARY)
+                        .into(),
+                ])
+                .width(Length::Shrink)
+                .spacing(8.0)
+                .into(),
+            ]);
+        }
+
+        #[cfg(feature = "normal-maps-convertible")]
+        {
+            use crate::ImageNormalMapProcessing;
+
+            settings =
+                settings.extend([
+                    vertical_space().height(2.0).into(),
+                    text("Choose a normal map conversion method:")
+                        .color(palette::TEXT_COLOR_SECONDARY)
+                        .into(),
+                    vertical_space().height(0.0).into(),
+                    widgets::pick_list(
+                        vec!["None", "OpenGL", "DirectX"],
+                        match state.settings.image_normal_map_processing() {
+                            ImageNormalMapProcessing::None => Some("None"),
+                            ImageNormalMapProcessing::OpenGl => Some("OpenGL"),
+                            ImageNormalMapProcessing::DirectX => Some("DirectX"),
+                        },
+                        move |selected| {
+                            let format = match selected {
+                                "None" => ImageNormalMapProcessing::None,
+                                "OpenGL" => ImageNormalMapProcessing::OpenGl,
+                                "DirectX" => ImageNormalMapProcessing::DirectX,
+                                _ => ImageNormalMapProcessing::None,
+                            };
+
+                            save_message(state.settings.update(|settings| {
+                                settings.set_image_normal_map_processing(format)
+                            }))
+                        },
+                    )
+                    .width(Length::Fixed(150.0))
+                    .into(),
+                    vertical_space().height(4.0).into(),
+                ]);
+        }
+
+        #[cfg(not(feature = "normal-maps-convertible"))]
+        {
+            settings = settings.push(vertical_space().height(4.0));
+        }
+
+        #[cfg(feature = "animations")]
+        {
+            use porter_animation::AnimationFileType;
+
+            let anim_formats = state.settings.anim_file_types();
+            let anim_format_enabled = |format: AnimationFileType| anim_formats.contains(&format);
+
+            settings = settings.extend([
+                text("Settings - Animations")
+                    .size(20.0)
+                    .color(palette::TEXT_COLOR_DEFAULT)
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose what animation file types to export to:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox("Cast", anim_format_enabled(AnimationFileType::Cast))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_anim_file_type(AnimationFileType::Cast, value)
+                        }))
+                    })
+                    .into(),
+                vertical_space().height(4.0).into(),
+            ]);
+        }
+
+        #[cfg(all(feature = "sounds", feature = "sounds-convertible"))]
+        {
+            use porter_audio::AudioFileType;
+
+            use crate::TranscriptFileType;
+
+            let audio_formats = state.settings.audio_file_types();
+            let audio_format_enabled = |format: AudioFileType| audio_formats.contains(&format);
+
+            let transcripts = state.settings.transcript_file_types();
+            let transcript_enabled =
+                |file_type: TranscriptFileType| transcripts.contains(&file_type);
+
+            settings = settings.extend([
+                text("Settings - Audio")
+                    .size(20.0)
+                    .color(palette::TEXT_COLOR_DEFAULT)
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose what audio file types to export to:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox("Wav", audio_format_enabled(AudioFileType::Wav))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_audio_file_type(AudioFileType::Wav, value)
+                        }))
+                    })
+                    .into(),
+                widgets::checkbox("Flac", audio_format_enabled(AudioFileType::Flac))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_audio_file_type(AudioFileType::Flac, value)
+                        }))
+                    })
+                    .into(),
+                widgets::checkbox("Ogg", audio_format_enabled(AudioFileType::Ogg))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_audio_file_type(AudioFileType::Ogg, value)
+                        }))
+                    })
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose what transcript sidecar file types to export alongside dialogue:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox("Txt", transcript_enabled(TranscriptFileType::Txt))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_transcript_file_type(TranscriptFileType::Txt, value)
+                        }))
+                    })
+                    .into(),
+                widgets::checkbox("Json", transcript_enabled(TranscriptFileType::Json))
+                    .on_toggle(move |value| {
+                        save_message(state.settings.update(|settings| {
+                            settings.set_transcript_file_type(TranscriptFileType::Json, value)
+                        }))
+                    })
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose whether or not to export every language of localized sounds:")
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                widgets::checkbox("Export all languages", state.settings.export_all_variants())
+                    .on_toggle(move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_export_all_variants(value)),
+                        )
+                    })
+                    .into(),
+                vertical_space().height(4.0).into(),
+            ]);
+        }
+
+        settings = settings.extend([
+            text("Settings - Preview")
+                .size(20.0)
+                .color(palette::TEXT_COLOR_DEFAULT)
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Change the preview control scheme:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                vec!["Simple", "Autodesk Maya", "Blender"],
+                match state.settings.preview_controls() {
                     PreviewControlScheme::Simple => Some("Simple"),
                     PreviewControlScheme::Maya => Some("Autodesk Maya"),
                     PreviewControlScheme::Blender => Some("Blender"),
@@ -528,6 +1232,36 @@ impl Settings {
             .width(Length::Fixed(150.0))
             .into(),
             vertical_space().height(2.0).into(),
+            text("Choose the GPU used for previews and conversions (requires a restart):")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                self.gpu_adapters.clone(),
+                Some(match state.settings.gpu_adapter() {
+                    GPUAdapterPreference::HighPerformance => String::from(GPU_ADAPTER_AUTOMATIC),
+                    GPUAdapterPreference::LowPower => String::from(GPU_ADAPTER_LOW_POWER),
+                    GPUAdapterPreference::Software => String::from(GPU_ADAPTER_SOFTWARE),
+                    GPUAdapterPreference::Named(name) => name,
+                }),
+                move |selected| {
+                    let adapter = match selected.as_str() {
+                        GPU_ADAPTER_AUTOMATIC => GPUAdapterPreference::HighPerformance,
+                        GPU_ADAPTER_LOW_POWER => GPUAdapterPreference::LowPower,
+                        GPU_ADAPTER_SOFTWARE => GPUAdapterPreference::Software,
+                        _ => GPUAdapterPreference::Named(selected),
+                    };
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_gpu_adapter(adapter)),
+                    )
+                },
+            )
+            .width(Length::Fixed(300.0))
+            .into(),
+            vertical_space().height(2.0).into(),
             text("Choose whether or not to open preview in a separate window:")
                 .color(palette::TEXT_COLOR_SECONDARY)
                 .into(),
@@ -556,6 +1290,32 @@ impl Settings {
                 })
                 .into(),
             vertical_space().height(2.0).into(),
+            text("Choose the preview background, a checkerboard shows transparent parts of images:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::pick_list(
+                PreviewBackground::PRESETS
+                    .iter()
+                    .map(|background| background.label())
+                    .collect::<Vec<_>>(),
+                Some(state.settings.preview_background().label()),
+                move |selected| {
+                    let background = PreviewBackground::PRESETS
+                        .into_iter()
+                        .find(|background| background.label() == selected)
+                        .unwrap_or_default();
+
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_preview_background(background)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .into(),
+            vertical_space().height(2.0).into(),
             text("Set the preview far clip distance (May impact performance):")
                 .color(palette::TEXT_COLOR_SECONDARY)
                 .into(),
@@ -569,9 +1329,95 @@ impl Settings {
                     )
                 })
                 .width(400.0)
-                .step(10000u32)
+                .step(10000u32)
+                .into(),
+                text(state.settings.far_clip().to_string())
+                    .width(100.0)
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the preview near clip distance (Automatic fits the size of the model):")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Automatic near clip", state.settings.near_clip().is_none())
+                .on_toggle(move |value| {
+                    save_message(state.settings.update(|settings| {
+                        settings.set_near_clip(if value { None } else { Some(0.1) })
+                    }))
+                })
+                .into(),
+            row([
+                widgets::slider(
+                    0.01..=10.0,
+                    state.settings.near_clip().unwrap_or(0.1),
+                    move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_near_clip(Some(value))),
+                        )
+                    },
+                )
+                .width(400.0)
+                .step(0.01f32)
+                .into(),
+                text(match state.settings.near_clip() {
+                    Some(near_clip) => format!("{near_clip:.2}"),
+                    None => String::from("Automatic"),
+                })
+                .width(100.0)
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the preview field of view in degrees:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::slider(30..=120, state.settings.fov(), move |value| {
+                    save_message(state.settings.update(|settings| settings.set_fov(value)))
+                })
+                .width(400.0)
+                .step(1u32)
+                .into(),
+                text(state.settings.fov().to_string())
+                    .width(100.0)
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the memory limit for previewing an image, larger images preview a smaller mip:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::slider(
+                    64..=2048,
+                    state.settings.preview_image_budget(),
+                    move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_preview_image_budget(value)),
+                        )
+                    },
+                )
+                .width(400.0)
+                .step(64u32)
                 .into(),
-                text(state.settings.far_clip().to_string())
+                text(format!("{} MB", state.settings.preview_image_budget()))
                     .width(100.0)
                     .color(palette::TEXT_COLOR_SECONDARY)
                     .into(),
@@ -645,6 +1491,164 @@ impl Settings {
         }
 
         settings = settings.extend([
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to write a manifest of exported assets:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Write export manifest", state.settings.export_manifest())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_export_manifest(value)),
+                    )
+                })
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to generate blender import scripts for exported assets:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox(
+                "Write blender import scripts",
+                state.settings.export_blender_script(),
+            )
+            .on_toggle(move |value| {
+                save_message(
+                    state
+                        .settings
+                        .update(|settings| settings.set_export_blender_script(value)),
+                )
+            })
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not cast files use stable hashes, so repeated exports are identical:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox(
+                "Use stable cast hashes",
+                state.settings.cast_stable_hashes(),
+            )
+            .on_toggle(move |value| {
+                save_message(
+                    state
+                        .settings
+                        .update(|settings| settings.set_cast_stable_hashes(value)),
+                )
+            })
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to compress cast files, this requires an importer that supports compression:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Compress cast files", state.settings.cast_compression())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_cast_compression(value)),
+                    )
+                })
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to keep local usage statistics, shown on the about page and never sent anywhere:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            widgets::checkbox("Keep usage statistics", state.settings.usage_statistics())
+                .on_toggle(move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_usage_statistics(value)),
+                    )
+                })
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the number of export worker threads:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::slider(0..=64, state.settings.export_threads(), move |value| {
+                    save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.set_export_threads(value)),
+                    )
+                })
+                .width(400.0)
+                .into(),
+                text(format_thread_count(state.settings.export_threads()))
+                    .width(100.0)
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the maximum number of files written at once, lower this for network or USB drives:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                widgets::slider(
+                    0..=64,
+                    state.settings.export_concurrent_writes(),
+                    move |value| {
+                        save_message(
+                            state
+                                .settings
+                                .update(|settings| settings.set_export_concurrent_writes(value)),
+                        )
+                    },
+                )
+                .width(400.0)
+                .into(),
+                text(format_thread_count(state.settings.export_concurrent_writes()))
+                    .width(100.0)
+                    .color(palette::TEXT_COLOR_SECONDARY)
+                    .into(),
+            ])
+            .width(Length::Shrink)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose name databases used to resolve hashed asset names:")
+                .color(palette::TEXT_COLOR_SECONDARY)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row(vec![
+                widgets::text_input(
+                    "No name databases loaded",
+                    &format_name_databases(state.settings.name_databases()),
+                )
+                .on_input(|_| Message::Noop)
+                .width(500.0)
+                .into(),
+                widgets::button("Add")
+                    .on_press(Message::from(SettingsMessage::PickNameDatabase))
+                    .into(),
+                widgets::button("Reload")
+                    .on_press_maybe(
+                        (!state.settings.name_databases().is_empty())
+                            .then_some(Message::NamesReload),
+                    )
+                    .into(),
+                widgets::button("Clear")
+                    .on_press(save_message(
+                        state
+                            .settings
+                            .update(|settings| settings.clear_name_databases()),
+                    ))
+                    .into(),
+            ])
+            .spacing(4.0)
+            .into(),
             vertical_space().height(2.0).into(),
             text("Troubleshooting options:")
                 .color(palette::TEXT_COLOR_DEFAULT)
@@ -675,16 +1679,165 @@ impl Settings {
         .into()
     }
 
-    /// Saves settings to state and disk.
+    /// Handles rendering the row color rules, and the inputs to add a new rule.
+    fn view_row_color_rules<'a>(&'a self, state: &'a AppState) -> Element<'a, Message> {
+        let rules = state.settings.row_color_rules();
+
+        let mut result: Column<_> = Column::with_capacity(rules.len() + 1);
+
+        for (index, rule) in rules.iter().enumerate() {
+            result = result.push(
+                row([
+                    text(format!(
+                        "{} contains \"{}\" ({})",
+                        rule.column, rule.value, rule.color
+                    ))
+                    .width(400.0)
+                    .color(rule.color.color())
+                    .into(),
+                    widgets::button("Remove")
+                        .on_press(Message::from(SettingsMessage::Save(
+                            state
+                                .settings
+                                .update(|settings| settings.remove_row_color_rule(index)),
+                        )))
+                        .into(),
+                ])
+                .spacing(4.0)
+                .align_y(Alignment::Center),
+            );
+        }
+
+        let columns: Vec<String> = state
+            .asset_columns
+            .iter()
+            .map(|column| column.header.to_string())
+            .collect();
+
+        result = result.push(
+            row([
+                widgets::pick_list(columns, self.row_color_column.clone(), |column| {
+                    Message::from(SettingsMessage::RowColorColumn(column))
+                })
+                .placeholder("Column")
+                .width(150.0)
+                .into(),
+                widgets::text_input("Contains", &self.row_color_value)
+                    .on_input(|value| Message::from(SettingsMessage::RowColorValue(value)))
+                    .width(200.0)
+                    .into(),
+                widgets::pick_list(RowColor::ALL, Some(self.row_color), |color| {
+                    Message::from(SettingsMessage::RowColorPick(color))
+                })
+                .width(100.0)
+                .into(),
+                widgets::button("Add")
+                    .on_press_maybe(
+                        (self.row_color_column.is_some() && !self.row_color_value.is_empty())
+                            .then_some(Message::from(SettingsMessage::AddRowColorRule)),
+                    )
+                    .into(),
+            ])
+            .spacing(4.0)
+            .align_y(Alignment::Center),
+        );
+
+        result.spacing(4.0).into()
+    }
+
+    /// Handles rendering the folder inputs for the type folders layout.
+    fn view_export_folders<'a>(&'a self, state: &'a AppState) -> Element<'a, Message> {
+        if state.settings.export_layout() != ExportLayout::TypeFolders {
+            return Column::new().into();
+        }
+
+        let export_folders = self
+            .export_folders
+            .as_ref()
+            .unwrap_or_else(|| state.settings.export_folders());
+
+        let mut folders: Column<_> = Column::with_capacity(ExportAssetType::ALL.len() + 2);
+
+        folders = folders.push(vertical_space().height(4.0));
+
+        for asset_type in ExportAssetType::ALL {
+            folders = folders.push(
+                row([
+                    text(asset_type.name())
+                        .width(100.0)
+                        .color(palette::TEXT_COLOR_SECONDARY)
+                        .into(),
+                    widgets::text_input(
+                        "Exported files directory",
+                        export_folders.folder(asset_type),
+                    )
+                    .on_input(move |input| {
+                        Message::from(SettingsMessage::ExportFolderInput(asset_type, input))
+                    })
+                    .on_submit(Message::from(SettingsMessage::ApplyExportFolders))
+                    .width(300.0)
+                    .into(),
+                ])
+                .spacing(4.0)
+                .align_y(Alignment::Center),
+            );
+        }
+
+        folders
+            .push(
+                widgets::button("Apply").on_press_maybe(
+                    self.export_folders
+                        .is_some()
+                        .then_some(Message::from(SettingsMessage::ApplyExportFolders)),
+                ),
+            )
+            .spacing(4.0)
+            .into()
+    }
+
+    /// Saves settings to state and disk, recording the previous settings so the change can be undone.
     fn on_save(&mut self, state: &mut AppState, settings: crate::Settings) -> Task<Message> {
+        if self.session.is_none() {
+            self.session = Some(state.settings.clone());
+        }
+
+        if self.undo.len() >= UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+
+        self.undo.push(state.settings.clone());
+        self.redo.clear();
+
+        self.apply(state, settings)
+    }
+
+    /// Applies settings to state and saves them to disk.
+    fn apply(&mut self, state: &mut AppState, settings: crate::Settings) -> Task<Message> {
         if !state.reload_required {
             state.reload_required = state.settings.reload_required(&settings);
         }
 
+        let names_changed = state.settings.name_databases() != settings.name_databases();
+
+        if settings.usage_statistics() && !state.settings.usage_statistics() {
+            state.usage_statistics = UsageStatistics::load(state.name);
+        }
+
         state.settings = settings;
         state.settings.save(state.name);
 
+        widgets::set_reduced_motion(state.settings.reduced_motion());
+
         self.custom_scale = state.settings.custom_scale().map(format_custom_scale);
+        self.channel_remap = None;
+        self.export_folders = None;
+
+        if names_changed {
+            return Task::batch([
+                Task::done(Message::from(PreviewMessage::SyncSettings)),
+                Task::done(Message::NamesReload),
+            ]);
+        }
 
         Task::done(Message::from(PreviewMessage::SyncSettings))
     }
@@ -694,6 +1847,11 @@ impl Settings {
         Task::done(Message::from(MainMessage::PickExportFolder))
     }
 
+    /// Allows the user to pick a new name database.
+    fn on_pick_name_database(&mut self, _: &mut AppState) -> Task<Message> {
+        Task::done(Message::from(MainMessage::PickNameDatabase))
+    }
+
     /// Opens the export folder.
     fn on_open_export_folder(&mut self, state: &mut AppState) -> Task<Message> {
         system::open_folder(state.settings.output_directory());
@@ -753,9 +1911,170 @@ impl Settings {
 
         Task::none()
     }
+
+    /// Applies the user provided channel remap.
+    fn on_apply_channel_remap(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(channel_remap) = self.channel_remap.take() else {
+            return Task::none();
+        };
+
+        let Ok(remap) = channel_remap.parse::<ImageChannelRemap>() else {
+            return Task::done(Message::from(MainMessage::Warning(String::from(
+                "Channel remap must be a list of channels and names, such as: R->AO, G->Roughness!",
+            ))));
+        };
+
+        self.on_save(
+            state,
+            state
+                .settings
+                .update(|settings| settings.set_image_channel_remap(Some(remap))),
+        )
+    }
+
+    /// Occurs when the user enters a channel remap.
+    fn on_channel_remap_input(&mut self, _: &mut AppState, input: String) -> Task<Message> {
+        self.channel_remap = Some(input);
+
+        Task::none()
+    }
+
+    /// Applies the user provided export folders.
+    fn on_apply_export_folders(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(export_folders) = self.export_folders.take() else {
+            return Task::none();
+        };
+
+        self.on_save(
+            state,
+            state
+                .settings
+                .update(|settings| settings.set_export_folders(export_folders)),
+        )
+    }
+
+    /// Occurs when the user enters an export folder for an asset type.
+    fn on_export_folder_input(
+        &mut self,
+        state: &mut AppState,
+        asset_type: ExportAssetType,
+        input: String,
+    ) -> Task<Message> {
+        self.export_folders
+            .get_or_insert_with(|| state.settings.export_folders().clone())
+            .set_folder(asset_type, input);
+
+        Task::none()
+    }
+
+    /// Occurs when the user picks the column of a new row color rule.
+    fn on_row_color_column(&mut self, column: String) -> Task<Message> {
+        self.row_color_column = Some(column);
+
+        Task::none()
+    }
+
+    /// Occurs when the user enters the value of a new row color rule.
+    fn on_row_color_value(&mut self, value: String) -> Task<Message> {
+        self.row_color_value = value;
+
+        Task::none()
+    }
+
+    /// Occurs when the user picks the color of a new row color rule.
+    fn on_row_color_pick(&mut self, color: RowColor) -> Task<Message> {
+        self.row_color = color;
+
+        Task::none()
+    }
+
+    /// Occurs when the user adds a new row color rule.
+    fn on_add_row_color_rule(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(column) = self.row_color_column.clone() else {
+            return Task::none();
+        };
+
+        if self.row_color_value.is_empty() {
+            return Task::none();
+        }
+
+        let rule = RowColorRule::new(
+            column,
+            std::mem::take(&mut self.row_color_value),
+            self.row_color,
+        );
+
+        self.on_save(
+            state,
+            state
+                .settings
+                .update(|settings| settings.add_row_color_rule(rule)),
+        )
+    }
+
+    /// Occurs when the user undoes the last settings change.
+    fn on_undo(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(settings) = self.undo.pop() else {
+            return Task::none();
+        };
+
+        self.redo.push(state.settings.clone());
+
+        self.apply(state, settings)
+    }
+
+    /// Occurs when the user redoes the last undone settings change.
+    fn on_redo(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(settings) = self.redo.pop() else {
+            return Task::none();
+        };
+
+        self.undo.push(state.settings.clone());
+
+        self.apply(state, settings)
+    }
+
+    /// Occurs when the user reverts every settings change made during this session.
+    fn on_revert_session(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some(settings) = self.session.clone() else {
+            return Task::none();
+        };
+
+        self.on_save(state, settings)
+    }
+
+    /// Occurs when the user resets the settings to defaults.
+    fn on_reset_defaults(&mut self, state: &mut AppState) -> Task<Message> {
+        self.on_save(
+            state,
+            state.settings.update(|settings| settings.reset_defaults()),
+        )
+    }
 }
 
 /// Formats a custom scale factor.
 fn format_custom_scale(scale: f32) -> String {
     format!("{scale:?}")
 }
+
+/// Formats a list of name databases for display.
+fn format_name_databases(name_databases: &[PathBuf]) -> String {
+    name_databases
+        .iter()
+        .map(|path| {
+            path.file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats a thread or file count for display, where zero is automatic.
+fn format_thread_count(count: u32) -> String {
+    match count {
+        0 => String::from("Automatic"),
+        count => count.to_string(),
+    }
+}
@@ -25,6 +25,7 @@ use porter_math::Vector3;
 
 use crate::Message;
 use crate::SplashMessage;
+use crate::widgets;
 
 /// The size of the cube in pixels.
 const CUBE_SIZE: f32 = 50.0;
@@ -76,6 +77,10 @@ impl Program<Message> for Splash {
                 return Some(Action::publish(Message::from(SplashMessage::Close)));
             }
 
+            if widgets::reduced_motion() {
+                return Some(Action::request_redraw_at(state.started + SPLASH_DURATION));
+            }
+
             state.last = *now;
 
             Some(Action::request_redraw_at(
@@ -1,5 +1,3 @@
-use std::cmp::Ordering;
-
 use iced::border::Radius;
 use iced::border::rounded;
 
@@ -11,6 +9,7 @@ use iced::widget::horizontal_space;
 use iced::widget::mouse_area;
 use iced::widget::scrollable;
 use iced::widget::text;
+use iced::widget::tooltip::Position;
 use iced::widget::vertical_space;
 
 use iced::Alignment;
@@ -23,10 +22,12 @@ use iced::Task;
 use iced::Theme;
 
 use crate::AppState;
+use crate::ExportJobState;
 use crate::Message;
 use crate::Sort;
 use crate::fonts;
 use crate::palette;
+use crate::row_color;
 use crate::widgets;
 
 use super::PreviewMessage;
@@ -68,6 +69,9 @@ pub enum VirtualListMessage {
     MoveDown,
     PageUp,
     PageDown,
+    ExtendUp,
+    ExtendDown,
+    SelectAll,
 }
 
 impl VirtualList {
@@ -99,6 +103,9 @@ impl VirtualList {
             MoveDown => self.on_move_down(state),
             PageUp => self.on_page_up(state),
             PageDown => self.on_page_down(state),
+            ExtendUp => self.on_extend_up(state),
+            ExtendDown => self.on_extend_down(state),
+            SelectAll => self.on_select_all(state),
         }
     }
 
@@ -149,29 +156,55 @@ impl VirtualList {
 
             rows = rows.push(top_gap);
 
+            let rules = state.settings.row_color_rules();
+
             for index in item_range {
                 let mut columns: Row<_> = Row::with_capacity(state.asset_columns.len());
                 let selected = state.assets_selected.contains(&index);
 
-                for (column, (value, color)) in state
-                    .asset_columns
-                    .iter()
-                    .zip(state.asset_manager.assets_info(index))
-                {
+                let info: Vec<_> = state
+                    .asset_manager
+                    .assets_column_values(index)
+                    .into_iter()
+                    .map(|(value, color)| (value.to_string(), color))
+                    .collect();
+                let rule_color = row_color(rules, &state.asset_columns, &info);
+                let job = state.export_queue.state(index);
+
+                for (column, (value, color)) in state.asset_columns.iter().zip(info) {
+                    let job = job.as_ref().filter(|_| column.header == "Status");
+
+                    let (value, color) = match job {
+                        Some(job) => (job.to_string(), Some(job.color())),
+                        None => (value, color),
+                    };
+
                     let color = if selected {
                         palette::TEXT_COLOR_DEFAULT
+                    } else if job.is_some() {
+                        color.unwrap_or(palette::TEXT_COLOR_DEFAULT)
+                    } else if let Some(rule_color) = rule_color {
+                        rule_color
                     } else {
                         color.unwrap_or_else(|| column.color.unwrap_or(palette::TEXT_COLOR_DEFAULT))
                     };
 
-                    columns = columns.push(
-                        widgets::text_wrap(value)
-                            .width(column.width.clamp(COLUMN_MIN, COLUMN_MAX))
-                            .height(Length::Fill)
-                            .padding(Padding::ZERO.left(4.0))
-                            .align_y(Alignment::Center)
-                            .color(color),
-                    );
+                    let cell = widgets::text_wrap(value)
+                        .width(column.width.clamp(COLUMN_MIN, COLUMN_MAX))
+                        .height(Length::Fill)
+                        .padding(Padding::ZERO.left(4.0))
+                        .align_y(Alignment::Center)
+                        .color(color);
+
+                    if let Some(ExportJobState::Error(report)) = job {
+                        columns = columns.push(widgets::tooltip(
+                            cell,
+                            text(report.to_string()),
+                            Position::Bottom,
+                        ));
+                    } else {
+                        columns = columns.push(cell);
+                    }
                 }
 
                 rows = rows.push(
@@ -329,21 +362,27 @@ impl VirtualList {
 
         self.viewport = Some(viewport);
 
-        scrollable::scroll_to(
-            self.header_id.clone(),
-            scrollable::AbsoluteOffset {
-                x: offsets.x,
-                y: 0.0,
-            },
-        )
+        Task::batch([
+            scrollable::scroll_to(
+                self.header_id.clone(),
+                scrollable::AbsoluteOffset {
+                    x: offsets.x,
+                    y: 0.0,
+                },
+            ),
+            Task::done(Message::AssetsFetch),
+        ])
     }
 
     /// Occurs when the scroll should reset.
     fn on_scroll_reset(&mut self, _: &mut AppState) -> Task<Message> {
-        scrollable::scroll_to(
-            self.scroll_id.clone(),
-            scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
-        )
+        Task::batch([
+            scrollable::scroll_to(
+                self.scroll_id.clone(),
+                scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+            ),
+            Task::done(Message::AssetsFetch),
+        ])
     }
 
     /// Occurs when a row has been clicked.
@@ -354,33 +393,27 @@ impl VirtualList {
             } else {
                 state.assets_selected.insert(index);
             }
+
+            state.assets_anchor = Some(index);
         } else if state.modifier_keys.shift() {
-            if let Some(first) = state.assets_selected.first() {
-                match index.cmp(first) {
-                    Ordering::Less => {
-                        for i in index..*first {
-                            state.assets_selected.insert(i);
-                        }
-                    }
-                    Ordering::Greater => {
-                        for i in *first..=index {
-                            state.assets_selected.insert(i);
-                        }
-                    }
-                    Ordering::Equal => {
-                        state.assets_selected.insert(index);
-                    }
+            match state
+                .assets_anchor
+                .or(state.assets_selected.first().copied())
+            {
+                Some(anchor) => select_range(state, anchor, index),
+                None => {
+                    state.assets_selected.insert(index);
+                    state.assets_anchor = Some(index);
                 }
-            } else if state.assets_selected.contains(&index) {
-                state.assets_selected.remove(&index);
-            } else {
-                state.assets_selected.insert(index);
             }
         } else if state.assets_selected.len() == 1 && state.assets_selected.contains(&index) {
+            state.assets_anchor = Some(index);
+
             return Task::none();
         } else {
             state.assets_selected.clear();
             state.assets_selected.insert(index);
+            state.assets_anchor = Some(index);
 
             return Task::done(Message::from(PreviewMessage::Request));
         }
@@ -392,6 +425,7 @@ impl VirtualList {
     fn on_double_click(&mut self, state: &mut AppState, index: usize) -> Task<Message> {
         state.assets_selected.clear();
         state.assets_selected.insert(index);
+        state.assets_anchor = Some(index);
 
         Task::done(Message::ExportSelected)
     }
@@ -440,6 +474,7 @@ impl VirtualList {
         if index > 0 && state.assets_selected.len() == 1 {
             state.assets_selected.clear();
             state.assets_selected.insert(index - 1);
+            state.assets_anchor = Some(index - 1);
 
             let Some(viewport) = self.viewport else {
                 return Task::done(Message::from(PreviewMessage::Request));
@@ -466,6 +501,7 @@ impl VirtualList {
         {
             state.assets_selected.clear();
             state.assets_selected.insert(index + 1);
+            state.assets_anchor = Some(index + 1);
 
             let Some(viewport) = self.viewport else {
                 return Task::done(Message::from(PreviewMessage::Request));
@@ -508,6 +544,59 @@ impl VirtualList {
         )
     }
 
+    /// Occurs when the selection should extend one row up.
+    fn on_extend_up(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some((anchor, end)) = selection_range(state) else {
+            return Task::none();
+        };
+
+        if end == 0 {
+            return Task::none();
+        }
+
+        select_range(state, anchor, end - 1);
+
+        match self.viewport {
+            Some(viewport) => self.on_scroll_into_view(&viewport, end - 1),
+            None => Task::none(),
+        }
+    }
+
+    /// Occurs when the selection should extend one row down.
+    fn on_extend_down(&mut self, state: &mut AppState) -> Task<Message> {
+        let Some((anchor, end)) = selection_range(state) else {
+            return Task::none();
+        };
+
+        if end + 1 >= state.asset_manager.assets_visible() {
+            return Task::none();
+        }
+
+        select_range(state, anchor, end + 1);
+
+        match self.viewport {
+            Some(viewport) => self.on_scroll_into_view(&viewport, end + 1),
+            None => Task::none(),
+        }
+    }
+
+    /// Occurs when every visible row should be selected.
+    fn on_select_all(&mut self, state: &mut AppState) -> Task<Message> {
+        if state.is_busy() {
+            return Task::none();
+        }
+
+        let visible = state.asset_manager.assets_visible();
+
+        state.assets_selected.extend(0..visible);
+
+        if state.assets_anchor.is_none_or(|anchor| anchor >= visible) {
+            state.assets_anchor = (visible > 0).then_some(0);
+        }
+
+        Task::none()
+    }
+
     /// Occurs when we want to scroll an item into view.
     fn on_scroll_into_view(
         &mut self,
@@ -541,6 +630,28 @@ impl VirtualList {
     }
 }
 
+/// Replaces the selection with the rows between the anchor and the given index.
+fn select_range(state: &mut AppState, anchor: usize, index: usize) {
+    state.assets_selected.clear();
+    state
+        .assets_selected
+        .extend(anchor.min(index)..=anchor.max(index));
+    state.assets_anchor = Some(anchor);
+}
+
+/// Gets the anchor of the selection, and the end of the selection opposite to it.
+fn selection_range(state: &AppState) -> Option<(usize, usize)> {
+    let first = *state.assets_selected.first()?;
+    let last = *state.assets_selected.last()?;
+
+    let anchor = state
+        .assets_anchor
+        .filter(|anchor| state.assets_selected.contains(anchor))
+        .unwrap_or(first);
+
+    Some((anchor, if anchor == first { last } else { first }))
+}
+
 /// Style for the list container.
 fn list_container_style(_: &Theme) -> container::Style {
     container::Style {
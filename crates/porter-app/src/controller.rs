@@ -1,10 +1,17 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use iced::futures::channel::mpsc;
 use iced::futures::channel::mpsc::UnboundedSender;
 
+use porter_utils::ErrorReport;
+
 use crate::AssetPreview;
+use crate::ExportAssetType;
+use crate::ExportMode;
 use crate::Message;
+use crate::components::ControlsMessage;
+use crate::components::NameGuessMessage;
 
 /// Control the app from anywhere.
 #[derive(Debug, Clone)]
@@ -39,6 +46,15 @@ impl Controller {
         debug_assert!(result.is_ok());
     }
 
+    /// Requests that files forwarded from another instance be loaded.
+    pub fn load_files_forwarded(&self, files: Vec<PathBuf>) {
+        let result = self
+            .channel
+            .unbounded_send(Message::LoadFilesForwarded(files));
+
+        debug_assert!(result.is_ok());
+    }
+
     /// Notifies the app of a load result.
     pub fn load_update(&self, result: Result<(), String>) {
         let result = self.channel.unbounded_send(Message::LoadUpdate(result));
@@ -46,6 +62,31 @@ impl Controller {
         debug_assert!(result.is_ok());
     }
 
+    /// Notifies the app that the name databases have been reloaded.
+    pub fn names_update(&self, result: Result<(), String>) {
+        let result = self.channel.unbounded_send(Message::NamesUpdate(result));
+
+        debug_assert!(result.is_ok());
+    }
+
+    /// Notifies the app that a name guess has finished.
+    pub fn name_guess_update(&self, result: Option<String>) {
+        let result = self
+            .channel
+            .unbounded_send(Message::from(NameGuessMessage::Result(result)));
+
+        debug_assert!(result.is_ok());
+    }
+
+    /// Notifies the app that a page of assets has been fetched.
+    pub fn assets_fetch_update(&self, page: usize, result: Result<(), String>) {
+        let result = self
+            .channel
+            .unbounded_send(Message::AssetsFetchUpdate(page, result));
+
+        debug_assert!(result.is_ok());
+    }
+
     /// Notifies the app of progress being made during an operation.
     pub fn progress_update(&self, finished: bool, progress: u32) {
         let result = self
@@ -55,14 +96,38 @@ impl Controller {
         debug_assert!(result.is_ok());
     }
 
-    /// Notifies the app of a preview asset being ready.
-    pub fn preview_update(&self, request_id: u64, asset: AssetPreview) {
+    /// Requests the given assets be exported again in the given mode, overwriting any existing files.
+    pub fn export_overwrite(&self, assets: Vec<usize>, mode: ExportMode) {
+        let result = self
+            .channel
+            .unbounded_send(Message::ExportOverwrite(assets, mode));
+
+        debug_assert!(result.is_ok());
+    }
+
+    /// Notifies the app of a preview asset being ready, or the error that occured while loading it.
+    pub fn preview_update(&self, request_id: u64, asset: Result<AssetPreview, ErrorReport>) {
         let result = self
             .channel
             .unbounded_send(Message::PreviewUpdate(request_id, asset));
 
         debug_assert!(result.is_ok());
     }
+
+    /// Notifies the app of the stored size of a selection, per asset type, used to estimate the export size.
+    pub fn export_estimate_update(
+        &self,
+        request_id: u64,
+        sizes: Option<HashMap<ExportAssetType, u64>>,
+    ) {
+        let result = self
+            .channel
+            .unbounded_send(Message::from(ControlsMessage::EstimateUpdate(
+                request_id, sizes,
+            )));
+
+        debug_assert!(result.is_ok());
+    }
 }
 
 impl Default for Controller {
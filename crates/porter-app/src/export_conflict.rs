@@ -0,0 +1,178 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How exported files which already exist on disk are handled.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportConflictPolicy {
+    /// Existing files are replaced.
+    #[default]
+    Overwrite,
+    /// Existing files are kept, and the new file isn't written.
+    Skip,
+    /// The new file is written with a numeric suffix.
+    Rename,
+    /// Existing files are kept, and the user is asked whether to overwrite them once the export finishes.
+    Ask,
+}
+
+impl ExportConflictPolicy {
+    /// Resolves the path a file should be written to, or `None` if the file should not be written.
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Some(path.to_path_buf());
+        }
+
+        match self {
+            Self::Overwrite => Some(path.to_path_buf()),
+            Self::Skip | Self::Ask => None,
+            Self::Rename => Some(rename_conflict(path)),
+        }
+    }
+}
+
+/// The paths reserved by files written during an export.
+#[derive(Debug, Default)]
+struct ReservedPaths {
+    paths: HashSet<PathBuf>,
+    suffixes: HashMap<(usize, PathBuf), usize>,
+}
+
+impl ReservedPaths {
+    /// Whether or not the path exists, or was reserved by another file in this export.
+    fn is_taken(&self, path: &Path) -> bool {
+        self.paths.contains(path) || path.exists()
+    }
+}
+
+/// The paths reserved during an export, and the assets which had conflicting files skipped while asking the user, shared between export threads.
+#[derive(Debug, Clone, Default)]
+pub struct ExportConflicts {
+    assets: Arc<Mutex<BTreeSet<usize>>>,
+    reserved: Arc<Mutex<ReservedPaths>>,
+}
+
+impl ExportConflicts {
+    /// Resolves the paths the files of the given asset should be written to with the given policy, reserving them for this export.
+    ///
+    /// Paths reserved by other files in this export count as existing, so assets exported in parallel never pick the same name.
+    /// Renamed files of one asset share a suffix, such as `name_1.cast` and `name_1.gltf`, including files resolved in later calls.
+    pub fn resolve<P: AsRef<Path>>(
+        &self,
+        policy: ExportConflictPolicy,
+        asset: usize,
+        paths: &[P],
+    ) -> Vec<Option<PathBuf>> {
+        let Ok(mut reserved) = self.reserved.lock() else {
+            return paths.iter().map(|path| policy.resolve(path)).collect();
+        };
+
+        let result: Vec<Option<PathBuf>> = match policy {
+            ExportConflictPolicy::Overwrite => paths
+                .iter()
+                .map(|path| Some(path.as_ref().to_path_buf()))
+                .collect(),
+            ExportConflictPolicy::Skip | ExportConflictPolicy::Ask => paths
+                .iter()
+                .map(|path| {
+                    let path = path.as_ref();
+
+                    (!reserved.is_taken(path)).then(|| path.to_path_buf())
+                })
+                .collect(),
+            ExportConflictPolicy::Rename => {
+                let keys: Vec<(usize, PathBuf)> = paths
+                    .iter()
+                    .map(|path| (asset, path.as_ref().with_extension("")))
+                    .collect();
+
+                let start = keys
+                    .iter()
+                    .filter_map(|key| reserved.suffixes.get(key))
+                    .max()
+                    .copied()
+                    .unwrap_or_default();
+
+                let suffix = (start..)
+                    .find(|index| {
+                        paths
+                            .iter()
+                            .all(|path| !reserved.is_taken(&suffixed(path.as_ref(), *index)))
+                    })
+                    .unwrap_or_default();
+
+                for key in keys {
+                    reserved.suffixes.insert(key, suffix);
+                }
+
+                paths
+                    .iter()
+                    .map(|path| Some(suffixed(path.as_ref(), suffix)))
+                    .collect()
+            }
+        };
+
+        for path in result.iter().flatten() {
+            reserved.paths.insert(path.clone());
+        }
+
+        if policy == ExportConflictPolicy::Ask && result.iter().any(Option::is_none) {
+            self.push(asset);
+        }
+
+        result
+    }
+
+    /// Records that the given asset had a conflicting file skipped.
+    pub fn push(&self, asset: usize) {
+        if let Ok(mut assets) = self.assets.lock() {
+            assets.insert(asset);
+        }
+    }
+
+    /// Takes the assets which had conflicting files skipped, leaving none behind.
+    pub fn take(&self) -> Vec<usize> {
+        self.assets
+            .lock()
+            .map(|mut assets| std::mem::take(&mut *assets).into_iter().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Finds the first path, with a numeric suffix such as `name_1.ext`, that doesn't exist yet.
+pub fn rename_conflict<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+
+    (1..)
+        .map(|index| suffixed(path, index))
+        .find(|path| !path.exists())
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Appends a numeric suffix such as `name_1.ext` to the file name of the path, a suffix of zero keeps the path as is.
+fn suffixed(path: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        return path.to_path_buf();
+    }
+
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}_{}.{}", stem, index, extension.to_string_lossy()),
+        None => format!("{}_{}", stem, index),
+    };
+
+    path.with_file_name(file_name)
+}
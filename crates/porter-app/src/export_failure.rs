@@ -0,0 +1,258 @@
+use std::fmt::Write;
+use std::io::ErrorKind;
+use std::io::Write as IoWrite;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use directories::ProjectDirs;
+
+use porter_utils::ErrorReport;
+use porter_utils::ReportError;
+
+/// The file name written to the output directory to check that it's writable.
+const PREFLIGHT_FILE_NAME: &str = ".porter_write_check";
+
+/// The reason an exported file could not be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExportFailureKind {
+    /// The file or folder is read-only, or protected by the system.
+    PermissionDenied,
+    /// The file is open in another program.
+    FileLocked,
+    /// The path is longer than the system allows.
+    PathTooLong,
+    /// The disk is out of space.
+    DiskFull,
+    /// The asset could not be converted to the export format.
+    Conversion,
+    /// Any other error.
+    Other,
+}
+
+impl ExportFailureKind {
+    /// Classifies the given io error.
+    pub fn classify(error: &std::io::Error) -> Self {
+        // ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION.
+        #[cfg(target_os = "windows")]
+        if matches!(error.raw_os_error(), Some(32 | 33)) {
+            return Self::FileLocked;
+        }
+
+        // ERROR_FILENAME_EXCED_RANGE.
+        #[cfg(target_os = "windows")]
+        if matches!(error.raw_os_error(), Some(206)) {
+            return Self::PathTooLong;
+        }
+
+        // ETXTBSY.
+        #[cfg(not(target_os = "windows"))]
+        if matches!(error.raw_os_error(), Some(26)) {
+            return Self::FileLocked;
+        }
+
+        match error.kind() {
+            ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => Self::PermissionDenied,
+            ErrorKind::ResourceBusy | ErrorKind::ExecutableFileBusy => Self::FileLocked,
+            ErrorKind::InvalidFilename => Self::PathTooLong,
+            ErrorKind::StorageFull | ErrorKind::QuotaExceeded => Self::DiskFull,
+            _ => Self::Other,
+        }
+    }
+
+    /// A short description of the failure.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::PermissionDenied => "Permission denied",
+            Self::FileLocked => "File is locked",
+            Self::PathTooLong => "Path is too long",
+            Self::DiskFull => "Disk is full",
+            Self::Conversion => "Failed to convert",
+            Self::Other => "Failed to write",
+        }
+    }
+
+    /// A suggested fix for the failure.
+    pub const fn suggestion(&self) -> &'static str {
+        match self {
+            Self::PermissionDenied => {
+                "Choose an exported files directory outside of protected folders such as Program Files, or remove the read-only attribute."
+            }
+            Self::FileLocked => {
+                "Close any programs that have the exported files open, such as a model viewer or image editor."
+            }
+            Self::PathTooLong => {
+                "Choose a shorter exported files directory, or enable long path support in Windows."
+            }
+            Self::DiskFull => {
+                "Free up disk space, or choose an exported files directory on another drive."
+            }
+            Self::Conversion => {
+                "The technical details were written to the export log, include them when reporting the problem."
+            }
+            Self::Other => "Check that the exported files directory exists and is accessible.",
+        }
+    }
+}
+
+/// A file which failed to be written during an export.
+#[derive(Debug, Clone)]
+pub struct ExportFailure {
+    /// The display name of the asset.
+    pub asset: String,
+    /// The path which failed to be written.
+    pub path: PathBuf,
+    /// The reason the file could not be written.
+    pub kind: ExportFailureKind,
+    /// The report of the error which caused the failure.
+    pub report: Option<ErrorReport>,
+}
+
+/// The failures which occured during an export, shared between export threads.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFailures {
+    failures: Arc<Mutex<Vec<ExportFailure>>>,
+}
+
+impl ExportFailures {
+    /// Records that a file for the given asset failed to be written.
+    pub fn push<A: Into<String>, P: Into<PathBuf>>(
+        &self,
+        asset: A,
+        path: P,
+        error: &std::io::Error,
+    ) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.push(ExportFailure {
+                asset: asset.into(),
+                path: path.into(),
+                kind: ExportFailureKind::classify(error),
+                report: Some(error.report()),
+            });
+        }
+    }
+
+    /// Records that the given asset failed to convert, with the report of the error.
+    pub fn push_report<A: Into<String>, P: Into<PathBuf>>(
+        &self,
+        asset: A,
+        path: P,
+        report: ErrorReport,
+    ) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.push(ExportFailure {
+                asset: asset.into(),
+                path: path.into(),
+                kind: ExportFailureKind::Conversion,
+                report: Some(report),
+            });
+        }
+    }
+
+    /// Takes the failures recorded so far, leaving none behind.
+    pub fn take(&self) -> Vec<ExportFailure> {
+        self.failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default()
+    }
+}
+
+/// Builds a summary of the given failures, grouped by kind with a suggested fix for each.
+pub fn export_failures_summary(failures: &[ExportFailure]) -> String {
+    let mut kinds: Vec<ExportFailureKind> = failures.iter().map(|failure| failure.kind).collect();
+
+    kinds.sort();
+    kinds.dedup();
+
+    let mut summary = format!("{} file(s) failed to export.\n", failures.len());
+
+    for kind in kinds {
+        let mut assets = failures.iter().filter(|failure| failure.kind == kind);
+
+        let _ = write!(summary, "\n{}: ", kind.description());
+
+        for (index, failure) in assets.by_ref().take(5).enumerate() {
+            if index > 0 {
+                summary.push_str(", ");
+            }
+
+            summary.push_str(&failure.asset);
+
+            if kind == ExportFailureKind::Conversion
+                && let Some(report) = &failure.report
+            {
+                let _ = write!(summary, " ({})", report);
+            }
+        }
+
+        let remaining = assets.count();
+
+        if remaining > 0 {
+            let _ = write!(summary, ", and {} more", remaining);
+        }
+
+        let _ = writeln!(summary, "\n{}", kind.suggestion());
+    }
+
+    summary
+}
+
+/// Appends the technical detail of the given failures to the export log, next to the settings.
+pub fn write_export_failures_log(name: &str, failures: &[ExportFailure]) {
+    let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+        return;
+    };
+
+    let mut log = String::new();
+
+    for failure in failures {
+        let _ = write!(
+            log,
+            "{}: {} [{}]",
+            failure.asset,
+            failure.path.display(),
+            failure.kind.description()
+        );
+
+        if let Some(report) = &failure.report {
+            let _ = write!(log, " {}: {}", report.code, report.detail);
+        }
+
+        log.push('\n');
+    }
+
+    let _ = std::fs::create_dir_all(project_directory.config_dir());
+
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(
+        project_directory
+            .config_dir()
+            .join(name.to_lowercase())
+            .with_extension("log"),
+    );
+
+    if let Ok(mut file) = file {
+        let _ = file.write_all(log.as_bytes());
+    }
+}
+
+/// Checks that the given output directory can be created and written to before an export starts.
+pub fn preflight_output_directory<P: AsRef<Path>>(directory: P) -> Result<(), ExportFailure> {
+    let directory = directory.as_ref();
+    let path = directory.join(PREFLIGHT_FILE_NAME);
+
+    let failure = |error: std::io::Error| ExportFailure {
+        asset: String::new(),
+        path: directory.to_path_buf(),
+        kind: ExportFailureKind::classify(&error),
+        report: Some(error.report()),
+    };
+
+    std::fs::create_dir_all(directory).map_err(failure)?;
+    std::fs::write(&path, []).map_err(failure)?;
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
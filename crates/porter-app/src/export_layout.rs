@@ -0,0 +1,185 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use porter_utils::PathExt;
+
+/// The type of asset being exported, used to pick an output folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportAssetType {
+    Model,
+    Image,
+    Material,
+    Animation,
+    Sound,
+    RawFile,
+}
+
+impl ExportAssetType {
+    /// Every asset type, in the order they are shown to the user.
+    pub const ALL: [Self; 6] = [
+        Self::Model,
+        Self::Image,
+        Self::Material,
+        Self::Animation,
+        Self::Sound,
+        Self::RawFile,
+    ];
+
+    /// The display name of the asset type.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Model => "Models",
+            Self::Image => "Images",
+            Self::Material => "Materials",
+            Self::Animation => "Animations",
+            Self::Sound => "Sounds",
+            Self::RawFile => "Raw Files",
+        }
+    }
+
+    /// Parses an asset type by name, accepting singular, plural, and short names such as `anim` or `raw-files`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase().replace(['-', '_', ' '], "");
+
+        Some(match name.as_str() {
+            "model" | "models" => Self::Model,
+            "image" | "images" => Self::Image,
+            "material" | "materials" => Self::Material,
+            "anim" | "anims" | "animation" | "animations" => Self::Animation,
+            "sound" | "sounds" | "audio" => Self::Sound,
+            "raw" | "rawfile" | "rawfiles" => Self::RawFile,
+            _ => return None,
+        })
+    }
+}
+
+/// User configurable folders for each asset type, used by the type folders layout.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct ExportFolders {
+    model: String,
+    image: String,
+    material: String,
+    animation: String,
+    sound: String,
+    raw_file: String,
+}
+
+impl ExportFolders {
+    /// Gets the folder used for the given asset type, an empty folder exports to the output directory.
+    pub fn folder(&self, asset_type: ExportAssetType) -> &str {
+        match asset_type {
+            ExportAssetType::Model => &self.model,
+            ExportAssetType::Image => &self.image,
+            ExportAssetType::Material => &self.material,
+            ExportAssetType::Animation => &self.animation,
+            ExportAssetType::Sound => &self.sound,
+            ExportAssetType::RawFile => &self.raw_file,
+        }
+    }
+
+    /// Sets the folder used for the given asset type, relative to the output directory.
+    pub fn set_folder<F: Into<String>>(&mut self, asset_type: ExportAssetType, folder: F) {
+        let folder = folder.into();
+
+        match asset_type {
+            ExportAssetType::Model => self.model = folder,
+            ExportAssetType::Image => self.image = folder,
+            ExportAssetType::Material => self.material = folder,
+            ExportAssetType::Animation => self.animation = folder,
+            ExportAssetType::Sound => self.sound = folder,
+            ExportAssetType::RawFile => self.raw_file = folder,
+        }
+    }
+}
+
+impl Default for ExportFolders {
+    fn default() -> Self {
+        Self {
+            model: String::from("models"),
+            image: String::from("images"),
+            material: String::from("materials"),
+            animation: String::from("anims"),
+            sound: String::from("sounds"),
+            raw_file: String::from("raw"),
+        }
+    }
+}
+
+/// How exported files are laid out in the output directory.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportLayout {
+    /// Files are laid out by the asset manager.
+    #[default]
+    Default,
+    /// Files are grouped into Unity style asset folders.
+    Unity,
+    /// Files are grouped into the user configured folders for each asset type.
+    TypeFolders,
+}
+
+impl ExportLayout {
+    /// Gets the folder, relative to the output directory, used for the given asset type.
+    pub fn folder<'a>(
+        &self,
+        folders: &'a ExportFolders,
+        asset_type: ExportAssetType,
+    ) -> Option<&'a str> {
+        match self {
+            Self::Default => None,
+            Self::Unity => Some(match asset_type {
+                ExportAssetType::Model => "Assets/Models",
+                ExportAssetType::Image => "Assets/Textures",
+                ExportAssetType::Material => "Assets/Materials",
+                ExportAssetType::Animation => "Assets/Animations",
+                ExportAssetType::Sound => "Assets/Audio",
+                ExportAssetType::RawFile => "Assets/Other",
+            }),
+            Self::TypeFolders => {
+                let folder = folders
+                    .folder(asset_type)
+                    .trim()
+                    .trim_matches(|ch| ch == '/' || ch == '\\');
+
+                (!folder.is_empty()).then_some(folder)
+            }
+        }
+    }
+
+    /// Gets the directory used for the given asset type in the given output directory.
+    pub fn directory<P: AsRef<Path>>(
+        &self,
+        output_directory: P,
+        folders: &ExportFolders,
+        asset_type: ExportAssetType,
+    ) -> PathBuf {
+        match self.folder(folders, asset_type) {
+            Some(folder) => output_directory.as_ref().join(folder),
+            None => output_directory.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Rewrites a texture file name, relative to the image folder, so that it's relative to the model folder.
+    pub fn texture_reference<F: AsRef<str>>(
+        &self,
+        folders: &ExportFolders,
+        file_name: F,
+    ) -> String {
+        let file_name = file_name.as_ref();
+
+        match (
+            self.folder(folders, ExportAssetType::Model),
+            self.folder(folders, ExportAssetType::Image),
+        ) {
+            (None, None) => file_name.to_string(),
+            (model, image) => Path::new(image.unwrap_or_default())
+                .join(file_name)
+                .relative_from(model.unwrap_or_default())
+                .map(|path| path.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_else(|| file_name.to_string()),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+
+use serde_json::Value;
+use serde_json::json;
+
+use porter_utils::HashExt;
+use porter_utils::PathExt;
+
+use crate::Settings;
+
+/// The file name of the manifest written to the output directory.
+pub const EXPORT_MANIFEST_FILE_NAME: &str = "export_manifest.json";
+
+/// A file written to disk for an exported asset.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportManifestFile {
+    /// The path of the file relative to the output directory.
+    pub path: PathBuf,
+    /// The file format, from the extension of the file.
+    pub format: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The xxhash3 64bit hash of the file contents, in hex.
+    pub hash: String,
+}
+
+/// An asset written to disk during an export run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExportManifestAsset {
+    /// The display name of the asset.
+    pub name: String,
+    /// A tool specific identifier for the source of the asset, such as a hash or path.
+    pub source: String,
+    /// The type of asset.
+    pub asset_type: String,
+    /// The unique formats of the files written for this asset.
+    pub formats: Vec<String>,
+    /// The files written for this asset.
+    pub files: Vec<ExportManifestFile>,
+    /// How long the asset took to export, in milliseconds.
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Debug)]
+struct ExportManifestInner {
+    output_directory: PathBuf,
+    settings: Value,
+    started: SystemTime,
+    timer: Instant,
+    assets: Mutex<BTreeMap<usize, ExportManifestAsset>>,
+    durations: Mutex<BTreeMap<usize, Duration>>,
+}
+
+/// Collects the assets exported in a single run into a machine readable manifest.
+#[derive(Debug, Clone)]
+pub struct ExportManifest {
+    inner: Arc<ExportManifestInner>,
+}
+
+impl ExportManifest {
+    /// Constructs a new export manifest if the settings have manifests enabled.
+    pub fn for_export(settings: &Settings) -> Option<Self> {
+        if !settings.export_manifest() {
+            return None;
+        }
+
+        Some(Self {
+            inner: Arc::new(ExportManifestInner {
+                output_directory: settings.output_directory(),
+                settings: serde_json::to_value(settings).unwrap_or_default(),
+                started: SystemTime::now(),
+                timer: Instant::now(),
+                assets: Mutex::new(BTreeMap::new()),
+                durations: Mutex::new(BTreeMap::new()),
+            }),
+        })
+    }
+
+    /// Starts a manifest for an export run with the given settings, and attaches it so exported files are recorded.
+    pub fn attach(settings: &mut Settings) -> Option<Self> {
+        let manifest = Self::for_export(settings);
+
+        settings.set_export_session_manifest(manifest.clone());
+
+        manifest
+    }
+
+    /// Adds the files written for an exported asset to the manifest, files for the same asset are combined.
+    pub fn push<N: Into<String>, S: Into<String>, T: Into<String>, P: AsRef<Path>>(
+        &self,
+        asset: usize,
+        name: N,
+        source: S,
+        asset_type: T,
+        files: &[P],
+    ) {
+        let files: Vec<_> = files
+            .iter()
+            .filter_map(|file| {
+                let file = file.as_ref();
+                let buffer = std::fs::read(file).ok()?;
+
+                Some(ExportManifestFile {
+                    path: file
+                        .relative_from(&self.inner.output_directory)
+                        .unwrap_or_else(|| file.to_path_buf()),
+                    format: file
+                        .extension()
+                        .map(|extension| extension.to_string_lossy().to_lowercase())
+                        .unwrap_or_default(),
+                    size: buffer.len() as u64,
+                    hash: format!("{:016x}", buffer.as_slice().hash_xxh364()),
+                })
+            })
+            .collect();
+
+        let Ok(mut assets) = self.inner.assets.lock() else {
+            return;
+        };
+
+        let entry = assets.entry(asset).or_default();
+
+        entry.name = name.into();
+        entry.source = source.into();
+        entry.asset_type = asset_type.into();
+
+        for file in files {
+            if !file.format.is_empty() && !entry.formats.contains(&file.format) {
+                entry.formats.push(file.format.clone());
+            }
+
+            entry.files.push(file);
+        }
+    }
+
+    /// Records how long the given asset took to export.
+    pub fn finish(&self, asset: usize, duration: Duration) {
+        if let Ok(mut durations) = self.inner.durations.lock() {
+            durations.insert(asset, duration);
+        }
+    }
+
+    /// Writes the manifest to the output directory, returning the path to the manifest.
+    pub fn save(&self) -> Result<PathBuf, std::io::Error> {
+        let mut assets = self
+            .inner
+            .assets
+            .lock()
+            .map(|assets| assets.clone())
+            .unwrap_or_default();
+
+        if let Ok(durations) = self.inner.durations.lock() {
+            for (asset, duration) in durations.iter() {
+                if let Some(entry) = assets.get_mut(asset) {
+                    entry.duration_ms = Some(duration.as_millis() as u64);
+                }
+            }
+        }
+
+        let started = self
+            .inner
+            .started
+            .duration_since(UNIX_EPOCH)
+            .map(|started| started.as_secs())
+            .unwrap_or_default();
+
+        let json = json!({
+            "version": 2,
+            "started": started,
+            "duration_ms": self.inner.timer.elapsed().as_millis() as u64,
+            "settings": self.inner.settings,
+            "assets": assets.into_values().collect::<Vec<_>>(),
+        });
+
+        let buffer = serde_json::to_vec_pretty(&json)?;
+
+        std::fs::create_dir_all(&self.inner.output_directory)?;
+
+        let path = self.inner.output_directory.join(EXPORT_MANIFEST_FILE_NAME);
+
+        std::fs::write(&path, buffer)?;
+
+        Ok(path)
+    }
+}
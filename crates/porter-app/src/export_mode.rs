@@ -0,0 +1,13 @@
+/// How the assets of an export are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportMode {
+    /// Each asset is written on its own.
+    #[default]
+    Separate,
+    /// Sounds are combined, in order, into a single file.
+    Combined,
+    /// Models are merged, in order, into a single model.
+    Merged,
+    /// Animations are concatenated, in order, into a single animation timeline.
+    Concatenated,
+}
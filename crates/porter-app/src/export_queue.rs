@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use iced::Color;
+
+use porter_utils::ErrorReport;
+
+use crate::palette;
+
+/// The state of an asset in the export queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportJobState {
+    /// The asset is waiting to be exported.
+    Queued,
+    /// The asset is being exported.
+    Exporting,
+    /// The asset was exported.
+    Done,
+    /// The asset failed to export, with the report of the error.
+    Error(ErrorReport),
+}
+
+impl ExportJobState {
+    /// Gets the color for this state.
+    pub const fn color(&self) -> Color {
+        match self {
+            Self::Queued => palette::ASSET_STATUS_QUEUED,
+            Self::Exporting => palette::ASSET_STATUS_EXPORTING,
+            Self::Done => palette::ASSET_STATUS_EXPORTED,
+            Self::Error(_) => palette::ASSET_STATUS_ERROR,
+        }
+    }
+}
+
+impl fmt::Display for ExportJobState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Queued => write!(f, "Queued"),
+            Self::Exporting => write!(f, "Exporting..."),
+            Self::Done => write!(f, "Exported"),
+            Self::Error(_) => write!(f, "Error"),
+        }
+    }
+}
+
+/// The queue of assets in an export, where each asset is a job shared between export threads.
+#[derive(Debug, Clone, Default)]
+pub struct ExportQueue {
+    jobs: Arc<Mutex<HashMap<usize, ExportJobState>>>,
+}
+
+impl ExportQueue {
+    /// Constructs a new export queue with a queued job for each of the given assets.
+    pub fn new(assets: &[usize]) -> Self {
+        Self {
+            jobs: Arc::new(Mutex::new(
+                assets
+                    .iter()
+                    .map(|asset| (*asset, ExportJobState::Queued))
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Sets the state of the job for the given asset, if the asset is in the queue.
+    pub fn set(&self, asset: usize, state: ExportJobState) {
+        if let Ok(mut jobs) = self.jobs.lock()
+            && let Some(job) = jobs.get_mut(&asset)
+        {
+            *job = state;
+        }
+    }
+
+    /// Gets the state of the job for the given asset, if the asset is in the queue.
+    pub fn state(&self, asset: usize) -> Option<ExportJobState> {
+        self.jobs
+            .lock()
+            .ok()
+            .and_then(|jobs| jobs.get(&asset).cloned())
+    }
+
+    /// Finishes the export, unfinished jobs are marked as done unless the export was canceled, where they're removed.
+    pub fn finish(&self, canceled: bool) {
+        let Ok(mut jobs) = self.jobs.lock() else {
+            return;
+        };
+
+        if canceled {
+            jobs.retain(|_, job| {
+                !matches!(job, ExportJobState::Queued | ExportJobState::Exporting)
+            });
+        } else {
+            for job in jobs.values_mut() {
+                if matches!(job, ExportJobState::Queued | ExportJobState::Exporting) {
+                    *job = ExportJobState::Done;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use porter_utils::ReportError;
+use porter_utils::WorkerPool;
+
+use crate::Controller;
+use crate::Settings;
+
+/// Exports each asset in parallel on the export worker threads, until every asset is exported or the export is canceled.
+///
+/// Each asset is reported to the export queue as it starts, finishes, or fails, and progress is sent to the controller
+/// as assets finish. The time taken by each asset is recorded in the export manifest, when manifests are enabled. Once every worker has stopped, the export is reported as finished.
+pub fn export_parallel<F, E>(
+    settings: &Settings,
+    assets: Vec<usize>,
+    controller: &Controller,
+    canceled: &AtomicBool,
+    export: F,
+) where
+    F: Fn(&Settings, usize) -> Result<(), E> + Sync,
+    E: ReportError,
+{
+    let total = assets.len();
+
+    // Workers share the thread pool with previews and loads, so there are never more workers than threads.
+    let workers = match settings.export_threads() {
+        0 => porter_threads::current_num_threads(),
+        threads => (threads as usize).min(porter_threads::current_num_threads()),
+    }
+    .clamp(1, total.max(1));
+
+    let next = AtomicUsize::new(0);
+    let finished = AtomicUsize::new(0);
+    let progress = AtomicU32::new(0);
+
+    WorkerPool::with_workers(workers).scope(|| {
+        while !canceled.load(Ordering::Relaxed) {
+            let Some(asset) = assets.get(next.fetch_add(1, Ordering::Relaxed)).copied() else {
+                break;
+            };
+
+            settings.export_job_started(asset);
+
+            let started = Instant::now();
+
+            match export(settings, asset) {
+                Ok(()) => settings.export_job_finished(asset),
+                Err(e) => settings.export_job_failed(asset, &e),
+            }
+
+            settings.record_export_duration(asset, started.elapsed());
+
+            let finished = finished.fetch_add(1, Ordering::Relaxed) + 1;
+            let percent = ((finished * 100) / total) as u32;
+
+            // Only notify when the percentage changes, as there may be thousands of assets.
+            if progress.fetch_max(percent, Ordering::Relaxed) < percent {
+                controller.progress_update(false, percent);
+            }
+        }
+    });
+
+    controller.progress_update(true, 100);
+}
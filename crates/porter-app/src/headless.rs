@@ -0,0 +1,342 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use iced::futures::channel::mpsc;
+use iced::futures::channel::mpsc::TryRecvError;
+
+use porter_animation::AnimationFileType;
+use porter_audio::AudioFileType;
+use porter_model::ModelFileType;
+use porter_texture::ImageFileType;
+
+use porter_console::Arguments;
+use porter_console::Color;
+use porter_console::console;
+
+use porter_utils::DirectoryCache;
+
+use crate::AppState;
+use crate::Controller;
+use crate::ExportAssetType;
+use crate::ExportConflictPolicy;
+use crate::ExportFailures;
+use crate::ExportManifest;
+use crate::ExportQueue;
+use crate::Message;
+use crate::SearchTerm;
+use crate::Settings;
+use crate::export_failures_summary;
+use crate::write_export_failures_log;
+
+/// How long to wait between checking for export progress.
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Options for exporting assets without the user interface, parsed from the command line.
+#[derive(Debug, Clone, Default)]
+pub struct HeadlessOptions {
+    /// The files to load, when not loading from a running game.
+    pub files: Vec<PathBuf>,
+    /// Whether or not to load from a running game.
+    pub game: bool,
+    /// The search term used to filter the exported assets.
+    pub search: Option<String>,
+    /// The asset types to export, or every type when empty.
+    pub asset_types: Vec<ExportAssetType>,
+    /// The output formats, such as `png` or `cast`, overriding the saved settings for their asset type.
+    pub formats: Vec<String>,
+    /// The directory to export to, overriding the saved settings.
+    pub output_directory: Option<PathBuf>,
+}
+
+impl HeadlessOptions {
+    /// Parses the headless options from the process arguments, or returns `None` when `--headless` isn't given.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        Self::parse(std::env::args_os().skip(1).collect())
+    }
+
+    /// Parses the headless options from the given arguments, or returns `None` when `--headless` isn't given.
+    ///
+    /// Usage: `--headless [--game] [--search TERM] [--type TYPES] [--format FORMATS] [--output DIR] [FILES...]`
+    pub fn parse(args: Vec<OsString>) -> Result<Option<Self>, String> {
+        let mut args = Arguments::from_vec(args);
+
+        if !args.contains("--headless") {
+            return Ok(None);
+        }
+
+        let game = args.contains("--game");
+
+        let search: Option<String> = args
+            .opt_value_from_str("--search")
+            .map_err(|e| e.to_string())?;
+
+        let asset_types = args
+            .values_from_str::<_, String>("--type")
+            .map_err(|e| e.to_string())?
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(|value| {
+                ExportAssetType::from_name(value)
+                    .ok_or_else(|| format!("Unknown asset type: {}", value.trim()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let formats = args
+            .values_from_str::<_, String>("--format")
+            .map_err(|e| e.to_string())?
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(|format| format.trim().to_lowercase())
+            .filter(|format| !format.is_empty())
+            .collect();
+
+        let output_directory: Option<PathBuf> = args
+            .opt_value_from_os_str("--output", |value| Ok::<_, String>(PathBuf::from(value)))
+            .map_err(|e| e.to_string())?;
+
+        let mut files = Vec::new();
+
+        for arg in args.finish() {
+            if arg.to_string_lossy().starts_with('-') {
+                return Err(format!("Unknown argument: {}", arg.to_string_lossy()));
+            }
+
+            files.push(PathBuf::from(arg));
+        }
+
+        if game == !files.is_empty() {
+            return Err(String::from(
+                "Either --game, or one or more files to load must be given.",
+            ));
+        }
+
+        Ok(Some(Self {
+            files,
+            game,
+            search,
+            asset_types,
+            formats,
+            output_directory,
+        }))
+    }
+}
+
+impl AppState {
+    /// Loads, searches, and exports assets without the user interface, using the saved settings and the given overrides.
+    pub fn run_headless(mut self, options: HeadlessOptions) -> Result<(), String> {
+        self.settings = Settings::load(self.name);
+
+        porter_gpu::set_gpu_adapter_preference(self.settings.gpu_adapter());
+        porter_threads::initialize_thread_pool();
+
+        let manager = self.asset_manager.clone();
+
+        console!(header = "Load", "Loading assets...");
+
+        let result = if options.game {
+            if !manager.supports_games() {
+                return Err(String::from("Loading from a running game isn't supported."));
+            }
+
+            manager.load_game(self.settings.clone())
+        } else {
+            if !manager.supports_files() {
+                return Err(String::from("Loading files isn't supported."));
+            }
+
+            manager.load_files(self.settings.clone(), options.files.clone())
+        };
+
+        result?;
+
+        self.settings.set_profile(manager.settings_profile());
+
+        if let Some(output_directory) = &options.output_directory {
+            self.settings
+                .set_output_directory(output_directory.to_path_buf());
+        }
+
+        apply_formats(&mut self.settings, &options.formats)?;
+
+        if options.search.is_some() || !options.asset_types.is_empty() {
+            let headers: Vec<&str> = self
+                .asset_columns
+                .iter()
+                .map(|column| column.header)
+                .collect();
+
+            let search = SearchTerm::compile(options.search.as_deref().unwrap_or_default())?
+                .resolve_columns(&headers)
+                .asset_types(options.asset_types.clone());
+
+            manager.search(Some(search));
+        }
+
+        let visible = manager.assets_visible();
+
+        if let Some(page_size) = manager.assets_page_size() {
+            for page in 0..visible.div_ceil(page_size.max(1)) {
+                if !manager.assets_page_fetched(page) {
+                    manager.assets_page_fetch(self.settings.clone(), page)?;
+                }
+            }
+        }
+
+        console!(
+            header = "Load",
+            "Loaded {} assets, {} matched.",
+            manager.assets_total(),
+            visible
+        );
+
+        if visible == 0 {
+            return Ok(());
+        }
+
+        for asset_type in self.settings.missing_export_formats() {
+            console!(header = "Warning", { color = Color::Yellow, "No export formats are selected for {}, they will be skipped.", asset_type.name() });
+        }
+
+        let assets: Vec<usize> = (0..visible).collect();
+
+        let mut settings = self.settings.clone();
+
+        // Nobody can be asked about conflicts, so existing files are replaced.
+        if settings.export_conflict_policy() == ExportConflictPolicy::Ask {
+            settings.set_export_conflict_policy(ExportConflictPolicy::Overwrite);
+        }
+
+        let failures = ExportFailures::default();
+
+        settings.set_export_failures(failures.clone());
+        settings.set_export_queue(ExportQueue::new(&assets));
+        settings.set_directory_cache(DirectoryCache::new());
+
+        let manifest = ExportManifest::attach(&mut settings);
+
+        let (sender, mut receiver) = mpsc::unbounded();
+        let controller = Controller::with_channel(sender);
+
+        console!(
+            header = "Export",
+            "Exporting to {}...",
+            settings.output_directory().display()
+        );
+
+        let export = std::thread::spawn(move || manager.export(settings, assets, controller));
+
+        let mut reported = 0;
+
+        loop {
+            match receiver.try_recv() {
+                Ok(Message::ProgressUpdate(finished, progress)) => {
+                    if finished {
+                        break;
+                    }
+
+                    // Report every tenth percent, to keep build logs short.
+                    if progress / 10 > reported / 10 {
+                        reported = progress;
+
+                        console!(header = "Export", "{}%", progress);
+                    }
+                }
+                Ok(_) => continue,
+                Err(TryRecvError::Closed) => break,
+                Err(TryRecvError::Empty) => std::thread::sleep(PROGRESS_POLL_INTERVAL),
+            }
+        }
+
+        if export.join().is_err() {
+            return Err(String::from("The export stopped unexpectedly."));
+        }
+
+        if let Some(manifest) = manifest {
+            let path = manifest
+                .save()
+                .map_err(|e| format!("Failed to write the export manifest: {}", e))?;
+
+            console!(header = "Export", "Wrote {}", path.display());
+        }
+
+        let failures = failures.take();
+
+        if !failures.is_empty() {
+            write_export_failures_log(self.name, &failures);
+
+            return Err(export_failures_summary(&failures));
+        }
+
+        console!(header = "Export", { color = Color::Green, "Export finished." });
+
+        Ok(())
+    }
+}
+
+/// Replaces the export formats of each asset type that has a format in the given list.
+fn apply_formats(settings: &mut Settings, formats: &[String]) -> Result<(), String> {
+    let mut models = Vec::new();
+    let mut animations = Vec::new();
+    let mut sounds = Vec::new();
+    let mut image = None;
+
+    for format in formats {
+        match format.as_str() {
+            "obj" => models.push(ModelFileType::Obj),
+            "smd" => models.push(ModelFileType::Smd),
+            "xna" | "xnalara" => models.push(ModelFileType::XnaLara),
+            "xmodel_export" => models.push(ModelFileType::XModelExport),
+            "ma" | "maya" => models.push(ModelFileType::Maya),
+            "fbx" => models.push(ModelFileType::Fbx),
+            "cast" => {
+                models.push(ModelFileType::Cast);
+                animations.push(AnimationFileType::Cast);
+            }
+            "wav" => sounds.push(AudioFileType::Wav),
+            "flac" => sounds.push(AudioFileType::Flac),
+            "ogg" => sounds.push(AudioFileType::Ogg),
+            "dds" => image = Some(ImageFileType::Dds),
+            "png" => image = Some(ImageFileType::Png),
+            "tiff" | "tif" => image = Some(ImageFileType::Tiff),
+            "tga" => image = Some(ImageFileType::Tga),
+            format => return Err(format!("Unknown export format: {}", format)),
+        }
+    }
+
+    if !models.is_empty() {
+        for file_type in settings.model_file_types() {
+            settings.set_model_file_type(file_type, false);
+        }
+
+        for file_type in models {
+            settings.set_model_file_type(file_type, true);
+        }
+    }
+
+    if !animations.is_empty() {
+        for file_type in settings.anim_file_types() {
+            settings.set_anim_file_type(file_type, false);
+        }
+
+        for file_type in animations {
+            settings.set_anim_file_type(file_type, true);
+        }
+    }
+
+    if !sounds.is_empty() {
+        for file_type in settings.audio_file_types() {
+            settings.set_audio_file_type(file_type, false);
+        }
+
+        for file_type in sounds {
+            settings.set_audio_file_type(file_type, true);
+        }
+    }
+
+    if let Some(image) = image {
+        settings.set_image_file_type(image);
+    }
+
+    Ok(())
+}
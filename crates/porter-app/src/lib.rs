@@ -11,40 +11,74 @@ pub(crate) mod icon_windows;
 mod app;
 mod app_state;
 mod asset_column;
+mod asset_column_value;
 mod asset_manager;
+mod asset_metadata;
 mod asset_preview;
+mod asset_preview_merge;
 mod asset_status;
 mod audio_player;
+mod audio_transcript;
 mod column_status;
 mod controller;
 mod executor;
+mod export_conflict;
+mod export_failure;
+mod export_layout;
+mod export_manifest;
+mod export_mode;
+mod export_queue;
+mod export_workers;
+mod headless;
 mod icon;
 mod message;
+mod row_color_rule;
 mod search;
 mod settings;
+mod settings_migration;
+mod single_instance;
 mod sort;
+mod unreal_preset;
+mod usage_statistics;
 mod windows;
 
 pub(crate) use app::*;
 pub(crate) use asset_column::*;
+pub(crate) use asset_preview_merge::*;
 pub(crate) use audio_player::*;
 pub(crate) use executor::*;
 pub(crate) use message::*;
+pub(crate) use settings_migration::*;
+pub(crate) use single_instance::*;
+pub(crate) use usage_statistics::*;
 pub(crate) use windows::*;
 
 /// Shared application palette and colors for ui elements.
 pub mod palette;
 
 pub use app_state::*;
+pub use asset_column_value::*;
 pub use asset_manager::*;
+pub use asset_metadata::*;
 pub use asset_preview::*;
 pub use asset_status::*;
+pub use audio_transcript::*;
 pub use column_status::*;
 pub use controller::*;
+pub use export_conflict::*;
+pub use export_failure::*;
+pub use export_layout::*;
+pub use export_manifest::*;
+pub use export_mode::*;
+pub use export_queue::*;
+pub use export_workers::*;
+pub use headless::*;
 pub use icon::*;
+pub use row_color_rule::*;
 pub use search::*;
 pub use settings::*;
 pub use sort::*;
+pub use unreal_preset::*;
 
 /// Re-exported for use in public interfaces.
 pub use iced::Color;
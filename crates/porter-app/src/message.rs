@@ -4,14 +4,18 @@ use iced::window;
 
 use iced::Event;
 
+use porter_utils::ErrorReport;
+
 use crate::AssetPreview;
 use crate::Controller;
+use crate::ExportMode;
 use crate::MainMessage;
 use crate::PreviewWindowMessage;
 use crate::SplashMessage;
 use crate::components::ContentMessage;
 use crate::components::ControlsMessage;
 use crate::components::HeaderMessage;
+use crate::components::NameGuessMessage;
 use crate::components::PreviewMessage;
 use crate::components::SearchBarMessage;
 use crate::components::SettingsMessage;
@@ -29,19 +33,28 @@ pub enum Message {
     PreviewWindow(PreviewWindowMessage),
     LoadUpdate(Result<(), String>),
     ProgressUpdate(bool, u32),
-    PreviewUpdate(u64, AssetPreview),
+    PreviewUpdate(u64, Result<AssetPreview, ErrorReport>),
     PreviewWindowCreate,
     PreviewWindowClosed,
     PreviewToggle,
     PreviewRequest,
     ExportSelected,
     ExportAll,
+    ExportCombined,
+    ExportMerged,
+    ExportConcatenated,
+    ExportOverwrite(Vec<usize>, ExportMode),
     ExportCancel,
     LoadFiles(Vec<PathBuf>),
     LoadFilesDropped,
+    LoadFilesForwarded(Vec<PathBuf>),
     LoadGame,
     Sort(Option<usize>),
     CheckReload,
+    NamesReload,
+    NamesUpdate(Result<(), String>),
+    AssetsFetch,
+    AssetsFetchUpdate(usize, Result<(), String>),
 }
 
 impl From<SplashMessage> for Message {
@@ -92,6 +105,12 @@ impl From<ControlsMessage> for Message {
     }
 }
 
+impl From<NameGuessMessage> for Message {
+    fn from(value: NameGuessMessage) -> Self {
+        Self::from(MainMessage::NameGuess(value))
+    }
+}
+
 impl From<SettingsMessage> for Message {
     fn from(value: SettingsMessage) -> Self {
         Self::from(MainMessage::Settings(value))
@@ -78,6 +78,8 @@ pub const ASSET_STATUS_ERROR: Color = Color::from_rgb8(0xD4, 0xAF, 0x37);
 pub const ASSET_STATUS_PLACEHOLDER: Color = Color::from_rgb8(0xEC, 0x34, 0xCA);
 /// Asset status exporting color.
 pub const ASSET_STATUS_EXPORTING: Color = Color::from_rgb8(0x90, 0x7A, 0xD6);
+/// Asset status queued color.
+pub const ASSET_STATUS_QUEUED: Color = Color::from_rgb8(0x80, 0x80, 0x80);
 /// Asset status not supported color.
 pub const ASSET_STATUS_NOT_SUPPORTED: Color = Color::from_rgb8(0xF1, 0xA3, 0x8B);
 
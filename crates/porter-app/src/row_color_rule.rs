@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use iced::Color;
+
+use crate::AssetColumn;
+
+/// The colors that can be given to rows in the asset list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RowColor {
+    Green,
+    Gray,
+    Gold,
+    Red,
+    Blue,
+    Purple,
+}
+
+impl RowColor {
+    /// Every row color, in the order they are shown to the user.
+    pub const ALL: [Self; 6] = [
+        Self::Green,
+        Self::Gray,
+        Self::Gold,
+        Self::Red,
+        Self::Blue,
+        Self::Purple,
+    ];
+
+    /// The color used to draw the row text.
+    pub const fn color(&self) -> Color {
+        match self {
+            Self::Green => Color::from_rgb8(0x23, 0xCE, 0x6B),
+            Self::Gray => Color::from_rgb8(0x80, 0x80, 0x80),
+            Self::Gold => Color::from_rgb8(0xD4, 0xAF, 0x37),
+            Self::Red => Color::from_rgb8(0xDB, 0x50, 0x4A),
+            Self::Blue => Color::from_rgb8(0x21, 0xB8, 0xEB),
+            Self::Purple => Color::from_rgb8(0x90, 0x7A, 0xD6),
+        }
+    }
+}
+
+impl fmt::Display for RowColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A rule which colors the rows of assets where a column contains a value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RowColorRule {
+    /// The header of the column to check.
+    pub column: String,
+    /// The value the column must contain, ignoring case.
+    pub value: String,
+    /// The color given to matching rows.
+    pub color: RowColor,
+}
+
+impl RowColorRule {
+    /// Constructs a new row color rule.
+    pub fn new<C: Into<String>, V: Into<String>>(column: C, value: V, color: RowColor) -> Self {
+        Self {
+            column: column.into(),
+            value: value.into(),
+            color,
+        }
+    }
+
+    /// Whether or not an asset with the given column info matches this rule.
+    pub fn matches(&self, columns: &[AssetColumn], info: &[(String, Option<Color>)]) -> bool {
+        let value = self.value.to_lowercase();
+
+        columns
+            .iter()
+            .zip(info)
+            .filter(|(column, _)| column.header.eq_ignore_ascii_case(&self.column))
+            .any(|(_, (info, _))| info.to_lowercase().contains(&value))
+    }
+}
+
+/// Finds the color of the first rule matching an asset with the given column info.
+pub fn row_color(
+    rules: &[RowColorRule],
+    columns: &[AssetColumn],
+    info: &[(String, Option<Color>)],
+) -> Option<Color> {
+    rules
+        .iter()
+        .find(|rule| rule.matches(columns, info))
+        .map(|rule| rule.color.color())
+}
@@ -1,5 +1,13 @@
 use std::num::ParseIntError;
 
+use regex::Regex;
+use regex::RegexBuilder;
+
+use iced::Color;
+
+use crate::AssetManager;
+use crate::ExportAssetType;
+
 /// Ways to filter on a number range.
 #[derive(Debug, Clone, Copy)]
 struct SearchRange {
@@ -20,6 +28,16 @@ impl Default for SearchRange {
 enum SearchName {
     Contained(String),
     NotContained(String),
+    Matched(Regex),
+    NotMatched(Regex),
+}
+
+/// Ways to filter on the value of a column.
+struct SearchColumn {
+    name: String,
+    index: Option<usize>,
+    value: String,
+    negated: bool,
 }
 
 /// The searchable data for an asset.
@@ -31,6 +49,15 @@ pub struct SearchAsset {
     width: u32,
     height: u32,
     channels: u32,
+    sample_rate: u32,
+    duration: u32,
+    mipmaps: u32,
+    vertex_count: u32,
+    face_count: u32,
+    lod_count: u32,
+    material_count: u32,
+    asset_type: Option<ExportAssetType>,
+    columns: Vec<(String, Option<Color>)>,
     name: String,
 }
 
@@ -45,6 +72,15 @@ impl SearchAsset {
             width: 0,
             height: 0,
             channels: 0,
+            sample_rate: 0,
+            duration: 0,
+            mipmaps: 0,
+            vertex_count: 0,
+            face_count: 0,
+            lod_count: 0,
+            material_count: 0,
+            asset_type: None,
+            columns: Vec::new(),
             name,
         }
     }
@@ -90,6 +126,63 @@ impl SearchAsset {
         self.channels = channels;
         self
     }
+
+    /// Sets the sample rate this asset has.
+    pub const fn sample_rate(mut self, rate: u32) -> Self {
+        self.sample_rate = rate;
+        self
+    }
+
+    /// Sets the duration, in seconds, this asset has.
+    pub const fn duration(mut self, seconds: u32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Sets the number of mipmaps this asset has.
+    pub const fn mipmaps(mut self, mipmaps: u32) -> Self {
+        self.mipmaps = mipmaps;
+        self
+    }
+
+    /// Sets the count of vertices this asset has.
+    pub const fn vertex_count(mut self, count: u32) -> Self {
+        self.vertex_count = count;
+        self
+    }
+
+    /// Sets the count of faces this asset has.
+    pub const fn face_count(mut self, count: u32) -> Self {
+        self.face_count = count;
+        self
+    }
+
+    /// Sets the count of levels of detail this asset has.
+    pub const fn lod_count(mut self, count: u32) -> Self {
+        self.lod_count = count;
+        self
+    }
+
+    /// Sets the count of materials this asset has.
+    pub const fn material_count(mut self, count: u32) -> Self {
+        self.material_count = count;
+        self
+    }
+
+    /// Sets the type of this asset, used to filter by asset type.
+    pub const fn asset_type(mut self, asset_type: ExportAssetType) -> Self {
+        self.asset_type = Some(asset_type);
+        self
+    }
+
+    /// Sets the column data of this asset, in the same order as the asset columns.
+    ///
+    /// This is only needed when [`SearchTerm::filters_columns`] is true and the asset is matched with
+    /// [`SearchTerm::matches`], as it's costly to format for every asset.
+    pub fn columns(mut self, columns: Vec<(String, Option<Color>)>) -> Self {
+        self.columns = columns;
+        self
+    }
 }
 
 /// A compiled search term.
@@ -102,17 +195,28 @@ pub struct SearchTerm {
     width: SearchRange,
     height: SearchRange,
     channels: SearchRange,
+    sample_rate: SearchRange,
+    duration: SearchRange,
+    mipmaps: SearchRange,
+    vertex_count: SearchRange,
+    face_count: SearchRange,
+    lod_count: SearchRange,
+    material_count: SearchRange,
+    asset_types: Vec<ExportAssetType>,
+    search_types: Vec<ExportAssetType>,
+    search_columns: Vec<SearchColumn>,
     search_names: [Option<SearchName>; 5],
 }
 
 impl SearchTerm {
     /// Compile a search command into a reusable search structure.
-    pub fn compile(search: &str) -> Self {
-        // Always process search terms as lowercase for case insensitivity.
-        let search = search.to_lowercase();
-
+    ///
+    /// Names may be plain text, wildcards using `*` and `?`, or a regex prefixed with `re:`, and any of them can be
+    /// negated with `!`. Filters such as `bones:>50` match a number range, `type:model|image` matches asset types, and
+    /// any other `column:value` matches the value of a column. Returns a message for the user if a pattern is invalid.
+    pub fn compile(search: &str) -> Result<Self, String> {
         // Get the commands, up to 5 of them in one search term.
-        let commands = search.splitn(5, ',');
+        let commands = split_commands(search);
 
         let mut bone_count = SearchRange::default();
         let mut mesh_count = SearchRange::default();
@@ -121,16 +225,52 @@ impl SearchTerm {
         let mut width = SearchRange::default();
         let mut height = SearchRange::default();
         let mut channels = SearchRange::default();
+        let mut sample_rate = SearchRange::default();
+        let mut duration = SearchRange::default();
+        let mut mipmaps = SearchRange::default();
+        let mut vertex_count = SearchRange::default();
+        let mut face_count = SearchRange::default();
+        let mut lod_count = SearchRange::default();
+        let mut material_count = SearchRange::default();
+
+        let mut search_types = Vec::new();
+        let mut search_columns = Vec::new();
 
         let mut search_names: [Option<SearchName>; 5] = [const { None }; 5];
         let mut search_names_index = 0;
 
         for command in commands {
-            if let Some(command) = command.strip_prefix("bonecount:") {
+            let (negated, pattern) = match command.trim_start().strip_prefix('!') {
+                Some(pattern) => (true, pattern),
+                None => (false, command),
+            };
+
+            // Regex patterns keep their case, as case is meaningful to escapes.
+            if let Some(pattern) = pattern.trim().strip_prefix("re:") {
+                let regex = RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| format!("Invalid regex \"{}\": {}", pattern, regex_error(e)))?;
+
+                search_names[search_names_index] = Some(if negated {
+                    SearchName::NotMatched(regex)
+                } else {
+                    SearchName::Matched(regex)
+                });
+                search_names_index += 1;
+
+                continue;
+            }
+
+            // Always process search terms as lowercase for case insensitivity.
+            let command = command.trim().to_lowercase();
+            let command = command.as_str();
+
+            if let Some(command) = strip_prefixes(command, &["bonecount:", "bones:"]) {
                 let _ = parse_search_number(command, &mut bone_count);
-            } else if let Some(command) = command.strip_prefix("meshcount:") {
+            } else if let Some(command) = strip_prefixes(command, &["meshcount:", "meshes:"]) {
                 let _ = parse_search_number(command, &mut mesh_count);
-            } else if let Some(command) = command.strip_prefix("framecount:") {
+            } else if let Some(command) = strip_prefixes(command, &["framecount:", "frames:"]) {
                 let _ = parse_search_number(command, &mut frame_count);
             } else if let Some(command) = command.strip_prefix("framerate:") {
                 let _ = parse_search_number(command, &mut frame_rate);
@@ -140,26 +280,64 @@ impl SearchTerm {
                 let _ = parse_search_number(command, &mut height);
             } else if let Some(command) = command.strip_prefix("channels:") {
                 let _ = parse_search_number(command, &mut channels);
-            } else if let Some(command) = command.strip_prefix('!') {
-                let command = command.trim();
-
-                if !command.is_empty() {
-                    search_names[search_names_index] =
-                        Some(SearchName::NotContained(command.to_owned()));
-                    search_names_index += 1;
+            } else if let Some(command) = command.strip_prefix("samplerate:") {
+                let _ = parse_search_number(command, &mut sample_rate);
+            } else if let Some(command) = command.strip_prefix("duration:") {
+                let _ = parse_search_number(command, &mut duration);
+            } else if let Some(command) = command.strip_prefix("mips:") {
+                let _ = parse_search_number(command, &mut mipmaps);
+            } else if let Some(command) = strip_prefixes(command, &["vertexcount:", "vertices:"]) {
+                let _ = parse_search_number(command, &mut vertex_count);
+            } else if let Some(command) = strip_prefixes(command, &["facecount:", "faces:"]) {
+                let _ = parse_search_number(command, &mut face_count);
+            } else if let Some(command) = strip_prefixes(command, &["lodcount:", "lods:"]) {
+                let _ = parse_search_number(command, &mut lod_count);
+            } else if let Some(command) = strip_prefixes(command, &["materialcount:", "materials:"])
+            {
+                let _ = parse_search_number(command, &mut material_count);
+            } else if let Some(command) = command.strip_prefix("type:") {
+                for name in command
+                    .split('|')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                {
+                    let asset_type = ExportAssetType::from_name(name)
+                        .ok_or_else(|| format!("Unknown asset type \"{}\"", name))?;
+
+                    search_types.push(asset_type);
                 }
+            } else if let Some((name, value)) = parse_search_column(pattern) {
+                search_columns.push(SearchColumn {
+                    name,
+                    index: None,
+                    value,
+                    negated,
+                });
             } else {
-                let command = command.trim();
+                let pattern = pattern.trim().to_lowercase();
 
-                if !command.is_empty() {
-                    search_names[search_names_index] =
-                        Some(SearchName::Contained(command.to_owned()));
-                    search_names_index += 1;
+                if pattern.is_empty() {
+                    continue;
                 }
+
+                search_names[search_names_index] = Some(if pattern.contains(['*', '?']) {
+                    let regex = compile_wildcard(&pattern)?;
+
+                    if negated {
+                        SearchName::NotMatched(regex)
+                    } else {
+                        SearchName::Matched(regex)
+                    }
+                } else if negated {
+                    SearchName::NotContained(pattern)
+                } else {
+                    SearchName::Contained(pattern)
+                });
+                search_names_index += 1;
             }
         }
 
-        Self {
+        Ok(Self {
             bone_count,
             mesh_count,
             frame_count,
@@ -167,13 +345,100 @@ impl SearchTerm {
             width,
             height,
             channels,
+            sample_rate,
+            duration,
+            mipmaps,
+            vertex_count,
+            face_count,
+            lod_count,
+            material_count,
+            asset_types: Vec::new(),
+            search_types,
+            search_columns,
             search_names,
+        })
+    }
+
+    /// Resolves column filters to the asset columns with the given headers.
+    ///
+    /// Filters which don't name a column match the asset name instead, as the name may contain a `:`.
+    pub fn resolve_columns(mut self, headers: &[&str]) -> Self {
+        let mut search_columns = Vec::with_capacity(self.search_columns.len());
+
+        for mut column in std::mem::take(&mut self.search_columns) {
+            column.index = headers.iter().position(|header| {
+                header
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .flat_map(char::to_lowercase)
+                    .eq(column.name.chars())
+            });
+
+            if column.index.is_some() {
+                search_columns.push(column);
+                continue;
+            }
+
+            let name = format!("{}:{}", column.name, column.value);
+
+            if let Some(slot) = self.search_names.iter_mut().find(|slot| slot.is_none()) {
+                *slot = Some(if column.negated {
+                    SearchName::NotContained(name)
+                } else {
+                    SearchName::Contained(name)
+                });
+            }
         }
+
+        self.search_columns = search_columns;
+        self
+    }
+
+    /// Whether or not this search term filters on column values, which must be given with [`SearchAsset::columns`].
+    pub fn filters_columns(&self) -> bool {
+        !self.search_columns.is_empty()
+    }
+
+    /// Only matches assets of the given types, or any type if empty.
+    pub fn asset_types(mut self, asset_types: Vec<ExportAssetType>) -> Self {
+        self.asset_types = asset_types;
+        self
     }
 
     /// Determines if the given asset matches this search command.
     #[inline(always)]
     pub fn matches(&self, asset: SearchAsset) -> bool {
+        self.matches_fields(&asset)
+            && self.matches_columns(|index| {
+                asset
+                    .columns
+                    .get(index)
+                    .map(|(value, _)| value.to_lowercase())
+            })
+    }
+
+    /// Determines if the given asset matches this search command, getting the values of filtered columns from the asset manager.
+    ///
+    /// Only the columns used by filters are requested with [`AssetManager::asset_search_metadata`], so column data
+    /// doesn't need to be given with [`SearchAsset::columns`].
+    #[inline(always)]
+    pub fn matches_asset<M: AssetManager + ?Sized>(
+        &self,
+        manager: &M,
+        index: usize,
+        asset: SearchAsset,
+    ) -> bool {
+        self.matches_fields(&asset)
+            && self.matches_columns(|column| {
+                manager
+                    .asset_search_metadata(index, column)
+                    .map(|value| value.to_lowercase())
+            })
+    }
+
+    /// Determines if the given asset matches the ranges, types, and names of this search command.
+    #[inline(always)]
+    fn matches_fields(&self, asset: &SearchAsset) -> bool {
         if asset.bone_count > self.bone_count.max || asset.bone_count < self.bone_count.min {
             return false;
         }
@@ -195,6 +460,46 @@ impl SearchTerm {
         if asset.channels > self.channels.max || asset.channels < self.channels.min {
             return false;
         }
+        if asset.sample_rate > self.sample_rate.max || asset.sample_rate < self.sample_rate.min {
+            return false;
+        }
+        if asset.duration > self.duration.max || asset.duration < self.duration.min {
+            return false;
+        }
+        if asset.mipmaps > self.mipmaps.max || asset.mipmaps < self.mipmaps.min {
+            return false;
+        }
+        if asset.vertex_count > self.vertex_count.max || asset.vertex_count < self.vertex_count.min
+        {
+            return false;
+        }
+        if asset.face_count > self.face_count.max || asset.face_count < self.face_count.min {
+            return false;
+        }
+        if asset.lod_count > self.lod_count.max || asset.lod_count < self.lod_count.min {
+            return false;
+        }
+        if asset.material_count > self.material_count.max
+            || asset.material_count < self.material_count.min
+        {
+            return false;
+        }
+
+        if !self.asset_types.is_empty()
+            && !asset
+                .asset_type
+                .is_some_and(|asset_type| self.asset_types.contains(&asset_type))
+        {
+            return false;
+        }
+
+        if !self.search_types.is_empty()
+            && !asset
+                .asset_type
+                .is_some_and(|asset_type| self.search_types.contains(&asset_type))
+        {
+            return false;
+        }
 
         let asset_name = asset.name.to_lowercase();
 
@@ -212,6 +517,34 @@ impl SearchTerm {
                         return false;
                     }
                 }
+                SearchName::Matched(regex) => {
+                    if !regex.is_match(&asset_name) {
+                        return false;
+                    }
+                }
+                SearchName::NotMatched(regex) => {
+                    if regex.is_match(&asset_name) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Determines if the lowercase column values, given by index, match the column filters of this search command.
+    #[inline(always)]
+    fn matches_columns<F: Fn(usize) -> Option<String>>(&self, values: F) -> bool {
+        for column in &self.search_columns {
+            let Some(index) = column.index else {
+                continue;
+            };
+
+            let contains = values(index).is_some_and(|value| value.contains(column.value.as_str()));
+
+            if contains == column.negated {
+                return false;
             }
         }
 
@@ -247,3 +580,83 @@ fn parse_search_number(number: &str, range: &mut SearchRange) -> Result<(), Pars
 
     Ok(())
 }
+
+/// Splits a search into up to 5 commands, a regex takes the rest of the search as it may contain commas.
+fn split_commands(search: &str) -> Vec<&str> {
+    let mut commands = Vec::with_capacity(5);
+    let mut rest = search;
+
+    while commands.len() < 4 {
+        let command = rest.trim_start();
+        let command = command.strip_prefix('!').unwrap_or(command);
+
+        if command.trim_start().starts_with("re:") {
+            break;
+        }
+
+        let Some((command, remaining)) = rest.split_once(',') else {
+            break;
+        };
+
+        commands.push(command);
+        rest = remaining;
+    }
+
+    commands.push(rest);
+    commands
+}
+
+/// Strips the first of the given prefixes from the command.
+fn strip_prefixes<'a>(command: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes
+        .iter()
+        .find_map(|prefix| command.strip_prefix(prefix))
+}
+
+/// Parses a column filter in the form of `column:value`, where the column is a single word.
+fn parse_search_column(pattern: &str) -> Option<(String, String)> {
+    let (name, value) = pattern.trim().split_once(':')?;
+
+    if name.is_empty() || !name.chars().all(char::is_alphanumeric) {
+        return None;
+    }
+
+    Some((name.to_lowercase(), value.trim().to_lowercase()))
+}
+
+/// Compiles a wildcard pattern, where `*` matches any run of characters and `?` matches one, into an anchored regex.
+fn compile_wildcard(pattern: &str) -> Result<Regex, String> {
+    let mut result = String::with_capacity(pattern.len() + 2);
+
+    result.push('^');
+
+    for part in pattern.split_inclusive(['*', '?']) {
+        let (text, wildcard) = match part.strip_suffix(['*', '?']) {
+            Some(text) => (text, part.chars().last()),
+            None => (part, None),
+        };
+
+        result.push_str(&regex::escape(text));
+
+        match wildcard {
+            Some('*') => result.push_str(".*"),
+            Some('?') => result.push('.'),
+            _ => {}
+        }
+    }
+
+    result.push('$');
+
+    Regex::new(&result).map_err(|e| format!("Invalid wildcard \"{}\": {}", pattern, regex_error(e)))
+}
+
+/// Formats a regex error on a single line, as syntax errors span several lines to point at the problem.
+fn regex_error(error: regex::Error) -> String {
+    let error = error.to_string();
+
+    error
+        .lines()
+        .last()
+        .map(|line| line.trim_start_matches("error: ").to_owned())
+        .unwrap_or(error)
+}
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -12,12 +16,54 @@ use directories::UserDirs;
 
 use bitflags::bitflags;
 
+use porter_animation::Animation;
+use porter_animation::AnimationError;
 use porter_animation::AnimationFileType;
 use porter_audio::AudioFileType;
+
+use porter_cast::CastHashMode;
+use porter_cast::CastOptions;
+
+use porter_gpu::GPUAdapterPreference;
+use porter_model::MeshNaming;
+use porter_model::Model;
 use porter_model::ModelFileType;
+use porter_model::ModelSaveOptions;
+use porter_texture::Image;
+use porter_texture::ImageChannelRemap;
+use porter_texture::ImageColorProfile;
+use porter_texture::ImageCompressionQuality;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageDecodeScheduler;
 use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+use porter_texture::ImageSaveOptions;
+use porter_texture::ImageSpriteSheet;
+use porter_texture::MipmapFilter;
+use porter_texture::TextureError;
+use porter_texture::TransformAlgorithm;
+use porter_utils::AtomicSemaphore;
+use porter_utils::DirectoryCache;
+use porter_utils::ReportError;
+use porter_viewport::PreviewBackground;
 use porter_viewport::PreviewControlScheme;
 
+use crate::ExportAssetType;
+use crate::ExportConflictPolicy;
+use crate::ExportConflicts;
+use crate::ExportFailures;
+use crate::ExportFolders;
+use crate::ExportJobState;
+use crate::ExportLayout;
+use crate::ExportManifest;
+use crate::ExportMode;
+use crate::ExportQueue;
+use crate::ExportStatistics;
+use crate::RowColorRule;
+use crate::SETTINGS_VERSION;
+use crate::TranscriptFileType;
+use crate::migrate_settings;
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 struct LoadSettings(u32);
 
@@ -52,6 +98,7 @@ bitflags! {
         const EXPORT_CAST = 1 << 5;
         const EXPORT_MAYA = 1 << 6;
         const EXPORT_FBX = 1 << 7;
+        const EXPORT_DEBUG = 1 << 8;
     }
 }
 
@@ -66,6 +113,9 @@ bitflags! {
     impl AudioSettings: u32 {
         const EXPORT_WAV = 1 << 0;
         const EXPORT_FLAC = 1 << 2;
+        const EXPORT_TRANSCRIPT_TXT = 1 << 3;
+        const EXPORT_TRANSCRIPT_JSON = 1 << 4;
+        const EXPORT_OGG = 1 << 5;
     }
 }
 
@@ -77,6 +127,75 @@ pub enum ImageNormalMapProcessing {
     DirectX,
 }
 
+/// Options for converting between premultiplied and straight alpha.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageAlphaProcessing {
+    #[default]
+    None,
+    Premultiply,
+    Unpremultiply,
+}
+
+impl ImageAlphaProcessing {
+    /// Gets the transform used to apply this conversion, if any.
+    pub fn transform_algorithm(&self) -> Option<TransformAlgorithm> {
+        match self {
+            Self::None => None,
+            Self::Premultiply => Some(TransformAlgorithm::Premultiply),
+            Self::Unpremultiply => Some(TransformAlgorithm::Unpremultiply),
+        }
+    }
+}
+
+/// Options for which frames of multi-frame images are exported.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFrameExport {
+    #[default]
+    All,
+    Previewed,
+    Range,
+}
+
+/// Options for which mipmaps of images are exported.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageMipmapExport {
+    #[default]
+    Source,
+    HighestOnly,
+    Generate,
+}
+
+/// Options for block compressing dds images.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageCompression {
+    #[default]
+    None,
+    Bc1,
+    Bc3,
+    Bc7,
+}
+
+/// Per-game export overrides layered on top of the global settings.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct SettingsProfile {
+    model_settings: Option<ModelSettings>,
+    anim_settings: Option<AnimSettings>,
+    audio_settings: Option<AudioSettings>,
+    image_file_type: Option<ImageFileType>,
+    image_normal_map_processing: Option<ImageNormalMapProcessing>,
+}
+
+impl SettingsProfile {
+    /// Whether or not this profile overrides any of the global settings.
+    pub fn has_overrides(&self) -> bool {
+        self.model_settings.is_some()
+            || self.anim_settings.is_some()
+            || self.audio_settings.is_some()
+            || self.image_file_type.is_some()
+            || self.image_normal_map_processing.is_some()
+    }
+}
+
 /// Global application settings.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Settings {
@@ -87,14 +206,73 @@ pub struct Settings {
     audio_settings: AudioSettings,
     image_file_type: ImageFileType,
     image_normal_map_processing: ImageNormalMapProcessing,
+    image_unreal_preset: bool,
+    image_alpha_mask: bool,
+    image_channel_remap: Option<ImageChannelRemap>,
+    image_alpha_processing: ImageAlphaProcessing,
+    image_decode_budget: u32,
+    image_frame_export: ImageFrameExport,
+    image_mipmap_export: ImageMipmapExport,
+    image_compression: ImageCompression,
+    image_compression_quality: ImageCompressionQuality,
+    image_frame_range: (u32, u32),
+    image_sprite_sheet: bool,
+    image_sprite_sheet_columns: u32,
+    image_color_profile: ImageColorProfile,
     output_directory: Option<PathBuf>,
+    export_layout: ExportLayout,
+    export_folders: ExportFolders,
     preview_controls: PreviewControlScheme,
     preview_overlay: bool,
+    preview_background: PreviewBackground,
     auto_scale: bool,
     far_clip: u32,
+    near_clip: Option<f32>,
+    fov: u32,
+    preview_image_budget: u32,
     preview_window: bool,
+    gpu_adapter: GPUAdapterPreference,
+    export_threads: u32,
+    export_concurrent_writes: u32,
     custom_scale: Option<f32>,
     volume: u32,
+    export_manifest: bool,
+    export_blender_script: bool,
+    export_visible_meshes: bool,
+    material_mesh_names: bool,
+    cast_stable_hashes: bool,
+    cast_compression: bool,
+    usage_statistics: bool,
+    skip_splash: bool,
+    reduced_motion: bool,
+    name_databases: Vec<PathBuf>,
+    row_color_rules: Vec<RowColorRule>,
+    profile: Option<String>,
+    profiles: BTreeMap<String, SettingsProfile>,
+    #[serde(skip)]
+    preview_visibility: Option<(usize, Vec<bool>)>,
+    #[serde(skip)]
+    preview_frame: Option<(usize, usize)>,
+    #[serde(skip)]
+    export_mode: ExportMode,
+    #[serde(skip)]
+    export_pose_frame: Option<u32>,
+    #[serde(skip)]
+    asset_variant: Option<String>,
+    export_all_variants: bool,
+    export_conflict_policy: ExportConflictPolicy,
+    #[serde(skip)]
+    export_conflicts: ExportConflicts,
+    #[serde(skip)]
+    export_failures: ExportFailures,
+    #[serde(skip)]
+    export_queue: ExportQueue,
+    #[serde(skip)]
+    export_statistics: ExportStatistics,
+    #[serde(skip)]
+    export_session_manifest: Option<ExportManifest>,
+    #[serde(skip)]
+    directory_cache: DirectoryCache,
 }
 
 impl Settings {
@@ -110,9 +288,12 @@ impl Settings {
                 .join(name.into().to_lowercase())
                 .with_extension("json"),
         )
-        .map_or(Default::default(), |buffer| {
-            serde_json::from_slice::<Self>(&buffer).unwrap_or_default()
-        })
+        .map_or(Default::default(), |buffer| Self::from_slice(&buffer))
+    }
+
+    /// Decodes the settings from the given buffer, upgrading older versions, or returns new ones.
+    pub fn from_slice(buffer: &[u8]) -> Settings {
+        migrate_settings(buffer)
     }
 
     /// Saves the settings to the disk at the given path.
@@ -232,39 +413,41 @@ impl Settings {
 
     /// The model file types to export to.
     pub fn model_file_types(&self) -> Vec<ModelFileType> {
+        let model_settings = self.model_settings();
         let mut result = Vec::with_capacity(8);
 
-        if self.model_settings.contains(ModelSettings::EXPORT_OBJ) {
+        if model_settings.contains(ModelSettings::EXPORT_OBJ) {
             result.push(ModelFileType::Obj);
         }
 
-        if self.model_settings.contains(ModelSettings::EXPORT_SMD) {
+        if model_settings.contains(ModelSettings::EXPORT_SMD) {
             result.push(ModelFileType::Smd);
         }
 
-        if self.model_settings.contains(ModelSettings::EXPORT_XNA_LARA) {
+        if model_settings.contains(ModelSettings::EXPORT_XNA_LARA) {
             result.push(ModelFileType::XnaLara);
         }
 
-        if self
-            .model_settings
-            .contains(ModelSettings::EXPORT_XMODEL_EXPORT)
-        {
+        if model_settings.contains(ModelSettings::EXPORT_XMODEL_EXPORT) {
             result.push(ModelFileType::XModelExport);
         }
 
-        if self.model_settings.contains(ModelSettings::EXPORT_CAST) {
+        if model_settings.contains(ModelSettings::EXPORT_CAST) {
             result.push(ModelFileType::Cast);
         }
 
-        if self.model_settings.contains(ModelSettings::EXPORT_MAYA) {
+        if model_settings.contains(ModelSettings::EXPORT_MAYA) {
             result.push(ModelFileType::Maya);
         }
 
-        if self.model_settings.contains(ModelSettings::EXPORT_FBX) {
+        if model_settings.contains(ModelSettings::EXPORT_FBX) {
             result.push(ModelFileType::Fbx);
         }
 
+        if model_settings.contains(ModelSettings::EXPORT_DEBUG) {
+            result.push(ModelFileType::Debug);
+        }
+
         result
     }
 
@@ -278,16 +461,24 @@ impl Settings {
             ModelFileType::Cast => ModelSettings::EXPORT_CAST,
             ModelFileType::Maya => ModelSettings::EXPORT_MAYA,
             ModelFileType::Fbx => ModelSettings::EXPORT_FBX,
+            ModelFileType::Debug => ModelSettings::EXPORT_DEBUG,
         };
 
-        self.model_settings.set(flag, value);
+        let mut model_settings = self.model_settings();
+
+        model_settings.set(flag, value);
+
+        match self.active_profile_mut() {
+            Some(profile) => profile.model_settings = Some(model_settings),
+            None => self.model_settings = model_settings,
+        }
     }
 
     /// The animation file types to export to.
     pub fn anim_file_types(&self) -> Vec<AnimationFileType> {
         let mut result = Vec::with_capacity(1);
 
-        if self.anim_settings.contains(AnimSettings::EXPORT_CAST) {
+        if self.anim_settings().contains(AnimSettings::EXPORT_CAST) {
             result.push(AnimationFileType::Cast);
         }
 
@@ -300,21 +491,74 @@ impl Settings {
             AnimationFileType::Cast => AnimSettings::EXPORT_CAST,
         };
 
-        self.anim_settings.set(flag, value);
+        let mut anim_settings = self.anim_settings();
+
+        anim_settings.set(flag, value);
+
+        match self.active_profile_mut() {
+            Some(profile) => profile.anim_settings = Some(anim_settings),
+            None => self.anim_settings = anim_settings,
+        }
+    }
+
+    /// Saves the animation in every enabled animation format, along with a blender script when enabled.
+    /// The animation is compacted first, so the full keyframes aren't held while the files are written.
+    pub fn save_animation(
+        &self,
+        animation: &mut Animation,
+        path: &Path,
+    ) -> Result<(), AnimationError> {
+        animation.compact();
+
+        let file_types = self.anim_file_types();
+
+        for file_type in &file_types {
+            animation.save_with_options(path, *file_type, self.cast_options())?;
+        }
+
+        if self.export_blender_script {
+            animation.save_blender_script(path, &file_types)?;
+        }
+
+        Ok(())
+    }
+
+    /// The asset types which have no export formats selected, these assets are skipped when exporting.
+    pub fn missing_export_formats(&self) -> Vec<ExportAssetType> {
+        let mut result = Vec::with_capacity(3);
+
+        if self.model_file_types().is_empty() {
+            result.push(ExportAssetType::Model);
+        }
+
+        if self.anim_file_types().is_empty() {
+            result.push(ExportAssetType::Animation);
+        }
+
+        if self.audio_file_types().is_empty() {
+            result.push(ExportAssetType::Sound);
+        }
+
+        result
     }
 
     /// The audio file types to export to.
     pub fn audio_file_types(&self) -> Vec<AudioFileType> {
+        let audio_settings = self.audio_settings();
         let mut result = Vec::with_capacity(3);
 
-        if self.audio_settings.contains(AudioSettings::EXPORT_WAV) {
+        if audio_settings.contains(AudioSettings::EXPORT_WAV) {
             result.push(AudioFileType::Wav);
         }
 
-        if self.audio_settings.contains(AudioSettings::EXPORT_FLAC) {
+        if audio_settings.contains(AudioSettings::EXPORT_FLAC) {
             result.push(AudioFileType::Flac);
         }
 
+        if audio_settings.contains(AudioSettings::EXPORT_OGG) {
+            result.push(AudioFileType::Ogg);
+        }
+
         result
     }
 
@@ -323,33 +567,533 @@ impl Settings {
         let flag = match file_type {
             AudioFileType::Wav => AudioSettings::EXPORT_WAV,
             AudioFileType::Flac => AudioSettings::EXPORT_FLAC,
-            AudioFileType::Ogg => {
-                // We don't support writing these formats.
-                return;
-            }
+            AudioFileType::Ogg => AudioSettings::EXPORT_OGG,
+        };
+
+        let mut audio_settings = self.audio_settings();
+
+        audio_settings.set(flag, value);
+
+        match self.active_profile_mut() {
+            Some(profile) => profile.audio_settings = Some(audio_settings),
+            None => self.audio_settings = audio_settings,
+        }
+    }
+
+    /// The transcript sidecar file types to export alongside audio.
+    pub fn transcript_file_types(&self) -> Vec<TranscriptFileType> {
+        let audio_settings = self.audio_settings();
+        let mut result = Vec::with_capacity(2);
+
+        if audio_settings.contains(AudioSettings::EXPORT_TRANSCRIPT_TXT) {
+            result.push(TranscriptFileType::Txt);
+        }
+
+        if audio_settings.contains(AudioSettings::EXPORT_TRANSCRIPT_JSON) {
+            result.push(TranscriptFileType::Json);
+        }
+
+        result
+    }
+
+    /// Sets whether or not a transcript sidecar file type is in use.
+    pub fn set_transcript_file_type(&mut self, file_type: TranscriptFileType, value: bool) {
+        let flag = match file_type {
+            TranscriptFileType::Txt => AudioSettings::EXPORT_TRANSCRIPT_TXT,
+            TranscriptFileType::Json => AudioSettings::EXPORT_TRANSCRIPT_JSON,
         };
 
-        self.audio_settings.set(flag, value);
+        let mut audio_settings = self.audio_settings();
+
+        audio_settings.set(flag, value);
+
+        match self.active_profile_mut() {
+            Some(profile) => profile.audio_settings = Some(audio_settings),
+            None => self.audio_settings = audio_settings,
+        }
     }
 
     /// The image file type to export to.
     pub fn image_file_type(&self) -> ImageFileType {
-        self.image_file_type
+        self.active_profile()
+            .and_then(|profile| profile.image_file_type)
+            .unwrap_or(self.image_file_type)
     }
 
     /// Sets the image file type to export to.
     pub fn set_image_file_type(&mut self, file_type: ImageFileType) {
-        self.image_file_type = file_type;
+        match self.active_profile_mut() {
+            Some(profile) => profile.image_file_type = Some(file_type),
+            None => self.image_file_type = file_type,
+        }
+    }
+
+    /// Gets the paths an asset of the given type and name would be exported to with the current settings, without writing anything.
+    ///
+    /// Each path is paired with the path it resolves to using the conflict policy, or `None` when it would be skipped.
+    pub fn dry_run_export_paths(
+        &self,
+        asset_type: ExportAssetType,
+        name: &str,
+    ) -> Vec<(PathBuf, Option<PathBuf>)> {
+        let path = self.output_directory_for(asset_type).join(name);
+
+        // Append the extension, as asset names may contain dots.
+        let with_extension = |extension: &OsStr| {
+            let mut path = path.clone().into_os_string();
+
+            path.push(".");
+            path.push(extension);
+
+            PathBuf::from(path)
+        };
+
+        let paths = match asset_type {
+            ExportAssetType::Model => self
+                .model_file_types()
+                .iter()
+                .map(|file_type| with_extension(file_type.as_ref()))
+                .collect(),
+            ExportAssetType::Image => vec![with_extension(self.image_file_type().as_ref())],
+            ExportAssetType::Animation => self
+                .anim_file_types()
+                .iter()
+                .map(|file_type| with_extension(file_type.as_ref()))
+                .collect(),
+            ExportAssetType::Sound => self
+                .audio_file_types()
+                .iter()
+                .map(|file_type| with_extension(file_type.as_ref()))
+                .collect(),
+            ExportAssetType::Material | ExportAssetType::RawFile => vec![path.clone()],
+        };
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let resolved = self.export_conflict_policy.resolve(&path);
+
+                (path, resolved)
+            })
+            .collect()
+    }
+
+    /// Estimates the size of exporting an asset of the given type and stored size, with the file types in use.
+    pub fn estimate_export_size(&self, asset_type: ExportAssetType, size: u64) -> u64 {
+        let ratio: f64 = match asset_type {
+            ExportAssetType::Model => self
+                .model_file_types()
+                .iter()
+                .map(|file_type| match file_type {
+                    ModelFileType::Cast | ModelFileType::Fbx => 1.0,
+                    _ => 3.0,
+                })
+                .sum(),
+            ExportAssetType::Image | ExportAssetType::Material => match self.image_file_type() {
+                ImageFileType::Dds => 1.0,
+                ImageFileType::Png => 2.0,
+                ImageFileType::Tiff | ImageFileType::Tga => 4.0,
+            },
+            ExportAssetType::Animation => self.anim_file_types().len() as f64,
+            ExportAssetType::Sound => self
+                .audio_file_types()
+                .iter()
+                .map(|file_type| match file_type {
+                    AudioFileType::Wav => 4.0,
+                    AudioFileType::Flac => 2.5,
+                    AudioFileType::Ogg => 1.0,
+                })
+                .sum(),
+            ExportAssetType::RawFile => 1.0,
+        };
+
+        (size as f64 * ratio) as u64
     }
 
     /// The image normal map processing technique.
     pub fn image_normal_map_processing(&self) -> ImageNormalMapProcessing {
-        self.image_normal_map_processing
+        self.active_profile()
+            .and_then(|profile| profile.image_normal_map_processing)
+            .unwrap_or(self.image_normal_map_processing)
     }
 
     /// Sets the image normal map processing.
     pub fn set_image_normal_map_processing(&mut self, processing: ImageNormalMapProcessing) {
-        self.image_normal_map_processing = processing;
+        match self.active_profile_mut() {
+            Some(profile) => profile.image_normal_map_processing = Some(processing),
+            None => self.image_normal_map_processing = processing,
+        }
+    }
+
+    /// Whether or not to apply the Unreal Engine naming and packing preset to material images.
+    pub fn image_unreal_preset(&self) -> bool {
+        self.image_unreal_preset
+    }
+
+    /// Sets whether or not to apply the Unreal Engine naming and packing preset to material images.
+    pub fn set_image_unreal_preset(&mut self, value: bool) {
+        self.image_unreal_preset = value;
+    }
+
+    /// Whether or not to export the alpha channel of images with meaningful alpha as a separate mask.
+    pub fn image_alpha_mask(&self) -> bool {
+        self.image_alpha_mask
+    }
+
+    /// Sets whether or not to export the alpha channel of images with meaningful alpha as a separate mask.
+    pub fn set_image_alpha_mask(&mut self, value: bool) {
+        self.image_alpha_mask = value;
+    }
+
+    /// The channels of packed images which are split into separate grayscale images, if enabled.
+    pub fn image_channel_remap(&self) -> Option<&ImageChannelRemap> {
+        self.image_channel_remap.as_ref()
+    }
+
+    /// Sets the channels of packed images which are split into separate grayscale images.
+    pub fn set_image_channel_remap(&mut self, remap: Option<ImageChannelRemap>) {
+        self.image_channel_remap = remap;
+    }
+
+    /// Saves the channels of the image as separate grayscale images next to the given path, when enabled.
+    pub fn save_remapped_channels(&self, image: &Image, path: &Path) -> Result<(), TextureError> {
+        let Some(remap) = &self.image_channel_remap else {
+            return Ok(());
+        };
+
+        image.save_remapped_channels(path, self.image_file_type(), remap)?;
+
+        Ok(())
+    }
+
+    /// The conversion applied between premultiplied and straight alpha.
+    pub fn image_alpha_processing(&self) -> ImageAlphaProcessing {
+        self.image_alpha_processing
+    }
+
+    /// Sets the conversion applied between premultiplied and straight alpha.
+    pub fn set_image_alpha_processing(&mut self, processing: ImageAlphaProcessing) {
+        self.image_alpha_processing = processing;
+    }
+
+    /// Saves the alpha channel of the image as a grayscale mask next to the given path, suffixed with `_alpha`,
+    /// when enabled and the image has meaningful alpha.
+    pub fn save_alpha_mask(&self, image: &Image, path: &Path) -> Result<(), TextureError> {
+        if !self.image_alpha_mask || !image.has_alpha()? {
+            return Ok(());
+        }
+
+        let file_type = self.image_file_type();
+
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default();
+
+        image.save_alpha_mask(
+            path.with_file_name(format!("{stem}_alpha"))
+                .with_extension(file_type),
+            file_type,
+        )?;
+
+        Ok(())
+    }
+
+    /// Converts the image between premultiplied and straight alpha, depending on the alpha processing.
+    /// Images without meaningful alpha are left as is, others are converted back to a format for the image file type.
+    pub fn apply_image_alpha_processing(&self, image: &mut Image) -> Result<(), TextureError> {
+        let Some(algorithm) = self.image_alpha_processing.transform_algorithm() else {
+            return Ok(());
+        };
+
+        if !image.has_alpha()? {
+            return Ok(());
+        }
+
+        image.transform(algorithm)?;
+        image.convert(
+            image.format_for_file_type(self.image_file_type()),
+            ImageConvertOptions::None,
+        )
+    }
+
+    /// The color profile embedded in exported srgb images.
+    pub fn image_color_profile(&self) -> ImageColorProfile {
+        self.image_color_profile
+    }
+
+    /// Sets the color profile embedded in exported srgb images.
+    pub fn set_image_color_profile(&mut self, profile: ImageColorProfile) {
+        self.image_color_profile = profile;
+    }
+
+    /// The memory limit, in megabytes, for images being decoded at once while exporting.
+    pub fn image_decode_budget(&self) -> u32 {
+        self.image_decode_budget
+    }
+
+    /// Sets the memory limit, in megabytes, for images being decoded at once while exporting.
+    pub fn set_image_decode_budget(&mut self, budget: u32) {
+        self.image_decode_budget = budget;
+    }
+
+    /// Creates a scheduler that limits decoded image memory to the configured budget.
+    pub fn image_decode_scheduler(&self) -> ImageDecodeScheduler {
+        ImageDecodeScheduler::new(self.image_decode_budget as u64 * 1024 * 1024)
+    }
+
+    /// Which frames of multi-frame images are exported.
+    pub fn image_frame_export(&self) -> ImageFrameExport {
+        self.image_frame_export
+    }
+
+    /// Sets which frames of multi-frame images are exported.
+    pub fn set_image_frame_export(&mut self, export: ImageFrameExport) {
+        self.image_frame_export = export;
+    }
+
+    /// Which mipmaps of images are exported.
+    pub fn image_mipmap_export(&self) -> ImageMipmapExport {
+        self.image_mipmap_export
+    }
+
+    /// Sets which mipmaps of images are exported.
+    pub fn set_image_mipmap_export(&mut self, export: ImageMipmapExport) {
+        self.image_mipmap_export = export;
+    }
+
+    /// The block compression used for dds images.
+    pub fn image_compression(&self) -> ImageCompression {
+        self.image_compression
+    }
+
+    /// Sets the block compression used for dds images.
+    pub fn set_image_compression(&mut self, compression: ImageCompression) {
+        self.image_compression = compression;
+    }
+
+    /// The quality preset used when block compressing dds images.
+    pub fn image_compression_quality(&self) -> ImageCompressionQuality {
+        self.image_compression_quality
+    }
+
+    /// Sets the quality preset used when block compressing dds images.
+    pub fn set_image_compression_quality(&mut self, quality: ImageCompressionQuality) {
+        self.image_compression_quality = quality;
+    }
+
+    /// Block compresses uncompressed images when exporting dds images with compression enabled.
+    /// Single and two channel images use Bc4 and Bc5, other images use the selected compression.
+    pub fn compress_image(&self, image: &mut Image) -> Result<(), TextureError> {
+        if self.image_file_type() != ImageFileType::Dds || image.format().is_compressed() {
+            return Ok(());
+        }
+
+        let srgb = image.format().is_srgb();
+
+        let format = match (self.image_compression, image.format()) {
+            (ImageCompression::None, _) => return Ok(()),
+            (_, ImageFormat::R8Unorm) => ImageFormat::Bc4Unorm,
+            (_, ImageFormat::R8G8Unorm) => ImageFormat::Bc5Unorm,
+            (ImageCompression::Bc1, _) if srgb => ImageFormat::Bc1UnormSrgb,
+            (ImageCompression::Bc1, _) => ImageFormat::Bc1Unorm,
+            (ImageCompression::Bc3, _) if srgb => ImageFormat::Bc3UnormSrgb,
+            (ImageCompression::Bc3, _) => ImageFormat::Bc3Unorm,
+            (ImageCompression::Bc7, _) if srgb => ImageFormat::Bc7UnormSrgb,
+            (ImageCompression::Bc7, _) => ImageFormat::Bc7Unorm,
+        };
+
+        image.compress(format, self.image_compression_quality)
+    }
+
+    /// Keeps only the highest mip, or generates a full mip chain for dds images, depending on the mipmap export.
+    /// Images in formats that mipmaps can't be generated for keep their existing mipmaps.
+    pub fn apply_image_mipmaps(&self, image: &mut Image) -> Result<(), TextureError> {
+        match self.image_mipmap_export {
+            ImageMipmapExport::Source => Ok(()),
+            ImageMipmapExport::HighestOnly => {
+                if image.mipmaps() > 1 {
+                    *image = image.mip(0)?;
+                }
+
+                Ok(())
+            }
+            ImageMipmapExport::Generate => {
+                if self.image_file_type() != ImageFileType::Dds || image.mipmaps() > 1 {
+                    return Ok(());
+                }
+
+                match image.generate_mipmaps(MipmapFilter::Box) {
+                    Ok(()) | Err(TextureError::UnsupportedImageFormat(_)) => Ok(()),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+
+    /// Saves the image in the selected image format next to the given path, along with its remapped channels and alpha mask when enabled.
+    /// The sprite sheet, mipmap export, and alpha processing are applied first, and dds images are block compressed last,
+    /// so the image is modified in place.
+    pub fn save_image(&self, image: &mut Image, path: &Path) -> Result<(), TextureError> {
+        let file_type = self.image_file_type();
+
+        self.assemble_sprite_sheet(image)?;
+        self.apply_image_mipmaps(image)?;
+        self.save_remapped_channels(image, path)?;
+        self.save_alpha_mask(image, path)?;
+        self.apply_image_alpha_processing(image)?;
+        self.compress_image(image)?;
+
+        image.save_with_options(
+            path.with_extension(file_type),
+            file_type,
+            self.image_save_options(),
+        )
+    }
+
+    /// The first and last frame, inclusive, exported when using a frame range.
+    pub fn image_frame_range(&self) -> (u32, u32) {
+        self.image_frame_range
+    }
+
+    /// Sets the first and last frame, inclusive, exported when using a frame range.
+    pub fn set_image_frame_range(&mut self, first: u32, last: u32) {
+        self.image_frame_range = (first, last.max(first));
+    }
+
+    /// Sets the previewed asset index and its selected frame, this is never saved.
+    pub fn set_preview_frame(&mut self, frame: Option<(usize, usize)>) {
+        self.preview_frame = frame;
+    }
+
+    /// How the assets of the current export are written.
+    pub fn export_mode(&self) -> ExportMode {
+        self.export_mode
+    }
+
+    /// Sets how the assets of the current export are written, this is never saved.
+    pub fn set_export_mode(&mut self, mode: ExportMode) {
+        self.export_mode = mode;
+    }
+
+    /// Whether or not the exported sounds should be combined, in order, into a single file.
+    pub fn export_combined_audio(&self) -> bool {
+        self.export_mode == ExportMode::Combined
+    }
+
+    /// Whether or not the exported models should be merged, in order, into a single model.
+    pub fn export_merged_models(&self) -> bool {
+        self.export_mode == ExportMode::Merged
+    }
+
+    /// Whether or not the exported animations should be concatenated, in order, into a single animation.
+    pub fn export_concatenated_animations(&self) -> bool {
+        self.export_mode == ExportMode::Concatenated
+    }
+
+    /// The animation frame to bake onto the model's skeleton, exporting the posed model instead of the animation.
+    pub fn export_pose_frame(&self) -> Option<u32> {
+        self.export_pose_frame
+    }
+
+    /// Sets the animation frame to bake onto the model's skeleton, this is never saved.
+    pub fn set_export_pose_frame(&mut self, frame: Option<u32>) {
+        self.export_pose_frame = frame;
+    }
+
+    /// The language variant selected by the user for previewing and exporting grouped assets.
+    pub fn asset_variant(&self) -> Option<&str> {
+        self.asset_variant.as_deref()
+    }
+
+    /// Sets the selected language variant for grouped assets, this is never saved.
+    pub fn set_asset_variant(&mut self, variant: Option<String>) {
+        self.asset_variant = variant;
+    }
+
+    /// Whether or not every language variant of grouped assets is exported.
+    pub fn export_all_variants(&self) -> bool {
+        self.export_all_variants
+    }
+
+    /// Sets whether or not every language variant of grouped assets is exported.
+    pub fn set_export_all_variants(&mut self, value: bool) {
+        self.export_all_variants = value;
+    }
+
+    /// Picks the variants of a grouped asset to export, falling back to the first variant when none is selected.
+    pub fn export_variants<'a>(&self, variants: &'a [String]) -> Vec<&'a str> {
+        if self.export_all_variants {
+            return variants.iter().map(String::as_str).collect();
+        }
+
+        variants
+            .iter()
+            .find(|variant| Some(variant.as_str()) == self.asset_variant())
+            .or_else(|| variants.first())
+            .map(String::as_str)
+            .into_iter()
+            .collect()
+    }
+
+    /// The range of frames to export for the given asset, falls back to every frame when the selection is empty.
+    pub fn image_frames(&self, asset: usize, frame_count: usize) -> Range<usize> {
+        let range = match self.image_frame_export {
+            ImageFrameExport::All => 0..frame_count,
+            ImageFrameExport::Previewed => match self.preview_frame {
+                Some((index, frame)) if index == asset => frame..frame + 1,
+                _ => 0..frame_count,
+            },
+            ImageFrameExport::Range => {
+                let (first, last) = self.image_frame_range;
+
+                first as usize..last as usize + 1
+            }
+        };
+
+        if range.start >= frame_count {
+            return 0..frame_count;
+        }
+
+        range.start..range.end.min(frame_count)
+    }
+
+    /// Removes the frames of the image which aren't selected for export.
+    pub fn retain_image_frames(&self, asset: usize, image: &mut Image) {
+        let frames = self.image_frames(asset, image.frames().len());
+
+        image.retain_frames(frames);
+    }
+
+    /// Whether or not multi-frame images are exported as a single sprite sheet.
+    pub fn image_sprite_sheet(&self) -> bool {
+        self.image_sprite_sheet
+    }
+
+    /// Sets whether or not multi-frame images are exported as a single sprite sheet.
+    pub fn set_image_sprite_sheet(&mut self, value: bool) {
+        self.image_sprite_sheet = value;
+    }
+
+    /// The number of columns in exported sprite sheets.
+    pub fn image_sprite_sheet_columns(&self) -> u32 {
+        self.image_sprite_sheet_columns.clamp(1, 64)
+    }
+
+    /// Sets the number of columns in exported sprite sheets.
+    pub fn set_image_sprite_sheet_columns(&mut self, columns: u32) {
+        self.image_sprite_sheet_columns = columns;
+    }
+
+    /// Assembles the frames of the image into a sprite sheet, when enabled and the image has more than one frame.
+    pub fn assemble_sprite_sheet(&self, image: &mut Image) -> Result<(), TextureError> {
+        if !self.image_sprite_sheet || image.frames().len() <= 1 {
+            return Ok(());
+        }
+
+        *image = ImageSpriteSheet::new(self.image_sprite_sheet_columns()).assemble(image)?;
+
+        Ok(())
     }
 
     /// An output directory used to save assets.
@@ -387,6 +1131,181 @@ impl Settings {
         self.output_directory = Some(path);
     }
 
+    /// Gets the layout used for exported files.
+    pub fn export_layout(&self) -> ExportLayout {
+        self.export_layout
+    }
+
+    /// Sets the layout used for exported files.
+    pub fn set_export_layout(&mut self, layout: ExportLayout) {
+        self.export_layout = layout;
+    }
+
+    /// Gets the folders used for each asset type by the type folders layout.
+    pub fn export_folders(&self) -> &ExportFolders {
+        &self.export_folders
+    }
+
+    /// Sets the folders used for each asset type by the type folders layout.
+    pub fn set_export_folders(&mut self, folders: ExportFolders) {
+        self.export_folders = folders;
+    }
+
+    /// Gets the output directory for the given asset type, using the export layout.
+    pub fn output_directory_for(&self, asset_type: ExportAssetType) -> PathBuf {
+        self.export_layout
+            .directory(self.output_directory(), &self.export_folders, asset_type)
+    }
+
+    /// Rewrites a texture file name, relative to the image folder, so that it's relative to the model folder.
+    pub fn texture_reference<F: AsRef<str>>(&self, file_name: F) -> String {
+        self.export_layout
+            .texture_reference(&self.export_folders, file_name)
+    }
+
+    /// Gets how exported files which already exist on disk are handled.
+    pub fn export_conflict_policy(&self) -> ExportConflictPolicy {
+        self.export_conflict_policy
+    }
+
+    /// Sets how exported files which already exist on disk are handled.
+    pub fn set_export_conflict_policy(&mut self, policy: ExportConflictPolicy) {
+        self.export_conflict_policy = policy;
+    }
+
+    /// Sets the conflicts collected while asking the user, this is never saved.
+    pub fn set_export_conflicts(&mut self, conflicts: ExportConflicts) {
+        self.export_conflicts = conflicts;
+    }
+
+    /// Resolves the path an exported file for the given asset should be written to, using the conflict policy.
+    ///
+    /// Returns `None` when the file should not be written, because it already exists.
+    pub fn resolve_export_path<P: AsRef<Path>>(&self, asset: usize, path: P) -> Option<PathBuf> {
+        self.resolve_export_paths(asset, &[path]).pop().flatten()
+    }
+
+    /// Resolves the paths every exported file of the given asset should be written to at once, using the conflict policy.
+    ///
+    /// Renamed files share one suffix across the formats of the asset, files which should not be written are `None`.
+    pub fn resolve_export_paths<P: AsRef<Path>>(
+        &self,
+        asset: usize,
+        paths: &[P],
+    ) -> Vec<Option<PathBuf>> {
+        for path in paths {
+            if let Some(parent) = path.as_ref().parent() {
+                let _ = self.directory_cache.create(parent);
+            }
+        }
+
+        self.export_conflicts
+            .resolve(self.export_conflict_policy, asset, paths)
+    }
+
+    /// Sets the cache of directories created during an export, this is never saved.
+    pub fn set_directory_cache(&mut self, cache: DirectoryCache) {
+        self.directory_cache = cache;
+    }
+
+    /// Creates the export directory and all of its parents, unless it was already created during this export.
+    pub fn create_export_directory<P: AsRef<Path>>(&self, path: P) -> Result<(), std::io::Error> {
+        self.directory_cache.create(path)
+    }
+
+    /// Creates each export directory up front, such as a folder per material, in parallel.
+    pub fn create_export_directories<I, P>(&self, paths: I) -> Result<(), std::io::Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.directory_cache.create_batch(paths)
+    }
+
+    /// Sets the failures collected for the export summary, this is never saved.
+    pub fn set_export_failures(&mut self, failures: ExportFailures) {
+        self.export_failures = failures;
+    }
+
+    /// Reports that a file for the given asset failed to be written, so that it's shown in the export summary.
+    pub fn report_export_failure<A: Into<String>, P: Into<PathBuf>>(
+        &self,
+        asset: A,
+        path: P,
+        error: &std::io::Error,
+    ) {
+        self.export_failures.push(asset, path, error);
+    }
+
+    /// Reports that the given asset failed to convert, so that it's shown in the export summary and written to the export log.
+    pub fn report_export_error<A: Into<String>, P: Into<PathBuf>, E: ReportError>(
+        &self,
+        asset: A,
+        path: P,
+        error: &E,
+    ) {
+        self.export_failures
+            .push_report(asset, path, error.report());
+    }
+
+    /// Sets the queue of assets being exported, this is never saved.
+    pub fn set_export_queue(&mut self, queue: ExportQueue) {
+        self.export_queue = queue;
+    }
+
+    /// Sets the statistics counting the assets exported per type, this is never saved.
+    pub(crate) fn set_export_statistics(&mut self, statistics: ExportStatistics) {
+        self.export_statistics = statistics;
+    }
+
+    /// Sets the manifest collecting the assets exported in this session, this is never saved.
+    pub fn set_export_session_manifest(&mut self, manifest: Option<ExportManifest>) {
+        self.export_session_manifest = manifest;
+    }
+
+    /// Records the files written for the given asset in the export manifest, when manifests are enabled.
+    pub fn record_exported_files<
+        N: Into<String>,
+        S: Into<String>,
+        T: Into<String>,
+        P: AsRef<Path>,
+    >(
+        &self,
+        asset: usize,
+        name: N,
+        source: S,
+        asset_type: T,
+        files: &[P],
+    ) {
+        if let Some(manifest) = &self.export_session_manifest {
+            manifest.push(asset, name, source, asset_type, files);
+        }
+    }
+
+    /// Records how long the given asset took to export in the export manifest, when manifests are enabled.
+    pub fn record_export_duration(&self, asset: usize, duration: Duration) {
+        if let Some(manifest) = &self.export_session_manifest {
+            manifest.finish(asset, duration);
+        }
+    }
+
+    /// Reports that the given asset started exporting, so that it's shown in the asset list.
+    pub fn export_job_started(&self, asset: usize) {
+        self.export_queue.set(asset, ExportJobState::Exporting);
+    }
+
+    /// Reports that the given asset finished exporting, so that it's shown in the asset list.
+    pub fn export_job_finished(&self, asset: usize) {
+        self.export_queue.set(asset, ExportJobState::Done);
+        self.export_statistics.finish(asset);
+    }
+
+    /// Reports that the given asset failed to export, so that the error is shown next to it in the asset list.
+    pub fn export_job_failed<E: ReportError>(&self, asset: usize, error: &E) {
+        self.export_queue
+            .set(asset, ExportJobState::Error(error.report()));
+    }
+
     /// Gets the preview control scheme.
     pub fn preview_controls(&self) -> PreviewControlScheme {
         self.preview_controls
@@ -407,6 +1326,16 @@ impl Settings {
         self.preview_overlay = value;
     }
 
+    /// Gets the background drawn behind the asset in the preview.
+    pub fn preview_background(&self) -> PreviewBackground {
+        self.preview_background
+    }
+
+    /// Sets the background drawn behind the asset in the preview.
+    pub fn set_preview_background(&mut self, background: PreviewBackground) {
+        self.preview_background = background;
+    }
+
     /// whether or not to automatically scale models and animations.
     pub fn auto_scale(&self) -> bool {
         self.auto_scale
@@ -427,6 +1356,36 @@ impl Settings {
         self.far_clip = far_clip;
     }
 
+    /// Gets the near clip distance for preview, none when it is fit to the model.
+    pub fn near_clip(&self) -> Option<f32> {
+        self.near_clip.map(|near_clip| near_clip.clamp(0.01, 10.0))
+    }
+
+    /// Sets the near clip distance for preview.
+    pub fn set_near_clip(&mut self, near_clip: Option<f32>) {
+        self.near_clip = near_clip;
+    }
+
+    /// Gets the field of view in degrees for preview.
+    pub fn fov(&self) -> u32 {
+        self.fov.clamp(30, 120)
+    }
+
+    /// Sets the field of view in degrees for preview.
+    pub fn set_fov(&mut self, fov: u32) {
+        self.fov = fov;
+    }
+
+    /// Gets the memory budget in megabytes for previewing an image, larger images preview a smaller mip.
+    pub fn preview_image_budget(&self) -> u32 {
+        self.preview_image_budget.clamp(64, 2048)
+    }
+
+    /// Sets the memory budget in megabytes for previewing an image.
+    pub fn set_preview_image_budget(&mut self, budget: u32) {
+        self.preview_image_budget = budget;
+    }
+
     /// Whether or not preview should open in a new window by default.
     pub fn preview_window(&self) -> bool {
         self.preview_window
@@ -437,6 +1396,44 @@ impl Settings {
         self.preview_window = value;
     }
 
+    /// Gets the GPU adapter used for previews and conversions, this takes effect after a restart.
+    pub fn gpu_adapter(&self) -> GPUAdapterPreference {
+        self.gpu_adapter.clone()
+    }
+
+    /// Sets the GPU adapter used for previews and conversions, this takes effect after a restart.
+    pub fn set_gpu_adapter(&mut self, adapter: GPUAdapterPreference) {
+        self.gpu_adapter = adapter;
+    }
+
+    /// Gets the number of export worker threads, or zero to use every thread of the thread pool.
+    pub fn export_threads(&self) -> u32 {
+        self.export_threads.min(64)
+    }
+
+    /// Sets the number of export worker threads, or zero to use every thread of the thread pool.
+    pub fn set_export_threads(&mut self, threads: u32) {
+        self.export_threads = threads;
+    }
+
+    /// Gets the maximum number of files written at once, or zero to match the export worker threads.
+    pub fn export_concurrent_writes(&self) -> u32 {
+        self.export_concurrent_writes.min(64)
+    }
+
+    /// Sets the maximum number of files written at once, or zero to match the export worker threads.
+    pub fn set_export_concurrent_writes(&mut self, writes: u32) {
+        self.export_concurrent_writes = writes;
+    }
+
+    /// Creates a semaphore that limits the number of files written at once while exporting.
+    pub fn export_write_semaphore(&self) -> AtomicSemaphore {
+        match self.export_concurrent_writes() {
+            0 => AtomicSemaphore::with_max(porter_threads::current_num_threads()),
+            writes => AtomicSemaphore::with_max(writes as usize),
+        }
+    }
+
     /// Gets the custom auto scale value to use.
     pub fn custom_scale(&self) -> Option<f32> {
         self.custom_scale
@@ -463,6 +1460,237 @@ impl Settings {
         self.volume = volume.clamp(0, 50);
     }
 
+    /// Whether or not to write a manifest of the exported assets.
+    pub fn export_manifest(&self) -> bool {
+        self.export_manifest
+    }
+
+    /// Sets whether or not to write a manifest of the exported assets.
+    pub fn set_export_manifest(&mut self, value: bool) {
+        self.export_manifest = value;
+    }
+
+    /// Whether or not to write blender import scripts alongside exported models and animations.
+    pub fn export_blender_script(&self) -> bool {
+        self.export_blender_script
+    }
+
+    /// Sets whether or not to write blender import scripts alongside exported models and animations.
+    pub fn set_export_blender_script(&mut self, value: bool) {
+        self.export_blender_script = value;
+    }
+
+    /// Whether or not meshes hidden in the previewer are excluded from exported models.
+    pub fn export_visible_meshes(&self) -> bool {
+        self.export_visible_meshes
+    }
+
+    /// Sets whether or not meshes hidden in the previewer are excluded from exported models.
+    pub fn set_export_visible_meshes(&mut self, value: bool) {
+        self.export_visible_meshes = value;
+    }
+
+    /// Sets the previewed asset index and its visible meshes, this is never saved.
+    pub fn set_preview_visibility(&mut self, visibility: Option<(usize, Vec<bool>)>) {
+        self.preview_visibility = visibility;
+    }
+
+    /// Removes the meshes hidden in the previewer from the model, when it belongs to the previewed asset.
+    pub fn retain_visible_meshes(&self, asset: usize, model: &mut Model) {
+        if !self.export_visible_meshes {
+            return;
+        }
+
+        if let Some((index, visible)) = &self.preview_visibility
+            && *index == asset
+        {
+            model.retain_visible_meshes(visible);
+        }
+    }
+
+    /// Whether or not unnamed meshes are named after their material when exported.
+    pub fn material_mesh_names(&self) -> bool {
+        self.material_mesh_names
+    }
+
+    /// Sets whether or not unnamed meshes are named after their material when exported.
+    pub fn set_material_mesh_names(&mut self, value: bool) {
+        self.material_mesh_names = value;
+    }
+
+    /// Gets the naming strategy used for exported meshes.
+    pub fn mesh_naming(&self) -> MeshNaming {
+        if self.material_mesh_names {
+            MeshNaming::Material
+        } else {
+            MeshNaming::Index
+        }
+    }
+
+    /// Whether or not cast node hashes are derived from names, so repeated exports are identical.
+    pub fn cast_stable_hashes(&self) -> bool {
+        self.cast_stable_hashes
+    }
+
+    /// Sets whether or not cast node hashes are derived from names, so repeated exports are identical.
+    pub fn set_cast_stable_hashes(&mut self, value: bool) {
+        self.cast_stable_hashes = value;
+    }
+
+    /// Whether or not cast files are compressed, which requires an importer that supports it.
+    pub fn cast_compression(&self) -> bool {
+        self.cast_compression
+    }
+
+    /// Sets whether or not cast files are compressed, which requires an importer that supports it.
+    pub fn set_cast_compression(&mut self, value: bool) {
+        self.cast_compression = value;
+    }
+
+    /// Whether or not usage statistics are recorded, they are only stored locally in the config folder.
+    pub fn usage_statistics(&self) -> bool {
+        self.usage_statistics
+    }
+
+    /// Sets whether or not usage statistics are recorded, they are only stored locally in the config folder.
+    pub fn set_usage_statistics(&mut self, value: bool) {
+        self.usage_statistics = value;
+    }
+
+    /// Whether or not the splash screen is skipped when the app launches.
+    pub fn skip_splash(&self) -> bool {
+        self.skip_splash
+    }
+
+    /// Sets whether or not the splash screen is skipped when the app launches.
+    pub fn set_skip_splash(&mut self, value: bool) {
+        self.skip_splash = value;
+    }
+
+    /// Whether or not decorative animations are disabled.
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Sets whether or not decorative animations are disabled.
+    pub fn set_reduced_motion(&mut self, value: bool) {
+        self.reduced_motion = value;
+    }
+
+    /// Gets the hash mode used when writing cast files.
+    pub fn cast_hash_mode(&self) -> CastHashMode {
+        if self.cast_stable_hashes {
+            CastHashMode::Stable
+        } else {
+            CastHashMode::Sequential
+        }
+    }
+
+    /// Gets the options used when writing cast files.
+    pub fn cast_options(&self) -> CastOptions {
+        CastOptions {
+            hash_mode: self.cast_hash_mode(),
+            compressed: self.cast_compression,
+        }
+    }
+
+    /// Gets the options used when saving models, pass these to [`Model::save_with_options`].
+    pub fn model_save_options(&self) -> ModelSaveOptions {
+        ModelSaveOptions {
+            mesh_naming: self.mesh_naming(),
+            cast: self.cast_options(),
+        }
+    }
+
+    /// Gets the options used when saving images, pass these to [`Image::save_with_options`].
+    pub fn image_save_options(&self) -> ImageSaveOptions {
+        ImageSaveOptions {
+            color_profile: self.image_color_profile,
+        }
+    }
+
+    /// Gets the user provided name databases used to resolve asset names.
+    pub fn name_databases(&self) -> &[PathBuf] {
+        &self.name_databases
+    }
+
+    /// Adds a user provided name database used to resolve asset names.
+    pub fn add_name_database(&mut self, path: PathBuf) {
+        if !self.name_databases.contains(&path) {
+            self.name_databases.push(path);
+        }
+    }
+
+    /// Removes all of the user provided name databases.
+    pub fn clear_name_databases(&mut self) {
+        self.name_databases.clear();
+    }
+
+    /// Gets the rules used to color rows in the asset list, in order of priority.
+    pub fn row_color_rules(&self) -> &[RowColorRule] {
+        &self.row_color_rules
+    }
+
+    /// Adds a rule used to color rows in the asset list, with the lowest priority.
+    pub fn add_row_color_rule(&mut self, rule: RowColorRule) {
+        if !self.row_color_rules.contains(&rule) {
+            self.row_color_rules.push(rule);
+        }
+    }
+
+    /// Removes the rule used to color rows in the asset list at the given index.
+    pub fn remove_row_color_rule(&mut self, index: usize) {
+        if index < self.row_color_rules.len() {
+            self.row_color_rules.remove(index);
+        }
+    }
+
+    /// Gets the active settings profile, or none if using the global settings.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Sets the active settings profile, or none to use the global settings.
+    pub fn set_profile(&mut self, profile: Option<String>) {
+        self.profile = profile;
+    }
+
+    /// Gets the names of all known settings profiles, including the active one.
+    pub fn profiles(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.profiles.keys().cloned().collect();
+
+        if let Some(profile) = &self.profile
+            && !self.profiles.contains_key(profile)
+        {
+            result.push(profile.clone());
+            result.sort();
+        }
+
+        result
+    }
+
+    /// Whether or not the active profile overrides any of the global settings.
+    pub fn profile_has_overrides(&self) -> bool {
+        self.active_profile()
+            .is_some_and(|profile| profile.has_overrides())
+    }
+
+    /// Removes all overrides from the active profile, reverting to the global settings.
+    pub fn reset_profile(&mut self) {
+        if let Some(profile) = &self.profile {
+            self.profiles.remove(profile);
+        }
+    }
+
+    /// Resets every setting to its default, keeping the settings profiles and the active profile.
+    pub fn reset_defaults(&mut self) {
+        *self = Self {
+            profile: self.profile.take(),
+            profiles: std::mem::take(&mut self.profiles),
+            ..Default::default()
+        };
+    }
+
     /// Update settings and returns a copy.
     pub fn update<F: FnOnce(&mut Self)>(&self, callback: F) -> Self {
         let mut settings = self.clone();
@@ -473,10 +1701,45 @@ impl Settings {
     }
 }
 
+impl Settings {
+    /// Gets the active profile overrides if any exist.
+    fn active_profile(&self) -> Option<&SettingsProfile> {
+        self.profiles.get(self.profile.as_ref()?)
+    }
+
+    /// Gets the active profile overrides, creating them if necessary.
+    fn active_profile_mut(&mut self) -> Option<&mut SettingsProfile> {
+        let profile = self.profile.clone()?;
+
+        Some(self.profiles.entry(profile).or_default())
+    }
+
+    /// The model settings, with the active profile applied.
+    fn model_settings(&self) -> ModelSettings {
+        self.active_profile()
+            .and_then(|profile| profile.model_settings)
+            .unwrap_or(self.model_settings)
+    }
+
+    /// The animation settings, with the active profile applied.
+    fn anim_settings(&self) -> AnimSettings {
+        self.active_profile()
+            .and_then(|profile| profile.anim_settings)
+            .unwrap_or(self.anim_settings)
+    }
+
+    /// The audio settings, with the active profile applied.
+    fn audio_settings(&self) -> AudioSettings {
+        self.active_profile()
+            .and_then(|profile| profile.audio_settings)
+            .unwrap_or(self.audio_settings)
+    }
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: SETTINGS_VERSION,
             load_settings: LoadSettings::all()
                 & !LoadSettings::LOAD_RAW_FILES
                 & !LoadSettings::LOAD_FORCE_RAW_FILES,
@@ -485,14 +1748,62 @@ impl Default for Settings {
             audio_settings: AudioSettings::EXPORT_WAV,
             image_file_type: ImageFileType::Png,
             image_normal_map_processing: ImageNormalMapProcessing::OpenGl,
+            image_unreal_preset: false,
+            image_alpha_mask: false,
+            image_channel_remap: None,
+            image_alpha_processing: ImageAlphaProcessing::None,
+            image_decode_budget: 4096,
+            image_frame_export: ImageFrameExport::All,
+            image_mipmap_export: ImageMipmapExport::Source,
+            image_compression: ImageCompression::None,
+            image_compression_quality: ImageCompressionQuality::Normal,
+            image_frame_range: (0, 0),
+            image_sprite_sheet: false,
+            image_sprite_sheet_columns: 4,
+            image_color_profile: ImageColorProfile::Chunks,
             output_directory: None,
+            export_layout: ExportLayout::Default,
+            export_folders: ExportFolders::default(),
             preview_controls: PreviewControlScheme::Simple,
             preview_overlay: true,
+            preview_background: PreviewBackground::default(),
             auto_scale: true,
             far_clip: 10000,
+            near_clip: None,
+            fov: 65,
+            preview_image_budget: 256,
             preview_window: false,
+            gpu_adapter: GPUAdapterPreference::HighPerformance,
+            export_threads: 0,
+            export_concurrent_writes: 0,
             custom_scale: None,
             volume: 30,
+            export_manifest: false,
+            export_blender_script: false,
+            export_visible_meshes: false,
+            material_mesh_names: false,
+            cast_stable_hashes: false,
+            cast_compression: false,
+            usage_statistics: true,
+            skip_splash: false,
+            reduced_motion: false,
+            name_databases: Vec::new(),
+            row_color_rules: Vec::new(),
+            profile: None,
+            profiles: BTreeMap::new(),
+            preview_visibility: None,
+            preview_frame: None,
+            export_mode: ExportMode::Separate,
+            export_pose_frame: None,
+            asset_variant: None,
+            export_all_variants: false,
+            export_conflict_policy: ExportConflictPolicy::Overwrite,
+            export_conflicts: ExportConflicts::default(),
+            export_failures: ExportFailures::default(),
+            export_queue: ExportQueue::default(),
+            export_statistics: ExportStatistics::default(),
+            export_session_manifest: None,
+            directory_cache: DirectoryCache::default(),
         }
     }
 }
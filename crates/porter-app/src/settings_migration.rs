@@ -0,0 +1,76 @@
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::Settings;
+
+/// The current version of the settings format.
+pub(crate) const SETTINGS_VERSION: u32 = 2;
+
+/// A migration which upgrades the settings from one version to the next.
+type SettingsMigration = fn(&mut Map<String, Value>);
+
+/// Migrations indexed by the version they upgrade from, starting at version 1.
+const SETTINGS_MIGRATIONS: [SettingsMigration; SETTINGS_VERSION as usize - 1] = [migrate_v1_to_v2];
+
+/// Decodes settings from the given buffer, upgrading older versions as necessary.
+pub(crate) fn migrate_settings(buffer: &[u8]) -> Settings {
+    let Ok(Value::Object(mut settings)) = serde_json::from_slice::<Value>(buffer) else {
+        return Default::default();
+    };
+
+    let mut version = settings
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(1)
+        .max(1) as u32;
+
+    while version < SETTINGS_VERSION {
+        SETTINGS_MIGRATIONS[version as usize - 1](&mut settings);
+
+        version += 1;
+    }
+
+    settings.insert(String::from("version"), json!(SETTINGS_VERSION));
+
+    let settings = Value::Object(settings);
+
+    if let Ok(settings) = serde_json::from_value::<Settings>(settings.clone()) {
+        return settings;
+    }
+
+    merge_settings(settings)
+}
+
+/// Merges each field that can be decoded on top of the default settings, discarding the rest.
+fn merge_settings(settings: Value) -> Settings {
+    let Value::Object(settings) = settings else {
+        return Default::default();
+    };
+
+    let Value::Object(mut result) = json!(Settings::default()) else {
+        return Default::default();
+    };
+
+    for (key, value) in settings {
+        let Some(previous) = result.get(&key).cloned() else {
+            continue;
+        };
+
+        result.insert(key.clone(), value);
+
+        if serde_json::from_value::<Settings>(Value::Object(result.clone())).is_err() {
+            result.insert(key, previous);
+        }
+    }
+
+    serde_json::from_value(Value::Object(result)).unwrap_or_default()
+}
+
+/// Version 2 introduced per-game settings profiles.
+fn migrate_v1_to_v2(settings: &mut Map<String, Value>) {
+    settings.entry("profile").or_insert(Value::Null);
+    settings
+        .entry("profiles")
+        .or_insert_with(|| Value::Object(Map::new()));
+}
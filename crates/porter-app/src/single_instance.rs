@@ -0,0 +1,219 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[cfg(not(unix))]
+use std::net::Ipv4Addr;
+#[cfg(not(unix))]
+use std::net::SocketAddr;
+#[cfg(not(unix))]
+use std::net::TcpListener as InstanceListener;
+#[cfg(not(unix))]
+use std::net::TcpStream as InstanceStream;
+
+#[cfg(unix)]
+use std::io::ErrorKind;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener as InstanceListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream as InstanceStream;
+
+use directories::BaseDirs;
+#[cfg(unix)]
+use directories::ProjectDirs;
+
+use crate::Controller;
+
+/// The first port in the range used for single instance sockets.
+#[cfg(not(unix))]
+const INSTANCE_PORT_START: u16 = 49152;
+/// The number of ports in the range used for single instance sockets.
+#[cfg(not(unix))]
+const INSTANCE_PORT_COUNT: u16 = 16384;
+/// How long to wait for the running instance to respond.
+const INSTANCE_TIMEOUT: Duration = Duration::from_secs(2);
+/// The response sent by the running instance once files have been received.
+const INSTANCE_ACKNOWLEDGE: &str = "ok";
+
+/// The address of the single instance socket, a socket file on unix and a loopback port elsewhere.
+#[cfg(unix)]
+type InstanceAddress = PathBuf;
+/// The address of the single instance socket, a socket file on unix and a loopback port elsewhere.
+#[cfg(not(unix))]
+type InstanceAddress = SocketAddr;
+
+/// Guards against running more than one instance of an app per user, forwarding load requests to the running instance.
+pub(crate) struct SingleInstance {
+    listener: InstanceListener,
+    handshake: String,
+}
+
+impl SingleInstance {
+    /// Acquires the instance for the app with the given name, for the current user.
+    ///
+    /// If another instance is running, the files are forwarded to it and `Err` is returned. If the instance can't be
+    /// acquired for any other reason, `Ok(None)` is returned and the app runs without a guard.
+    pub fn acquire(name: &str, files: &[PathBuf]) -> Result<Option<Self>, ()> {
+        let user = user_identity();
+        let handshake = format!("porter:{}:{}", name.to_lowercase(), user);
+
+        let Some(address) = instance_address(name, &user) else {
+            return Ok(None);
+        };
+
+        if let Ok(listener) = InstanceListener::bind(&address) {
+            return Ok(Some(Self {
+                listener,
+                handshake,
+            }));
+        }
+
+        if forward_files(&address, &handshake, files).is_some() {
+            return Err(());
+        }
+
+        // A socket file is left behind when an instance exits without cleaning up, nothing listens on it anymore.
+        #[cfg(unix)]
+        if InstanceStream::connect(&address)
+            .is_err_and(|error| error.kind() == ErrorKind::ConnectionRefused)
+            && std::fs::remove_file(&address).is_ok()
+            && let Ok(listener) = InstanceListener::bind(&address)
+        {
+            return Ok(Some(Self {
+                listener,
+                handshake,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Listens for load requests from other instances, and sends them to the given controller.
+    pub fn listen(self, controller: Controller) {
+        let result = std::thread::Builder::new()
+            .name(String::from("porter-single-instance"))
+            .spawn(move || {
+                for stream in self.listener.incoming().flatten() {
+                    if let Some(files) = receive_files(stream, &self.handshake) {
+                        controller.load_files_forwarded(files);
+                    }
+                }
+            });
+
+        debug_assert!(result.is_ok());
+    }
+}
+
+/// Identifies the current user, so that instances of different users on the same machine never talk to each other.
+fn user_identity() -> String {
+    BaseDirs::new()
+        .map(|directories| directories.home_dir().to_string_lossy().into_owned())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_default()
+}
+
+/// Computes a stable socket file for the app with the given name, in the user's runtime or config directory.
+#[cfg(unix)]
+fn instance_address(name: &str, _user: &str) -> Option<InstanceAddress> {
+    let project_directory = ProjectDirs::from("com", "DTZxPorter", "GameTools")?;
+
+    let directory = project_directory
+        .runtime_dir()
+        .unwrap_or_else(|| project_directory.config_dir());
+
+    std::fs::create_dir_all(directory).ok()?;
+
+    Some(directory.join(format!("{}_instance.sock", name.to_lowercase())))
+}
+
+/// Computes a stable loopback address for the app with the given name and user.
+#[cfg(not(unix))]
+fn instance_address(name: &str, user: &str) -> Option<InstanceAddress> {
+    // FNV-1a, which is stable between builds unlike the default hasher.
+    let hash = name
+        .to_lowercase()
+        .bytes()
+        .chain([0])
+        .chain(user.bytes())
+        .fold(0x811C9DC5u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        });
+
+    let port = INSTANCE_PORT_START + (hash % INSTANCE_PORT_COUNT as u32) as u16;
+
+    Some(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+}
+
+/// Connects to the running instance at the given address.
+#[cfg(unix)]
+fn connect(address: &InstanceAddress) -> Option<InstanceStream> {
+    InstanceStream::connect(address).ok()
+}
+
+/// Connects to the running instance at the given address.
+#[cfg(not(unix))]
+fn connect(address: &InstanceAddress) -> Option<InstanceStream> {
+    InstanceStream::connect_timeout(address, INSTANCE_TIMEOUT).ok()
+}
+
+/// Sends the given files to the running instance, returning `None` if it did not acknowledge them.
+fn forward_files(address: &InstanceAddress, handshake: &str, files: &[PathBuf]) -> Option<()> {
+    let mut stream = connect(address)?;
+
+    stream.set_read_timeout(Some(INSTANCE_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(INSTANCE_TIMEOUT)).ok()?;
+
+    let mut request = format!("{}\n", handshake);
+
+    for file in files {
+        let file = file.canonicalize().unwrap_or_else(|_| file.clone());
+
+        request.push_str(&file.to_string_lossy());
+        request.push('\n');
+    }
+
+    request.push('\n');
+
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut response = String::new();
+
+    BufReader::new(stream).read_line(&mut response).ok()?;
+
+    (response.trim_end() == INSTANCE_ACKNOWLEDGE).then_some(())
+}
+
+/// Receives files from another instance, returning `None` if the request was not from an instance of this app and user.
+fn receive_files(stream: InstanceStream, handshake: &str) -> Option<Vec<PathBuf>> {
+    stream.set_read_timeout(Some(INSTANCE_TIMEOUT)).ok()?;
+
+    let mut reader = BufReader::new(stream);
+    let mut lines = (&mut reader).lines();
+
+    if lines.next()?.ok()? != handshake {
+        return None;
+    }
+
+    let mut files = Vec::new();
+
+    for line in lines {
+        let line = line.ok()?;
+
+        if line.is_empty() {
+            break;
+        }
+
+        files.push(PathBuf::from(line));
+    }
+
+    let mut stream = reader.into_inner();
+
+    stream
+        .write_all(format!("{}\n", INSTANCE_ACKNOWLEDGE).as_bytes())
+        .ok()?;
+
+    Some(files)
+}
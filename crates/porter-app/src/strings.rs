@@ -17,3 +17,5 @@ pub const PORTER_BUG_REPORT: &str =
 pub const GRAPHICS_DRIVER_ERROR: &str = "Failed to initialize a graphics device. This could be due to outdated graphics drivers on your system. For troubleshooting steps, please see the game tools tutorial: https://dtzxporter.com/game-tools-tutorial.";
 /// Program graphics device message.
 pub const GRAPHICS_DEVICE_ERROR: &str = "Failed to find a suitable graphics device. For troubleshooting steps, please see the game tools tutorial: https://dtzxporter.com/game-tools-tutorial.";
+/// Program export conflicts prompt, following the number of assets.
+pub const EXPORT_CONFLICTS_PROMPT: &str = "exported asset(s) had files which already exist, and were skipped.\n\nDo you want to overwrite them?";
@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::sync::OnceLock;
 
 /// System specific workarounds for various issues.
 pub fn initialize_workarounds() {
@@ -109,3 +110,54 @@ pub fn open_folder<F: AsRef<Path>>(folder: F) {
         debug_assert!(result.is_ok());
     }
 }
+
+/// Gets the decimal and digit grouping separators for numbers in the user's locale.
+pub fn number_separators() -> (char, char) {
+    static SEPARATORS: OnceLock<(char, char)> = OnceLock::new();
+
+    *SEPARATORS.get_or_init(|| {
+        #[cfg(target_os = "windows")]
+        {
+            use windows_sys::Win32::Globalization::*;
+
+            let separator = |kind: u32, default: char| {
+                let mut buffer = [0u16; 8];
+
+                // SAFETY: The buffer is valid for the given length, and a null locale name is the user's default locale.
+                let length = unsafe {
+                    GetLocaleInfoEx(
+                        std::ptr::null(),
+                        kind,
+                        buffer.as_mut_ptr(),
+                        buffer.len() as i32,
+                    )
+                };
+
+                char::decode_utf16(buffer[0..(length.max(1) - 1) as usize].iter().copied())
+                    .next()
+                    .and_then(Result::ok)
+                    .unwrap_or(default)
+            };
+
+            (
+                separator(LOCALE_SDECIMAL, '.'),
+                separator(LOCALE_STHOUSAND, ','),
+            )
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+                .iter()
+                .filter_map(|name| std::env::var(name).ok())
+                .find(|value| !value.is_empty())
+                .unwrap_or_default();
+
+            match locale.get(0..2).unwrap_or_default() {
+                "de" | "es" | "it" | "nl" | "pt" | "tr" | "id" => (',', '.'),
+                "fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => (',', '\u{A0}'),
+                _ => ('.', ','),
+            }
+        }
+    })
+}
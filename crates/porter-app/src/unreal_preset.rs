@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use porter_model::Material;
+use porter_model::MaterialTextureRef;
+use porter_model::MaterialTextureRefUsage;
+use porter_texture::Image;
+use porter_texture::ImageChannel;
+use porter_texture::ImageChannelPacker;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageSaveOptions;
+use porter_texture::TextureError;
+
+/// Unreal Engine texture naming and ORM packing applied to exported materials.
+pub struct UnrealPreset;
+
+impl UnrealPreset {
+    /// Gets the Unreal Engine base name for the given material name.
+    pub fn material_base_name<N: AsRef<str>>(name: N) -> String {
+        let name = name.as_ref();
+
+        name.strip_prefix("MI_")
+            .or_else(|| name.strip_prefix("M_"))
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    /// Gets the Unreal Engine texture suffix and material parameter for the given usage.
+    pub fn texture_suffix(usage: MaterialTextureRefUsage) -> (&'static str, &'static str) {
+        match usage {
+            MaterialTextureRefUsage::Albedo | MaterialTextureRefUsage::Diffuse => {
+                ("D", "BaseColor")
+            }
+            MaterialTextureRefUsage::Specular => ("S", "Specular"),
+            MaterialTextureRefUsage::Normal => ("N", "Normal"),
+            MaterialTextureRefUsage::Emissive => ("E", "EmissiveColor"),
+            MaterialTextureRefUsage::EmissiveMask => ("EM", "EmissiveMask"),
+            MaterialTextureRefUsage::EmissiveStrength => ("ES", "EmissiveStrength"),
+            MaterialTextureRefUsage::Gloss => ("G", "Gloss"),
+            MaterialTextureRefUsage::Roughness => ("R", "Roughness"),
+            MaterialTextureRefUsage::AmbientOcclusion => ("AO", "AmbientOcclusion"),
+            MaterialTextureRefUsage::Anisotropy => ("A", "Anisotropy"),
+            MaterialTextureRefUsage::Cavity => ("C", "Cavity"),
+            MaterialTextureRefUsage::Metalness => ("M", "Metallic"),
+            MaterialTextureRefUsage::Unknown | MaterialTextureRefUsage::Count => ("X", "Unknown"),
+        }
+    }
+
+    /// Exports the textures of a material using Unreal Engine naming, packing occlusion, roughness, and metalness
+    /// into a single ORM texture, and writes a json sidecar describing the material. Returns the files written.
+    pub fn export_material<P: AsRef<Path>, F: FnMut(&MaterialTextureRef) -> Option<Image>>(
+        directory: P,
+        material: &Material,
+        file_type: ImageFileType,
+        options: ImageSaveOptions,
+        mut load: F,
+    ) -> Result<Vec<PathBuf>, TextureError> {
+        let directory = directory.as_ref();
+        let base_name = Self::material_base_name(&material.name);
+
+        let mut files = Vec::with_capacity(material.textures.len() + 1);
+        let mut parameters = Map::new();
+        let mut used_names: HashSet<String> = HashSet::new();
+
+        let mut occlusion: Option<Image> = None;
+        let mut roughness: Option<Image> = None;
+        let mut metalness: Option<Image> = None;
+
+        let save = |name: String, image: &mut Image| -> Result<PathBuf, TextureError> {
+            let mut path = directory.join(name).into_os_string();
+
+            path.push(".");
+            path.push(file_type);
+
+            let path = PathBuf::from(path);
+
+            image.convert(
+                image.format_for_file_type(file_type),
+                ImageConvertOptions::None,
+            )?;
+            image.save_with_options(&path, file_type, options)?;
+
+            Ok(path)
+        };
+
+        for texture in &material.textures {
+            if texture.is_empty() {
+                continue;
+            }
+
+            let packed = match texture.texture_usage {
+                MaterialTextureRefUsage::AmbientOcclusion => Some(&mut occlusion),
+                MaterialTextureRefUsage::Roughness => Some(&mut roughness),
+                MaterialTextureRefUsage::Metalness => Some(&mut metalness),
+                _ => None,
+            };
+
+            if let Some(packed) = packed
+                && packed.is_none()
+            {
+                *packed = load(texture);
+                continue;
+            }
+
+            let Some(mut image) = load(texture) else {
+                continue;
+            };
+
+            let (suffix, parameter) = Self::texture_suffix(texture.texture_usage);
+
+            let mut name = format!("T_{base_name}_{suffix}");
+            let mut index = 1;
+
+            while !used_names.insert(name.clone()) {
+                name = format!("T_{base_name}_{suffix}{index}");
+                index += 1;
+            }
+
+            let path = save(name, &mut image)?;
+
+            if !parameters.contains_key(parameter) {
+                parameters.insert(parameter.to_string(), json!(file_name(&path)));
+            }
+
+            files.push(path);
+        }
+
+        if occlusion.is_some() || roughness.is_some() || metalness.is_some() {
+            let mut packer = ImageChannelPacker::new()
+                .default_value(ImageChannel::Red, 0xFF)
+                .default_value(ImageChannel::Green, 0xFF)
+                .default_value(ImageChannel::Blue, 0x0);
+
+            if let Some(occlusion) = &occlusion {
+                packer = packer.red(occlusion, ImageChannel::Red);
+            }
+
+            if let Some(roughness) = &roughness {
+                packer = packer.green(roughness, ImageChannel::Red);
+            }
+
+            if let Some(metalness) = &metalness {
+                packer = packer.blue(metalness, ImageChannel::Red);
+            }
+
+            let mut image = packer.pack()?;
+
+            let path = save(format!("T_{base_name}_ORM"), &mut image)?;
+
+            parameters.insert(String::from("ORM"), json!(file_name(&path)));
+
+            files.push(path);
+        }
+
+        let sidecar = json!({
+            "material": format!("M_{base_name}"),
+            "source": material.source_name,
+            "textures": Value::Object(parameters),
+        });
+
+        let path = directory.join(format!("M_{base_name}.json"));
+
+        let buffer = serde_json::to_vec_pretty(&sidecar).map_err(std::io::Error::from)?;
+
+        std::fs::write(&path, buffer)?;
+
+        files.push(path);
+
+        Ok(files)
+    }
+}
+
+/// Gets the file name of the given path as a string.
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use directories::ProjectDirs;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::AssetManager;
+
+/// Local usage statistics, which are only ever stored in the config directory.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub(crate) struct UsageStatistics {
+    exported: BTreeMap<String, u64>,
+    games: BTreeMap<String, u64>,
+    export_seconds: u64,
+}
+
+impl UsageStatistics {
+    /// Loads the statistics for the app with the given name, or returns new ones.
+    pub fn load(name: &str) -> Self {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return Default::default();
+        };
+
+        std::fs::read(
+            project_directory
+                .config_dir()
+                .join(format!("{}_statistics", name.to_lowercase()))
+                .with_extension("json"),
+        )
+        .ok()
+        .and_then(|buffer| serde_json::from_slice(&buffer).ok())
+        .unwrap_or_default()
+    }
+
+    /// Saves the statistics for the app with the given name.
+    pub fn save(&self, name: &str) {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return;
+        };
+
+        let Ok(result) = serde_json::to_vec_pretty(self) else {
+            return;
+        };
+
+        let _ = std::fs::create_dir_all(project_directory.config_dir());
+        let _ = std::fs::write(
+            project_directory
+                .config_dir()
+                .join(format!("{}_statistics", name.to_lowercase()))
+                .with_extension("json"),
+            result,
+        );
+    }
+
+    /// Records a finished export of the given number of assets per type, for the given game.
+    pub fn record_export(&mut self, game: &str, exported: &BTreeMap<String, u64>, time: Duration) {
+        let total: u64 = exported.values().sum();
+
+        for (asset_type, count) in exported {
+            *self.exported.entry(asset_type.clone()).or_default() += count;
+        }
+
+        if total > 0 {
+            *self.games.entry(game.to_owned()).or_default() += total;
+        }
+
+        self.export_seconds += time.as_secs();
+    }
+
+    /// The total number of assets exported.
+    pub fn total_exported(&self) -> u64 {
+        self.exported.values().sum()
+    }
+
+    /// The number of assets exported per type, most exported first.
+    pub fn exported_by_type(&self) -> Vec<(&str, u64)> {
+        sorted_counts(&self.exported)
+    }
+
+    /// The number of assets exported per game, most exported first.
+    pub fn exported_by_game(&self) -> Vec<(&str, u64)> {
+        sorted_counts(&self.games)
+    }
+
+    /// The total time spent exporting.
+    pub fn export_time(&self) -> Duration {
+        Duration::from_secs(self.export_seconds)
+    }
+}
+
+/// Counts the assets exported per type during an export, shared between export threads.
+#[derive(Clone, Default)]
+pub(crate) struct ExportStatistics {
+    asset_manager: Option<Arc<dyn AssetManager>>,
+    exported: Arc<Mutex<BTreeMap<String, u64>>>,
+}
+
+impl ExportStatistics {
+    /// Constructs new export statistics, which look up the type of each exported asset in the given asset manager.
+    pub fn new(asset_manager: Arc<dyn AssetManager>) -> Self {
+        Self {
+            asset_manager: Some(asset_manager),
+            exported: Default::default(),
+        }
+    }
+
+    /// Counts the given asset as exported, by its type.
+    pub fn finish(&self, asset: usize) {
+        let Some(asset_manager) = &self.asset_manager else {
+            return;
+        };
+
+        let asset_type = asset_manager
+            .asset_export_name(asset)
+            .map(|(asset_type, _)| asset_type)
+            .or_else(|| {
+                asset_manager
+                    .asset_size(asset)
+                    .map(|(asset_type, _)| asset_type)
+            })
+            .map_or("Other", |asset_type| asset_type.name());
+
+        if let Ok(mut exported) = self.exported.lock() {
+            *exported.entry(asset_type.to_owned()).or_default() += 1;
+        }
+    }
+
+    /// Takes the number of assets exported per type so far.
+    pub fn take(&self) -> BTreeMap<String, u64> {
+        self.exported
+            .lock()
+            .map(|mut exported| std::mem::take(&mut *exported))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for ExportStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExportStatistics")
+            .field("exported", &self.exported)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sorts the given counts from largest to smallest.
+fn sorted_counts(counts: &BTreeMap<String, u64>) -> Vec<(&str, u64)> {
+    let mut result: Vec<(&str, u64)> = counts
+        .iter()
+        .map(|(name, count)| (name.as_str(), *count))
+        .collect();
+
+    result.sort_by(|a, b| b.1.cmp(&a.1));
+    result
+}
@@ -141,7 +141,7 @@ where
             state.focused = false;
         }
 
-        if !state.focused {
+        if !state.focused || super::reduced_motion() {
             return;
         }
 
@@ -13,6 +13,8 @@ pub use viewport::ViewportState;
 use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::ops::RangeInclusive;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use iced::border::Radius;
@@ -44,6 +46,19 @@ use iced::Vector;
 use crate::fonts;
 use crate::palette;
 
+/// Whether or not decorative animations are disabled.
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether or not decorative animations are disabled, for every animated widget.
+pub fn set_reduced_motion(value: bool) {
+    REDUCED_MOTION.store(value, Ordering::Relaxed);
+}
+
+/// Whether or not decorative animations are disabled.
+pub fn reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
 /// Styled button widget.
 pub fn button<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -187,6 +187,10 @@ impl<Message> Widget<Message, Theme, iced::Renderer> for Spinner<'_> {
         let state = tree.state.downcast_mut::<State>();
 
         if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if super::reduced_motion() {
+                return;
+            }
+
             state.step_animation(self.cycle_duration, self.rotation_duration, *now);
             state.cache.clear();
 
@@ -15,6 +15,7 @@ use iced::advanced::widget::tree;
 
 use iced::keyboard;
 use iced::keyboard::Key;
+use iced::keyboard::key::Named;
 
 use iced::widget::canvas::Image;
 use iced::widget::image::Handle;
@@ -30,11 +31,17 @@ use iced::Size;
 use iced::Vector;
 
 use porter_viewport::PreviewControlScheme;
+use porter_viewport::PreviewError;
 use porter_viewport::ViewportKeyState;
 use porter_viewport::ViewportRenderer;
 
 use crate::palette;
 
+/// The distance in pixels the cursor can move between press and release to still pick.
+const PICK_DRAG_THRESHOLD: f32 = 4.0;
+/// The longest time in seconds an animation advances between redraws, so stalls don't skip ahead.
+const MAX_ANIMATION_STEP: f32 = 0.25;
+
 /// Preview viewport rendering widget.
 pub struct Viewport<'a, Message, Theme, Renderer, A> {
     state: &'a ViewportState,
@@ -48,6 +55,7 @@ pub struct ViewportState {
     bounds: Rectangle<f32>,
     dirty: Option<Instant>,
     cache: Option<Handle>,
+    animation_tick: Option<Instant>,
 }
 
 /// Actions performed on the viewport state.
@@ -56,11 +64,26 @@ pub enum ViewportAction {
     Resized(Rectangle<f32>),
     Cached(Handle, Instant),
     ResetView,
+    FrameSelected,
+    Pick(Vector<f32>),
+    ToggleSelectedVisibility,
+    ShowAllMeshes,
     ToggleGrid,
     ToggleBones,
-    ToggleWireframe,
+    ToggleBoneAxes,
+    CycleWireframe,
+    ToggleBackfaceCulling,
     ToggleShaded,
     CycleMaterial,
+    CycleMaterialReverse,
+    SelectMaterial(usize),
+    ToggleUnpremultiply,
+    CycleUvChecker,
+    CycleRenderMode,
+    ToggleAnimation,
+    StepAnimation(i32),
+    AdvanceAnimation(Instant),
+    Inspect(Option<Vector<f32>>),
     ScrollDelta(f32),
     MouseMove(Vector<f32>, Option<mouse::Button>, keyboard::Modifiers),
 }
@@ -70,6 +93,8 @@ struct State {
     mouse_position: Point<f32>,
     mouse_button: Option<mouse::Button>,
     keyboard_modifiers: keyboard::Modifiers,
+    inspecting: bool,
+    press_position: Option<Point<f32>>,
 }
 
 impl<'a, Message, Theme, Renderer, A> Viewport<'a, Message, Theme, Renderer, A>
@@ -104,6 +129,8 @@ where
             mouse_position: Point::ORIGIN,
             mouse_button: None,
             keyboard_modifiers: keyboard::Modifiers::empty(),
+            inspecting: false,
+            press_position: None,
         })
     }
 
@@ -138,7 +165,21 @@ where
 
         match event {
             Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
-                if !modifiers.is_empty() || shell.is_event_captured() {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                if *modifiers == keyboard::Modifiers::SHIFT
+                    && matches!(key.as_ref(), Key::Character("n" | "N"))
+                {
+                    shell.publish((self.on_action)(CycleMaterialReverse));
+                    shell.capture_event();
+
+                    shell.redraw_request();
+                    return;
+                }
+
+                if !modifiers.is_empty() {
                     return;
                 }
 
@@ -149,6 +190,24 @@ where
 
                         shell.redraw_request();
                     }
+                    Key::Character("f") => {
+                        shell.publish((self.on_action)(FrameSelected));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character("h") => {
+                        shell.publish((self.on_action)(ToggleSelectedVisibility));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character("v") => {
+                        shell.publish((self.on_action)(ShowAllMeshes));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
                     Key::Character("g") => {
                         shell.publish((self.on_action)(ToggleGrid));
                         shell.capture_event();
@@ -161,8 +220,20 @@ where
 
                         shell.redraw_request();
                     }
+                    Key::Character("x") => {
+                        shell.publish((self.on_action)(ToggleBoneAxes));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
                     Key::Character("w") => {
-                        shell.publish((self.on_action)(ToggleWireframe));
+                        shell.publish((self.on_action)(CycleWireframe));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character("c") => {
+                        shell.publish((self.on_action)(ToggleBackfaceCulling));
                         shell.capture_event();
 
                         shell.redraw_request();
@@ -179,6 +250,42 @@ where
 
                         shell.redraw_request();
                     }
+                    Key::Character("a") => {
+                        shell.publish((self.on_action)(ToggleUnpremultiply));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character("u") => {
+                        shell.publish((self.on_action)(CycleUvChecker));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character("i") => {
+                        shell.publish((self.on_action)(CycleRenderMode));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Named(Named::Space) => {
+                        shell.publish((self.on_action)(ToggleAnimation));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character(",") => {
+                        shell.publish((self.on_action)(StepAnimation(-1)));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
+                    Key::Character(".") => {
+                        shell.publish((self.on_action)(StepAnimation(1)));
+                        shell.capture_event();
+
+                        shell.redraw_request();
+                    }
                     _ => {
                         // Not used key.
                     }
@@ -190,7 +297,21 @@ where
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
                 let state = tree.state.downcast_mut::<State>();
 
-                if state.mouse_button.is_none() && !cursor.is_over(layout.bounds()) {
+                let bounds = layout.bounds();
+
+                if cursor.is_over(bounds) {
+                    state.inspecting = true;
+
+                    shell.publish((self.on_action)(Inspect(Some(
+                        *position - bounds.position(),
+                    ))));
+                } else if state.inspecting {
+                    state.inspecting = false;
+
+                    shell.publish((self.on_action)(Inspect(None)));
+                }
+
+                if state.mouse_button.is_none() && !cursor.is_over(bounds) {
                     return;
                 }
 
@@ -208,13 +329,42 @@ where
 
                 state.mouse_position = *position;
             }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.inspecting {
+                    state.inspecting = false;
+
+                    shell.publish((self.on_action)(Inspect(None)));
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(button)) => {
                 if cursor.is_over(layout.bounds()) {
-                    tree.state.downcast_mut::<State>().mouse_button = Some(*button);
+                    let state = tree.state.downcast_mut::<State>();
+
+                    state.mouse_button = Some(*button);
+                    state.press_position = if *button == mouse::Button::Left {
+                        cursor.position()
+                    } else {
+                        None
+                    };
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(_)) => {
-                tree.state.downcast_mut::<State>().mouse_button = None;
+                let state = tree.state.downcast_mut::<State>();
+
+                state.mouse_button = None;
+
+                // A click that didn't drag the camera picks the element under the cursor.
+                if let (Some(pressed), Some(position)) =
+                    (state.press_position.take(), cursor.position())
+                    && pressed.distance(position) <= PICK_DRAG_THRESHOLD
+                {
+                    shell.publish((self.on_action)(Pick(
+                        position - layout.bounds().position(),
+                    )));
+                    shell.redraw_request();
+                }
             }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 if cursor.is_over(layout.bounds()) {
@@ -230,6 +380,12 @@ where
                 }
             }
             Event::Window(window::Event::RedrawRequested(now)) => {
+                // A playing animation keeps requesting redraws to advance each frame.
+                if self.state.renderer.is_animation_playing() {
+                    shell.publish((self.on_action)(AdvanceAnimation(*now)));
+                    shell.request_redraw();
+                }
+
                 if self.state.dirty.is_none() {
                     return;
                 }
@@ -286,11 +442,22 @@ where
 impl ViewportState {
     /// Constructs a new viewport state with the given config values.
     pub fn new() -> Self {
+        Self::with_renderer(ViewportRenderer::new())
+    }
+
+    /// Constructs a new viewport state, fails if no GPU is available for the renderer.
+    pub fn try_new() -> Result<Self, PreviewError> {
+        Ok(Self::with_renderer(ViewportRenderer::try_new()?))
+    }
+
+    /// Constructs a new viewport state with the given renderer.
+    fn with_renderer(renderer: ViewportRenderer) -> Self {
         Self {
-            renderer: ViewportRenderer::new(),
+            renderer,
             bounds: Rectangle::INFINITE,
             dirty: Some(Instant::now()),
             cache: None,
+            animation_tick: None,
         }
     }
 
@@ -322,6 +489,22 @@ impl ViewportState {
                 self.renderer.reset_view();
                 self.dirty = Some(Instant::now());
             }
+            FrameSelected => {
+                self.renderer.frame_selected();
+                self.dirty = Some(Instant::now());
+            }
+            Pick(position) => {
+                self.renderer.pick((position.x, position.y));
+                self.dirty = Some(Instant::now());
+            }
+            ToggleSelectedVisibility => {
+                self.renderer.toggle_selected_visibility();
+                self.dirty = Some(Instant::now());
+            }
+            ShowAllMeshes => {
+                self.renderer.show_all_meshes();
+                self.dirty = Some(Instant::now());
+            }
             ToggleGrid => {
                 self.renderer.toggle_grid();
                 self.dirty = Some(Instant::now());
@@ -330,8 +513,16 @@ impl ViewportState {
                 self.renderer.toggle_bones();
                 self.dirty = Some(Instant::now());
             }
-            ToggleWireframe => {
-                self.renderer.toggle_wireframe();
+            ToggleBoneAxes => {
+                self.renderer.toggle_bone_axes();
+                self.dirty = Some(Instant::now());
+            }
+            CycleWireframe => {
+                self.renderer.cycle_wireframe_mode();
+                self.dirty = Some(Instant::now());
+            }
+            ToggleBackfaceCulling => {
+                self.renderer.toggle_backface_culling();
                 self.dirty = Some(Instant::now());
             }
             ToggleShaded => {
@@ -340,8 +531,56 @@ impl ViewportState {
             }
             CycleMaterial => {
                 self.renderer.cycle_material();
+                self.renderer.cycle_image_frame();
+                self.dirty = Some(Instant::now());
+            }
+            CycleMaterialReverse => {
+                self.renderer.cycle_material_reverse();
+                self.renderer.cycle_image_frame_reverse();
+                self.dirty = Some(Instant::now());
+            }
+            SelectMaterial(index) => {
+                self.renderer.select_material(index);
+                self.dirty = Some(Instant::now());
+            }
+            ToggleUnpremultiply => {
+                self.renderer.toggle_unpremultiply();
                 self.dirty = Some(Instant::now());
             }
+            CycleUvChecker => {
+                self.renderer.cycle_uv_checker();
+                self.dirty = Some(Instant::now());
+            }
+            CycleRenderMode => {
+                self.renderer.cycle_model_render_mode();
+                self.dirty = Some(Instant::now());
+            }
+            ToggleAnimation => {
+                self.renderer.toggle_animation_playback();
+                self.animation_tick = None;
+                self.dirty = Some(Instant::now());
+            }
+            StepAnimation(frames) => {
+                self.renderer.step_animation(frames);
+                self.dirty = Some(Instant::now());
+            }
+            AdvanceAnimation(now) => {
+                let seconds = self
+                    .animation_tick
+                    .map(|tick| now.saturating_duration_since(tick).as_secs_f32())
+                    .unwrap_or_default()
+                    .min(MAX_ANIMATION_STEP);
+
+                self.animation_tick = Some(now);
+
+                if self.renderer.advance_animation(seconds) {
+                    self.dirty = Some(Instant::now());
+                }
+            }
+            Inspect(position) => {
+                self.renderer
+                    .inspect(position.map(|position| (position.x, position.y)));
+            }
             ScrollDelta(delta) => {
                 self.renderer.scroll_delta(delta);
                 self.dirty = Some(Instant::now());
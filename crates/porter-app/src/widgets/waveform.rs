@@ -24,6 +24,8 @@ use iced::Rectangle;
 use iced::Size;
 use iced::window;
 
+use porter_audio::AudioPeaks;
+
 use crate::palette;
 
 /// Maximum safe floating point integer.
@@ -35,6 +37,8 @@ const MAX_HEIGHT: f32 = 650.0;
 const WAVEFORM_COUNT: usize = 25;
 /// Spacing between waveform lines.
 const WAVEFORM_SPACING: f32 = 8.0;
+/// Spacing between waveform peak lines.
+const PEAK_SPACING: f32 = 3.0;
 
 /// Refresh interval.
 const REFRESH_INTERVAL: Duration = Duration::from_millis(33);
@@ -49,6 +53,7 @@ pub struct Waveform<'a, Message, Theme, Renderer> {
     height: Length,
     is_playing: bool,
     seed: u64,
+    peaks: Option<(&'a AudioPeaks, f32)>,
     on_update: Message,
     _phantom: PhantomData<&'a (Message, Theme, Renderer)>,
 }
@@ -63,7 +68,7 @@ struct State {
     values: Vec<f32>,
 }
 
-impl<Message, Theme, Renderer> Waveform<'_, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> Waveform<'a, Message, Theme, Renderer>
 where
     Message: Clone,
     Renderer: advanced::Renderer,
@@ -75,11 +80,20 @@ where
             height: Length::Shrink,
             is_playing,
             seed,
+            peaks: None,
             on_update,
             _phantom: PhantomData,
         }
     }
 
+    /// Draws the given peaks instead of the animated effect, with the played portion up to the position from 0.0 to 1.0.
+    pub fn peaks(mut self, peaks: Option<&'a AudioPeaks>, position: f32) -> Self {
+        self.peaks = peaks
+            .filter(|peaks| !peaks.is_empty())
+            .map(|peaks| (peaks, position.clamp(0.0, 1.0)));
+        self
+    }
+
     /// Sets the width of the [`Waveform`] boundaries.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -191,6 +205,11 @@ where
 
         let height = bounds.height.min(MAX_HEIGHT) / 2.0;
 
+        if let Some((peaks, position)) = self.peaks {
+            draw_peaks(renderer, bounds, height, peaks, position);
+            return;
+        }
+
         let bar_count = state.values.len();
         let bar_width = bar_count as f32 * WAVEFORM_SPACING;
 
@@ -232,6 +251,47 @@ where
     }
 }
 
+/// Draws a line for each column of peaks, highlighting the portion that has been played.
+fn draw_peaks<Renderer: advanced::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    height: f32,
+    peaks: &AudioPeaks,
+    position: f32,
+) {
+    let count = (bounds.width / PEAK_SPACING).floor().max(1.0) as usize;
+    let played = (position * count as f32) as usize;
+
+    let start_x = bounds.x + (bounds.width - count as f32 * PEAK_SPACING) / 2.0;
+
+    for (i, (min, max)) in peaks
+        .columns(0, peaks.frames(), count)
+        .into_iter()
+        .enumerate()
+    {
+        let top = bounds.center_y() - max * height;
+        let bottom = bounds.center_y() - min * height;
+
+        renderer.fill_quad(
+            advanced::renderer::Quad {
+                bounds: Rectangle {
+                    x: start_x + i as f32 * PEAK_SPACING,
+                    y: top,
+                    width: PEAK_SPACING - 1.0,
+                    height: (bottom - top).max(1.0),
+                },
+                border: Default::default(),
+                shadow: Default::default(),
+            },
+            if i < played {
+                Background::Color(palette::PRIMARY_COLOR)
+            } else {
+                Background::Color(palette::PRIMARY_COLOR_DARK_250)
+            },
+        );
+    }
+}
+
 impl State {
     /// Calculates new waveforms based on the current seed.
     fn calculate_waveforms(&mut self) {
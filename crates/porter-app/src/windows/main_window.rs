@@ -25,9 +25,11 @@ use porter_utils::StringCaseExt;
 use rfd::FileDialog;
 use rfd::MessageButtons;
 use rfd::MessageDialog;
+use rfd::MessageDialogResult;
 use rfd::MessageLevel;
 
 use crate::AppState;
+use crate::ExportMode;
 use crate::Message;
 use crate::components::About;
 use crate::components::Content;
@@ -36,12 +38,15 @@ use crate::components::Controls;
 use crate::components::ControlsMessage;
 use crate::components::Header;
 use crate::components::HeaderMessage;
+use crate::components::NameGuess;
+use crate::components::NameGuessMessage;
 use crate::components::SearchBar;
 use crate::components::SearchBarMessage;
 use crate::components::Settings;
 use crate::components::SettingsMessage;
 use crate::components::VirtualListMessage;
 use crate::palette;
+use crate::strings;
 
 /// Main window handler.
 pub struct MainWindow {
@@ -50,6 +55,7 @@ pub struct MainWindow {
     search_bar: SearchBar,
     content: Content,
     controls: Controls,
+    name_guess: NameGuess,
     about: About,
     settings: Settings,
 }
@@ -63,10 +69,13 @@ pub enum MainMessage {
     SearchBar(SearchBarMessage),
     Content(ContentMessage),
     Controls(ControlsMessage),
+    NameGuess(NameGuessMessage),
     Settings(SettingsMessage),
     LoadFile,
     PickExportFolder,
+    PickNameDatabase,
     Warning(String),
+    ExportConflicts(Vec<usize>, ExportMode),
 }
 
 impl MainWindow {
@@ -87,6 +96,7 @@ impl MainWindow {
                 search_bar: SearchBar::new(),
                 content: Content::with_virtual_list(),
                 controls: Controls::new(),
+                name_guess: NameGuess::new(),
                 about: About::new(),
                 settings: Settings::new(),
             },
@@ -103,18 +113,23 @@ impl MainWindow {
     pub fn update(&mut self, state: &mut AppState, message: MainMessage) -> Task<Message> {
         use MainMessage::*;
 
-        match message {
+        let task = match message {
             UI(event) => self.on_ui(state, event),
             Show => self.on_show(state),
             Header(message) => self.header.update(state, message),
             SearchBar(message) => self.search_bar.update(state, message),
             Content(message) => self.content.update(state, message),
             Controls(message) => self.controls.update(state, message),
+            NameGuess(message) => self.name_guess.update(state, message),
             Settings(message) => self.settings.update(state, message),
             LoadFile => self.on_load_file(state),
             PickExportFolder => self.on_pick_export_folder(state),
+            PickNameDatabase => self.on_pick_name_database(state),
             Warning(message) => self.on_warning(state, message),
-        }
+            ExportConflicts(assets, mode) => self.on_export_conflicts(state, assets, mode),
+        };
+
+        Task::batch([task, self.controls.estimate(state)])
     }
 
     /// Handles rendering the main window.
@@ -130,8 +145,13 @@ impl MainWindow {
         } else {
             columns = columns
                 .push(self.search_bar.view(state))
-                .push(self.content.view(state))
-                .push(self.controls.view(state));
+                .push(self.content.view(state));
+
+            if self.name_guess.show {
+                columns = columns.push(self.name_guess.view(state));
+            }
+
+            columns = columns.push(self.controls.view(state));
         }
 
         container(columns)
@@ -182,8 +202,27 @@ impl MainWindow {
                     Task::none()
                 }
             }
-            Key::Named(Named::ArrowUp) => Task::done(Message::from(VirtualListMessage::MoveUp)),
-            Key::Named(Named::ArrowDown) => Task::done(Message::from(VirtualListMessage::MoveDown)),
+            Key::Character("a") => {
+                if state.modifier_keys.command() {
+                    Task::done(Message::from(VirtualListMessage::SelectAll))
+                } else {
+                    Task::none()
+                }
+            }
+            Key::Named(Named::ArrowUp) => {
+                if state.modifier_keys.shift() {
+                    Task::done(Message::from(VirtualListMessage::ExtendUp))
+                } else {
+                    Task::done(Message::from(VirtualListMessage::MoveUp))
+                }
+            }
+            Key::Named(Named::ArrowDown) => {
+                if state.modifier_keys.shift() {
+                    Task::done(Message::from(VirtualListMessage::ExtendDown))
+                } else {
+                    Task::done(Message::from(VirtualListMessage::MoveDown))
+                }
+            }
             Key::Named(Named::PageUp) => Task::done(Message::from(VirtualListMessage::PageUp)),
             Key::Named(Named::PageDown) => Task::done(Message::from(VirtualListMessage::PageDown)),
             _ => Task::none(),
@@ -310,6 +349,29 @@ impl MainWindow {
         })
     }
 
+    /// Picks a name database to add to the settings.
+    fn on_pick_name_database(&mut self, state: &mut AppState) -> Task<Message> {
+        let mut settings = state.settings.clone();
+
+        let title = format!("{} | Select a name database", state.name.to_titlecase());
+
+        window::run_with_handle(self.id, move |handle| {
+            let path = FileDialog::new()
+                .add_filter("Name Database", &["csv", "db"])
+                .set_parent(&handle)
+                .set_title(title)
+                .pick_file();
+
+            if let Some(path) = path {
+                settings.add_name_database(path);
+
+                Message::from(SettingsMessage::Save(settings))
+            } else {
+                Message::Noop
+            }
+        })
+    }
+
     /// Show a warning to the user.
     fn on_warning(&mut self, state: &mut AppState, message: String) -> Task<Message> {
         let title = state.name.to_titlecase();
@@ -336,6 +398,42 @@ impl MainWindow {
         })
     }
 
+    /// Asks the user whether or not to overwrite the files which already existed during an export, made in the given mode.
+    fn on_export_conflicts(
+        &mut self,
+        state: &mut AppState,
+        assets: Vec<usize>,
+        mode: ExportMode,
+    ) -> Task<Message> {
+        let title = state.name.to_titlecase();
+        let controller = state.controller.clone();
+
+        let message = format!("{} {}", assets.len(), strings::EXPORT_CONFLICTS_PROMPT);
+
+        window::run_with_handle(self.id, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(message)
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::YesNo)
+                .set_parent(&handle);
+
+            let dialog = move || {
+                if matches!(dialog.show(), MessageDialogResult::Yes) {
+                    controller.export_overwrite(assets, mode);
+                }
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
     /// Copy the selected assets to the clipboard.
     fn on_copy_text(&mut self, state: &mut AppState) -> Task<Message> {
         if state.is_busy() || state.assets_selected.is_empty() {
@@ -68,6 +68,14 @@ impl SplashWindow {
         (Self { id, open: true }, task)
     }
 
+    /// Creates a splash window that was skipped, and never opens.
+    pub fn skipped() -> Self {
+        Self {
+            id: window::Id::unique(),
+            open: false,
+        }
+    }
+
     /// Handles the title of the splash screen.
     pub fn title(&self, state: &AppState) -> String {
         format!("{} v{}", state.name.to_titlecase(), state.version)
@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::PathBuf;
+
+use porter_texture::Image;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+
+use porter_app::ImageAlphaProcessing;
+use porter_app::Settings;
+
+/// Creates an empty directory for the output of the given test.
+fn output_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join("porter-app-tests").join(name);
+
+    let _ = fs::remove_dir_all(&directory);
+
+    fs::create_dir_all(&directory).expect("failed to create test output directory");
+
+    directory
+}
+
+/// Saves a half transparent orange image through the export settings as a tga, returning the saved pixels in bgra.
+fn save_orange(name: &str, processing: ImageAlphaProcessing) -> Vec<[u8; 4]> {
+    let directory = output_directory(name);
+
+    let settings = Settings::default().update(|settings| {
+        settings.set_image_file_type(ImageFileType::Tga);
+        settings.set_image_alpha_processing(processing);
+    });
+
+    let mut image = Image::from_rgba(255, 128, 0, 128, false).expect("failed to create image");
+
+    image
+        .convert(
+            image.format_for_file_type(ImageFileType::Tga),
+            ImageConvertOptions::None,
+        )
+        .expect("failed to convert image");
+
+    settings
+        .save_image(&mut image, &directory.join("orange"))
+        .expect("failed to save image");
+
+    let image = Image::load(directory.join("orange.tga"), ImageFileType::Tga)
+        .expect("failed to load image");
+
+    assert_eq!(image.format(), ImageFormat::B8G8R8A8Unorm);
+
+    image.frames()[0]
+        .buffer()
+        .chunks_exact(4)
+        .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+        .collect()
+}
+
+#[test]
+fn save_image_keeps_straight_alpha() {
+    let pixels = save_orange("alpha_none", ImageAlphaProcessing::None);
+
+    assert!(pixels.iter().all(|pixel| *pixel == [0, 128, 255, 128]));
+}
+
+#[test]
+fn save_image_premultiplies_alpha() {
+    let pixels = save_orange("alpha_premultiply", ImageAlphaProcessing::Premultiply);
+
+    assert!(pixels.iter().all(|pixel| *pixel == [0, 64, 128, 128]));
+}
+
+#[test]
+fn save_image_unpremultiplies_alpha() {
+    let pixels = save_orange("alpha_unpremultiply", ImageAlphaProcessing::Unpremultiply);
+
+    assert!(pixels.iter().all(|pixel| *pixel == [0, 255, 255, 128]));
+}
+
+#[test]
+fn save_image_exports_alpha_mask() {
+    let directory = output_directory("alpha_mask");
+
+    let settings = Settings::default().update(|settings| {
+        settings.set_image_file_type(ImageFileType::Tga);
+        settings.set_image_alpha_mask(true);
+    });
+
+    for (name, alpha) in [("transparent", 128), ("opaque", 255)] {
+        let mut image =
+            Image::from_rgba(255, 128, 0, alpha, false).expect("failed to create image");
+
+        image
+            .convert(
+                image.format_for_file_type(ImageFileType::Tga),
+                ImageConvertOptions::None,
+            )
+            .expect("failed to convert image");
+
+        settings
+            .save_image(&mut image, &directory.join(name))
+            .expect("failed to save image");
+    }
+
+    let mask = Image::load(directory.join("transparent_alpha.tga"), ImageFileType::Tga)
+        .expect("failed to load alpha mask");
+
+    assert_eq!(mask.format(), ImageFormat::R8Unorm);
+    assert!(mask.frames()[0].buffer().iter().all(|value| *value == 128));
+
+    assert!(directory.join("opaque.tga").exists());
+    assert!(!directory.join("opaque_alpha.tga").exists());
+}
@@ -0,0 +1,100 @@
+use porter_model::ModelFileType;
+use porter_texture::ImageFileType;
+use porter_viewport::PreviewControlScheme;
+
+use porter_app::ExportConflictPolicy;
+use porter_app::ExportLayout;
+use porter_app::ImageCompression;
+use porter_app::ImageNormalMapProcessing;
+use porter_app::Settings;
+
+/// Settings written before profiles were introduced.
+const SETTINGS_V1: &[u8] = include_bytes!("fixtures/settings_v1.json");
+/// Settings written before the format was versioned, with a removed field and a removed image file type.
+const SETTINGS_UNVERSIONED: &[u8] = include_bytes!("fixtures/settings_unversioned.json");
+
+#[test]
+fn v1_settings_migrate_without_profiles() {
+    let settings = Settings::from_slice(SETTINGS_V1);
+
+    assert_eq!(settings.profile(), None);
+    assert!(settings.profiles().is_empty());
+    assert!(!settings.profile_has_overrides());
+}
+
+#[test]
+fn v1_settings_keep_every_field() {
+    let settings = Settings::from_slice(SETTINGS_V1);
+
+    assert!(settings.load_models());
+    assert!(settings.load_sounds());
+    assert!(!settings.load_raw_files());
+    assert_eq!(
+        settings.model_file_types(),
+        vec![ModelFileType::Obj, ModelFileType::Cast]
+    );
+    assert_eq!(settings.image_file_type(), ImageFileType::Tga);
+    assert!(matches!(
+        settings.image_normal_map_processing(),
+        ImageNormalMapProcessing::DirectX
+    ));
+    assert_eq!(settings.image_compression(), ImageCompression::Bc7);
+    assert_eq!(settings.export_layout(), ExportLayout::Unity);
+    assert!(matches!(
+        settings.preview_controls(),
+        PreviewControlScheme::Maya
+    ));
+    assert_eq!(settings.far_clip(), 5000);
+    assert_eq!(settings.fov(), 90);
+    assert_eq!(settings.volume(), 75);
+    assert!(!settings.usage_statistics());
+    assert_eq!(
+        settings.export_conflict_policy(),
+        ExportConflictPolicy::Rename
+    );
+}
+
+#[test]
+fn unversioned_settings_merge_valid_fields() {
+    let settings = Settings::from_slice(SETTINGS_UNVERSIONED);
+
+    assert!(settings.load_models());
+    assert!(!settings.load_raw_files());
+    assert_eq!(
+        settings.model_file_types(),
+        vec![ModelFileType::Obj, ModelFileType::Cast]
+    );
+    assert!(matches!(
+        settings.preview_controls(),
+        PreviewControlScheme::Blender
+    ));
+    assert_eq!(settings.far_clip(), 2500);
+    assert_eq!(settings.fov(), 80);
+    assert_eq!(settings.volume(), 50);
+    assert_eq!(settings.profile(), None);
+    assert!(settings.profiles().is_empty());
+}
+
+#[test]
+fn unversioned_settings_fall_back_per_field() {
+    let settings = Settings::from_slice(SETTINGS_UNVERSIONED);
+    let defaults = Settings::default();
+
+    assert_eq!(settings.image_file_type(), defaults.image_file_type());
+    assert_eq!(settings.export_layout(), defaults.export_layout());
+    assert_eq!(
+        settings.export_conflict_policy(),
+        defaults.export_conflict_policy()
+    );
+    assert_eq!(settings.usage_statistics(), defaults.usage_statistics());
+}
+
+#[test]
+fn invalid_settings_use_defaults() {
+    let settings = Settings::from_slice(b"{ not json");
+    let defaults = Settings::default();
+
+    assert_eq!(settings.far_clip(), defaults.far_clip());
+    assert_eq!(settings.fov(), defaults.fov());
+    assert_eq!(settings.image_file_type(), defaults.image_file_type());
+}
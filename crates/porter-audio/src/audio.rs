@@ -11,15 +11,14 @@ use porter_utils::BufferWriteExt;
 use crate::AudioError;
 use crate::AudioFileType;
 use crate::AudioFormat;
+use crate::AudioInfo;
 use crate::audio_file_type_flac;
+use crate::audio_file_type_ogg;
 use crate::audio_file_type_wav;
 use crate::software_coerce_audio;
 use crate::software_compress_audio;
 use crate::software_decompress_audio;
 
-#[cfg(feature = "ogg")]
-use crate::audio_file_type_ogg;
-
 /// Represents a raw audio stream with 1-many channels.
 #[derive(Debug, Clone)]
 pub struct Audio {
@@ -137,15 +136,58 @@ impl Audio {
         Err(AudioError::UnsupportedAudioFormat(format))
     }
 
+    /// Concatenates the given segments, in order, into a single audio stream.
+    ///
+    /// Compressed segments are decompressed first, and every segment must match the channels,
+    /// sample rate, and bits per sample of the first segment.
+    pub fn concat(segments: &[Audio]) -> Result<Self, AudioError> {
+        let Some(first) = segments.first() else {
+            return Err(AudioError::NoAudioSegments);
+        };
+
+        let mut result = first.clone();
+
+        if result.format.is_compressed() {
+            software_decompress_audio(&mut result)?;
+        }
+
+        result.frame_count = None;
+        result.byte_rate = None;
+
+        for (index, segment) in segments.iter().enumerate().skip(1) {
+            let mut segment = segment.clone();
+
+            if segment.format != result.format {
+                segment.convert(result.format)?;
+            }
+
+            if segment.channels != result.channels
+                || segment.sample_rate != result.sample_rate
+                || segment.bits_per_sample != result.bits_per_sample
+                || segment.frame_align() != result.frame_align()
+            {
+                return Err(AudioError::MismatchedAudioSegment(index));
+            }
+
+            result.data.try_reserve(segment.data.len())?;
+            result.data.extend_from_slice(&segment.data);
+        }
+
+        Ok(result)
+    }
+
+    /// The size in bytes of a single frame of every channel.
+    fn frame_align(&self) -> u32 {
+        self.block_align
+            .unwrap_or_else(|| (self.channels * self.bits_per_sample) / 8)
+    }
+
     /// Calculates the optimal audio format required to save this audio stream to the given file type.
     pub fn format_for_file_type(&self, file_type: AudioFileType) -> AudioFormat {
         match file_type {
             AudioFileType::Wav => audio_file_type_wav::pick_format(self.format),
             AudioFileType::Flac => audio_file_type_flac::pick_format(self.format),
-            AudioFileType::Ogg => {
-                // We don't support writing these formats.
-                AudioFormat::Unknown
-            }
+            AudioFileType::Ogg => audio_file_type_ogg::pick_format(self.format),
         }
     }
 
@@ -169,6 +211,29 @@ impl Audio {
         }
     }
 
+    /// Reads the metadata of an audio stream from the given path, without decoding the stream.
+    pub fn probe<P: AsRef<Path>>(
+        path: P,
+        file_type: AudioFileType,
+    ) -> Result<AudioInfo, AudioError> {
+        Self::probe_from(&mut File::open(path)?.buffer_read(), file_type)
+    }
+
+    /// Reads the metadata of an audio stream from the given input buffer, without decoding the stream.
+    pub fn probe_from<I: Read + Seek>(
+        input: &mut I,
+        file_type: AudioFileType,
+    ) -> Result<AudioInfo, AudioError> {
+        match file_type {
+            AudioFileType::Wav => audio_file_type_wav::probe_wav(input),
+            AudioFileType::Flac => audio_file_type_flac::probe_flac(input),
+            #[cfg(feature = "ogg")]
+            AudioFileType::Ogg => audio_file_type_ogg::probe_ogg(input),
+            #[cfg(not(feature = "ogg"))]
+            AudioFileType::Ogg => Err(AudioError::UnsupportedAudioFileType),
+        }
+    }
+
     /// Saves the audio stream to the given file path in the given audio file type.
     pub fn save<P: AsRef<Path>>(
         &self,
@@ -193,10 +258,30 @@ impl Audio {
         match file_type {
             AudioFileType::Wav => audio_file_type_wav::to_wav(self, output),
             AudioFileType::Flac => audio_file_type_flac::to_flac(self, output),
-            AudioFileType::Ogg => {
-                // We don't support writing these formats.
-                Err(AudioError::UnsupportedAudioFileType)
+            AudioFileType::Ogg => audio_file_type_ogg::to_ogg(self, output),
+        }
+    }
+
+    /// Returns the metadata of this audio stream.
+    pub fn info(&self) -> AudioInfo {
+        let frame_count = self.frame_count.or_else(|| {
+            if !matches!(self.format, AudioFormat::FloatPcm | AudioFormat::IntegerPcm) {
+                return None;
             }
+
+            let block_align = self
+                .block_align()
+                .unwrap_or_else(|| (self.channels() * self.bits_per_sample()) / 8);
+
+            (block_align > 0).then(|| self.data.len() as u64 / block_align as u64)
+        });
+
+        AudioInfo {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            format: self.format,
+            frame_count,
         }
     }
 
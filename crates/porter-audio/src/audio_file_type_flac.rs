@@ -17,6 +17,7 @@ use flacenc::source::Source;
 use crate::Audio;
 use crate::AudioError;
 use crate::AudioFormat;
+use crate::AudioInfo;
 
 struct FlacSource<'a> {
     audio: &'a Audio,
@@ -204,3 +205,17 @@ pub fn from_flac<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
 
     Ok(audio)
 }
+
+/// Reads the metadata of a flac file without decoding the samples.
+pub fn probe_flac<I: Read + Seek>(input: &mut I) -> Result<AudioInfo, AudioError> {
+    let reader = FlacReader::new(input)?;
+    let stream_info = reader.streaminfo();
+
+    Ok(AudioInfo {
+        channels: stream_info.channels,
+        sample_rate: stream_info.sample_rate,
+        bits_per_sample: stream_info.bits_per_sample,
+        format: AudioFormat::IntegerPcm,
+        frame_count: stream_info.samples,
+    })
+}
@@ -1,6 +1,9 @@
+#[cfg(feature = "ogg")]
 use lewton::inside_ogg::OggStreamReader;
 
+#[cfg(feature = "ogg")]
 use std::io::Cursor;
+#[cfg(feature = "ogg")]
 use std::io::Read;
 use std::io::Seek;
 use std::io::Write;
@@ -8,8 +11,217 @@ use std::io::Write;
 use crate::Audio;
 use crate::AudioError;
 use crate::AudioFormat;
+use crate::VorbisEncoder;
 
-/// Reads a flac file to an audio stream.
+#[cfg(feature = "ogg")]
+use crate::AudioInfo;
+
+/// The serial number of the only logical stream in the file.
+const OGG_STREAM_SERIAL: u32 = 0x706F7274;
+/// The size of a page after which it is written, even if more packets would fit.
+const OGG_PAGE_SIZE: usize = 4096;
+/// The largest number of segments in a page.
+const OGG_PAGE_SEGMENTS: usize = 255;
+
+/// The crc lookup table for ogg page checksums.
+const OGG_CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = (i as u32) << 24;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 0x80000000 != 0 {
+                (crc << 1) ^ 0x04C11DB7
+            } else {
+                crc << 1
+            };
+
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+/// Writes packets of a single logical stream to ogg pages.
+struct OggWriter<'a, O: Write> {
+    output: &'a mut O,
+    sequence: u32,
+    segments: Vec<u8>,
+    data: Vec<u8>,
+    granule: Option<u64>,
+    continued: bool,
+}
+
+impl<'a, O: Write> OggWriter<'a, O> {
+    /// Constructs a new ogg writer for the given output.
+    fn new(output: &'a mut O) -> Self {
+        Self {
+            output,
+            sequence: 0,
+            segments: Vec::new(),
+            data: Vec::new(),
+            granule: None,
+            continued: false,
+        }
+    }
+
+    /// Writes a packet which ends at the given granule position, optionally finishing the page.
+    fn write_packet(
+        &mut self,
+        packet: &[u8],
+        granule: u64,
+        flush: bool,
+        last: bool,
+    ) -> Result<(), AudioError> {
+        let mut remaining = packet;
+
+        loop {
+            if self.segments.len() == OGG_PAGE_SEGMENTS {
+                self.write_page(false)?;
+            }
+
+            let size = remaining.len().min(255);
+
+            self.segments.push(size as u8);
+            self.data.extend_from_slice(&remaining[..size]);
+
+            remaining = &remaining[size..];
+
+            if size < 255 {
+                break;
+            }
+        }
+
+        self.granule = Some(granule);
+
+        if flush || last || self.data.len() >= OGG_PAGE_SIZE {
+            self.write_page(last)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pending segments as a page.
+    fn write_page(&mut self, last: bool) -> Result<(), AudioError> {
+        let mut flags = 0;
+
+        if self.continued {
+            flags |= 0x1;
+        }
+
+        if self.sequence == 0 {
+            flags |= 0x2;
+        }
+
+        if last {
+            flags |= 0x4;
+        }
+
+        // Pages where no packet finishes have no granule position.
+        let granule = self.granule.map(|x| x as i64).unwrap_or(-1);
+
+        let mut page = Vec::with_capacity(27 + self.segments.len() + self.data.len());
+
+        page.extend_from_slice(b"OggS");
+        page.push(0);
+        page.push(flags);
+        page.extend_from_slice(&granule.to_le_bytes());
+        page.extend_from_slice(&OGG_STREAM_SERIAL.to_le_bytes());
+        page.extend_from_slice(&self.sequence.to_le_bytes());
+        page.extend_from_slice(&0u32.to_le_bytes());
+        page.push(self.segments.len() as u8);
+        page.extend_from_slice(&self.segments);
+        page.extend_from_slice(&self.data);
+
+        let checksum = page.iter().fold(0u32, |crc, byte| {
+            (crc << 8) ^ OGG_CRC_TABLE[((crc >> 24) as u8 ^ byte) as usize]
+        });
+
+        page[22..26].copy_from_slice(&checksum.to_le_bytes());
+
+        self.output.write_all(&page)?;
+
+        self.continued = self.segments.last().is_some_and(|size| *size == 255);
+        self.sequence += 1;
+        self.segments.clear();
+        self.data.clear();
+        self.granule = None;
+
+        Ok(())
+    }
+}
+
+/// Picks the proper format required to save the input format to an ogg file type.
+pub const fn pick_format(_: AudioFormat) -> AudioFormat {
+    AudioFormat::FloatPcm
+}
+
+/// Writes an audio stream to an ogg vorbis file to the output stream.
+pub fn to_ogg<O: Write + Seek>(audio: &Audio, output: &mut O) -> Result<(), AudioError> {
+    if !matches!(audio.format(), AudioFormat::FloatPcm) || audio.bits_per_sample() != 32 {
+        return Err(AudioError::UnsupportedAudioFormat(audio.format()));
+    }
+
+    if audio.channels() > u8::MAX as u32 {
+        return Err(AudioError::InvalidAudioChannels(audio.channels()));
+    }
+
+    let channels = audio.channels() as usize;
+
+    let mut samples: Vec<Vec<f32>> = vec![Vec::new(); channels];
+
+    for (i, sample) in audio.data().chunks_exact(4).enumerate() {
+        samples[i % channels].push(f32::from_le_bytes(sample.try_into()?));
+    }
+
+    let frames = samples[0].len();
+
+    let encoder = VorbisEncoder::new(audio.channels(), audio.sample_rate());
+    let mut writer = OggWriter::new(output);
+
+    writer.write_packet(&encoder.identification_header(), 0, true, false)?;
+    writer.write_packet(&encoder.comment_header(), 0, false, false)?;
+    writer.write_packet(&encoder.setup_header(), 0, true, false)?;
+
+    // Every block is centered on a multiple of the step, so the first block only primes the decoder.
+    let step = VorbisEncoder::BLOCK_STEP;
+    let blocks = frames.div_ceil(step).max(1);
+
+    let mut block = vec![vec![0.0; VorbisEncoder::BLOCK_SIZE]; channels];
+
+    for i in 0..=blocks {
+        let start = i as isize * step as isize - step as isize;
+
+        for (channel, samples) in block.iter_mut().zip(&samples) {
+            for (j, sample) in channel.iter_mut().enumerate() {
+                let index = start + j as isize;
+
+                *sample = if index >= 0 {
+                    samples.get(index as usize).copied().unwrap_or_default()
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        let packet = encoder.encode(&block);
+        let granule = (i * step).min(frames) as u64;
+
+        writer.write_packet(&packet, granule, i == 0, i == blocks)?;
+    }
+
+    Ok(())
+}
+
+/// Reads an ogg file to an audio stream.
+#[cfg(feature = "ogg")]
 pub fn from_ogg<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
     let mut reader = OggStreamReader::new(input)?;
     let mut data: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -31,3 +243,18 @@ pub fn from_ogg<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
 
     Ok(audio)
 }
+
+/// Reads the metadata of an ogg file without decoding the samples.
+#[cfg(feature = "ogg")]
+pub fn probe_ogg<I: Read + Seek>(input: &mut I) -> Result<AudioInfo, AudioError> {
+    let reader = OggStreamReader::new(input)?;
+
+    Ok(AudioInfo {
+        channels: reader.ident_hdr.audio_channels as u32,
+        sample_rate: reader.ident_hdr.audio_sample_rate,
+        bits_per_sample: 16,
+        format: AudioFormat::IntegerPcm,
+        // The total length is only stored in the granule position of the last page.
+        frame_count: None,
+    })
+}
@@ -10,6 +10,7 @@ use crate::Audio;
 use crate::AudioError;
 use crate::AudioFileType;
 use crate::AudioFormat;
+use crate::AudioInfo;
 
 #[repr(C, packed)]
 #[derive(Debug, Default, Clone, Copy)]
@@ -44,14 +45,42 @@ const fn compute_byte_rate(bits_per_sample: u32, channels: u32, sample_rate: u32
 /// Picks the proper format required to save the input format to a wav file type.
 pub const fn pick_format(format: AudioFormat) -> AudioFormat {
     match format {
-        AudioFormat::RawFlac
-        | AudioFormat::Xma2
-        | AudioFormat::WmaV1
-        | AudioFormat::WmaV2 => AudioFormat::IntegerPcm,
+        AudioFormat::RawFlac | AudioFormat::Xma2 | AudioFormat::WmaV1 | AudioFormat::WmaV2 => {
+            AudioFormat::IntegerPcm
+        }
         _ => format,
     }
 }
 
+/// Maps the wav format tag to an audio format, adjusting the header for custom formats.
+fn wav_format(header: &mut WavefmtHeader) -> Result<AudioFormat, AudioError> {
+    let format = match header.format {
+        0x1 => AudioFormat::IntegerPcm,
+        0x2 => AudioFormat::MsAdpcm,
+        0x3 => AudioFormat::FloatPcm,
+        0x11 => AudioFormat::ImaAdpcm,
+        0x160 => AudioFormat::WmaV1,
+        0x161 => AudioFormat::WmaV2,
+        0x166 => AudioFormat::Xma2,
+        0xFFFF => {
+            header.block_align = 1;
+            header.bits_per_sample = 8;
+
+            AudioFormat::WwiseVorbis
+        }
+        _ => {
+            #[cfg(debug_assertions)]
+            println!("Unknown wav format: {:#02x?}", { header.format });
+            return Err(AudioError::ContainerFormatInvalid(
+                AudioFormat::Unknown,
+                AudioFileType::Wav,
+            ));
+        }
+    };
+
+    Ok(format)
+}
+
 /// Writes an audio stream to a wav file to the output stream.
 pub fn to_wav<O: Write + Seek>(audio: &Audio, output: &mut O) -> Result<(), AudioError> {
     output.write_struct(0x46464952u32)?; // 'RIFF'
@@ -170,8 +199,17 @@ pub fn to_wav<O: Write + Seek>(audio: &Audio, output: &mut O) -> Result<(), Audi
     Ok(())
 }
 
-/// Reads a wav file to an audio stream.
-pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
+/// The header blocks of a wav file, read up to the start of the audio data.
+struct WavHeader {
+    fmt: WavefmtHeader,
+    format: AudioFormat,
+    extra: Vec<u8>,
+    dpds_last: Option<u32>,
+    data_size: u32,
+}
+
+/// Reads the header blocks of a wav file, leaving the input at the start of the audio data.
+fn read_wav_header<I: Read + Seek>(input: &mut I) -> Result<WavHeader, AudioError> {
     let magic: u32 = input.read_struct()?;
 
     // 'RIFF'
@@ -181,12 +219,11 @@ pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
 
     let _file_size: u32 = input.read_struct()?;
 
-    let mut data = Vec::new();
     let mut extra = Vec::new();
-    let mut dpds: Vec<u32> = Vec::new();
-    let mut header: WavefmtHeader = Default::default();
+    let mut dpds_last: Option<u32> = None;
+    let mut fmt: Option<WavefmtHeader> = None;
 
-    loop {
+    let data_size = loop {
         let block: u32 = input.read_struct()?;
         let size: u32 = input.read_struct()?;
 
@@ -198,7 +235,7 @@ pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
                     return Err(AudioError::ContainerInvalid(AudioFileType::Wav));
                 }
 
-                header = input.read_struct()?;
+                let header: WavefmtHeader = input.read_struct()?;
 
                 if header.size >= 0x12 {
                     let size: u16 = input.read_struct()?;
@@ -208,27 +245,23 @@ pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
 
                     input.read_exact(&mut extra)?;
                 }
+
+                fmt = Some(header);
             }
-            // 'dpds' (xWMA decoded packet cumulative byte counts)
+            // 'dpds' (xWMA decoded packet cumulative byte counts, only the total is needed)
             0x73647064 => {
                 let count = size as usize / size_of::<u32>();
 
-                dpds.try_reserve_exact(count)?;
+                if count > 0 {
+                    input.skip(((count - 1) * size_of::<u32>()) as u32)?;
 
-                for _ in 0..count {
-                    dpds.push(input.read_struct()?);
+                    dpds_last = Some(input.read_struct()?);
                 }
 
                 input.skip((size as usize % size_of::<u32>()) as u32)?;
             }
             // 'data'
-            0x61746164 => {
-                data.try_reserve_exact(size as _)?;
-                data.resize(size as _, 0);
-
-                input.read_exact(&mut data)?;
-                break;
-            }
+            0x61746164 => break size,
             _ => {
                 #[cfg(all(debug_assertions, feature = "debug"))]
                 {
@@ -252,47 +285,55 @@ pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
                 input.skip(size)?;
             }
         }
-    }
-
-    let format = match header.format {
-        0x1 => AudioFormat::IntegerPcm,
-        0x2 => AudioFormat::MsAdpcm,
-        0x3 => AudioFormat::FloatPcm,
-        0x11 => AudioFormat::ImaAdpcm,
-        0x160 => AudioFormat::WmaV1,
-        0x161 => AudioFormat::WmaV2,
-        0x166 => AudioFormat::Xma2,
-        0xFFFF => {
-            header.block_align = 1;
-            header.bits_per_sample = 8;
+    };
 
-            AudioFormat::WwiseVorbis
-        }
-        _ => {
-            #[cfg(debug_assertions)]
-            println!("Unknown wav format: {:#02x?}", { header.format });
-            return Err(AudioError::ContainerFormatInvalid(
-                AudioFormat::Unknown,
-                AudioFileType::Wav,
-            ));
-        }
+    let Some(mut fmt) = fmt else {
+        return Err(AudioError::ContainerInvalid(AudioFileType::Wav));
     };
 
+    let format = wav_format(&mut fmt)?;
+
+    Ok(WavHeader {
+        fmt,
+        format,
+        extra,
+        dpds_last,
+        data_size,
+    })
+}
+
+/// Reads a wav file to an audio stream.
+pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
+    let WavHeader {
+        fmt,
+        format,
+        extra,
+        dpds_last,
+        data_size,
+    } = read_wav_header(input)?;
+
+    let mut data = Vec::new();
+
+    data.try_reserve_exact(data_size as _)?;
+    data.resize(data_size as _, 0);
+
+    input.read_exact(&mut data)?;
+
     let mut audio = Audio::with_block_align(
-        header.channel_count as u32,
-        header.sample_rate,
-        header.block_align as u32,
-        header.bits_per_sample as u32,
+        fmt.channel_count as u32,
+        fmt.sample_rate,
+        fmt.block_align as u32,
+        fmt.bits_per_sample as u32,
         format,
     )?;
 
     audio.set_data(data);
     audio.set_extra(extra);
-    audio.set_byte_rate(header.byte_rate);
+    audio.set_byte_rate(fmt.byte_rate);
 
     // The xWMA dpds table declares the exact decoded length in bytes.
-    if let Some(&last) = dpds.last() {
-        let bytes_per_frame = (header.channel_count as u32 * header.bits_per_sample as u32) / 8;
+    if let Some(last) = dpds_last {
+        let bytes_per_frame = (fmt.channel_count as u32 * fmt.bits_per_sample as u32) / 8;
 
         if bytes_per_frame > 0 {
             audio.set_frame_count(last as u64 / bytes_per_frame as u64);
@@ -301,3 +342,38 @@ pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
 
     Ok(audio)
 }
+
+/// Reads the metadata of a wav file without reading the audio data.
+pub fn probe_wav<I: Read + Seek>(input: &mut I) -> Result<AudioInfo, AudioError> {
+    let WavHeader {
+        fmt: header,
+        format,
+        dpds_last,
+        data_size,
+        ..
+    } = read_wav_header(input)?;
+
+    let bytes_per_frame = (header.channel_count as u32 * header.bits_per_sample as u32) / 8;
+
+    let frame_count = if let Some(last) = dpds_last {
+        (bytes_per_frame > 0).then(|| last as u64 / bytes_per_frame as u64)
+    } else if matches!(format, AudioFormat::IntegerPcm | AudioFormat::FloatPcm) {
+        let block_align = if header.block_align > 0 {
+            header.block_align as u32
+        } else {
+            bytes_per_frame
+        };
+
+        (block_align > 0).then(|| data_size as u64 / block_align as u64)
+    } else {
+        None
+    };
+
+    Ok(AudioInfo {
+        channels: header.channel_count as u32,
+        sample_rate: header.sample_rate,
+        bits_per_sample: header.bits_per_sample as u32,
+        format,
+        frame_count,
+    })
+}
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use crate::AudioFormat;
+
+/// Metadata for an audio stream, read without decoding the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioInfo {
+    /// The number of channels in the stream.
+    pub channels: u32,
+    /// The sample rate of the stream.
+    pub sample_rate: u32,
+    /// The bits per sample of the stream.
+    pub bits_per_sample: u32,
+    /// The format of the stream.
+    pub format: AudioFormat,
+    /// The number of frames in the stream, when it can be determined without decoding.
+    pub frame_count: Option<u64>,
+}
+
+impl AudioInfo {
+    /// Calculates the duration of the stream, when the frame count is known.
+    pub fn duration(&self) -> Option<Duration> {
+        if self.sample_rate == 0 {
+            return None;
+        }
+
+        self.frame_count
+            .map(|frame_count| Duration::from_millis(frame_count * 1000 / self.sample_rate as u64))
+    }
+}
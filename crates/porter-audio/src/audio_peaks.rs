@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+
+use crate::Audio;
+use crate::AudioError;
+use crate::AudioFormat;
+
+/// The number of frames summarized by each peak in the first level.
+const PEAK_BLOCK_SIZE: u64 = 64;
+
+/// A pyramid of min/max peaks, mixed down from every channel, used to draw a waveform at any zoom level.
+///
+/// Each level summarizes twice as many frames per peak as the level before it, so a range of frames is
+/// summarized by reading a few peaks from the level that matches its length, instead of every sample.
+#[derive(Debug, Clone, Default)]
+pub struct AudioPeaks {
+    frames: u64,
+    levels: Vec<Vec<(f32, f32)>>,
+}
+
+impl AudioPeaks {
+    /// Computes the peaks of the given audio, decompressing it if necessary.
+    pub fn from_audio(audio: &Audio) -> Result<Self, AudioError> {
+        let audio = if matches!(
+            audio.format(),
+            AudioFormat::IntegerPcm | AudioFormat::FloatPcm
+        ) {
+            Cow::Borrowed(audio)
+        } else {
+            let mut audio = audio.clone();
+
+            audio.convert(AudioFormat::IntegerPcm)?;
+
+            Cow::Owned(audio)
+        };
+
+        let channels = audio.channels().max(1) as usize;
+        let bytes_per_sample = (audio.bits_per_sample() / 8) as usize;
+
+        if bytes_per_sample == 0 {
+            return Err(AudioError::InvalidAudioBitsPerSample(
+                audio.bits_per_sample(),
+            ));
+        }
+
+        let read_sample: fn(&[u8]) -> f32 = match (audio.format(), bytes_per_sample) {
+            (AudioFormat::IntegerPcm, 1) => |sample| (sample[0] as f32 - 128.0) / 128.0,
+            (AudioFormat::IntegerPcm, 2) => {
+                |sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / i16::MAX as f32
+            }
+            (AudioFormat::IntegerPcm, 3) => |sample| {
+                i32::from_le_bytes([0, sample[0], sample[1], sample[2]]) as f32 / i32::MAX as f32
+            },
+            (AudioFormat::IntegerPcm, 4) => |sample| {
+                i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]) as f32
+                    / i32::MAX as f32
+            },
+            (AudioFormat::FloatPcm, 4) => {
+                |sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]])
+            }
+            (AudioFormat::FloatPcm, 8) => {
+                |sample| f64::from_le_bytes(sample[0..8].try_into().unwrap_or_default()) as f32
+            }
+            _ => {
+                return Err(AudioError::InvalidAudioBitsPerSample(
+                    audio.bits_per_sample(),
+                ));
+            }
+        };
+
+        let frame_size = channels * bytes_per_sample;
+        let frames = (audio.data().len() / frame_size) as u64;
+
+        let mut peaks = Vec::with_capacity(frames.div_ceil(PEAK_BLOCK_SIZE) as usize);
+
+        for block in audio.data().chunks(frame_size * PEAK_BLOCK_SIZE as usize) {
+            peaks.push(merge_peaks(
+                block
+                    .chunks_exact(bytes_per_sample)
+                    .map(|sample| read_sample(sample).clamp(-1.0, 1.0))
+                    .map(|sample| (sample, sample)),
+            ));
+        }
+
+        let mut levels = vec![peaks];
+
+        while let Some(last) = levels.last()
+            && last.len() > 1
+        {
+            let next = last
+                .chunks(2)
+                .map(|pair| merge_peaks(pair.iter().copied()))
+                .collect();
+
+            levels.push(next);
+        }
+
+        Ok(Self { frames, levels })
+    }
+
+    /// The number of frames summarized by the peaks.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Whether or not there are no peaks.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Returns the minimum and maximum sample in the given range of frames, from -1.0 to 1.0.
+    pub fn peak(&self, start: u64, end: u64) -> (f32, f32) {
+        let end = end.min(self.frames);
+
+        if start >= end {
+            return (0.0, 0.0);
+        }
+
+        // Pick the coarsest level whose peaks are no larger than half of the range, so only a few are read.
+        let span = end - start;
+        let level = (0..self.levels.len())
+            .rev()
+            .find(|level| (PEAK_BLOCK_SIZE << level) * 2 <= span)
+            .unwrap_or_default();
+
+        let block_size = PEAK_BLOCK_SIZE << level;
+        let peaks = &self.levels[level];
+
+        let first = (start / block_size) as usize;
+        let last = (((end - 1) / block_size) as usize).min(peaks.len().saturating_sub(1));
+
+        merge_peaks(peaks.get(first..=last).unwrap_or_default().iter().copied())
+    }
+
+    /// Returns the peaks for the given number of evenly sized columns, covering the given range of frames.
+    pub fn columns(&self, start: u64, end: u64, count: usize) -> Vec<(f32, f32)> {
+        let end = end.min(self.frames);
+        let span = end.saturating_sub(start) as f64;
+
+        (0..count)
+            .map(|column| {
+                let column_start = start + (span * column as f64 / count as f64) as u64;
+                let column_end = start + (span * (column + 1) as f64 / count as f64).ceil() as u64;
+
+                self.peak(column_start, column_end.max(column_start + 1))
+            })
+            .collect()
+    }
+}
+
+/// Utility to merge peaks into a single peak.
+fn merge_peaks<I: Iterator<Item = (f32, f32)>>(peaks: I) -> (f32, f32) {
+    let (min, max) = peaks.fold((f32::MAX, f32::MIN), |(min, max), peak| {
+        (min.min(peak.0), max.max(peak.1))
+    });
+
+    if min > max { (0.0, 0.0) } else { (min, max) }
+}
@@ -1,3 +1,5 @@
+use porter_utils::ReportError;
+
 use crate::AudioFileType;
 use crate::AudioFormat;
 
@@ -14,6 +16,8 @@ pub enum AudioError {
     ContainerInvalid(AudioFileType),
     ConversionError,
     ConversionFeatureDisabled,
+    NoAudioSegments,
+    MismatchedAudioSegment(usize),
     IoError(std::io::Error),
     FlacVerifyError(flacenc::error::VerifyError),
     FlacSourceError,
@@ -109,3 +113,89 @@ impl From<lewton::OggReadError> for AudioError {
         Self::OggReadError(value)
     }
 }
+
+impl ReportError for AudioError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::UnsupportedAudioFormat(_) => "AUD-001",
+            Self::UnsupportedAudioFileType => "AUD-002",
+            Self::InvalidAudioFormat(_) => "AUD-003",
+            Self::InvalidAudioChannels(_) => "AUD-004",
+            Self::InvalidAudioBlockAlign(_) => "AUD-005",
+            Self::InvalidAudioBitsPerSample(_) => "AUD-006",
+            Self::ContainerFormatInvalid(_, _) => "AUD-007",
+            Self::ContainerInvalid(_) => "AUD-008",
+            Self::ConversionError => "AUD-009",
+            Self::ConversionFeatureDisabled => "AUD-010",
+            Self::NoAudioSegments => "AUD-011",
+            Self::MismatchedAudioSegment(_) => "AUD-012",
+            Self::IoError(e) => e.error_code(),
+            Self::FlacVerifyError(_)
+            | Self::FlacSourceError
+            | Self::FlacEncodeError
+            | Self::FlacDecodeError(_) => "AUD-013",
+            Self::TryFromSliceError(_) => "AUD-014",
+            Self::TryReserveError(e) => e.error_code(),
+            #[cfg(feature = "xma2-wma")]
+            Self::Xma2WmaDecodeError(_) => "AUD-015",
+            #[cfg(feature = "wwise-vorbis")]
+            Self::WwiseHeaderReadError(_) | Self::WwiseAudioReadError(_) => "AUD-016",
+            #[cfg(feature = "ogg")]
+            Self::OggVorbisError(_) | Self::OggReadError(_) => "AUD-017",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::UnsupportedAudioFormat(format) => {
+                format!("The audio format {format:?} is not supported.")
+            }
+            Self::UnsupportedAudioFileType => String::from("The audio file type is not supported."),
+            Self::InvalidAudioFormat(format) => format!("The audio format {format:?} is invalid."),
+            Self::InvalidAudioChannels(channels) => {
+                format!("The audio channel count {channels} is invalid.")
+            }
+            Self::InvalidAudioBlockAlign(align) => {
+                format!("The audio block align {align} is invalid.")
+            }
+            Self::InvalidAudioBitsPerSample(bits) => {
+                format!("The audio bits per sample {bits} is invalid.")
+            }
+            Self::ContainerFormatInvalid(format, file_type) => {
+                format!("The audio format {format:?} can't be saved as {file_type:?}.")
+            }
+            Self::ContainerInvalid(file_type) => {
+                format!("The {file_type:?} audio file is invalid.")
+            }
+            Self::ConversionError => String::from("The audio could not be converted."),
+            Self::ConversionFeatureDisabled => {
+                String::from("Audio conversion is not available in this build.")
+            }
+            Self::NoAudioSegments => String::from("There is no audio to combine."),
+            Self::MismatchedAudioSegment(index) => {
+                format!("Audio segment {index} doesn't match the format of the first segment.")
+            }
+            Self::IoError(e) => e.error_message(),
+            Self::FlacVerifyError(_)
+            | Self::FlacSourceError
+            | Self::FlacEncodeError
+            | Self::FlacDecodeError(_) => {
+                String::from("The flac audio could not be read or saved.")
+            }
+            Self::TryFromSliceError(_) => String::from("The audio data is incomplete or corrupt."),
+            Self::TryReserveError(e) => e.error_message(),
+            #[cfg(feature = "xma2-wma")]
+            Self::Xma2WmaDecodeError(_) => {
+                String::from("The xma2 or wma audio could not be decoded.")
+            }
+            #[cfg(feature = "wwise-vorbis")]
+            Self::WwiseHeaderReadError(_) | Self::WwiseAudioReadError(_) => {
+                String::from("The wwise vorbis audio could not be decoded.")
+            }
+            #[cfg(feature = "ogg")]
+            Self::OggVorbisError(_) | Self::OggReadError(_) => {
+                String::from("The ogg vorbis audio could not be decoded.")
+            }
+        }
+    }
+}
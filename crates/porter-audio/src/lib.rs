@@ -3,25 +3,29 @@
 mod audio;
 mod audio_file_type;
 mod audio_format;
+mod audio_info;
+mod audio_peaks;
 mod error;
 mod software_coerce;
 mod software_compress;
 mod software_decompress;
+mod vorbis_encoder;
 
 pub(crate) mod audio_file_type_flac;
-pub(crate) mod audio_file_type_wav;
-
-#[cfg(feature = "ogg")]
 pub(crate) mod audio_file_type_ogg;
+pub(crate) mod audio_file_type_wav;
 
 pub use audio::*;
 pub use audio_file_type::*;
 pub use audio_format::*;
+pub use audio_info::*;
+pub use audio_peaks::*;
 pub use error::*;
 
 pub(crate) use software_coerce::*;
 pub(crate) use software_compress::*;
 pub(crate) use software_decompress::*;
+pub(crate) use vorbis_encoder::*;
 
 #[cfg(feature = "ima-adpcm")]
 mod software_decompress_ima_adpcm;
@@ -0,0 +1,1056 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::PI;
+
+/// The size of every block as a power of two, both vorbis block sizes are the same.
+const BLOCK_SIZE_EXPONENT: u32 = 11;
+/// The size of every block in samples.
+const BLOCK_SIZE: usize = 1 << BLOCK_SIZE_EXPONENT;
+/// The number of spectral coefficients in every block.
+const SPECTRUM_SIZE: usize = BLOCK_SIZE / 2;
+
+/// The vendor written to the comment header.
+const VENDOR: &str = "porter-audio";
+
+/// The floor amplitude multiplier, a step of 1.1db.
+const FLOOR_MULTIPLIER: u32 = 2;
+/// The range of a floor amplitude for the multiplier.
+const FLOOR_RANGE: u32 = 128;
+/// The number of floor partitions.
+const FLOOR_PARTITIONS: usize = 8;
+/// The number of floor posts in every partition.
+const FLOOR_PARTITION_POSTS: usize = 4;
+
+/// The number of coefficients in every residue partition.
+const RESIDUE_PARTITION_SIZE: usize = 16;
+/// The number of residue classifications.
+const RESIDUE_CLASSIFICATIONS: usize = 5;
+/// The number of residue classifications coded by every class codeword.
+const RESIDUE_CLASSWORDS: usize = 2;
+/// The largest residue value that can be coded.
+const RESIDUE_MAX: i32 = 248;
+
+/// The ratio of the band energy to the quantization step, which sets the quality.
+const QUALITY_RATIO: f32 = 12.0;
+/// The ratio of a band peak to the quantization step, which prevents clipping the residue.
+const PEAK_RATIO: f32 = 200.0;
+/// The quietest coefficient coded, relative to the loudest coefficient in the block.
+const DYNAMIC_RANGE: f32 = 0.001;
+
+/// The codebook used for floor amplitudes.
+const FLOOR_BOOK: usize = 0;
+/// The codebook used for residue classifications.
+const CLASS_BOOK: usize = 1;
+
+/// The inverse db table from the vorbis specification.
+#[rustfmt::skip]
+#[allow(clippy::excessive_precision)]
+const FLOOR1_INVERSE_DB_TABLE: [f32; 256] = [
+    1.0649863e-07, 1.1341951e-07, 1.2079015e-07, 1.2863978e-07,
+    1.3699951e-07, 1.4590251e-07, 1.5538408e-07, 1.6548181e-07,
+    1.7623575e-07, 1.8768855e-07, 1.9988561e-07, 2.1287530e-07,
+    2.2670913e-07, 2.4144197e-07, 2.5713223e-07, 2.7384213e-07,
+    2.9163793e-07, 3.1059021e-07, 3.3077411e-07, 3.5226968e-07,
+    3.7516214e-07, 3.9954229e-07, 4.2550680e-07, 4.5315863e-07,
+    4.8260743e-07, 5.1396998e-07, 5.4737065e-07, 5.8294187e-07,
+    6.2082472e-07, 6.6116941e-07, 7.0413592e-07, 7.4989464e-07,
+    7.9862701e-07, 8.5052630e-07, 9.0579828e-07, 9.6466216e-07,
+    1.0273513e-06, 1.0941144e-06, 1.1652161e-06, 1.2409384e-06,
+    1.3215816e-06, 1.4074654e-06, 1.4989305e-06, 1.5963394e-06,
+    1.7000785e-06, 1.8105592e-06, 1.9282195e-06, 2.0535261e-06,
+    2.1869758e-06, 2.3290978e-06, 2.4804557e-06, 2.6416497e-06,
+    2.8133190e-06, 2.9961443e-06, 3.1908506e-06, 3.3982101e-06,
+    3.6190449e-06, 3.8542308e-06, 4.1047004e-06, 4.3714470e-06,
+    4.6555282e-06, 4.9580707e-06, 5.2802740e-06, 5.6234160e-06,
+    5.9888572e-06, 6.3780469e-06, 6.7925283e-06, 7.2339451e-06,
+    7.7040476e-06, 8.2047000e-06, 8.7378876e-06, 9.3057248e-06,
+    9.9104632e-06, 1.0554501e-05, 1.1240392e-05, 1.1970856e-05,
+    1.2748789e-05, 1.3577278e-05, 1.4459606e-05, 1.5399272e-05,
+    1.6400004e-05, 1.7465768e-05, 1.8600792e-05, 1.9809576e-05,
+    2.1096914e-05, 2.2467911e-05, 2.3928002e-05, 2.5482978e-05,
+    2.7139006e-05, 2.8902651e-05, 3.0780908e-05, 3.2781225e-05,
+    3.4911534e-05, 3.7180282e-05, 3.9596466e-05, 4.2169667e-05,
+    4.4910090e-05, 4.7828601e-05, 5.0936773e-05, 5.4246931e-05,
+    5.7772202e-05, 6.1526565e-05, 6.5524908e-05, 6.9783085e-05,
+    7.4317983e-05, 7.9147585e-05, 8.4291040e-05, 8.9768747e-05,
+    9.5602426e-05, 0.00010181521, 0.00010843174, 0.00011547824,
+    0.00012298267, 0.00013097477, 0.00013948625, 0.00014855085,
+    0.00015820453, 0.00016848555, 0.00017943469, 0.00019109536,
+    0.00020351382, 0.00021673929, 0.00023082423, 0.00024582449,
+    0.00026179955, 0.00027881276, 0.00029693158, 0.00031622787,
+    0.00033677814, 0.00035866388, 0.00038197188, 0.00040679456,
+    0.00043323036, 0.00046138411, 0.00049136745, 0.00052329927,
+    0.00055730621, 0.00059352311, 0.00063209358, 0.00067317058,
+    0.00071691700, 0.00076350630, 0.00081312324, 0.00086596457,
+    0.00092223983, 0.00098217216, 0.0010459992,  0.0011139742,
+    0.0011863665,  0.0012634633,  0.0013455702,  0.0014330129,
+    0.0015261382,  0.0016253153,  0.0017309374,  0.0018434235,
+    0.0019632195,  0.0020908006,  0.0022266726,  0.0023713743,
+    0.0025254795,  0.0026895994,  0.0028643847,  0.0030505286,
+    0.0032487691,  0.0034598925,  0.0036847358,  0.0039241906,
+    0.0041792066,  0.0044507950,  0.0047400328,  0.0050480668,
+    0.0053761186,  0.0057254891,  0.0060975636,  0.0064938176,
+    0.0069158225,  0.0073652516,  0.0078438871,  0.0083536271,
+    0.0088964928,  0.009474637,   0.010090352,   0.010746080,
+    0.011444421,   0.012188144,   0.012980198,   0.013823725,
+    0.014722068,   0.015678791,   0.016697687,   0.017782797,
+    0.018938423,   0.020169149,   0.021479854,   0.022875735,
+    0.024362330,   0.025945531,   0.027631618,   0.029427276,
+    0.031339626,   0.033376252,   0.035545228,   0.037855157,
+    0.040315199,   0.042935108,   0.045725273,   0.048696758,
+    0.051861348,   0.055231591,   0.058820850,   0.062643361,
+    0.066714279,   0.071049749,   0.075666962,   0.080584227,
+    0.085821044,   0.091398179,   0.097337747,   0.10366330,
+    0.11039993,    0.11757434,    0.12521498,    0.13335215,
+    0.14201813,    0.15124727,    0.16107617,    0.17154380,
+    0.18269168,    0.19456402,    0.20720788,    0.22067342,
+    0.23501402,    0.25028656,    0.26655159,    0.28387361,
+    0.30232132,    0.32196786,    0.34289114,    0.36517414,
+    0.38890521,    0.41417847,    0.44109412,    0.46975890,
+    0.50028648,    0.53279791,    0.56742212,    0.60429640,
+    0.64356699,    0.68538959,    0.72993007,    0.77736504,
+    0.82788260,    0.88168307,    0.9389798,     1.0,
+];
+
+/// Writes values to a vorbis packet, least significant bit first.
+struct BitWriter {
+    buffer: Vec<u8>,
+    accumulator: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    /// Constructs a new empty bit writer.
+    fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            accumulator: 0,
+            count: 0,
+        }
+    }
+
+    /// Writes the lowest bits of the given value.
+    fn write(&mut self, value: u32, bits: u32) {
+        let mask = if bits >= 32 {
+            u32::MAX as u64
+        } else {
+            (1u64 << bits) - 1
+        };
+
+        self.accumulator |= (value as u64 & mask) << self.count;
+        self.count += bits;
+
+        while self.count >= 8 {
+            self.buffer.push(self.accumulator as u8);
+            self.accumulator >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Writes the given bytes.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write(*byte as u32, 8);
+        }
+    }
+
+    /// Finishes the packet, padding the last byte with zeros.
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.buffer.push(self.accumulator as u8);
+        }
+
+        self.buffer
+    }
+}
+
+/// The value lookup of a vector codebook, where every dimension uses the same values.
+#[derive(Debug, Clone, Copy)]
+struct Lattice {
+    minimum: i32,
+    delta: i32,
+    values: u32,
+}
+
+/// A vorbis codebook and the codewords used to write its entries.
+struct Codebook {
+    dimensions: u32,
+    lengths: Vec<u8>,
+    codewords: Vec<u32>,
+    lattice: Option<Lattice>,
+}
+
+impl Codebook {
+    /// Constructs a codebook of entries without values, with codeword lengths fit to the given weights.
+    fn entries(dimensions: u32, weights: &[f64]) -> Self {
+        let lengths = huffman_lengths(weights);
+        let codewords = codewords(&lengths);
+
+        Self {
+            dimensions,
+            lengths,
+            codewords,
+            lattice: None,
+        }
+    }
+
+    /// Constructs a vector codebook of every combination of the given values, with codeword lengths fit to
+    /// the weight of every value.
+    fn lattice<W: Fn(i32) -> f64>(
+        dimensions: u32,
+        minimum: i32,
+        delta: i32,
+        values: u32,
+        weight: W,
+    ) -> Self {
+        let entries = values.pow(dimensions);
+
+        let weights: Vec<f64> = (0..entries)
+            .map(|entry| {
+                let mut entry = entry;
+                let mut result = 1.0;
+
+                for _ in 0..dimensions {
+                    result *= weight(minimum + (entry % values) as i32 * delta);
+                    entry /= values;
+                }
+
+                result
+            })
+            .collect();
+
+        let lengths = huffman_lengths(&weights);
+        let codewords = codewords(&lengths);
+
+        Self {
+            dimensions,
+            lengths,
+            codewords,
+            lattice: Some(Lattice {
+                minimum,
+                delta,
+                values,
+            }),
+        }
+    }
+
+    /// Writes the codebook to the setup header.
+    fn write_header(&self, writer: &mut BitWriter) {
+        writer.write(0x564342, 24);
+        writer.write(self.dimensions, 16);
+        writer.write(self.lengths.len() as u32, 24);
+
+        // Entries are unordered, and every entry is used.
+        writer.write(0, 1);
+        writer.write(0, 1);
+
+        for length in &self.lengths {
+            writer.write(*length as u32 - 1, 5);
+        }
+
+        let Some(lattice) = self.lattice else {
+            writer.write(0, 4);
+            return;
+        };
+
+        let value_bits = ilog(lattice.values - 1).max(1);
+
+        writer.write(1, 4);
+        writer.write(pack_float(lattice.minimum), 32);
+        writer.write(pack_float(lattice.delta), 32);
+        writer.write(value_bits - 1, 4);
+        writer.write(0, 1);
+
+        for multiplicand in 0..lattice.values {
+            writer.write(multiplicand, value_bits);
+        }
+    }
+
+    /// Writes the codeword for the given entry.
+    fn write_entry(&self, writer: &mut BitWriter, entry: u32) {
+        writer.write(
+            self.codewords[entry as usize],
+            self.lengths[entry as usize] as u32,
+        );
+    }
+
+    /// Writes the codeword for the entry with the given values, which must be on the lattice.
+    fn write_vector(&self, writer: &mut BitWriter, vector: &[i32]) {
+        let Some(lattice) = self.lattice else {
+            return;
+        };
+
+        let mut entry = 0;
+
+        for value in vector.iter().rev() {
+            entry = entry * lattice.values + ((value - lattice.minimum) / lattice.delta) as u32;
+        }
+
+        self.write_entry(writer, entry);
+    }
+}
+
+/// The residue books used by every classification, per pass.
+const RESIDUE_BOOKS: [&[usize]; RESIDUE_CLASSIFICATIONS] = [&[], &[2], &[3], &[4], &[5, 6]];
+
+/// Builds every codebook used by the encoder.
+fn build_codebooks() -> Vec<Codebook> {
+    vec![
+        // Floor amplitudes, which are usually close to the predicted amplitude.
+        Codebook::entries(
+            1,
+            &(0..FLOOR_RANGE)
+                .map(|value| (-(value as f64) / 10.0).exp())
+                .collect::<Vec<_>>(),
+        ),
+        // Residue classifications, where every entry codes the classification of several partitions.
+        Codebook::entries(
+            RESIDUE_CLASSWORDS as u32,
+            &[1.0; RESIDUE_CLASSIFICATIONS.pow(RESIDUE_CLASSWORDS as u32)],
+        ),
+        // Residue values, from the quietest to the loudest partitions.
+        Codebook::lattice(4, -1, 1, 3, |value| (-(value.abs() as f64)).exp()),
+        Codebook::lattice(2, -4, 1, 9, |value| (-(value.abs() as f64) * 0.6).exp()),
+        Codebook::lattice(2, -15, 1, 31, |value| (-(value.abs() as f64) * 0.2).exp()),
+        Codebook::lattice(2, -240, 16, 31, |value| {
+            (-(value.abs() as f64) / 16.0 * 0.3).exp()
+        }),
+        Codebook::lattice(2, -8, 1, 17, |value| (-(value.abs() as f64) * 0.05).exp()),
+    ]
+}
+
+/// Computes the codeword lengths of a complete huffman tree for the given weights.
+fn huffman_lengths(weights: &[f64]) -> Vec<u8> {
+    let mut weights: Vec<f64> = weights.to_vec();
+
+    loop {
+        let total: f64 = weights.iter().sum();
+
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = weights
+            .iter()
+            .enumerate()
+            .map(|(index, weight)| {
+                Reverse(((weight / total * (1u64 << 40) as f64) as u64 + 1, index))
+            })
+            .collect();
+
+        let mut parents = vec![usize::MAX; weights.len() * 2];
+        let mut next = weights.len();
+
+        while heap.len() > 1 {
+            let (Some(Reverse((a_weight, a))), Some(Reverse((b_weight, b)))) =
+                (heap.pop(), heap.pop())
+            else {
+                break;
+            };
+
+            parents[a] = next;
+            parents[b] = next;
+
+            heap.push(Reverse((a_weight + b_weight, next)));
+            next += 1;
+        }
+
+        let lengths: Vec<u8> = (0..weights.len())
+            .map(|mut node| {
+                let mut length = 0;
+
+                while parents[node] != usize::MAX {
+                    node = parents[node];
+                    length += 1;
+                }
+
+                length
+            })
+            .collect();
+
+        // Flatten the weights until every codeword fits comfortably in the 32 bit limit.
+        if lengths.iter().all(|length| *length <= 24) {
+            return lengths;
+        }
+
+        weights
+            .iter_mut()
+            .for_each(|weight| *weight = weight.sqrt());
+    }
+}
+
+/// Assigns codewords to the given lengths the way a vorbis decoder does, bit reversed to be written.
+fn codewords(lengths: &[u8]) -> Vec<u32> {
+    let mut marker = [0u32; 33];
+    let mut result = Vec::with_capacity(lengths.len());
+
+    for length in lengths {
+        let length = *length as usize;
+        let mut entry = marker[length];
+
+        result.push(entry);
+
+        for j in (1..=length).rev() {
+            if marker[j] & 1 != 0 {
+                if j == 1 {
+                    marker[1] += 1;
+                } else {
+                    marker[j] = marker[j - 1] << 1;
+                }
+
+                break;
+            }
+
+            marker[j] += 1;
+        }
+
+        for j in length + 1..33 {
+            if marker[j] >> 1 == entry {
+                entry = marker[j];
+                marker[j] = marker[j - 1] << 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    result
+        .into_iter()
+        .zip(lengths)
+        .map(|(codeword, length)| {
+            if *length == 0 {
+                0
+            } else {
+                codeword.reverse_bits() >> (32 - *length as u32)
+            }
+        })
+        .collect()
+}
+
+/// Packs an integer in the vorbis float format.
+fn pack_float(value: i32) -> u32 {
+    let sign = if value < 0 { 0x80000000 } else { 0 };
+
+    sign | (788 << 21) | (value.unsigned_abs() & 0x1FFFFF)
+}
+
+/// The number of bits required to store the given value.
+fn ilog(value: u32) -> u32 {
+    u32::BITS - value.leading_zeros()
+}
+
+/// Computes the floor post positions, denser at low frequencies.
+fn floor_posts() -> Vec<u32> {
+    let count = FLOOR_PARTITIONS * FLOOR_PARTITION_POSTS;
+    let mut result = vec![0, SPECTRUM_SIZE as u32];
+    let mut previous = 0;
+
+    for i in 1..=count {
+        let position = (i as f32 / (count + 1) as f32).powi(2) * SPECTRUM_SIZE as f32;
+        let position = (position.round() as u32).max(previous + 1);
+
+        result.push(position);
+        previous = position;
+    }
+
+    result
+}
+
+/// Finds the index of the closest post below the post at the given index, out of the posts before it.
+fn low_neighbor(posts: &[u32], index: usize) -> usize {
+    (0..index)
+        .filter(|i| posts[*i] < posts[index])
+        .max_by_key(|i| posts[*i])
+        .unwrap_or(0)
+}
+
+/// Finds the index of the closest post above the post at the given index, out of the posts before it.
+fn high_neighbor(posts: &[u32], index: usize) -> usize {
+    (0..index)
+        .filter(|i| posts[*i] > posts[index])
+        .min_by_key(|i| posts[*i])
+        .unwrap_or(1)
+}
+
+/// Predicts the amplitude at a position on the line between two posts.
+fn render_point(x0: u32, y0: u32, x1: u32, y1: u32, x: u32) -> u32 {
+    let dy = y1 as i32 - y0 as i32;
+    let adx = (x1 - x0) as i32;
+    let offset = dy.abs() * (x - x0) as i32 / adx;
+
+    if dy < 0 {
+        (y0 as i32 - offset) as u32
+    } else {
+        (y0 as i32 + offset) as u32
+    }
+}
+
+/// Renders the line between two posts into the floor curve.
+fn render_line(x0: u32, y0: u32, x1: u32, y1: u32, curve: &mut Vec<u32>) {
+    let dy = y1 as i32 - y0 as i32;
+    let adx = x1 as i32 - x0 as i32;
+    let base = dy / adx;
+    let sy = base + if dy < 0 { -1 } else { 1 };
+    let ady = dy.abs() - base.abs() * adx;
+
+    let mut y = y0 as i32;
+    let mut error = 0;
+
+    curve.push(y as u32);
+
+    for _ in (x0 + 1)..x1 {
+        error += ady;
+
+        if error >= adx {
+            error -= adx;
+            y += sy;
+        } else {
+            y += base;
+        }
+
+        curve.push(y as u32);
+    }
+}
+
+/// A floor coded for a single channel.
+struct Floor {
+    values: Vec<u32>,
+    curve: Vec<f32>,
+}
+
+/// A radix-2 complex fft.
+fn fft(real: &mut [f32], imaginary: &mut [f32], twiddles: &[(f32, f32)]) {
+    let size = real.len();
+    let mut j = 0;
+
+    for i in 1..size {
+        let mut bit = size >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+
+        j |= bit;
+
+        if i < j {
+            real.swap(i, j);
+            imaginary.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+
+    while length <= size {
+        let stride = size / length;
+
+        for start in (0..size).step_by(length) {
+            for k in 0..length / 2 {
+                let (twiddle_real, twiddle_imaginary) = twiddles[k * stride];
+
+                let a = start + k;
+                let b = a + length / 2;
+
+                let real_b = real[b] * twiddle_real - imaginary[b] * twiddle_imaginary;
+                let imaginary_b = real[b] * twiddle_imaginary + imaginary[b] * twiddle_real;
+
+                real[b] = real[a] - real_b;
+                imaginary[b] = imaginary[a] - imaginary_b;
+                real[a] += real_b;
+                imaginary[a] += imaginary_b;
+            }
+        }
+
+        length <<= 1;
+    }
+}
+
+/// An encoder which writes vorbis packets for blocks of samples.
+pub struct VorbisEncoder {
+    channels: u32,
+    sample_rate: u32,
+    codebooks: Vec<Codebook>,
+    posts: Vec<u32>,
+    window: Vec<f32>,
+    pre_twiddles: Vec<(f32, f32)>,
+    post_twiddles: Vec<(f32, f32)>,
+    fft_twiddles: Vec<(f32, f32)>,
+}
+
+impl VorbisEncoder {
+    /// The number of samples per channel in every block.
+    pub const BLOCK_SIZE: usize = BLOCK_SIZE;
+    /// The number of samples per channel a block advances by.
+    pub const BLOCK_STEP: usize = BLOCK_SIZE / 2;
+
+    /// Constructs a new encoder for the given channels and sample rate.
+    pub fn new(channels: u32, sample_rate: u32) -> Self {
+        let window = (0..BLOCK_SIZE)
+            .map(|i| {
+                let x = ((i as f32 + 0.5) / BLOCK_SIZE as f32 * PI).sin();
+
+                (FRAC_PI_2 * x * x).sin()
+            })
+            .collect();
+
+        let quarter = SPECTRUM_SIZE / 2;
+
+        let pre_twiddles = (0..quarter)
+            .map(|n| {
+                let angle = -PI * (n as f32 + 0.25) / SPECTRUM_SIZE as f32;
+
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+
+        let post_twiddles = (0..quarter)
+            .map(|k| {
+                let angle = -PI * k as f32 / SPECTRUM_SIZE as f32;
+
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+
+        let fft_twiddles = (0..quarter / 2)
+            .map(|k| {
+                let angle = -2.0 * PI * k as f32 / quarter as f32;
+
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+
+        Self {
+            channels,
+            sample_rate,
+            codebooks: build_codebooks(),
+            posts: floor_posts(),
+            window,
+            pre_twiddles,
+            post_twiddles,
+            fft_twiddles,
+        }
+    }
+
+    /// Builds the identification header packet.
+    pub fn identification_header(&self) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+
+        writer.write(1, 8);
+        writer.write_bytes(b"vorbis");
+        writer.write(0, 32);
+        writer.write(self.channels, 8);
+        writer.write(self.sample_rate, 32);
+
+        // Maximum, nominal, and minimum bitrates are unset.
+        writer.write(0, 32);
+        writer.write(0, 32);
+        writer.write(0, 32);
+
+        writer.write(BLOCK_SIZE_EXPONENT, 4);
+        writer.write(BLOCK_SIZE_EXPONENT, 4);
+        writer.write(1, 1);
+
+        writer.finish()
+    }
+
+    /// Builds the comment header packet.
+    pub fn comment_header(&self) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+
+        writer.write(3, 8);
+        writer.write_bytes(b"vorbis");
+        writer.write(VENDOR.len() as u32, 32);
+        writer.write_bytes(VENDOR.as_bytes());
+        writer.write(0, 32);
+        writer.write(1, 1);
+
+        writer.finish()
+    }
+
+    /// Builds the setup header packet.
+    pub fn setup_header(&self) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+
+        writer.write(5, 8);
+        writer.write_bytes(b"vorbis");
+
+        writer.write(self.codebooks.len() as u32 - 1, 8);
+
+        for codebook in &self.codebooks {
+            codebook.write_header(&mut writer);
+        }
+
+        // A single unused time domain transform.
+        writer.write(0, 6);
+        writer.write(0, 16);
+
+        // A single type 1 floor, with every partition using the same class.
+        writer.write(0, 6);
+        writer.write(1, 16);
+        writer.write(FLOOR_PARTITIONS as u32, 5);
+
+        for _ in 0..FLOOR_PARTITIONS {
+            writer.write(0, 4);
+        }
+
+        writer.write(FLOOR_PARTITION_POSTS as u32 - 1, 3);
+        writer.write(0, 2);
+        writer.write(FLOOR_BOOK as u32 + 1, 8);
+        writer.write(FLOOR_MULTIPLIER - 1, 2);
+        writer.write(BLOCK_SIZE_EXPONENT - 1, 4);
+
+        for post in &self.posts[2..] {
+            writer.write(*post, BLOCK_SIZE_EXPONENT - 1);
+        }
+
+        // A single type 1 residue.
+        writer.write(0, 6);
+        writer.write(1, 16);
+        writer.write(0, 24);
+        writer.write(SPECTRUM_SIZE as u32, 24);
+        writer.write(RESIDUE_PARTITION_SIZE as u32 - 1, 24);
+        writer.write(RESIDUE_CLASSIFICATIONS as u32 - 1, 6);
+        writer.write(CLASS_BOOK as u32, 8);
+
+        for books in RESIDUE_BOOKS {
+            let cascade = (1u32 << books.len()) - 1;
+
+            writer.write(cascade & 0x7, 3);
+            writer.write((cascade > 0x7) as u32, 1);
+
+            if cascade > 0x7 {
+                writer.write(cascade >> 3, 5);
+            }
+        }
+
+        for books in RESIDUE_BOOKS {
+            for book in books {
+                writer.write(*book as u32, 8);
+            }
+        }
+
+        // A single mapping, with one submap and no channel coupling.
+        writer.write(0, 6);
+        writer.write(0, 16);
+        writer.write(0, 1);
+        writer.write(0, 1);
+        writer.write(0, 2);
+        writer.write(0, 8);
+        writer.write(0, 8);
+        writer.write(0, 8);
+
+        // A single mode.
+        writer.write(0, 6);
+        writer.write(0, 1);
+        writer.write(0, 16);
+        writer.write(0, 16);
+        writer.write(0, 8);
+
+        writer.write(1, 1);
+
+        writer.finish()
+    }
+
+    /// Encodes an audio packet for a block of samples per channel.
+    pub fn encode(&self, block: &[Vec<f32>]) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+
+        writer.write(0, 1);
+
+        let spectrums: Vec<Vec<f32>> = block
+            .iter()
+            .map(|samples| self.transform(samples))
+            .collect();
+
+        let mut residues = Vec::with_capacity(spectrums.len());
+
+        for spectrum in &spectrums {
+            let floor = self.floor(spectrum);
+
+            writer.write(1, 1);
+            writer.write(floor.values[0], ilog(FLOOR_RANGE - 1));
+            writer.write(floor.values[1], ilog(FLOOR_RANGE - 1));
+
+            for value in &floor.values[2..] {
+                self.codebooks[FLOOR_BOOK].write_entry(&mut writer, *value);
+            }
+
+            let residue: Vec<i32> = spectrum
+                .iter()
+                .zip(&floor.curve)
+                .map(|(coefficient, floor)| {
+                    ((coefficient / floor).round() as i32).clamp(-RESIDUE_MAX, RESIDUE_MAX)
+                })
+                .collect();
+
+            residues.push(residue);
+        }
+
+        self.write_residue(&mut writer, &residues);
+
+        writer.finish()
+    }
+
+    /// Transforms a windowed block of samples into spectral coefficients.
+    fn transform(&self, samples: &[f32]) -> Vec<f32> {
+        let quarter = BLOCK_SIZE / 4;
+        let half = SPECTRUM_SIZE / 2;
+
+        let sample = |i: usize| samples.get(i).copied().unwrap_or_default() * self.window[i];
+
+        // Fold the block into a type 4 dct.
+        let mut folded = vec![0.0; SPECTRUM_SIZE];
+
+        for i in 0..quarter {
+            folded[i] = -sample(3 * quarter - 1 - i) - sample(3 * quarter + i);
+            folded[quarter + i] = sample(i) - sample(SPECTRUM_SIZE - 1 - i);
+        }
+
+        let mut real = vec![0.0; half];
+        let mut imaginary = vec![0.0; half];
+
+        for n in 0..half {
+            let (a, b) = (folded[2 * n], folded[SPECTRUM_SIZE - 1 - 2 * n]);
+            let (twiddle_real, twiddle_imaginary) = self.pre_twiddles[n];
+
+            real[n] = a * twiddle_real - b * twiddle_imaginary;
+            imaginary[n] = a * twiddle_imaginary + b * twiddle_real;
+        }
+
+        fft(&mut real, &mut imaginary, &self.fft_twiddles);
+
+        let scale = 4.0 / BLOCK_SIZE as f32;
+        let mut result = vec![0.0; SPECTRUM_SIZE];
+
+        for k in 0..half {
+            let (twiddle_real, twiddle_imaginary) = self.post_twiddles[k];
+
+            result[2 * k] = (real[k] * twiddle_real - imaginary[k] * twiddle_imaginary) * scale;
+            result[SPECTRUM_SIZE - 1 - 2 * k] =
+                -(real[k] * twiddle_imaginary + imaginary[k] * twiddle_real) * scale;
+        }
+
+        result
+    }
+
+    /// Fits a floor to the given spectrum, which sets the quantization step of every coefficient.
+    fn floor(&self, spectrum: &[f32]) -> Floor {
+        let posts = &self.posts;
+
+        let loudest = spectrum.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+
+        let mut sorted: Vec<usize> = (0..posts.len()).collect();
+
+        sorted.sort_by_key(|i| posts[*i]);
+
+        // Find the amplitude each post should have from the band of coefficients around it.
+        let mut targets = vec![0u32; posts.len()];
+
+        for (s, index) in sorted.iter().enumerate() {
+            let start = if s == 0 {
+                0
+            } else {
+                (posts[sorted[s - 1]] + posts[*index]) / 2
+            } as usize;
+
+            let end = if s + 1 == sorted.len() {
+                SPECTRUM_SIZE
+            } else {
+                (posts[*index] + posts[sorted[s + 1]]) as usize / 2 + 1
+            }
+            .min(SPECTRUM_SIZE);
+
+            let band =
+                &spectrum[start.min(SPECTRUM_SIZE - 1)..end.max(start + 1).min(SPECTRUM_SIZE)];
+
+            let energy = band.iter().map(|x| x * x).sum::<f32>() / band.len() as f32;
+            let peak = band.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+
+            let target = (energy.sqrt() / QUALITY_RATIO)
+                .max(peak / PEAK_RATIO)
+                .max(loudest * DYNAMIC_RANGE);
+
+            targets[*index] = (0..FLOOR_RANGE)
+                .rev()
+                .find(|y| FLOOR1_INVERSE_DB_TABLE[(y * FLOOR_MULTIPLIER) as usize] <= target)
+                .unwrap_or(0);
+        }
+
+        // Code every post relative to the amplitude predicted from its neighbors.
+        let mut values = vec![0u32; posts.len()];
+        let mut final_y = vec![0u32; posts.len()];
+        let mut used = vec![false; posts.len()];
+
+        values[0] = targets[0];
+        values[1] = targets[1];
+        final_y[0] = targets[0];
+        final_y[1] = targets[1];
+        used[0] = true;
+        used[1] = true;
+
+        for i in 2..posts.len() {
+            let low = low_neighbor(posts, i);
+            let high = high_neighbor(posts, i);
+
+            let predicted = render_point(
+                posts[low],
+                final_y[low],
+                posts[high],
+                final_y[high],
+                posts[i],
+            );
+
+            let target = targets[i];
+
+            if target == predicted {
+                final_y[i] = predicted;
+                continue;
+            }
+
+            let high_room = FLOOR_RANGE - predicted;
+            let low_room = predicted;
+            let room = high_room.min(low_room) * 2;
+
+            let value = if target > predicted {
+                let value = (target - predicted) * 2;
+
+                if value < room {
+                    value
+                } else {
+                    target - predicted + low_room
+                }
+            } else {
+                let value = (predicted - target) * 2 - 1;
+
+                if value < room {
+                    value
+                } else {
+                    predicted - target + high_room - 1
+                }
+            };
+
+            values[i] = value;
+            final_y[i] = target;
+            used[low] = true;
+            used[high] = true;
+            used[i] = true;
+        }
+
+        // Render the floor exactly as a decoder would.
+        let mut curve = Vec::with_capacity(SPECTRUM_SIZE);
+
+        let mut lx = 0;
+        let mut ly = final_y[sorted[0]] * FLOOR_MULTIPLIER;
+        let mut hx = 0;
+        let mut hy = 0;
+
+        for index in &sorted[1..] {
+            if used[*index] {
+                hx = posts[*index];
+                hy = final_y[*index] * FLOOR_MULTIPLIER;
+
+                render_line(lx, ly, hx, hy, &mut curve);
+
+                lx = hx;
+                ly = hy;
+            }
+        }
+
+        if hx < SPECTRUM_SIZE as u32 {
+            render_line(hx, hy, SPECTRUM_SIZE as u32, hy, &mut curve);
+        }
+
+        let curve = curve
+            .into_iter()
+            .take(SPECTRUM_SIZE)
+            .map(|y| FLOOR1_INVERSE_DB_TABLE[y as usize])
+            .collect();
+
+        Floor { values, curve }
+    }
+
+    /// Writes the residue of every channel.
+    fn write_residue(&self, writer: &mut BitWriter, residues: &[Vec<i32>]) {
+        let partitions = SPECTRUM_SIZE / RESIDUE_PARTITION_SIZE;
+
+        let classifications: Vec<Vec<usize>> = residues
+            .iter()
+            .map(|residue| {
+                residue
+                    .chunks_exact(RESIDUE_PARTITION_SIZE)
+                    .map(|partition| {
+                        match partition.iter().map(|x| x.abs()).max().unwrap_or_default() {
+                            0 => 0,
+                            1 => 1,
+                            2..=4 => 2,
+                            5..=15 => 3,
+                            _ => 4,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for pass in 0..8 {
+            let mut partition = 0;
+
+            while partition < partitions {
+                if pass == 0 {
+                    for classification in &classifications {
+                        let classword = (0..RESIDUE_CLASSWORDS).fold(0, |classword, i| {
+                            classword * RESIDUE_CLASSIFICATIONS
+                                + classification
+                                    .get(partition + i)
+                                    .copied()
+                                    .unwrap_or_default()
+                        });
+
+                        self.codebooks[CLASS_BOOK].write_entry(writer, classword as u32);
+                    }
+                }
+
+                for _ in 0..RESIDUE_CLASSWORDS {
+                    if partition >= partitions {
+                        break;
+                    }
+
+                    for (residue, classification) in residues.iter().zip(&classifications) {
+                        let books = RESIDUE_BOOKS[classification[partition]];
+
+                        let Some(book) = books.get(pass) else {
+                            continue;
+                        };
+
+                        let book = &self.codebooks[*book];
+                        let start = partition * RESIDUE_PARTITION_SIZE;
+
+                        for vector in residue[start..start + RESIDUE_PARTITION_SIZE]
+                            .chunks_exact(book.dimensions as usize)
+                        {
+                            let vector: Vec<i32> = vector
+                                .iter()
+                                .map(|value| cascade_value(*value, books.len(), pass))
+                                .collect();
+
+                            book.write_vector(writer, &vector);
+                        }
+                    }
+
+                    partition += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Splits a residue value into the part coded by the given pass of a cascade.
+fn cascade_value(value: i32, passes: usize, pass: usize) -> i32 {
+    if passes == 1 {
+        return value;
+    }
+
+    let coarse = ((value as f32 / 16.0).round() as i32).clamp(-15, 15) * 16;
+
+    if pass == 0 {
+        coarse
+    } else {
+        (value - coarse).clamp(-8, 8)
+    }
+}
@@ -1,3 +1,4 @@
+use std::io::Cursor;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Read;
@@ -9,6 +10,10 @@ use porter_utils::StructReadExt;
 use porter_utils::StructWriteExt;
 
 use crate::CastNode;
+use crate::CastOptions;
+
+/// Header flag set when the nodes are lz4 compressed, this is a porter extension to the cast format.
+const CAST_FLAG_COMPRESSED: u32 = 0x1;
 
 #[repr(C, packed)]
 #[derive(Debug, Clone, Copy)]
@@ -20,19 +25,38 @@ struct CastHeader {
 }
 
 /// A cast file.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct CastFile {
     root_nodes: Vec<CastNode>,
+    compressed: bool,
 }
 
 impl CastFile {
-    /// Constructs a new cast file.
+    /// Constructs a new uncompressed cast file.
     pub fn new() -> Self {
+        Self::with_options(CastOptions::default())
+    }
+
+    /// Constructs a new cast file, compressed when the options ask for it.
+    pub fn with_options(options: CastOptions) -> Self {
         Self {
             root_nodes: Vec::new(),
+            compressed: options.compressed,
         }
     }
 
+    /// Whether or not the nodes are compressed when written.
+    ///
+    /// Compressed files can only be read by importers that support the porter extension.
+    pub fn compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Sets whether or not the nodes are compressed when written.
+    pub fn set_compressed(&mut self, compressed: bool) {
+        self.compressed = compressed;
+    }
+
     /// Appends a root node to the file.
     pub fn push(&mut self, node: CastNode) {
         self.root_nodes.push(node);
@@ -50,19 +74,42 @@ impl CastFile {
 
     /// Serializes the cast file to the writer.
     pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
-        let header = CastHeader {
+        let mut header = CastHeader {
             magic: 0x74736163,
             version: 1,
             root_nodes: self.root_nodes.len() as u32,
             flags: 0,
         };
 
-        writer.write_struct(header)?;
+        if !self.compressed {
+            writer.write_struct(header)?;
+
+            for root in &self.root_nodes {
+                root.write(&mut writer)?;
+            }
+
+            return Ok(());
+        }
+
+        let mut nodes: Vec<u8> = Vec::new();
 
         for root in &self.root_nodes {
-            root.write(&mut writer)?;
+            root.write(&mut nodes)?;
+        }
+
+        // The decompressed size is stored as a u32, larger files are written uncompressed.
+        if nodes.len() > u32::MAX as usize {
+            writer.write_struct(header)?;
+            writer.write_all(&nodes)?;
+
+            return Ok(());
         }
 
+        header.flags |= CAST_FLAG_COMPRESSED;
+
+        writer.write_struct(header)?;
+        writer.write_all(&lz4_flex::compress_prepend_size(&nodes))?;
+
         Ok(())
     }
 
@@ -77,13 +124,20 @@ impl CastFile {
             ));
         }
 
-        let mut root_nodes = Vec::new();
+        let compressed = header.flags & CAST_FLAG_COMPRESSED != 0;
 
-        root_nodes.try_reserve_exact(header.root_nodes as _)?;
+        let mut root_nodes = if compressed {
+            let mut buffer = Vec::new();
 
-        for _ in 0..header.root_nodes {
-            root_nodes.push(CastNode::read(&mut reader)?);
-        }
+            reader.read_to_end(&mut buffer)?;
+
+            let nodes = lz4_flex::decompress_size_prepended(&buffer)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+            Self::read_roots(&mut Cursor::new(nodes), header.root_nodes)?
+        } else {
+            Self::read_roots(&mut reader, header.root_nodes)?
+        };
 
         let mut largest_hash_next: u64 = 0;
 
@@ -97,6 +151,28 @@ impl CastFile {
             root.set_hash_next(hash_next.clone());
         }
 
-        Ok(Self { root_nodes })
+        Ok(Self {
+            root_nodes,
+            compressed,
+        })
+    }
+
+    /// Deserializes the given number of root nodes from the reader.
+    fn read_roots<R: Read>(reader: &mut R, count: u32) -> Result<Vec<CastNode>, Error> {
+        let mut root_nodes = Vec::new();
+
+        root_nodes.try_reserve_exact(count as _)?;
+
+        for _ in 0..count {
+            root_nodes.push(CastNode::read(reader)?);
+        }
+
+        Ok(root_nodes)
+    }
+}
+
+impl Default for CastFile {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,9 @@
+/// How hashes are assigned to newly created cast nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CastHashMode {
+    /// Hashes are assigned from a counter, in the order nodes are created.
+    #[default]
+    Sequential,
+    /// Hashes are derived from the parent hash, node type, and name or position, so they stay stable across exports.
+    Stable,
+}
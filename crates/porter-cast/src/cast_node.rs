@@ -5,9 +5,11 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 
+use porter_utils::HashExt;
 use porter_utils::StructReadExt;
 use porter_utils::StructWriteExt;
 
+use crate::CastHashMode;
 use crate::CastId;
 use crate::CastProperty;
 use crate::CastPropertyId;
@@ -31,17 +33,24 @@ pub struct CastNode {
     identifier: CastId,
     hash: u64,
     hash_next: Arc<AtomicU64>,
+    hash_mode: CastHashMode,
     properties: Vec<CastProperty>,
     children: Vec<Self>,
 }
 
 impl CastNode {
-    /// Creates a new root node.
+    /// Creates a new root node, using sequential hashes.
     pub fn root() -> Self {
+        Self::root_with_hash_mode(CastHashMode::Sequential)
+    }
+
+    /// Creates a new root node, using the given hash mode for all of it's children.
+    pub fn root_with_hash_mode(hash_mode: CastHashMode) -> Self {
         Self {
             identifier: CastId::Root,
             hash: HASH_BASE,
             hash_next: Arc::new(AtomicU64::new(HASH_BASE + 1)),
+            hash_mode,
             properties: Vec::new(),
             children: Vec::new(),
         }
@@ -49,21 +58,33 @@ impl CastNode {
 
     /// Creates a new child node with the given identifier.
     pub fn create(&mut self, identifier: CastId) -> &mut Self {
-        debug_assert!(!matches!(identifier, CastId::Root));
+        let hash = match self.hash_mode {
+            CastHashMode::Sequential => self.hash_next.fetch_add(1, Ordering::Relaxed),
+            CastHashMode::Stable => {
+                let position = self.children_of_type(identifier).count() as u64;
 
-        let child = CastNode {
-            identifier,
-            hash: self.hash_next.fetch_add(1, Ordering::Relaxed),
-            hash_next: self.hash_next.clone(),
-            properties: Vec::new(),
-            children: Vec::new(),
+                self.stable_hash(identifier, &position.to_le_bytes())
+            }
         };
 
-        self.children.push(child);
+        self.create_with_hash(identifier, hash)
+    }
 
-        let index = self.children.len() - 1;
+    /// Creates a new child node with the given identifier, in stable hash mode the hash is derived from the name.
+    pub fn create_named<N: AsRef<str>>(&mut self, identifier: CastId, name: N) -> &mut Self {
+        match self.hash_mode {
+            CastHashMode::Sequential => self.create(identifier),
+            CastHashMode::Stable => {
+                let hash = self.stable_hash(identifier, name.as_ref().as_bytes());
 
-        self.children.get_mut(index).unwrap()
+                self.create_with_hash(identifier, hash)
+            }
+        }
+    }
+
+    /// Returns the hash mode used for new children of this node.
+    pub fn hash_mode(&self) -> CastHashMode {
+        self.hash_mode
     }
 
     /// Creates a new property with the given type and name.
@@ -173,6 +194,7 @@ impl CastNode {
             identifier: header.identifier,
             hash: header.node_hash,
             hash_next: Arc::new(AtomicU64::new(0)),
+            hash_mode: CastHashMode::Sequential,
             properties,
             children,
         })
@@ -202,6 +224,43 @@ impl CastNode {
         self.hash_next = hash_next;
     }
 
+    /// Appends a new child node with the given identifier and hash.
+    fn create_with_hash(&mut self, identifier: CastId, hash: u64) -> &mut Self {
+        debug_assert!(!matches!(identifier, CastId::Root));
+
+        let child = CastNode {
+            identifier,
+            hash,
+            hash_next: self.hash_next.clone(),
+            hash_mode: self.hash_mode,
+            properties: Vec::new(),
+            children: Vec::new(),
+        };
+
+        self.children.push(child);
+
+        let index = self.children.len() - 1;
+
+        self.children.get_mut(index).unwrap()
+    }
+
+    /// Derives a hash for a new child from this node's hash, the identifier, and the given key.
+    fn stable_hash(&self, identifier: CastId, key: &[u8]) -> u64 {
+        let mut buffer = Vec::with_capacity(size_of::<CastId>() + key.len());
+
+        buffer.extend_from_slice(&(identifier as u32).to_le_bytes());
+        buffer.extend_from_slice(key);
+
+        let mut hash = buffer.as_slice().hash_xxh364_with_seed(self.hash);
+
+        // Siblings that share a name still need unique hashes, so probe deterministically.
+        while hash == self.hash || self.children.iter().any(|x| x.hash == hash) {
+            hash = hash.wrapping_add(1);
+        }
+
+        hash
+    }
+
     /// Gets the length in bytes of this cast node.
     fn length(&self) -> u32 {
         let mut result = size_of::<CastNodeHeader>() as u32;
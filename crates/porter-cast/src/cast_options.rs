@@ -0,0 +1,10 @@
+use crate::CastHashMode;
+
+/// Options used when writing cast files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CastOptions {
+    /// How hashes are assigned to the nodes.
+    pub hash_mode: CastHashMode,
+    /// Whether or not the nodes are lz4 compressed.
+    pub compressed: bool,
+}
@@ -1,11 +1,15 @@
 #![deny(unsafe_code)]
 
 mod cast_file;
+mod cast_hash_mode;
 mod cast_id;
 mod cast_node;
+mod cast_options;
 mod cast_property;
 
 pub use cast_file::*;
+pub use cast_hash_mode::*;
 pub use cast_id::*;
 pub use cast_node::*;
+pub use cast_options::*;
 pub use cast_property::*;
@@ -13,8 +13,9 @@ struct VertexOutput {
 
 struct GridInput {
     @builtin(vertex_index) in_vertex_index: u32,
-    @location(0) position: vec3<f32>,
-    @location(1) color: vec3<f32>,
+    @location(0) offset: f32,
+    @location(1) extent: f32,
+    @location(2) color: vec3<f32>,
 }
 
 struct GridOutput {
@@ -22,12 +23,39 @@ struct GridOutput {
     @location(0) color: vec4<f32>,
 }
 
+struct BackgroundInput {
+    @builtin(vertex_index) in_vertex_index: u32,
+    @location(0) top: vec4<f32>,
+    @location(1) bottom: vec4<f32>,
+    @location(2) checkerboard: u32,
+}
+
+struct BackgroundOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) top: vec4<f32>,
+    @location(1) bottom: vec4<f32>,
+    @location(2) height: f32,
+    @location(3) @interpolate(flat) checkerboard: u32,
+}
+
 struct BoneInput {
+    @builtin(vertex_index) in_vertex_index: u32,
     @location(0) position: vec3<f32>,
+    @location(1) parent: vec3<f32>,
+    @location(5) physics: u32,
 }
 
 struct BoneOutput {
     @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+struct BoneAxisInput {
+    @builtin(vertex_index) in_vertex_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(2) rotation: vec4<f32>,
+    @location(3) lengths: vec3<f32>,
+    @location(4) non_uniform: u32,
 }
 
 struct ImageInput {
@@ -49,6 +77,7 @@ struct CameraUniform {
     inverse_model_matrix: mat4x4<f32>,
     default_shaded: u32,
     srgb: u32,
+    flat: u32,
 }
 
 @group(0) @binding(0)
@@ -59,6 +88,12 @@ var t_albedo: texture_2d<f32>;
 @group(1) @binding(1)
 var s_albedo: sampler;
 
+fn rotate_vector(value: vec3<f32>, rotation: vec4<f32>) -> vec3<f32> {
+    let uv = cross(rotation.xyz, value);
+    let uuv = cross(rotation.xyz, uv);
+    return value + (uv * (2.0 * rotation.w)) + (uuv * 2.0);
+}
+
 fn linear_to_srgb(linear: vec3<f32>) -> vec3<f32> {
     let less = linear * 12.92;
     let more = pow(max(linear, vec3<f32>(0.0)), vec3<f32>(1.0 / 2.4)) * 1.055 - vec3<f32>(0.055);
@@ -90,6 +125,10 @@ fn fs_main_nocull(in: VertexOutput) -> @location(0) vec4<f32> {
 }
 
 fn fs_main_full(in: VertexOutput, culling: bool) -> vec4<f32> {
+    if camera.flat == 1u {
+        return vec4<f32>(textureSample(t_albedo, s_albedo, in.uv).xyz, 1.0);
+    }
+
     let ambient_strength: f32 = 0.1;
     let ambient: vec3<f32> = ambient_strength * vec3<f32>(1.0, 1.0, 1.0);
 
@@ -115,13 +154,26 @@ fn fs_main_full(in: VertexOutput, culling: bool) -> vec4<f32> {
     }
 }
 
+@fragment
+fn fs_wireframe_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+}
+
 @vertex
 fn vs_grid_main(in: GridInput) -> GridOutput {
     let vp: mat4x4<f32> = camera.projection_matrix * camera.view_matrix;
 
+    // Each instance is a line along the z axis, and a line along the x axis.
+    var positions = array<vec3<f32>, 4>(
+        vec3<f32>(in.offset, 0.0, in.extent),
+        vec3<f32>(in.offset, 0.0, -in.extent),
+        vec3<f32>(in.extent, 0.0, in.offset),
+        vec3<f32>(-in.extent, 0.0, in.offset),
+    );
+
     var out: GridOutput;
 
-    out.position = vp * vec4<f32>(in.position, 1.0);
+    out.position = vp * vec4<f32>(positions[in.in_vertex_index], 1.0);
     out.color = vec4<f32>(in.color, 1.0);
 
     return out;
@@ -132,20 +184,89 @@ fn fs_grid_main(in: GridOutput) -> @location(0) vec4<f32> {
     return in.color;
 }
 
+@vertex
+fn vs_background_main(in: BackgroundInput) -> BackgroundOutput {
+    // A single triangle which covers the whole viewport.
+    let uv = vec2<f32>(f32((in.in_vertex_index << 1u) & 2u), f32(in.in_vertex_index & 2u));
+
+    var out: BackgroundOutput;
+
+    out.position = vec4<f32>(uv * 2.0 - 1.0, 1.0, 1.0);
+    out.top = in.top;
+    out.bottom = in.bottom;
+    out.height = uv.y;
+    out.checkerboard = in.checkerboard;
+
+    return out;
+}
+
+@fragment
+fn fs_background_main(in: BackgroundOutput) -> @location(0) vec4<f32> {
+    if in.checkerboard == 1u {
+        let cell = vec2<u32>(in.position.xy / 8.0);
+
+        return select(in.bottom, in.top, (cell.x + cell.y) % 2u == 0u);
+    }
+
+    return mix(in.bottom, in.top, clamp(in.height, 0.0, 1.0));
+}
+
 @vertex
 fn vs_bone_main(in: BoneInput) -> BoneOutput {
     let mvp: mat4x4<f32> = camera.projection_matrix * camera.view_matrix * camera.model_matrix;
 
     var out: BoneOutput;
 
-    out.position = mvp * vec4<f32>(in.position, 1.0);
+    out.position = mvp * vec4<f32>(select(in.position, in.parent, in.in_vertex_index == 1u), 1.0);
+
+    // Physics driven bones are highlighted, so jiggle and cloth bones stand out.
+    if in.physics != 0u {
+        out.color = vec4<f32>(0.608, 0.349, 0.714, 1.0);
+    } else {
+        out.color = vec4<f32>(0.153, 0.608, 0.831, 1.0);
+    }
 
     return out;
 }
 
 @fragment
 fn fs_bone_main(in: BoneOutput) -> @location(0) vec4<f32> {
-    return vec4<f32>(0.153, 0.608, 0.831, 1.0);
+    return in.color;
+}
+
+@vertex
+fn vs_bone_axis_main(in: BoneAxisInput) -> GridOutput {
+    let mvp: mat4x4<f32> = camera.projection_matrix * camera.view_matrix * camera.model_matrix;
+
+    // Each instance is three lines, one for each local axis of the bone.
+    let axis = in.in_vertex_index / 2u;
+
+    var colors = array<vec3<f32>, 3>(
+        vec3<f32>(0.906, 0.298, 0.235),
+        vec3<f32>(0.180, 0.800, 0.443),
+        vec3<f32>(0.204, 0.596, 0.859),
+    );
+
+    var direction = vec3<f32>(0.0);
+    direction[axis] = 1.0;
+
+    var position = in.position;
+
+    if in.in_vertex_index % 2u == 1u {
+        position += rotate_vector(direction, in.rotation) * in.lengths[axis];
+    }
+
+    var out: GridOutput;
+
+    out.position = mvp * vec4<f32>(position, 1.0);
+
+    if (in.non_uniform & (1u << axis)) != 0u {
+        out.color = vec4<f32>(0.953, 0.612, 0.071, 1.0);
+    } else {
+        out.color = vec4<f32>(colors[axis], 1.0);
+    }
+
+    return out;
 }
 
 @vertex
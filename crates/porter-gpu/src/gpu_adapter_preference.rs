@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The adapter used to initialize the GPU instance.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
+pub enum GPUAdapterPreference {
+    /// The default, highest performance adapter.
+    #[default]
+    HighPerformance,
+    /// The lowest power adapter, usually the integrated GPU.
+    LowPower,
+    /// A specific adapter by name, see [`crate::gpu_adapters`].
+    Named(String),
+    /// A software adapter, when available.
+    Software,
+}
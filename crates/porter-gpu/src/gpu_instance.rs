@@ -1,6 +1,8 @@
 use std::fmt::Debug;
+use std::sync::Mutex;
 use std::sync::OnceLock;
 
+use wgpu::Adapter;
 use wgpu::Backends;
 use wgpu::Device;
 use wgpu::DeviceDescriptor;
@@ -13,6 +15,8 @@ use wgpu::Queue;
 use wgpu::RequestAdapterOptionsBase;
 use wgpu::ShaderModule;
 
+use crate::GPUAdapterPreference;
+
 /// Stores an active GPU device, queue, and compiled shaders.
 #[derive(Clone)]
 pub struct GPUInstance {
@@ -67,9 +71,18 @@ impl GPUInstance {
     }
 }
 
-/// Async initialization routine required for `wgpu`.
-async fn initialize() -> GPUInstance {
-    let instance = Instance::new(&InstanceDescriptor {
+/// The features required by the converter and preview shaders.
+fn required_features() -> Features {
+    Features::TEXTURE_COMPRESSION_BC
+        | Features::TEXTURE_FORMAT_16BIT_NORM
+        | Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
+        | Features::POLYGON_MODE_LINE
+        | Features::FLOAT32_FILTERABLE
+}
+
+/// Creates the wgpu instance used to find adapters.
+fn create_instance() -> Instance {
+    Instance::new(&InstanceDescriptor {
         backends: Backends::all() & !Backends::GL,
         flags: if cfg!(debug_assertions) {
             InstanceFlags::debugging()
@@ -77,48 +90,132 @@ async fn initialize() -> GPUInstance {
             InstanceFlags::empty()
         },
         ..Default::default()
-    });
+    })
+}
 
-    let adapter = instance
-        .request_adapter(&RequestAdapterOptionsBase {
-            power_preference: PowerPreference::HighPerformance,
-            force_fallback_adapter: false,
-            compatible_surface: None,
-        })
-        .await
-        .unwrap();
-
-    let descriptor = DeviceDescriptor {
-        required_features: Features::TEXTURE_COMPRESSION_BC
-            | Features::TEXTURE_FORMAT_16BIT_NORM
-            | Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
-            | Features::POLYGON_MODE_LINE
-            | Features::FLOAT32_FILTERABLE,
-        required_limits: adapter.limits(),
-        ..Default::default()
+/// Gets a display name for the given adapter, used to select it by name.
+fn adapter_name(adapter: &Adapter) -> String {
+    let info = adapter.get_info();
+
+    format!("{} ({:?})", info.name, info.backend)
+}
+
+/// Returns the adapters to try in order for the given preference.
+async fn candidate_adapters(
+    instance: &Instance,
+    preference: &GPUAdapterPreference,
+) -> Vec<Adapter> {
+    let request = async |power_preference, force_fallback_adapter| {
+        instance
+            .request_adapter(&RequestAdapterOptionsBase {
+                power_preference,
+                force_fallback_adapter,
+                compatible_surface: None,
+            })
+            .await
+            .ok()
     };
 
-    let (device, queue) = adapter.request_device(&descriptor).await.unwrap();
+    let mut candidates = Vec::new();
 
-    let gpu_converter_shader =
-        device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_converter.wgsl"));
+    match preference {
+        GPUAdapterPreference::HighPerformance => {
+            candidates.extend(request(PowerPreference::HighPerformance, false).await);
+        }
+        GPUAdapterPreference::LowPower => {
+            candidates.extend(request(PowerPreference::LowPower, false).await);
+        }
+        GPUAdapterPreference::Named(name) => {
+            candidates.extend(
+                instance
+                    .enumerate_adapters(Backends::all() & !Backends::GL)
+                    .into_iter()
+                    .find(|adapter| adapter_name(adapter) == *name),
+            );
+        }
+        GPUAdapterPreference::Software => {
+            candidates.extend(request(PowerPreference::None, true).await);
+        }
+    }
 
-    let gpu_preview_shader =
-        device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_preview.wgsl"));
+    // Fall back to the default adapter, then to a software adapter, if the preferred one is missing or unsupported.
+    candidates.extend(request(PowerPreference::HighPerformance, false).await);
+    candidates.extend(request(PowerPreference::None, true).await);
 
-    GPUInstance::new(
-        instance,
-        device,
-        queue,
-        gpu_converter_shader,
-        gpu_preview_shader,
-    )
+    candidates
 }
 
-/// Global GPU instance, device, queue, and shaders.
-static GPU_INSTANCE: OnceLock<GPUInstance> = OnceLock::new();
+/// Async initialization routine required for `wgpu`.
+async fn initialize(preference: GPUAdapterPreference) -> Option<GPUInstance> {
+    let instance = create_instance();
+
+    for adapter in candidate_adapters(&instance, &preference).await {
+        if !adapter.features().contains(required_features()) {
+            continue;
+        }
+
+        let descriptor = DeviceDescriptor {
+            required_features: required_features(),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        };
+
+        let Ok((device, queue)) = adapter.request_device(&descriptor).await else {
+            continue;
+        };
+
+        let gpu_converter_shader =
+            device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_converter.wgsl"));
+
+        let gpu_preview_shader =
+            device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_preview.wgsl"));
+
+        return Some(GPUInstance::new(
+            instance,
+            device,
+            queue,
+            gpu_converter_shader,
+            gpu_preview_shader,
+        ));
+    }
+
+    None
+}
+
+/// Global GPU instance, device, queue, and shaders, or none if no suitable adapter exists.
+static GPU_INSTANCE: OnceLock<Option<GPUInstance>> = OnceLock::new();
+
+/// The adapter preference used when the GPU instance is initialized.
+static GPU_ADAPTER_PREFERENCE: Mutex<GPUAdapterPreference> =
+    Mutex::new(GPUAdapterPreference::HighPerformance);
+
+/// Sets the adapter preference, this must be called before the GPU instance is first used.
+pub fn set_gpu_adapter_preference(preference: GPUAdapterPreference) {
+    *GPU_ADAPTER_PREFERENCE.lock().unwrap() = preference;
+}
+
+/// Returns the names of the adapters that are available, which can be used with [`GPUAdapterPreference::Named`].
+pub fn gpu_adapters() -> Vec<String> {
+    create_instance()
+        .enumerate_adapters(Backends::all() & !Backends::GL)
+        .iter()
+        .filter(|adapter| adapter.features().contains(required_features()))
+        .map(adapter_name)
+        .collect()
+}
+
+/// Gets or initializes the current GPU instance, returns none if no suitable adapter exists.
+pub fn try_gpu_instance() -> Option<&'static GPUInstance> {
+    GPU_INSTANCE
+        .get_or_init(|| {
+            let preference = GPU_ADAPTER_PREFERENCE.lock().unwrap().clone();
+
+            pollster::block_on(initialize(preference))
+        })
+        .as_ref()
+}
 
 /// Gets or initializes the current GPU instance.
 pub fn gpu_instance() -> &'static GPUInstance {
-    GPU_INSTANCE.get_or_init(|| pollster::block_on(initialize()))
+    try_gpu_instance().expect("No suitable GPU adapter was found!")
 }
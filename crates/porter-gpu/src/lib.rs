@@ -1,5 +1,7 @@
 #![deny(unsafe_code)]
 
+mod gpu_adapter_preference;
 mod gpu_instance;
 
+pub use gpu_adapter_preference::*;
 pub use gpu_instance::*;
@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use porter_math::Axis;
+
+use porter_utils::AsPythonString;
+use porter_utils::BufferWriteExt;
+
+use crate::MaterialTextureRefUsage;
+use crate::Model;
+use crate::ModelError;
+use crate::ModelFileType;
+
+/// Shared helpers used to load and assign textures to imported materials.
+const BLENDER_SCRIPT_HELPERS: &str = r#"import os
+import bpy
+
+directory = os.path.dirname(os.path.abspath(__file__))
+
+
+def load_image(file_name):
+    path = os.path.join(directory, file_name)
+
+    if not os.path.exists(path):
+        return None
+
+    return bpy.data.images.load(path, check_existing=True)
+
+
+def assign_textures(name, textures):
+    material = bpy.data.materials.get(name)
+
+    if material is None:
+        return
+
+    material.use_nodes = True
+
+    nodes = material.node_tree.nodes
+    links = material.node_tree.links
+    shader = next((x for x in nodes if x.type == "BSDF_PRINCIPLED"), None)
+
+    if shader is None:
+        return
+
+    for (file_name, slot, non_color) in textures:
+        if slot not in shader.inputs or shader.inputs[slot].is_linked:
+            continue
+
+        image = load_image(file_name)
+
+        if image is None:
+            continue
+
+        if non_color:
+            image.colorspace_settings.name = "Non-Color"
+
+        texture = nodes.new("ShaderNodeTexImage")
+        texture.image = image
+
+        if slot == "Normal":
+            normal_map = nodes.new("ShaderNodeNormalMap")
+            links.new(texture.outputs["Color"], normal_map.inputs["Color"])
+            links.new(normal_map.outputs["Normal"], shader.inputs["Normal"])
+        else:
+            links.new(texture.outputs["Color"], shader.inputs[slot])
+
+
+def apply_scale(scale):
+    for obj in bpy.context.selected_objects:
+        if obj.parent is None:
+            obj.scale *= scale
+"#;
+
+/// The blender import command for the given file type, or `None` if blender can't import it natively.
+fn import_command(model: &Model, file_type: ModelFileType) -> Option<String> {
+    match file_type {
+        ModelFileType::Obj => {
+            let (up_axis, forward_axis) = match model.up_axis {
+                Axis::X => ("X", "Y"),
+                Axis::Y => ("Y", "NEGATIVE_Z"),
+                Axis::Z => ("Z", "Y"),
+            };
+
+            Some(format!(
+                "bpy.ops.wm.obj_import(filepath=path, up_axis=\"{up_axis}\", forward_axis=\"{forward_axis}\")"
+            ))
+        }
+        ModelFileType::Fbx => Some(String::from("bpy.ops.import_scene.fbx(filepath=path)")),
+        ModelFileType::Cast => Some(String::from("bpy.ops.import_scene.cast(filepath=path)")),
+        ModelFileType::Smd => Some(String::from("bpy.ops.import_scene.smd(filepath=path)")),
+        ModelFileType::XnaLara
+        | ModelFileType::XModelExport
+        | ModelFileType::Maya
+        | ModelFileType::Debug => None,
+    }
+}
+
+/// Writes a blender python script that imports the model saved with the first of the given file types blender can import.
+pub fn to_blender_script<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    file_types: &[ModelFileType],
+    scale: f32,
+) -> Result<(), ModelError> {
+    let path = path.as_ref();
+
+    let Some((file_type, import)) = file_types
+        .iter()
+        .find_map(|file_type| Some((*file_type, import_command(model, *file_type)?)))
+    else {
+        // Blender can't import any of these formats without third party tools.
+        return Ok(());
+    };
+
+    let file_name = path.with_extension(file_type);
+    let file_name = file_name
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+
+    let mut script = File::create(path.with_extension("py"))?.buffer_write();
+
+    writeln!(
+        script,
+        "# Exported by PorterLib\n# Please credit DTZxPorter for use of this asset!\n"
+    )?;
+
+    writeln!(script, "{BLENDER_SCRIPT_HELPERS}")?;
+
+    writeln!(
+        script,
+        "\npath = os.path.join(directory, {})\n\n{import}\napply_scale({scale:.6})\n",
+        file_name.as_python_string()
+    )?;
+
+    for material in &model.materials {
+        let mut textures = Vec::with_capacity(material.textures.len());
+
+        for texture in &material.textures {
+            if texture.is_empty() {
+                continue;
+            }
+
+            let (slot, non_color) = match texture.texture_usage {
+                MaterialTextureRefUsage::Albedo | MaterialTextureRefUsage::Diffuse => {
+                    ("Base Color", false)
+                }
+                MaterialTextureRefUsage::Normal => ("Normal", true),
+                MaterialTextureRefUsage::Roughness => ("Roughness", true),
+                MaterialTextureRefUsage::Metalness => ("Metallic", true),
+                MaterialTextureRefUsage::Emissive => ("Emission Color", false),
+                _ => continue,
+            };
+
+            textures.push(format!(
+                "({}, \"{slot}\", {})",
+                texture.file_name.as_python_string(),
+                if non_color { "True" } else { "False" }
+            ));
+        }
+
+        if textures.is_empty() {
+            continue;
+        }
+
+        writeln!(
+            script,
+            "assign_textures({}, [{}])",
+            material.name.as_python_string(),
+            textures.join(", ")
+        )?;
+    }
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ use porter_math::Vector3;
 
 use porter_utils::SanitizeExt;
 
+use crate::CustomProperties;
+
 /// Cleans a bone name.
 fn sanitize_bone_name(name: String) -> String {
     let mut name = name.replace(' ', "_").sanitized();
@@ -17,6 +19,9 @@ fn sanitize_bone_name(name: String) -> String {
     name
 }
 
+/// The relative difference between scale axes before a scale is considered non-uniform.
+const NON_UNIFORM_SCALE_TOLERANCE: f32 = 0.001;
+
 /// Represents a bone in a skeleton of a model.
 #[derive(Debug, Clone)]
 pub struct Bone {
@@ -29,6 +34,12 @@ pub struct Bone {
     pub world_position: Vector3,
     pub world_rotation: Quaternion,
     pub world_scale: Vector3,
+    /// The world space bind pose matrix, when it differs from the world transform.
+    pub bind_matrix: Option<Matrix4x4>,
+    /// Whether or not the bone is driven by physics, such as cloth or jiggle bones.
+    pub physics: bool,
+    /// Game specific properties, written to formats that support user properties.
+    pub properties: CustomProperties,
 }
 
 impl Bone {
@@ -44,6 +55,9 @@ impl Bone {
             world_position: Vector3::zero(),
             world_rotation: Quaternion::identity(),
             world_scale: Vector3::one(),
+            bind_matrix: None,
+            physics: false,
+            properties: CustomProperties::new(),
         }
     }
 
@@ -103,6 +117,30 @@ impl Bone {
         self
     }
 
+    /// Sets the world space bind pose matrix.
+    #[inline]
+    pub fn bind_matrix(mut self, matrix: Matrix4x4) -> Self {
+        self.bind_matrix = Some(matrix);
+        self
+    }
+
+    /// Sets whether or not the bone is driven by physics.
+    #[inline]
+    pub fn physics(mut self, physics: bool) -> Self {
+        self.physics = physics;
+        self
+    }
+
+    /// Whether or not the world scale differs between axes, which skews any children that inherit it.
+    pub fn has_non_uniform_scale(&self) -> bool {
+        let scale = self.world_scale;
+
+        let min = scale.x.abs().min(scale.y.abs()).min(scale.z.abs());
+        let max = scale.x.abs().max(scale.y.abs()).max(scale.z.abs());
+
+        max - min > max * NON_UNIFORM_SCALE_TOLERANCE
+    }
+
     /// Gets the local matrix (T * R * S).
     pub fn local_matrix(&self) -> Matrix4x4 {
         Matrix4x4::create_position(self.local_position)
@@ -116,4 +154,14 @@ impl Bone {
             * Matrix4x4::create_rotation(self.world_rotation)
             * Matrix4x4::create_scale(self.world_scale)
     }
+
+    /// Gets the world space bind pose matrix, which defaults to the world matrix.
+    pub fn bind_pose_matrix(&self) -> Matrix4x4 {
+        self.bind_matrix.unwrap_or_else(|| self.world_matrix())
+    }
+
+    /// Gets the inverse bind matrix, which moves a vertex from model space into bone space.
+    pub fn inverse_bind_matrix(&self) -> Matrix4x4 {
+        self.bind_pose_matrix().inverse()
+    }
 }
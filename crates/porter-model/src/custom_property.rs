@@ -0,0 +1,124 @@
+use porter_math::Vector3;
+
+/// A value for a custom property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomPropertyValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Vector3(Vector3),
+}
+
+/// A collection of game specific key/value properties, which are written to formats that support user properties.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomProperties {
+    properties: Vec<(String, CustomPropertyValue)>,
+}
+
+impl CustomProperties {
+    /// Constructs a new empty collection of properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the property with the given key, replacing the previous value.
+    pub fn set<K: Into<String>, V: Into<CustomPropertyValue>>(&mut self, key: K, value: V) {
+        let key = key.into();
+        let value = value.into();
+
+        match self.properties.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.properties.push((key, value)),
+        }
+    }
+
+    /// Gets the property with the given key.
+    pub fn get<K: AsRef<str>>(&self, key: K) -> Option<&CustomPropertyValue> {
+        self.properties
+            .iter()
+            .find(|(name, _)| name == key.as_ref())
+            .map(|(_, value)| value)
+    }
+
+    /// Removes the property with the given key, returning its value.
+    pub fn remove<K: AsRef<str>>(&mut self, key: K) -> Option<CustomPropertyValue> {
+        let index = self
+            .properties
+            .iter()
+            .position(|(name, _)| name == key.as_ref())?;
+
+        Some(self.properties.remove(index).1)
+    }
+
+    /// The number of properties.
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Whether or not there are no properties.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// Iterates over each property, in the order they were first set.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &CustomPropertyValue)> {
+        self.properties
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl From<bool> for CustomPropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for CustomPropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value as i64)
+    }
+}
+
+impl From<u32> for CustomPropertyValue {
+    fn from(value: u32) -> Self {
+        Self::Integer(value as i64)
+    }
+}
+
+impl From<i64> for CustomPropertyValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f32> for CustomPropertyValue {
+    fn from(value: f32) -> Self {
+        Self::Float(value as f64)
+    }
+}
+
+impl From<f64> for CustomPropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<String> for CustomPropertyValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for CustomPropertyValue {
+    fn from(value: &str) -> Self {
+        Self::String(String::from(value))
+    }
+}
+
+impl From<Vector3> for CustomPropertyValue {
+    fn from(value: Vector3) -> Self {
+        Self::Vector3(value)
+    }
+}
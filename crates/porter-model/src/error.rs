@@ -1,8 +1,17 @@
+use porter_utils::ReportError;
+
+use crate::VertexChannel;
+use crate::WeightBoneId;
+
 /// Errors that can occur in the model crate.
 #[derive(Debug)]
 pub enum ModelError {
     IoError(std::io::Error),
     TryReserveError(std::collections::TryReserveError),
+    MissingVertexChannel(VertexChannel),
+    InvalidVertexChannelLength(VertexChannel, usize),
+    InvalidVertexChannelValue(VertexChannel, usize),
+    InvalidVertexWeightBone(usize, WeightBoneId),
 }
 
 impl From<std::io::Error> for ModelError {
@@ -16,3 +25,35 @@ impl From<std::collections::TryReserveError> for ModelError {
         Self::TryReserveError(value)
     }
 }
+
+impl ReportError for ModelError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::IoError(e) => e.error_code(),
+            Self::TryReserveError(e) => e.error_code(),
+            Self::MissingVertexChannel(_) => "MDL-001",
+            Self::InvalidVertexChannelLength(_, _) => "MDL-002",
+            Self::InvalidVertexChannelValue(_, _) => "MDL-003",
+            Self::InvalidVertexWeightBone(_, _) => "MDL-004",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::IoError(e) => e.error_message(),
+            Self::TryReserveError(e) => e.error_message(),
+            Self::MissingVertexChannel(channel) => {
+                format!("The model is missing its {channel:?} vertex data.")
+            }
+            Self::InvalidVertexChannelLength(channel, _) => {
+                format!("The model's {channel:?} vertex data has the wrong length.")
+            }
+            Self::InvalidVertexChannelValue(channel, index) => {
+                format!("The model's {channel:?} vertex data is invalid at vertex {index}.")
+            }
+            Self::InvalidVertexWeightBone(index, bone) => {
+                format!("Vertex {index} is weighted to bone {bone}, which doesn't exist.")
+            }
+        }
+    }
+}
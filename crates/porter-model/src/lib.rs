@@ -2,6 +2,7 @@ mod aabb;
 mod blend_shape;
 mod bone;
 mod constraint;
+mod custom_property;
 mod error;
 mod face_buffer;
 mod face_buffer_ext;
@@ -10,19 +11,28 @@ mod ik_handle;
 mod material;
 mod material_remap;
 mod mesh;
+mod mesh_material_range;
+mod mesh_naming;
 mod model;
 mod model_file_type;
+mod model_info;
+mod model_save_options;
 mod skeleton;
 mod skinning_method;
+mod socket;
+mod uv_layer;
 mod vertex;
 mod vertex_buffer;
+mod vertex_channel;
 mod vertex_color;
 mod vertex_weight;
+mod weight_normalization;
 
 pub use aabb::*;
 pub use blend_shape::*;
 pub use bone::*;
 pub use constraint::*;
+pub use custom_property::*;
 pub use error::*;
 pub use face_buffer::*;
 pub use face_buffer_ext::*;
@@ -31,16 +41,26 @@ pub use ik_handle::*;
 pub use material::*;
 pub use material_remap::*;
 pub use mesh::*;
+pub use mesh_material_range::*;
+pub use mesh_naming::*;
 pub use model::*;
 pub use model_file_type::*;
+pub use model_info::*;
+pub use model_save_options::*;
 pub use skeleton::*;
 pub use skinning_method::*;
+pub use socket::*;
+pub use uv_layer::*;
 pub use vertex::*;
 pub use vertex_buffer::*;
+pub use vertex_channel::*;
 pub use vertex_color::*;
 pub use vertex_weight::*;
+pub use weight_normalization::*;
 
+pub(crate) mod blender_script;
 pub(crate) mod model_file_type_cast;
+pub(crate) mod model_file_type_debug;
 pub(crate) mod model_file_type_fbx;
 pub(crate) mod model_file_type_maya;
 pub(crate) mod model_file_type_obj;
@@ -9,13 +9,18 @@ use porter_utils::VecExt;
 
 use crate::Aabb;
 use crate::BlendShape;
+use crate::CustomProperties;
 use crate::Face;
 use crate::FaceBuffer;
+use crate::MeshMaterialRange;
 use crate::ModelError;
 use crate::Skeleton;
 use crate::SkinningMethod;
+use crate::UvLayer;
+use crate::UvLayerSemantic;
 use crate::VertexBuffer;
 use crate::WeightBoneId;
+use crate::WeightNormalization;
 
 /// A polygon mesh for a model.
 #[derive(Debug, Clone)]
@@ -28,10 +33,16 @@ pub struct Mesh {
     pub vertices: VertexBuffer,
     /// The material index for this mesh.
     pub material: Option<usize>,
+    /// Ranges of faces that use a different material than the mesh, later ranges take priority.
+    pub material_ranges: Vec<MeshMaterialRange>,
+    /// The name and semantic of each uv layer, by index, layers without an entry are unnamed.
+    pub uv_layers: Vec<UvLayer>,
     /// A collection of blend shapes that go with this mesh.
     pub blend_shapes: Vec<BlendShape>,
     /// The method used to skin this mesh.
     pub skinning_method: SkinningMethod,
+    /// Game specific properties, written to formats that support user properties.
+    pub properties: CustomProperties,
 }
 
 impl Mesh {
@@ -40,10 +51,13 @@ impl Mesh {
         Self {
             name: None,
             material: None,
+            material_ranges: Vec::new(),
             faces,
             vertices,
+            uv_layers: Vec::new(),
             blend_shapes: Vec::new(),
             skinning_method: SkinningMethod::Linear,
+            properties: CustomProperties::new(),
         }
     }
 
@@ -56,10 +70,13 @@ impl Mesh {
         Self {
             name: None,
             material: None,
+            material_ranges: Vec::new(),
             faces,
             vertices,
+            uv_layers: Vec::new(),
             blend_shapes: Vec::new(),
             skinning_method,
+            properties: CustomProperties::new(),
         }
     }
 
@@ -136,6 +153,96 @@ impl Mesh {
         self
     }
 
+    /// Adds a range of faces that use the given material.
+    pub fn material_range(mut self, material: usize, face_start: usize, length: usize) -> Self {
+        self.material_ranges
+            .push(MeshMaterialRange::new(material, face_start, length));
+        self
+    }
+
+    /// Adds the name and semantic for the next uv layer.
+    pub fn uv_layer(mut self, layer: UvLayer) -> Self {
+        self.uv_layers.push(layer);
+        self
+    }
+
+    /// Gets the name of the given uv layer, or a default name based on the index.
+    pub fn uv_layer_name(&self, index: usize) -> String {
+        self.uv_layers
+            .get(index)
+            .and_then(|layer| layer.name.clone())
+            .unwrap_or_else(|| format!("map{}", index + 1))
+    }
+
+    /// Gets the semantic of the given uv layer.
+    pub fn uv_layer_semantic(&self, index: usize) -> UvLayerSemantic {
+        self.uv_layers
+            .get(index)
+            .map(|layer| layer.semantic)
+            .unwrap_or_default()
+    }
+
+    /// Finds the first uv layer with the given semantic.
+    pub fn find_uv_layer(&self, semantic: UvLayerSemantic) -> Option<usize> {
+        (0..self.vertices.uv_layers()).find(|index| self.uv_layer_semantic(*index) == semantic)
+    }
+
+    /// Gets the material index used by the given face.
+    pub fn face_material(&self, face: usize) -> Option<usize> {
+        self.material_ranges
+            .iter()
+            .rev()
+            .find(|range| range.contains(face))
+            .map(|range| range.material)
+            .or(self.material)
+    }
+
+    /// Splits the mesh into one mesh per material used by its faces.
+    pub fn split_material_ranges(&self) -> Vec<Mesh> {
+        if self.material_ranges.is_empty() {
+            return vec![self.clone()];
+        }
+
+        let mut materials: Vec<(Option<usize>, Vec<usize>)> = Vec::new();
+
+        for face in 0..self.faces.len() {
+            let material = self.face_material(face);
+
+            match materials.iter_mut().find(|(used, _)| *used == material) {
+                Some((_, faces)) => faces.push(face),
+                None => materials.push((material, vec![face])),
+            }
+        }
+
+        materials
+            .into_iter()
+            .map(|(material, faces)| self.extract_faces(material, &faces))
+            .collect()
+    }
+
+    /// Calculates the bounding box for this mesh.
+    pub fn bounding_box(&self) -> Aabb {
+        let mut min = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+        let mut max = Vector3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for i in 0..self.vertices.len() {
+            let position = self.vertices.vertex(i).position();
+
+            min = Vector3::new(
+                min.x.min(position.x),
+                min.y.min(position.y),
+                min.z.min(position.z),
+            );
+            max = Vector3::new(
+                max.x.max(position.x),
+                max.y.max(position.y),
+                max.z.max(position.z),
+            );
+        }
+
+        Aabb::new(min, max)
+    }
+
     /// Scales the mesh by the given factor.
     pub fn scale(&mut self, factor: f32) {
         for i in 0..self.vertices.len() {
@@ -171,6 +278,11 @@ impl Mesh {
         }
     }
 
+    /// Normalizes the vertex weights so that they sum to one.
+    pub fn normalize_weights(&mut self, normalization: WeightNormalization) {
+        self.vertices.normalize_weights(normalization);
+    }
+
     /// Generates vertex normals by averaging the face normals.
     pub fn generate_vertex_normals(&mut self) -> Result<(), ModelError> {
         let mut normals: Vec<Vector3> =
@@ -205,6 +317,8 @@ impl Mesh {
             self.vertices.vertex_mut(i).set_normal(normal);
         }
 
+        self.vertices.set_has_normals(true);
+
         Ok(())
     }
 
@@ -246,6 +360,72 @@ impl Mesh {
         }
     }
 
+    /// Creates a new mesh from the given faces, with only the vertices they use.
+    fn extract_faces(&self, material: Option<usize>, faces: &[usize]) -> Mesh {
+        let mut mesh = Mesh::with_skinning_method(
+            FaceBuffer::with_capacity(faces.len()),
+            VertexBuffer::builder()
+                .colors(self.vertices.colors())
+                .uv_layers(self.vertices.uv_layers())
+                .maximum_influence(self.vertices.maximum_influence())
+                .has_normals(self.vertices.has_normals())
+                .build(),
+            self.skinning_method,
+        );
+
+        mesh.name = self.name.clone();
+        mesh.material = material;
+        mesh.uv_layers = self.uv_layers.clone();
+        mesh.properties = self.properties.clone();
+
+        let mut blend_shapes: Vec<BlendShape> = self
+            .blend_shapes
+            .iter()
+            .map(|blend_shape| {
+                BlendShape::new(blend_shape.name.clone()).target_scale(blend_shape.target_scale)
+            })
+            .collect();
+
+        let mut vertex_remap: Vec<u32> = vec![u32::MAX; self.vertices.len()];
+
+        for face in faces {
+            let face = self.faces[*face];
+
+            let mut remap_index = |index: u32| {
+                let remap = &mut vertex_remap[index as usize];
+
+                if *remap == u32::MAX {
+                    *remap = mesh.vertices.len() as u32;
+
+                    mesh.vertices
+                        .create()
+                        .copy_from(&self.vertices.vertex(index as usize));
+
+                    for (blend_shape, new_shape) in
+                        self.blend_shapes.iter().zip(blend_shapes.iter_mut())
+                    {
+                        if let Some(delta) = blend_shape.vertex_deltas.get(&index) {
+                            new_shape.vertex_deltas.insert(*remap, *delta);
+                        }
+                    }
+                }
+
+                *remap
+            };
+
+            let i1 = remap_index(face.i1);
+            let i2 = remap_index(face.i2);
+            let i3 = remap_index(face.i3);
+
+            mesh.faces.push(Face::new(i1, i2, i3));
+        }
+
+        blend_shapes.retain(|blend_shape| !blend_shape.vertex_deltas.is_empty());
+
+        mesh.blend_shapes = blend_shapes;
+        mesh
+    }
+
     /// Validates the mesh has some form of valid data.
     #[cfg(debug_assertions)]
     pub fn validate(&self, bone_count: usize) {
@@ -316,6 +496,15 @@ impl Mesh {
             );
         }
 
+        for range in &self.material_ranges {
+            if range.face_start + range.length > self.faces.len() {
+                println!(
+                    "Validate Error: Found material range outside of faces: {}, {} [{}]",
+                    range.face_start, range.length, range.material
+                );
+            }
+        }
+
         for blend_shape in &self.blend_shapes {
             blend_shape.validate(self.vertices.len());
         }
@@ -0,0 +1,26 @@
+/// A range of faces in a mesh that use a different material than the mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeshMaterialRange {
+    /// The material index for this range of faces.
+    pub material: usize,
+    /// The index of the first face in this range.
+    pub face_start: usize,
+    /// The number of faces in this range.
+    pub length: usize,
+}
+
+impl MeshMaterialRange {
+    /// Constructs a new mesh material range.
+    pub const fn new(material: usize, face_start: usize, length: usize) -> Self {
+        Self {
+            material,
+            face_start,
+            length,
+        }
+    }
+
+    /// Whether or not the given face index is in this range.
+    pub const fn contains(&self, face: usize) -> bool {
+        face >= self.face_start && face < self.face_start + self.length
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::Model;
+
+/// How exported meshes are named by model writers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeshNaming {
+    /// Each writer names meshes by their index, in the way the format expects.
+    #[default]
+    Index,
+    /// Meshes keep their names, unnamed meshes are named by their material, such as `mesh_<material>_N`.
+    Material,
+}
+
+impl MeshNaming {
+    /// Gets the name of each mesh in the model, none when the writer should use its own name.
+    ///
+    /// Names are made unique, so meshes split from the same source mesh don't collide.
+    pub fn mesh_names(&self, model: &Model) -> Vec<Option<String>> {
+        if matches!(self, Self::Index) {
+            return vec![None; model.meshes.len()];
+        }
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let mut names = Vec::with_capacity(model.meshes.len());
+
+        for mesh in &model.meshes {
+            let name = match &mesh.name {
+                Some(name) => {
+                    let count = counts.entry(name.clone()).or_default();
+                    let name = if *count > 0 {
+                        format!("{name}_{count}")
+                    } else {
+                        name.clone()
+                    };
+
+                    *count += 1;
+
+                    name
+                }
+                None => {
+                    let material = mesh
+                        .material
+                        .and_then(|material| model.materials.get(material))
+                        .map(|material| material.name.as_str())
+                        .unwrap_or("default_material");
+
+                    let base = format!("mesh_{material}");
+                    let count = counts.entry(base.clone()).or_default();
+                    let name = format!("{base}_{count}");
+
+                    *count += 1;
+
+                    name
+                }
+            };
+
+            names.push(Some(name));
+        }
+
+        names
+    }
+}
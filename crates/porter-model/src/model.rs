@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
@@ -5,6 +6,7 @@ use std::path::Path;
 
 use porter_math::Axis;
 use porter_math::Matrix4x4;
+use porter_math::Quaternion;
 use porter_math::Vector3;
 
 use crate::Aabb;
@@ -19,10 +21,15 @@ use crate::MaterialTextureRef;
 use crate::Mesh;
 use crate::ModelError;
 use crate::ModelFileType;
+use crate::ModelInfo;
+use crate::ModelSaveOptions;
 use crate::Skeleton;
 use crate::VertexBuffer;
 use crate::WeightBoneId;
+use crate::WeightNormalization;
+use crate::blender_script;
 use crate::model_file_type_cast;
+use crate::model_file_type_debug;
 use crate::model_file_type_fbx;
 use crate::model_file_type_maya;
 use crate::model_file_type_obj;
@@ -78,6 +85,18 @@ impl Model {
         self.meshes.iter().map(|x| x.faces.len()).sum()
     }
 
+    /// Returns the metadata of this model, as a single level of detail.
+    pub fn info(&self) -> ModelInfo {
+        ModelInfo {
+            bones: self.skeleton.bones.len() as u32,
+            meshes: self.meshes.len() as u32,
+            vertices: self.vertex_count() as u32,
+            faces: self.face_count() as u32,
+            lods: 1,
+            materials: self.materials.len() as u32,
+        }
+    }
+
     /// Scales the model by the given factor.
     pub fn scale(&mut self, factor: f32) {
         for mesh in &mut self.meshes {
@@ -104,6 +123,13 @@ impl Model {
         self.skeleton.transform(matrix);
     }
 
+    /// Normalizes the vertex weights of every mesh so that they sum to one.
+    pub fn normalize_weights(&mut self, normalization: WeightNormalization) {
+        for mesh in &mut self.meshes {
+            mesh.normalize_weights(normalization);
+        }
+    }
+
     /// Applies a different bind pose to the model meshes.
     pub fn apply_bind_pose(&mut self, inv_bind_poses: &BTreeMap<WeightBoneId, Matrix4x4>) {
         for mesh in &mut self.meshes {
@@ -111,6 +137,43 @@ impl Model {
         }
     }
 
+    /// Poses the skeleton with the local bone transforms returned for each named bone, and deforms the meshes to match.
+    ///
+    /// The posed skeleton becomes the new bind pose, so the result can be exported as a static model.
+    pub fn pose<F>(&mut self, mut pose: F)
+    where
+        F: FnMut(&str) -> (Option<Vector3>, Option<Quaternion>),
+    {
+        let inv_bind_poses: BTreeMap<WeightBoneId, Matrix4x4> = self
+            .skeleton
+            .inverse_bind_matrices()
+            .into_iter()
+            .enumerate()
+            .map(|(index, matrix)| (index as WeightBoneId, matrix))
+            .collect();
+
+        for bone in &mut self.skeleton.bones {
+            bone.bind_matrix = None;
+
+            let Some(name) = &bone.name else {
+                continue;
+            };
+
+            let (position, rotation) = pose(name);
+
+            if let Some(position) = position {
+                bone.local_position = position;
+            }
+
+            if let Some(rotation) = rotation {
+                bone.local_rotation = rotation;
+            }
+        }
+
+        self.skeleton.generate_world_transforms();
+        self.apply_bind_pose(&inv_bind_poses);
+    }
+
     /// Remaps the model's meshes by their materials and vertices.
     pub fn remap_meshes_by_vertices<R: AsRef<[MaterialRemapVertices]>>(&mut self, remaps: R) {
         let remaps = remaps.as_ref();
@@ -157,17 +220,21 @@ impl Model {
                         old_mesh.vertices.maximum_influence(),
                     ))
                     .or_insert_with(|| {
-                        let mesh = Mesh::with_skinning_method(
+                        let mut mesh = Mesh::with_skinning_method(
                             FaceBuffer::new(),
                             VertexBuffer::builder()
                                 .colors(old_mesh.vertices.colors())
                                 .uv_layers(old_mesh.vertices.uv_layers())
                                 .maximum_influence(old_mesh.vertices.maximum_influence())
+                                .has_normals(old_mesh.vertices.has_normals())
                                 .build(),
                             old_mesh.skinning_method,
                         )
                         .name(old_mesh.name.clone());
 
+                        mesh.uv_layers = old_mesh.uv_layers.clone();
+                        mesh.properties = old_mesh.properties.clone();
+
                         (mesh, HashMap::with_capacity(old_mesh.blend_shapes.len()))
                     });
 
@@ -293,17 +360,21 @@ impl Model {
                         old_mesh.vertices.maximum_influence(),
                     ))
                     .or_insert_with(|| {
-                        let mesh = Mesh::with_skinning_method(
+                        let mut mesh = Mesh::with_skinning_method(
                             FaceBuffer::new(),
                             VertexBuffer::builder()
                                 .colors(old_mesh.vertices.colors())
                                 .uv_layers(old_mesh.vertices.uv_layers())
                                 .maximum_influence(old_mesh.vertices.maximum_influence())
+                                .has_normals(old_mesh.vertices.has_normals())
                                 .build(),
                             old_mesh.skinning_method,
                         )
                         .name(old_mesh.name.clone());
 
+                        mesh.uv_layers = old_mesh.uv_layers.clone();
+                        mesh.properties = old_mesh.properties.clone();
+
                         (mesh, HashMap::with_capacity(old_mesh.blend_shapes.len()))
                     });
 
@@ -366,6 +437,165 @@ impl Model {
         }
     }
 
+    /// Generates vertex normals for meshes that don't have source normals, for formats and previews that need them.
+    pub fn generate_missing_normals(&self) -> Result<Cow<'_, Model>, ModelError> {
+        if self.meshes.iter().all(|mesh| mesh.vertices.has_normals()) {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        let mut model = self.clone();
+
+        for mesh in &mut model.meshes {
+            if !mesh.vertices.has_normals() {
+                mesh.generate_vertex_normals()?;
+            }
+        }
+
+        Ok(Cow::Owned(model))
+    }
+
+    /// Splits meshes with material ranges into one mesh per material, for formats that only support one material per mesh.
+    pub fn split_material_ranges(&self) -> Cow<'_, Model> {
+        if self
+            .meshes
+            .iter()
+            .all(|mesh| mesh.material_ranges.is_empty())
+        {
+            return Cow::Borrowed(self);
+        }
+
+        let mut model = self.clone();
+
+        model.meshes = self
+            .meshes
+            .iter()
+            .flat_map(|mesh| mesh.split_material_ranges())
+            .collect();
+
+        Cow::Owned(model)
+    }
+
+    /// Removes the meshes that aren't visible in the given mask, meshes past the end of the mask are kept.
+    pub fn retain_visible_meshes(&mut self, visible: &[bool]) {
+        let mut index = 0;
+
+        self.meshes.retain(|_| {
+            let keep = visible.get(index).copied().unwrap_or(true);
+
+            index += 1;
+
+            keep
+        });
+    }
+
+    /// Merges another model into this one, as is, without offsetting it.
+    ///
+    /// Bones which share a name with an existing bone are merged into it, and the rest are appended.
+    /// The meshes, hairs, and materials are appended, and remapped to the merged bones and materials.
+    pub fn merge(&mut self, other: Model) {
+        let mut names: HashMap<String, usize> = HashMap::with_capacity(self.skeleton.bones.len());
+
+        for (index, bone) in self.skeleton.bones.iter().enumerate() {
+            if let Some(name) = &bone.name {
+                names.entry(name.clone()).or_insert(index);
+            }
+        }
+
+        let mut bone_map: Vec<usize> = Vec::with_capacity(other.skeleton.bones.len());
+        let bone_offset = self.skeleton.bones.len();
+        let mut appended = 0;
+
+        for bone in &other.skeleton.bones {
+            match bone.name.as_ref().and_then(|name| names.get(name)) {
+                Some(index) => bone_map.push(*index),
+                None => {
+                    bone_map.push(bone_offset + appended);
+                    appended += 1;
+                }
+            }
+        }
+
+        for (index, mut bone) in other.skeleton.bones.into_iter().enumerate() {
+            if bone_map[index] < bone_offset {
+                continue;
+            }
+
+            if bone.parent > -1 {
+                bone.parent = bone_map
+                    .get(bone.parent as usize)
+                    .map(|parent| *parent as i32)
+                    .unwrap_or(-1);
+            }
+
+            self.skeleton.bones.push(bone);
+        }
+
+        let remap_bone = |bone: usize| bone_map.get(bone).copied().unwrap_or(bone);
+
+        for mut ik_handle in other.skeleton.ik_handles {
+            ik_handle.start_bone = remap_bone(ik_handle.start_bone);
+            ik_handle.end_bone = remap_bone(ik_handle.end_bone);
+            ik_handle.target_bone = ik_handle.target_bone.map(remap_bone);
+            ik_handle.pole_vector_bone = ik_handle.pole_vector_bone.map(remap_bone);
+            ik_handle.pole_bone = ik_handle.pole_bone.map(remap_bone);
+
+            self.skeleton.ik_handles.push(ik_handle);
+        }
+
+        for mut constraint in other.skeleton.constraints {
+            constraint.constraint_bone = remap_bone(constraint.constraint_bone);
+            constraint.target_bone = remap_bone(constraint.target_bone);
+
+            self.skeleton.constraints.push(constraint);
+        }
+
+        for mut socket in other.skeleton.sockets {
+            if socket.parent > -1 {
+                socket.parent = remap_bone(socket.parent as usize) as i32;
+            }
+
+            self.skeleton.sockets.push(socket);
+        }
+
+        let material_offset = self.materials.len();
+        let remap_weights = bone_map
+            .iter()
+            .enumerate()
+            .any(|(index, bone)| index != *bone);
+
+        for mut mesh in other.meshes {
+            mesh.material = mesh.material.map(|material| material + material_offset);
+
+            for range in &mut mesh.material_ranges {
+                range.material += material_offset;
+            }
+
+            if remap_weights {
+                let influence = mesh.vertices.maximum_influence();
+
+                for v in 0..mesh.vertices.len() {
+                    let mut vertex = mesh.vertices.vertex_mut(v);
+
+                    for i in 0..influence {
+                        let bone = vertex.weight(i).bone;
+
+                        vertex.set_weight_bone(i, remap_bone(bone as usize) as WeightBoneId);
+                    }
+                }
+            }
+
+            self.meshes.push(mesh);
+        }
+
+        for mut hair in other.hairs {
+            hair.material = hair.material.map(|material| material + material_offset);
+
+            self.hairs.push(hair);
+        }
+
+        self.materials.extend(other.materials);
+    }
+
     /// Gets the base texture for each material in this model.
     pub fn material_textures(&self) -> Vec<Option<MaterialTextureRef>> {
         let mut result = Vec::with_capacity(self.materials.len());
@@ -388,16 +618,14 @@ impl Model {
         let mut max_z = f32::NEG_INFINITY;
 
         for mesh in &self.meshes {
-            for i in 0..mesh.vertices.len() {
-                let position = mesh.vertices.vertex(i).position();
-
-                min_x = min_x.min(position.x);
-                min_y = min_y.min(position.y);
-                min_z = min_z.min(position.z);
-                max_x = max_x.max(position.x);
-                max_y = max_y.max(position.y);
-                max_z = max_z.max(position.z);
-            }
+            let bounds = mesh.bounding_box();
+
+            min_x = min_x.min(bounds.min.x);
+            min_y = min_y.min(bounds.min.y);
+            min_z = min_z.min(bounds.min.z);
+            max_x = max_x.max(bounds.max.x);
+            max_y = max_y.max(bounds.max.y);
+            max_z = max_z.max(bounds.max.z);
         }
 
         Aabb::new(
@@ -412,19 +640,54 @@ impl Model {
         path: P,
         file_type: ModelFileType,
     ) -> Result<(), ModelError> {
+        self.save_with_options(path, file_type, ModelSaveOptions::default())
+    }
+
+    /// Saves the model to the given file path in the given model format, using the given options.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ModelFileType,
+        options: ModelSaveOptions,
+    ) -> Result<(), ModelError> {
+        let model = self.generate_missing_normals()?;
+        let naming = options.mesh_naming;
+
         match file_type {
-            ModelFileType::Obj => model_file_type_obj::to_obj(path, self),
-            ModelFileType::Smd => model_file_type_smd::to_smd(path, self),
-            ModelFileType::XnaLara => model_file_type_xna_lara::to_xna_lara(path, self),
-            ModelFileType::XModelExport => {
-                model_file_type_xmodel_export::to_xmodel_export(path, self)
+            ModelFileType::Obj => model_file_type_obj::to_obj(path, &model, naming),
+            ModelFileType::Smd => model_file_type_smd::to_smd(path, &model),
+            ModelFileType::XnaLara => {
+                model_file_type_xna_lara::to_xna_lara(path, &model.split_material_ranges(), naming)
+            }
+            ModelFileType::XModelExport => model_file_type_xmodel_export::to_xmodel_export(
+                path,
+                &model.split_material_ranges(),
+                naming,
+            ),
+            ModelFileType::Cast => {
+                model_file_type_cast::to_cast(path, &model.split_material_ranges(), options)
+            }
+            ModelFileType::Fbx => {
+                model_file_type_fbx::to_fbx(path, &model.split_material_ranges(), naming)
             }
-            ModelFileType::Cast => model_file_type_cast::to_cast(path, self),
-            ModelFileType::Fbx => model_file_type_fbx::to_fbx(path, self),
-            ModelFileType::Maya => model_file_type_maya::to_maya(path, self),
+            ModelFileType::Maya => {
+                model_file_type_maya::to_maya(path, &model.split_material_ranges(), naming)
+            }
+            ModelFileType::Debug => model_file_type_debug::to_debug(path, &model),
         }
     }
 
+    /// Saves a blender script next to the given file path, which imports the model saved in the given formats.
+    /// Only the first format blender can import natively is used, no script is written if there are none.
+    pub fn save_blender_script<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_types: &[ModelFileType],
+        scale: f32,
+    ) -> Result<(), ModelError> {
+        blender_script::to_blender_script(path, self, file_types, scale)
+    }
+
     /// Validates the model has some form of valid data.
     #[cfg(debug_assertions)]
     pub fn validate(&self) {
@@ -13,6 +13,7 @@ pub enum ModelFileType {
     Cast,
     Maya,
     Fbx,
+    Debug,
 }
 
 impl AsRef<OsStr> for ModelFileType {
@@ -25,6 +26,7 @@ impl AsRef<OsStr> for ModelFileType {
             Self::Cast => OsStr::new("cast"),
             Self::Maya => OsStr::new("ma"),
             Self::Fbx => OsStr::new("fbx"),
+            Self::Debug => OsStr::new("debug.txt"),
         }
     }
 }
@@ -16,16 +16,55 @@ use porter_utils::BufferWriteExt;
 
 use crate::ConstraintOffset;
 use crate::ConstraintType;
+use crate::CustomProperties;
+use crate::CustomPropertyValue;
 use crate::MaterialParameterType;
 use crate::MaterialParameterValue;
 use crate::MaterialTextureRefUsage;
 use crate::Model;
 use crate::ModelError;
+use crate::ModelSaveOptions;
 use crate::SkinningMethod;
 
+/// Utility to write custom properties to a node, skipping any which would replace a known property.
+fn write_custom_properties(node: &mut CastNode, properties: &CustomProperties) {
+    for (name, value) in properties.iter() {
+        if node.property(name).is_some() {
+            continue;
+        }
+
+        match value {
+            CustomPropertyValue::Bool(value) => {
+                node.create_property(CastPropertyId::Byte, name)
+                    .push(*value);
+            }
+            CustomPropertyValue::Integer(value) => {
+                node.create_property(CastPropertyId::Integer64, name)
+                    .push(*value as u64);
+            }
+            CustomPropertyValue::Float(value) => {
+                node.create_property(CastPropertyId::Double, name)
+                    .push(*value);
+            }
+            CustomPropertyValue::String(value) => {
+                node.create_property(CastPropertyId::String, name)
+                    .push(value.as_str());
+            }
+            CustomPropertyValue::Vector3(value) => {
+                node.create_property(CastPropertyId::Vector3, name)
+                    .push(*value);
+            }
+        }
+    }
+}
+
 /// Writes a model in cast format to the given path.
-pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
-    let mut root = CastNode::root();
+pub fn to_cast<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    options: ModelSaveOptions,
+) -> Result<(), ModelError> {
+    let mut root = CastNode::root_with_hash_mode(options.cast.hash_mode);
 
     let meta_node = root.create(CastId::Metadata);
 
@@ -49,20 +88,23 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     let model_node = root.create(CastId::Model);
 
-    if !model.skeleton.bones.is_empty() {
+    if !model.skeleton.bones.is_empty() || !model.skeleton.sockets.is_empty() {
         let skeleton_node = model_node.create(CastId::Skeleton);
 
         let mut bone_map: HashMap<usize, CastPropertyValue> =
             HashMap::with_capacity(model.skeleton.bones.len());
 
         for (bone_index, bone) in model.skeleton.bones.iter().enumerate() {
-            let bone_node = skeleton_node.create(CastId::Bone);
+            let bone_name = bone
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("porter_bone_{bone_index}"));
 
-            bone_node.create_property(CastPropertyId::String, "n").push(
-                bone.name
-                    .as_deref()
-                    .unwrap_or(&format!("porter_bone_{bone_index}")),
-            );
+            let bone_node = skeleton_node.create_named(CastId::Bone, &bone_name);
+
+            bone_node
+                .create_property(CastPropertyId::String, "n")
+                .push(bone_name.as_str());
 
             bone_node
                 .create_property(CastPropertyId::Integer32, "p")
@@ -90,6 +132,14 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 .create_property(CastPropertyId::Vector3, "s")
                 .push(bone.local_scale);
 
+            if bone.physics {
+                bone_node
+                    .create_property(CastPropertyId::Byte, "phy")
+                    .push(true);
+            }
+
+            write_custom_properties(bone_node, &bone.properties);
+
             bone_map.insert(bone_index, CastPropertyValue::from(bone_node));
         }
 
@@ -199,13 +249,43 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 .create_property(CastPropertyId::Byte, "sz")
                 .push(constraint.skip_z as u8);
         }
+
+        // Sockets are written as bones after the skeleton, so they don't change the weight bone indices.
+        for socket in &model.skeleton.sockets {
+            let (world_position, world_rotation, _) =
+                model.skeleton.socket_world_matrix(socket).decompose();
+
+            let bone_node = skeleton_node.create_named(CastId::Bone, &socket.name);
+
+            bone_node
+                .create_property(CastPropertyId::String, "n")
+                .push(socket.name.as_str());
+
+            bone_node
+                .create_property(CastPropertyId::Integer32, "p")
+                .push(socket.parent as u32);
+
+            bone_node
+                .create_property(CastPropertyId::Vector3, "lp")
+                .push(socket.local_position);
+            bone_node
+                .create_property(CastPropertyId::Vector4, "lr")
+                .push(socket.local_rotation);
+
+            bone_node
+                .create_property(CastPropertyId::Vector3, "wp")
+                .push(world_position);
+            bone_node
+                .create_property(CastPropertyId::Vector4, "wr")
+                .push(world_rotation);
+        }
     }
 
     let mut material_map: HashMap<usize, CastPropertyValue> =
         HashMap::with_capacity(model.materials.len());
 
     for (material_index, material) in model.materials.iter().enumerate() {
-        let material_node = model_node.create(CastId::Material);
+        let material_node = model_node.create_named(CastId::Material, &material.name);
 
         material_node
             .create_property(CastPropertyId::String, "n")
@@ -253,7 +333,7 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         };
 
         for texture in &material.textures {
-            let file = material_node.create(CastId::File);
+            let file = material_node.create_named(CastId::File, &texture.file_name);
 
             file.create_property(CastPropertyId::String, "p")
                 .push(texture.file_name.as_str());
@@ -317,10 +397,17 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         material_map.insert(material_index, CastPropertyValue::from(material_node));
     }
 
-    for mesh in &model.meshes {
-        let mesh_node = model_node.create(CastId::Mesh);
+    let mesh_names = options.mesh_naming.mesh_names(model);
 
-        if let Some(name) = &mesh.name {
+    for (mesh, mesh_name) in model.meshes.iter().zip(mesh_names) {
+        let mesh_name = mesh_name.or_else(|| mesh.name.clone());
+
+        let mesh_node = match &mesh_name {
+            Some(name) => model_node.create_named(CastId::Mesh, name),
+            None => model_node.create(CastId::Mesh),
+        };
+
+        if let Some(name) = &mesh_name {
             mesh_node
                 .create_property(CastPropertyId::String, "n")
                 .push(name.as_str());
@@ -461,6 +548,8 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 .push(material.clone());
         }
 
+        write_custom_properties(mesh_node, &mesh.properties);
+
         let mesh_hash = CastPropertyValue::from(mesh_node);
 
         for blend_shape in &*mesh.blend_shapes {
@@ -522,7 +611,10 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
     }
 
     for hair in &model.hairs {
-        let hair_node = model_node.create(CastId::Hair);
+        let hair_node = match &hair.name {
+            Some(name) => model_node.create_named(CastId::Hair, name),
+            None => model_node.create(CastId::Hair),
+        };
 
         if let Some(name) = &hair.name {
             hair_node
@@ -579,7 +671,7 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     let writer = File::create(path.as_ref().with_extension("cast"))?.buffer_write();
 
-    let mut file = CastFile::new();
+    let mut file = CastFile::with_options(options.cast);
 
     file.push(root);
     file.write(writer)?;
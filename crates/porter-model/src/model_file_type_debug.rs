@@ -0,0 +1,178 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use porter_math::Axis;
+
+use porter_utils::BufferWriteExt;
+
+use crate::Model;
+use crate::ModelError;
+
+/// The number of vertices, and faces, written for each mesh.
+const DEBUG_ELEMENT_COUNT: usize = 16;
+
+/// Writes a model as a deterministic plain text dump to the given path.
+pub fn to_debug<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+    let mut debug = File::create(path.as_ref().with_extension("debug.txt"))?.buffer_write();
+
+    let up_axis = match model.up_axis {
+        Axis::X => "x",
+        Axis::Y => "y",
+        Axis::Z => "z",
+    };
+
+    writeln!(debug, "// Exported by PorterLib")?;
+    writeln!(debug, "up_axis {up_axis}")?;
+    writeln!(debug, "bones {}", model.skeleton.bones.len())?;
+
+    for (bone_index, bone) in model.skeleton.bones.iter().enumerate() {
+        writeln!(
+            debug,
+            "bone {} \"{}\" parent {} position {:.6} {:.6} {:.6} rotation {:.6} {:.6} {:.6} {:.6} scale {:.6} {:.6} {:.6}",
+            bone_index,
+            bone.name.as_deref().unwrap_or_default(),
+            bone.parent,
+            bone.local_position.x,
+            bone.local_position.y,
+            bone.local_position.z,
+            bone.local_rotation.x,
+            bone.local_rotation.y,
+            bone.local_rotation.z,
+            bone.local_rotation.w,
+            bone.local_scale.x,
+            bone.local_scale.y,
+            bone.local_scale.z,
+        )?;
+    }
+
+    writeln!(debug, "materials {}", model.materials.len())?;
+
+    for (material_index, material) in model.materials.iter().enumerate() {
+        writeln!(
+            debug,
+            "material {} \"{}\" source \"{}\" textures {} parameters {}",
+            material_index,
+            material.name,
+            material.source_name,
+            material.textures.len(),
+            material.parameters.len(),
+        )?;
+
+        for texture in &material.textures {
+            writeln!(
+                debug,
+                "  texture {} \"{}\" alias \"{}\" hash {:#x}",
+                texture.texture_usage,
+                texture.file_name,
+                texture.texture_alias,
+                texture.texture_hash,
+            )?;
+        }
+    }
+
+    writeln!(debug, "meshes {}", model.meshes.len())?;
+
+    for (mesh_index, mesh) in model.meshes.iter().enumerate() {
+        writeln!(
+            debug,
+            "mesh {} \"{}\" material {} vertices {} faces {} uv_layers {} maximum_influence {} colors {} blend_shapes {} skinning {:?}",
+            mesh_index,
+            mesh.name.as_deref().unwrap_or_default(),
+            mesh.material
+                .map(|material| material.to_string())
+                .unwrap_or_else(|| String::from("none")),
+            mesh.vertices.len(),
+            mesh.faces.len(),
+            mesh.vertices.uv_layers(),
+            mesh.vertices.maximum_influence(),
+            mesh.vertices.colors(),
+            mesh.blend_shapes.len(),
+            mesh.skinning_method,
+        )?;
+
+        for (layer, uv_layer) in mesh.uv_layers.iter().enumerate() {
+            writeln!(
+                debug,
+                "  uv_layer {} \"{}\" semantic {:?}",
+                layer,
+                mesh.uv_layer_name(layer),
+                uv_layer.semantic
+            )?;
+        }
+
+        for range in &mesh.material_ranges {
+            writeln!(
+                debug,
+                "  material_range {} faces {} {}",
+                range.material, range.face_start, range.length
+            )?;
+        }
+
+        for vertex_index in 0..mesh.vertices.len().min(DEBUG_ELEMENT_COUNT) {
+            let vertex = mesh.vertices.vertex(vertex_index);
+
+            let position = vertex.position();
+            let normal = vertex.normal();
+
+            write!(
+                debug,
+                "  vertex {} position {:.6} {:.6} {:.6} normal {:.6} {:.6} {:.6}",
+                vertex_index, position.x, position.y, position.z, normal.x, normal.y, normal.z,
+            )?;
+
+            for uv_layer in 0..mesh.vertices.uv_layers() {
+                let uv = vertex.uv(uv_layer);
+
+                write!(debug, " uv {:.6} {:.6}", uv.x, uv.y)?;
+            }
+
+            for color in 0..mesh.vertices.colors() {
+                let color = vertex.color(color);
+
+                write!(
+                    debug,
+                    " color {} {} {} {}",
+                    color.r, color.g, color.b, color.a
+                )?;
+            }
+
+            for weight in 0..mesh.vertices.maximum_influence() {
+                let weight = vertex.weight(weight);
+
+                write!(debug, " weight {} {:.6}", { weight.bone }, { weight.value })?;
+            }
+
+            writeln!(debug)?;
+        }
+
+        for (face_index, face) in mesh.faces.iter().take(DEBUG_ELEMENT_COUNT).enumerate() {
+            writeln!(
+                debug,
+                "  face {} {} {} {}",
+                face_index,
+                { face.i1 },
+                { face.i2 },
+                { face.i3 },
+            )?;
+        }
+    }
+
+    writeln!(debug, "hairs {}", model.hairs.len())?;
+
+    for (hair_index, hair) in model.hairs.iter().enumerate() {
+        writeln!(
+            debug,
+            "hair {} \"{}\" material {} strands {} particles {}",
+            hair_index,
+            hair.name.as_deref().unwrap_or_default(),
+            hair.material
+                .map(|material| material.to_string())
+                .unwrap_or_else(|| String::from("none")),
+            hair.segments.len(),
+            hair.particles.len(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::collections::btree_map::Entry;
 use std::fs::File;
 use std::path::Path;
@@ -16,8 +16,11 @@ use porter_math::Matrix4x4;
 
 use porter_utils::BufferWriteExt;
 
+use crate::CustomProperties;
+use crate::CustomPropertyValue;
 use crate::MaterialTextureRef;
 use crate::MaterialTextureRefUsage;
+use crate::MeshNaming;
 use crate::Model;
 use crate::ModelError;
 
@@ -183,6 +186,66 @@ fn initialize_texture_node(
     );
 }
 
+/// Adds custom properties as user properties to the given properties node.
+fn add_custom_properties(properties: &mut FbxNode, custom_properties: &CustomProperties) {
+    for (name, value) in custom_properties.iter() {
+        let (type_name, data_type) = match value {
+            CustomPropertyValue::Bool(_) => ("bool", ""),
+            CustomPropertyValue::Integer(_) => ("int", "Integer"),
+            CustomPropertyValue::Float(_) => ("double", "Number"),
+            CustomPropertyValue::String(_) => ("KString", ""),
+            CustomPropertyValue::Vector3(_) => ("Vector3D", "Vector"),
+        };
+
+        let props = properties.create("P");
+
+        props
+            .create_property(FbxPropertyType::String)
+            .push_string(name);
+        props
+            .create_property(FbxPropertyType::String)
+            .push_string(type_name);
+        props
+            .create_property(FbxPropertyType::String)
+            .push_string(data_type);
+        props
+            .create_property(FbxPropertyType::String)
+            .push_string("A+U");
+
+        match value {
+            CustomPropertyValue::Bool(value) => {
+                props
+                    .create_property(FbxPropertyType::Integer32)
+                    .push(*value as u32);
+            }
+            CustomPropertyValue::Integer(value) => {
+                props
+                    .create_property(FbxPropertyType::Integer32)
+                    .push((*value).clamp(i32::MIN as i64, i32::MAX as i64) as i32 as u32);
+            }
+            CustomPropertyValue::Float(value) => {
+                props.create_property(FbxPropertyType::Float64).push(*value);
+            }
+            CustomPropertyValue::String(value) => {
+                props
+                    .create_property(FbxPropertyType::String)
+                    .push_string(value.as_str());
+            }
+            CustomPropertyValue::Vector3(value) => {
+                props
+                    .create_property(FbxPropertyType::Float64)
+                    .push(value.x as f64);
+                props
+                    .create_property(FbxPropertyType::Float64)
+                    .push(value.y as f64);
+                props
+                    .create_property(FbxPropertyType::Float64)
+                    .push(value.z as f64);
+            }
+        }
+    }
+}
+
 /// Adds basic properties to the model and skeleton root nodes.
 fn initialize_root_node(root_node: &mut FbxNode) {
     root_node
@@ -290,7 +353,11 @@ fn initialize_root_node(root_node: &mut FbxNode) {
 }
 
 /// Writes a model in fbx format to the given path.
-pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+pub fn to_fbx<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    naming: MeshNaming,
+) -> Result<(), ModelError> {
     let mut root = FbxDocument::new();
     let root_hash = FbxPropertyValue::from(root.root_node());
 
@@ -545,6 +612,8 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 props.create_property(FbxPropertyType::Integer32).push(0u32);
             }
 
+            add_custom_properties(properties, &bone.properties);
+
             let joint_hash = FbxPropertyValue::from(joint);
 
             add_object_connection(root.connections_node(), skeleton_hash, joint_hash);
@@ -628,13 +697,121 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     add_object_connection(root.connections_node(), model_hash, root_hash);
 
+    for socket in &model.skeleton.sockets {
+        let attribute = root.objects_node().create("NodeAttribute");
+
+        attribute.create_hash();
+        attribute
+            .create_property(FbxPropertyType::String)
+            .push_string("\u{0000}\u{0001}NodeAttribute");
+        attribute
+            .create_property(FbxPropertyType::String)
+            .push_string("Null");
+
+        attribute
+            .create("TypeFlags")
+            .create_property(FbxPropertyType::String)
+            .push_string("Null");
+
+        let attribute_hash = FbxPropertyValue::from(attribute);
+
+        let socket_node = root.objects_node().create("Model");
+
+        socket_node.create_hash();
+        socket_node
+            .create_property(FbxPropertyType::String)
+            .push_string(format!("{}\u{0000}\u{0001}Model", socket.name));
+        socket_node
+            .create_property(FbxPropertyType::String)
+            .push_string("Null");
+
+        socket_node
+            .create("Version")
+            .create_property(FbxPropertyType::Integer32)
+            .push(232u32);
+
+        let properties = socket_node.create("Properties70");
+
+        {
+            let props = properties.create("P");
+            let position = socket.local_position;
+
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Translation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Translation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A");
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.x as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.y as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.z as f64);
+        }
+
+        {
+            let props = properties.create("P");
+            let rotation = socket.local_rotation.to_euler(Angles::Degrees);
+
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Rotation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Rotation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A");
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.x as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.y as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.z as f64);
+        }
+
+        let socket_hash = FbxPropertyValue::from(socket_node);
+
+        add_object_connection(root.connections_node(), attribute_hash, socket_hash);
+
+        // Sockets without a parent bone are placed under the model, which shares the up axis of the joints.
+        match joints_map.get(&(socket.parent.max(0) as usize)) {
+            Some(joint_hash) if socket.parent > -1 => {
+                add_object_connection(root.connections_node(), socket_hash, *joint_hash);
+            }
+            _ => add_object_connection(root.connections_node(), socket_hash, model_hash),
+        }
+    }
+
+    let mesh_names = naming.mesh_names(model);
+
     for (mesh_index, mesh) in model.meshes.iter().enumerate() {
+        let mesh_name = mesh_names[mesh_index]
+            .clone()
+            .unwrap_or_else(|| format!("PorterMesh{mesh_index}"));
+
         let mesh_node = root.objects_node().create("Model");
 
         mesh_node.create_hash();
         mesh_node
             .create_property(FbxPropertyType::String)
-            .push_string(format!("PorterMesh{mesh_index}\u{0000}\u{0001}Model"));
+            .push_string(format!("{mesh_name}\u{0000}\u{0001}Model"));
         mesh_node
             .create_property(FbxPropertyType::String)
             .push_string("Mesh");
@@ -702,6 +879,8 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             props.create_property(FbxPropertyType::Integer32).push(1u32);
         }
 
+        add_custom_properties(properties, &mesh.properties);
+
         mesh_node
             .create("MultiLayer")
             .create_property(FbxPropertyType::Integer32)
@@ -726,7 +905,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         geometry.create_hash();
         geometry
             .create_property(FbxPropertyType::String)
-            .push_string(format!("PorterMesh{mesh_index}\u{0000}\u{0001}Geometry"));
+            .push_string(format!("{mesh_name}\u{0000}\u{0001}Geometry"));
         geometry
             .create_property(FbxPropertyType::String)
             .push_string("Mesh");
@@ -804,7 +983,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             layer_uvs
                 .create("Name")
                 .create_property(FbxPropertyType::String)
-                .push_string(format!("map{}", i + 1));
+                .push_string(mesh.uv_layer_name(i));
             layer_uvs
                 .create("Version")
                 .create_property(FbxPropertyType::Integer32)
@@ -983,7 +1162,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         deformer.create_hash();
         deformer
             .create_property(FbxPropertyType::String)
-            .push_string(format!("PorterMesh{mesh_index}\u{0000}\u{0001}Deformer"));
+            .push_string(format!("{mesh_name}\u{0000}\u{0001}Deformer"));
         deformer
             .create_property(FbxPropertyType::String)
             .push_string("Skin");
@@ -1001,7 +1180,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
         add_object_connection(root.connections_node(), deformer_hash, geometry_hash);
 
-        let mut sub_deformers: HashMap<u16, BTreeMap<usize, f32>> = HashMap::new();
+        let mut sub_deformers: BTreeMap<u16, BTreeMap<usize, f32>> = BTreeMap::new();
 
         for i in 0..mesh.vertices.len() {
             let vertex = mesh.vertices.vertex(i);
@@ -1020,7 +1199,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             }
         }
 
-        let mut bind_pose_ids: HashSet<u16> = sub_deformers.keys().copied().collect();
+        let mut bind_pose_ids: BTreeSet<u16> = sub_deformers.keys().copied().collect();
 
         for bone_id in sub_deformers.keys() {
             let mut current_parent = model.skeleton.bones[*bone_id as usize].parent;
@@ -1067,7 +1246,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 .create("Matrix")
                 .create_property(FbxPropertyType::Float64Array);
 
-            let global_matrix = model.skeleton.bones[bone_id as usize].world_matrix();
+            let global_matrix = model.skeleton.bones[bone_id as usize].bind_pose_matrix();
 
             for i in 0..16 {
                 matrix.push(global_matrix[i] as f64);
@@ -1100,7 +1279,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             sub_deformer
                 .create_property(FbxPropertyType::String)
                 .push_string(format!(
-                    "PorterMesh{mesh_index}_Bone{bone_id}\u{0000}\u{0001}SubDeformer"
+                    "{mesh_name}_Bone{bone_id}\u{0000}\u{0001}SubDeformer"
                 ));
             sub_deformer
                 .create_property(FbxPropertyType::String)
@@ -1127,8 +1306,10 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 value_buffer.push(*weight as f64);
             }
 
-            let transform_link_matrix = model.skeleton.bones[bone_id as usize].world_matrix();
-            let transform_matrix = transform_link_matrix.inverse();
+            let bone = &model.skeleton.bones[bone_id as usize];
+
+            let transform_link_matrix = bone.bind_pose_matrix();
+            let transform_matrix = bone.inverse_bind_matrix();
 
             let transform = sub_deformer
                 .create("Transform")
@@ -9,17 +9,31 @@ use porter_math::Angles;
 use porter_utils::BufferWriteExt;
 use porter_utils::HashExt;
 
+use crate::MeshNaming;
 use crate::Model;
 use crate::ModelError;
 
 /// Writes a model in maya format to the given path.
-pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+pub fn to_maya<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    naming: MeshNaming,
+) -> Result<(), ModelError> {
     let path = path.as_ref();
     let file_name = path
         .file_stem()
         .map(|x| x.to_string_lossy().into_owned())
         .unwrap_or_else(|| String::from("porter_model"));
     let hash = file_name.hash_xxh364() as u32;
+    let mesh_names: Vec<String> = naming
+        .mesh_names(model)
+        .into_iter()
+        .enumerate()
+        .map(|(mesh_index, name)| match name {
+            Some(name) => format!("{name}_{hash:02x}"),
+            None => format!("PorterMesh_{hash:02x}_{mesh_index}"),
+        })
+        .collect();
 
     let mut maya = File::create(path.with_extension("ma"))?.buffer_write();
 
@@ -148,21 +162,19 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         writeln!(
             maya,
             concat!(
-                "createNode transform -n \"PorterMesh_{:02x}_{}\" -p \"{}\";\n",
+                "createNode transform -n \"{}\" -p \"{}\";\n",
                 "\tsetAttr \".rp\" -type \"double3\" 0.000000 0.000000 0.000000;\n",
                 "\tsetAttr \".sp\" -type \"double3\" 0.000000 0.000000 0.000000;\n",
-                "createNode mesh -n \"MeshShape_{}\" -p \"PorterMesh_{:02x}_{}\";\n",
+                "createNode mesh -n \"MeshShape_{}\" -p \"{}\";\n",
                 "\tsetAttr -k off \".v\";\n",
                 "\tsetAttr \".vir\" yes;\n",
                 "\tsetAttr \".vif\" yes;\n",
                 "\tsetAttr -s {} \".uvst\";",
             ),
-            hash,
-            mesh_index,
+            mesh_names[mesh_index],
             file_name,
             mesh_index,
-            hash,
-            mesh_index,
+            mesh_names[mesh_index],
             mesh.vertices.uv_layers()
         )?;
 
@@ -174,11 +186,11 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             write!(
                 maya,
                 concat!(
-                    "\tsetAttr \".uvst[{}].uvsn\" -type \"string\" \"map{}\";\n",
+                    "\tsetAttr \".uvst[{}].uvsn\" -type \"string\" \"{}\";\n",
                     "\tsetAttr -s {} \".uvst[{}].uvsp[{}]\" -type \"float2\"",
                 ),
                 uv_layer,
-                uv_layer + 1,
+                mesh.uv_layer_name(uv_layer),
                 mesh.vertices.len(),
                 uv_layer,
                 maya_range(mesh.vertices.len())
@@ -196,9 +208,10 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         writeln!(
             maya,
             concat!(
-                "\tsetAttr \".cuvs\" -type \"string\" \"map1\";\n",
+                "\tsetAttr \".cuvs\" -type \"string\" \"{}\";\n",
                 "\tsetAttr \".dcc\" -type \"string\" \"Ambient+Diffuse\";",
-            )
+            ),
+            mesh.uv_layer_name(0)
         )?;
 
         for color_layer in 0..mesh.vertices.colors() {
@@ -610,12 +623,8 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
     for (mesh_index, mesh) in model.meshes.iter().enumerate() {
         writeln!(
             bind,
-            concat!(
-                "global proc PorterMesh_{:02x}_{}_BindFunc()\n",
-                "{{\n",
-                "\tselect -r PorterMesh_{:02x}_{};",
-            ),
-            hash, mesh_index, hash, mesh_index
+            concat!("global proc {}_BindFunc()\n", "{{\n", "\tselect -r {};",),
+            mesh_names[mesh_index], mesh_names[mesh_index]
         )?;
 
         let maximum_influence = mesh.vertices.maximum_influence();
@@ -654,9 +663,9 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             bind,
             concat!(
                 "\tnewSkinCluster \"-toSelectedBones -mi {} -omi true -dr 5.0 -rui false\";\n",
-                "\tstring $clu = findRelatedSkinCluster(\"PorterMesh_{:02x}_{}\");",
+                "\tstring $clu = findRelatedSkinCluster(\"{}\");",
             ),
-            maximum_influence, hash, mesh_index
+            maximum_influence, mesh_names[mesh_index]
         )?;
 
         if bone_names.len() > 1 {
@@ -719,12 +728,8 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     writeln!(bind, "global proc RunAdvancedScript()\n{{")?;
 
-    for mesh_index in 0..model.meshes.len() {
-        writeln!(
-            bind,
-            "\tcatch(PorterMesh_{:02x}_{}_BindFunc());",
-            hash, mesh_index
-        )?;
+    for mesh_name in &mesh_names {
+        writeln!(bind, "\tcatch({}_BindFunc());", mesh_name)?;
     }
 
     writeln!(
@@ -7,11 +7,16 @@ use porter_utils::BufferWriteExt;
 use static_assertions::const_assert;
 
 use crate::MaterialTextureRefUsage;
+use crate::MeshNaming;
 use crate::Model;
 use crate::ModelError;
 
 /// Writes a model in obj format to the given path.
-pub fn to_obj<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+pub fn to_obj<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    naming: MeshNaming,
+) -> Result<(), ModelError> {
     let path = path.as_ref();
 
     let mut obj = File::create(path.with_extension("obj"))?.buffer_write();
@@ -84,20 +89,39 @@ pub fn to_obj<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     let mut global_face_index = 1;
 
-    for mesh in &model.meshes {
-        if let Some(material_index) = mesh.material {
-            writeln!(
-                obj,
-                "g {}\nusemtl {}",
-                model.materials[material_index].name, model.materials[material_index].name
-            )?;
-        } else {
-            writeln!(obj, "g default_material\nusemtl default_material")?;
-        }
+    let mesh_names = naming.mesh_names(model);
+
+    for (mesh, mesh_name) in model.meshes.iter().zip(mesh_names) {
+        let material_name = mesh
+            .material
+            .map(|material_index| model.materials[material_index].name.as_str())
+            .unwrap_or("default_material");
+
+        writeln!(
+            obj,
+            "g {}\nusemtl {}",
+            mesh_name.as_deref().unwrap_or(material_name),
+            material_name
+        )?;
 
         let use_tex_coords = mesh.vertices.uv_layers() > 0;
 
-        for _ in &mesh.faces {
+        let mut current_material = mesh.material;
+
+        for face_index in 0..mesh.faces.len() {
+            let material = mesh.face_material(face_index);
+
+            if material != current_material {
+                match material {
+                    Some(material_index) => {
+                        writeln!(obj, "usemtl {}", model.materials[material_index].name)?
+                    }
+                    None => writeln!(obj, "usemtl default_material")?,
+                }
+
+                current_material = material;
+            }
+
             if use_tex_coords {
                 writeln!(
                     obj,
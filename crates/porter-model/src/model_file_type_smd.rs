@@ -92,12 +92,12 @@ pub fn to_smd<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
     for mesh in &model.meshes {
         writeln!(smd, "triangles")?;
 
-        let material = match mesh.material {
-            Some(index) => model.materials[index].name.as_str(),
-            None => "default_material",
-        };
+        for (face_index, face) in mesh.faces.iter().enumerate() {
+            let material = match mesh.face_material(face_index) {
+                Some(index) => model.materials[index].name.as_str(),
+                None => "default_material",
+            };
 
-        for face in &mesh.faces {
             writeln!(smd, "{material}")?;
 
             write_face_vertex!(smd, mesh, face.i3);
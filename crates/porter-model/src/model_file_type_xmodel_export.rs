@@ -4,6 +4,7 @@ use std::path::Path;
 
 use porter_utils::BufferWriteExt;
 
+use crate::MeshNaming;
 use crate::Model;
 use crate::ModelError;
 
@@ -60,7 +61,11 @@ macro_rules! write_face_vertex {
 }
 
 /// Writes a model in xmodel export format to the given path.
-pub fn to_xmodel_export<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+pub fn to_xmodel_export<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    naming: MeshNaming,
+) -> Result<(), ModelError> {
     let mut xmodel = File::create(path.as_ref().with_extension("xmodel_export"))?.buffer_write();
 
     writeln!(
@@ -189,8 +194,11 @@ pub fn to_xmodel_export<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), Mo
 
     writeln!(xmodel, "NUMOBJECTS {}", model.meshes.len())?;
 
-    for i in 0..model.meshes.len() {
-        writeln!(xmodel, "OBJECT {} \"PorterMesh_{}\"", i, i)?;
+    for (i, mesh_name) in naming.mesh_names(model).into_iter().enumerate() {
+        match mesh_name {
+            Some(name) => writeln!(xmodel, "OBJECT {} \"{}\"", i, name)?,
+            None => writeln!(xmodel, "OBJECT {} \"PorterMesh_{}\"", i, i)?,
+        }
     }
 
     if needs_default_material {
@@ -6,13 +6,18 @@ use porter_math::normalize_array_f32;
 
 use porter_utils::BufferWriteExt;
 
+use crate::MeshNaming;
 use crate::Model;
 use crate::ModelError;
 use crate::VertexColor;
 use crate::WeightBoneId;
 
 /// Writes a model in xna lara format to the given path.
-pub fn to_xna_lara<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
+pub fn to_xna_lara<P: AsRef<Path>>(
+    path: P,
+    model: &Model,
+    naming: MeshNaming,
+) -> Result<(), ModelError> {
     let mut xna = File::create(path.as_ref().with_extension("mesh.ascii"))?.buffer_write();
 
     writeln!(xna, "{}", model.skeleton.bones.len())?;
@@ -35,11 +40,15 @@ pub fn to_xna_lara<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelEr
 
     writeln!(xna, "{}", model.meshes.len())?;
 
+    let mesh_names = naming.mesh_names(model);
+
     for (mesh_index, mesh) in model.meshes.iter().enumerate() {
         writeln!(
             xna,
-            "PorterMesh{}\n{}\n{}",
-            mesh_index,
+            "{}\n{}\n{}",
+            mesh_names[mesh_index]
+                .clone()
+                .unwrap_or_else(|| format!("PorterMesh{mesh_index}")),
             mesh.vertices.uv_layers(),
             mesh.vertices.uv_layers()
         )?;
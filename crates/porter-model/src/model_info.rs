@@ -0,0 +1,16 @@
+/// Metadata for a model, used to describe a model without loading all of its data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ModelInfo {
+    /// The number of bones in the skeleton.
+    pub bones: u32,
+    /// The number of meshes, in the highest level of detail.
+    pub meshes: u32,
+    /// The number of vertices, in the highest level of detail.
+    pub vertices: u32,
+    /// The number of faces, in the highest level of detail.
+    pub faces: u32,
+    /// The number of levels of detail.
+    pub lods: u32,
+    /// The number of materials.
+    pub materials: u32,
+}
@@ -0,0 +1,12 @@
+use porter_cast::CastOptions;
+
+use crate::MeshNaming;
+
+/// Options used when saving models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModelSaveOptions {
+    /// How meshes are named by the writers.
+    pub mesh_naming: MeshNaming,
+    /// The options used when writing cast files.
+    pub cast: CastOptions,
+}
@@ -1,10 +1,13 @@
 use porter_math::Matrix4x4;
+use porter_math::Quaternion;
+use porter_math::Vector3;
 
 use crate::Bone;
 use crate::Constraint;
 use crate::ConstraintOffset;
 use crate::ConstraintType;
 use crate::IKHandle;
+use crate::Socket;
 
 /// Represents a skeleton, or collection of bones for a model.
 #[derive(Debug, Clone, Default)]
@@ -15,6 +18,8 @@ pub struct Skeleton {
     pub ik_handles: Vec<IKHandle>,
     /// A collection of constraints for this skeleton.
     pub constraints: Vec<Constraint>,
+    /// A collection of attachment points for this skeleton.
+    pub sockets: Vec<Socket>,
 }
 
 impl Skeleton {
@@ -24,6 +29,7 @@ impl Skeleton {
             bones: Vec::new(),
             ik_handles: Vec::new(),
             constraints: Vec::new(),
+            sockets: Vec::new(),
         }
     }
 
@@ -33,6 +39,7 @@ impl Skeleton {
             bones: Vec::with_capacity(capacity),
             ik_handles: Vec::new(),
             constraints: Vec::new(),
+            sockets: Vec::new(),
         }
     }
 
@@ -88,14 +95,33 @@ impl Skeleton {
 
     /// Scales the skeleton by the given factor.
     pub fn scale(&mut self, factor: f32) {
+        for socket in &mut self.sockets {
+            socket.local_position *= factor;
+        }
+
         for bone in &mut self.bones {
             bone.local_position *= factor;
             bone.world_position *= factor;
+
+            if let Some(bind_matrix) = &mut bone.bind_matrix {
+                let (position, rotation, scale) = bind_matrix.decompose();
+
+                *bind_matrix = Matrix4x4::create_position(position * factor)
+                    * Matrix4x4::create_rotation(rotation)
+                    * Matrix4x4::create_scale(scale);
+            }
         }
     }
 
     /// Transforms the skeleton by the given matrix.
     pub fn transform(&mut self, matrix: &Matrix4x4) {
+        for socket in &mut self.sockets {
+            let (position, rotation, _) = (socket.local_matrix() * *matrix).decompose();
+
+            socket.local_position = position;
+            socket.local_rotation = rotation;
+        }
+
         for bone in &mut self.bones {
             let result = bone.local_matrix() * *matrix;
             let (position, rotation, scale) = result.decompose();
@@ -110,9 +136,21 @@ impl Skeleton {
             bone.world_position = position;
             bone.world_rotation = rotation;
             bone.world_scale = scale;
+
+            if let Some(bind_matrix) = &mut bone.bind_matrix {
+                *bind_matrix = *bind_matrix * *matrix;
+            }
         }
     }
 
+    /// Gets the inverse bind matrix of each bone, used to skin meshes to this skeleton.
+    pub fn inverse_bind_matrices(&self) -> Vec<Matrix4x4> {
+        self.bones
+            .iter()
+            .map(|bone| bone.inverse_bind_matrix())
+            .collect()
+    }
+
     /// Creates an ik handle if the all of given bones are found in the skeleton.
     #[allow(clippy::too_many_arguments)]
     pub fn create_ik_handle<
@@ -207,6 +245,37 @@ impl Skeleton {
         }
     }
 
+    /// Creates a socket if the given parent bone is found in the skeleton, or relative to the model without one.
+    pub fn create_socket<N: Into<String>, P: AsRef<str>>(
+        &mut self,
+        name: N,
+        parent_bone: Option<P>,
+        local_position: Vector3,
+        local_rotation: Quaternion,
+    ) {
+        let parent = match parent_bone {
+            Some(parent_bone) => match self.index(parent_bone) {
+                Some(parent) => parent as i32,
+                None => return,
+            },
+            None => -1,
+        };
+
+        self.sockets.push(
+            Socket::new(name, parent)
+                .local_position(local_position)
+                .local_rotation(local_rotation),
+        );
+    }
+
+    /// Gets the world matrix of the given socket, relative to its parent bone.
+    pub fn socket_world_matrix(&self, socket: &Socket) -> Matrix4x4 {
+        match self.bones.get(socket.parent.max(0) as usize) {
+            Some(parent) if socket.parent > -1 => parent.world_matrix() * socket.local_matrix(),
+            _ => socket.local_matrix(),
+        }
+    }
+
     /// Attempts to find a bone with the given name.
     pub fn find<N: AsRef<str>>(&self, name: N) -> Option<&Bone> {
         self.bones.get(self.index(name)?)
@@ -243,5 +312,18 @@ impl Skeleton {
                 bone.parent, index, bone.name
             );
         }
+
+        for socket in &self.sockets {
+            if socket.parent == -1
+                || (socket.parent > -1 && socket.parent < self.bones.len() as i32)
+            {
+                continue;
+            }
+
+            println!(
+                "Validate Error: Found socket with invalid parent: {} {:?}",
+                socket.parent, socket.name
+            );
+        }
     }
 }
@@ -0,0 +1,48 @@
+use porter_math::Matrix4x4;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+/// An attachment point, such as a tag or socket, offset from a parent bone.
+#[derive(Debug, Clone)]
+pub struct Socket {
+    /// The name of the socket.
+    pub name: String,
+    /// The index of the parent bone, or -1 when relative to the model.
+    pub parent: i32,
+    /// The position offset from the parent bone.
+    pub local_position: Vector3,
+    /// The rotation offset from the parent bone.
+    pub local_rotation: Quaternion,
+}
+
+impl Socket {
+    /// Constructs a new socket with the given name and parent bone index.
+    pub fn new<N: Into<String>>(name: N, parent: i32) -> Self {
+        Self {
+            name: name.into(),
+            parent,
+            local_position: Vector3::zero(),
+            local_rotation: Quaternion::identity(),
+        }
+    }
+
+    /// Sets the position offset from the parent bone.
+    #[inline]
+    pub fn local_position(mut self, position: Vector3) -> Self {
+        self.local_position = position;
+        self
+    }
+
+    /// Sets the rotation offset from the parent bone.
+    #[inline]
+    pub fn local_rotation(mut self, rotation: Quaternion) -> Self {
+        self.local_rotation = rotation;
+        self
+    }
+
+    /// Gets the local matrix (T * R).
+    pub fn local_matrix(&self) -> Matrix4x4 {
+        Matrix4x4::create_position(self.local_position)
+            * Matrix4x4::create_rotation(self.local_rotation)
+    }
+}
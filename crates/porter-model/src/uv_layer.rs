@@ -0,0 +1,28 @@
+/// What a uv layer is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UvLayerSemantic {
+    #[default]
+    Diffuse,
+    Lightmap,
+    Detail,
+    Unknown,
+}
+
+/// The name and semantic of a uv layer in a mesh.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UvLayer {
+    /// The name of the uv layer, if it has one.
+    pub name: Option<String>,
+    /// What the uv layer is used for.
+    pub semantic: UvLayerSemantic,
+}
+
+impl UvLayer {
+    /// Constructs a new uv layer with the given name and semantic.
+    pub fn new<S: Into<String>>(name: Option<S>, semantic: UvLayerSemantic) -> Self {
+        Self {
+            name: name.map(Into::into),
+            semantic,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::VertexBuffer;
 use crate::VertexColor;
 use crate::VertexWeight;
 use crate::WeightBoneId;
+use crate::WeightNormalization;
 
 /// A single vertex of a polygon mesh.
 pub struct Vertex<'a> {
@@ -204,6 +205,67 @@ impl<'a> VertexMut<'a> {
         self
     }
 
+    /// Normalizes the weights for this vertex so that they sum to one.
+    pub fn normalize_weights(&mut self, normalization: WeightNormalization) -> &mut Self {
+        let influence = self.buffer.maximum_influence();
+
+        if influence == 0 {
+            return self;
+        }
+
+        for w in 0..influence {
+            let weight = self.weight(w);
+
+            if weight.value.is_nan() || weight.value <= normalization.epsilon {
+                self.set_weight_value(w, 0.0);
+            }
+        }
+
+        if normalization.prune {
+            let mut count = 0;
+
+            'weights: for w in 0..influence {
+                let weight = self.weight(w);
+
+                if weight.value == 0.0 {
+                    continue;
+                }
+
+                for existing in 0..count {
+                    let target = self.weight(existing);
+
+                    if target.bone == weight.bone {
+                        self.set_weight_value(existing, target.value + weight.value);
+                        continue 'weights;
+                    }
+                }
+
+                self.set_weight(count, weight);
+
+                count += 1;
+            }
+
+            for w in count..influence {
+                self.set_weight(w, VertexWeight::new(0, 0.0));
+            }
+        }
+
+        let sum: f32 = (0..influence).map(|w| self.weight(w).value).sum();
+
+        if sum > normalization.epsilon {
+            for w in 0..influence {
+                let weight = self.weight(w);
+
+                self.set_weight_value(w, weight.value / sum);
+            }
+        } else {
+            // A vertex without any influence is rigidly bound to the first bone.
+            self.set_weight_value(0, 1.0);
+        }
+
+        self
+    }
+
     /// Returns the color for this vertex.
     #[track_caller]
     pub fn color(&self, index: usize) -> VertexColor {
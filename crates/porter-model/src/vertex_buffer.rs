@@ -3,10 +3,13 @@ use std::fmt;
 use porter_math::Vector2;
 use porter_math::Vector3;
 
+use crate::ModelError;
 use crate::Vertex;
+use crate::VertexChannel;
 use crate::VertexColor;
 use crate::VertexMut;
 use crate::VertexWeight;
+use crate::WeightNormalization;
 
 /// Utility to compute the stride of each vertex in bytes.
 const fn compute_stride(uv_layers: usize, maximum_influence: usize, colors: usize) -> usize {
@@ -28,6 +31,7 @@ pub struct VertexBuffer {
     colors: usize,
     uv_layers: usize,
     maximum_influence: usize,
+    has_normals: bool,
 }
 
 /// Used to build a buffer of vertices based on the configuration.
@@ -37,6 +41,20 @@ pub struct VertexBufferBuilder {
     pub(crate) colors: usize,
     pub(crate) uv_layers: usize,
     pub(crate) maximum_influence: usize,
+    bone_count: Option<usize>,
+    weight_normalization: Option<WeightNormalization>,
+    has_normals: Option<bool>,
+}
+
+/// Used to build a buffer of vertices from declared channels, which are validated when building.
+#[derive(Debug, Clone)]
+pub struct VertexChannelBuilder {
+    config: VertexBufferBuilder,
+    positions: Option<Vec<Vector3>>,
+    normals: Option<Vec<Vector3>>,
+    uv_channels: Vec<Vec<Vector2>>,
+    color_channels: Vec<Vec<VertexColor>>,
+    weights: Option<(usize, Vec<VertexWeight>)>,
 }
 
 impl VertexBuffer {
@@ -88,6 +106,16 @@ impl VertexBuffer {
         self.colors
     }
 
+    /// Whether or not the vertices have source normals, otherwise they must be generated before use.
+    pub fn has_normals(&self) -> bool {
+        self.has_normals
+    }
+
+    /// Sets whether or not the vertices have source normals.
+    pub fn set_has_normals(&mut self, has_normals: bool) {
+        self.has_normals = has_normals;
+    }
+
     /// Clears the vertex buffer.
     pub fn clear(&mut self) {
         self.buffer.clear();
@@ -125,6 +153,17 @@ impl VertexBuffer {
         VertexMut::new(self, index)
     }
 
+    /// Normalizes the weights of every vertex so that they sum to one.
+    pub fn normalize_weights(&mut self, normalization: WeightNormalization) {
+        if self.maximum_influence == 0 {
+            return;
+        }
+
+        for i in 0..self.len() {
+            self.vertex_mut(i).normalize_weights(normalization);
+        }
+    }
+
     /// Returns the internal buffer used by this vertex buffer.
     pub fn as_slice(&self) -> &[u8] {
         &self.buffer
@@ -150,6 +189,49 @@ impl VertexBufferBuilder {
         self
     }
 
+    /// Sets whether or not the vertices will have source normals, by default this is true unless channels are declared without normals.
+    pub fn has_normals(mut self, has_normals: bool) -> Self {
+        self.has_normals = Some(has_normals);
+        self
+    }
+
+    /// Sets the number of bones in the skeleton, used to validate weight bone indices.
+    pub fn bone_count(mut self, bone_count: usize) -> Self {
+        self.bone_count = Some(bone_count);
+        self
+    }
+
+    /// Normalizes the declared weights when building, so that each vertex sums to one.
+    pub fn normalize_weights(mut self, normalization: WeightNormalization) -> Self {
+        self.weight_normalization = Some(normalization);
+        self
+    }
+
+    /// Declares the position of each vertex, which sets the number of vertices in the buffer.
+    pub fn positions(self, positions: Vec<Vector3>) -> VertexChannelBuilder {
+        VertexChannelBuilder::new(self).positions(positions)
+    }
+
+    /// Declares the normal of each vertex.
+    pub fn normals(self, normals: Vec<Vector3>) -> VertexChannelBuilder {
+        VertexChannelBuilder::new(self).normals(normals)
+    }
+
+    /// Declares the next uv layer, with one uv per vertex.
+    pub fn uv_channel(self, uvs: Vec<Vector2>) -> VertexChannelBuilder {
+        VertexChannelBuilder::new(self).uv_channel(uvs)
+    }
+
+    /// Declares the next color layer, with one color per vertex.
+    pub fn color_channel(self, colors: Vec<VertexColor>) -> VertexChannelBuilder {
+        VertexChannelBuilder::new(self).color_channel(colors)
+    }
+
+    /// Declares the weights of each vertex, with `influence` weights per vertex laid out one vertex after another.
+    pub fn weights(self, influence: usize, weights: Vec<VertexWeight>) -> VertexChannelBuilder {
+        VertexChannelBuilder::new(self).weights(influence, weights)
+    }
+
     /// Builds the vertex buffer.
     #[inline]
     pub fn build(self) -> VertexBuffer {
@@ -160,7 +242,249 @@ impl VertexBufferBuilder {
             colors: self.colors,
             uv_layers: self.uv_layers,
             maximum_influence: self.maximum_influence,
+            has_normals: self.has_normals.unwrap_or(true),
+        }
+    }
+}
+
+impl VertexChannelBuilder {
+    /// Constructs a new channel builder with the given configuration and no channels.
+    fn new(config: VertexBufferBuilder) -> Self {
+        Self {
+            config,
+            positions: None,
+            normals: None,
+            uv_channels: Vec::new(),
+            color_channels: Vec::new(),
+            weights: None,
+        }
+    }
+
+    /// Sets the minimum number of color layers per vertex.
+    pub fn colors(mut self, colors: usize) -> Self {
+        self.config = self.config.colors(colors);
+        self
+    }
+
+    /// Sets the minimum number of uv layers per vertex.
+    pub fn uv_layers(mut self, uv_layers: usize) -> Self {
+        self.config = self.config.uv_layers(uv_layers);
+        self
+    }
+
+    /// Sets the minimum influence per vertex.
+    pub fn maximum_influence(mut self, maximum_influence: usize) -> Self {
+        self.config = self.config.maximum_influence(maximum_influence);
+        self
+    }
+
+    /// Sets whether or not the vertices will have source normals, by default this is true when normals are declared.
+    pub fn has_normals(mut self, has_normals: bool) -> Self {
+        self.config = self.config.has_normals(has_normals);
+        self
+    }
+
+    /// Sets the number of bones in the skeleton, used to validate weight bone indices.
+    pub fn bone_count(mut self, bone_count: usize) -> Self {
+        self.config = self.config.bone_count(bone_count);
+        self
+    }
+
+    /// Normalizes the declared weights when building, so that each vertex sums to one.
+    pub fn normalize_weights(mut self, normalization: WeightNormalization) -> Self {
+        self.config = self.config.normalize_weights(normalization);
+        self
+    }
+
+    /// Declares the position of each vertex, which sets the number of vertices in the buffer.
+    pub fn positions(mut self, positions: Vec<Vector3>) -> Self {
+        self.positions = Some(positions);
+        self
+    }
+
+    /// Declares the normal of each vertex.
+    pub fn normals(mut self, normals: Vec<Vector3>) -> Self {
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Declares the next uv layer, with one uv per vertex.
+    pub fn uv_channel(mut self, uvs: Vec<Vector2>) -> Self {
+        self.uv_channels.push(uvs);
+        self
+    }
+
+    /// Declares the next color layer, with one color per vertex.
+    pub fn color_channel(mut self, colors: Vec<VertexColor>) -> Self {
+        self.color_channels.push(colors);
+        self
+    }
+
+    /// Declares the weights of each vertex, with `influence` weights per vertex laid out one vertex after another.
+    pub fn weights(mut self, influence: usize, weights: Vec<VertexWeight>) -> Self {
+        self.weights = Some((influence, weights));
+        self
+    }
+
+    /// Validates the declared channels and builds the vertex buffer filled with them.
+    pub fn try_build(self) -> Result<VertexBuffer, ModelError> {
+        let positions = self.validate()?;
+
+        let config = self.config;
+
+        let colors = config.colors.max(self.color_channels.len());
+        let uv_layers = config.uv_layers.max(self.uv_channels.len());
+        let maximum_influence = config.maximum_influence.max(
+            self.weights
+                .as_ref()
+                .map(|(influence, _)| *influence)
+                .unwrap_or_default(),
+        );
+
+        let stride = compute_stride(uv_layers, maximum_influence, colors);
+
+        let mut buffer = Vec::new();
+
+        buffer.try_reserve_exact(config.capacity.max(positions.len()) * stride)?;
+        buffer.resize(positions.len() * stride, 0);
+
+        let mut vertices = VertexBuffer {
+            buffer,
+            colors,
+            uv_layers,
+            maximum_influence,
+            has_normals: config.has_normals.unwrap_or(self.normals.is_some()),
+        };
+
+        for (index, position) in positions.iter().enumerate() {
+            let mut vertex = vertices.vertex_mut(index);
+
+            vertex.set_position(*position);
+
+            if let Some(normals) = &self.normals {
+                vertex.set_normal(normals[index]);
+            }
+
+            for (layer, uvs) in self.uv_channels.iter().enumerate() {
+                vertex.set_uv(layer, uvs[index]);
+            }
+
+            for (layer, colors) in self.color_channels.iter().enumerate() {
+                vertex.set_color(layer, colors[index]);
+            }
+
+            if let Some((influence, weights)) = &self.weights {
+                for (slot, weight) in weights[index * influence..(index + 1) * influence]
+                    .iter()
+                    .enumerate()
+                {
+                    vertex.set_weight(slot, *weight);
+                }
+            }
         }
+
+        if let Some(normalization) = config.weight_normalization {
+            vertices.normalize_weights(normalization);
+        }
+
+        Ok(vertices)
+    }
+
+    /// Validates that every declared channel matches the positions and holds usable values, returning the positions.
+    fn validate(&self) -> Result<&[Vector3], ModelError> {
+        let Some(positions) = &self.positions else {
+            return Err(ModelError::MissingVertexChannel(VertexChannel::Position));
+        };
+
+        let count = positions.len();
+
+        let vector3_valid =
+            |vector: &Vector3| vector.x.is_finite() && vector.y.is_finite() && vector.z.is_finite();
+
+        if let Some(index) = positions
+            .iter()
+            .position(|position| !vector3_valid(position))
+        {
+            return Err(ModelError::InvalidVertexChannelValue(
+                VertexChannel::Position,
+                index,
+            ));
+        }
+
+        if let Some(normals) = &self.normals {
+            if normals.len() != count {
+                return Err(ModelError::InvalidVertexChannelLength(
+                    VertexChannel::Normal,
+                    normals.len(),
+                ));
+            }
+
+            if let Some(index) = normals.iter().position(|normal| !vector3_valid(normal)) {
+                return Err(ModelError::InvalidVertexChannelValue(
+                    VertexChannel::Normal,
+                    index,
+                ));
+            }
+        }
+
+        for (layer, uvs) in self.uv_channels.iter().enumerate() {
+            if uvs.len() != count {
+                return Err(ModelError::InvalidVertexChannelLength(
+                    VertexChannel::Uv(layer),
+                    uvs.len(),
+                ));
+            }
+
+            if let Some(index) = uvs
+                .iter()
+                .position(|uv| !uv.x.is_finite() || !uv.y.is_finite())
+            {
+                return Err(ModelError::InvalidVertexChannelValue(
+                    VertexChannel::Uv(layer),
+                    index,
+                ));
+            }
+        }
+
+        for (layer, colors) in self.color_channels.iter().enumerate() {
+            if colors.len() != count {
+                return Err(ModelError::InvalidVertexChannelLength(
+                    VertexChannel::Color(layer),
+                    colors.len(),
+                ));
+            }
+        }
+
+        if let Some((influence, weights)) = &self.weights {
+            if weights.len() != count * influence {
+                return Err(ModelError::InvalidVertexChannelLength(
+                    VertexChannel::Weight,
+                    weights.len(),
+                ));
+            }
+
+            for (index, weight) in weights.iter().enumerate() {
+                let value = weight.value;
+
+                if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+                    return Err(ModelError::InvalidVertexChannelValue(
+                        VertexChannel::Weight,
+                        index / influence,
+                    ));
+                }
+
+                if let Some(bone_count) = self.config.bone_count
+                    && weight.bone as usize >= bone_count
+                {
+                    return Err(ModelError::InvalidVertexWeightBone(
+                        index / influence,
+                        weight.bone,
+                    ));
+                }
+            }
+        }
+
+        Ok(positions)
     }
 }
 
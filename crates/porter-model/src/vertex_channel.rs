@@ -0,0 +1,9 @@
+/// A channel of per vertex data declared on a vertex buffer builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexChannel {
+    Position,
+    Normal,
+    Uv(usize),
+    Color(usize),
+    Weight,
+}
@@ -0,0 +1,21 @@
+/// Options used when normalizing vertex weights so that they sum to one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightNormalization {
+    /// Weights at or below this value are treated as zero.
+    pub epsilon: f32,
+    /// Whether or not zero weights are removed, and duplicate bones merged, moving the remaining weights to the front.
+    pub prune: bool,
+}
+
+impl WeightNormalization {
+    /// Constructs new weight normalization options.
+    pub const fn new(epsilon: f32, prune: bool) -> Self {
+        Self { epsilon, prune }
+    }
+}
+
+impl Default for WeightNormalization {
+    fn default() -> Self {
+        Self::new(1e-6, true)
+    }
+}
@@ -1,3 +1,5 @@
+use porter_utils::ReportError;
+
 /// Errors that can occur in the process crate.
 #[derive(Debug)]
 pub enum ProcessError {
@@ -42,3 +44,29 @@ impl From<ProcessError> for std::io::Error {
         }
     }
 }
+
+impl ReportError for ProcessError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotFound => "PRC-001",
+            Self::AccessDenied => "PRC-002",
+            Self::IoError(e) => e.error_code(),
+            Self::TryReserveError(e) => e.error_code(),
+            #[cfg(target_os = "linux")]
+            Self::ProcError(_) => "PRC-003",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::NotFound => String::from("The game process could not be found."),
+            Self::AccessDenied => String::from(
+                "Access to the game process was denied, try running as an administrator.",
+            ),
+            Self::IoError(e) => e.error_message(),
+            Self::TryReserveError(e) => e.error_message(),
+            #[cfg(target_os = "linux")]
+            Self::ProcError(_) => String::from("The game process could not be read."),
+        }
+    }
+}
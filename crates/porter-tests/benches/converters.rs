@@ -0,0 +1,256 @@
+use std::hint::black_box;
+use std::io::Cursor;
+
+use criterion::BatchSize;
+use criterion::Criterion;
+use criterion::Throughput;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use porter_model::ModelFileType;
+
+use porter_texture::ImageCompressionQuality;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+
+use porter_utils::DirectoryCache;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_image;
+use porter_tests::synthetic_mesh;
+use porter_tests::synthetic_texture;
+
+/// The size of the representative 4K texture.
+const TEXTURE_SIZE: u32 = 4096;
+
+/// The number of vertices in the representative mesh.
+const MESH_VERTICES: usize = 1_000_000;
+
+/// The number of per material folders created for a representative export.
+const MATERIAL_FOLDERS: usize = 2_000;
+
+/// Benchmarks decoding block compressed textures to R8G8B8A8.
+fn bcn_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bcn_decode");
+
+    group.sample_size(10);
+    group.throughput(Throughput::Elements((TEXTURE_SIZE * TEXTURE_SIZE) as u64));
+
+    for (name, format) in [
+        ("bc1", ImageFormat::Bc1Unorm),
+        ("bc3", ImageFormat::Bc3Unorm),
+        ("bc7", ImageFormat::Bc7Unorm),
+    ] {
+        let image = synthetic_texture(TEXTURE_SIZE, TEXTURE_SIZE, format, 0xBC);
+
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || image.clone(),
+                |mut image| {
+                    image
+                        .convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)
+                        .expect("failed to decode image");
+
+                    image
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks block compressing a 4K texture from R8G8B8A8.
+fn bcn_encode(c: &mut Criterion) {
+    let image = synthetic_image(TEXTURE_SIZE, TEXTURE_SIZE);
+
+    let mut group = c.benchmark_group("bcn_encode");
+
+    group.sample_size(10);
+    group.throughput(Throughput::Elements((TEXTURE_SIZE * TEXTURE_SIZE) as u64));
+
+    for (name, format) in [
+        ("bc1", ImageFormat::Bc1Unorm),
+        ("bc3", ImageFormat::Bc3Unorm),
+        ("bc7", ImageFormat::Bc7Unorm),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || image.clone(),
+                |mut image| {
+                    image
+                        .compress(format, ImageCompressionQuality::Normal)
+                        .expect("failed to encode image");
+
+                    image
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks encoding a 4K texture as a run length encoded tga, both with mostly raw packets and with long runs.
+fn tga_rle_encode(c: &mut Criterion) {
+    let gradient = synthetic_image(TEXTURE_SIZE, TEXTURE_SIZE);
+    let mut blocks = gradient.clone();
+
+    // Flatten the gradient into solid blocks so that most of each row is run-length encoded.
+    for (index, pixel) in blocks.frames_mut()[0]
+        .buffer_mut()
+        .chunks_exact_mut(4)
+        .enumerate()
+    {
+        let x = index as u32 % TEXTURE_SIZE;
+        let y = index as u32 / TEXTURE_SIZE;
+
+        pixel.copy_from_slice(&[(x / 64) as u8, (y / 64) as u8, 0x80, 0xFF]);
+    }
+
+    let mut group = c.benchmark_group("tga_rle_encode");
+
+    group.sample_size(10);
+
+    for (name, mut image) in [("4k", gradient), ("4k_runs", blocks)] {
+        image
+            .convert(
+                image.format_for_file_type(ImageFileType::Tga),
+                ImageConvertOptions::None,
+            )
+            .expect("failed to convert image");
+
+        group.throughput(Throughput::Bytes(image.frames()[0].buffer().len() as u64));
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut output = Cursor::new(Vec::new());
+
+                image
+                    .save_to(&mut output, ImageFileType::Tga)
+                    .expect("failed to encode image");
+
+                output
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks writing a 1M vertex model as a cast file.
+fn cast_write(c: &mut Criterion) {
+    let model = synthetic_mesh(MESH_VERTICES);
+    let path = output_directory("bench_cast_write").join("synthetic_mesh.cast");
+
+    let mut group = c.benchmark_group("cast_write");
+
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(MESH_VERTICES as u64));
+
+    group.bench_function("1m_vertices", |b| {
+        b.iter(|| {
+            model
+                .save(&path, ModelFileType::Cast)
+                .expect("failed to write model");
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmarks iterating every vertex of a 1M vertex mesh.
+fn vertex_buffer_iteration(c: &mut Criterion) {
+    let model = synthetic_mesh(MESH_VERTICES);
+    let vertices = &model.meshes[0].vertices;
+
+    let mut group = c.benchmark_group("vertex_buffer_iteration");
+
+    group.throughput(Throughput::Elements(vertices.len() as u64));
+
+    group.bench_function("1m_vertices", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+
+            for index in 0..vertices.len() {
+                let vertex = vertices.vertex(index);
+
+                sum += vertex.position().y + vertex.normal().y + vertex.uv(0).x;
+            }
+
+            black_box(sum)
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmarks creating per material folders, once for every texture, with and without the directory cache.
+fn directory_creation(c: &mut Criterion) {
+    let directory = output_directory("bench_directory_creation");
+
+    let paths: Vec<_> = (0..MATERIAL_FOLDERS)
+        .flat_map(|index| {
+            let material = directory.join(format!("material_{}", index));
+
+            [material.clone(), material.clone(), material]
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("directory_creation");
+
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(paths.len() as u64));
+
+    group.bench_function("create_dir_all", |b| {
+        b.iter(|| {
+            for path in &paths {
+                std::fs::create_dir_all(path).expect("failed to create directory");
+            }
+        })
+    });
+
+    group.bench_function("cached", |b| {
+        b.iter_batched(
+            DirectoryCache::new,
+            |cache| {
+                for path in &paths {
+                    cache.create(path).expect("failed to create directory");
+                }
+
+                cache
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("batched", |b| {
+        b.iter_batched(
+            DirectoryCache::new,
+            |cache| {
+                cache
+                    .create_batch(&paths)
+                    .expect("failed to create directories");
+
+                cache
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bcn_decode,
+    bcn_encode,
+    tga_rle_encode,
+    cast_write,
+    vertex_buffer_iteration,
+    directory_creation
+);
+criterion_main!(benches);
@@ -0,0 +1,257 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use porter_animation::Animation;
+use porter_animation::Curve;
+use porter_animation::CurveAttribute;
+use porter_animation::CurveDataType;
+use porter_animation::Joint;
+
+use porter_audio::Audio;
+use porter_audio::AudioFormat;
+
+use porter_math::Quaternion;
+use porter_math::Vector2;
+use porter_math::Vector3;
+
+use porter_model::Bone;
+use porter_model::Face;
+use porter_model::FaceBuffer;
+use porter_model::Material;
+use porter_model::MaterialTextureRef;
+use porter_model::MaterialTextureRefUsage;
+use porter_model::Mesh;
+use porter_model::Model;
+use porter_model::VertexBuffer;
+use porter_model::VertexColor;
+use porter_model::VertexWeight;
+
+use porter_texture::Image;
+use porter_texture::ImageFormat;
+
+use crate::TestRng;
+
+/// The number of vertices along each side of the synthetic mesh grid.
+const GRID_SIZE: u32 = 3;
+
+/// Builds a small skinned model with two bones, one material, and a grid mesh.
+pub fn synthetic_model() -> Model {
+    let mut model = Model::new();
+
+    model.skeleton.bones.push(
+        Bone::new(Some(String::from("root")), -1)
+            .local_position(Vector3::zero())
+            .local_rotation(Quaternion::identity()),
+    );
+    model.skeleton.bones.push(
+        Bone::new(Some(String::from("child")), 0)
+            .local_position(Vector3::new(0.0, 0.0, 10.0))
+            .local_rotation(Quaternion::new(0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+    );
+    model.skeleton.generate_world_transforms();
+
+    let mut material = Material::new("synthetic_material");
+
+    material.push(MaterialTextureRef::new(
+        "synthetic_color",
+        MaterialTextureRefUsage::Diffuse,
+        "synthetic_color",
+    ));
+    material.push(MaterialTextureRef::new(
+        "synthetic_normal",
+        MaterialTextureRefUsage::Normal,
+        "synthetic_normal",
+    ));
+
+    model.materials.push(material);
+
+    let mut vertices = VertexBuffer::builder()
+        .uv_layers(1)
+        .maximum_influence(2)
+        .colors(1)
+        .build();
+
+    for y in 0..GRID_SIZE {
+        for x in 0..GRID_SIZE {
+            let u = x as f32 / (GRID_SIZE - 1) as f32;
+            let v = y as f32 / (GRID_SIZE - 1) as f32;
+
+            vertices
+                .create()
+                .set_position(Vector3::new(u * 10.0, 0.0, v * 10.0))
+                .set_normal(Vector3::new(0.0, -1.0, 0.0))
+                .set_uv(0, Vector2::new(u, v))
+                .set_color(0, VertexColor::new(255, (u * 255.0) as u8, 0, 255))
+                .set_weight(0, VertexWeight::new(0, 1.0 - v))
+                .set_weight(1, VertexWeight::new(1, v));
+        }
+    }
+
+    let mut faces = FaceBuffer::new();
+
+    for y in 0..GRID_SIZE - 1 {
+        for x in 0..GRID_SIZE - 1 {
+            let i = y * GRID_SIZE + x;
+
+            faces.push(Face::new(i, i + 1, i + GRID_SIZE));
+            faces.push(Face::new(i + 1, i + GRID_SIZE + 1, i + GRID_SIZE));
+        }
+    }
+
+    let mut mesh = Mesh::new(faces, vertices);
+
+    mesh.name = Some(String::from("synthetic_mesh"));
+    mesh.material = Some(0);
+
+    model.meshes.push(mesh);
+
+    model
+}
+
+/// Builds a gradient image with the given size in R8G8B8A8 format.
+pub fn synthetic_image(width: u32, height: u32) -> Image {
+    let mut image =
+        Image::new(width, height, ImageFormat::R8G8B8A8Unorm).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    for (index, pixel) in frame.buffer_mut().chunks_exact_mut(4).enumerate() {
+        let x = index as u32 % width;
+        let y = index as u32 / width;
+
+        pixel[0] = (x * 255 / width.max(2).saturating_sub(1)).min(255) as u8;
+        pixel[1] = (y * 255 / height.max(2).saturating_sub(1)).min(255) as u8;
+        pixel[2] = ((x ^ y) & 0xFF) as u8;
+        pixel[3] = 0xFF - ((x + y) & 0x7F) as u8;
+    }
+
+    image
+}
+
+/// Builds an image array in R8G8B8A8 format, where every byte of each frame is filled with the frame index.
+pub fn synthetic_array(width: u32, height: u32, frames: usize) -> Image {
+    let mut image = synthetic_image(width, height);
+
+    image.frames_mut()[0].buffer_mut().fill(0);
+
+    for index in 1..frames {
+        image
+            .create_frame()
+            .expect("failed to create frame")
+            .buffer_mut()
+            .fill(index as u8);
+    }
+
+    image
+}
+
+/// Builds an image of the given size and format filled with random data, compressed formats get random blocks.
+pub fn synthetic_texture(width: u32, height: u32, format: ImageFormat, seed: u64) -> Image {
+    let mut image = Image::new(width, height, format).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    TestRng::new(seed).fill(frame.buffer_mut());
+
+    image
+}
+
+/// Builds a model with a single grid mesh that has at least the given number of vertices.
+pub fn synthetic_mesh(vertex_count: usize) -> Model {
+    let size = (vertex_count as f64).sqrt().ceil().max(2.0) as u32;
+
+    let mut model = Model::new();
+
+    let mut vertices = VertexBuffer::with_capacity((size * size) as usize)
+        .uv_layers(1)
+        .maximum_influence(1)
+        .build();
+
+    for y in 0..size {
+        for x in 0..size {
+            let u = x as f32 / (size - 1) as f32;
+            let v = y as f32 / (size - 1) as f32;
+
+            vertices
+                .create()
+                .set_position(Vector3::new(u * 100.0, (u * v * 10.0).sin(), v * 100.0))
+                .set_normal(Vector3::new(0.0, 1.0, 0.0))
+                .set_uv(0, Vector2::new(u, v))
+                .set_weight(0, VertexWeight::new(0, 1.0));
+        }
+    }
+
+    let mut faces = FaceBuffer::with_capacity(((size - 1) * (size - 1) * 2) as usize);
+
+    for y in 0..size - 1 {
+        for x in 0..size - 1 {
+            let i = y * size + x;
+
+            faces.push(Face::new(i, i + 1, i + size));
+            faces.push(Face::new(i + 1, i + size + 1, i + size));
+        }
+    }
+
+    model
+        .skeleton
+        .bones
+        .push(Bone::new(Some(String::from("root")), -1));
+    model.meshes.push(Mesh::new(faces, vertices));
+
+    model
+}
+
+/// Builds the joints matching the skeleton of the synthetic model.
+pub fn synthetic_joints() -> Vec<(Option<String>, Joint)> {
+    synthetic_model()
+        .skeleton
+        .bones
+        .iter()
+        .map(|bone| {
+            (
+                bone.name.clone(),
+                Joint::new(bone.parent)
+                    .local_position(bone.local_position)
+                    .local_rotation(bone.local_rotation),
+            )
+        })
+        .collect()
+}
+
+/// Builds a short animation which moves the root and rotates the child bone.
+pub fn synthetic_animation() -> Animation {
+    let mut animation = Animation::new(30.0, true);
+
+    let mut translate = Curve::new("root", CurveAttribute::Translate, CurveDataType::Absolute);
+
+    translate.insert(0, Vector3::zero());
+    translate.insert(5, Vector3::new(0.0, 5.0, 0.0));
+    translate.insert(10, Vector3::new(0.0, 10.0, 0.0));
+
+    let mut rotation = Curve::new("child", CurveAttribute::Rotation, CurveDataType::Absolute);
+
+    rotation.insert(0, Quaternion::identity());
+    rotation.insert(10, Quaternion::new(0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+
+    animation.curves.push(translate);
+    animation.curves.push(rotation);
+
+    animation
+}
+
+/// Builds a short 16-bit stereo pcm stream, with a rising sawtooth on the left channel and a falling one on the right.
+pub fn synthetic_audio() -> Audio {
+    let mut audio =
+        Audio::new(2, 22050, 16, AudioFormat::IntegerPcm).expect("failed to create audio");
+
+    let mut data = Vec::with_capacity(256 * 4);
+
+    for frame in 0..256i32 {
+        let sample = ((frame % 64) * 1024 - 32768) as i16;
+
+        data.extend_from_slice(&sample.to_le_bytes());
+        data.extend_from_slice(&(-1 - sample).to_le_bytes());
+    }
+
+    audio.set_data(data);
+    audio
+}
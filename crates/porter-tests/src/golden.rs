@@ -0,0 +1,88 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The environment variable used to rewrite golden files instead of comparing them.
+pub const UPDATE_GOLDEN_VAR: &str = "PORTER_UPDATE_GOLDEN";
+
+/// Creates an empty output directory for the given test.
+pub fn output_directory<N: AsRef<str>>(name: N) -> PathBuf {
+    let directory = std::env::temp_dir()
+        .join("porter-tests")
+        .join(name.as_ref());
+
+    let _ = fs::remove_dir_all(&directory);
+
+    fs::create_dir_all(&directory).expect("failed to create test output directory");
+
+    directory
+}
+
+/// Compares every file in the directory against the golden files with the given name.
+pub fn assert_golden_directory<N: AsRef<str>, P: AsRef<Path>>(name: N, directory: P) {
+    let mut files: Vec<PathBuf> = fs::read_dir(directory.as_ref())
+        .expect("failed to read test output directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect();
+
+    files.sort();
+
+    assert!(
+        !files.is_empty(),
+        "no files were written for {}",
+        name.as_ref()
+    );
+
+    for file in files {
+        let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+        let actual = fs::read(&file).expect("failed to read test output file");
+
+        assert_golden(format!("{}/{}", name.as_ref(), file_name), &actual);
+    }
+}
+
+/// Compares the buffer against the golden file with the given name.
+pub fn assert_golden<N: AsRef<str>>(name: N, actual: &[u8]) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("golden")
+        .join(name.as_ref());
+
+    if std::env::var_os(UPDATE_GOLDEN_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create golden directory");
+        }
+
+        fs::write(&path, actual).expect("failed to write golden file");
+        return;
+    }
+
+    let Ok(expected) = fs::read(&path) else {
+        panic!(
+            "missing golden file {}, run with {}=1 to create it",
+            path.display(),
+            UPDATE_GOLDEN_VAR
+        );
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let offset = expected
+        .iter()
+        .zip(actual)
+        .position(|(expected, actual)| expected != actual)
+        .unwrap_or(expected.len().min(actual.len()));
+
+    let line = expected[..offset].iter().filter(|x| **x == b'\n').count() + 1;
+
+    panic!(
+        "{} differs from golden at byte {} (line {}), expected {} bytes, got {} bytes",
+        name.as_ref(),
+        offset,
+        line,
+        expected.len(),
+        actual.len()
+    );
+}
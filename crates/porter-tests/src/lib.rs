@@ -0,0 +1,9 @@
+#![deny(unsafe_code)]
+
+mod fixtures;
+mod golden;
+mod test_rng;
+
+pub use fixtures::*;
+pub use golden::*;
+pub use test_rng::*;
@@ -0,0 +1,36 @@
+/// A small deterministic xorshift random number generator, used to generate fuzz style inputs.
+#[derive(Debug, Clone, Copy)]
+pub struct TestRng {
+    state: u64,
+}
+
+impl TestRng {
+    /// Constructs a new random number generator with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    /// Generates the next random 64bit value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Generates a random value in the given inclusive range.
+    pub fn range(&mut self, min: u32, max: u32) -> u32 {
+        debug_assert!(min <= max);
+
+        min + (self.next_u64() % (max - min + 1) as u64) as u32
+    }
+
+    /// Fills the buffer with random bytes.
+    pub fn fill(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(8) {
+            let value = self.next_u64().to_le_bytes();
+
+            chunk.copy_from_slice(&value[..chunk.len()]);
+        }
+    }
+}
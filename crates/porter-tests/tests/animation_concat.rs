@@ -0,0 +1,46 @@
+use porter_animation::Animation;
+use porter_animation::CurveAttribute;
+
+use porter_tests::synthetic_animation;
+
+#[test]
+fn concat_appends_clips_to_the_timeline() {
+    let mut animation = Animation::new(30.0, false);
+
+    animation.concat("intro", synthetic_animation());
+    animation.concat("outro", synthetic_animation());
+
+    // Each clip is 11 frames long, so the second one starts right after the first.
+    assert_eq!(animation.frame_count(), 22);
+    assert_eq!(animation.notification_count(), 2);
+
+    let translate = animation
+        .find("root", CurveAttribute::Translate)
+        .expect("missing curve");
+
+    let times: Vec<u32> = translate.iter().map(|keyframe| keyframe.time).collect();
+
+    assert_eq!(times, [0, 5, 10, 11, 16, 21]);
+
+    let outro = animation
+        .find("outro", CurveAttribute::Notetrack)
+        .expect("missing marker");
+
+    assert_eq!(outro.keyframe(0).map(|keyframe| keyframe.time), Some(11));
+}
+
+#[test]
+fn concat_resamples_clips_with_a_different_framerate() {
+    let mut animation = synthetic_animation();
+    let mut slow = synthetic_animation();
+
+    slow.framerate = 15.0;
+
+    animation.concat("slow", slow);
+
+    let rotation = animation
+        .find("child", CurveAttribute::Rotation)
+        .expect("missing curve");
+
+    assert_eq!(rotation.keyframe(3).map(|keyframe| keyframe.time), Some(31));
+}
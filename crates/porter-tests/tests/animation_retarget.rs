@@ -0,0 +1,179 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use porter_animation::Animation;
+use porter_animation::BoneMap;
+use porter_animation::Curve;
+use porter_animation::CurveAttribute;
+use porter_animation::CurveDataType;
+use porter_animation::CurveModeOverride;
+use porter_animation::KeyframeValue;
+
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_model::Bone;
+use porter_model::Skeleton;
+
+use porter_tests::synthetic_animation;
+use porter_tests::synthetic_model;
+
+/// Builds a target skeleton with the given bone names, a longer child bone, and no rest rotation.
+fn target_skeleton(root: &str, child: &str) -> Skeleton {
+    let mut skeleton = Skeleton::new();
+
+    skeleton.bones.push(
+        Bone::new(Some(String::from(root)), -1)
+            .local_position(Vector3::zero())
+            .local_rotation(Quaternion::identity()),
+    );
+    skeleton.bones.push(
+        Bone::new(Some(String::from(child)), 0)
+            .local_position(Vector3::new(0.0, 0.0, 20.0))
+            .local_rotation(Quaternion::identity()),
+    );
+
+    skeleton
+}
+
+#[test]
+fn retarget_matches_bones_by_name_ignoring_case_and_namespaces() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("ROOT", "Child");
+
+    let mut animation = synthetic_animation();
+    let mut scale = Curve::new("rig:root", CurveAttribute::Scale, CurveDataType::Absolute);
+
+    scale.insert(0, Vector3::one());
+
+    animation.curves.push(scale);
+
+    let animation = animation.retarget(&source, &target, &BoneMap::new());
+
+    assert!(animation.find("ROOT", CurveAttribute::Translate).is_some());
+    assert!(animation.find("ROOT", CurveAttribute::Scale).is_some());
+    assert!(animation.find("Child", CurveAttribute::Rotation).is_some());
+    assert_eq!(animation.frame_count(), 11);
+}
+
+#[test]
+fn retarget_corrects_for_the_rest_pose() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("root", "child");
+
+    let animation = synthetic_animation().retarget(&source, &target, &BoneMap::new());
+
+    let rotation = animation
+        .find("child", CurveAttribute::Rotation)
+        .expect("missing curve");
+
+    // The source rests rotated 90 degrees, so the rest pose maps to the target's identity rest pose.
+    assert_eq!(
+        rotation.keyframe(1).map(|keyframe| keyframe.value),
+        Some(KeyframeValue::Quaternion(Quaternion::identity()))
+    );
+    assert_eq!(
+        rotation.keyframe(0).map(|keyframe| keyframe.value),
+        Some(KeyframeValue::Quaternion(Quaternion::new(
+            0.0,
+            0.0,
+            -FRAC_1_SQRT_2,
+            FRAC_1_SQRT_2
+        )))
+    );
+}
+
+#[test]
+fn retarget_scales_translations_by_bone_length() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("root", "child");
+
+    let mut animation = Animation::new(30.0, false);
+    let mut translate = Curve::new("child", CurveAttribute::Translate, CurveDataType::Absolute);
+
+    translate.insert(0, Vector3::new(0.0, 0.0, 15.0));
+
+    animation.curves.push(translate);
+
+    let animation = animation.retarget(&source, &target, &BoneMap::new());
+
+    let translate = animation
+        .find("child", CurveAttribute::Translate)
+        .expect("missing curve");
+
+    // The target bone is twice as long, so the offset from the rest pose doubles.
+    assert_eq!(
+        translate.keyframe(0).map(|keyframe| keyframe.value),
+        Some(KeyframeValue::Vector3(Vector3::new(0.0, 0.0, 30.0)))
+    );
+}
+
+#[test]
+fn retarget_uses_the_bone_map_and_drops_unmatched_curves() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("pelvis", "spine");
+
+    let map = BoneMap::new().map("root", "pelvis");
+
+    let animation = synthetic_animation().retarget(&source, &target, &map);
+
+    assert!(
+        animation
+            .find("pelvis", CurveAttribute::Translate)
+            .is_some()
+    );
+    assert!(animation.find("child", CurveAttribute::Rotation).is_none());
+    assert!(animation.find("spine", CurveAttribute::Rotation).is_none());
+    assert_eq!(animation.curves.len(), 1);
+}
+
+#[test]
+fn retarget_skips_rest_pose_correction_for_overridden_bones() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("root", "child");
+
+    let mut animation = synthetic_animation();
+
+    // The override on the root applies to the child bone as well.
+    animation
+        .curve_mode_overrides
+        .push(CurveModeOverride::new("root", CurveDataType::Relative).override_rotation(true));
+
+    let animation = animation.retarget(&source, &target, &BoneMap::new());
+
+    let rotation = animation
+        .find("child", CurveAttribute::Rotation)
+        .expect("missing curve");
+
+    assert_eq!(
+        rotation.keyframe(0).map(|keyframe| keyframe.value),
+        Some(KeyframeValue::Quaternion(Quaternion::identity()))
+    );
+    assert_eq!(animation.curve_mode_overrides.len(), 1);
+}
+
+#[test]
+fn retarget_keeps_the_closest_match_for_duplicate_targets() {
+    let source = synthetic_model().skeleton;
+    let target = target_skeleton("root", "child");
+
+    let mut animation = Animation::new(30.0, false);
+
+    for (name, z) in [("rig:child", 1.0), ("child", 2.0)] {
+        let mut scale = Curve::new(name, CurveAttribute::Scale, CurveDataType::Relative);
+
+        scale.insert(0, Vector3::new(1.0, 1.0, z));
+
+        animation.curves.push(scale);
+    }
+
+    let animation = animation.retarget(&source, &target, &BoneMap::new());
+
+    assert_eq!(animation.curves.len(), 1);
+    assert_eq!(
+        animation
+            .find("child", CurveAttribute::Scale)
+            .and_then(|curve| curve.keyframe(0))
+            .map(|keyframe| keyframe.value),
+        Some(KeyframeValue::Vector3(Vector3::new(1.0, 1.0, 2.0)))
+    );
+}
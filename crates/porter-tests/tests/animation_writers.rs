@@ -0,0 +1,68 @@
+use std::fs;
+
+use porter_animation::AnimationFileType;
+use porter_animation::Joint;
+
+use porter_tests::assert_golden_directory;
+use porter_tests::output_directory;
+use porter_tests::synthetic_animation;
+use porter_tests::synthetic_joints;
+
+#[test]
+fn animation_cast() {
+    let directory = output_directory("animation_cast");
+
+    synthetic_animation()
+        .save(
+            directory.join("synthetic_animation.cast"),
+            AnimationFileType::Cast,
+        )
+        .expect("failed to save animation");
+
+    assert_golden_directory("animation/cast", directory);
+}
+
+#[test]
+fn animation_bvh() {
+    let directory = output_directory("animation_bvh");
+
+    synthetic_animation()
+        .save_bvh(directory.join("synthetic_animation"), &synthetic_joints())
+        .expect("failed to save animation");
+
+    assert_golden_directory("animation/bvh", directory);
+}
+
+#[test]
+fn animation_bvh_joins_multiple_roots() {
+    let directory = output_directory("animation_bvh_roots");
+
+    let mut joints = synthetic_joints();
+
+    joints.push((Some(String::from("prop")), Joint::new(-1)));
+
+    synthetic_animation()
+        .save_bvh(directory.join("synthetic_animation"), &joints)
+        .expect("failed to save animation");
+
+    let bvh = fs::read_to_string(directory.join("synthetic_animation.bvh"))
+        .expect("failed to read animation");
+
+    let roots: Vec<&str> = bvh
+        .lines()
+        .filter(|line| line.trim_start().starts_with("ROOT "))
+        .collect();
+
+    assert_eq!(roots, vec!["ROOT porter_root"]);
+    assert!(bvh.contains("\tJOINT root\n"));
+    assert!(bvh.contains("\tJOINT prop\n"));
+
+    let (_, motion) = bvh.split_once("MOTION").expect("missing motion");
+
+    for frame in motion.lines().skip(3) {
+        let values: Vec<&str> = frame.split(' ').collect();
+
+        assert_eq!(values.len(), (joints.len() + 1) * 6);
+        assert!(values[..6].iter().all(|value| *value == "0.000000"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::io::Cursor;
+
+use porter_audio::Audio;
+use porter_audio::AudioError;
+use porter_audio::AudioFileType;
+use porter_audio::AudioFormat;
+
+/// Builds a 16-bit pcm segment where every sample is the given value.
+fn synthetic_segment(channels: u32, sample_rate: u32, frames: usize, value: i16) -> Audio {
+    let mut audio = Audio::new(channels, sample_rate, 16, AudioFormat::IntegerPcm)
+        .expect("failed to create audio");
+
+    audio.set_data(
+        std::iter::repeat_n(value.to_le_bytes(), frames * channels as usize)
+            .flatten()
+            .collect(),
+    );
+
+    audio
+}
+
+#[test]
+fn concat_joins_segments_in_order() {
+    let segments = [
+        synthetic_segment(2, 48000, 48000, 1),
+        synthetic_segment(2, 48000, 24000, 2),
+        synthetic_segment(2, 48000, 24000, 3),
+    ];
+
+    let audio = Audio::concat(&segments).expect("failed to concat audio");
+
+    assert_eq!(audio.info().frame_count, Some(96000));
+    assert_eq!(audio.duration().expect("no duration").as_millis(), 2000);
+
+    let samples: Vec<i16> = audio
+        .data()
+        .chunks_exact(2)
+        .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+        .collect();
+
+    assert_eq!(samples[0], 1);
+    assert_eq!(samples[48000 * 2], 2);
+    assert_eq!(samples[72000 * 2], 3);
+}
+
+#[test]
+fn concat_rejects_mismatched_segments() {
+    let segments = [
+        synthetic_segment(2, 48000, 100, 0),
+        synthetic_segment(2, 48000, 100, 0),
+        synthetic_segment(2, 44100, 100, 0),
+    ];
+
+    assert!(matches!(
+        Audio::concat(&segments),
+        Err(AudioError::MismatchedAudioSegment(2))
+    ));
+
+    let segments = [
+        synthetic_segment(2, 48000, 100, 0),
+        synthetic_segment(1, 48000, 100, 0),
+    ];
+
+    assert!(matches!(
+        Audio::concat(&segments),
+        Err(AudioError::MismatchedAudioSegment(1))
+    ));
+}
+
+#[test]
+fn concat_rejects_no_segments() {
+    assert!(matches!(
+        Audio::concat(&[]),
+        Err(AudioError::NoAudioSegments)
+    ));
+}
+
+#[test]
+fn wav_probe_matches_loaded_audio() {
+    let audio = synthetic_segment(2, 44100, 1000, 7);
+
+    let mut output = Cursor::new(Vec::new());
+
+    audio
+        .save_to(&mut output, AudioFileType::Wav)
+        .expect("failed to save wav");
+
+    output.set_position(0);
+
+    let info = Audio::probe_from(&mut output, AudioFileType::Wav).expect("failed to probe wav");
+
+    output.set_position(0);
+
+    let loaded = Audio::load_from(&mut output, AudioFileType::Wav).expect("failed to load wav");
+
+    assert_eq!(info.frame_count, Some(1000));
+    assert_eq!(info, loaded.info());
+    assert_eq!(loaded.data(), audio.data());
+}
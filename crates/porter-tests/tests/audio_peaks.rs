@@ -0,0 +1,83 @@
+use porter_audio::Audio;
+use porter_audio::AudioFormat;
+use porter_audio::AudioPeaks;
+
+/// Builds a 16-bit stereo pcm sound with a deterministic pattern of samples.
+fn synthetic_sound(frames: usize) -> (Audio, Vec<f32>) {
+    let mut audio =
+        Audio::new(2, 48000, 16, AudioFormat::IntegerPcm).expect("failed to create audio");
+
+    let samples: Vec<i16> = (0..frames * 2)
+        .map(|index| ((index as u64 * 2654435761) % 65535) as i16)
+        .collect();
+
+    audio.set_data(
+        samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect(),
+    );
+
+    let samples = samples
+        .iter()
+        .map(|sample| *sample as f32 / i16::MAX as f32)
+        .collect();
+
+    (audio, samples)
+}
+
+/// Scans every sample in the given range of frames for the minimum and maximum.
+fn scan(samples: &[f32], start: u64, end: u64) -> (f32, f32) {
+    samples[start as usize * 2..end as usize * 2]
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), sample| {
+            (min.min(*sample), max.max(*sample))
+        })
+}
+
+#[test]
+fn peaks_cover_every_frame() {
+    let (audio, samples) = synthetic_sound(10_000);
+
+    let peaks = AudioPeaks::from_audio(&audio).expect("failed to compute peaks");
+
+    assert_eq!(peaks.frames(), 10_000);
+    assert_eq!(peaks.peak(0, 10_000), scan(&samples, 0, 10_000));
+}
+
+#[test]
+fn peaks_contain_the_scanned_range() {
+    let (audio, samples) = synthetic_sound(10_000);
+
+    let peaks = AudioPeaks::from_audio(&audio).expect("failed to compute peaks");
+
+    for (start, end) in [(0, 1), (63, 65), (100, 900), (1024, 4096), (5000, 10_000)] {
+        let (min, max) = peaks.peak(start, end);
+        let (scan_min, scan_max) = scan(&samples, start, end);
+
+        assert!(min <= scan_min);
+        assert!(max >= scan_max);
+    }
+}
+
+#[test]
+fn peaks_columns_match_width() {
+    let (audio, _) = synthetic_sound(10_000);
+
+    let peaks = AudioPeaks::from_audio(&audio).expect("failed to compute peaks");
+
+    let columns = peaks.columns(0, peaks.frames(), 300);
+
+    assert_eq!(columns.len(), 300);
+    assert!(columns.iter().all(|(min, max)| min <= max));
+}
+
+#[test]
+fn peaks_empty_audio() {
+    let audio = Audio::new(1, 48000, 16, AudioFormat::IntegerPcm).expect("failed to create audio");
+
+    let peaks = AudioPeaks::from_audio(&audio).expect("failed to compute peaks");
+
+    assert!(peaks.is_empty());
+    assert_eq!(peaks.peak(0, 100), (0.0, 0.0));
+}
@@ -0,0 +1,29 @@
+use porter_audio::AudioFileType;
+
+use porter_tests::assert_golden_directory;
+use porter_tests::output_directory;
+use porter_tests::synthetic_audio;
+
+/// Saves the synthetic audio in the given format and compares the output with the golden files.
+fn check_audio_writer(name: &str, file_type: AudioFileType) {
+    let directory = output_directory(format!("audio_{name}"));
+
+    synthetic_audio()
+        .save(
+            directory.join("synthetic_audio").with_extension(file_type),
+            file_type,
+        )
+        .expect("failed to save audio");
+
+    assert_golden_directory(format!("audio/{name}"), directory);
+}
+
+#[test]
+fn audio_wav() {
+    check_audio_writer("wav", AudioFileType::Wav);
+}
+
+#[test]
+fn audio_flac() {
+    check_audio_writer("flac", AudioFileType::Flac);
+}
@@ -0,0 +1,106 @@
+use std::io::Cursor;
+
+use porter_utils::BitfieldExt;
+use porter_utils::ByteSwap;
+use porter_utils::SeekExt;
+use porter_utils::StructReadExt;
+use porter_utils::StructWriteExt;
+
+#[test]
+fn read_explicit_endianness() {
+    let mut reader = Cursor::new([
+        0x01, 0x02, 0x03, 0x04, 0x01, 0x02, 0x03, 0x04, 0x3F, 0x80, 0x00, 0x00,
+    ]);
+
+    assert_eq!(reader.read_le::<u32>().unwrap(), 0x04030201);
+    assert_eq!(reader.read_be::<u32>().unwrap(), 0x01020304);
+    assert_eq!(reader.read_be::<f32>().unwrap(), 1.0);
+    assert!(reader.read_le::<u8>().is_err());
+}
+
+#[test]
+fn write_explicit_endianness_round_trips() {
+    let mut writer = Cursor::new(Vec::new());
+
+    writer.write_be(0x1234u16).unwrap();
+    writer.write_le(-2i64).unwrap();
+    writer.write_be([1.5f32, -0.25]).unwrap();
+
+    assert_eq!(&writer.get_ref()[0..2], &[0x12, 0x34]);
+
+    writer.set_position(0);
+
+    assert_eq!(writer.read_be::<u16>().unwrap(), 0x1234);
+    assert_eq!(writer.read_le::<i64>().unwrap(), -2);
+    assert_eq!(writer.read_be::<[f32; 2]>().unwrap(), [1.5, -0.25]);
+}
+
+#[test]
+fn byte_swap_values() {
+    assert_eq!(ByteSwap::swap_bytes(0x11223344u32), 0x44332211);
+    assert_eq!(ByteSwap::swap_bytes([0x0102u16, 0x0304]), [0x0201, 0x0403]);
+    assert_eq!(ByteSwap::swap_bytes(ByteSwap::swap_bytes(3.25f64)), 3.25);
+}
+
+#[test]
+fn bitfields_extract_and_replace() {
+    let value: u32 = 0b1011_0110_0000_1111;
+
+    assert_eq!(value.bits(0, 4), 0b1111);
+    assert_eq!(value.bits(8, 8), 0b1011_0110);
+    assert_eq!(value.bits(0, 32), value);
+    assert_eq!(value.bits(4, 0), 0);
+    assert!(value.bit(15));
+    assert!(!value.bit(4));
+
+    assert_eq!(value.with_bits(4, 4, 0xF), 0b1011_0110_1111_1111);
+    assert_eq!(0u8.with_bits(6, 2, 0xFF), 0b1100_0000);
+
+    assert_eq!(0b1110u8.signed_bits(1, 3), -1);
+    assert_eq!(0b0110u8.signed_bits(1, 3), 3);
+    assert_eq!(u64::MAX.signed_bits(0, 64), -1);
+}
+
+#[test]
+fn signed_var_integers_round_trip() {
+    let values = [0, 1, -1, 63, -64, 64, i64::MAX, i64::MIN];
+
+    let mut writer = Cursor::new(Vec::new());
+
+    for value in values {
+        writer.write_var_signed_integer(value).unwrap();
+    }
+
+    // Small magnitudes fit in a single byte.
+    assert_eq!(&writer.get_ref()[0..5], &[0, 2, 1, 126, 127]);
+
+    writer.set_position(0);
+
+    for value in values {
+        assert_eq!(writer.read_var_signed_integer().unwrap(), value);
+    }
+}
+
+#[test]
+fn var_integers_reject_overlong_encodings() {
+    let mut reader = Cursor::new([0xFF; 11]);
+
+    assert!(reader.read_var_integer().is_err());
+}
+
+#[test]
+fn align_and_pad_positions() {
+    let mut stream = Cursor::new(Vec::new());
+
+    stream.write_struct(0xAAu8).unwrap();
+
+    assert_eq!(stream.pad_position(8).unwrap(), 8);
+    assert_eq!(stream.get_ref(), &[0xAA, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(stream.pad_position(8).unwrap(), 8);
+
+    stream.set_position(5);
+
+    assert_eq!(stream.align_position_from(2, 4).unwrap(), 6);
+    assert_eq!(stream.align_position(4).unwrap(), 8);
+    assert!(stream.align_position_from(9, 4).is_err());
+}
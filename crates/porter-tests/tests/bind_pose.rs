@@ -0,0 +1,57 @@
+use porter_math::Matrix4x4;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_tests::synthetic_model;
+
+/// The tolerance used when comparing transformed positions.
+const EPSILON: f32 = 1e-4;
+
+/// Asserts that two vectors are equal within the tolerance.
+fn assert_near(left: Vector3, right: Vector3) {
+    assert!(
+        (left - right).length() < EPSILON,
+        "{left:?} is not near {right:?}"
+    );
+}
+
+#[test]
+fn inverse_bind_matrices_reference_skin() {
+    let model = synthetic_model();
+
+    let inverse_bind_matrices = model.skeleton.inverse_bind_matrices();
+
+    assert_eq!(inverse_bind_matrices.len(), model.skeleton.bones.len());
+
+    for (bone, inverse_bind_matrix) in model.skeleton.bones.iter().zip(&inverse_bind_matrices) {
+        assert_near(
+            bone.world_position.transform(inverse_bind_matrix),
+            Vector3::zero(),
+        );
+    }
+
+    // The child bone sits at (0, 0, 10) rotated 90 degrees around z, so a point one unit along
+    // the world y axis from it lands on the bone's x axis.
+    assert_near(
+        Vector3::new(0.0, 1.0, 10.0).transform(&inverse_bind_matrices[1]),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn inverse_bind_matrices_use_bind_override() {
+    let mut model = synthetic_model();
+
+    let bind_matrix = Matrix4x4::create_position(Vector3::new(5.0, 0.0, 0.0))
+        * Matrix4x4::create_rotation(Quaternion::identity());
+
+    model.skeleton.bones[0].bind_matrix = Some(bind_matrix);
+    model.skeleton.scale(2.0);
+
+    let inverse_bind_matrices = model.skeleton.inverse_bind_matrices();
+
+    assert_near(
+        Vector3::new(10.0, 0.0, 0.0).transform(&inverse_bind_matrices[0]),
+        Vector3::zero(),
+    );
+}
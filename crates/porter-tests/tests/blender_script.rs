@@ -0,0 +1,48 @@
+use porter_model::ModelFileType;
+
+use porter_utils::AsPythonString;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+#[test]
+fn python_string_escapes_special_characters() {
+    assert_eq!("plain".as_python_string(), "\"plain\"");
+    assert_eq!(
+        "C:\\models\\\"gun\"\n".as_python_string(),
+        "\"C:\\\\models\\\\\\\"gun\\\"\\n\""
+    );
+}
+
+#[test]
+fn blender_script_imports_first_supported_format() {
+    let directory = output_directory("blender_script_first_supported");
+    let path = directory.join("synthetic_model");
+
+    synthetic_model()
+        .save_blender_script(
+            &path,
+            &[ModelFileType::Maya, ModelFileType::Cast, ModelFileType::Obj],
+            1.0,
+        )
+        .expect("failed to save blender script");
+
+    let script =
+        std::fs::read_to_string(path.with_extension("py")).expect("failed to read blender script");
+
+    assert!(script.contains("\"synthetic_model.cast\""));
+    assert!(script.contains("bpy.ops.import_scene.cast"));
+    assert!(!script.contains("bpy.ops.wm.obj_import"));
+}
+
+#[test]
+fn blender_script_skips_unsupported_formats() {
+    let directory = output_directory("blender_script_unsupported");
+    let path = directory.join("synthetic_model");
+
+    synthetic_model()
+        .save_blender_script(&path, &[ModelFileType::Maya, ModelFileType::XnaLara], 1.0)
+        .expect("failed to save blender script");
+
+    assert!(!path.with_extension("py").exists());
+}
@@ -0,0 +1,349 @@
+use porter_texture::Image;
+use porter_texture::ImageCompressionQuality;
+use porter_texture::ImageFormat;
+use porter_texture::MipmapFilter;
+use porter_texture::TextureError;
+
+use porter_tests::synthetic_image;
+
+/// Decodes a compressed block to rgba pixels.
+type BlockDecoder = fn(&[u8]) -> [[u8; 4]; 16];
+
+/// Expands a 565 color to 8 bits per channel.
+fn expand_565(color: u16) -> [u32; 3] {
+    let r = ((color >> 11) & 0x1F) as u32;
+    let g = ((color >> 5) & 0x3F) as u32;
+    let b = (color & 0x1F) as u32;
+
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+    ]
+}
+
+/// Reference decoder for a bc1 color block, bc3 color blocks always use four colors.
+fn decode_bc1(block: &[u8], four_color: bool) -> [[u8; 4]; 16] {
+    let color0 = u16::from_le_bytes([block[0], block[1]]);
+    let color1 = u16::from_le_bytes([block[2], block[3]]);
+    let bits = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+
+    let (c0, c1) = (expand_565(color0), expand_565(color1));
+
+    let palette: [[u32; 4]; 4] = if four_color || color0 > color1 {
+        [
+            [c0[0], c0[1], c0[2], 255],
+            [c1[0], c1[1], c1[2], 255],
+            std::array::from_fn(|i| if i == 3 { 255 } else { (2 * c0[i] + c1[i]) / 3 }),
+            std::array::from_fn(|i| if i == 3 { 255 } else { (c0[i] + 2 * c1[i]) / 3 }),
+        ]
+    } else {
+        [
+            [c0[0], c0[1], c0[2], 255],
+            [c1[0], c1[1], c1[2], 255],
+            std::array::from_fn(|i| if i == 3 { 255 } else { (c0[i] + c1[i]) / 2 }),
+            [0, 0, 0, 0],
+        ]
+    };
+
+    std::array::from_fn(|index| palette[(bits >> (index * 2)) as usize & 3].map(|v| v as u8))
+}
+
+/// Reference decoder for a bc4 block.
+fn decode_bc4(block: &[u8]) -> [u8; 16] {
+    let (a, b) = (block[0] as u32, block[1] as u32);
+
+    let mut bits = [0u8; 8];
+
+    bits[0..6].copy_from_slice(&block[2..8]);
+
+    let bits = u64::from_le_bytes(bits);
+
+    std::array::from_fn(|index| {
+        let code = ((bits >> (index * 3)) & 7) as u32;
+
+        (match code {
+            0 => a,
+            1 => b,
+            code if a > b => ((8 - code) * a + (code - 1) * b) / 7,
+            6 => 0,
+            7 => 255,
+            code => ((6 - code) * a + (code - 1) * b) / 5,
+        }) as u8
+    })
+}
+
+/// Reference decoder for a bc7 mode 6 block.
+fn decode_bc7_mode6(block: &[u8]) -> [[u8; 4]; 16] {
+    const WEIGHTS: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+    let bits = u128::from_le_bytes(block.try_into().expect("invalid block"));
+
+    assert_eq!(bits & 0x7F, 1 << 6, "block isn't mode 6");
+
+    let mut position = 7;
+
+    let mut read = |count: u32| {
+        let value = ((bits >> position) & ((1 << count) - 1)) as u32;
+
+        position += count;
+        value
+    };
+
+    let mut e0 = [0u32; 4];
+    let mut e1 = [0u32; 4];
+
+    for (start, end) in e0.iter_mut().zip(e1.iter_mut()) {
+        *start = read(7);
+        *end = read(7);
+    }
+
+    let (pbit0, pbit1) = (read(1), read(1));
+
+    let e0 = e0.map(|value| (value << 1) | pbit0);
+    let e1 = e1.map(|value| (value << 1) | pbit1);
+
+    std::array::from_fn(|index| {
+        let weight = WEIGHTS[read(if index == 0 { 3 } else { 4 }) as usize];
+
+        std::array::from_fn(|i| (((64 - weight) * e0[i] + weight * e1[i] + 32) >> 6) as u8)
+    })
+}
+
+/// Decodes the first mip of the first frame of a compressed image to rgba pixels, in row order.
+fn decode(image: &Image) -> Vec<[u8; 4]> {
+    let blocks_wide = image.width().div_ceil(4) as usize;
+    let blocks_high = image.height().div_ceil(4) as usize;
+
+    let (block_size, decode_block): (usize, BlockDecoder) = match image.format() {
+        ImageFormat::Bc1Unorm => (8, |block| decode_bc1(block, false)),
+        ImageFormat::Bc3Unorm => (16, |block| {
+            let alpha = decode_bc4(&block[0..8]);
+            let mut color = decode_bc1(&block[8..16], true);
+
+            for (pixel, alpha) in color.iter_mut().zip(alpha) {
+                pixel[3] = alpha;
+            }
+
+            color
+        }),
+        ImageFormat::Bc4Unorm => (8, |block| decode_bc4(block).map(|r| [r, 0, 0, 255])),
+        ImageFormat::Bc5Unorm => (16, |block| {
+            let red = decode_bc4(&block[0..8]);
+            let green = decode_bc4(&block[8..16]);
+
+            std::array::from_fn(|index| [red[index], green[index], 0, 255])
+        }),
+        ImageFormat::Bc7Unorm => (16, decode_bc7_mode6),
+        format => panic!("no reference decoder for {format:?}"),
+    };
+
+    let buffer = image.frames()[0].buffer();
+
+    let mut pixels = vec![[0; 4]; image.width() as usize * image.height() as usize];
+
+    for block_y in 0..blocks_high {
+        for block_x in 0..blocks_wide {
+            let offset = (block_y * blocks_wide + block_x) * block_size;
+            let decoded = decode_block(&buffer[offset..offset + block_size]);
+
+            for (index, pixel) in decoded.iter().enumerate() {
+                let x = block_x * 4 + index % 4;
+                let y = block_y * 4 + index / 4;
+
+                if x < image.width() as usize && y < image.height() as usize {
+                    pixels[y * image.width() as usize + x] = *pixel;
+                }
+            }
+        }
+    }
+
+    pixels
+}
+
+/// The mean absolute error of the given channels between the source image and the decoded pixels.
+fn mean_error(source: &Image, decoded: &[[u8; 4]], channels: &[usize]) -> f32 {
+    let total: u32 = source.frames()[0]
+        .buffer()
+        .chunks_exact(4)
+        .zip(decoded)
+        .map(|(source, decoded)| {
+            channels
+                .iter()
+                .map(|channel| source[*channel].abs_diff(decoded[*channel]) as u32)
+                .sum::<u32>()
+        })
+        .sum();
+
+    total as f32 / (decoded.len() * channels.len()) as f32
+}
+
+/// Compresses a copy of the image, returning it.
+fn compress(image: &Image, format: ImageFormat, quality: ImageCompressionQuality) -> Image {
+    let mut compressed = image.clone();
+
+    compressed
+        .compress(format, quality)
+        .expect("failed to compress image");
+
+    assert_eq!(compressed.format(), format);
+    assert_eq!(
+        compressed.frames()[0].buffer().len() as u32,
+        compressed.frame_size(image.width(), image.height())
+    );
+
+    compressed
+}
+
+#[test]
+fn bc1_compresses_color() {
+    let image = synthetic_image(32, 32);
+
+    for quality in [
+        ImageCompressionQuality::Fast,
+        ImageCompressionQuality::Normal,
+        ImageCompressionQuality::High,
+    ] {
+        let decoded = decode(&compress(&image, ImageFormat::Bc1Unorm, quality));
+
+        let error = mean_error(&image, &decoded, &[0, 1, 2]);
+
+        assert!(error < 12.0, "{quality:?} bc1 error {error} is too high");
+    }
+}
+
+#[test]
+fn bc1_uses_punch_through_alpha() {
+    let mut image = synthetic_image(8, 8);
+
+    for (index, pixel) in image.frames_mut()[0]
+        .buffer_mut()
+        .chunks_exact_mut(4)
+        .enumerate()
+    {
+        pixel[3] = if index % 3 == 0 { 0 } else { 0xFF };
+    }
+
+    let decoded = decode(&compress(
+        &image,
+        ImageFormat::Bc1Unorm,
+        ImageCompressionQuality::Normal,
+    ));
+
+    for (index, pixel) in decoded.iter().enumerate() {
+        assert_eq!(pixel[3] == 0, index % 3 == 0);
+    }
+}
+
+#[test]
+fn bc3_compresses_alpha() {
+    let image = synthetic_image(16, 16);
+
+    let decoded = decode(&compress(
+        &image,
+        ImageFormat::Bc3Unorm,
+        ImageCompressionQuality::Normal,
+    ));
+
+    assert!(mean_error(&image, &decoded, &[3]) < 2.0);
+    assert!(mean_error(&image, &decoded, &[0, 1, 2]) < 12.0);
+}
+
+#[test]
+fn bc4_and_bc5_compress_grayscale_channels() {
+    let mut image = Image::new(12, 12, ImageFormat::R8G8Unorm).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    for (index, pixel) in frame.buffer_mut().chunks_exact_mut(2).enumerate() {
+        let (x, y) = (index % 12, index / 12);
+
+        pixel[0] = ((x + y) * 10) as u8;
+        pixel[1] = if index % 2 == 0 { 0 } else { 255 };
+    }
+
+    let rgba: Vec<u8> = image.frames()[0]
+        .buffer()
+        .chunks_exact(2)
+        .flat_map(|pixel| [pixel[0], pixel[1], 0, 255])
+        .collect();
+
+    let mut reference =
+        Image::new(12, 12, ImageFormat::R8G8B8A8Unorm).expect("failed to create image");
+
+    reference
+        .create_frame()
+        .expect("failed to create frame")
+        .buffer_mut()
+        .copy_from_slice(&rgba);
+
+    let bc5 = decode(&compress(
+        &image,
+        ImageFormat::Bc5Unorm,
+        ImageCompressionQuality::High,
+    ));
+
+    assert!(mean_error(&reference, &bc5, &[0]) < 6.0);
+    assert_eq!(mean_error(&reference, &bc5, &[1]), 0.0);
+
+    let bc4 = decode(&compress(
+        &image,
+        ImageFormat::Bc4Unorm,
+        ImageCompressionQuality::Fast,
+    ));
+
+    assert!(mean_error(&reference, &bc4, &[0]) < 6.0);
+}
+
+#[test]
+fn bc7_compresses_color_and_alpha() {
+    let image = synthetic_image(32, 32);
+
+    let fast = decode(&compress(
+        &image,
+        ImageFormat::Bc7Unorm,
+        ImageCompressionQuality::Fast,
+    ));
+    let high = decode(&compress(
+        &image,
+        ImageFormat::Bc7Unorm,
+        ImageCompressionQuality::High,
+    ));
+
+    let fast_error = mean_error(&image, &fast, &[0, 1, 2, 3]);
+    let high_error = mean_error(&image, &high, &[0, 1, 2, 3]);
+
+    assert!(high_error < 6.0, "bc7 error {high_error} is too high");
+    assert!(high_error <= fast_error);
+}
+
+#[test]
+fn compress_keeps_frames_and_mipmaps() {
+    let mut image = synthetic_image(6, 5);
+
+    image.create_frame().expect("failed to create frame");
+    image
+        .generate_mipmaps(MipmapFilter::Box)
+        .expect("failed to generate mipmaps");
+
+    let compressed = compress(&image, ImageFormat::Bc7Unorm, ImageCompressionQuality::Fast);
+
+    assert_eq!(compressed.mipmaps(), 3);
+    assert_eq!(compressed.frames().len(), 2);
+    assert_eq!(
+        compressed.frames()[1].buffer().len() as u32,
+        ImageFormat::Bc7Unorm.buffer_size(6, 5)
+            + ImageFormat::Bc7Unorm.buffer_size(3, 2)
+            + ImageFormat::Bc7Unorm.buffer_size(1, 1)
+    );
+}
+
+#[test]
+fn compress_rejects_unsupported_formats() {
+    let mut image = synthetic_image(4, 4);
+
+    assert!(matches!(
+        image.compress(ImageFormat::Bc6HUf16, ImageCompressionQuality::Normal),
+        Err(TextureError::UnsupportedImageFormat(_))
+    ));
+}
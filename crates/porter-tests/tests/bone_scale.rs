@@ -0,0 +1,38 @@
+use porter_math::Vector3;
+
+use porter_model::Bone;
+
+use porter_tests::synthetic_model;
+
+#[test]
+fn uniform_scale_is_not_flagged() {
+    let model = synthetic_model();
+
+    assert!(
+        model
+            .skeleton
+            .bones
+            .iter()
+            .all(|bone| !bone.has_non_uniform_scale())
+    );
+
+    // Mirroring flips the sign of an axis without changing its magnitude.
+    let bone = Bone::new(None, -1).world_scale(Vector3::new(-2.0, 2.0, 2.0));
+
+    assert!(!bone.has_non_uniform_scale());
+
+    let bone = Bone::new(None, -1).world_scale(Vector3::new(1.0, 1.0, 1.0001));
+
+    assert!(!bone.has_non_uniform_scale());
+}
+
+#[test]
+fn non_uniform_scale_is_flagged() {
+    let bone = Bone::new(None, -1).world_scale(Vector3::new(1.0, 1.0, 1.5));
+
+    assert!(bone.has_non_uniform_scale());
+
+    let bone = Bone::new(None, -1).world_scale(Vector3::new(0.0, 1.0, 1.0));
+
+    assert!(bone.has_non_uniform_scale());
+}
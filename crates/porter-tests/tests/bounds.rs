@@ -0,0 +1,48 @@
+use porter_math::Vector3;
+
+use porter_tests::synthetic_mesh;
+use porter_tests::synthetic_model;
+
+#[test]
+fn mesh_bounds_cover_vertices() {
+    let model = synthetic_mesh(16);
+
+    let bounds = model.meshes[0].bounding_box();
+
+    assert_eq!(bounds.min.x, 0.0);
+    assert_eq!(bounds.min.z, 0.0);
+    assert_eq!(bounds.max.x, 100.0);
+    assert_eq!(bounds.max.z, 100.0);
+}
+
+#[test]
+fn model_bounds_combine_meshes() {
+    let mut model = synthetic_model();
+    let mut offset = model.meshes[0].clone();
+
+    offset.scale(2.0);
+
+    let first = model.meshes[0].bounding_box();
+    let second = offset.bounding_box();
+
+    model.meshes.push(offset);
+
+    let bounds = model.bounding_box();
+
+    assert_eq!(
+        bounds.min,
+        Vector3::new(
+            first.min.x.min(second.min.x),
+            first.min.y.min(second.min.y),
+            first.min.z.min(second.min.z),
+        )
+    );
+    assert_eq!(
+        bounds.max,
+        Vector3::new(
+            first.max.x.max(second.max.x),
+            first.max.y.max(second.max.y),
+            first.max.z.max(second.max.z),
+        )
+    );
+}
@@ -0,0 +1,130 @@
+use std::fs::File;
+
+use porter_cast::CastFile;
+use porter_cast::CastHashMode;
+use porter_cast::CastId;
+use porter_cast::CastNode;
+use porter_cast::CastOptions;
+use porter_cast::CastPropertyValue;
+
+use porter_model::Material;
+use porter_model::ModelFileType;
+use porter_model::ModelSaveOptions;
+
+use porter_utils::BufferReadExt;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+/// Gets the hash of a node through it's reference value.
+fn node_hash(node: &CastNode) -> u64 {
+    u64::try_from(CastPropertyValue::from(node)).expect("node references should be 64bit integers")
+}
+
+#[test]
+fn stable_hashes_ignore_creation_order() {
+    let mut first = CastNode::root_with_hash_mode(CastHashMode::Stable);
+    let mut second = CastNode::root_with_hash_mode(CastHashMode::Stable);
+
+    let first_a = node_hash(first.create_named(CastId::Material, "a"));
+    let first_b = node_hash(first.create_named(CastId::Material, "b"));
+
+    let second_b = node_hash(second.create_named(CastId::Material, "b"));
+    let second_a = node_hash(second.create_named(CastId::Material, "a"));
+
+    assert_eq!(first_a, second_a);
+    assert_eq!(first_b, second_b);
+    assert_ne!(first_a, first_b);
+}
+
+#[test]
+fn stable_hashes_are_unique_for_duplicate_names() {
+    let mut root = CastNode::root_with_hash_mode(CastHashMode::Stable);
+
+    let first = node_hash(root.create_named(CastId::Mesh, "duplicate"));
+    let second = node_hash(root.create_named(CastId::Mesh, "duplicate"));
+    let unnamed = node_hash(root.create(CastId::Mesh));
+
+    assert_ne!(first, second);
+    assert_ne!(first, unnamed);
+    assert_ne!(second, unnamed);
+}
+
+#[test]
+fn sequential_hashes_ignore_names() {
+    let mut root = CastNode::root_with_hash_mode(CastHashMode::Sequential);
+
+    let first = node_hash(root.create_named(CastId::Material, "a"));
+    let second = node_hash(root.create(CastId::Material));
+
+    assert_eq!(first + 1, second);
+}
+
+#[test]
+fn stable_cast_exports_are_identical() {
+    let options = ModelSaveOptions {
+        cast: CastOptions {
+            hash_mode: CastHashMode::Stable,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let directory = output_directory("stable_cast_exports");
+
+    let model = synthetic_model();
+
+    // A material appended later must not change the hashes of the existing nodes.
+    let mut extended = model.clone();
+
+    extended
+        .materials
+        .insert(0, Material::new("extra_material"));
+    extended.meshes[0].material = Some(1);
+
+    model
+        .save_with_options(directory.join("first.cast"), ModelFileType::Cast, options)
+        .expect("failed to save model");
+    model
+        .save_with_options(directory.join("second.cast"), ModelFileType::Cast, options)
+        .expect("failed to save model");
+    extended
+        .save_with_options(
+            directory.join("extended.cast"),
+            ModelFileType::Cast,
+            options,
+        )
+        .expect("failed to save model");
+
+    let first = std::fs::read(directory.join("first.cast")).expect("failed to read cast file");
+    let second = std::fs::read(directory.join("second.cast")).expect("failed to read cast file");
+
+    assert_eq!(first, second, "repeated exports differ");
+
+    let material_hash = |name: &str| {
+        let cast = CastFile::read(
+            File::open(directory.join(name))
+                .expect("failed to open cast file")
+                .buffer_read(),
+        )
+        .expect("failed to read cast file");
+
+        let model = cast.roots()[0]
+            .children_of_type(CastId::Model)
+            .next()
+            .expect("missing model node");
+
+        model
+            .children_of_type(CastId::Material)
+            .find(|material| {
+                material
+                    .property("n")
+                    .and_then(|name| name.values::<String>().next())
+                    .is_some_and(|name| name == "synthetic_material")
+            })
+            .map(node_hash)
+            .expect("missing material node")
+    };
+
+    assert_eq!(material_hash("first.cast"), material_hash("extended.cast"));
+}
@@ -0,0 +1,135 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use porter_animation::AnimationFileType;
+use porter_animation::AnimationSampler;
+use porter_animation::Curve;
+use porter_animation::CurveAttribute;
+use porter_animation::CurveDataType;
+use porter_animation::KeyframeValue;
+
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_animation;
+
+/// The tolerance used when comparing decompressed values.
+const EPSILON: f32 = 1e-3;
+
+#[test]
+fn compact_curves_round_trip_within_tolerance() {
+    let mut rotation = Curve::new("bone", CurveAttribute::Rotation, CurveDataType::Absolute);
+    let mut translate = Curve::new("bone", CurveAttribute::Translate, CurveDataType::Absolute);
+
+    for frame in 0..100 {
+        let angle = frame as f32 * 0.1;
+
+        rotation.insert(
+            frame,
+            Quaternion::new(0.0, (angle * 0.5).sin(), 0.0, -(angle * 0.5).cos()),
+        );
+        translate.insert(frame, Vector3::new(angle, -angle * 2.0, 4.0));
+    }
+
+    let original_rotation = rotation.clone();
+    let original_translate = translate.clone();
+
+    rotation.compact();
+    translate.compact();
+
+    assert!(rotation.is_compact());
+    assert!(translate.is_compact());
+    assert_eq!(rotation.len(), 100);
+    assert_eq!(translate.largest_frame_time(), 99);
+
+    for (original, compact) in original_rotation.keyframes().iter().zip(rotation.iter()) {
+        let (KeyframeValue::Quaternion(lhs), KeyframeValue::Quaternion(rhs)) =
+            (original.value, compact.value)
+        else {
+            panic!("expected a quaternion");
+        };
+
+        assert_eq!(original.time, compact.time);
+        assert!(1.0 - lhs.dot(rhs).abs() < EPSILON);
+    }
+
+    for (original, compact) in original_translate.keyframes().iter().zip(translate.iter()) {
+        let (KeyframeValue::Vector3(lhs), KeyframeValue::Vector3(rhs)) =
+            (original.value, compact.value)
+        else {
+            panic!("expected a vector");
+        };
+
+        assert!((lhs - rhs).length() < EPSILON);
+    }
+}
+
+#[test]
+fn compact_curves_decompress_when_modified() {
+    let mut rotation = Curve::new("bone", CurveAttribute::Rotation, CurveDataType::Absolute);
+
+    rotation.insert(0, Quaternion::identity());
+    rotation.compact();
+    rotation.insert(
+        70000,
+        Quaternion::new(0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    );
+
+    assert!(!rotation.is_compact());
+    assert_eq!(rotation.len(), 2);
+    assert_eq!(rotation.largest_frame_time(), 70000);
+}
+
+#[test]
+fn compact_animations_can_be_scaled_and_saved() {
+    let mut animation = synthetic_animation();
+
+    animation.compact();
+    animation.scale(2.0);
+
+    assert!(animation.curves.iter().all(|curve| curve.is_compact()));
+    assert_eq!(animation.frame_count(), 11);
+
+    let last = animation.curves[0].keyframe(2).expect("missing keyframe");
+
+    let KeyframeValue::Vector3(vector) = last.value else {
+        panic!("expected a vector");
+    };
+
+    assert!((vector - Vector3::new(0.0, 20.0, 0.0)).length() < EPSILON);
+
+    animation
+        .save(
+            output_directory("compact_curves").join("synthetic_animation"),
+            AnimationFileType::Cast,
+        )
+        .expect("failed to save animation");
+}
+
+#[test]
+fn compact_animations_sample_without_decompressing() {
+    let mut sampler = AnimationSampler::new(synthetic_animation());
+    let mut compact = synthetic_animation();
+
+    compact.compact();
+
+    let mut compact_sampler = AnimationSampler::new(compact);
+
+    for _ in 0..=sampler.frame_count() + 1 {
+        sampler.step().expect("failed to step sampler");
+        compact_sampler.step().expect("failed to step sampler");
+
+        let (Some(KeyframeValue::Vector3(lhs)), Some(KeyframeValue::Vector3(rhs))) = (
+            sampler.evaulate("root", CurveAttribute::Translate),
+            compact_sampler.evaulate("root", CurveAttribute::Translate),
+        ) else {
+            panic!("expected a vector");
+        };
+
+        assert!((lhs - rhs).length() < EPSILON);
+    }
+
+    let animation = compact_sampler.into_animation();
+
+    assert!(animation.curves.iter().all(|curve| curve.is_compact()));
+}
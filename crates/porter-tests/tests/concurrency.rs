@@ -0,0 +1,129 @@
+use std::sync::Mutex;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use porter_utils::AtomicCancel;
+use porter_utils::AtomicProgress;
+use porter_utils::ProgressSink;
+use porter_utils::WorkerPool;
+
+#[test]
+fn child_cancel_follows_parent() {
+    let parent = AtomicCancel::new();
+    let child = parent.child();
+    let grandchild = child.child();
+
+    child.cancel();
+
+    assert!(!parent.is_cancelled());
+    assert!(child.is_cancelled());
+    assert!(grandchild.is_cancelled());
+
+    child.reset();
+    parent.cancel();
+
+    assert!(child.is_cancelled());
+    assert!(grandchild.is_cancelled());
+
+    parent.reset();
+
+    assert!(!grandchild.is_cancelled());
+}
+
+#[test]
+fn worker_pool_returns_results_in_order() {
+    let items: Vec<u32> = (0..1000).collect();
+
+    let results = WorkerPool::with_workers(4).run(&items, |item| item * 2);
+
+    assert_eq!(results.len(), items.len());
+
+    for (item, result) in items.iter().zip(results) {
+        assert_eq!(result, Some(item * 2));
+    }
+}
+
+#[test]
+fn worker_pool_runs_nested_pools() {
+    let items: Vec<u32> = (0..32).collect();
+
+    let results = WorkerPool::with_workers(4).run(&items, |item| {
+        WorkerPool::with_workers(4)
+            .run(&items, |inner| item * inner)
+            .into_iter()
+            .flatten()
+            .sum::<u32>()
+    });
+
+    for (item, result) in items.iter().zip(results) {
+        assert_eq!(result, Some(item * 496));
+    }
+}
+
+#[test]
+fn worker_pool_handles_empty_input() {
+    let results = WorkerPool::new().run(&[] as &[u32], |item| *item);
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn worker_pool_stops_when_cancelled() {
+    let cancel = AtomicCancel::new();
+    let items: Vec<u32> = (0..100).collect();
+
+    let results = WorkerPool::with_workers(2)
+        .cancel(cancel.clone())
+        .run(&items, |item| {
+            if *item == 10 {
+                cancel.cancel();
+            }
+        });
+
+    let completed = results.iter().filter(|result| result.is_some()).count();
+
+    assert!(completed > 10);
+    assert!(completed < items.len());
+}
+
+#[test]
+fn worker_pool_reports_progress() {
+    let items: Vec<u32> = (0..64).collect();
+
+    let progress = AtomicProgress::new();
+
+    WorkerPool::with_workers(3)
+        .progress(&progress)
+        .run(&items, |_| ());
+
+    assert_eq!(progress.progress(), 100);
+
+    let calls = AtomicUsize::new(0);
+    let last = Mutex::new((0, 0));
+
+    let sink = |complete: usize, total: usize| {
+        calls.fetch_add(1, Ordering::Relaxed);
+
+        let mut last = last.lock().unwrap();
+
+        last.0 = last.0.max(complete);
+        last.1 = total;
+    };
+
+    WorkerPool::with_workers(3)
+        .progress(&sink)
+        .run(&items, |_| ());
+
+    assert_eq!(calls.load(Ordering::Relaxed), items.len());
+    assert_eq!(*last.lock().unwrap(), (items.len(), items.len()));
+}
+
+#[test]
+fn atomic_progress_sink_never_goes_backwards() {
+    let progress = AtomicProgress::new();
+
+    ProgressSink::progress(&progress, 3, 4);
+    ProgressSink::progress(&progress, 1, 4);
+
+    assert_eq!(progress.progress(), 75);
+}
@@ -0,0 +1,77 @@
+use porter_math::Vector3;
+
+use porter_model::CustomProperties;
+use porter_model::CustomPropertyValue;
+use porter_model::ModelFileType;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+#[test]
+fn custom_properties_replace_existing_keys() {
+    let mut properties = CustomProperties::new();
+
+    properties.set("surface", "metal");
+    properties.set("mass", 2.5);
+    properties.set("surface", "wood");
+
+    assert_eq!(properties.len(), 2);
+    assert_eq!(
+        properties.get("surface"),
+        Some(&CustomPropertyValue::String(String::from("wood")))
+    );
+
+    let keys: Vec<&str> = properties.iter().map(|(key, _)| key).collect();
+
+    assert_eq!(keys, ["surface", "mass"]);
+    assert_eq!(
+        properties.remove("mass"),
+        Some(CustomPropertyValue::Float(2.5))
+    );
+    assert!(properties.get("mass").is_none());
+}
+
+#[test]
+fn custom_properties_are_exported() {
+    let mut model = synthetic_model();
+
+    model.skeleton.bones[1]
+        .properties
+        .set("jiggle_stiffness", 0.75);
+    model.skeleton.bones[1]
+        .properties
+        .set("jiggle_axis", Vector3::new(0.0, 0.0, 1.0));
+    model.meshes[0]
+        .properties
+        .set("surface_type", "cloth_heavy");
+    model.meshes[0].properties.set("lod_bias", 2);
+    // Known properties are never replaced.
+    model.meshes[0].properties.set("n", "replaced");
+
+    let directory = output_directory("custom_properties");
+
+    for (file_type, extension) in [(ModelFileType::Cast, "cast"), (ModelFileType::Fbx, "fbx")] {
+        model
+            .save(directory.join("synthetic_model"), file_type)
+            .expect("failed to save model");
+
+        let buffer = std::fs::read(directory.join("synthetic_model").with_extension(extension))
+            .expect("failed to read model");
+
+        let contains = |name: &[u8]| buffer.windows(name.len()).any(|window| window == name);
+
+        for name in [
+            &b"jiggle_stiffness"[..],
+            &b"jiggle_axis"[..],
+            &b"surface_type"[..],
+            &b"cloth_heavy"[..],
+            &b"lod_bias"[..],
+        ] {
+            assert!(contains(name), "{extension} is missing a custom property");
+        }
+
+        if extension == "cast" {
+            assert!(!contains(b"replaced"));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use porter_texture::ImageDecodeScheduler;
+use porter_texture::ImageFormat;
+use porter_texture::ImageInfo;
+
+use porter_tests::TestRng;
+
+/// The budget used by the scheduler tests.
+const BUDGET: u64 = 1024;
+
+#[test]
+fn scheduler_stays_within_budget() {
+    let scheduler = ImageDecodeScheduler::new(BUDGET);
+
+    let mut rng = TestRng::new(0x5EED);
+
+    let items: Vec<u64> = (0..256).map(|_| rng.range(1, 512) as u64).collect();
+
+    let peak = AtomicU64::new(0);
+
+    let results = scheduler.process(
+        items.clone(),
+        |size| *size,
+        |size| {
+            peak.fetch_max(scheduler.in_use(), Ordering::SeqCst);
+            size * 2
+        },
+    );
+
+    assert_eq!(
+        results,
+        items.iter().map(|size| size * 2).collect::<Vec<_>>()
+    );
+    assert!(peak.load(Ordering::SeqCst) <= BUDGET);
+    assert_eq!(scheduler.in_use(), 0);
+}
+
+#[test]
+fn scheduler_admits_oversized_alone() {
+    let scheduler = ImageDecodeScheduler::new(BUDGET);
+
+    let small = scheduler.acquire(16);
+
+    assert!(scheduler.try_acquire(BUDGET * 4).is_none());
+
+    drop(small);
+
+    let large = scheduler
+        .try_acquire(BUDGET * 4)
+        .expect("an oversized request should be admitted when idle");
+
+    assert_eq!(scheduler.in_use(), BUDGET * 4);
+    assert!(scheduler.try_acquire(1).is_none());
+
+    drop(large);
+
+    assert_eq!(scheduler.in_use(), 0);
+}
+
+#[test]
+fn image_info_memory_size() {
+    let info = ImageInfo {
+        width: 4096,
+        height: 4096,
+        mipmaps: 13,
+        frames: 1,
+        format: ImageFormat::Bc1Unorm,
+    };
+
+    assert_eq!(
+        info.decoded_size(ImageFormat::R8G8B8A8Unorm),
+        4096 * 4096 * 4
+    );
+    assert!(info.memory_size() > 4096 * 4096 / 2);
+    assert!(info.memory_size() < 4096 * 4096 / 2 * 4 / 3 + 64);
+}
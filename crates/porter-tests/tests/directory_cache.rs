@@ -0,0 +1,64 @@
+use porter_utils::DirectoryCache;
+
+use porter_tests::output_directory;
+
+#[test]
+fn create_caches_directory_and_parents() {
+    let directory = output_directory("directory_cache_create");
+    let path = directory.join("materials").join("brick");
+
+    let cache = DirectoryCache::new();
+
+    cache.create(&path).expect("failed to create directory");
+
+    assert!(path.is_dir());
+    assert!(cache.contains(&path));
+    assert!(cache.contains(directory.join("materials")));
+}
+
+#[test]
+fn create_skips_cached_directories() {
+    let directory = output_directory("directory_cache_skip");
+    let path = directory.join("models");
+
+    let cache = DirectoryCache::new();
+
+    cache.create(&path).expect("failed to create directory");
+
+    std::fs::remove_dir(&path).expect("failed to remove directory");
+
+    cache.create(&path).expect("failed to create directory");
+
+    assert!(!path.exists());
+
+    cache.clear();
+    cache.create(&path).expect("failed to create directory");
+
+    assert!(path.is_dir());
+}
+
+#[test]
+fn create_batch_creates_every_directory() {
+    let directory = output_directory("directory_cache_batch");
+
+    let paths: Vec<_> = (0..64)
+        .flat_map(|index| {
+            let material = directory
+                .join("materials")
+                .join(format!("material_{}", index));
+
+            [material.clone(), material.join("textures"), material]
+        })
+        .collect();
+
+    let cache = DirectoryCache::new();
+
+    cache
+        .create_batch(&paths)
+        .expect("failed to create directories");
+
+    for path in &paths {
+        assert!(path.is_dir());
+        assert!(cache.contains(path));
+    }
+}
@@ -0,0 +1,34 @@
+use std::io::ErrorKind;
+
+use porter_model::ModelError;
+use porter_model::VertexChannel;
+
+use porter_texture::ImageFormat;
+use porter_texture::TextureError;
+
+use porter_utils::ReportError;
+
+#[test]
+fn reports_have_codes_messages_and_detail() {
+    let report = TextureError::UnsupportedImageFormat(ImageFormat::R8G8B8A8Unorm).report();
+
+    assert_eq!(report.code, "TEX-003");
+    assert!(report.message.contains("R8G8B8A8Unorm"));
+    assert!(report.detail.contains("UnsupportedImageFormat"));
+    assert_eq!(report.to_string(), format!("{} (TEX-003)", report.message));
+
+    let report = ModelError::MissingVertexChannel(VertexChannel::Normal).report();
+
+    assert_eq!(report.code, "MDL-001");
+}
+
+#[test]
+fn io_errors_are_reported_through_the_wrapping_error() {
+    let error = ModelError::from(std::io::Error::from(ErrorKind::PermissionDenied));
+
+    assert_eq!(error.error_code(), "IO-002");
+    assert_eq!(
+        error.error_message(),
+        std::io::Error::from(ErrorKind::PermissionDenied).error_message()
+    );
+}
@@ -0,0 +1,97 @@
+use porter_texture::Image;
+use porter_texture::ImageChannel;
+use porter_texture::ImageChannelRemap;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_image;
+
+#[test]
+fn channel_remap_parses_and_displays() {
+    let remap: ImageChannelRemap = "R->AO, g -> Roughness,Blue->Metalness"
+        .parse()
+        .expect("failed to parse remap");
+
+    assert_eq!(
+        remap,
+        ImageChannelRemap::new()
+            .channel(ImageChannel::Red, "AO")
+            .channel(ImageChannel::Green, "Roughness")
+            .channel(ImageChannel::Blue, "Metalness")
+    );
+    assert_eq!(remap.to_string(), "R->AO, G->Roughness, B->Metalness");
+}
+
+#[test]
+fn channel_remap_rejects_invalid_specs() {
+    for spec in ["", "R", "X->AO", "R->", "R->../AO", "R->AO, G->AO"] {
+        assert!(
+            spec.parse::<ImageChannelRemap>().is_err(),
+            "{spec:?} should be invalid"
+        );
+    }
+}
+
+#[test]
+fn remap_channels_splits_grayscale_images() {
+    let image = synthetic_image(16, 8);
+
+    let remap = ImageChannelRemap::new()
+        .channel(ImageChannel::Blue, "Metalness")
+        .channel(ImageChannel::Alpha, "Opacity");
+
+    let mut swizzled = image.clone();
+
+    swizzled
+        .convert(ImageFormat::B8G8R8A8Unorm, ImageConvertOptions::None)
+        .expect("failed to convert image");
+
+    for source in [&image, &swizzled] {
+        let split = source.remap_channels(&remap).expect("failed to remap");
+
+        assert_eq!(split.len(), 2);
+        assert_eq!(split[0].0, "Metalness");
+        assert_eq!(split[1].0, "Opacity");
+
+        for (index, (_, channel)) in split.iter().enumerate() {
+            assert_eq!(channel.format(), ImageFormat::R8Unorm);
+            assert_eq!((channel.width(), channel.height()), (16, 8));
+
+            for (value, pixel) in channel.frames()[0]
+                .buffer()
+                .iter()
+                .zip(image.frames()[0].buffer().chunks_exact(4))
+            {
+                assert_eq!(*value, pixel[index + 2]);
+            }
+        }
+    }
+}
+
+#[test]
+fn save_remapped_channels_writes_suffixed_images() {
+    let directory = output_directory("save_remapped_channels");
+    let path = directory.join("packed.png");
+
+    let remap: ImageChannelRemap = "R->AO, G->Roughness".parse().expect("failed to parse");
+
+    let paths = synthetic_image(8, 8)
+        .save_remapped_channels(&path, ImageFileType::Png, &remap)
+        .expect("failed to save channels");
+
+    assert_eq!(
+        paths,
+        [
+            directory.join("packed_AO.png"),
+            directory.join("packed_Roughness.png")
+        ]
+    );
+
+    for path in paths {
+        let image = Image::load(&path, ImageFileType::Png).expect("failed to load channel");
+
+        assert_eq!((image.width(), image.height()), (8, 8));
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::Cursor;
+
+use porter_texture::Image;
+use porter_texture::ImageColorProfile;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+use porter_texture::ImageSaveOptions;
+
+use porter_tests::synthetic_image;
+
+/// Saves the image with the given color profile and file type, returning the file.
+fn save_with_profile(
+    image: &Image,
+    profile: ImageColorProfile,
+    file_type: ImageFileType,
+) -> Vec<u8> {
+    let mut output = Cursor::new(Vec::new());
+
+    image
+        .save_to_with_options(
+            &mut output,
+            file_type,
+            ImageSaveOptions {
+                color_profile: profile,
+            },
+        )
+        .expect("failed to save image");
+
+    output.into_inner()
+}
+
+/// Whether or not the png file contains a chunk with the given type.
+fn has_chunk(png: &[u8], chunk: &[u8; 4]) -> bool {
+    png.windows(4).any(|window| window == chunk)
+}
+
+/// Builds a synthetic srgb image.
+fn srgb_image() -> Image {
+    let mut image =
+        Image::new(8, 8, ImageFormat::R8G8B8A8UnormSrgb).expect("failed to create image");
+
+    image.create_frame().expect("failed to create frame");
+    image
+}
+
+#[test]
+fn png_color_profile_chunks() {
+    let png = save_with_profile(&srgb_image(), ImageColorProfile::Chunks, ImageFileType::Png);
+
+    assert!(has_chunk(&png, b"sRGB"));
+    assert!(has_chunk(&png, b"gAMA"));
+    assert!(has_chunk(&png, b"cHRM"));
+    assert!(!has_chunk(&png, b"iCCP"));
+
+    let info = Image::probe_from(&mut Cursor::new(png), ImageFileType::Png)
+        .expect("failed to probe image");
+
+    assert!(info.format.is_srgb());
+}
+
+#[test]
+fn png_color_profile_icc() {
+    let png = save_with_profile(&srgb_image(), ImageColorProfile::Icc, ImageFileType::Png);
+
+    assert!(has_chunk(&png, b"iCCP"));
+    assert!(has_chunk(&png, b"gAMA"));
+    assert!(!has_chunk(&png, b"sRGB"));
+
+    let info = Image::probe_from(&mut Cursor::new(png), ImageFileType::Png)
+        .expect("failed to probe image");
+
+    assert!(info.format.is_srgb());
+}
+
+#[test]
+fn png_color_profile_none() {
+    let png = save_with_profile(&srgb_image(), ImageColorProfile::None, ImageFileType::Png);
+
+    assert!(!has_chunk(&png, b"sRGB"));
+    assert!(!has_chunk(&png, b"gAMA"));
+    assert!(!has_chunk(&png, b"iCCP"));
+}
+
+#[test]
+fn png_color_profile_skips_linear_images() {
+    let png = save_with_profile(
+        &synthetic_image(8, 8),
+        ImageColorProfile::Icc,
+        ImageFileType::Png,
+    );
+
+    assert!(!has_chunk(&png, b"sRGB"));
+    assert!(!has_chunk(&png, b"gAMA"));
+    assert!(!has_chunk(&png, b"iCCP"));
+}
+
+#[test]
+fn tiff_color_profile_none() {
+    let with = save_with_profile(
+        &srgb_image(),
+        ImageColorProfile::Chunks,
+        ImageFileType::Tiff,
+    );
+    let without = save_with_profile(&srgb_image(), ImageColorProfile::None, ImageFileType::Tiff);
+
+    // The embedded icc profile is the only difference between the files.
+    assert!(with.len() > without.len() + 3000);
+}
@@ -0,0 +1,113 @@
+use porter_texture::Image;
+use porter_texture::ImageBlendMode;
+use porter_texture::ImageChannel;
+use porter_texture::ImageChannelPacker;
+use porter_texture::ImageFormat;
+
+/// Builds an image of the given size filled with a single rgba color.
+fn solid_image(width: u32, height: u32, color: [u8; 4]) -> Image {
+    let mut image =
+        Image::new(width, height, ImageFormat::R8G8B8A8Unorm).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    for pixel in frame.buffer_mut().chunks_exact_mut(4) {
+        pixel.copy_from_slice(&color);
+    }
+
+    image
+}
+
+/// Builds a grayscale image of the given size filled with a single value.
+fn grayscale_image(width: u32, height: u32, value: u8) -> Image {
+    let mut image =
+        Image::new(width, height, ImageFormat::R8Unorm).expect("failed to create image");
+
+    image
+        .create_frame()
+        .expect("failed to create frame")
+        .buffer_mut()
+        .fill(value);
+
+    image
+}
+
+#[test]
+fn blend_modes_match_their_formulas() {
+    assert_eq!(ImageBlendMode::Normal.blend(0.2, 0.6), 0.6);
+    assert_eq!(ImageBlendMode::Multiply.blend(0.5, 0.5), 0.25);
+    assert_eq!(ImageBlendMode::Screen.blend(0.5, 0.5), 0.75);
+    assert_eq!(ImageBlendMode::Overlay.blend(0.25, 0.5), 0.25);
+    assert_eq!(ImageBlendMode::Overlay.blend(0.75, 0.5), 0.75);
+    assert_eq!(ImageBlendMode::Add.blend(0.75, 0.5), 1.0);
+    assert_eq!(ImageBlendMode::Subtract.blend(0.25, 0.5), 0.0);
+    assert_eq!(ImageBlendMode::Darken.blend(0.25, 0.5), 0.25);
+    assert_eq!(ImageBlendMode::Lighten.blend(0.25, 0.5), 0.5);
+}
+
+#[test]
+fn overlay_blends_with_opacity_and_layer_alpha() {
+    let mut image = solid_image(4, 4, [200, 100, 0, 0x80]);
+    let layer = solid_image(4, 4, [0, 0, 255, 0xFF]);
+
+    image
+        .overlay(&layer, ImageBlendMode::Normal, 0.5)
+        .expect("failed to overlay image");
+
+    // Halfway between the colors, keeping the base alpha.
+    assert_eq!(image.pixel_rgba8(0, 0), Some([100, 50, 128, 0x80]));
+
+    let mut image = solid_image(4, 4, [200, 100, 0, 0xFF]);
+    let layer = solid_image(4, 4, [0, 0, 255, 0x00]);
+
+    image
+        .overlay(&layer, ImageBlendMode::Normal, 1.0)
+        .expect("failed to overlay image");
+
+    // A transparent layer leaves the image unchanged.
+    assert_eq!(image.pixel_rgba8(0, 0), Some([200, 100, 0, 0xFF]));
+}
+
+#[test]
+fn overlay_resizes_the_layer_to_fit() {
+    let mut image = solid_image(8, 8, [0xFF, 0xFF, 0xFF, 0xFF]);
+    let layer = solid_image(2, 2, [0x80, 0x40, 0x00, 0xFF]);
+
+    image
+        .overlay(&layer, ImageBlendMode::Multiply, 1.0)
+        .expect("failed to overlay image");
+
+    assert_eq!((image.width(), image.height()), (8, 8));
+    assert_eq!(image.pixel_rgba8(5, 5), Some([0x80, 0x40, 0x00, 0xFF]));
+}
+
+#[test]
+fn channel_packer_merges_grayscale_images() {
+    let occlusion = grayscale_image(4, 4, 0x10);
+    let roughness = grayscale_image(4, 4, 0x20);
+    let metalness = grayscale_image(4, 4, 0x30);
+
+    let image = ImageChannelPacker::new()
+        .grayscale(ImageChannel::Red, &occlusion)
+        .grayscale(ImageChannel::Green, &roughness)
+        .grayscale(ImageChannel::Blue, &metalness)
+        .pack()
+        .expect("failed to pack channels");
+
+    assert_eq!(image.pixel_rgba8(3, 3), Some([0x10, 0x20, 0x30, 0xFF]));
+}
+
+#[test]
+fn channel_packer_swizzles_source_channels() {
+    let source = solid_image(4, 4, [0x10, 0x20, 0x30, 0x40]);
+
+    let image = ImageChannelPacker::new()
+        .red(&source, ImageChannel::Alpha)
+        .green(&source, ImageChannel::Blue)
+        .blue(&source, ImageChannel::Green)
+        .default_value(ImageChannel::Alpha, 0x80)
+        .pack()
+        .expect("failed to pack channels");
+
+    assert_eq!(image.pixel_rgba8(0, 0), Some([0x40, 0x30, 0x20, 0x80]));
+}
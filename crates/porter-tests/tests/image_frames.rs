@@ -0,0 +1,43 @@
+use porter_texture::Image;
+
+use porter_tests::synthetic_array;
+
+/// Gets the index each remaining frame was filled with.
+fn frame_indices(image: &Image) -> Vec<u8> {
+    image
+        .frames()
+        .iter()
+        .map(|frame| frame.buffer()[0])
+        .collect()
+}
+
+#[test]
+fn retain_frames_keeps_range() {
+    let mut image = synthetic_array(4, 4, 8);
+
+    image.retain_frames(2..5);
+
+    assert_eq!(frame_indices(&image), vec![2, 3, 4]);
+}
+
+#[test]
+fn retain_frames_keeps_single_frame() {
+    let mut image = synthetic_array(4, 4, 8);
+
+    image.retain_frames(6..7);
+
+    assert_eq!(frame_indices(&image), vec![6]);
+}
+
+#[test]
+fn retain_frames_clamps_to_frame_count() {
+    let mut image = synthetic_array(4, 4, 4);
+
+    image.retain_frames(2..10);
+
+    assert_eq!(frame_indices(&image), vec![2, 3]);
+
+    image.retain_frames(5..10);
+
+    assert!(image.frames().is_empty());
+}
@@ -0,0 +1,56 @@
+use porter_texture::Image;
+use porter_texture::ImageFormat;
+use porter_texture::TextureError;
+
+/// Builds an 8x8 image with a full mip chain, where each mip is filled with its level.
+fn synthetic_mipmapped(frames: usize) -> Image {
+    let mut image =
+        Image::with_mipmaps(8, 8, 4, ImageFormat::R8G8B8A8Unorm).expect("failed to create image");
+
+    for _ in 0..frames {
+        let frame = image.create_frame().expect("failed to create frame");
+
+        let mut offset = 0;
+
+        for (level, size) in [(0u8, 8 * 8 * 4), (1, 4 * 4 * 4), (2, 2 * 2 * 4), (3, 4)] {
+            frame.buffer_mut()[offset..offset + size].fill(level);
+
+            offset += size;
+        }
+    }
+
+    image
+}
+
+#[test]
+fn mip_extracts_level_from_every_frame() {
+    let image = synthetic_mipmapped(2);
+
+    let mip = image.mip(2).expect("failed to extract mip");
+
+    assert_eq!((mip.width(), mip.height()), (2, 2));
+    assert_eq!(mip.mipmaps(), 1);
+    assert_eq!(mip.frames().len(), 2);
+
+    for frame in mip.frames() {
+        assert!(frame.buffer().iter().all(|value| *value == 2));
+    }
+}
+
+#[test]
+fn mip_rejects_missing_level() {
+    let image = synthetic_mipmapped(1);
+
+    assert!(matches!(image.mip(4), Err(TextureError::InvalidMipMaps(4))));
+}
+
+#[test]
+fn mip_for_budget_picks_first_fitting_level() {
+    let image = synthetic_mipmapped(2);
+
+    assert_eq!(image.mip_for_budget(u64::MAX), 0);
+    assert_eq!(image.mip_for_budget(8 * 8 * 4 * 2), 0);
+    assert_eq!(image.mip_for_budget(4 * 4 * 4 * 2), 1);
+    assert_eq!(image.mip_for_budget(100), 2);
+    assert_eq!(image.mip_for_budget(0), 3);
+}
@@ -0,0 +1,98 @@
+use porter_texture::Image;
+use porter_texture::ImageFormat;
+
+use porter_tests::synthetic_image;
+use porter_tests::synthetic_texture;
+
+#[test]
+fn pixel_reads_rgba8() {
+    let image = synthetic_image(16, 8);
+
+    let offset = (3 * 16 + 5) * 4;
+    let expected = &image.frames()[0].buffer()[offset..offset + 4];
+
+    assert_eq!(image.pixel(5, 3), Some(expected));
+    assert_eq!(
+        image.pixel_rgba8(5, 3),
+        Some([expected[0], expected[1], expected[2], expected[3]])
+    );
+}
+
+#[test]
+fn pixel_rgba8_swizzles_bgra8() {
+    let mut image = Image::new(2, 2, ImageFormat::B8G8R8A8Unorm).expect("failed to create image");
+
+    image
+        .create_frame()
+        .expect("failed to create frame")
+        .buffer_mut()
+        .copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    assert_eq!(image.pixel_rgba8(1, 1), Some([15, 14, 13, 16]));
+}
+
+#[test]
+fn pixel_reads_raw_wide_formats() {
+    let image = synthetic_texture(4, 4, ImageFormat::R16G16B16A16Float, 0x2215);
+
+    let offset = (2 * 4 + 1) * 8;
+    let expected = &image.frames()[0].buffer()[offset..offset + 8];
+
+    assert_eq!(image.pixel(1, 2), Some(expected));
+    assert_eq!(image.pixel_rgba8(1, 2), None);
+}
+
+#[test]
+fn pixel_rejects_invalid_reads() {
+    let image = synthetic_image(4, 4);
+
+    assert_eq!(image.pixel(4, 0), None);
+    assert_eq!(image.pixel(0, 4), None);
+
+    let compressed = synthetic_texture(8, 8, ImageFormat::Bc1Unorm, 0x2215);
+
+    assert_eq!(compressed.pixel(0, 0), None);
+}
+
+#[test]
+fn pixel_to_rgba8_decodes_bc1_blocks() {
+    let mut image = Image::new(8, 4, ImageFormat::Bc1Unorm).expect("failed to create image");
+
+    let buffer = image
+        .create_frame()
+        .expect("failed to create frame")
+        .buffer_mut();
+
+    // A four color block from red to blue, where each pixel uses the palette entry of its column.
+    buffer[0..8].copy_from_slice(&[0x00, 0xF8, 0x1F, 0x00, 0xE4, 0xE4, 0xE4, 0xE4]);
+    // A three color block from blue to red, where the first pixel is the midpoint and the rest are transparent.
+    buffer[8..16].copy_from_slice(&[0x1F, 0x00, 0x00, 0xF8, 0xFE, 0xFF, 0xFF, 0xFF]);
+
+    assert_eq!(image.pixel_to_rgba8(0, 0).ok(), Some([255, 0, 0, 255]));
+    assert_eq!(image.pixel_to_rgba8(1, 2).ok(), Some([0, 0, 255, 255]));
+    assert_eq!(image.pixel_to_rgba8(2, 1).ok(), Some([170, 0, 85, 255]));
+    assert_eq!(image.pixel_to_rgba8(3, 3).ok(), Some([85, 0, 170, 255]));
+    assert_eq!(image.pixel_to_rgba8(4, 0).ok(), Some([127, 0, 127, 255]));
+    assert_eq!(image.pixel_to_rgba8(7, 3).ok(), Some([0, 0, 0, 0]));
+
+    assert!(image.pixel_to_rgba8(8, 0).is_err());
+}
+
+#[test]
+fn sample_blocks_picks_evenly_spaced_blocks() {
+    let image = synthetic_image(16, 8);
+
+    let sample = image.sample_blocks(4).expect("failed to sample image");
+
+    assert_eq!((sample.width(), sample.height()), (4, 4));
+    assert_eq!(sample.pixel(1, 1), image.pixel(4, 2));
+    assert_eq!(sample.pixel(3, 3), image.pixel(12, 6));
+
+    let compressed = synthetic_texture(32, 32, ImageFormat::Bc1Unorm, 0x2199);
+    let sample = compressed
+        .sample_blocks(64)
+        .expect("failed to sample image");
+
+    assert_eq!((sample.width(), sample.height()), (32, 32));
+    assert_eq!(sample.frames()[0].buffer(), compressed.frames()[0].buffer());
+}
@@ -0,0 +1,90 @@
+use std::io::Cursor;
+
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageSaveOptions;
+use porter_texture::ImageSaveProgress;
+use porter_texture::TextureError;
+
+use porter_utils::AtomicCancel;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_array;
+
+#[test]
+fn progress_is_reported_per_slice() {
+    let image = synthetic_array(16, 16, 12);
+
+    // Dds and tiff store every slice, other formats stack up to a cubemap worth of slices.
+    for (file_type, total) in [
+        (ImageFileType::Dds, 12),
+        (ImageFileType::Png, 6),
+        (ImageFileType::Tga, 6),
+        (ImageFileType::Tiff, 12),
+    ] {
+        let mut image = image.clone();
+
+        image
+            .convert(
+                image.format_for_file_type(file_type),
+                ImageConvertOptions::None,
+            )
+            .expect("failed to convert image");
+
+        let mut reported = Vec::new();
+        let mut progress =
+            ImageSaveProgress::new().on_frame(|written, total| reported.push((written, total)));
+
+        image
+            .save_to_with_progress(
+                &mut Cursor::new(Vec::new()),
+                file_type,
+                ImageSaveOptions::default(),
+                &mut progress,
+            )
+            .expect("failed to save image");
+
+        drop(progress);
+
+        assert_eq!(
+            reported,
+            (1..=total)
+                .map(|written| (written, total))
+                .collect::<Vec<_>>(),
+            "{file_type:?}"
+        );
+    }
+}
+
+#[test]
+fn cancelled_save_stops_and_removes_the_file() {
+    let directory = output_directory("image_save_progress");
+    let path = directory.join("synthetic_array.dds");
+
+    let image = synthetic_array(16, 16, 12);
+    let cancel = AtomicCancel::new();
+
+    let mut written = 0;
+    let mut progress = ImageSaveProgress::new()
+        .cancel(cancel.clone())
+        .on_frame(|frame, _| {
+            written = frame;
+
+            if frame == 4 {
+                cancel.cancel();
+            }
+        });
+
+    let result = image.save_with_progress(
+        &path,
+        ImageFileType::Dds,
+        ImageSaveOptions::default(),
+        &mut progress,
+    );
+
+    drop(progress);
+
+    assert!(matches!(result, Err(TextureError::Cancelled)));
+    assert_eq!(written, 4);
+    assert!(!path.exists());
+}
@@ -0,0 +1,74 @@
+use porter_texture::Image;
+use porter_texture::ImageFormat;
+use porter_texture::ImageSpriteSheet;
+use porter_texture::TextureError;
+
+use porter_tests::synthetic_array;
+use porter_tests::synthetic_image;
+
+#[test]
+fn grid_fits_frames_to_columns() {
+    assert_eq!(ImageSpriteSheet::new(4).grid(6), (4, 2));
+    assert_eq!(ImageSpriteSheet::new(4).grid(2), (2, 1));
+    assert_eq!(ImageSpriteSheet::new(0).grid(3), (1, 3));
+}
+
+#[test]
+fn assemble_lays_out_frames_in_rows() {
+    let image = synthetic_array(4, 2, 6);
+    let sheet = ImageSpriteSheet::new(4)
+        .assemble(&image)
+        .expect("failed to assemble sprite sheet");
+
+    assert_eq!((sheet.width(), sheet.height()), (16, 4));
+    assert_eq!(sheet.frames().len(), 1);
+
+    assert_eq!(sheet.pixel_rgba8(0, 0), Some([0; 4]));
+    assert_eq!(sheet.pixel_rgba8(12, 1), Some([3; 4]));
+    assert_eq!(sheet.pixel_rgba8(4, 2), Some([5; 4]));
+
+    // Cells past the last frame are left empty.
+    assert_eq!(sheet.pixel_rgba8(8, 2), Some([0; 4]));
+}
+
+#[test]
+fn split_restores_assembled_frames() {
+    let image = synthetic_array(4, 2, 6);
+    let sprite_sheet = ImageSpriteSheet::new(4);
+
+    let sheet = sprite_sheet
+        .assemble(&image)
+        .expect("failed to assemble sprite sheet");
+    let split = sprite_sheet
+        .split(&sheet, 6)
+        .expect("failed to split sprite sheet");
+
+    assert_eq!((split.width(), split.height()), (4, 2));
+    assert_eq!(split.frames().len(), 6);
+
+    for (split, frame) in split.frames().iter().zip(image.frames()) {
+        assert_eq!(split.buffer(), frame.buffer());
+    }
+}
+
+#[test]
+fn split_rejects_uneven_sheets() {
+    let sheet = synthetic_image(10, 4);
+
+    assert!(matches!(
+        ImageSpriteSheet::new(4).split(&sheet, 4),
+        Err(TextureError::InvalidImageSize(10, 4))
+    ));
+}
+
+#[test]
+fn assemble_rejects_compressed_formats() {
+    let mut image = Image::new(4, 4, ImageFormat::Bc1Unorm).expect("failed to create image");
+
+    image.create_frame().expect("failed to create frame");
+
+    assert!(matches!(
+        ImageSpriteSheet::new(2).assemble(&image),
+        Err(TextureError::UnsupportedImageFormat(ImageFormat::Bc1Unorm))
+    ));
+}
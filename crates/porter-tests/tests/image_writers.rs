@@ -0,0 +1,49 @@
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+
+use porter_tests::assert_golden_directory;
+use porter_tests::output_directory;
+use porter_tests::synthetic_image;
+
+/// Saves the synthetic image in the given format and compares the output with the golden files.
+fn check_image_writer(name: &str, file_type: ImageFileType) {
+    let directory = output_directory(format!("image_{name}"));
+
+    let mut image = synthetic_image(16, 8);
+
+    image
+        .convert(
+            image.format_for_file_type(file_type),
+            ImageConvertOptions::None,
+        )
+        .expect("failed to convert image");
+
+    image
+        .save(
+            directory.join("synthetic_image").with_extension(file_type),
+            file_type,
+        )
+        .expect("failed to save image");
+
+    assert_golden_directory(format!("image/{name}"), directory);
+}
+
+#[test]
+fn image_dds() {
+    check_image_writer("dds", ImageFileType::Dds);
+}
+
+#[test]
+fn image_png() {
+    check_image_writer("png", ImageFileType::Png);
+}
+
+#[test]
+fn image_tga() {
+    check_image_writer("tga", ImageFileType::Tga);
+}
+
+#[test]
+fn image_tiff() {
+    check_image_writer("tiff", ImageFileType::Tiff);
+}
@@ -0,0 +1,62 @@
+use std::fs;
+
+use porter_model::Material;
+use porter_model::MeshMaterialRange;
+use porter_model::ModelFileType;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+#[test]
+fn material_ranges_split_meshes() {
+    let mut model = synthetic_model();
+
+    model.materials.push(Material::new("synthetic_range"));
+
+    let face_count = model.meshes[0].faces.len();
+    let vertex_count = model.meshes[0].vertices.len();
+
+    model.meshes[0]
+        .material_ranges
+        .push(MeshMaterialRange::new(1, 0, 4));
+
+    assert_eq!(model.meshes[0].face_material(3), Some(1));
+    assert_eq!(model.meshes[0].face_material(4), Some(0));
+
+    let split = model.split_material_ranges();
+
+    assert_eq!(split.meshes.len(), 2);
+    assert_eq!(split.meshes[0].material, Some(1));
+    assert_eq!(split.meshes[0].faces.len(), 4);
+    assert_eq!(split.meshes[1].material, Some(0));
+    assert_eq!(split.meshes[1].faces.len(), face_count - 4);
+    assert!(split.meshes[0].vertices.len() < vertex_count);
+    assert!(
+        split
+            .meshes
+            .iter()
+            .all(|mesh| mesh.material_ranges.is_empty())
+    );
+}
+
+#[test]
+fn material_ranges_obj_switches_materials() {
+    let directory = output_directory("material_ranges_obj");
+
+    let mut model = synthetic_model();
+
+    model.materials.push(Material::new("synthetic_range"));
+    model.meshes[0]
+        .material_ranges
+        .push(MeshMaterialRange::new(1, 2, 2));
+
+    model
+        .save(directory.join("synthetic_model"), ModelFileType::Obj)
+        .expect("failed to save model");
+
+    let obj =
+        fs::read_to_string(directory.join("synthetic_model.obj")).expect("failed to read obj");
+
+    assert_eq!(obj.matches("usemtl synthetic_range").count(), 1);
+    assert_eq!(obj.matches("usemtl synthetic_material").count(), 2);
+}
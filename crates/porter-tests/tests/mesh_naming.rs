@@ -0,0 +1,93 @@
+use std::fs;
+
+use porter_model::Material;
+use porter_model::MeshNaming;
+use porter_model::ModelFileType;
+use porter_model::ModelSaveOptions;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+#[test]
+fn index_naming_leaves_names_to_writers() {
+    let model = synthetic_model();
+
+    assert_eq!(MeshNaming::Index.mesh_names(&model), vec![None]);
+}
+
+#[test]
+fn material_naming_names_unnamed_meshes() {
+    let mut model = synthetic_model();
+
+    model.materials.push(Material::new("skin"));
+
+    let mut second = model.meshes[0].clone();
+    let mut third = model.meshes[0].clone();
+    let mut fourth = model.meshes[0].clone();
+
+    second.name = None;
+    third.name = None;
+    third.material = Some(1);
+    fourth.name = None;
+    fourth.material = None;
+
+    model.meshes.push(second.clone());
+    model.meshes.push(third);
+    model.meshes.push(second);
+    model.meshes.push(fourth);
+
+    let names = MeshNaming::Material.mesh_names(&model);
+
+    assert_eq!(
+        names,
+        vec![
+            Some(String::from("synthetic_mesh")),
+            Some(String::from("mesh_synthetic_material_0")),
+            Some(String::from("mesh_skin_0")),
+            Some(String::from("mesh_synthetic_material_1")),
+            Some(String::from("mesh_default_material_0")),
+        ]
+    );
+}
+
+#[test]
+fn material_naming_keeps_split_names_unique() {
+    let mut model = synthetic_model();
+
+    model.meshes.push(model.meshes[0].clone());
+
+    let names = MeshNaming::Material.mesh_names(&model);
+
+    assert_eq!(
+        names,
+        vec![
+            Some(String::from("synthetic_mesh")),
+            Some(String::from("synthetic_mesh_1")),
+        ]
+    );
+}
+
+#[test]
+fn material_naming_applies_to_writers() {
+    let directory = output_directory("mesh_naming");
+
+    let mut model = synthetic_model();
+
+    model.meshes[0].name = None;
+
+    model
+        .save_with_options(
+            directory.join("synthetic_model"),
+            ModelFileType::XModelExport,
+            ModelSaveOptions {
+                mesh_naming: MeshNaming::Material,
+                ..Default::default()
+            },
+        )
+        .expect("failed to save model");
+
+    let xmodel = fs::read_to_string(directory.join("synthetic_model.xmodel_export"))
+        .expect("failed to read xmodel_export");
+
+    assert!(xmodel.contains("OBJECT 0 \"mesh_synthetic_material_0\""));
+}
@@ -0,0 +1,35 @@
+use porter_tests::synthetic_model;
+
+#[test]
+fn hidden_meshes_are_removed() {
+    let mut model = synthetic_model();
+
+    let mut second = model.meshes[0].clone();
+    let mut third = model.meshes[0].clone();
+
+    second.name = Some(String::from("second"));
+    third.name = Some(String::from("third"));
+
+    model.meshes.push(second);
+    model.meshes.push(third);
+
+    model.retain_visible_meshes(&[true, false, true]);
+
+    assert_eq!(model.meshes.len(), 2);
+    assert_eq!(model.meshes[1].name.as_deref(), Some("third"));
+}
+
+#[test]
+fn meshes_past_the_mask_are_kept() {
+    let mut model = synthetic_model();
+
+    model.meshes.push(model.meshes[0].clone());
+
+    model.retain_visible_meshes(&[false]);
+
+    assert_eq!(model.meshes.len(), 1);
+
+    model.retain_visible_meshes(&[]);
+
+    assert_eq!(model.meshes.len(), 1);
+}
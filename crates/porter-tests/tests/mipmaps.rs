@@ -0,0 +1,140 @@
+use std::io::Cursor;
+
+use porter_texture::Image;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+use porter_texture::MipmapFilter;
+use porter_texture::TextureError;
+
+use porter_tests::synthetic_image;
+
+/// Builds a checkerboard of single black and white pixels in the given format.
+fn checkerboard(width: u32, height: u32, format: ImageFormat) -> Image {
+    let mut image = Image::new(width, height, format).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    for (index, pixel) in frame.buffer_mut().chunks_exact_mut(4).enumerate() {
+        let x = index as u32 % width;
+        let y = index as u32 / width;
+
+        let value = if (x + y).is_multiple_of(2) {
+            0x00
+        } else {
+            0xFF
+        };
+
+        pixel.copy_from_slice(&[value, value, value, 0xFF]);
+    }
+
+    image
+}
+
+#[test]
+fn generate_mipmaps_builds_the_full_chain() {
+    let mut image = synthetic_image(16, 4);
+
+    image
+        .generate_mipmaps(MipmapFilter::Box)
+        .expect("failed to generate mipmaps");
+
+    assert_eq!(image.mipmaps(), 5);
+    assert_eq!(image.mip_size(4), (1, 1));
+    assert_eq!(
+        image.frames()[0].buffer().len() as u32,
+        image.frame_size(16, 4)
+    );
+
+    // The first mip is unchanged.
+    let original = synthetic_image(16, 4);
+
+    assert_eq!(
+        image.mip(0).expect("failed to get mip").frames()[0].buffer(),
+        original.frames()[0].buffer()
+    );
+}
+
+#[test]
+fn generate_mipmaps_box_averages_pixels() {
+    let mut image = checkerboard(4, 4, ImageFormat::R8G8B8A8Unorm);
+
+    image
+        .generate_mipmaps(MipmapFilter::Box)
+        .expect("failed to generate mipmaps");
+
+    let mip = image.mip(1).expect("failed to get mip");
+
+    assert_eq!(mip.pixel_rgba8(1, 1), Some([0x80, 0x80, 0x80, 0xFF]));
+}
+
+#[test]
+fn generate_mipmaps_filters_srgb_in_linear_space() {
+    let mut image = checkerboard(2, 2, ImageFormat::R8G8B8A8UnormSrgb);
+
+    image
+        .generate_mipmaps(MipmapFilter::Box)
+        .expect("failed to generate mipmaps");
+
+    let mip = image.mip(1).expect("failed to get mip");
+
+    // Half of linear white is brighter than half in srgb.
+    assert_eq!(mip.pixel_rgba8(0, 0), Some([0xBC, 0xBC, 0xBC, 0xFF]));
+}
+
+#[test]
+fn generate_mipmaps_kaiser_keeps_solid_colors() {
+    let mut image = Image::new(32, 32, ImageFormat::R8Unorm).expect("failed to create image");
+
+    image
+        .create_frame()
+        .expect("failed to create frame")
+        .buffer_mut()
+        .fill(0x60);
+
+    image
+        .generate_mipmaps(MipmapFilter::Kaiser)
+        .expect("failed to generate mipmaps");
+
+    assert_eq!(image.mipmaps(), 6);
+    assert!(
+        image.frames()[0]
+            .buffer()
+            .iter()
+            .all(|value| *value == 0x60)
+    );
+}
+
+#[test]
+fn generate_mipmaps_rejects_unsupported_formats() {
+    let mut image =
+        Image::new(4, 4, ImageFormat::R16G16B16A16Float).expect("failed to create image");
+
+    image.create_frame().expect("failed to create frame");
+
+    assert!(matches!(
+        image.generate_mipmaps(MipmapFilter::Box),
+        Err(TextureError::UnsupportedImageFormat(_))
+    ));
+}
+
+#[test]
+fn dds_writes_generated_mip_chains() {
+    let mut image = synthetic_image(32, 16);
+
+    image
+        .generate_mipmaps(MipmapFilter::Kaiser)
+        .expect("failed to generate mipmaps");
+
+    let mut output = Cursor::new(Vec::new());
+
+    image
+        .save_to(&mut output, ImageFileType::Dds)
+        .expect("failed to save image");
+
+    output.set_position(0);
+
+    let loaded = Image::load_from(&mut output, ImageFileType::Dds).expect("failed to load image");
+
+    assert_eq!(loaded.mipmaps(), 6);
+    assert_eq!(loaded.frames()[0].buffer(), image.frames()[0].buffer());
+}
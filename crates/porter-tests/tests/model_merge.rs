@@ -0,0 +1,53 @@
+use porter_model::Bone;
+
+use porter_tests::synthetic_model;
+
+#[test]
+fn merge_shares_bones_with_matching_names() {
+    let mut model = synthetic_model();
+
+    model.merge(synthetic_model());
+
+    assert_eq!(model.skeleton.bones.len(), 2);
+    assert_eq!(model.meshes.len(), 2);
+    assert_eq!(model.materials.len(), 2);
+    assert_eq!(model.meshes[1].material, Some(1));
+    assert_eq!(model.vertex_count(), synthetic_model().vertex_count() * 2);
+}
+
+#[test]
+fn merge_appends_and_remaps_new_bones() {
+    let mut model = synthetic_model();
+    let mut head = synthetic_model();
+
+    // The head has its own bone first, so every weight and parent needs to be remapped.
+    head.skeleton
+        .bones
+        .insert(0, Bone::new(Some(String::from("head")), -1));
+    head.skeleton.bones[1].parent = 0;
+    head.skeleton.bones[2].parent = 1;
+
+    for v in 0..head.meshes[0].vertices.len() {
+        let mut vertex = head.meshes[0].vertices.vertex_mut(v);
+
+        vertex.set_weight_bone(0, 0).set_weight_bone(1, 2);
+    }
+
+    model.merge(head);
+
+    let names: Vec<_> = model
+        .skeleton
+        .bones
+        .iter()
+        .map(|bone| bone.name.as_deref().unwrap_or_default())
+        .collect();
+
+    assert_eq!(names, ["root", "child", "head"]);
+    assert_eq!(model.skeleton.bones[2].parent, -1);
+    assert_eq!(model.skeleton.bones[0].parent, -1);
+
+    let vertex = model.meshes[1].vertices.vertex(0);
+
+    assert_eq!({ vertex.weight(0).bone }, 2);
+    assert_eq!({ vertex.weight(1).bone }, 1);
+}
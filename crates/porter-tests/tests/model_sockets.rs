@@ -0,0 +1,96 @@
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_model::ModelFileType;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+/// The tolerance used when comparing transformed positions.
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn sockets_follow_their_parent_bone() {
+    let mut model = synthetic_model();
+
+    model.skeleton.create_socket(
+        "tag_weapon",
+        Some("child"),
+        Vector3::new(1.0, 0.0, 0.0),
+        Quaternion::identity(),
+    );
+    model.skeleton.create_socket(
+        "tag_missing",
+        Some("missing"),
+        Vector3::zero(),
+        Quaternion::identity(),
+    );
+
+    assert_eq!(model.skeleton.sockets.len(), 1);
+    assert_eq!(model.skeleton.sockets[0].parent, 1);
+
+    // The child bone is at (0, 0, 10) rotated 90 degrees around z, so its x axis is the world y axis.
+    let position = model
+        .skeleton
+        .socket_world_matrix(&model.skeleton.sockets[0])
+        .position();
+
+    assert!((position - Vector3::new(0.0, 1.0, 10.0)).length() < EPSILON);
+}
+
+#[test]
+fn sockets_are_remapped_when_merged() {
+    let mut model = synthetic_model();
+    let mut attachment = synthetic_model();
+
+    attachment.skeleton.bones.swap(0, 1);
+    attachment.skeleton.bones[0].parent = 1;
+    attachment.skeleton.bones[1].parent = -1;
+    attachment.skeleton.create_socket(
+        "tag_child",
+        Some("child"),
+        Vector3::zero(),
+        Quaternion::identity(),
+    );
+
+    model.merge(attachment);
+
+    assert_eq!(model.skeleton.sockets.len(), 1);
+    assert_eq!(model.skeleton.sockets[0].parent, 1);
+}
+
+#[test]
+fn sockets_are_exported() {
+    let mut model = synthetic_model();
+
+    model.skeleton.create_socket(
+        "tag_weapon",
+        Some("child"),
+        Vector3::new(1.0, 0.0, 0.0),
+        Quaternion::identity(),
+    );
+    model.skeleton.create_socket::<_, &str>(
+        "tag_origin",
+        None,
+        Vector3::zero(),
+        Quaternion::identity(),
+    );
+
+    let directory = output_directory("model_sockets");
+
+    for (file_type, extension) in [(ModelFileType::Cast, "cast"), (ModelFileType::Fbx, "fbx")] {
+        model
+            .save(directory.join("synthetic_model"), file_type)
+            .expect("failed to save model");
+
+        let buffer = std::fs::read(directory.join("synthetic_model").with_extension(extension))
+            .expect("failed to read model");
+
+        for name in [&b"tag_weapon"[..], &b"tag_origin"[..]] {
+            assert!(
+                buffer.windows(name.len()).any(|window| window == name),
+                "{extension} is missing a socket"
+            );
+        }
+    }
+}
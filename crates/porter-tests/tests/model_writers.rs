@@ -0,0 +1,56 @@
+use porter_model::ModelFileType;
+
+use porter_tests::assert_golden_directory;
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+/// Saves the synthetic model in the given format and compares the output with the golden files.
+fn check_model_writer(name: &str, file_type: ModelFileType) {
+    let directory = output_directory(format!("model_{name}"));
+
+    synthetic_model()
+        .save(directory.join("synthetic_model"), file_type)
+        .expect("failed to save model");
+
+    assert_golden_directory(format!("model/{name}"), directory);
+}
+
+#[test]
+fn model_obj() {
+    check_model_writer("obj", ModelFileType::Obj);
+}
+
+#[test]
+fn model_smd() {
+    check_model_writer("smd", ModelFileType::Smd);
+}
+
+#[test]
+fn model_xna_lara() {
+    check_model_writer("xna_lara", ModelFileType::XnaLara);
+}
+
+#[test]
+fn model_xmodel_export() {
+    check_model_writer("xmodel_export", ModelFileType::XModelExport);
+}
+
+#[test]
+fn model_cast() {
+    check_model_writer("cast", ModelFileType::Cast);
+}
+
+#[test]
+fn model_maya() {
+    check_model_writer("maya", ModelFileType::Maya);
+}
+
+#[test]
+fn model_fbx() {
+    check_model_writer("fbx", ModelFileType::Fbx);
+}
+
+#[test]
+fn model_debug() {
+    check_model_writer("debug", ModelFileType::Debug);
+}
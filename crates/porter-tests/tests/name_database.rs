@@ -0,0 +1,39 @@
+use porter_utils::NameDatabase;
+
+use porter_tests::output_directory;
+
+/// Loads a name database from a csv file with the given contents.
+fn load_csv(name: &str, contents: &str) -> NameDatabase {
+    let path = output_directory(name).join("names.csv");
+
+    std::fs::write(&path, contents).expect("failed to write csv");
+
+    NameDatabase::load_csv(path).expect("failed to load csv")
+}
+
+#[test]
+fn load_csv_parses_prefixed_hashes_as_hex() {
+    let database = load_csv("name_database_hex", "0x10,sixteen\n0XFF,\"quoted\"\n");
+
+    assert_eq!(database.get(&0x10).map(String::as_str), Some("sixteen"));
+    assert_eq!(database.get(&0xFF).map(String::as_str), Some("quoted"));
+}
+
+#[test]
+fn load_csv_parses_unprefixed_hashes_as_decimal() {
+    let database = load_csv("name_database_decimal", "10,ten\n");
+
+    assert_eq!(database.get(&10).map(String::as_str), Some("ten"));
+    assert!(!database.contains_key(&0x10));
+}
+
+#[test]
+fn load_csv_skips_unparsable_lines() {
+    let database = load_csv(
+        "name_database_skip",
+        "hash,name\nDEADBEEF,unprefixed\n# comment\n0x1,\n2,two\n",
+    );
+
+    assert_eq!(database.len(), 1);
+    assert_eq!(database.get(&2).map(String::as_str), Some("two"));
+}
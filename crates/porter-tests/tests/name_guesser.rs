@@ -0,0 +1,77 @@
+use porter_utils::AtomicCancel;
+use porter_utils::NameGuesser;
+use porter_utils::NameHashFunction;
+use porter_utils::NameHasher;
+
+const HASHER: NameHasher = NameHasher::new(NameHashFunction::Fnv1a64);
+
+#[test]
+fn hasher_applies_mask_to_target() {
+    let hasher = HASHER.mask(0xFFFF_FFFF);
+    let hash = HASHER.hash("weapon");
+
+    assert_eq!(hasher.hash("weapon"), hash & 0xFFFF_FFFF);
+    assert!(hasher.matches("weapon", hash | 0xFFFF_0000_0000_0000));
+}
+
+#[test]
+fn wordlist_joins_words_with_separators() {
+    let target = HASHER.hash("mp_rifle_body");
+    let words = ["body", "rifle", "mp"];
+
+    let name = NameGuesser::new(HASHER).guess_wordlist(target, &words, 3, &AtomicCancel::new());
+
+    assert_eq!(name.as_deref(), Some("mp_rifle_body"));
+}
+
+#[test]
+fn wordlist_adds_prefix_and_suffix() {
+    let target = HASHER.hash("models/rifle.xmodel");
+
+    let name = NameGuesser::new(HASHER)
+        .prefix("models/")
+        .suffix(".xmodel")
+        .guess_wordlist(target, &["rifle"], 1, &AtomicCancel::new());
+
+    assert_eq!(name.as_deref(), Some("models/rifle.xmodel"));
+}
+
+#[test]
+fn wordlist_returns_none_without_match() {
+    let target = HASHER.hash("unrelated");
+
+    let name = NameGuesser::new(HASHER).guess_wordlist(
+        target,
+        &["body", "rifle"],
+        2,
+        &AtomicCancel::new(),
+    );
+
+    assert_eq!(name, None);
+}
+
+#[test]
+fn brute_force_finds_short_names() {
+    let target = HASHER.hash("tex_b2");
+
+    let name = NameGuesser::new(HASHER)
+        .prefix("tex_")
+        .charset("abc123")
+        .max_length(2)
+        .guess_brute_force(target, &AtomicCancel::new());
+
+    assert_eq!(name.as_deref(), Some("tex_b2"));
+}
+
+#[test]
+fn brute_force_stops_when_cancelled() {
+    let cancel = AtomicCancel::new();
+
+    cancel.cancel();
+
+    let name = NameGuesser::new(HASHER)
+        .max_length(8)
+        .guess_brute_force(0, &cancel);
+
+    assert_eq!(name, None);
+}
@@ -0,0 +1,118 @@
+use std::io::Cursor;
+
+use porter_audio::Audio;
+use porter_audio::AudioFileType;
+use porter_audio::AudioFormat;
+
+/// Builds a 16-bit pcm stream with a different tone in every channel.
+fn synthetic_tones(channels: u32, sample_rate: u32, frames: usize) -> Audio {
+    let mut audio = Audio::new(channels, sample_rate, 16, AudioFormat::IntegerPcm)
+        .expect("failed to create audio");
+
+    let mut data = Vec::with_capacity(frames * channels as usize * 2);
+
+    for frame in 0..frames {
+        for channel in 0..channels {
+            let frequency = 440.0 * (channel + 1) as f32;
+            let time = frame as f32 / sample_rate as f32;
+            let sample = (time * frequency * std::f32::consts::TAU).sin() * 0.5;
+
+            data.extend_from_slice(&((sample * i16::MAX as f32) as i16).to_le_bytes());
+        }
+    }
+
+    audio.set_data(data);
+    audio
+}
+
+/// Reads every sample of a 16-bit pcm stream.
+fn samples(audio: &Audio) -> Vec<f32> {
+    audio
+        .data()
+        .chunks_exact(2)
+        .map(|sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / i16::MAX as f32)
+        .collect()
+}
+
+/// Encodes the given audio to ogg vorbis, and decodes it again.
+fn round_trip(audio: &Audio) -> (usize, Audio) {
+    let mut converted = audio.clone();
+
+    converted
+        .convert(converted.format_for_file_type(AudioFileType::Ogg))
+        .expect("failed to convert audio");
+
+    assert_eq!(converted.format(), AudioFormat::FloatPcm);
+
+    let mut buffer = Cursor::new(Vec::new());
+
+    converted
+        .save_to(&mut buffer, AudioFileType::Ogg)
+        .expect("failed to encode ogg");
+
+    let size = buffer.get_ref().len();
+
+    buffer.set_position(0);
+
+    let decoded = Audio::load_from(&mut buffer, AudioFileType::Ogg).expect("failed to decode ogg");
+
+    (size, decoded)
+}
+
+#[test]
+fn ogg_vorbis_round_trips_tones() {
+    let audio = synthetic_tones(2, 48000, 72000);
+    let (size, decoded) = round_trip(&audio);
+
+    assert_eq!(decoded.channels(), 2);
+    assert_eq!(decoded.sample_rate(), 48000);
+    assert_eq!(decoded.info().frame_count, Some(72000));
+
+    // Lossy, but far smaller than the source and close to it.
+    assert!(size < audio.data().len() / 4, "{size} bytes");
+
+    let original = samples(&audio);
+    let decoded = samples(&decoded);
+
+    let signal: f32 = original.iter().map(|x| x * x).sum();
+    let noise: f32 = original
+        .iter()
+        .zip(&decoded)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum();
+
+    let snr = 10.0 * (signal / noise).log10();
+
+    assert!(snr > 20.0, "{snr} db");
+}
+
+#[test]
+fn ogg_vorbis_round_trips_short_and_silent_streams() {
+    for frames in [0, 1, 1023, 1024, 1025] {
+        let audio = synthetic_tones(1, 22050, frames);
+        let (_, decoded) = round_trip(&audio);
+
+        assert_eq!(decoded.info().frame_count, Some(frames as u64), "{frames}");
+    }
+
+    let mut silence =
+        Audio::new(3, 44100, 16, AudioFormat::IntegerPcm).expect("failed to create audio");
+
+    silence.set_data(vec![0; 44100 * 3 * 2]);
+
+    let (_, decoded) = round_trip(&silence);
+
+    assert_eq!(decoded.channels(), 3);
+    assert!(samples(&decoded).iter().all(|x| *x == 0.0));
+}
+
+#[test]
+fn ogg_vorbis_rejects_integer_samples() {
+    let audio = synthetic_tones(1, 48000, 100);
+
+    assert!(
+        audio
+            .save_to(&mut Cursor::new(Vec::new()), AudioFileType::Ogg)
+            .is_err()
+    );
+}
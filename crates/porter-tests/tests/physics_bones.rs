@@ -0,0 +1,32 @@
+use porter_model::ModelFileType;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+/// Saves the model to cast, and returns whether or not the physics property was written.
+fn cast_has_physics(name: &str, physics: bool) -> bool {
+    let mut model = synthetic_model();
+
+    model.skeleton.bones[1].physics = physics;
+
+    let directory = output_directory(name);
+
+    model
+        .save(directory.join("synthetic_model"), ModelFileType::Cast)
+        .expect("failed to save model");
+
+    let buffer =
+        std::fs::read(directory.join("synthetic_model.cast")).expect("failed to read model");
+
+    buffer.windows(3).any(|window| window == b"phy")
+}
+
+#[test]
+fn physics_bones_are_flagged_in_cast() {
+    assert!(cast_has_physics("physics_bones_flagged", true));
+}
+
+#[test]
+fn physics_flag_is_omitted_by_default() {
+    assert!(!cast_has_physics("physics_bones_default", false));
+}
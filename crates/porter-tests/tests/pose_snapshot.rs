@@ -0,0 +1,101 @@
+use porter_animation::Curve;
+use porter_animation::CurveAttribute;
+use porter_animation::CurveDataType;
+
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_model::ModelFileType;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_animation;
+use porter_tests::synthetic_model;
+
+/// The tolerance used when comparing transformed positions.
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn animations_sample_between_keyframes() {
+    let animation = synthetic_animation();
+
+    let translation: Vector3 = animation
+        .sample("root", CurveAttribute::Translate, 7)
+        .expect("missing curve")
+        .try_into()
+        .expect("expected a vector");
+
+    assert!((translation - Vector3::new(0.0, 7.0, 0.0)).length() < EPSILON);
+
+    let last: Vector3 = animation
+        .sample("root", CurveAttribute::Translate, 50)
+        .expect("missing curve")
+        .try_into()
+        .expect("expected a vector");
+
+    assert!((last - Vector3::new(0.0, 10.0, 0.0)).length() < EPSILON);
+}
+
+#[test]
+fn pose_bakes_an_animation_frame_onto_the_model() {
+    let mut model = synthetic_model();
+    let animation = synthetic_animation();
+
+    model.pose(|name| {
+        (
+            animation
+                .sample(name, CurveAttribute::Translate, 10)
+                .and_then(|value| Vector3::try_from(value).ok()),
+            animation
+                .sample(name, CurveAttribute::Rotation, 10)
+                .and_then(|value| Quaternion::try_from(value).ok()),
+        )
+    });
+
+    // The root moves 10 units along y, so every vertex weighted to it follows.
+    assert!(
+        (model.skeleton.bones[0].world_position - Vector3::new(0.0, 10.0, 0.0)).length() < EPSILON
+    );
+    assert!(
+        model
+            .skeleton
+            .bones
+            .iter()
+            .all(|bone| bone.bind_matrix.is_none())
+    );
+    assert!(
+        (model.meshes[0].vertices.vertex(0).position() - Vector3::new(0.0, 10.0, 0.0)).length()
+            < EPSILON
+    );
+
+    model
+        .save(
+            output_directory("pose_snapshot").join("synthetic_model"),
+            ModelFileType::Cast,
+        )
+        .expect("failed to save model");
+}
+
+#[test]
+fn pose_model_evaluates_an_animation_frame() {
+    let mut model = synthetic_model();
+    let mut animation = synthetic_animation();
+
+    let mut translate = Curve::new("child", CurveAttribute::Translate, CurveDataType::Relative);
+
+    translate.insert(0, Vector3::new(1.0, 0.0, 0.0));
+
+    animation.curves.push(translate);
+    animation.pose_model(&mut model, 5);
+
+    // The root is halfway through its absolute curve, and the child is offset from its rest position.
+    assert!(
+        (model.skeleton.bones[0].local_position - Vector3::new(0.0, 5.0, 0.0)).length() < EPSILON
+    );
+    assert!(
+        (model.skeleton.bones[1].local_position - Vector3::new(1.0, 0.0, 10.0)).length() < EPSILON
+    );
+    assert!(
+        (model.meshes[0].vertices.vertex(0).position() - Vector3::new(0.0, 5.0, 0.0)).length()
+            < EPSILON
+    );
+}
@@ -0,0 +1,265 @@
+use std::fs::File;
+use std::io::Cursor;
+
+use porter_cast::CastFile;
+use porter_cast::CastOptions;
+
+use porter_model::ModelFileType;
+use porter_model::ModelSaveOptions;
+
+use porter_texture::Image;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+
+use porter_utils::BufferReadExt;
+
+use porter_tests::TestRng;
+use porter_tests::output_directory;
+use porter_tests::synthetic_image;
+use porter_tests::synthetic_model;
+
+/// The number of random images generated for each image round trip.
+const IMAGE_ITERATIONS: usize = 32;
+
+/// Saves random images in the given format, loads them back, and verifies the pixels match.
+fn check_image_round_trip(name: &str, file_type: ImageFileType, seed: u64) {
+    let directory = output_directory(format!("round_trip_{name}"));
+
+    let mut rng = TestRng::new(seed);
+
+    for iteration in 0..IMAGE_ITERATIONS {
+        let width = rng.range(1, 96);
+        let height = rng.range(1, 96);
+
+        let mut image = synthetic_image(width, height);
+
+        // Every other image uses random pixels with runs, to cover raw and compressed packets.
+        if iteration % 2 == 1 {
+            for frame in image.frames_mut() {
+                for row in frame.buffer_mut().chunks_mut(width as usize * 4) {
+                    let mut pixel = [0u8; 4];
+
+                    for (index, target) in row.chunks_exact_mut(4).enumerate() {
+                        if index % rng.range(1, 8) as usize == 0 {
+                            rng.fill(&mut pixel);
+                        }
+
+                        target.copy_from_slice(&pixel);
+                    }
+                }
+            }
+        }
+
+        let path = directory.join(format!("image_{iteration}.{name}"));
+
+        let mut saved = image.clone();
+
+        saved
+            .convert(
+                saved.format_for_file_type(file_type),
+                ImageConvertOptions::None,
+            )
+            .expect("failed to convert image for saving");
+
+        saved.save(&path, file_type).unwrap_or_else(|error| {
+            panic!("failed to save {width}x{height} {name} image: {error:?}")
+        });
+
+        let mut loaded = Image::load(&path, file_type).unwrap_or_else(|error| {
+            panic!("failed to load {width}x{height} {name} image: {error:?}")
+        });
+
+        loaded
+            .convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)
+            .expect("failed to convert loaded image");
+
+        assert_eq!(loaded.width(), width);
+        assert_eq!(loaded.height(), height);
+        assert_eq!(
+            loaded.frames()[0].buffer(),
+            image.frames()[0].buffer(),
+            "{width}x{height} {name} image pixels differ after round trip"
+        );
+    }
+}
+
+#[test]
+fn round_trip_tga() {
+    check_image_round_trip("tga", ImageFileType::Tga, 0x7467_6100);
+}
+
+#[test]
+fn round_trip_tga_long_runs() {
+    let mut image = Image::new(300, 4, ImageFormat::B8G8R8A8Unorm).expect("failed to create image");
+
+    let frame = image.create_frame().expect("failed to create frame");
+
+    for (index, pixel) in frame.buffer_mut().chunks_exact_mut(4).enumerate() {
+        pixel.copy_from_slice(&[0x10, 0x20, (index / 300) as u8, 0xFF]);
+    }
+
+    let mut output = Cursor::new(Vec::new());
+
+    image
+        .save_to(&mut output, ImageFileType::Tga)
+        .expect("failed to save tga image");
+
+    // Each row splits into run-length packets of 128, 128, and 44 pixels after the 18 byte header.
+    assert_eq!(output.get_ref().len(), 18 + 4 * 3 * 5);
+
+    output.set_position(0);
+
+    let loaded = Image::load_from(&mut output, ImageFileType::Tga).expect("failed to load image");
+
+    assert_eq!(loaded.frames()[0].buffer(), image.frames()[0].buffer());
+}
+
+#[test]
+fn round_trip_dds() {
+    check_image_round_trip("dds", ImageFileType::Dds, 0x6464_7300);
+}
+
+#[test]
+fn round_trip_png() {
+    check_image_round_trip("png", ImageFileType::Png, 0x706E_6700);
+}
+
+#[test]
+fn round_trip_tiff() {
+    check_image_round_trip("tiff", ImageFileType::Tiff, 0x7469_6600);
+}
+
+#[test]
+fn round_trip_tiff_pages() {
+    let mut rng = TestRng::new(0x7469_6601);
+
+    let mut image =
+        Image::new(24, 16, ImageFormat::R16G16B16A16Unorm).expect("failed to create image");
+
+    for _ in 0..3 {
+        rng.fill(
+            image
+                .create_frame()
+                .expect("failed to create frame")
+                .buffer_mut(),
+        );
+    }
+
+    let mut output = Cursor::new(Vec::new());
+
+    image
+        .save_to(&mut output, ImageFileType::Tiff)
+        .expect("failed to save tiff image");
+
+    output.set_position(0);
+
+    let info = Image::probe_from(&mut output, ImageFileType::Tiff).expect("failed to probe image");
+
+    assert_eq!(info.frames, 3);
+
+    output.set_position(0);
+
+    let loaded = Image::load_from(&mut output, ImageFileType::Tiff).expect("failed to load image");
+
+    assert_eq!(loaded.format(), ImageFormat::R16G16B16A16Unorm);
+    assert_eq!(loaded.frames().len(), 3);
+
+    for (loaded, frame) in loaded.frames().iter().zip(image.frames()) {
+        assert_eq!(
+            loaded.buffer(),
+            frame.buffer(),
+            "tiff page differs after round trip"
+        );
+    }
+}
+
+#[test]
+fn round_trip_cast() {
+    let directory = output_directory("round_trip_cast");
+    let path = directory.join("synthetic_model.cast");
+
+    synthetic_model()
+        .save(&path, ModelFileType::Cast)
+        .expect("failed to save model");
+
+    let expected = std::fs::read(&path).expect("failed to read cast file");
+
+    let cast = CastFile::read(
+        File::open(&path)
+            .expect("failed to open cast file")
+            .buffer_read(),
+    )
+    .expect("failed to read cast file");
+
+    let mut actual = Vec::new();
+
+    cast.write(&mut actual).expect("failed to write cast file");
+
+    assert_eq!(cast.roots().len(), 1);
+    assert_eq!(actual, expected, "cast file differs after round trip");
+}
+
+#[test]
+fn round_trip_cast_compressed() {
+    let directory = output_directory("round_trip_cast_compressed");
+    let path = directory.join("synthetic_model.cast");
+
+    synthetic_model()
+        .save(&path, ModelFileType::Cast)
+        .expect("failed to save model");
+
+    let expected = std::fs::read(&path).expect("failed to read cast file");
+
+    let mut cast = CastFile::read(expected.as_slice()).expect("failed to read cast file");
+
+    cast.set_compressed(true);
+
+    let mut compressed = Vec::new();
+
+    cast.write(&mut compressed)
+        .expect("failed to write cast file");
+
+    assert!(
+        compressed.len() < expected.len(),
+        "compression had no effect"
+    );
+
+    let mut cast = CastFile::read(compressed.as_slice()).expect("failed to read cast file");
+
+    assert!(cast.compressed());
+
+    cast.set_compressed(false);
+
+    let mut actual = Vec::new();
+
+    cast.write(&mut actual).expect("failed to write cast file");
+
+    assert_eq!(
+        actual, expected,
+        "cast file differs after compressed round trip"
+    );
+}
+
+#[test]
+fn cast_options_compress_saved_models() {
+    let directory = output_directory("cast_options_compress_saved_models");
+    let path = directory.join("synthetic_model.cast");
+
+    let options = ModelSaveOptions {
+        cast: CastOptions {
+            compressed: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    synthetic_model()
+        .save_with_options(&path, ModelFileType::Cast, options)
+        .expect("failed to save model");
+
+    let buffer = std::fs::read(&path).expect("failed to read cast file");
+    let cast = CastFile::read(buffer.as_slice()).expect("failed to read cast file");
+
+    assert!(cast.compressed());
+}
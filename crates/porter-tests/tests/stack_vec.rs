@@ -0,0 +1,128 @@
+use std::io::Write;
+
+use porter_utils::StackVec;
+
+#[test]
+fn stays_on_the_stack_within_capacity() {
+    let mut vec = StackVec::new([0u32; 4]);
+
+    vec.extend_from_slice([1, 2, 3]);
+    vec.push(4);
+
+    assert!(!vec.spilled());
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(&vec[..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn spills_to_the_heap_when_full() {
+    let mut vec = StackVec::new([0u32; 2]);
+
+    vec.push(1);
+    vec.push(2);
+    vec.push(3);
+
+    assert!(vec.spilled());
+    assert!(vec.capacity() >= 3);
+    assert_eq!(&vec[..], &[1, 2, 3]);
+
+    vec.extend_from_slice([4, 5]);
+    vec.insert(0, 0);
+
+    assert_eq!(vec.into_vec(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn insert_remove_and_pop() {
+    let mut vec = StackVec::new([0u8; 3]);
+
+    vec.extend_from_slice([1, 3]);
+    vec.insert(1, 2);
+
+    assert_eq!(&vec[..], &[1, 2, 3]);
+    assert_eq!(vec.remove(0), 1);
+    assert_eq!(&vec[..], &[2, 3]);
+
+    // Inserting past the stack capacity spills in place.
+    vec.insert(1, 9);
+    vec.insert(1, 8);
+
+    assert!(vec.spilled());
+    assert_eq!(&vec[..], &[2, 8, 9, 3]);
+    assert_eq!(vec.remove(3), 3);
+    assert_eq!(vec.pop(), Some(9));
+    assert_eq!(vec.pop(), Some(8));
+    assert_eq!(vec.pop(), Some(2));
+    assert_eq!(vec.pop(), None);
+}
+
+#[test]
+fn resize_and_truncate() {
+    let mut vec: StackVec<u16, 4> = StackVec::default();
+
+    vec.resize(3, 7);
+
+    assert_eq!(&vec[..], &[7, 7, 7]);
+
+    vec.resize(6, 1);
+
+    assert!(vec.spilled());
+    assert_eq!(&vec[..], &[7, 7, 7, 1, 1, 1]);
+
+    vec.truncate(2);
+
+    assert_eq!(&vec[..], &[7, 7]);
+
+    vec.clear();
+
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn writes_past_capacity() {
+    let mut vec = StackVec::new([0u8; 4]);
+
+    vec.write_all(b"hello world").unwrap();
+
+    assert_eq!(&vec[..], b"hello world");
+}
+
+#[test]
+fn iterates_and_collects() {
+    let vec: StackVec<u32, 2> = (1..=5).collect();
+
+    assert_eq!(vec.len(), 5);
+    assert_eq!((&vec).into_iter().sum::<u32>(), 15);
+
+    let mut doubled = vec.clone();
+
+    for value in &mut doubled {
+        *value *= 2;
+    }
+
+    assert_eq!(
+        doubled.into_iter().collect::<Vec<_>>(),
+        vec![2, 4, 6, 8, 10]
+    );
+
+    let mut extended = StackVec::new([0u32; 8]);
+
+    extended.extend(vec.iter());
+
+    assert_eq!(extended, vec);
+    assert_eq!(format!("{vec:?}"), "[1, 2, 3, 4, 5]");
+}
+
+#[test]
+fn try_reserve_spills() {
+    let mut vec = StackVec::new([0u8; 2]);
+
+    vec.try_reserve(1).unwrap();
+
+    assert!(!vec.spilled());
+
+    vec.try_reserve_exact(8).unwrap();
+
+    assert!(vec.spilled());
+    assert!(vec.capacity() >= 8);
+}
@@ -0,0 +1,52 @@
+use porter_utils::AsHexString;
+use porter_utils::StringCaseExt;
+
+#[test]
+fn snake_and_kebab_case() {
+    assert_eq!("HTTPServerError".to_snake_case(), "http_server_error");
+    assert_eq!("diffuseMap2".to_snake_case(), "diffuse_map2");
+    assert_eq!("Export Folder Name".to_snake_case(), "export_folder_name");
+    assert_eq!(
+        "  leading--and__trailing  ".to_kebab_case(),
+        "leading-and-trailing"
+    );
+    assert_eq!("xmodel_export".to_kebab_case(), "xmodel-export");
+    assert_eq!("".to_snake_case(), "");
+}
+
+#[test]
+fn title_words_keep_acronyms() {
+    assert_eq!("raw_files".to_title_words(), "Raw Files");
+    assert_eq!("HTTPServer".to_title_words(), "HTTP Server");
+    assert_eq!("normalMapDX".to_title_words(), "Normal Map DX");
+    assert_eq!("UV layer 2".to_title_words(), "UV Layer 2");
+}
+
+#[test]
+fn title_words_with_known_acronyms() {
+    let acronyms = ["bc7", "srgb", "lod"];
+
+    assert_eq!(
+        "bc7_unorm_srgb".to_title_words_with(&acronyms),
+        "BC7 Unorm SRGB"
+    );
+    assert_eq!(
+        String::from("lodCount").to_title_words_with(&acronyms),
+        "LOD Count"
+    );
+}
+
+#[test]
+fn titlecase_only_changes_the_first_char() {
+    assert_eq!("porter app".to_titlecase(), "Porter app");
+    assert_eq!(String::new().to_titlecase(), "");
+}
+
+#[test]
+fn hex_strings() {
+    assert_eq!(0xC0FFEEu32.as_hex_string(), "0x00c0ffee");
+    assert_eq!(0xABu8.as_hex_string(), "0xab");
+    assert_eq!(0x1234u64.as_hex_string(), "0x0000000000001234");
+    assert_eq!(0x1234u64.as_hex_string_width(0), "0x1234");
+    assert_eq!(0x1234u64.as_hex_string_width(6), "0x001234");
+}
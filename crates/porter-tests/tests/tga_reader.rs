@@ -0,0 +1,85 @@
+use std::io::Cursor;
+
+use porter_texture::Image;
+use porter_texture::ImageFileType;
+use porter_texture::ImageFormat;
+
+/// Builds a 2x2 tga file with the given image type, bits per pixel, descriptor, and pixel data.
+fn tga_file(image_type: u8, bits_per_pixel: u8, image_descriptor: u8, data: &[u8]) -> Vec<u8> {
+    let mut file = vec![0, 0, image_type, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0];
+
+    file.push(bits_per_pixel);
+    file.push(image_descriptor);
+    file.extend_from_slice(data);
+    file
+}
+
+/// Loads a tga file, returning the image.
+fn load_tga(file: Vec<u8>) -> Image {
+    let info = Image::probe_from(&mut Cursor::new(file.clone()), ImageFileType::Tga)
+        .expect("failed to probe tga");
+
+    let image =
+        Image::load_from(&mut Cursor::new(file), ImageFileType::Tga).expect("failed to load tga");
+
+    assert_eq!(info.format, image.format());
+
+    image
+}
+
+/// The pixels of each 2x2 test image in bgra, top-left first.
+const PIXELS: [[u8; 4]; 4] = [
+    [0x01, 0x02, 0x03, 0xFF],
+    [0x11, 0x12, 0x13, 0xFF],
+    [0x21, 0x22, 0x23, 0xFF],
+    [0x31, 0x32, 0x33, 0xFF],
+];
+
+/// Asserts the image matches the expected pixels.
+fn assert_pixels(image: &Image) {
+    assert_eq!(image.format(), ImageFormat::B8G8R8A8Unorm);
+    assert_eq!(image.frames()[0].buffer(), PIXELS.concat());
+}
+
+#[test]
+fn tga_reads_24_bit_bottom_up() {
+    // Bottom row first, without alpha.
+    let data = [
+        &PIXELS[2][..3],
+        &PIXELS[3][..3],
+        &PIXELS[0][..3],
+        &PIXELS[1][..3],
+    ]
+    .concat();
+
+    assert_pixels(&load_tga(tga_file(2, 24, 0, &data)));
+}
+
+#[test]
+fn tga_reads_24_bit_run_length_encoded() {
+    let mut data = vec![0x01];
+
+    for pixel in &PIXELS {
+        if data.len() == 7 {
+            data.push(0x01);
+        }
+
+        data.extend_from_slice(&pixel[..3]);
+    }
+
+    assert_pixels(&load_tga(tga_file(10, 24, 32, &data)));
+}
+
+#[test]
+fn tga_reads_32_bit_right_to_left() {
+    let data = [PIXELS[1], PIXELS[0], PIXELS[3], PIXELS[2]].concat();
+
+    assert_pixels(&load_tga(tga_file(2, 32, 32 | 16 | 8, &data)));
+}
+
+#[test]
+fn tga_rejects_mismatched_grayscale_depth() {
+    let file = tga_file(3, 24, 32, &[0; 12]);
+
+    assert!(Image::load_from(&mut Cursor::new(file), ImageFileType::Tga).is_err());
+}
@@ -0,0 +1,44 @@
+use std::fs;
+
+use porter_model::ModelFileType;
+use porter_model::UvLayer;
+use porter_model::UvLayerSemantic;
+
+use porter_tests::output_directory;
+use porter_tests::synthetic_model;
+
+#[test]
+fn uv_layers_named_in_maya() {
+    let directory = output_directory("uv_layers_maya");
+
+    let mut model = synthetic_model();
+
+    model.meshes[0]
+        .uv_layers
+        .push(UvLayer::new(Some("lightmap"), UvLayerSemantic::Lightmap));
+
+    assert_eq!(
+        model.meshes[0].find_uv_layer(UvLayerSemantic::Lightmap),
+        Some(0)
+    );
+
+    model
+        .save(directory.join("synthetic_model"), ModelFileType::Maya)
+        .expect("failed to save model");
+
+    let maya = fs::read_to_string(directory.join("synthetic_model.ma")).expect("failed to read ma");
+
+    assert!(maya.contains("\".uvst[0].uvsn\" -type \"string\" \"lightmap\""));
+    assert!(maya.contains("\".cuvs\" -type \"string\" \"lightmap\""));
+}
+
+#[test]
+fn uv_layers_default_names() {
+    let model = synthetic_model();
+
+    assert_eq!(model.meshes[0].uv_layer_name(0), "map1");
+    assert_eq!(
+        model.meshes[0].uv_layer_semantic(0),
+        UvLayerSemantic::Diffuse
+    );
+}
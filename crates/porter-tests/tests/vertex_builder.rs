@@ -0,0 +1,207 @@
+use porter_math::Vector2;
+use porter_math::Vector3;
+
+use porter_model::Face;
+use porter_model::FaceBuffer;
+use porter_model::Mesh;
+use porter_model::Model;
+use porter_model::ModelError;
+use porter_model::VertexBuffer;
+use porter_model::VertexChannel;
+use porter_model::VertexColor;
+use porter_model::VertexWeight;
+use porter_model::WeightNormalization;
+
+#[test]
+fn vertex_builder_fills_channels() {
+    let vertices = VertexBuffer::builder()
+        .positions(vec![
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+        ])
+        .normals(vec![Vector3::new(0.0, 1.0, 0.0); 2])
+        .uv_channel(vec![Vector2::new(0.25, 0.5), Vector2::new(0.75, 1.0)])
+        .uv_channel(vec![Vector2::new(0.0, 0.0); 2])
+        .color_channel(vec![VertexColor::new(1, 2, 3, 4); 2])
+        .weights(
+            2,
+            vec![
+                VertexWeight::new(0, 0.75),
+                VertexWeight::new(1, 0.25),
+                VertexWeight::new(1, 1.0),
+                VertexWeight::new(0, 0.0),
+            ],
+        )
+        .bone_count(2)
+        .try_build()
+        .expect("valid channels should build");
+
+    assert_eq!(vertices.len(), 2);
+    assert_eq!(vertices.uv_layers(), 2);
+    assert_eq!(vertices.colors(), 1);
+    assert_eq!(vertices.maximum_influence(), 2);
+
+    let vertex = vertices.vertex(1);
+
+    assert_eq!(vertex.position(), Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(vertex.uv(0), Vector2::new(0.75, 1.0));
+    assert_eq!(vertex.color(0).a, 4);
+    assert_eq!({ vertex.weight(0).bone }, 1);
+    assert_eq!({ vertex.weight(0).value }, 1.0);
+}
+
+#[test]
+fn vertex_builder_rejects_invalid_channels() {
+    let positions = vec![Vector3::zero(); 3];
+
+    let result = VertexBuffer::builder()
+        .uv_channel(vec![Vector2::zero(); 3])
+        .try_build();
+
+    assert!(matches!(
+        result,
+        Err(ModelError::MissingVertexChannel(VertexChannel::Position))
+    ));
+
+    let result = VertexBuffer::builder()
+        .positions(positions.clone())
+        .uv_channel(vec![Vector2::zero(); 3])
+        .uv_channel(vec![Vector2::zero(); 2])
+        .try_build();
+
+    assert!(matches!(
+        result,
+        Err(ModelError::InvalidVertexChannelLength(
+            VertexChannel::Uv(1),
+            2
+        ))
+    ));
+
+    let result = VertexBuffer::builder()
+        .positions(positions.clone())
+        .normals(vec![
+            Vector3::zero(),
+            Vector3::new(f32::NAN, 0.0, 0.0),
+            Vector3::zero(),
+        ])
+        .try_build();
+
+    assert!(matches!(
+        result,
+        Err(ModelError::InvalidVertexChannelValue(
+            VertexChannel::Normal,
+            1
+        ))
+    ));
+
+    let result = VertexBuffer::builder()
+        .positions(positions)
+        .weights(1, vec![VertexWeight::new(0, 1.0); 3])
+        .bone_count(0)
+        .try_build();
+
+    assert!(matches!(
+        result,
+        Err(ModelError::InvalidVertexWeightBone(0, 0))
+    ));
+}
+
+#[test]
+fn vertex_builder_normalizes_weights() {
+    let vertices = VertexBuffer::builder()
+        .positions(vec![Vector3::zero(); 3])
+        .weights(
+            3,
+            vec![
+                VertexWeight::new(2, 0.5),
+                VertexWeight::new(1, 0.0000001),
+                VertexWeight::new(2, 0.5),
+                VertexWeight::new(0, 0.2),
+                VertexWeight::new(1, 0.2),
+                VertexWeight::new(3, 0.0),
+                VertexWeight::new(4, 0.0),
+                VertexWeight::new(5, 0.0),
+                VertexWeight::new(6, 0.0),
+            ],
+        )
+        .normalize_weights(WeightNormalization::default())
+        .try_build()
+        .expect("valid channels should build");
+
+    let merged = vertices.vertex(0);
+
+    assert_eq!({ merged.weight(0).bone }, 2);
+    assert_eq!({ merged.weight(0).value }, 1.0);
+    assert_eq!({ merged.weight(1).value }, 0.0);
+
+    let scaled = vertices.vertex(1);
+
+    assert_eq!({ scaled.weight(0).value }, 0.5);
+    assert_eq!({ scaled.weight(1).value }, 0.5);
+
+    let rigid = vertices.vertex(2);
+
+    assert_eq!({ rigid.weight(0).value }, 1.0);
+
+    let mut vertices = VertexBuffer::builder()
+        .positions(vec![Vector3::zero()])
+        .weights(
+            2,
+            vec![VertexWeight::new(0, 0.01), VertexWeight::new(1, 0.99)],
+        )
+        .try_build()
+        .expect("valid channels should build");
+
+    vertices.normalize_weights(WeightNormalization::new(0.05, true));
+
+    let pruned = vertices.vertex(0);
+
+    assert_eq!({ pruned.weight(0).bone }, 1);
+    assert_eq!({ pruned.weight(0).value }, 1.0);
+}
+
+#[test]
+fn vertex_builder_generates_missing_normals() {
+    let vertices = VertexBuffer::builder()
+        .positions(vec![
+            Vector3::zero(),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ])
+        .try_build()
+        .expect("valid channels should build");
+
+    assert!(!vertices.has_normals());
+
+    let faces: FaceBuffer = vec![Face::new(0, 1, 2)];
+
+    let mut model = Model::new();
+
+    model.meshes.push(Mesh::new(faces, vertices));
+
+    let generated = model
+        .generate_missing_normals()
+        .expect("failed to generate normals");
+
+    let mesh = &generated.meshes[0];
+
+    assert!(mesh.vertices.has_normals());
+    assert_eq!(
+        mesh.vertices.vertex(0).normal().length_squared().round(),
+        1.0
+    );
+    assert!(!model.meshes[0].vertices.has_normals());
+}
+
+#[test]
+fn vertex_builder_is_reusable() {
+    let builder = VertexBuffer::builder().uv_layers(2).colors(1);
+
+    let first = builder.build();
+    let second = builder.maximum_influence(4).build();
+
+    assert_eq!(first.uv_layers(), 2);
+    assert_eq!(first.maximum_influence(), 0);
+    assert_eq!(second.colors(), 1);
+    assert_eq!(second.maximum_influence(), 4);
+}
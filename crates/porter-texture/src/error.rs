@@ -1,3 +1,5 @@
+use porter_utils::ReportError;
+
 use crate::ImageFileType;
 use crate::ImageFormat;
 
@@ -14,11 +16,14 @@ pub enum TextureError {
     ContainerFormatInvalid(ImageFormat, ImageFileType),
     ContainerInvalid(ImageFileType),
     ConversionError,
+    GPUUnavailable,
     InvalidOperation,
+    Cancelled,
     IoError(std::io::Error),
     PngEncodingError(png::EncodingError),
     PngDecodingError(png::DecodingError),
     TiffError(tiff::TiffError),
+    InvalidChannelRemap(String),
 }
 
 impl From<png::EncodingError> for TextureError {
@@ -44,3 +49,63 @@ impl From<tiff::TiffError> for TextureError {
         Self::TiffError(value)
     }
 }
+
+impl ReportError for TextureError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::InvalidImageFormat(_) => "TEX-001",
+            Self::InvalidDxgiFormat(_) => "TEX-002",
+            Self::UnsupportedImageFormat(_) => "TEX-003",
+            Self::InvalidImageSize(_, _) => "TEX-004",
+            Self::InvalidFrameSize(_, _) => "TEX-005",
+            Self::InvalidMipMaps(_) => "TEX-006",
+            Self::FrameAllocationFailed => "TEX-007",
+            Self::ContainerFormatInvalid(_, _) => "TEX-008",
+            Self::ContainerInvalid(_) => "TEX-009",
+            Self::ConversionError => "TEX-010",
+            Self::GPUUnavailable => "TEX-011",
+            Self::InvalidOperation => "TEX-012",
+            Self::Cancelled => "TEX-013",
+            Self::IoError(e) => e.error_code(),
+            Self::PngEncodingError(_) => "TEX-014",
+            Self::PngDecodingError(_) => "TEX-015",
+            Self::TiffError(_) => "TEX-016",
+            Self::InvalidChannelRemap(_) => "TEX-017",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::InvalidImageFormat(format) => format!("The image format {format:?} is invalid."),
+            Self::InvalidDxgiFormat(format) => format!("The dxgi format {format} is invalid."),
+            Self::UnsupportedImageFormat(format) => {
+                format!("The image format {format:?} is not supported.")
+            }
+            Self::InvalidImageSize(width, height) => {
+                format!("The image size {width}x{height} is invalid.")
+            }
+            Self::InvalidFrameSize(width, height) => {
+                format!("The image frame size {width}x{height} is invalid.")
+            }
+            Self::InvalidMipMaps(mips) => format!("The image mip count {mips} is invalid."),
+            Self::FrameAllocationFailed => String::from("The system ran out of memory."),
+            Self::ContainerFormatInvalid(format, file_type) => {
+                format!("The image format {format:?} can't be saved as {file_type:?}.")
+            }
+            Self::ContainerInvalid(file_type) => {
+                format!("The {file_type:?} image file is invalid.")
+            }
+            Self::ConversionError => String::from("The image could not be converted."),
+            Self::GPUUnavailable => {
+                String::from("A graphics device is required to convert the image.")
+            }
+            Self::InvalidOperation => String::from("The image doesn't support this operation."),
+            Self::Cancelled => String::from("The image conversion was cancelled."),
+            Self::IoError(e) => e.error_message(),
+            Self::PngEncodingError(_) => String::from("The image could not be saved as png."),
+            Self::PngDecodingError(_) => String::from("The png image could not be read."),
+            Self::TiffError(_) => String::from("The tiff image could not be read or saved."),
+            Self::InvalidChannelRemap(spec) => format!("The channel remap \"{spec}\" is invalid."),
+        }
+    }
+}
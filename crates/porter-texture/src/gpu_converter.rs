@@ -8,7 +8,7 @@ use porter_utils::AsAligned;
 use porter_utils::AsByteSlice;
 
 use porter_gpu::GPUInstance;
-use porter_gpu::gpu_instance;
+use porter_gpu::try_gpu_instance;
 
 use crate::ImageConvertOptions;
 use crate::TextureError;
@@ -37,21 +37,21 @@ pub struct GPUConverter {
 }
 
 impl GPUConverter {
-    /// Creates a new instance of the GPU converter.
+    /// Creates a new instance of the GPU converter, fails if no GPU adapter is available.
     pub fn new(
         width: u32,
         height: u32,
         input_format: TextureFormat,
         output_format: TextureFormat,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, TextureError> {
+        Ok(Self {
             width,
             height,
             input_format,
             output_format,
             options: Default::default(),
-            instance: gpu_instance(),
-        }
+            instance: try_gpu_instance().ok_or(TextureError::GPUUnavailable)?,
+        })
     }
 
     /// Sets conversion options.
@@ -219,9 +219,8 @@ impl GPUConverter {
             ImageConvertOptions::ReconstructZ | ImageConvertOptions::ReconstructZInvertY => {
                 "fs_rz_main"
             }
-            ImageConvertOptions::BumpmapReconstructZ | ImageConvertOptions::BumpmapReconstructZInvertY => {
-                "fs_rz_bm_main"
-            }
+            ImageConvertOptions::BumpmapReconstructZ
+            | ImageConvertOptions::BumpmapReconstructZInvertY => "fs_rz_bm_main",
             ImageConvertOptions::AutoReconstructZ
             | ImageConvertOptions::AutoReconstructZInvertY => {
                 if matches!(self.input_format, TextureFormat::Bc5RgUnorm) {
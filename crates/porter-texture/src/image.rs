@@ -1,8 +1,11 @@
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::BufRead;
 use std::io::Seek;
 use std::io::Write;
+use std::ops::Range;
 use std::path::Path;
+use std::path::PathBuf;
 
 use wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
 
@@ -15,17 +18,26 @@ use porter_math::Rect;
 
 use crate::Frame;
 use crate::GPUConverter;
+use crate::ImageBlendMode;
+use crate::ImageChannelRemap;
+use crate::ImageCompressionQuality;
 use crate::ImageConvertOptions;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageInfo;
+use crate::ImageSaveOptions;
+use crate::ImageSaveProgress;
+use crate::MipmapFilter;
 use crate::ResizeAlgorithm;
 use crate::TextureError;
 use crate::TextureExtensions;
 use crate::TransformAlgorithm;
+use crate::decode_bc1_block;
 use crate::image_file_type_dds;
 use crate::image_file_type_png;
 use crate::image_file_type_tga;
 use crate::image_file_type_tiff;
+use crate::software_compress_image;
 use crate::software_swizzle_image;
 use crate::software_unpack_image;
 
@@ -191,7 +203,7 @@ impl Image {
             let mut buffer = Vec::try_new_with_value(0, size)
                 .map_err(|_| TextureError::FrameAllocationFailed)?;
 
-            let mut converter = GPUConverter::new(width, height, source_format, target_format);
+            let mut converter = GPUConverter::new(width, height, source_format, target_format)?;
 
             converter.set_options(options);
             converter.convert(frame.buffer(), &mut buffer)?;
@@ -222,6 +234,16 @@ impl Image {
         Ok(())
     }
 
+    /// Block compresses all frames and mipmaps of the image to the specified format, using the given quality preset.
+    /// Supports Bc1, Bc3, Bc4, Bc5, and Bc7 formats.
+    pub fn compress(
+        &mut self,
+        format: ImageFormat,
+        quality: ImageCompressionQuality,
+    ) -> Result<(), TextureError> {
+        software_compress_image(self, format, quality)
+    }
+
     /// Transforms the image using the given algorithm.
     pub fn transform(&mut self, algorithm: TransformAlgorithm) -> Result<(), TextureError> {
         algorithm.transform(self)?;
@@ -249,6 +271,61 @@ impl Image {
         Ok(())
     }
 
+    /// Composites the first frame of the layer over each frame of this image with the given blend mode and opacity.
+    /// The layer is resized to fit, its alpha scales the opacity, and this image keeps its own alpha.
+    /// This will convert the image to R8G8B8A8 and drop any mipmaps if they exist.
+    pub fn overlay(
+        &mut self,
+        layer: &Image,
+        mode: ImageBlendMode,
+        opacity: f32,
+    ) -> Result<(), TextureError> {
+        let format = if self.format.is_srgb() {
+            ImageFormat::R8G8B8A8UnormSrgb
+        } else {
+            ImageFormat::R8G8B8A8Unorm
+        };
+
+        if self.mipmaps > 1 {
+            *self = self.mip(0)?;
+        }
+
+        self.convert(format, ImageConvertOptions::None)?;
+
+        let mut layer = layer.clone();
+
+        layer.convert(format, ImageConvertOptions::None)?;
+
+        if layer.width != self.width || layer.height != self.height {
+            layer.resize(self.width, self.height, ResizeAlgorithm::Bicubic)?;
+        }
+
+        let Some(layer_frame) = layer.frames.first() else {
+            return Err(TextureError::InvalidOperation);
+        };
+
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        for frame in &mut self.frames {
+            for (pixel, layer_pixel) in frame
+                .buffer_mut()
+                .chunks_exact_mut(4)
+                .zip(layer_frame.buffer().chunks_exact(4))
+            {
+                let amount = opacity * (layer_pixel[3] as f32 / 255.0);
+
+                for channel in 0..3 {
+                    let base = pixel[channel] as f32 / 255.0;
+                    let blended = mode.blend(base, layer_pixel[channel] as f32 / 255.0);
+
+                    pixel[channel] = ((base + (blended - base) * amount) * 255.0).round() as u8;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Copies a rectangle from the given src image to the destination in this image,
     /// truncating the image as necessary on any edge. Both formats must be the same,
     /// and not a compressed image format.
@@ -448,19 +525,70 @@ impl Image {
         }
     }
 
+    /// Reads the metadata of an image from the given path, without decoding the image data.
+    pub fn probe<P: AsRef<Path>>(
+        path: P,
+        file_type: ImageFileType,
+    ) -> Result<ImageInfo, TextureError> {
+        Self::probe_from(&mut File::open(path)?.buffer_read(), file_type)
+    }
+
+    /// Reads the metadata of an image from the given input buffer, without decoding the image data.
+    pub fn probe_from<I: BufRead + Seek>(
+        input: &mut I,
+        file_type: ImageFileType,
+    ) -> Result<ImageInfo, TextureError> {
+        match file_type {
+            ImageFileType::Dds => image_file_type_dds::probe_dds(input),
+            ImageFileType::Png => image_file_type_png::probe_png(input),
+            ImageFileType::Tiff => image_file_type_tiff::probe_tiff(input),
+            ImageFileType::Tga => image_file_type_tga::probe_tga(input),
+        }
+    }
+
     /// Saves the image to the given file path in the given image file type.
     pub fn save<P: AsRef<Path>>(
         &self,
         path: P,
         file_type: ImageFileType,
     ) -> Result<(), TextureError> {
+        self.save_with_options(path, file_type, ImageSaveOptions::default())
+    }
+
+    /// Saves the image to the given file path in the given image file type, using the given options.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ImageFileType,
+        options: ImageSaveOptions,
+    ) -> Result<(), TextureError> {
+        self.save_with_progress(path, file_type, options, &mut ImageSaveProgress::new())
+    }
+
+    /// Saves the image to the given file path in the given image file type, using the given options, reporting progress for each frame.
+    /// When the save is cancelled, the partially written file is removed.
+    pub fn save_with_progress<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ImageFileType,
+        options: ImageSaveOptions,
+        progress: &mut ImageSaveProgress,
+    ) -> Result<(), TextureError> {
+        let path = path.as_ref();
+
         let mut output = File::create(path)?.buffer_write();
 
-        self.save_to(&mut output, file_type)?;
+        let result = self
+            .save_to_with_progress(&mut output, file_type, options, progress)
+            .and_then(|_| Ok(output.flush()?));
 
-        output.flush()?;
+        if matches!(result, Err(TextureError::Cancelled)) {
+            drop(output);
 
-        Ok(())
+            let _ = std::fs::remove_file(path);
+        }
+
+        result
     }
 
     /// Saves the image to the given output buffer in the given image file type.
@@ -468,12 +596,33 @@ impl Image {
         &self,
         output: &mut O,
         file_type: ImageFileType,
+    ) -> Result<(), TextureError> {
+        self.save_to_with_options(output, file_type, ImageSaveOptions::default())
+    }
+
+    /// Saves the image to the given output buffer in the given image file type, using the given options.
+    pub fn save_to_with_options<O: Write + Seek>(
+        &self,
+        output: &mut O,
+        file_type: ImageFileType,
+        options: ImageSaveOptions,
+    ) -> Result<(), TextureError> {
+        self.save_to_with_progress(output, file_type, options, &mut ImageSaveProgress::new())
+    }
+
+    /// Saves the image to the given output buffer in the given image file type, using the given options, reporting progress for each frame.
+    pub fn save_to_with_progress<O: Write + Seek>(
+        &self,
+        output: &mut O,
+        file_type: ImageFileType,
+        options: ImageSaveOptions,
+        progress: &mut ImageSaveProgress,
     ) -> Result<(), TextureError> {
         match file_type {
-            ImageFileType::Dds => image_file_type_dds::to_dds(self, output),
-            ImageFileType::Png => image_file_type_png::to_png(self, output),
-            ImageFileType::Tiff => image_file_type_tiff::to_tiff(self, output),
-            ImageFileType::Tga => image_file_type_tga::to_tga(self, output),
+            ImageFileType::Dds => image_file_type_dds::to_dds(self, output, progress),
+            ImageFileType::Png => image_file_type_png::to_png(self, output, options, progress),
+            ImageFileType::Tiff => image_file_type_tiff::to_tiff(self, output, options, progress),
+            ImageFileType::Tga => image_file_type_tga::to_tga(self, output, progress),
         }
     }
 
@@ -550,8 +699,389 @@ impl Image {
         &mut self.frames
     }
 
+    /// Keeps only the frames in the given range, which is clamped to the number of frames.
+    pub fn retain_frames(&mut self, range: Range<usize>) {
+        let end = range.end.min(self.frames.len());
+        let start = range.start.min(end);
+
+        self.frames.truncate(end);
+        self.frames.drain(..start);
+    }
+
+    /// Returns the dimensions of the given mip level, each side is at least one pixel.
+    pub fn mip_size(&self, level: u32) -> (u32, u32) {
+        (
+            self.width.checked_shr(level).unwrap_or_default().max(1),
+            self.height.checked_shr(level).unwrap_or_default().max(1),
+        )
+    }
+
+    /// Returns the first mip level whose frames fit within the given budget in bytes, or the last mip if none fit.
+    pub fn mip_for_budget(&self, budget: u64) -> u32 {
+        let frames = self.frames.len().max(1) as u64;
+
+        (0..self.mipmaps)
+            .find(|level| {
+                let (width, height) = self.mip_size(*level);
+
+                self.format.buffer_size(width, height) as u64 * frames <= budget
+            })
+            .unwrap_or(self.mipmaps.saturating_sub(1))
+    }
+
+    /// Creates a new image from the given mip level of each frame, without any further mipmaps.
+    pub fn mip(&self, level: u32) -> Result<Image, TextureError> {
+        if level >= self.mipmaps {
+            return Err(TextureError::InvalidMipMaps(level));
+        }
+
+        let (width, height) = self.mip_size(level);
+
+        let offset = self.frame_size_with_mipmaps(self.width, self.height, level) as usize;
+        let size = self.format.buffer_size(width, height) as usize;
+
+        let mut image = Image::new(width, height, self.format)?;
+
+        for frame in &self.frames {
+            let source = frame
+                .buffer()
+                .get(offset..offset + size)
+                .ok_or(TextureError::InvalidOperation)?;
+
+            image.create_frame()?.buffer_mut().copy_from_slice(source);
+        }
+
+        Ok(image)
+    }
+
+    /// Generates the full mip chain of each frame from the first mip using the given filter, replacing any existing mipmaps.
+    /// The format must be an 8 bit per channel R, RG, RGBA, or BGRA format.
+    pub fn generate_mipmaps(&mut self, filter: MipmapFilter) -> Result<(), TextureError> {
+        filter.generate(self)
+    }
+
+    /// Returns the metadata of this image.
+    pub fn info(&self) -> ImageInfo {
+        ImageInfo {
+            width: self.width,
+            height: self.height,
+            mipmaps: self.mipmaps,
+            frames: self.frames.len() as u32,
+            format: self.format,
+        }
+    }
+
+    /// Whether or not the image has meaningful alpha, where at least one pixel isn't fully opaque.
+    pub fn has_alpha(&self) -> Result<bool, TextureError> {
+        let image = self.to_rgba8()?;
+
+        Ok(image
+            .frames()
+            .iter()
+            .any(|frame| frame.buffer().chunks_exact(4).any(|pixel| pixel[3] != 0xFF)))
+    }
+
+    /// Whether or not the image looks like it has premultiplied alpha, where every color channel is at most the alpha value.
+    /// This is only a hint, images with straight alpha can match as well.
+    pub fn is_likely_premultiplied(&self) -> Result<bool, TextureError> {
+        let image = self.to_rgba8()?;
+
+        let mut has_alpha = false;
+
+        for frame in image.frames() {
+            for pixel in frame.buffer().chunks_exact(4) {
+                if pixel[0] > pixel[3] || pixel[1] > pixel[3] || pixel[2] > pixel[3] {
+                    return Ok(false);
+                }
+
+                has_alpha |= pixel[3] != 0xFF;
+            }
+        }
+
+        Ok(has_alpha)
+    }
+
+    /// Extracts the alpha channel of each frame into a new grayscale mask image, without mipmaps.
+    pub fn alpha_mask(&self) -> Result<Image, TextureError> {
+        let image = self.to_rgba8()?;
+
+        let mut mask = Image::new(self.width, self.height, ImageFormat::R8Unorm)?;
+
+        for source in image.frames() {
+            let frame = mask.create_frame()?;
+
+            for (value, pixel) in frame
+                .buffer_mut()
+                .iter_mut()
+                .zip(source.buffer().chunks_exact(4))
+            {
+                *value = pixel[3];
+            }
+        }
+
+        Ok(mask)
+    }
+
+    /// Saves the alpha channel as a grayscale mask to the given path, if the image has meaningful alpha.
+    /// Returns whether or not the mask was written.
+    pub fn save_alpha_mask<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ImageFileType,
+    ) -> Result<bool, TextureError> {
+        let mut mask = self.alpha_mask()?;
+
+        if mask
+            .frames()
+            .iter()
+            .all(|frame| frame.buffer().iter().all(|value| *value == 0xFF))
+        {
+            return Ok(false);
+        }
+
+        mask.convert(
+            mask.format_for_file_type(file_type),
+            ImageConvertOptions::None,
+        )?;
+        mask.save(path, file_type)?;
+
+        Ok(true)
+    }
+
+    /// Splits the channels of each frame into new grayscale images, without mipmaps, named by the given remap.
+    pub fn remap_channels(
+        &self,
+        remap: &ImageChannelRemap,
+    ) -> Result<Vec<(String, Image)>, TextureError> {
+        let image = self.to_rgba8()?;
+
+        let swizzled = matches!(
+            image.format,
+            ImageFormat::B8G8R8A8Unorm | ImageFormat::B8G8R8A8UnormSrgb
+        );
+
+        let mut result = Vec::with_capacity(remap.len());
+
+        for (channel, name) in remap.channels() {
+            let index = match (swizzled, channel.index()) {
+                (true, 0) => 2,
+                (true, 2) => 0,
+                (_, index) => index,
+            };
+
+            let mut split = Image::new(self.width, self.height, ImageFormat::R8Unorm)?;
+
+            for source in image.frames() {
+                let frame = split.create_frame()?;
+
+                for (value, pixel) in frame
+                    .buffer_mut()
+                    .iter_mut()
+                    .zip(source.buffer().chunks_exact(4))
+                {
+                    *value = pixel[index];
+                }
+            }
+
+            result.push((name.to_string(), split));
+        }
+
+        Ok(result)
+    }
+
+    /// Saves each remapped channel as a grayscale image next to the given path, suffixed with the channel name.
+    /// Returns the paths of the images that were written.
+    pub fn save_remapped_channels<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ImageFileType,
+        remap: &ImageChannelRemap,
+    ) -> Result<Vec<PathBuf>, TextureError> {
+        let path = path.as_ref();
+
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default();
+
+        let mut paths = Vec::with_capacity(remap.len());
+
+        for (name, mut image) in self.remap_channels(remap)? {
+            let path = path
+                .with_file_name(format!("{stem}_{name}"))
+                .with_extension(file_type);
+
+            image.convert(
+                image.format_for_file_type(file_type),
+                ImageConvertOptions::None,
+            )?;
+            image.save(&path, file_type)?;
+
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
     /// Image is considered a cubemap if it has exactly 6 frames.
     pub fn is_cubemap(&self) -> bool {
         self.frames.len() == 6
     }
+
+    /// Gets the raw bytes of the pixel at the given coordinates in the first frame.
+    /// Returns none for compressed formats, formats smaller than a byte, or coordinates outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<&[u8]> {
+        if self.format.is_compressed() || x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let bits_per_pixel = self.format.bits_per_pixel();
+
+        if bits_per_pixel < 8 || !bits_per_pixel.is_multiple_of(8) {
+            return None;
+        }
+
+        let bytes_per_pixel = (bits_per_pixel / 8) as usize;
+        let offset = (y as usize * self.width as usize + x as usize) * bytes_per_pixel;
+
+        self.frames
+            .first()?
+            .buffer()
+            .get(offset..offset + bytes_per_pixel)
+    }
+
+    /// Gets the rgba value of the pixel at the given coordinates in the first frame.
+    /// The image must be a 4 component, 8 bit per channel format, see [`Image::to_rgba8`].
+    pub fn pixel_rgba8(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        let pixel = self.pixel(x, y)?;
+
+        match self.format {
+            ImageFormat::R8G8B8A8Unorm | ImageFormat::R8G8B8A8UnormSrgb => {
+                Some([pixel[0], pixel[1], pixel[2], pixel[3]])
+            }
+            ImageFormat::B8G8R8A8Unorm | ImageFormat::B8G8R8A8UnormSrgb => {
+                Some([pixel[2], pixel[1], pixel[0], pixel[3]])
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the rgba value of the pixel at the given coordinates in the first frame, in any format.
+    /// Only the block containing the pixel is converted, so this is cheap even for very large images.
+    /// Bc1 blocks are decoded in software, other compressed formats are converted on the GPU.
+    pub fn pixel_to_rgba8(&self, x: u32, y: u32) -> Result<[u8; 4], TextureError> {
+        if x >= self.width || y >= self.height {
+            return Err(TextureError::InvalidOperation);
+        }
+
+        if let Some(rgba) = self.pixel_rgba8(x, y) {
+            return Ok(rgba);
+        }
+
+        let (block_x, block_y) = self.format.block_dimensions();
+
+        let block = self.gather_blocks(1, 1, |_, _| (x / block_x, y / block_y))?;
+
+        if matches!(self.format, ImageFormat::Bc1Unorm) {
+            let pixels = decode_bc1_block(block.frames()[0].buffer());
+
+            return Ok(pixels[((y % block_y) * block_x + x % block_x) as usize]);
+        }
+
+        let block = block.to_rgba8()?.pixel_rgba8(x % block_x, y % block_y);
+
+        block.ok_or(TextureError::InvalidOperation)
+    }
+
+    /// Builds a smaller image of evenly spaced blocks from the first frame, with at most `max_blocks` blocks along each side.
+    /// Used to cheaply estimate properties of very large images, such as [`Image::is_likely_premultiplied`].
+    pub fn sample_blocks(&self, max_blocks: u32) -> Result<Image, TextureError> {
+        let (block_x, block_y) = self.format.block_dimensions();
+
+        let blocks_x = self.width.div_ceil(block_x);
+        let blocks_y = self.height.div_ceil(block_y);
+
+        let columns = blocks_x.min(max_blocks.max(1));
+        let rows = blocks_y.min(max_blocks.max(1));
+
+        self.gather_blocks(columns, rows, |column, row| {
+            (column * blocks_x / columns, row * blocks_y / rows)
+        })
+    }
+
+    /// Copies the blocks picked by `source` for each column and row of the first frame into a new image of the same format.
+    fn gather_blocks<F: Fn(u32, u32) -> (u32, u32)>(
+        &self,
+        columns: u32,
+        rows: u32,
+        source: F,
+    ) -> Result<Image, TextureError> {
+        let bytes_per_block = if self.format.is_compressed() {
+            self.format.block_size()
+        } else {
+            let bits_per_pixel = self.format.bits_per_pixel();
+
+            if self.format.is_palettized()
+                || bits_per_pixel < 8
+                || !bits_per_pixel.is_multiple_of(8)
+            {
+                return Err(TextureError::UnsupportedImageFormat(self.format));
+            }
+
+            bits_per_pixel / 8
+        } as usize;
+
+        let Some(frame) = self.frames.first() else {
+            return Err(TextureError::InvalidOperation);
+        };
+
+        let (block_x, block_y) = self.format.block_dimensions();
+
+        let mut image = Image::new(columns * block_x, rows * block_y, self.format)?;
+
+        let source_stride = self.format.bytes_per_row(self.width) as usize;
+        let target_stride = columns as usize * bytes_per_block;
+
+        let target = image.create_frame()?.buffer_mut();
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let (x, y) = source(column, row);
+
+                let source_offset = y as usize * source_stride + x as usize * bytes_per_block;
+                let target_offset =
+                    row as usize * target_stride + column as usize * bytes_per_block;
+
+                let Some(block) = frame
+                    .buffer()
+                    .get(source_offset..source_offset + bytes_per_block)
+                else {
+                    return Err(TextureError::InvalidOperation);
+                };
+
+                target[target_offset..target_offset + bytes_per_block].copy_from_slice(block);
+            }
+        }
+
+        Ok(image)
+    }
+
+    /// Gets this image as a 4 component, 8 bit per channel image with alpha in the last component.
+    pub fn to_rgba8(&self) -> Result<Cow<'_, Image>, TextureError> {
+        if matches!(
+            self.format,
+            ImageFormat::R8G8B8A8Unorm
+                | ImageFormat::R8G8B8A8UnormSrgb
+                | ImageFormat::B8G8R8A8Unorm
+                | ImageFormat::B8G8R8A8UnormSrgb
+        ) {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        let mut image = self.clone();
+
+        image.convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)?;
+
+        Ok(Cow::Owned(image))
+    }
 }
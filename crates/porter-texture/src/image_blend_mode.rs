@@ -0,0 +1,37 @@
+/// Blend modes used when compositing one image over another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageBlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Add,
+    Subtract,
+    Darken,
+    Lighten,
+}
+
+impl ImageBlendMode {
+    /// Blends a normalized layer value with a normalized base value.
+    pub fn blend(&self, base: f32, layer: f32) -> f32 {
+        let result = match self {
+            Self::Normal => layer,
+            Self::Multiply => base * layer,
+            Self::Screen => 1.0 - (1.0 - base) * (1.0 - layer),
+            Self::Overlay => {
+                if base < 0.5 {
+                    2.0 * base * layer
+                } else {
+                    1.0 - 2.0 * (1.0 - base) * (1.0 - layer)
+                }
+            }
+            Self::Add => base + layer,
+            Self::Subtract => base - layer,
+            Self::Darken => base.min(layer),
+            Self::Lighten => base.max(layer),
+        };
+
+        result.clamp(0.0, 1.0)
+    }
+}
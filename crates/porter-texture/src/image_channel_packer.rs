@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use crate::Image;
+use crate::ImageChannel;
+use crate::ImageConvertOptions;
+use crate::ImageFormat;
+use crate::ResizeAlgorithm;
+use crate::TextureError;
+
+/// A single channel of a source image.
+#[derive(Debug, Clone, Copy)]
+struct ImageChannelSource<'a> {
+    image: &'a Image,
+    channel: ImageChannel,
+}
+
+/// Packs channels from one or more images into a single R8G8B8A8 image.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageChannelPacker<'a> {
+    sources: [Option<ImageChannelSource<'a>>; 4],
+    defaults: [u8; 4],
+}
+
+impl<'a> ImageChannelPacker<'a> {
+    /// Constructs a new channel packer, with each channel defaulting to 0 except alpha.
+    pub fn new() -> Self {
+        Self {
+            sources: [None; 4],
+            defaults: [0x0, 0x0, 0x0, 0xFF],
+        }
+    }
+
+    /// Sets the red channel to the given channel of the source image.
+    pub fn red(self, image: &'a Image, channel: ImageChannel) -> Self {
+        self.channel(ImageChannel::Red, image, channel)
+    }
+
+    /// Sets the green channel to the given channel of the source image.
+    pub fn green(self, image: &'a Image, channel: ImageChannel) -> Self {
+        self.channel(ImageChannel::Green, image, channel)
+    }
+
+    /// Sets the blue channel to the given channel of the source image.
+    pub fn blue(self, image: &'a Image, channel: ImageChannel) -> Self {
+        self.channel(ImageChannel::Blue, image, channel)
+    }
+
+    /// Sets the alpha channel to the given channel of the source image.
+    pub fn alpha(self, image: &'a Image, channel: ImageChannel) -> Self {
+        self.channel(ImageChannel::Alpha, image, channel)
+    }
+
+    /// Sets the given channel to a grayscale source image, using its first channel.
+    pub fn grayscale(self, target: ImageChannel, image: &'a Image) -> Self {
+        self.channel(target, image, ImageChannel::Red)
+    }
+
+    /// Sets the value used for the given channel when it has no source image.
+    pub fn default_value(mut self, channel: ImageChannel, value: u8) -> Self {
+        self.defaults[channel.index()] = value;
+        self
+    }
+
+    /// Packs the channels into a new image, the size of the largest source image.
+    pub fn pack(&self) -> Result<Image, TextureError> {
+        let width = self
+            .sources
+            .iter()
+            .flatten()
+            .map(|source| source.image.width())
+            .max()
+            .ok_or(TextureError::InvalidOperation)?;
+
+        let height = self
+            .sources
+            .iter()
+            .flatten()
+            .map(|source| source.image.height())
+            .max()
+            .ok_or(TextureError::InvalidOperation)?;
+
+        let mut result = Image::new(width, height, ImageFormat::R8G8B8A8Unorm)?;
+
+        let frame = result.create_frame()?;
+
+        for pixel in frame.buffer_mut().chunks_exact_mut(4) {
+            pixel.copy_from_slice(&self.defaults);
+        }
+
+        for (target, source) in self.sources.iter().enumerate() {
+            let Some(source) = source else {
+                continue;
+            };
+
+            let sized = source.image.width() == width && source.image.height() == height;
+
+            // Grayscale and rgba images of the same size are sampled directly, without a copy.
+            let (image, bytes_per_pixel, channel) = match source.image.format() {
+                ImageFormat::R8Unorm | ImageFormat::R8Typeless if sized => {
+                    (Cow::Borrowed(source.image), 1, 0)
+                }
+                ImageFormat::R8G8B8A8Unorm if sized => {
+                    (Cow::Borrowed(source.image), 4, source.channel.index())
+                }
+                _ => {
+                    let mut image = source.image.clone();
+
+                    image.convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)?;
+
+                    if image.width() != width || image.height() != height {
+                        image.resize(width, height, ResizeAlgorithm::Bicubic)?;
+                    }
+
+                    (Cow::Owned(image), 4, source.channel.index())
+                }
+            };
+
+            let Some(source_frame) = image.frames().first() else {
+                return Err(TextureError::InvalidOperation);
+            };
+
+            for (pixel, source_pixel) in frame
+                .buffer_mut()
+                .chunks_exact_mut(4)
+                .zip(source_frame.buffer().chunks_exact(bytes_per_pixel))
+            {
+                pixel[target] = source_pixel[channel];
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the target channel to the given channel of the source image.
+    fn channel(mut self, target: ImageChannel, image: &'a Image, channel: ImageChannel) -> Self {
+        self.sources[target.index()] = Some(ImageChannelSource { image, channel });
+        self
+    }
+}
+
+impl Default for ImageChannelPacker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
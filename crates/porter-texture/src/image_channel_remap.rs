@@ -0,0 +1,144 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::TextureError;
+
+/// A single color channel of an image.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageChannel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl ImageChannel {
+    /// The index of the channel in an rgba pixel.
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::Red => 0,
+            Self::Green => 1,
+            Self::Blue => 2,
+            Self::Alpha => 3,
+        }
+    }
+
+    /// The short name of the channel.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Red => "R",
+            Self::Green => "G",
+            Self::Blue => "B",
+            Self::Alpha => "A",
+        }
+    }
+}
+
+impl FromStr for ImageChannel {
+    type Err = TextureError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "r" | "red" => Ok(Self::Red),
+            "g" | "green" => Ok(Self::Green),
+            "b" | "blue" => Ok(Self::Blue),
+            "a" | "alpha" => Ok(Self::Alpha),
+            _ => Err(TextureError::InvalidChannelRemap(value.to_string())),
+        }
+    }
+}
+
+/// Describes how the channels of a packed image are split into separate grayscale images.
+///
+/// The spec is written as a comma separated list of channel to name pairs, such as `R->AO, G->Roughness, B->Metalness`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImageChannelRemap {
+    channels: Vec<(ImageChannel, String)>,
+}
+
+impl ImageChannelRemap {
+    /// Constructs a new empty channel remap.
+    pub fn new() -> Self {
+        Self {
+            channels: Vec::new(),
+        }
+    }
+
+    /// Splits the given channel into an image with the given name.
+    pub fn channel<S: Into<String>>(mut self, channel: ImageChannel, name: S) -> Self {
+        self.channels.push((channel, name.into()));
+        self
+    }
+
+    /// The channels and the names of the images they're split into.
+    pub fn channels(&self) -> impl Iterator<Item = (ImageChannel, &str)> {
+        self.channels
+            .iter()
+            .map(|(channel, name)| (*channel, name.as_str()))
+    }
+
+    /// The number of channels being split.
+    pub fn len(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Whether or not no channels are being split.
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+}
+
+impl FromStr for ImageChannelRemap {
+    type Err = TextureError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || TextureError::InvalidChannelRemap(spec.to_string());
+
+        let mut remap = Self::new();
+
+        for entry in spec.split(',').filter(|entry| !entry.trim().is_empty()) {
+            let (channel, name) = entry.split_once("->").ok_or_else(invalid)?;
+
+            let channel: ImageChannel = channel.parse().map_err(|_| invalid())?;
+            let name = name.trim();
+
+            // Names become part of the file name, so they must be safe to use in a path.
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '-' | ' '))
+            {
+                return Err(invalid());
+            }
+
+            if remap.channels().any(|(_, existing)| existing == name) {
+                return Err(invalid());
+            }
+
+            remap = remap.channel(channel, name);
+        }
+
+        if remap.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(remap)
+    }
+}
+
+impl fmt::Display for ImageChannelRemap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (channel, name)) in self.channels().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}->{}", channel.as_str(), name)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The quality preset used when block compressing an image, higher quality is slower.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageCompressionQuality {
+    /// Endpoints are picked from the bounds of each block.
+    Fast,
+    /// Endpoints are fit along the principal axis of each block, then refined once.
+    #[default]
+    Normal,
+    /// Endpoints are fit along the principal axis of each block, then refined until they stop improving.
+    High,
+}
+
+impl ImageCompressionQuality {
+    /// The number of least squares refinement passes for the endpoints of each block.
+    pub(crate) const fn refinements(&self) -> usize {
+        match self {
+            Self::Fast => 0,
+            Self::Normal => 1,
+            Self::High => 8,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+use porter_utils::WorkerPool;
+
+/// Shared state between a scheduler and its permits.
+#[derive(Debug)]
+struct SchedulerState {
+    budget: u64,
+    in_use: Mutex<u64>,
+    released: Condvar,
+}
+
+impl SchedulerState {
+    /// Whether or not a request of the given size can be admitted right now.
+    fn admits(&self, in_use: u64, size: u64) -> bool {
+        // A request larger than the whole budget is admitted alone, so that it can't starve.
+        in_use == 0 || in_use.saturating_add(size) <= self.budget
+    }
+
+    /// Returns the given size to the budget and wakes any waiting workers.
+    fn release(&self, size: u64) {
+        let mut in_use = self.in_use.lock().unwrap();
+
+        *in_use = in_use.saturating_sub(size);
+
+        self.released.notify_all();
+    }
+}
+
+/// Limits the amount of decoded image memory that is alive at once across worker threads.
+#[derive(Debug, Clone)]
+pub struct ImageDecodeScheduler {
+    state: Arc<SchedulerState>,
+}
+
+/// A reservation of decoded image memory, returned to the scheduler when dropped.
+#[derive(Debug)]
+pub struct ImageDecodePermit {
+    state: Arc<SchedulerState>,
+    size: u64,
+}
+
+impl ImageDecodeScheduler {
+    /// Constructs a new scheduler with the given memory budget in bytes.
+    pub fn new(budget: u64) -> Self {
+        Self {
+            state: Arc::new(SchedulerState {
+                budget,
+                in_use: Mutex::new(0),
+                released: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Constructs a new scheduler that never limits decoding.
+    pub fn unlimited() -> Self {
+        Self::new(u64::MAX)
+    }
+
+    /// The memory budget in bytes.
+    pub fn budget(&self) -> u64 {
+        self.state.budget
+    }
+
+    /// The amount of memory in bytes that is currently reserved.
+    pub fn in_use(&self) -> u64 {
+        *self.state.in_use.lock().unwrap()
+    }
+
+    /// Reserves the given amount of memory, blocking until it fits in the budget.
+    pub fn acquire(&self, size: u64) -> ImageDecodePermit {
+        let mut in_use = self.state.in_use.lock().unwrap();
+
+        while !self.state.admits(*in_use, size) {
+            in_use = self.state.released.wait(in_use).unwrap();
+        }
+
+        *in_use += size;
+
+        ImageDecodePermit {
+            state: self.state.clone(),
+            size,
+        }
+    }
+
+    /// Reserves the given amount of memory if it fits in the budget right now.
+    pub fn try_acquire(&self, size: u64) -> Option<ImageDecodePermit> {
+        let mut in_use = self.state.in_use.lock().unwrap();
+
+        if !self.state.admits(*in_use, size) {
+            return None;
+        }
+
+        *in_use += size;
+
+        Some(ImageDecodePermit {
+            state: self.state.clone(),
+            size,
+        })
+    }
+
+    /// Processes each item on the thread pool, only running items whose estimated size fits in the budget.
+    ///
+    /// When the next item doesn't fit, workers pick up a later item that does, so the pool stays busy.
+    pub fn process<T, R, S, F>(&self, items: Vec<T>, size: S, work: F) -> Vec<R>
+    where
+        T: Send,
+        R: Send,
+        S: Fn(&T) -> u64 + Sync,
+        F: Fn(T) -> R + Sync,
+    {
+        let count = items.len();
+        let workers = porter_threads::current_num_threads().clamp(1, count.max(1));
+
+        let queue: Mutex<VecDeque<(usize, T)>> =
+            Mutex::new(items.into_iter().enumerate().collect());
+        let results: Mutex<Vec<Option<R>>> = Mutex::new((0..count).map(|_| None).collect());
+
+        WorkerPool::with_workers(workers).scope(|| {
+            while let Some((index, item, permit)) = self.next(&queue, &size) {
+                let result = work(item);
+
+                drop(permit);
+
+                results.lock().unwrap()[index] = Some(result);
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|result| result.expect("every item is processed"))
+            .collect()
+    }
+
+    /// Takes the first queued item that fits in the budget, waiting for memory to be released if none do.
+    fn next<T, S>(
+        &self,
+        queue: &Mutex<VecDeque<(usize, T)>>,
+        size: &S,
+    ) -> Option<(usize, T, ImageDecodePermit)>
+    where
+        S: Fn(&T) -> u64,
+    {
+        let mut in_use = self.state.in_use.lock().unwrap();
+
+        loop {
+            let mut queue = queue.lock().unwrap();
+
+            if queue.is_empty() {
+                return None;
+            }
+
+            let fits = queue
+                .iter()
+                .position(|(_, item)| self.state.admits(*in_use, size(item)));
+
+            if let Some(position) = fits
+                && let Some((index, item)) = queue.remove(position)
+            {
+                let item_size = size(&item);
+
+                *in_use += item_size;
+
+                return Some((
+                    index,
+                    item,
+                    ImageDecodePermit {
+                        state: self.state.clone(),
+                        size: item_size,
+                    },
+                ));
+            }
+
+            drop(queue);
+
+            in_use = self.state.released.wait(in_use).unwrap();
+        }
+    }
+}
+
+impl ImageDecodePermit {
+    /// The amount of memory in bytes reserved by this permit.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl Drop for ImageDecodePermit {
+    fn drop(&mut self) {
+        self.state.release(self.size);
+    }
+}
@@ -8,6 +8,8 @@ use porter_utils::StructWriteExt;
 use crate::Image;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageInfo;
+use crate::ImageSaveProgress;
 use crate::TextureError;
 
 const DDS_FOURCC: u32 = 0x00000004;
@@ -382,7 +384,11 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
 }
 
 /// Writes an image to a dds file to the output stream.
-pub fn to_dds<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), TextureError> {
+pub fn to_dds<O: Write + Seek>(
+    image: &Image,
+    output: &mut O,
+    progress: &mut ImageSaveProgress,
+) -> Result<(), TextureError> {
     let (header, header_dx10) = format_to_dds(image);
 
     output.write_all(&make_four_cc!('D', 'D', 'S', ' ').to_le_bytes())?;
@@ -393,21 +399,21 @@ pub fn to_dds<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
         output.write_struct(header_dx10)?;
     }
 
-    for frame in image.frames() {
+    let frames = image.frames();
+
+    for (index, frame) in frames.iter().enumerate() {
+        progress.check()?;
+
         output.write_all(frame.buffer())?;
+
+        progress.frame(index + 1, frames.len());
     }
 
     Ok(())
 }
 
-/// Reads a dds file from the input stream to an image.
-pub fn from_dds<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
-    let magic: u32 = input.read_struct()?;
-
-    if magic != make_four_cc!('D', 'D', 'S', ' ') {
-        return Err(TextureError::ContainerInvalid(ImageFileType::Dds));
-    }
-
+/// Reads the dds headers after the magic, returning the image metadata.
+fn read_dds_info<I: Read + Seek>(input: &mut I) -> Result<ImageInfo, TextureError> {
     let header: DdsHeader = input.read_struct()?;
 
     let mut frames = if header.caps2 & DDS_CUBEMAP_ALLFACES == DDS_CUBEMAP_ALLFACES {
@@ -433,14 +439,39 @@ pub fn from_dds<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
         format = format.to_srgb();
     }
 
-    let mut image = Image::with_mipmaps(
-        header.width,
-        header.height,
-        header.mip_map_count.max(1),
+    Ok(ImageInfo {
+        width: header.width,
+        height: header.height,
+        mipmaps: header.mip_map_count.max(1),
+        frames,
         format,
-    )?;
+    })
+}
+
+/// Reads the metadata of a dds file from the input stream without reading the image data.
+pub fn probe_dds<I: Read + Seek>(input: &mut I) -> Result<ImageInfo, TextureError> {
+    let magic: u32 = input.read_struct()?;
+
+    if magic != make_four_cc!('D', 'D', 'S', ' ') {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Dds));
+    }
+
+    read_dds_info(input)
+}
+
+/// Reads a dds file from the input stream to an image.
+pub fn from_dds<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
+    let magic: u32 = input.read_struct()?;
+
+    if magic != make_four_cc!('D', 'D', 'S', ' ') {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Dds));
+    }
+
+    let info = read_dds_info(input)?;
+
+    let mut image = Image::with_mipmaps(info.width, info.height, info.mipmaps, info.format)?;
 
-    for _ in 0..frames {
+    for _ in 0..info.frames {
         let frame = image.create_frame()?;
 
         input.read_exact(frame.buffer_mut())?;
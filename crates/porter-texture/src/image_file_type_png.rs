@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::io::BufRead;
 use std::io::Seek;
 use std::io::Write;
@@ -7,12 +8,20 @@ use png::ColorType;
 use png::Compression;
 use png::Decoder;
 use png::Encoder;
+use png::Info;
+use png::ScaledFloat;
+use png::SourceChromaticities;
 use png::SrgbRenderingIntent;
 use png::Transformations;
 
+use crate::ICC_SRGB_PROFILE;
 use crate::Image;
+use crate::ImageColorProfile;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageInfo;
+use crate::ImageSaveOptions;
+use crate::ImageSaveProgress;
 use crate::TextureError;
 
 /// Maximum number of png frames to expand.
@@ -54,6 +63,15 @@ const fn png_to_format(format: (ColorType, BitDepth)) -> Result<ImageFormat, Tex
     })
 }
 
+/// Whether or not the png info marks the image as srgb, either with the srgb chunk or the srgb icc profile.
+fn is_srgb(info: &Info) -> bool {
+    info.srgb.is_some()
+        || info
+            .icc_profile
+            .as_deref()
+            .is_some_and(|profile| profile == ICC_SRGB_PROFILE)
+}
+
 /// Picks the proper format required to save the input format to a png file type.
 pub const fn pick_format(format: ImageFormat) -> ImageFormat {
     match format {
@@ -112,31 +130,68 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
 }
 
 /// Writes an image to a png file to the output stream.
-pub fn to_png<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), TextureError> {
+pub fn to_png<O: Write + Seek>(
+    image: &Image,
+    output: &mut O,
+    options: ImageSaveOptions,
+    progress: &mut ImageSaveProgress,
+) -> Result<(), TextureError> {
     let (color_type, bit_depth, is_srgb) = format_to_png(image.format())?;
 
     let frames = image.frames();
     let height = image.height() * frames.len().min(MAXIMUM_PNG_FRAMES) as u32;
     let width = image.width();
 
-    let mut encoder = Encoder::new(output, width, height);
+    let profile = options.color_profile;
+
+    let mut info = Info::with_size(width, height);
+
+    if profile.embeds(is_srgb) {
+        // Gamma and chromaticities are the fallback values from the png specification for srgb.
+        info.source_gamma = Some(ScaledFloat::from_scaled(45455));
+        info.source_chromaticities = Some(SourceChromaticities {
+            white: (
+                ScaledFloat::from_scaled(31270),
+                ScaledFloat::from_scaled(32900),
+            ),
+            red: (
+                ScaledFloat::from_scaled(64000),
+                ScaledFloat::from_scaled(33000),
+            ),
+            green: (
+                ScaledFloat::from_scaled(30000),
+                ScaledFloat::from_scaled(60000),
+            ),
+            blue: (
+                ScaledFloat::from_scaled(15000),
+                ScaledFloat::from_scaled(6000),
+            ),
+        });
+
+        if matches!(profile, ImageColorProfile::Icc) {
+            info.icc_profile = Some(Cow::Borrowed(&ICC_SRGB_PROFILE));
+        } else {
+            info.srgb = Some(SrgbRenderingIntent::Perceptual);
+        }
+    }
+
+    let mut encoder = Encoder::with_info(output, info)?;
 
     encoder.set_compression(Compression::Fast);
     encoder.set_color(color_type);
     encoder.set_depth(bit_depth);
 
-    if is_srgb {
-        encoder.set_source_srgb(SrgbRenderingIntent::Perceptual);
-    }
-
     encoder.add_text_chunk("Author".into(), "DTZxPorter".into())?;
 
     let mut encoder = encoder.write_header()?;
     let mut writer = encoder.stream_writer_with_size(MAXIMUM_PNG_BUFFER)?;
 
     let size = image.frame_size_with_mipmaps(image.width(), image.height(), 1);
+    let total = frames.len().min(MAXIMUM_PNG_FRAMES);
+
+    for (index, frame) in frames.iter().take(total).enumerate() {
+        progress.check()?;
 
-    for frame in frames.iter().take(MAXIMUM_PNG_FRAMES) {
         // Png requires big-endian format for 16bit formats.
         if matches!(bit_depth, BitDepth::Sixteen) {
             for pixel in frame.buffer()[..size as usize].chunks_exact(2) {
@@ -145,6 +200,8 @@ pub fn to_png<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
         } else {
             writer.write_all(&frame.buffer()[..size as usize])?;
         }
+
+        progress.frame(index + 1, total);
     }
 
     Ok(())
@@ -162,7 +219,7 @@ pub fn from_png<I: BufRead + Seek>(input: &mut I) -> Result<Image, TextureError>
 
     let mut format = png_to_format((color_type, bit_depth))?;
 
-    if decoder.info().srgb.is_some() {
+    if is_srgb(decoder.info()) {
         format = format.to_srgb();
     }
 
@@ -180,3 +237,26 @@ pub fn from_png<I: BufRead + Seek>(input: &mut I) -> Result<Image, TextureError>
 
     Ok(image)
 }
+
+/// Reads the metadata of a png file from the input stream without decoding the image data.
+pub fn probe_png<I: BufRead + Seek>(input: &mut I) -> Result<ImageInfo, TextureError> {
+    let mut decoder = Decoder::new(input);
+
+    decoder.set_transformations(Transformations::ALPHA);
+
+    let decoder = decoder.read_info()?;
+
+    let mut format = png_to_format(decoder.output_color_type())?;
+
+    if is_srgb(decoder.info()) {
+        format = format.to_srgb();
+    }
+
+    Ok(ImageInfo {
+        width: decoder.info().width,
+        height: decoder.info().height,
+        mipmaps: 1,
+        frames: 1,
+        format,
+    })
+}
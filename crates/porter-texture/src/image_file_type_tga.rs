@@ -4,23 +4,20 @@ use std::io::Seek;
 use std::io::Write;
 
 use porter_utils::SeekExt;
-use porter_utils::StackVec;
 use porter_utils::StructReadExt;
 use porter_utils::StructWriteExt;
 
 use crate::Image;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageInfo;
+use crate::ImageSaveProgress;
 use crate::TextureError;
 
 /// Maximum number of tga frames to expand.
 const MAXIMUM_TGA_FRAMES: usize = 6;
 /// Maximum run-length chunk size.
 const MAXIMUM_RLE_LENGTH: usize = 128;
-/// The maximum bytes per pixel for a tga.
-const MAXIMUM_BYTES_PER_PIXEL: usize = 4;
-/// The maximum run-length buffer size.
-const MAXIMUM_RLE_BUFFER: usize = MAXIMUM_BYTES_PER_PIXEL * MAXIMUM_RLE_LENGTH;
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy)]
@@ -106,7 +103,11 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
 }
 
 /// Writes an image to a tga file to the output stream.
-pub fn to_tga<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), TextureError> {
+pub fn to_tga<O: Write + Seek>(
+    image: &Image,
+    output: &mut O,
+    progress: &mut ImageSaveProgress,
+) -> Result<(), TextureError> {
     let (color_type, image_type, bit_depth) = format_to_tga(image.format())?;
 
     let frames = image.frames();
@@ -140,7 +141,11 @@ pub fn to_tga<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
         ColorType::Rgba => image.width() as usize * 4,
     };
 
-    for frame in frames.iter().take(MAXIMUM_TGA_FRAMES) {
+    let total = frames.len().min(MAXIMUM_TGA_FRAMES);
+
+    for (index, frame) in frames.iter().take(total).enumerate() {
+        progress.check()?;
+
         match color_type {
             ColorType::Gray => {
                 write_rle_encode::<1, _>(&frame.buffer()[..size as usize], stride, output)?
@@ -149,11 +154,39 @@ pub fn to_tga<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
                 write_rle_encode::<4, _>(&frame.buffer()[..size as usize], stride, output)?
             }
         };
+
+        progress.frame(index + 1, total);
     }
 
     Ok(())
 }
 
+/// Picks the image format for the given bits per pixel, 24 bit images are expanded to include alpha.
+const fn tga_to_format(bits_per_pixel: u8) -> Result<ImageFormat, TextureError> {
+    Ok(match bits_per_pixel {
+        8 => ImageFormat::R8Unorm,
+        24 | 32 => ImageFormat::B8G8R8A8Unorm,
+        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Tga)),
+    })
+}
+
+/// Reads the metadata of a tga file from the input stream without reading the image data.
+pub fn probe_tga<I: Read + Seek>(input: &mut I) -> Result<ImageInfo, TextureError> {
+    let header: TgaHeader = input.read_struct()?;
+
+    if header.color_type != 0 {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Tga));
+    }
+
+    Ok(ImageInfo {
+        width: header.width as u32,
+        height: header.height as u32,
+        mipmaps: 1,
+        frames: 1,
+        format: tga_to_format(header.bits_per_pixel)?,
+    })
+}
+
 /// Reads a tga file from the input stream to an image.
 pub fn from_tga<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
     let header: TgaHeader = input.read_struct()?;
@@ -164,43 +197,72 @@ pub fn from_tga<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
         return Err(TextureError::ContainerInvalid(ImageFileType::Tga));
     }
 
-    if header.x_origin != 0 || header.y_origin != 0 {
-        return Err(TextureError::ContainerInvalid(ImageFileType::Tga));
-    }
+    let format = tga_to_format(header.bits_per_pixel)?;
 
-    let format = match header.bits_per_pixel {
-        8 => ImageFormat::R8Unorm,
-        24 => ImageFormat::B8G8R8Unorm,
-        32 => ImageFormat::B8G8R8A8Unorm,
+    let (compressed, grayscale) = match header.image_type {
+        x if x == ImageType::UncompressedRgb as u8 => (false, false),
+        x if x == ImageType::UncompressedGrayscale as u8 => (false, true),
+        x if x == ImageType::CompressedRgb as u8 => (true, false),
+        x if x == ImageType::CompressedGrayscale as u8 => (true, true),
         _ => return Err(TextureError::ContainerInvalid(ImageFileType::Tga)),
     };
 
+    if grayscale != (header.bits_per_pixel == 8) {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Tga));
+    }
+
     let mut image = Image::new(header.width as u32, header.height as u32, format)?;
     let frame = image.create_frame()?;
 
-    match header.image_type {
-        x if x == ImageType::UncompressedRgb as u8 => {
-            input.read_exact(frame.buffer_mut())?;
-        }
-        x if x == ImageType::UncompressedGrayscale as u8 => {
-            input.read_exact(frame.buffer_mut())?;
-        }
-        x if x == ImageType::CompressedRgb as u8 => {
-            read_rle_decode::<4, _>(frame.buffer_mut(), input)?;
-        }
-        x if x == ImageType::CompressedGrayscale as u8 => {
-            read_rle_decode::<1, _>(frame.buffer_mut(), input)?;
+    match header.bits_per_pixel {
+        8 => read_pixels::<1, _>(frame.buffer_mut(), compressed, input)?,
+        24 => {
+            let pixels = header.width as usize * header.height as usize;
+
+            let mut buffer = vec![0; pixels * 3];
+
+            read_pixels::<3, _>(&mut buffer, compressed, input)?;
+
+            for (target, source) in frame
+                .buffer_mut()
+                .chunks_exact_mut(4)
+                .zip(buffer.chunks_exact(3))
+            {
+                target[0..3].copy_from_slice(source);
+                target[3] = 0xFF;
+            }
         }
-        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Tga)),
+        _ => read_pixels::<4, _>(frame.buffer_mut(), compressed, input)?,
     }
 
+    // Pixels are stored bottom-up unless the top-down bit is set.
     if header.image_descriptor & 32 == 0 {
         image.flip_vertical()?;
     }
 
+    // Pixels are stored left-to-right unless the right-to-left bit is set.
+    if header.image_descriptor & 16 != 0 {
+        image.flip_horizontal()?;
+    }
+
     Ok(image)
 }
 
+/// Utility method to read the pixels of a frame, which may be run-length encoded.
+fn read_pixels<const BYTES_PER_PIXEL: usize, I: Read + Seek>(
+    buffer: &mut [u8],
+    compressed: bool,
+    input: &mut I,
+) -> Result<(), TextureError> {
+    if compressed {
+        read_rle_decode::<BYTES_PER_PIXEL, _>(buffer, input)
+    } else {
+        input.read_exact(buffer)?;
+
+        Ok(())
+    }
+}
+
 /// Utility method to read a run-length frame and decode it.
 fn read_rle_decode<const BYTES_PER_PIXEL: usize, I: Read + Seek>(
     buffer: &mut [u8],
@@ -236,94 +298,57 @@ fn write_rle_encode<const BYTES_PER_PIXEL: usize, O: Write + Seek>(
     stride: usize,
     output: &mut O,
 ) -> Result<(), TextureError> {
-    let mut scratch = StackVec::new([0; MAXIMUM_RLE_BUFFER]);
+    let width = stride / BYTES_PER_PIXEL;
+
+    // Worst case every pixel is written raw, with one opcode per maximum length packet.
+    let mut encoded: Vec<u8> = Vec::with_capacity(stride + width.div_ceil(MAXIMUM_RLE_LENGTH));
 
     for row in buffer.chunks_exact(stride) {
-        let mut counter = 0;
-        let mut prev_pixel: Option<&[u8]> = None;
-        let mut packet_type_rle = true;
-
-        for pixel in row.chunks_exact(BYTES_PER_PIXEL) {
-            if let Some(prev_pixel) = prev_pixel {
-                if pixel == prev_pixel {
-                    if !packet_type_rle && counter > 1 {
-                        write_raw(
-                            &scratch[0..scratch.len() - BYTES_PER_PIXEL],
-                            counter as u8 - 1,
-                            output,
-                        )?;
-
-                        counter = 1;
-                        scratch.clear();
-                        scratch.write_all(pixel)?;
-
-                        packet_type_rle = true;
-                    }
-                } else if packet_type_rle && counter > 1 {
-                    write_rle(prev_pixel, counter as u8, output)?;
-
-                    counter = 0;
-                    packet_type_rle = false;
-                    scratch.clear();
-                } else {
-                    packet_type_rle = false;
-                }
-            }
+        let pixel = |index: usize| -> [u8; BYTES_PER_PIXEL] {
+            let offset = index * BYTES_PER_PIXEL;
+
+            row[offset..offset + BYTES_PER_PIXEL]
+                .try_into()
+                .unwrap_or([0; BYTES_PER_PIXEL])
+        };
+
+        encoded.clear();
 
-            counter += 1;
-            scratch.write_all(pixel)?;
+        let mut start = 0;
 
-            if counter == MAXIMUM_RLE_LENGTH {
-                if packet_type_rle {
-                    write_rle(prev_pixel.unwrap_or_default(), counter as u8, output)?;
-                } else {
-                    write_raw(&scratch, counter as u8, output)?;
-                }
+        while start < width {
+            let end = (start + MAXIMUM_RLE_LENGTH).min(width);
+            let first = pixel(start);
 
-                counter = 0;
-                packet_type_rle = true;
-                scratch.clear();
+            let mut run = start + 1;
+
+            while run < end && pixel(run) == first {
+                run += 1;
             }
 
-            prev_pixel = Some(pixel);
-        }
+            if run - start > 1 {
+                encoded.push(0x80 | (run - start - 1) as u8);
+                encoded.extend_from_slice(&first);
 
-        if counter > 0 {
-            if packet_type_rle {
-                write_rle(prev_pixel.unwrap_or_default(), counter as u8, output)?;
-            } else {
-                write_raw(&scratch, counter as u8, output)?;
+                start = run;
+                continue;
             }
-        }
 
-        scratch.clear();
-    }
+            // Raw packets end where the next run of at least two pixels begins.
+            let mut raw = start + 1;
 
-    Ok(())
-}
+            while raw < end && (raw + 1 >= width || pixel(raw) != pixel(raw + 1)) {
+                raw += 1;
+            }
 
-/// Utility method to write a raw opcode.
-#[inline]
-fn write_raw<O: Write + Seek>(
-    buffer: &[u8],
-    counter: u8,
-    output: &mut O,
-) -> Result<(), TextureError> {
-    output.write_struct(counter - 1)?;
-    output.write_all(buffer)?;
+            encoded.push((raw - start - 1) as u8);
+            encoded.extend_from_slice(&row[start * BYTES_PER_PIXEL..raw * BYTES_PER_PIXEL]);
 
-    Ok(())
-}
+            start = raw;
+        }
 
-/// Utility method to write a run-length opcode.
-#[inline]
-fn write_rle<O: Write + Seek>(
-    buffer: &[u8],
-    counter: u8,
-    output: &mut O,
-) -> Result<(), TextureError> {
-    output.write_struct(0x80 | (counter - 1))?;
-    output.write_all(buffer)?;
+        output.write_all(&encoded)?;
+    }
 
     Ok(())
 }
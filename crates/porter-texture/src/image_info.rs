@@ -0,0 +1,37 @@
+use crate::ImageFormat;
+
+/// Metadata for an image, read without decoding the image data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// The base width of the image.
+    pub width: u32,
+    /// The base height of the image.
+    pub height: u32,
+    /// The number of mipmaps in the image.
+    pub mipmaps: u32,
+    /// The number of frames in the image.
+    pub frames: u32,
+    /// The format of the image data.
+    pub format: ImageFormat,
+}
+
+impl ImageInfo {
+    /// Calculates the size in bytes of the image data, including every mipmap and frame.
+    pub fn memory_size(&self) -> u64 {
+        let mut size = 0u64;
+
+        for mipmap in 0..self.mipmaps.max(1) {
+            let width = (self.width >> mipmap).max(1);
+            let height = (self.height >> mipmap).max(1);
+
+            size += self.format.buffer_size(width, height) as u64;
+        }
+
+        size * self.frames.max(1) as u64
+    }
+
+    /// Calculates the size in bytes of the first mipmap of each frame once converted to the given format.
+    pub fn decoded_size(&self, format: ImageFormat) -> u64 {
+        format.buffer_size(self.width, self.height) as u64 * self.frames.max(1) as u64
+    }
+}
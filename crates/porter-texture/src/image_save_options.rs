@@ -0,0 +1,8 @@
+use crate::ImageColorProfile;
+
+/// Options used when saving images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImageSaveOptions {
+    /// How the color space of srgb images is embedded.
+    pub color_profile: ImageColorProfile,
+}
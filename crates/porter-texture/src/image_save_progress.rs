@@ -0,0 +1,54 @@
+use porter_utils::AtomicCancel;
+
+use crate::TextureError;
+
+/// Reports progress for each frame written while saving an image, and allows the save to be cancelled.
+#[derive(Default)]
+pub struct ImageSaveProgress<'a> {
+    callback: Option<Box<dyn FnMut(usize, usize) + 'a>>,
+    cancel: Option<AtomicCancel>,
+}
+
+impl<'a> ImageSaveProgress<'a> {
+    /// Constructs a new image save progress that doesn't report or cancel.
+    pub fn new() -> Self {
+        Self {
+            callback: None,
+            cancel: None,
+        }
+    }
+
+    /// Sets a callback which is given the number of frames written, and the total number of frames.
+    #[must_use]
+    pub fn on_frame<C: FnMut(usize, usize) + 'a>(mut self, callback: C) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the canceller which is checked before each frame is written.
+    #[must_use]
+    pub fn cancel(mut self, cancel: AtomicCancel) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Checks whether or not the save was cancelled, before writing the next frame.
+    pub(crate) fn check(&self) -> Result<(), TextureError> {
+        if self
+            .cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.is_cancelled())
+        {
+            return Err(TextureError::Cancelled);
+        }
+
+        Ok(())
+    }
+
+    /// Reports that the given number of frames have been written.
+    pub(crate) fn frame(&mut self, written: usize, total: usize) {
+        if let Some(callback) = &mut self.callback {
+            callback(written, total);
+        }
+    }
+}
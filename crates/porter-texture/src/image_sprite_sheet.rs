@@ -0,0 +1,131 @@
+use crate::Image;
+use crate::TextureError;
+
+/// Assembles the frames of an image into a sprite sheet grid, or splits a sprite sheet back into frames.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageSpriteSheet {
+    columns: u32,
+}
+
+impl ImageSpriteSheet {
+    /// Constructs a new sprite sheet layout with the given number of columns.
+    pub fn new(columns: u32) -> Self {
+        Self {
+            columns: columns.max(1),
+        }
+    }
+
+    /// The number of columns in the sprite sheet grid.
+    pub fn columns(&self) -> u32 {
+        self.columns
+    }
+
+    /// Calculates the columns and rows used to layout the given number of frames.
+    pub fn grid(&self, frames: u32) -> (u32, u32) {
+        let columns = self.columns.min(frames).max(1);
+        let rows = frames.div_ceil(columns).max(1);
+
+        (columns, rows)
+    }
+
+    /// Assembles each frame of the image into a single frame sprite sheet, left to right, top to bottom.
+    pub fn assemble(&self, image: &Image) -> Result<Image, TextureError> {
+        validate_format(image)?;
+
+        if image.frames().is_empty() {
+            return Err(TextureError::InvalidOperation);
+        }
+
+        let (columns, rows) = self.grid(image.frames().len() as u32);
+
+        let mut sheet = Image::new(
+            image.width() * columns,
+            image.height() * rows,
+            image.format(),
+        )?;
+
+        let bytes_per_row = image.format().bytes_per_row(image.width()) as usize;
+        let sheet_bytes_per_row = sheet.format().bytes_per_row(sheet.width()) as usize;
+
+        let sheet_frame = sheet.create_frame()?;
+
+        for (index, frame) in image.frames().iter().enumerate() {
+            let x = (index as u32 % columns) as usize * bytes_per_row;
+            let y = (index as u32 / columns * image.height()) as usize;
+
+            for (row, source) in frame
+                .buffer()
+                .chunks_exact(bytes_per_row)
+                .take(image.height() as usize)
+                .enumerate()
+            {
+                let offset = (y + row) * sheet_bytes_per_row + x;
+
+                sheet_frame.buffer_mut()[offset..offset + bytes_per_row].copy_from_slice(source);
+            }
+        }
+
+        Ok(sheet)
+    }
+
+    /// Splits the first frame of the sprite sheet into the given number of frames, left to right, top to bottom.
+    pub fn split(&self, sheet: &Image, frames: u32) -> Result<Image, TextureError> {
+        validate_format(sheet)?;
+
+        let Some(sheet_frame) = sheet.frames().first() else {
+            return Err(TextureError::InvalidOperation);
+        };
+
+        if frames == 0 {
+            return Err(TextureError::InvalidOperation);
+        }
+
+        let (columns, rows) = self.grid(frames);
+
+        if !sheet.width().is_multiple_of(columns) || !sheet.height().is_multiple_of(rows) {
+            return Err(TextureError::InvalidImageSize(
+                sheet.width(),
+                sheet.height(),
+            ));
+        }
+
+        let mut image = Image::new(
+            sheet.width() / columns,
+            sheet.height() / rows,
+            sheet.format(),
+        )?;
+
+        let bytes_per_row = image.format().bytes_per_row(image.width()) as usize;
+        let sheet_bytes_per_row = sheet.format().bytes_per_row(sheet.width()) as usize;
+
+        for index in 0..frames {
+            let x = (index % columns) as usize * bytes_per_row;
+            let y = (index / columns * image.height()) as usize;
+
+            let height = image.height() as usize;
+            let frame = image.create_frame()?;
+
+            for (row, target) in frame
+                .buffer_mut()
+                .chunks_exact_mut(bytes_per_row)
+                .take(height)
+                .enumerate()
+            {
+                let offset = (y + row) * sheet_bytes_per_row + x;
+
+                target.copy_from_slice(&sheet_frame.buffer()[offset..offset + bytes_per_row]);
+            }
+        }
+
+        Ok(image)
+    }
+}
+
+/// Ensures the image can be copied by whole pixels.
+fn validate_format(image: &Image) -> Result<(), TextureError> {
+    if image.format().is_compressed() || image.format().bits_per_pixel() < 8 {
+        return Err(TextureError::UnsupportedImageFormat(image.format()));
+    }
+
+    Ok(())
+}
@@ -4,10 +4,22 @@ mod error;
 mod frame;
 mod gpu_converter;
 mod image;
+mod image_blend_mode;
+mod image_channel_packer;
+mod image_channel_remap;
+mod image_color_profile;
+mod image_compression_quality;
 mod image_convert_options;
+mod image_decode_scheduler;
 mod image_file_type;
 mod image_format;
+mod image_info;
+mod image_save_options;
+mod image_save_progress;
+mod image_sprite_sheet;
+mod mipmap_filter;
 mod resize_algorithm;
+mod software_compress;
 mod software_swizzle;
 mod software_unpack;
 mod texture_extensions;
@@ -22,14 +34,26 @@ pub(crate) mod image_file_type_tiff;
 pub use error::*;
 pub use frame::*;
 pub use image::*;
+pub use image_blend_mode::*;
+pub use image_channel_packer::*;
+pub use image_channel_remap::*;
+pub use image_color_profile::*;
+pub use image_compression_quality::*;
 pub use image_convert_options::*;
+pub use image_decode_scheduler::*;
 pub use image_file_type::*;
 pub use image_format::*;
+pub use image_info::*;
+pub use image_save_options::*;
+pub use image_save_progress::*;
+pub use image_sprite_sheet::*;
+pub use mipmap_filter::*;
 pub use resize_algorithm::*;
 pub use texture_extensions::*;
 pub use transform_algorithm::*;
 pub use utilities::*;
 
 pub(crate) use gpu_converter::*;
+pub(crate) use software_compress::*;
 pub(crate) use software_swizzle::*;
 pub(crate) use software_unpack::*;
@@ -0,0 +1,239 @@
+use std::f32::consts::PI;
+
+use crate::Image;
+use crate::ImageFormat;
+use crate::TextureError;
+
+/// The width of the kaiser filter, in destination pixels, on either side of the center.
+const KAISER_WIDTH: f32 = 3.0;
+/// The shape of the kaiser window, higher values give a sharper falloff.
+const KAISER_ALPHA: f32 = 4.0;
+
+/// The filter used to generate mipmaps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MipmapFilter {
+    /// Each pixel in the next mip averages the pixels it covers in the previous mip.
+    #[default]
+    Box,
+    /// Each pixel in the next mip is a kaiser windowed sinc of the previous mip, which keeps more detail.
+    Kaiser,
+}
+
+/// The source pixels and weights for a single destination pixel along one axis.
+struct FilterTaps {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+impl MipmapFilter {
+    /// Generates the full mip chain of each frame, starting from the first mip.
+    pub(crate) fn generate(&self, image: &mut Image) -> Result<(), TextureError> {
+        let format = image.format();
+
+        let channels = match format {
+            ImageFormat::R8Unorm => 1,
+            ImageFormat::R8G8Unorm => 2,
+            ImageFormat::R8G8B8A8Unorm
+            | ImageFormat::R8G8B8A8UnormSrgb
+            | ImageFormat::B8G8R8A8Unorm
+            | ImageFormat::B8G8R8A8UnormSrgb => 4,
+            _ => return Err(TextureError::UnsupportedImageFormat(format)),
+        };
+
+        // Color channels of srgb images are filtered in linear space, alpha is always linear.
+        let linear_channels = if format.is_srgb() { 3 } else { 0 };
+
+        let mipmaps = image.width().max(image.height()).ilog2() + 1;
+
+        let mut result = Image::with_mipmaps(image.width(), image.height(), mipmaps, format)?;
+
+        let base_size = format.buffer_size(image.width(), image.height()) as usize;
+
+        let decode: Vec<f32> = (0..=255u8)
+            .map(|value| srgb_to_linear(value as f32 / 255.0))
+            .collect();
+
+        for source in image.frames() {
+            let frame = result.create_frame()?;
+            let buffer = frame.buffer_mut();
+
+            let base = source
+                .buffer()
+                .get(0..base_size)
+                .ok_or(TextureError::InvalidOperation)?;
+
+            buffer[0..base_size].copy_from_slice(base);
+
+            let mut offset = 0;
+            let (mut width, mut height) = (image.width() as usize, image.height() as usize);
+
+            for _ in 1..mipmaps {
+                let next_width = (width / 2).max(1);
+                let next_height = (height / 2).max(1);
+
+                let size = width * height * channels;
+
+                let (previous, next) = buffer[offset..].split_at_mut(size);
+
+                let columns = self.taps(width, next_width);
+                let rows = self.taps(height, next_height);
+
+                let value = |byte: u8, channel: usize| {
+                    if channel < linear_channels {
+                        decode[byte as usize]
+                    } else {
+                        byte as f32 / 255.0
+                    }
+                };
+
+                // Filter horizontally into a temporary buffer, then vertically into the next mip.
+                let mut horizontal = vec![0.0f32; next_width * height * channels];
+
+                for y in 0..height {
+                    let row = &previous[y * width * channels..(y + 1) * width * channels];
+
+                    for (x, taps) in columns.iter().enumerate() {
+                        for channel in 0..channels {
+                            horizontal[(y * next_width + x) * channels + channel] = taps
+                                .weights
+                                .iter()
+                                .enumerate()
+                                .map(|(tap, weight)| {
+                                    let index = (taps.start + tap).min(width - 1);
+
+                                    value(row[index * channels + channel], channel) * weight
+                                })
+                                .sum();
+                        }
+                    }
+                }
+
+                for (y, taps) in rows.iter().enumerate() {
+                    for x in 0..next_width {
+                        for channel in 0..channels {
+                            let mut sum: f32 = taps
+                                .weights
+                                .iter()
+                                .enumerate()
+                                .map(|(tap, weight)| {
+                                    let index = (taps.start + tap).min(height - 1);
+
+                                    horizontal[(index * next_width + x) * channels + channel]
+                                        * weight
+                                })
+                                .sum();
+
+                            sum = sum.clamp(0.0, 1.0);
+
+                            if channel < linear_channels {
+                                sum = linear_to_srgb(sum);
+                            }
+
+                            next[(y * next_width + x) * channels + channel] =
+                                (sum * 255.0).round() as u8;
+                        }
+                    }
+                }
+
+                offset += size;
+                width = next_width;
+                height = next_height;
+            }
+        }
+
+        *image = result;
+
+        Ok(())
+    }
+
+    /// Computes the normalized filter taps for each destination pixel along one axis.
+    fn taps(&self, source: usize, destination: usize) -> Vec<FilterTaps> {
+        let scale = source as f32 / destination as f32;
+
+        (0..destination)
+            .map(|x| {
+                let (start, mut weights) = match self {
+                    Self::Box => {
+                        let start = (x as f32 * scale) as usize;
+                        let end = (((x + 1) as f32 * scale) as usize)
+                            .max(start + 1)
+                            .min(source);
+
+                        (start, vec![1.0; end - start])
+                    }
+                    Self::Kaiser => {
+                        let center = (x as f32 + 0.5) * scale - 0.5;
+                        let radius = KAISER_WIDTH * scale;
+
+                        let start = (center - radius).ceil().max(0.0) as usize;
+                        let end = ((center + radius).floor() as usize).min(source - 1);
+
+                        let weights = (start..=end)
+                            .map(|index| kaiser((index as f32 - center) / scale))
+                            .collect();
+
+                        (start, weights)
+                    }
+                };
+
+                let total: f32 = weights.iter().sum();
+
+                if total.abs() > f32::EPSILON {
+                    weights.iter_mut().for_each(|weight| *weight /= total);
+                }
+
+                FilterTaps { start, weights }
+            })
+            .collect()
+    }
+}
+
+/// A kaiser windowed sinc, where x is the distance from the center in destination pixels.
+fn kaiser(x: f32) -> f32 {
+    let t = x / KAISER_WIDTH;
+
+    if t.abs() >= 1.0 {
+        return 0.0;
+    }
+
+    let sinc = if x.abs() <= f32::EPSILON {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    };
+
+    sinc * bessel_i0(KAISER_ALPHA * (1.0 - t * t).sqrt()) / bessel_i0(KAISER_ALPHA)
+}
+
+/// The zeroth order modified bessel function of the first kind.
+fn bessel_i0(x: f32) -> f32 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+
+    while term > sum * 1e-7 {
+        term *= (x / (2.0 * k)) * (x / (2.0 * k));
+        sum += term;
+        k += 1.0;
+    }
+
+    sum
+}
+
+/// Decodes a normalized srgb value to linear.
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a normalized linear value to srgb.
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
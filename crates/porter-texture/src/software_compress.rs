@@ -0,0 +1,748 @@
+use porter_threads::IndexedParallelIterator;
+use porter_threads::ParallelIterator;
+use porter_threads::ParallelSliceMut;
+
+use crate::Image;
+use crate::ImageCompressionQuality;
+use crate::ImageConvertOptions;
+use crate::ImageFormat;
+use crate::TextureError;
+
+/// A block of 4x4 rgba pixels, in row order.
+type Block = [[u8; 4]; 16];
+
+/// The interpolation weights of bc7 4 bit indices, out of 64.
+const BC7_WEIGHTS: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+/// The block encoding used for a compressed format.
+#[derive(Debug, Clone, Copy)]
+enum BlockEncoder {
+    Bc1,
+    Bc3,
+    Bc4,
+    Bc5,
+    Bc7,
+}
+
+impl BlockEncoder {
+    /// Picks the block encoder for the given format.
+    const fn from_format(format: ImageFormat) -> Option<Self> {
+        Some(match format {
+            ImageFormat::Bc1Unorm | ImageFormat::Bc1UnormSrgb => Self::Bc1,
+            ImageFormat::Bc3Unorm | ImageFormat::Bc3UnormSrgb => Self::Bc3,
+            ImageFormat::Bc4Unorm => Self::Bc4,
+            ImageFormat::Bc5Unorm => Self::Bc5,
+            ImageFormat::Bc7Unorm | ImageFormat::Bc7UnormSrgb => Self::Bc7,
+            _ => return None,
+        })
+    }
+
+    /// The size in bytes of an encoded block.
+    const fn block_size(&self) -> usize {
+        match self {
+            Self::Bc1 | Self::Bc4 => 8,
+            Self::Bc3 | Self::Bc5 | Self::Bc7 => 16,
+        }
+    }
+
+    /// Encodes a block of pixels to the output.
+    fn encode(&self, block: &Block, quality: ImageCompressionQuality, output: &mut [u8]) {
+        match self {
+            Self::Bc1 => encode_bc1(block, quality, true, output),
+            Self::Bc3 => {
+                encode_bc4(&channel(block, 3), quality, &mut output[0..8]);
+                encode_bc1(block, quality, false, &mut output[8..16]);
+            }
+            Self::Bc4 => encode_bc4(&channel(block, 0), quality, output),
+            Self::Bc5 => {
+                encode_bc4(&channel(block, 0), quality, &mut output[0..8]);
+                encode_bc4(&channel(block, 1), quality, &mut output[8..16]);
+            }
+            Self::Bc7 => encode_bc7(block, quality, output),
+        }
+    }
+}
+
+/// Compresses every frame and mip of the image to the given block compressed format, encoding blocks in parallel.
+pub fn software_compress_image(
+    image: &mut Image,
+    format: ImageFormat,
+    quality: ImageCompressionQuality,
+) -> Result<(), TextureError> {
+    let Some(encoder) = BlockEncoder::from_format(format) else {
+        return Err(TextureError::UnsupportedImageFormat(format));
+    };
+
+    if image.format() == format {
+        return Ok(());
+    }
+
+    if !matches!(
+        image.format(),
+        ImageFormat::R8Unorm
+            | ImageFormat::R8G8Unorm
+            | ImageFormat::R8G8B8A8Unorm
+            | ImageFormat::R8G8B8A8UnormSrgb
+            | ImageFormat::B8G8R8A8Unorm
+            | ImageFormat::B8G8R8A8UnormSrgb
+    ) {
+        image.convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)?;
+    }
+
+    let source_format = image.format();
+
+    let bytes_per_pixel = match source_format {
+        ImageFormat::R8Unorm => 1,
+        ImageFormat::R8G8Unorm => 2,
+        _ => 4,
+    };
+
+    let swizzled = matches!(
+        source_format,
+        ImageFormat::B8G8R8A8Unorm | ImageFormat::B8G8R8A8UnormSrgb
+    );
+
+    let mut result = Image::with_mipmaps(image.width(), image.height(), image.mipmaps(), format)?;
+
+    for source in image.frames() {
+        let frame = result.create_frame()?;
+
+        let mut source_offset = 0;
+        let mut offset = 0;
+
+        for level in 0..image.mipmaps() {
+            let (width, height) = image.mip_size(level);
+
+            let source_size = source_format.buffer_size(width, height) as usize;
+            let size = format.buffer_size(width, height) as usize;
+
+            let pixels = source
+                .buffer()
+                .get(source_offset..source_offset + source_size)
+                .ok_or(TextureError::InvalidOperation)?;
+
+            let row_size = width.div_ceil(4) as usize * encoder.block_size();
+
+            frame.buffer_mut()[offset..offset + size]
+                .par_chunks_mut(row_size)
+                .enumerate()
+                .for_each(|(block_y, row)| {
+                    for (block_x, output) in row.chunks_exact_mut(encoder.block_size()).enumerate()
+                    {
+                        let block = read_block(
+                            pixels,
+                            (width as usize, height as usize),
+                            bytes_per_pixel,
+                            swizzled,
+                            (block_x, block_y),
+                        );
+
+                        encoder.encode(&block, quality, output);
+                    }
+                });
+
+            source_offset += source_size;
+            offset += size;
+        }
+    }
+
+    *image = result;
+
+    Ok(())
+}
+
+/// Reads a block of pixels as rgba, repeating the edge pixels of blocks which extend past the image.
+fn read_block(
+    pixels: &[u8],
+    (width, height): (usize, usize),
+    bytes_per_pixel: usize,
+    swizzled: bool,
+    (block_x, block_y): (usize, usize),
+) -> Block {
+    let mut block = [[0, 0, 0, 0xFF]; 16];
+
+    for (index, pixel) in block.iter_mut().enumerate() {
+        let x = (block_x * 4 + index % 4).min(width - 1);
+        let y = (block_y * 4 + index / 4).min(height - 1);
+
+        let offset = (y * width + x) * bytes_per_pixel;
+        let source = &pixels[offset..offset + bytes_per_pixel];
+
+        match bytes_per_pixel {
+            1 => pixel[0] = source[0],
+            2 => {
+                pixel[0] = source[0];
+                pixel[1] = source[1];
+            }
+            _ if swizzled => *pixel = [source[2], source[1], source[0], source[3]],
+            _ => pixel.copy_from_slice(source),
+        }
+    }
+
+    block
+}
+
+/// Gets a single channel of each pixel in the block.
+fn channel(block: &Block, channel: usize) -> [u8; 16] {
+    block.map(|pixel| pixel[channel])
+}
+
+/// Fits two endpoints to the points using the first number of channels.
+fn fit_endpoints(
+    points: &[[f32; 4]],
+    channels: usize,
+    quality: ImageCompressionQuality,
+) -> ([f32; 4], [f32; 4]) {
+    let mut min = [0.0; 4];
+    let mut max = [0.0; 4];
+
+    for channel in 0..channels {
+        min[channel] = points
+            .iter()
+            .map(|point| point[channel])
+            .fold(255.0, f32::min);
+        max[channel] = points
+            .iter()
+            .map(|point| point[channel])
+            .fold(0.0, f32::max);
+    }
+
+    if quality == ImageCompressionQuality::Fast || points.len() < 2 {
+        return (min, max);
+    }
+
+    let count = points.len() as f32;
+
+    let mut mean = [0.0; 4];
+
+    for point in points {
+        for channel in 0..channels {
+            mean[channel] += point[channel] / count;
+        }
+    }
+
+    let mut covariance = [[0.0f32; 4]; 4];
+
+    for point in points {
+        for a in 0..channels {
+            for b in 0..channels {
+                covariance[a][b] += (point[a] - mean[a]) * (point[b] - mean[b]);
+            }
+        }
+    }
+
+    // Power iteration starting from the bounding box diagonal finds the principal axis.
+    let mut axis = [0.0f32; 4];
+
+    for channel in 0..channels {
+        axis[channel] = max[channel] - min[channel];
+    }
+
+    for _ in 0..8 {
+        let mut next = [0.0f32; 4];
+
+        for a in 0..channels {
+            for b in 0..channels {
+                next[a] += covariance[a][b] * axis[b];
+            }
+        }
+
+        let length = next.iter().map(|value| value.abs()).fold(0.0, f32::max);
+
+        if length <= f32::EPSILON {
+            break;
+        }
+
+        axis = next.map(|value| value / length);
+    }
+
+    let length = axis.iter().map(|value| value * value).sum::<f32>().sqrt();
+
+    if length <= f32::EPSILON {
+        return (mean, mean);
+    }
+
+    let axis = axis.map(|value| value / length);
+
+    let project = |point: &[f32; 4]| -> f32 {
+        (0..channels)
+            .map(|channel| (point[channel] - mean[channel]) * axis[channel])
+            .sum()
+    };
+
+    let low = points.iter().map(project).fold(f32::MAX, f32::min);
+    let high = points.iter().map(project).fold(f32::MIN, f32::max);
+
+    let mut start = [0.0; 4];
+    let mut end = [0.0; 4];
+
+    for channel in 0..channels {
+        start[channel] = (mean[channel] + axis[channel] * low).clamp(0.0, 255.0);
+        end[channel] = (mean[channel] + axis[channel] * high).clamp(0.0, 255.0);
+    }
+
+    (start, end)
+}
+
+/// Solves for the endpoints which best fit the points, given each point's weight towards the second endpoint.
+fn least_squares(
+    points: &[[f32; 4]],
+    weights: &[f32],
+    channels: usize,
+) -> Option<([f32; 4], [f32; 4])> {
+    let mut aa = 0.0;
+    let mut bb = 0.0;
+    let mut ab = 0.0;
+    let mut ax = [0.0f32; 4];
+    let mut bx = [0.0f32; 4];
+
+    for (point, weight) in points.iter().zip(weights) {
+        let alpha = 1.0 - weight;
+        let beta = *weight;
+
+        aa += alpha * alpha;
+        bb += beta * beta;
+        ab += alpha * beta;
+
+        for channel in 0..channels {
+            ax[channel] += alpha * point[channel];
+            bx[channel] += beta * point[channel];
+        }
+    }
+
+    let determinant = aa * bb - ab * ab;
+
+    if determinant.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    let mut start = [0.0; 4];
+    let mut end = [0.0; 4];
+
+    for channel in 0..channels {
+        start[channel] = ((ax[channel] * bb - bx[channel] * ab) / determinant).clamp(0.0, 255.0);
+        end[channel] = ((bx[channel] * aa - ax[channel] * ab) / determinant).clamp(0.0, 255.0);
+    }
+
+    Some((start, end))
+}
+
+/// Finds the closest palette entry to the value, returning the index and squared error.
+fn closest<const N: usize>(palette: &[[i32; N]], value: &[i32; N]) -> (usize, u32) {
+    palette
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let error = entry
+                .iter()
+                .zip(value)
+                .map(|(a, b)| ((a - b) * (a - b)) as u32)
+                .sum();
+
+            (index, error)
+        })
+        .min_by_key(|(_, error)| *error)
+        .unwrap_or_default()
+}
+
+/// A candidate encoding of a block, with its total squared error.
+struct Candidate<E> {
+    endpoints: E,
+    indices: [u8; 16],
+    error: u32,
+}
+
+/// Fits, evaluates, and refines endpoints for the points, keeping the candidate with the lowest error.
+fn refine<E>(
+    points: &[[f32; 4]],
+    channels: usize,
+    quality: ImageCompressionQuality,
+    evaluate: impl Fn([f32; 4], [f32; 4]) -> Candidate<E>,
+    weight: impl Fn(&E, u8) -> f32,
+) -> Candidate<E> {
+    let (start, end) = fit_endpoints(points, channels, quality);
+
+    let mut best = evaluate(start, end);
+
+    for _ in 0..quality.refinements() {
+        let weights: Vec<f32> = best.indices[..points.len()]
+            .iter()
+            .map(|index| weight(&best.endpoints, *index))
+            .collect();
+
+        let Some((start, end)) = least_squares(points, &weights, channels) else {
+            break;
+        };
+
+        let candidate = evaluate(start, end);
+
+        if candidate.error >= best.error {
+            break;
+        }
+
+        best = candidate;
+    }
+
+    best
+}
+
+/// Quantizes a color to 565.
+fn quantize_565(color: [f32; 4]) -> u16 {
+    let r = (color[0] * 31.0 / 255.0).round().clamp(0.0, 31.0) as u16;
+    let g = (color[1] * 63.0 / 255.0).round().clamp(0.0, 63.0) as u16;
+    let b = (color[2] * 31.0 / 255.0).round().clamp(0.0, 31.0) as u16;
+
+    (r << 11) | (g << 5) | b
+}
+
+/// Expands a 565 color to 8 bits per channel.
+fn expand_565(color: u16) -> [i32; 3] {
+    let r = ((color >> 11) & 0x1F) as i32;
+    let g = ((color >> 5) & 0x3F) as i32;
+    let b = (color & 0x1F) as i32;
+
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+    ]
+}
+
+/// Encodes a bc1 color block, pixels with alpha below half are transparent when punch through alpha is allowed.
+fn encode_bc1(
+    block: &Block,
+    quality: ImageCompressionQuality,
+    punch_through: bool,
+    output: &mut [u8],
+) {
+    let transparent: [bool; 16] = block.map(|pixel| punch_through && pixel[3] < 0x80);
+    let three_color = transparent.iter().any(|transparent| *transparent);
+
+    let mut points = Vec::with_capacity(16);
+
+    for (pixel, transparent) in block.iter().zip(transparent) {
+        if !transparent {
+            points.push([pixel[0] as f32, pixel[1] as f32, pixel[2] as f32, 0.0]);
+        }
+    }
+
+    let evaluate = |start: [f32; 4], end: [f32; 4]| -> Candidate<(u16, u16)> {
+        let (mut color0, mut color1) = (quantize_565(start), quantize_565(end));
+
+        // The order of the endpoints selects between the four color and three color palettes.
+        if (three_color && color0 > color1) || (!three_color && color0 < color1) {
+            std::mem::swap(&mut color0, &mut color1);
+        }
+
+        let (c0, c1) = (expand_565(color0), expand_565(color1));
+
+        let palette: Vec<[i32; 3]> = if three_color {
+            vec![c0, c1, std::array::from_fn(|i| (c0[i] + c1[i]) / 2)]
+        } else if color0 == color1 {
+            vec![c0]
+        } else {
+            vec![
+                c0,
+                c1,
+                std::array::from_fn(|i| (2 * c0[i] + c1[i]) / 3),
+                std::array::from_fn(|i| (c0[i] + 2 * c1[i]) / 3),
+            ]
+        };
+
+        let mut indices = [0; 16];
+        let mut error = 0;
+
+        for (slot, point) in points.iter().enumerate() {
+            let (index, distance) = closest(
+                &palette,
+                &[point[0] as i32, point[1] as i32, point[2] as i32],
+            );
+
+            indices[slot] = index as u8;
+            error += distance;
+        }
+
+        Candidate {
+            endpoints: (color0, color1),
+            indices,
+            error,
+        }
+    };
+
+    let weight = |_: &(u16, u16), index: u8| -> f32 {
+        match (three_color, index) {
+            (_, 0) => 0.0,
+            (_, 1) => 1.0,
+            (true, _) => 0.5,
+            (false, 2) => 1.0 / 3.0,
+            (false, _) => 2.0 / 3.0,
+        }
+    };
+
+    let (color0, color1, indices) = if points.is_empty() {
+        (0, 0, [0; 16])
+    } else {
+        let best = refine(&points, 3, quality, evaluate, weight);
+
+        (best.endpoints.0, best.endpoints.1, best.indices)
+    };
+
+    let mut bits: u32 = 0;
+    let mut slot = 0;
+
+    for (index, transparent) in transparent.iter().enumerate() {
+        let value = if *transparent {
+            3
+        } else {
+            slot += 1;
+            indices[slot - 1] as u32
+        };
+
+        bits |= value << (index * 2);
+    }
+
+    output[0..2].copy_from_slice(&color0.to_le_bytes());
+    output[2..4].copy_from_slice(&color1.to_le_bytes());
+    output[4..8].copy_from_slice(&bits.to_le_bytes());
+}
+
+/// Decodes a bc1 color block to rgba pixels in row order, used to read pixels without converting on the GPU.
+pub(crate) fn decode_bc1_block(input: &[u8]) -> [[u8; 4]; 16] {
+    let color0 = u16::from_le_bytes([input[0], input[1]]);
+    let color1 = u16::from_le_bytes([input[2], input[3]]);
+    let bits = u32::from_le_bytes([input[4], input[5], input[6], input[7]]);
+
+    let (c0, c1) = (expand_565(color0), expand_565(color1));
+
+    let rgba = |[r, g, b]: [i32; 3]| [r as u8, g as u8, b as u8, 0xFF];
+
+    // The order of the endpoints selects between the four color and three color palettes.
+    let palette = if color0 > color1 {
+        [
+            rgba(c0),
+            rgba(c1),
+            rgba(std::array::from_fn(|i| (2 * c0[i] + c1[i]) / 3)),
+            rgba(std::array::from_fn(|i| (c0[i] + 2 * c1[i]) / 3)),
+        ]
+    } else {
+        [
+            rgba(c0),
+            rgba(c1),
+            rgba(std::array::from_fn(|i| (c0[i] + c1[i]) / 2)),
+            [0, 0, 0, 0],
+        ]
+    };
+
+    std::array::from_fn(|index| palette[((bits >> (index * 2)) & 0x3) as usize])
+}
+
+/// Builds the palette of a bc4 block from its endpoints.
+fn bc4_palette(value0: u8, value1: u8) -> [[i32; 1]; 8] {
+    let (a, b) = (value0 as i32, value1 as i32);
+
+    let mut palette = [[a], [b], [0], [0], [0], [0], [0], [0]];
+
+    if value0 > value1 {
+        for (code, entry) in palette.iter_mut().enumerate().skip(2) {
+            *entry = [((8 - code as i32) * a + (code as i32 - 1) * b) / 7];
+        }
+    } else {
+        for (code, entry) in palette.iter_mut().enumerate().skip(2).take(4) {
+            *entry = [((6 - code as i32) * a + (code as i32 - 1) * b) / 5];
+        }
+
+        palette[6] = [0];
+        palette[7] = [255];
+    }
+
+    palette
+}
+
+/// Encodes a bc4 single channel block.
+fn encode_bc4(values: &[u8; 16], quality: ImageCompressionQuality, output: &mut [u8]) {
+    let evaluate = |value0: u8, value1: u8| -> Candidate<(u8, u8)> {
+        let palette = bc4_palette(value0, value1);
+
+        let mut indices = [0; 16];
+        let mut error = 0;
+
+        for (index, value) in values.iter().enumerate() {
+            let (code, distance) = closest(&palette, &[*value as i32]);
+
+            indices[index] = code as u8;
+            error += distance;
+        }
+
+        Candidate {
+            endpoints: (value0, value1),
+            indices,
+            error,
+        }
+    };
+
+    let min = values.iter().copied().min().unwrap_or_default();
+    let max = values.iter().copied().max().unwrap_or_default();
+
+    // The eight value palette spans the block, the six value palette keeps exact black and white.
+    let mut best = evaluate(max, min);
+
+    if quality != ImageCompressionQuality::Fast {
+        let inner = values
+            .iter()
+            .copied()
+            .filter(|value| *value != 0 && *value != 255);
+
+        let inner_min = inner.clone().min().unwrap_or(0);
+        let inner_max = inner.max().unwrap_or(255);
+
+        let candidate = evaluate(inner_min, inner_max);
+
+        if candidate.error < best.error {
+            best = candidate;
+        }
+
+        let points: Vec<[f32; 4]> = values.map(|value| [value as f32, 0.0, 0.0, 0.0]).to_vec();
+
+        for _ in 0..quality.refinements() {
+            let (value0, value1) = best.endpoints;
+
+            if value0 <= value1 {
+                break;
+            }
+
+            let weights: Vec<f32> = best
+                .indices
+                .iter()
+                .map(|code| match code {
+                    0 => 0.0,
+                    1 => 1.0,
+                    code => (*code as f32 - 1.0) / 7.0,
+                })
+                .collect();
+
+            let Some((start, end)) = least_squares(&points, &weights, 1) else {
+                break;
+            };
+
+            let (value0, value1) = (start[0].round() as u8, end[0].round() as u8);
+
+            if value0 <= value1 {
+                break;
+            }
+
+            let candidate = evaluate(value0, value1);
+
+            if candidate.error >= best.error {
+                break;
+            }
+
+            best = candidate;
+        }
+    }
+
+    let mut bits: u64 = 0;
+
+    for (index, code) in best.indices.iter().enumerate() {
+        bits |= (*code as u64) << (index * 3);
+    }
+
+    output[0] = best.endpoints.0;
+    output[1] = best.endpoints.1;
+    output[2..8].copy_from_slice(&bits.to_le_bytes()[0..6]);
+}
+
+/// Quantizes a bc7 mode 6 endpoint to 7 bits per channel and a shared p-bit, picking the p-bit with the lowest error.
+fn quantize_bc7(color: [f32; 4]) -> ([u8; 4], u8) {
+    (0..2u8)
+        .map(|pbit| {
+            let quantized =
+                color.map(|value| ((value - pbit as f32) / 2.0).round().clamp(0.0, 127.0) as u8);
+
+            let error: f32 = quantized
+                .iter()
+                .zip(color)
+                .map(|(quantized, value)| {
+                    let expanded = ((*quantized as u32) << 1 | pbit as u32) as f32;
+
+                    (expanded - value) * (expanded - value)
+                })
+                .sum();
+
+            ((quantized, pbit), error)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(result, _)| result)
+        .unwrap_or_default()
+}
+
+/// Expands a bc7 mode 6 endpoint to 8 bits per channel.
+fn expand_bc7((color, pbit): ([u8; 4], u8)) -> [u32; 4] {
+    color.map(|value| (value as u32) << 1 | pbit as u32)
+}
+
+/// Encodes a bc7 block using mode 6, a single subset with 4 bit indices and alpha.
+fn encode_bc7(block: &Block, quality: ImageCompressionQuality, output: &mut [u8]) {
+    let points: Vec<[f32; 4]> = block.map(|pixel| pixel.map(|value| value as f32)).to_vec();
+
+    type Endpoints = (([u8; 4], u8), ([u8; 4], u8));
+
+    let evaluate = |start: [f32; 4], end: [f32; 4]| -> Candidate<Endpoints> {
+        let endpoints = (quantize_bc7(start), quantize_bc7(end));
+
+        let (e0, e1) = (expand_bc7(endpoints.0), expand_bc7(endpoints.1));
+
+        let palette: [[i32; 4]; 16] = BC7_WEIGHTS.map(|weight| {
+            std::array::from_fn(|i| (((64 - weight) * e0[i] + weight * e1[i] + 32) >> 6) as i32)
+        });
+
+        let mut indices = [0; 16];
+        let mut error = 0;
+
+        for (index, pixel) in block.iter().enumerate() {
+            let (code, distance) = closest(&palette, &pixel.map(|value| value as i32));
+
+            indices[index] = code as u8;
+            error += distance;
+        }
+
+        Candidate {
+            endpoints,
+            indices,
+            error,
+        }
+    };
+
+    let weight = |_: &Endpoints, index: u8| BC7_WEIGHTS[index as usize] as f32 / 64.0;
+
+    let mut best = refine(&points, 4, quality, evaluate, weight);
+
+    // The first index has an implied zero high bit, so swap the endpoints when it's set.
+    if best.indices[0] >= 8 {
+        best.endpoints = (best.endpoints.1, best.endpoints.0);
+        best.indices = best.indices.map(|index| 15 - index);
+    }
+
+    let ((color0, pbit0), (color1, pbit1)) = best.endpoints;
+
+    let mut bits: u128 = 1 << 6;
+    let mut position = 7;
+
+    let mut write = |value: u32, count: u32| {
+        bits |= (value as u128) << position;
+        position += count;
+    };
+
+    for channel in 0..4 {
+        write(color0[channel] as u32, 7);
+        write(color1[channel] as u32, 7);
+    }
+
+    write(pbit0 as u32, 1);
+    write(pbit1 as u32, 1);
+
+    for (index, value) in best.indices.iter().enumerate() {
+        write(*value as u32, if index == 0 { 3 } else { 4 });
+    }
+
+    output.copy_from_slice(&bits.to_le_bytes());
+}
@@ -20,6 +20,10 @@ pub enum TransformAlgorithm {
     ReconstructZInvertY,
     /// Transform the image by scale and bias.
     UniformScaleBias(f32, f32),
+    /// Multiply the color channels of the image by alpha.
+    Premultiply,
+    /// Divide the color channels of the image by alpha, restoring straight alpha.
+    Unpremultiply,
 }
 
 impl TransformAlgorithm {
@@ -31,6 +35,8 @@ impl TransformAlgorithm {
             TransformAlgorithm::UniformScaleBias(scale, bias) => {
                 uniform_scale_bias(image, *scale, *bias)?
             }
+            TransformAlgorithm::Premultiply => premultiply(image, false)?,
+            TransformAlgorithm::Unpremultiply => premultiply(image, true)?,
         }
 
         Ok(())
@@ -83,6 +89,46 @@ fn uniform_scale_bias(image: &mut Image, scale: f32, bias: f32) -> Result<(), Te
     Ok(())
 }
 
+/// Transforms the image by multiplying, or dividing, the color channels by alpha in linear color space.
+#[inline]
+fn premultiply(image: &mut Image, inverse: bool) -> Result<(), TextureError> {
+    // Needed to track sRGB correction after converting to linear color.
+    let source_format = image.format();
+
+    image.convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::None)?;
+
+    for frame in image.frames_mut() {
+        for pixel in frame.buffer_mut().chunks_exact_mut(4) {
+            let alpha = unpack_unorm8(pixel[3]);
+
+            let factor = if !inverse {
+                alpha
+            } else if alpha > 0.0 {
+                1.0 / alpha
+            } else {
+                // Fully transparent pixels have no color to restore.
+                continue;
+            };
+
+            for channel in &mut pixel[0..3] {
+                let value = (unpack_unorm8(*channel) * factor).min(1.0);
+
+                *channel = if source_format.is_srgb() {
+                    pack_unorm8(linear_to_srgb(value))
+                } else {
+                    pack_unorm8(value)
+                };
+            }
+        }
+    }
+
+    if source_format.is_srgb() {
+        image.set_format(image.format().to_srgb())?;
+    }
+
+    Ok(())
+}
+
 /// Transforms the image by reconstructing the Z (blue) channel from the XY (red, green) channels, and setting alpha to 1.0.
 #[inline]
 fn reconstruct_z(image: &mut Image, invert_y: bool) -> Result<(), TextureError> {
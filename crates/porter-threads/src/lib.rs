@@ -107,6 +107,11 @@ where
     rayon::scope(op)
 }
 
+/// Gets the number of threads in the thread pool.
+pub fn current_num_threads() -> usize {
+    rayon::current_num_threads()
+}
+
 /// Ensures the thread pool has been initialized.
 pub fn initialize_thread_pool() {
     static INITIALIZE: Once = Once::new();
@@ -0,0 +1,29 @@
+/// Helper to implement the routine for a generic number type.
+macro_rules! impl_hex_string {
+    ($typ:ty) => {
+        impl AsHexString for $typ {
+            fn as_hex_string(&self) -> String {
+                self.as_hex_string_width(size_of::<$typ>() * 2)
+            }
+
+            fn as_hex_string_width(&self, width: usize) -> String {
+                format!("0x{:0width$x}", self, width = width)
+            }
+        }
+    };
+}
+
+/// Utility trait to format numbers, such as hashes, as 0x prefixed hex strings.
+pub trait AsHexString {
+    /// Formats the number as a lowercase hex string, zero padded to the full width of the type, like `0x00c0ffee`.
+    fn as_hex_string(&self) -> String;
+    /// Formats the number as a lowercase hex string, zero padded to at least `width` digits, or unpadded when zero.
+    fn as_hex_string_width(&self, width: usize) -> String;
+}
+
+impl_hex_string!(u8);
+impl_hex_string!(u16);
+impl_hex_string!(u32);
+impl_hex_string!(u64);
+impl_hex_string!(u128);
+impl_hex_string!(usize);
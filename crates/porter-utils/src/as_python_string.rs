@@ -0,0 +1,28 @@
+/// Utility trait to format strings as python string literals, such as paths in generated scripts.
+pub trait AsPythonString {
+    /// Formats the string as a double quoted python string literal, escaping quotes, backslashes, and newlines.
+    fn as_python_string(&self) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> AsPythonString for T {
+    fn as_python_string(&self) -> String {
+        let value = self.as_ref();
+
+        let mut result = String::with_capacity(value.len() + 2);
+
+        result.push('"');
+
+        for char in value.chars() {
+            match char {
+                '\\' => result.push_str("\\\\"),
+                '"' => result.push_str("\\\""),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                _ => result.push(char),
+            }
+        }
+
+        result.push('"');
+        result
+    }
+}
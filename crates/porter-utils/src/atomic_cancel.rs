@@ -2,33 +2,62 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 
+struct AtomicCancelInner {
+    cancelled: AtomicBool,
+    parent: Option<AtomicCancel>,
+}
+
 /// Used to atomically cancel a multi-threaded operation.
 #[repr(transparent)]
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct AtomicCancel {
-    inner: Arc<AtomicBool>,
+    inner: Arc<AtomicCancelInner>,
 }
 
 impl AtomicCancel {
     /// Constructs a new atomic cancel.
     pub fn new() -> Self {
         Self {
-            inner: Arc::new(AtomicBool::new(false)),
+            inner: Arc::new(AtomicCancelInner {
+                cancelled: AtomicBool::new(false),
+                parent: None,
+            }),
+        }
+    }
+
+    /// Constructs a child canceller, which is cancelled along with this one, but can also be cancelled on its own.
+    pub fn child(&self) -> Self {
+        Self {
+            inner: Arc::new(AtomicCancelInner {
+                cancelled: AtomicBool::new(false),
+                parent: Some(self.clone()),
+            }),
         }
     }
 
     /// Resets the value of the canceller.
     pub fn reset(&self) {
-        self.inner.store(false, Ordering::Relaxed);
+        self.inner.cancelled.store(false, Ordering::Relaxed);
     }
 
     /// Signals that the operation is cancelled.
     pub fn cancel(&self) {
-        self.inner.store(true, Ordering::Relaxed);
+        self.inner.cancelled.store(true, Ordering::Relaxed);
     }
 
-    /// Whether or not the operation is cancelled.
+    /// Whether or not the operation, or any parent operation, is cancelled.
     pub fn is_cancelled(&self) -> bool {
-        self.inner.load(Ordering::Relaxed)
+        self.inner.cancelled.load(Ordering::Relaxed)
+            || self
+                .inner
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_cancelled())
+    }
+}
+
+impl Default for AtomicCancel {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
+use crate::ProgressSink;
+
 struct AtomicProgressInner {
     total: AtomicUsize,
     complete: AtomicUsize,
@@ -59,3 +61,10 @@ impl Default for AtomicProgress {
         Self::new()
     }
 }
+
+impl ProgressSink for AtomicProgress {
+    fn progress(&self, complete: usize, total: usize) {
+        self.inner.total.store(total, Ordering::Relaxed);
+        self.inner.complete.fetch_max(complete, Ordering::Relaxed);
+    }
+}
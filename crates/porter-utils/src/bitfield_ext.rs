@@ -0,0 +1,63 @@
+/// Utility to implement the trait.
+macro_rules! impl_bitfield {
+    ($type:ty) => {
+        impl BitfieldExt for $type {
+            #[track_caller]
+            fn bits(self, offset: u32, count: u32) -> Self {
+                debug_assert!(offset + count <= <$type>::BITS);
+
+                if count == 0 {
+                    return 0;
+                }
+
+                (self >> offset) & (<$type>::MAX >> (<$type>::BITS - count))
+            }
+
+            #[track_caller]
+            fn with_bits(self, offset: u32, count: u32, value: Self) -> Self {
+                debug_assert!(offset + count <= <$type>::BITS);
+
+                if count == 0 {
+                    return self;
+                }
+
+                let mask = (<$type>::MAX >> (<$type>::BITS - count)) << offset;
+
+                (self & !mask) | ((value << offset) & mask)
+            }
+
+            fn bit(self, index: u32) -> bool {
+                self.bits(index, 1) != 0
+            }
+
+            fn signed_bits(self, offset: u32, count: u32) -> i64 {
+                let value = self.bits(offset, count) as u64;
+
+                if count == 0 {
+                    return 0;
+                }
+
+                let shift = u64::BITS - count;
+
+                ((value << shift) as i64) >> shift
+            }
+        }
+    };
+}
+
+/// A trait that reads and writes bitfields packed into unsigned integers.
+pub trait BitfieldExt: Copy {
+    /// Extracts `count` bits starting at the bit `offset`.
+    fn bits(self, offset: u32, count: u32) -> Self;
+    /// Replaces `count` bits starting at the bit `offset` with the low bits of the value.
+    fn with_bits(self, offset: u32, count: u32, value: Self) -> Self;
+    /// Whether or not the bit at the index is set.
+    fn bit(self, index: u32) -> bool;
+    /// Extracts `count` bits starting at the bit `offset`, sign extending the result.
+    fn signed_bits(self, offset: u32, count: u32) -> i64;
+}
+
+impl_bitfield!(u8);
+impl_bitfield!(u16);
+impl_bitfield!(u32);
+impl_bitfield!(u64);
@@ -0,0 +1,75 @@
+/// Utility to implement the trait.
+macro_rules! impl_byte_swap {
+    ($type:ty) => {
+        impl ByteSwap for $type {
+            fn swap_bytes(self) -> Self {
+                <$type>::swap_bytes(self)
+            }
+        }
+    };
+    ($type:ty, float) => {
+        impl ByteSwap for $type {
+            fn swap_bytes(self) -> Self {
+                <$type>::from_bits(self.to_bits().swap_bytes())
+            }
+        }
+    };
+}
+
+/// A trait for values which can be converted between byte orders.
+pub trait ByteSwap: Copy + 'static {
+    /// Reverses the byte order of the value.
+    fn swap_bytes(self) -> Self;
+
+    /// Converts a little endian value to the native byte order.
+    fn from_le(value: Self) -> Self {
+        if cfg!(target_endian = "little") {
+            value
+        } else {
+            value.swap_bytes()
+        }
+    }
+
+    /// Converts a big endian value to the native byte order.
+    fn from_be(value: Self) -> Self {
+        if cfg!(target_endian = "big") {
+            value
+        } else {
+            value.swap_bytes()
+        }
+    }
+
+    /// Converts a native value to little endian byte order.
+    fn to_le(self) -> Self {
+        Self::from_le(self)
+    }
+
+    /// Converts a native value to big endian byte order.
+    fn to_be(self) -> Self {
+        Self::from_be(self)
+    }
+}
+
+impl_byte_swap!(u8);
+impl_byte_swap!(u16);
+impl_byte_swap!(u32);
+impl_byte_swap!(u64);
+impl_byte_swap!(u128);
+impl_byte_swap!(usize);
+impl_byte_swap!(i8);
+impl_byte_swap!(i16);
+impl_byte_swap!(i32);
+impl_byte_swap!(i64);
+impl_byte_swap!(i128);
+impl_byte_swap!(isize);
+impl_byte_swap!(f32, float);
+impl_byte_swap!(f64, float);
+
+impl<S, const N: usize> ByteSwap for [S; N]
+where
+    S: ByteSwap,
+{
+    fn swap_bytes(self) -> Self {
+        self.map(ByteSwap::swap_bytes)
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use crate::WorkerPool;
+
+/// A cache of directories which are known to exist, shared between threads, to avoid repeated `create_dir_all` calls.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryCache {
+    directories: Arc<RwLock<HashSet<PathBuf>>>,
+}
+
+impl DirectoryCache {
+    /// Constructs a new empty directory cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether or not the directory is known to exist.
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.directories
+            .read()
+            .map(|directories| directories.contains(path.as_ref()))
+            .unwrap_or_default()
+    }
+
+    /// The number of directories known to exist.
+    pub fn len(&self) -> usize {
+        self.directories
+            .read()
+            .map(|directories| directories.len())
+            .unwrap_or_default()
+    }
+
+    /// Whether or not no directories are known to exist.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets every cached directory, such as when they may have been removed.
+    pub fn clear(&self) {
+        if let Ok(mut directories) = self.directories.write() {
+            directories.clear();
+        }
+    }
+
+    /// Creates the directory and all of its parents, unless it was already created.
+    pub fn create<P: AsRef<Path>>(&self, path: P) -> Result<(), std::io::Error> {
+        let path = path.as_ref();
+
+        if self.contains(path) {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(path)?;
+
+        self.insert(path);
+
+        Ok(())
+    }
+
+    /// Creates each directory and all of their parents, skipping the ones already created.
+    ///
+    /// Directories which are a parent of another are created implicitly, and the rest are created in parallel.
+    pub fn create_batch<I, P>(&self, paths: I) -> Result<(), std::io::Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut paths: Vec<PathBuf> = paths
+            .into_iter()
+            .filter(|path| !self.contains(path))
+            .map(|path| path.as_ref().to_path_buf())
+            .collect();
+
+        paths.sort_unstable();
+        paths.dedup();
+
+        // Sorted paths place a parent directly before its children.
+        let leaves: Vec<PathBuf> = paths
+            .iter()
+            .enumerate()
+            .filter(|(index, path)| {
+                paths
+                    .get(index + 1)
+                    .is_none_or(|next| !next.starts_with(path))
+            })
+            .map(|(_, path)| path.clone())
+            .collect();
+
+        if leaves.len() <= 1 {
+            return leaves.iter().try_for_each(|path| self.create(path));
+        }
+
+        WorkerPool::with_workers(porter_threads::current_num_threads().min(leaves.len()))
+            .run(&leaves, |path| self.create(path))
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Marks the directory, and all of its parents, as created.
+    fn insert(&self, path: &Path) {
+        if let Ok(mut directories) = self.directories.write() {
+            for ancestor in path.ancestors() {
+                if ancestor.as_os_str().is_empty() || !directories.insert(ancestor.to_path_buf()) {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::io::ErrorKind;
+
+/// A report of an error, with a stable code, a message for the user, and the technical detail for logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    /// A stable code which identifies the kind of error, such as `TEX-003`.
+    pub code: &'static str,
+    /// A short message written for the user.
+    pub message: String,
+    /// The technical detail of the error.
+    pub detail: String,
+}
+
+impl ErrorReport {
+    /// Constructs a new error report.
+    pub fn new<M: Into<String>, D: Into<String>>(
+        code: &'static str,
+        message: M,
+        detail: D,
+    ) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+/// Describes an error in a way that can be shown to the user.
+pub trait ReportError: fmt::Debug {
+    /// A stable code which identifies the kind of error.
+    fn error_code(&self) -> &'static str;
+
+    /// A short message written for the user.
+    fn error_message(&self) -> String;
+
+    /// Builds a report of this error, using the debug representation as the technical detail.
+    fn report(&self) -> ErrorReport {
+        ErrorReport::new(self.error_code(), self.error_message(), format!("{self:?}"))
+    }
+}
+
+impl ReportError for std::io::Error {
+    fn error_code(&self) -> &'static str {
+        match self.kind() {
+            ErrorKind::NotFound => "IO-001",
+            ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => "IO-002",
+            ErrorKind::StorageFull | ErrorKind::QuotaExceeded => "IO-003",
+            ErrorKind::UnexpectedEof | ErrorKind::InvalidData => "IO-004",
+            ErrorKind::OutOfMemory => "IO-005",
+            _ => "IO-000",
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self.kind() {
+            ErrorKind::NotFound => String::from("A file or folder could not be found."),
+            ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => {
+                String::from("Access to a file or folder was denied.")
+            }
+            ErrorKind::StorageFull | ErrorKind::QuotaExceeded => {
+                String::from("The disk is out of space.")
+            }
+            ErrorKind::UnexpectedEof | ErrorKind::InvalidData => {
+                String::from("The data is incomplete or corrupt.")
+            }
+            ErrorKind::OutOfMemory => String::from("The system ran out of memory."),
+            _ => format!("A file operation failed: {self}."),
+        }
+    }
+}
+
+impl ReportError for std::collections::TryReserveError {
+    fn error_code(&self) -> &'static str {
+        "IO-005"
+    }
+
+    fn error_message(&self) -> String {
+        String::from("The system ran out of memory.")
+    }
+}
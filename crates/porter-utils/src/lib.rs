@@ -2,23 +2,31 @@ mod array_read_ext;
 mod array_write_ext;
 mod as_aligned;
 mod as_byte_slice;
+mod as_hex_string;
 mod as_human_bytes;
+mod as_python_string;
 mod as_this_slice;
 mod atomic_cancel;
 mod atomic_progress;
 mod atomic_semaphore;
 mod bit_sink;
 mod bit_stream;
+mod bitfield_ext;
 mod buffer_read_ext;
 mod buffer_write_ext;
+mod byte_swap;
 mod debug_bits;
 mod debug_hex;
+mod directory_cache;
+mod error_report;
 mod extract_digits;
 mod hash_ext;
 mod name_database;
+mod name_guesser;
 mod option_ext;
 mod path_ext;
 mod pattern;
+mod progress_sink;
 mod result_ext;
 mod sanitize_ext;
 mod seek_ext;
@@ -29,6 +37,7 @@ mod string_write_ext;
 mod struct_read_ext;
 mod struct_write_ext;
 mod vec_ext;
+mod worker_pool;
 
 pub(crate) mod hashes;
 
@@ -36,23 +45,31 @@ pub use array_read_ext::*;
 pub use array_write_ext::*;
 pub use as_aligned::*;
 pub use as_byte_slice::*;
+pub use as_hex_string::*;
 pub use as_human_bytes::*;
+pub use as_python_string::*;
 pub use as_this_slice::*;
 pub use atomic_cancel::*;
 pub use atomic_progress::*;
 pub use atomic_semaphore::*;
 pub use bit_sink::*;
 pub use bit_stream::*;
+pub use bitfield_ext::*;
 pub use buffer_read_ext::*;
 pub use buffer_write_ext::*;
+pub use byte_swap::*;
 pub use debug_bits::*;
 pub use debug_hex::*;
+pub use directory_cache::*;
+pub use error_report::*;
 pub use extract_digits::*;
 pub use hash_ext::*;
 pub use name_database::*;
+pub use name_guesser::*;
 pub use option_ext::*;
 pub use path_ext::*;
 pub use pattern::*;
+pub use progress_sink::*;
 pub use result_ext::*;
 pub use sanitize_ext::*;
 pub use seek_ext::*;
@@ -63,3 +80,4 @@ pub use string_write_ext::*;
 pub use struct_read_ext::*;
 pub use struct_write_ext::*;
 pub use vec_ext::*;
+pub use worker_pool::*;
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::Cursor;
 use std::io::Read;
 use std::io::Write;
@@ -88,6 +90,44 @@ impl NameDatabase {
         })
     }
 
+    /// Loads a name database from the given csv file path, in the form of `hash,name` per line.
+    ///
+    /// Hashes prefixed with `0x` are hex, all others are decimal. Lines that can't be parsed, such as
+    /// headers or comments, are skipped.
+    pub fn load_csv<P: AsRef<Path>>(file: P) -> Result<Self, io::Error> {
+        let file = BufReader::new(File::open(file.as_ref())?);
+
+        let mut inner = HashMap::new();
+
+        for line in file.lines() {
+            let line = line?;
+
+            let Some((hash, name)) = line.split_once(',') else {
+                continue;
+            };
+
+            let hash = hash.trim();
+            let name = name.trim().trim_matches('"');
+
+            if name.is_empty() {
+                continue;
+            }
+
+            let hash =
+                if let Some(hash) = hash.strip_prefix("0x").or_else(|| hash.strip_prefix("0X")) {
+                    u64::from_str_radix(hash, 16)
+                } else {
+                    hash.parse::<u64>()
+                };
+
+            if let Ok(hash) = hash {
+                inner.insert(hash, name.to_string());
+            }
+        }
+
+        Ok(Self { inner })
+    }
+
     /// Saves a name database with the current entries to the given file path.
     pub fn save<P: AsRef<Path>>(&self, file: P) -> Result<(), io::Error> {
         let mut file = File::create(file.as_ref())?;
@@ -0,0 +1,241 @@
+use crate::AtomicCancel;
+use crate::HashExt;
+
+/// How often, in candidates, to check whether or not a guess was cancelled.
+const CANCEL_INTERVAL: usize = 0x10000;
+
+/// A hash function used to hash asset names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameHashFunction {
+    Xxh364,
+    Murmura64,
+    Fnv1a64,
+}
+
+/// Hashes names the same way as a game, used to verify name guesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameHasher {
+    function: NameHashFunction,
+    seed: Option<u64>,
+    mask: u64,
+}
+
+impl NameHasher {
+    /// Constructs a new name hasher with the given hash function.
+    pub const fn new(function: NameHashFunction) -> Self {
+        Self {
+            function,
+            seed: None,
+            mask: u64::MAX,
+        }
+    }
+
+    /// Sets a custom seed for the hash function.
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets a mask that is applied to the hash, for games that reserve the upper bits.
+    pub const fn mask(mut self, mask: u64) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Hashes the given name.
+    pub fn hash<N: AsRef<[u8]>>(&self, name: N) -> u64 {
+        let name = name.as_ref();
+
+        let hash = match (self.function, self.seed) {
+            (NameHashFunction::Xxh364, None) => name.hash_xxh364(),
+            (NameHashFunction::Xxh364, Some(seed)) => name.hash_xxh364_with_seed(seed),
+            (NameHashFunction::Murmura64, None) => name.hash_murmura64(),
+            (NameHashFunction::Murmura64, Some(seed)) => name.hash_murmura64_with_seed(seed),
+            (NameHashFunction::Fnv1a64, None) => name.hash_fnv1a64(),
+            (NameHashFunction::Fnv1a64, Some(seed)) => name.hash_fnv1a64_with_seed(seed),
+        };
+
+        hash & self.mask
+    }
+
+    /// Whether or not the given name hashes to the target hash.
+    pub fn matches<N: AsRef<[u8]>>(&self, name: N, target: u64) -> bool {
+        self.hash(name) == (target & self.mask)
+    }
+}
+
+/// Guesses names for unresolved hashes by expanding wordlists, or brute forcing short names.
+#[derive(Debug, Clone)]
+pub struct NameGuesser {
+    hasher: NameHasher,
+    prefix: String,
+    suffix: String,
+    separators: Vec<String>,
+    charset: Vec<u8>,
+    max_length: usize,
+}
+
+impl NameGuesser {
+    /// Constructs a new name guesser for the given hasher.
+    pub fn new(hasher: NameHasher) -> Self {
+        Self {
+            hasher,
+            prefix: String::new(),
+            suffix: String::new(),
+            separators: vec![String::new(), String::from("_")],
+            charset: b"abcdefghijklmnopqrstuvwxyz0123456789_".to_vec(),
+            max_length: 5,
+        }
+    }
+
+    /// Sets a prefix that is added to every guess.
+    pub fn prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets a suffix that is added to every guess.
+    pub fn suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Sets the separators used to join words when expanding a wordlist.
+    pub fn separators<S: AsRef<str>>(mut self, separators: &[S]) -> Self {
+        self.separators = separators
+            .iter()
+            .map(|separator| separator.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the characters used when brute forcing names.
+    pub fn charset<C: AsRef<str>>(mut self, charset: C) -> Self {
+        self.charset = charset.as_ref().as_bytes().to_vec();
+        self
+    }
+
+    /// Sets the maximum length of a brute forced name, not including the prefix and suffix.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Expands the wordlist by joining up to `depth` words with each separator, returning the first matching name.
+    pub fn guess_wordlist<W: AsRef<str>>(
+        &self,
+        target: u64,
+        words: &[W],
+        depth: usize,
+        cancel: &AtomicCancel,
+    ) -> Option<String> {
+        if words.is_empty() || depth == 0 {
+            return None;
+        }
+
+        let separators: &[String] = if self.separators.is_empty() {
+            &[String::new()]
+        } else {
+            &self.separators
+        };
+
+        let mut indices = vec![0usize; depth];
+        let mut candidate = Vec::with_capacity(256);
+        let mut checked = 0usize;
+
+        for count in 1..=depth {
+            indices[..count].fill(0);
+
+            loop {
+                for separator in separators {
+                    candidate.clear();
+                    candidate.extend_from_slice(self.prefix.as_bytes());
+
+                    for (i, index) in indices[..count].iter().enumerate() {
+                        if i > 0 {
+                            candidate.extend_from_slice(separator.as_bytes());
+                        }
+
+                        candidate.extend_from_slice(words[*index].as_ref().as_bytes());
+                    }
+
+                    candidate.extend_from_slice(self.suffix.as_bytes());
+
+                    if self.hasher.matches(&candidate, target) {
+                        return Some(String::from_utf8_lossy(&candidate).into_owned());
+                    }
+
+                    // A single word doesn't use a separator, so only check it once.
+                    if count == 1 {
+                        break;
+                    }
+                }
+
+                checked += 1;
+
+                if checked.is_multiple_of(CANCEL_INTERVAL) && cancel.is_cancelled() {
+                    return None;
+                }
+
+                if !Self::advance(&mut indices[..count], words.len()) {
+                    break;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Brute forces every name up to the maximum length using the charset, returning the first matching name.
+    pub fn guess_brute_force(&self, target: u64, cancel: &AtomicCancel) -> Option<String> {
+        if self.charset.is_empty() {
+            return None;
+        }
+
+        let mut indices = vec![0usize; self.max_length];
+        let mut candidate = Vec::with_capacity(self.prefix.len() + self.max_length + 64);
+        let mut checked = 0usize;
+
+        for length in 1..=self.max_length {
+            indices[..length].fill(0);
+
+            loop {
+                candidate.clear();
+                candidate.extend_from_slice(self.prefix.as_bytes());
+                candidate.extend(indices[..length].iter().map(|index| self.charset[*index]));
+                candidate.extend_from_slice(self.suffix.as_bytes());
+
+                if self.hasher.matches(&candidate, target) {
+                    return Some(String::from_utf8_lossy(&candidate).into_owned());
+                }
+
+                checked += 1;
+
+                if checked.is_multiple_of(CANCEL_INTERVAL) && cancel.is_cancelled() {
+                    return None;
+                }
+
+                if !Self::advance(&mut indices[..length], self.charset.len()) {
+                    break;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Advances the indices to the next combination, returns false once every combination has been visited.
+    fn advance(indices: &mut [usize], count: usize) -> bool {
+        for index in indices.iter_mut().rev() {
+            *index += 1;
+
+            if *index < count {
+                return true;
+            }
+
+            *index = 0;
+        }
+
+        false
+    }
+}
@@ -0,0 +1,14 @@
+/// Receives progress updates from long running, possibly multi-threaded, operations.
+pub trait ProgressSink: Sync {
+    /// Called when the given number of items out of the total are complete.
+    fn progress(&self, complete: usize, total: usize);
+}
+
+impl<F> ProgressSink for F
+where
+    F: Fn(usize, usize) + Sync,
+{
+    fn progress(&self, complete: usize, total: usize) {
+        self(complete, total)
+    }
+}
@@ -2,6 +2,7 @@ use std::io;
 use std::io::Read;
 use std::io::Seek;
 use std::io::SeekFrom;
+use std::io::Write;
 
 use crate::AsAligned;
 use crate::StructReadExt;
@@ -10,6 +11,12 @@ use crate::StructReadExt;
 pub trait SeekExt: Seek {
     /// Aligns the current stream position to the given alignment.
     fn align_position(&mut self, alignment: u64) -> io::Result<u64>;
+    /// Aligns the current stream position to the given alignment, relative to the base offset.
+    fn align_position_from(&mut self, base: u64, alignment: u64) -> io::Result<u64>;
+    /// Aligns the current stream position to the given alignment by writing zeros.
+    fn pad_position(&mut self, alignment: u64) -> io::Result<u64>
+    where
+        Self: Write;
     /// Skips over the given number of bytes from the current position.
     fn skip<P: Copy + 'static>(&mut self, size: P) -> io::Result<u64>
     where
@@ -35,6 +42,28 @@ where
         self.seek(SeekFrom::Start(position.as_aligned(alignment)))
     }
 
+    fn align_position_from(&mut self, base: u64, alignment: u64) -> io::Result<u64> {
+        let position = self.stream_position()?;
+
+        let relative = position
+            .checked_sub(base)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+        self.seek(SeekFrom::Start(base + relative.as_aligned(alignment)))
+    }
+
+    fn pad_position(&mut self, alignment: u64) -> io::Result<u64>
+    where
+        Self: Write,
+    {
+        let position = self.stream_position()?;
+        let padding = position.as_aligned(alignment) - position;
+
+        io::copy(&mut io::repeat(0).take(padding), self)?;
+
+        Ok(position + padding)
+    }
+
     fn skip<P: Copy + 'static>(&mut self, size: P) -> io::Result<u64>
     where
         u64: TryFrom<P>,
@@ -1,6 +1,4 @@
-use std::io::Cursor;
-use std::io::Error;
-use std::io::ErrorKind;
+use std::fmt;
 use std::io::IoSlice;
 use std::io::Result;
 use std::io::Write;
@@ -9,13 +7,24 @@ use std::ops::DerefMut;
 use std::ops::Index;
 use std::ops::IndexMut;
 use std::slice::SliceIndex;
-use std::slice::from_raw_parts;
-use std::slice::from_raw_parts_mut;
 
-/// A simple stack allocated vector for `Copy` types.
-#[repr(transparent)]
+/// The storage of a stack vector, which moves to the heap once it outgrows the stack buffer.
+#[derive(Clone)]
+enum StackVecStorage<T, const SIZE: usize> {
+    Stack { buffer: [T; SIZE], len: usize },
+    Heap(Vec<T>),
+}
+
+/// A simple stack allocated vector for `Copy` types, which spills to the heap when it runs out of capacity.
+#[derive(Clone)]
 pub struct StackVec<T, const SIZE: usize> {
-    buffer: Cursor<[T; SIZE]>,
+    storage: StackVecStorage<T, SIZE>,
+}
+
+/// An iterator that moves the elements out of a stack vector.
+pub struct StackVecIntoIter<T, const SIZE: usize> {
+    vec: StackVec<T, SIZE>,
+    index: usize,
 }
 
 impl<T, const SIZE: usize> StackVec<T, SIZE>
@@ -26,20 +35,26 @@ where
     #[inline]
     pub const fn new(buffer: [T; SIZE]) -> Self {
         Self {
-            buffer: Cursor::new(buffer),
+            storage: StackVecStorage::Stack { buffer, len: 0 },
         }
     }
 
-    /// Returns the number of elements that can fit in this stack vector.
+    /// Returns the number of elements that can fit in this stack vector without reallocating.
     #[inline]
     pub fn capacity(&self) -> usize {
-        SIZE
+        match &self.storage {
+            StackVecStorage::Stack { .. } => SIZE,
+            StackVecStorage::Heap(vec) => vec.capacity(),
+        }
     }
 
     /// Returns the number of elements in the stack vector.
     #[inline]
     pub fn len(&self) -> usize {
-        self.buffer.position() as usize
+        match &self.storage {
+            StackVecStorage::Stack { len, .. } => *len,
+            StackVecStorage::Heap(vec) => vec.len(),
+        }
     }
 
     /// Returns whether or not the stack vector is empty.
@@ -48,22 +63,42 @@ where
         self.len() == 0
     }
 
+    /// Returns whether or not the elements have spilled to the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, StackVecStorage::Heap(_))
+    }
+
     /// Appends an element to the stack vector.
-    ///
-    /// # Panics
-    /// Will panic if out of capacity.
     #[inline]
     pub fn push(&mut self, value: T) {
-        let position = self.reserve(1);
+        self.reserve(1);
+
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                buffer[*len] = value;
+                *len += 1;
+            }
+            StackVecStorage::Heap(vec) => vec.push(value),
+        }
+    }
+
+    /// Removes the last element from the stack vector and returns it, or `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.last().copied()?;
 
-        self.buffer.get_mut()[position] = value;
+        self.truncate(self.len() - 1);
+
+        Some(value)
     }
 
     /// Inserts an element at position `index` within the stack vector, shifting all,
     /// elements after it to the right.
     ///
     /// # Panics
-    /// Panics if `index > len` or if out of capacity.
+    /// Panics if `index > len`.
+    #[track_caller]
     pub fn insert(&mut self, index: usize, element: T) {
         let position = self.len();
 
@@ -71,13 +106,16 @@ where
             panic!("insertion index (is {index}) should be <= len (is {position})");
         }
 
-        let position = self.reserve(1);
-
-        self.buffer
-            .get_mut()
-            .copy_within(index..position, index + 1);
+        self.reserve(1);
 
-        self.buffer.get_mut()[index] = element;
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                buffer.copy_within(index..position, index + 1);
+                buffer[index] = element;
+                *len += 1;
+            }
+            StackVecStorage::Heap(vec) => vec.insert(index, element),
+        }
     }
 
     /// Removes and returns the element at position `index` within the stack vector,
@@ -93,85 +131,123 @@ where
             panic!("removal index (is {index}) should be < len (is {position})");
         }
 
-        let result = self.buffer.get_ref()[position];
+        let result = self[index];
 
-        self.buffer
-            .get_mut()
-            .copy_within(index + 1..position, index);
-
-        self.buffer.set_position(position as u64 - 1);
+        self.copy_within(index + 1..position, index);
+        self.truncate(position - 1);
 
         result
     }
 
     /// Extends this stack vector with the given slice.
-    ///
-    /// # Panics
-    /// Will panic if out of capacity.
     #[inline]
     pub fn extend_from_slice<S: AsRef<[T]>>(&mut self, slice: S) {
         let slice = slice.as_ref();
-        let position = self.reserve(slice.len());
 
-        self.buffer.get_mut()[position..position + slice.len()].copy_from_slice(slice);
+        self.reserve(slice.len());
+
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                buffer[*len..*len + slice.len()].copy_from_slice(slice);
+                *len += slice.len();
+            }
+            StackVecStorage::Heap(vec) => vec.extend_from_slice(slice),
+        }
     }
 
     /// Resizes the stack vector in-place so that `len` is equal to `new_len`.
-    ///
-    /// # Panics
-    /// Will panic if `new_len > capacity`.
     pub fn resize(&mut self, new_len: usize, value: T) {
         let position = self.len();
 
-        if new_len > position {
-            let additional = new_len - position;
-            let position = self.reserve(additional);
+        if new_len <= position {
+            return self.truncate(new_len);
+        }
+
+        self.reserve(new_len - position);
 
-            for i in position..position + additional {
-                self.buffer.get_mut()[i] = value;
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                buffer[position..new_len].fill(value);
+                *len = new_len;
             }
-        } else {
-            self.buffer.set_position(new_len as u64);
+            StackVecStorage::Heap(vec) => vec.resize(new_len, value),
+        }
+    }
+
+    /// Shortens the stack vector, keeping the first `len` elements.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.storage {
+            StackVecStorage::Stack { len, .. } => *len = new_len.min(*len),
+            StackVecStorage::Heap(vec) => vec.truncate(new_len),
         }
     }
 
     /// Removes all elements from the stack vector.
     #[inline]
     pub fn clear(&mut self) {
-        self.buffer.set_position(0);
+        self.truncate(0);
     }
 
-    /// Try to reserve capacity for at least additioanl more elements to be inserted in the given stack vec.
-    #[inline]
+    /// Reserves capacity for at least `additional` more elements, spilling to the heap if they don't fit on the stack.
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                if *len + additional > SIZE {
+                    let mut vec = Vec::with_capacity(*len + additional);
+
+                    vec.extend_from_slice(&buffer[0..*len]);
+
+                    self.storage = StackVecStorage::Heap(vec);
+                }
+            }
+            StackVecStorage::Heap(vec) => vec.reserve(additional),
+        }
+    }
+
+    /// Try to reserve capacity for at least `additional` more elements to be inserted in the given stack vec.
     pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
-        if self.len() + additional > self.capacity() {
-            Err(Error::from(ErrorKind::OutOfMemory))
-        } else {
-            Ok(())
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => {
+                if *len + additional > SIZE {
+                    let mut vec = Vec::new();
+
+                    vec.try_reserve(*len + additional)?;
+                    vec.extend_from_slice(&buffer[0..*len]);
+
+                    self.storage = StackVecStorage::Heap(vec);
+                }
+            }
+            StackVecStorage::Heap(vec) => vec.try_reserve(additional)?,
         }
+
+        Ok(())
     }
 
-    /// Try to reserve capacity for at least additioanl more elements to be inserted in the given stack vec.
+    /// Try to reserve capacity for exactly `additional` more elements to be inserted in the given stack vec.
     ///
-    /// This is just an alias to `try_reserve(additional)` because we are just checking if we can fit more elements.
+    /// This is just an alias to `try_reserve(additional)` because the stack buffer is a fixed size.
     #[inline]
     pub fn try_reserve_exact(&mut self, additional: usize) -> Result<()> {
         self.try_reserve(additional)
     }
 
-    /// Reserves space for additional elements.
-    #[inline]
-    fn reserve(&mut self, additional: usize) -> usize {
-        let position = self.len();
-
-        if position + additional > self.capacity() {
-            panic!("capacity overflow");
+    /// Converts the stack vector into a heap allocated vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.storage {
+            StackVecStorage::Stack { buffer, len } => buffer[0..len].to_vec(),
+            StackVecStorage::Heap(vec) => vec,
         }
+    }
+}
 
-        self.buffer
-            .set_position(position as u64 + additional as u64);
-
-        position
+impl<T, const SIZE: usize> Default for StackVec<T, SIZE>
+where
+    T: Copy + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new([T::default(); SIZE])
     }
 }
 
@@ -188,22 +264,34 @@ where
 impl<const SIZE: usize> Write for StackVec<u8, SIZE> {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        self.buffer.write(buf)
+        self.extend_from_slice(buf);
+
+        Ok(buf.len())
     }
 
     #[inline]
     fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
-        self.buffer.write_vectored(bufs)
+        let len = bufs.iter().map(|buf| buf.len()).sum();
+
+        self.reserve(len);
+
+        for buf in bufs {
+            self.extend_from_slice(&**buf);
+        }
+
+        Ok(len)
     }
 
     #[inline]
     fn write_all(&mut self, buf: &[u8]) -> Result<()> {
-        self.buffer.write_all(buf)
+        self.extend_from_slice(buf);
+
+        Ok(())
     }
 
     #[inline]
     fn flush(&mut self) -> Result<()> {
-        self.buffer.flush()
+        Ok(())
     }
 }
 
@@ -212,12 +300,9 @@ impl<T, const SIZE: usize> Deref for StackVec<T, SIZE> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        // SAFETY: Data is always initialized, and position is managed by Cursor<[T; SIZE]>.
-        unsafe {
-            from_raw_parts(
-                self.buffer.get_ref().as_ptr(),
-                self.buffer.position() as usize,
-            )
+        match &self.storage {
+            StackVecStorage::Stack { buffer, len } => &buffer[0..*len],
+            StackVecStorage::Heap(vec) => vec,
         }
     }
 }
@@ -225,12 +310,9 @@ impl<T, const SIZE: usize> Deref for StackVec<T, SIZE> {
 impl<T, const SIZE: usize> DerefMut for StackVec<T, SIZE> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFETY: Data is always initialized, and position is managed by Cursor<[T; SIZE]>.
-        unsafe {
-            from_raw_parts_mut(
-                self.buffer.get_mut().as_mut_ptr(),
-                self.buffer.position() as usize,
-            )
+        match &mut self.storage {
+            StackVecStorage::Stack { buffer, len } => &mut buffer[0..*len],
+            StackVecStorage::Heap(vec) => vec,
         }
     }
 }
@@ -264,3 +346,132 @@ impl<T, I: SliceIndex<[T]>, const SIZE: usize> IndexMut<I> for StackVec<T, SIZE>
         IndexMut::index_mut(&mut **self, index)
     }
 }
+
+impl<T, const SIZE: usize> fmt::Debug for StackVec<T, SIZE>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const SIZE: usize, const OTHER: usize> PartialEq<StackVec<T, OTHER>> for StackVec<T, SIZE>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &StackVec<T, OTHER>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T, const SIZE: usize> PartialEq<[T]> for StackVec<T, SIZE>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T, const SIZE: usize> Eq for StackVec<T, SIZE> where T: Eq {}
+
+impl<T, const SIZE: usize> Extend<T> for StackVec<T, SIZE>
+where
+    T: Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T, const SIZE: usize> Extend<&'a T> for StackVec<T, SIZE>
+where
+    T: Copy + 'a,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const SIZE: usize> FromIterator<T> for StackVec<T, SIZE>
+where
+    T: Copy + Default,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::default();
+
+        result.extend(iter);
+        result
+    }
+}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a StackVec<T, SIZE> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a mut StackVec<T, SIZE> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const SIZE: usize> IntoIterator for StackVec<T, SIZE>
+where
+    T: Copy,
+{
+    type Item = T;
+    type IntoIter = StackVecIntoIter<T, SIZE>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        StackVecIntoIter {
+            vec: self,
+            index: 0,
+        }
+    }
+}
+
+impl<T, const SIZE: usize> Iterator for StackVecIntoIter<T, SIZE>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.vec.get(self.index).copied()?;
+
+        self.index += 1;
+
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len() - self.index;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const SIZE: usize> ExactSizeIterator for StackVecIntoIter<T, SIZE> where T: Copy {}
@@ -2,6 +2,82 @@
 pub trait StringCaseExt {
     /// Returns the titlecase equivalent of a string, as a new [`String`].
     fn to_titlecase(&self) -> String;
+    /// Returns the snake_case equivalent of a string, as a new [`String`].
+    fn to_snake_case(&self) -> String;
+    /// Returns the kebab-case equivalent of a string, as a new [`String`].
+    fn to_kebab_case(&self) -> String;
+    /// Returns the string split into capitalized words, as a new [`String`], words in all caps such as `HTTP` are kept as is.
+    fn to_title_words(&self) -> String;
+    /// Returns the string split into capitalized words, as a new [`String`], words matching one of the acronyms are in all caps.
+    fn to_title_words_with(&self, acronyms: &[&str]) -> String;
+}
+
+/// Splits a string into words, on separators, case changes, and the end of acronyms such as `HTTPServer`.
+fn split_words(value: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    let chars: Vec<(usize, char)> = value.char_indices().collect();
+
+    for (index, &(offset, char)) in chars.iter().enumerate() {
+        if !char.is_alphanumeric() {
+            if let Some(start) = start.take() {
+                words.push(&value[start..offset]);
+            }
+
+            continue;
+        }
+
+        let Some(word_start) = start else {
+            start = Some(offset);
+            continue;
+        };
+
+        let previous = chars[index - 1].1;
+        let next = chars.get(index + 1).map(|(_, char)| *char);
+
+        let boundary = char.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase())));
+
+        if boundary {
+            words.push(&value[word_start..offset]);
+            start = Some(offset);
+        }
+    }
+
+    if let Some(start) = start {
+        words.push(&value[start..]);
+    }
+
+    words
+}
+
+/// Joins the words in lowercase with the given separator.
+fn join_lowercase(value: &str, separator: &str) -> String {
+    split_words(value)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Capitalizes each word, keeping acronyms in all caps.
+fn join_title<F: Fn(&str) -> bool>(value: &str, is_acronym: F) -> String {
+    split_words(value)
+        .iter()
+        .map(|word| {
+            if is_acronym(word) {
+                return word.to_uppercase();
+            }
+
+            let lowercase = word.to_lowercase();
+
+            lowercase.as_str().to_titlecase()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 impl StringCaseExt for &str {
@@ -13,10 +89,50 @@ impl StringCaseExt for &str {
             Some(char) => char.to_uppercase().collect::<String>() + chars.as_str(),
         }
     }
+
+    fn to_snake_case(&self) -> String {
+        join_lowercase(self, "_")
+    }
+
+    fn to_kebab_case(&self) -> String {
+        join_lowercase(self, "-")
+    }
+
+    fn to_title_words(&self) -> String {
+        join_title(self, |word| {
+            word.chars().count() > 1
+                && word.chars().any(char::is_alphabetic)
+                && !word.chars().any(char::is_lowercase)
+        })
+    }
+
+    fn to_title_words_with(&self, acronyms: &[&str]) -> String {
+        join_title(self, |word| {
+            acronyms
+                .iter()
+                .any(|acronym| acronym.eq_ignore_ascii_case(word))
+        })
+    }
 }
 
 impl StringCaseExt for String {
     fn to_titlecase(&self) -> String {
         self.as_str().to_titlecase()
     }
+
+    fn to_snake_case(&self) -> String {
+        self.as_str().to_snake_case()
+    }
+
+    fn to_kebab_case(&self) -> String {
+        self.as_str().to_kebab_case()
+    }
+
+    fn to_title_words(&self) -> String {
+        self.as_str().to_title_words()
+    }
+
+    fn to_title_words_with(&self, acronyms: &[&str]) -> String {
+        self.as_str().to_title_words_with(acronyms)
+    }
 }
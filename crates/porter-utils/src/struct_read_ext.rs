@@ -3,16 +3,24 @@ use std::io::Read;
 use std::mem::MaybeUninit;
 use std::slice::from_raw_parts_mut;
 
+use crate::ByteSwap;
+
 /// A trait that reads structs from `Read` sources.
 pub trait StructReadExt: Read {
     /// Reads the type from the reader and advances the stream.
     fn read_struct<S: Copy + 'static>(&mut self) -> Result<S, io::Error>;
+    /// Reads a little endian value from the reader and advances the stream.
+    fn read_le<S: ByteSwap>(&mut self) -> Result<S, io::Error>;
+    /// Reads a big endian value from the reader and advances the stream.
+    fn read_be<S: ByteSwap>(&mut self) -> Result<S, io::Error>;
     /// Reads a byte length integer from the reader and advances the stream.
     fn read_sized_integer(&mut self, size: usize) -> Result<u64, io::Error>;
     /// Reads a big endian byte length integer from the reader and advances the stream.
     fn read_be_sized_integer(&mut self, size: usize) -> Result<u64, io::Error>;
     /// Reads a variable length integer from the reader and advances the stream.
     fn read_var_integer(&mut self) -> Result<u64, io::Error>;
+    /// Reads a zigzag encoded variable length integer from the reader and advances the stream.
+    fn read_var_signed_integer(&mut self) -> Result<i64, io::Error>;
 }
 
 impl<T> StructReadExt for T
@@ -31,6 +39,14 @@ where
         Ok(unsafe { result.assume_init() })
     }
 
+    fn read_le<S: ByteSwap>(&mut self) -> Result<S, io::Error> {
+        Ok(S::from_le(self.read_struct()?))
+    }
+
+    fn read_be<S: ByteSwap>(&mut self) -> Result<S, io::Error> {
+        Ok(S::from_be(self.read_struct()?))
+    }
+
     #[track_caller]
     fn read_sized_integer(&mut self, size: usize) -> Result<u64, io::Error> {
         debug_assert!(size <= size_of::<u64>());
@@ -78,4 +94,10 @@ where
 
         Ok(result)
     }
+
+    fn read_var_signed_integer(&mut self) -> Result<i64, io::Error> {
+        let value = self.read_var_integer()?;
+
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
 }
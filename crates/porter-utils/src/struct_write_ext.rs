@@ -2,17 +2,24 @@ use std::io;
 use std::io::Write;
 
 use crate::AsByteSlice;
+use crate::ByteSwap;
 
 /// A trait that writes structs to `Write` destinations.
 pub trait StructWriteExt: Write {
     /// Writes the type to the writer and advances the stream.
     fn write_struct<S: Copy + 'static>(&mut self, value: S) -> Result<(), io::Error>;
+    /// Writes the value to the writer in little endian and advances the stream.
+    fn write_le<S: ByteSwap>(&mut self, value: S) -> Result<(), io::Error>;
+    /// Writes the value to the writer in big endian and advances the stream.
+    fn write_be<S: ByteSwap>(&mut self, value: S) -> Result<(), io::Error>;
     /// Writes a byte length integer to the writer and advances the stream.
     fn write_sized_integer(&mut self, value: u64, size: usize) -> Result<(), io::Error>;
-    /// Writes a big endian byte length integer to the writr and advances the stream.
+    /// Writes a big endian byte length integer to the writer and advances the stream.
     fn write_be_sized_integer(&mut self, value: u64, size: usize) -> Result<(), io::Error>;
     /// Writes a variable length integer to the writer and advances the stream.
     fn write_var_integer(&mut self, value: u64) -> Result<(), io::Error>;
+    /// Writes a zigzag encoded variable length integer to the writer and advances the stream.
+    fn write_var_signed_integer(&mut self, value: i64) -> Result<(), io::Error>;
 }
 
 impl<T> StructWriteExt for T
@@ -23,6 +30,14 @@ where
         self.write_all(value.as_byte_slice())
     }
 
+    fn write_le<S: ByteSwap>(&mut self, value: S) -> Result<(), io::Error> {
+        self.write_struct(value.to_le())
+    }
+
+    fn write_be<S: ByteSwap>(&mut self, value: S) -> Result<(), io::Error> {
+        self.write_struct(value.to_be())
+    }
+
     #[track_caller]
     fn write_sized_integer(&mut self, value: u64, size: usize) -> Result<(), io::Error> {
         debug_assert!(size <= size_of::<u64>());
@@ -55,4 +70,8 @@ where
 
         Ok(())
     }
+
+    fn write_var_signed_integer(&mut self, value: i64) -> Result<(), io::Error> {
+        self.write_var_integer(((value << 1) ^ (value >> 63)) as u64)
+    }
 }
@@ -0,0 +1,111 @@
+use std::sync::Mutex;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::AtomicCancel;
+use crate::ProgressSink;
+
+/// A scoped pool of workers running on the thread pool, which share a cancel signal and report progress to a sink.
+pub struct WorkerPool<'a> {
+    workers: usize,
+    cancel: AtomicCancel,
+    sink: Option<&'a dyn ProgressSink>,
+}
+
+impl<'a> WorkerPool<'a> {
+    /// Constructs a new worker pool with one worker per thread in the thread pool.
+    pub fn new() -> Self {
+        Self::with_workers(porter_threads::current_num_threads())
+    }
+
+    /// Constructs a new worker pool with the given thread count.
+    pub fn with_workers(workers: usize) -> Self {
+        Self {
+            workers: workers.max(1),
+            cancel: AtomicCancel::new(),
+            sink: None,
+        }
+    }
+
+    /// Sets the canceller used to stop the workers before the next item.
+    pub fn cancel(mut self, cancel: AtomicCancel) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Sets the sink which receives progress as each item completes.
+    pub fn progress(mut self, sink: &'a dyn ProgressSink) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// The number of worker threads in this pool.
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Runs the worker on each worker of the pool at once, returning when every worker has returned.
+    ///
+    /// The workers run on the thread pool, so a worker loop should pull items from shared state until there are none left.
+    pub fn scope<F>(&self, worker: F)
+    where
+        F: Fn() + Sync,
+    {
+        let worker = &worker;
+
+        porter_threads::scope(|scope| {
+            for _ in 0..self.workers {
+                scope.spawn(move |_| worker());
+            }
+        });
+    }
+
+    /// Runs the work on each item, returning the results in order, items skipped by cancellation are `None`.
+    pub fn run<T, R, F>(&self, items: &[T], work: F) -> Vec<Option<R>>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let total = items.len();
+        let next = AtomicUsize::new(0);
+        let finished = AtomicUsize::new(0);
+
+        let results: Mutex<Vec<Option<R>>> =
+            Mutex::new(std::iter::repeat_with(|| None).take(total).collect());
+
+        self.scope(|| {
+            let mut completed = Vec::new();
+
+            while !self.cancel.is_cancelled() {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                completed.push((index, work(item)));
+
+                let complete = finished.fetch_add(1, Ordering::Relaxed) + 1;
+
+                if let Some(sink) = self.sink {
+                    sink.progress(complete, total);
+                }
+            }
+
+            if let Ok(mut results) = results.lock() {
+                for (index, result) in completed {
+                    results[index] = Some(result);
+                }
+            }
+        });
+
+        results.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for WorkerPool<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,9 +1,12 @@
+use porter_utils::ReportError;
+
 /// Errors that can occur in the preview crate.
 #[derive(Debug)]
 pub enum PreviewError {
     Unsupported,
     InvalidAsset,
     OutOfMemory,
+    GPUUnavailable,
     IoError(std::io::Error),
     TryReserveError(std::collections::TryReserveError),
 }
@@ -19,3 +22,27 @@ impl From<std::collections::TryReserveError> for PreviewError {
         Self::TryReserveError(value)
     }
 }
+
+impl ReportError for PreviewError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::Unsupported => "PVW-001",
+            Self::InvalidAsset => "PVW-002",
+            Self::OutOfMemory => "PVW-003",
+            Self::GPUUnavailable => "PVW-004",
+            Self::IoError(e) => e.error_code(),
+            Self::TryReserveError(e) => e.error_code(),
+        }
+    }
+
+    fn error_message(&self) -> String {
+        match self {
+            Self::Unsupported => String::from("This asset can't be previewed."),
+            Self::InvalidAsset => String::from("The asset is invalid and can't be previewed."),
+            Self::OutOfMemory => String::from("The graphics device ran out of memory."),
+            Self::GPUUnavailable => String::from("A graphics device is required for the preview."),
+            Self::IoError(e) => e.error_message(),
+            Self::TryReserveError(e) => e.error_message(),
+        }
+    }
+}
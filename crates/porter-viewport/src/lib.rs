@@ -1,6 +1,10 @@
 #![deny(unsafe_code)]
 
 mod error;
+mod model_render_mode;
+mod preview_asset;
+mod preview_background;
+mod render_animation;
 mod render_image;
 mod render_material;
 mod render_material_texture;
@@ -11,11 +15,19 @@ mod render_type;
 mod viewport_camera;
 mod viewport_key_state;
 mod viewport_renderer;
+mod viewport_selection;
+mod wireframe_mode;
 
 pub use error::*;
+pub use model_render_mode::*;
+pub use preview_asset::*;
+pub use preview_background::*;
 pub use viewport_key_state::*;
 pub use viewport_renderer::*;
+pub use viewport_selection::*;
+pub use wireframe_mode::*;
 
+pub(crate) use render_animation::*;
 pub(crate) use render_image::*;
 pub(crate) use render_material::*;
 pub(crate) use render_material_texture::*;
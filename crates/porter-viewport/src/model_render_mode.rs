@@ -0,0 +1,36 @@
+/// The way meshes of a model are colored in the preview.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModelRenderMode {
+    /// Meshes are drawn with their material textures.
+    #[default]
+    Material,
+    /// Meshes are drawn with a flat color unique to each mesh.
+    MeshId,
+    /// Meshes are drawn with a flat white mask.
+    Silhouette,
+}
+
+impl ModelRenderMode {
+    /// Returns the next render mode, wrapping back to materials after the last mode.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Material => Self::MeshId,
+            Self::MeshId => Self::Silhouette,
+            Self::Silhouette => Self::Material,
+        }
+    }
+
+    /// Whether or not meshes are drawn with flat, unlit colors.
+    pub fn is_flat(self) -> bool {
+        !matches!(self, Self::Material)
+    }
+
+    /// The display name of this render mode.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Material => "Material",
+            Self::MeshId => "Mesh ID",
+            Self::Silhouette => "Silhouette",
+        }
+    }
+}
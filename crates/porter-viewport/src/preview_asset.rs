@@ -0,0 +1,14 @@
+use porter_model::MaterialTextureRefUsage;
+use porter_model::Model;
+
+use porter_texture::Image;
+
+/// An asset that can be rendered by the viewport renderer without a window.
+pub enum PreviewAsset {
+    /// An image.
+    Image(Image),
+    /// A material, rendered using the first image.
+    Material(Vec<(String, MaterialTextureRefUsage, Image)>),
+    /// A model with an optional diffuse image for each material.
+    Model(Model, Vec<Option<Image>>),
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The light and dark colors of the checkerboard background.
+const CHECKERBOARD_COLORS: ([u8; 3], [u8; 3]) = ([0x4C, 0x4C, 0x4C], [0x33, 0x33, 0x33]);
+
+/// The background drawn behind the asset in the preview.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewBackground {
+    /// A single color.
+    Solid([u8; 3]),
+    /// A vertical gradient from the top color to the bottom color.
+    Gradient([u8; 3], [u8; 3]),
+    /// A checkerboard, which makes transparent parts of images easy to see.
+    Checkerboard,
+}
+
+impl PreviewBackground {
+    /// The backgrounds offered to the user, in the order they are shown.
+    pub const PRESETS: [Self; 6] = [
+        Self::Solid([0x11, 0x11, 0x11]),
+        Self::Solid([0x00, 0x00, 0x00]),
+        Self::Solid([0x80, 0x80, 0x80]),
+        Self::Solid([0xFF, 0xFF, 0xFF]),
+        Self::Gradient([0x3A, 0x3A, 0x3A], [0x11, 0x11, 0x11]),
+        Self::Checkerboard,
+    ];
+
+    /// The display name of this background.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Solid([0x11, 0x11, 0x11]) => "Dark",
+            Self::Solid([0x00, 0x00, 0x00]) => "Black",
+            Self::Solid([0x80, 0x80, 0x80]) => "Gray",
+            Self::Solid([0xFF, 0xFF, 0xFF]) => "White",
+            Self::Solid(_) => "Custom",
+            Self::Gradient(..) => "Gradient",
+            Self::Checkerboard => "Checkerboard",
+        }
+    }
+
+    /// The top and bottom colors of the background, or the light and dark colors of a checkerboard, as 0-1 rgba.
+    pub(crate) fn colors(self) -> ([f32; 4], [f32; 4]) {
+        let (top, bottom) = match self {
+            Self::Solid(color) => (color, color),
+            Self::Gradient(top, bottom) => (top, bottom),
+            Self::Checkerboard => CHECKERBOARD_COLORS,
+        };
+
+        let rgba = |[r, g, b]: [u8; 3]| [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0];
+
+        (rgba(top), rgba(bottom))
+    }
+
+    /// Whether or not the background is a checkerboard.
+    pub(crate) fn is_checkerboard(self) -> bool {
+        matches!(self, Self::Checkerboard)
+    }
+}
+
+impl Default for PreviewBackground {
+    fn default() -> Self {
+        Self::PRESETS[0]
+    }
+}
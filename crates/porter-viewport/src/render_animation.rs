@@ -0,0 +1,110 @@
+use porter_animation::Animation;
+
+use porter_model::Model;
+
+/// The framerate used when an animation doesn't have a valid one.
+const DEFAULT_FRAMERATE: f32 = 30.0;
+
+/// An animation played back on the previewed model.
+pub struct RenderAnimation {
+    animation: Animation,
+    name: String,
+    frame_count: u32,
+    time: f32,
+    playing: bool,
+}
+
+impl RenderAnimation {
+    /// Constructs a new render animation, which starts playing from the first frame.
+    pub fn new(name: String, animation: Animation) -> Self {
+        Self {
+            frame_count: animation.frame_count(),
+            animation,
+            name,
+            time: 0.0,
+            playing: true,
+        }
+    }
+
+    /// The name of the animation.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The frame being previewed.
+    pub fn frame(&self) -> u32 {
+        self.time as u32
+    }
+
+    /// The number of frames in the animation.
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Whether or not the animation is playing.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Plays or pauses the animation, playing a finished animation restarts it.
+    pub fn toggle_playing(&mut self) {
+        if !self.playing && self.frame() + 1 >= self.frame_count {
+            self.time = 0.0;
+        }
+
+        self.playing = !self.playing;
+    }
+
+    /// Advances playback by the given number of seconds, returns true if the frame changed.
+    pub fn advance(&mut self, seconds: f32) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        let last = self.frame_count.saturating_sub(1) as f32;
+
+        if last <= 0.0 {
+            self.playing = false;
+            return false;
+        }
+
+        let framerate = if self.animation.framerate > 0.0 {
+            self.animation.framerate
+        } else {
+            DEFAULT_FRAMERATE
+        };
+
+        let previous = self.frame();
+
+        self.time += seconds * framerate;
+
+        if self.time >= last {
+            if self.animation.looping {
+                self.time %= last;
+            } else {
+                self.time = last;
+                self.playing = false;
+            }
+        }
+
+        self.frame() != previous
+    }
+
+    /// Pauses the animation and steps the given number of frames, wrapping around either end.
+    pub fn step(&mut self, frames: i32) {
+        let frame_count = self.frame_count.max(1) as i64;
+        let frame = (self.frame() as i64 + frames as i64).rem_euclid(frame_count);
+
+        self.playing = false;
+        self.time = frame as f32;
+    }
+
+    /// Returns a copy of the model posed at the current frame.
+    pub fn pose(&self, model: &Model) -> Model {
+        let mut posed = model.clone();
+
+        self.animation.pose_model(&mut posed, self.frame());
+
+        posed
+    }
+}
@@ -182,7 +182,7 @@ impl RenderImage {
                     },
                     targets: &[Some(ColorTargetState {
                         format: TextureFormat::Rgba8Unorm,
-                        blend: Some(BlendState::REPLACE),
+                        blend: Some(BlendState::ALPHA_BLENDING),
                         write_mask: ColorWrites::ALL,
                     })],
                     compilation_options: Default::default(),
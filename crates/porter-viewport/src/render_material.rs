@@ -12,7 +12,12 @@ use crate::RenderImage;
 
 /// A 3d render material.
 pub struct RenderMaterial {
-    images: Vec<(Option<RenderImage>, ImageFormat, MaterialTextureRefUsage)>,
+    images: Vec<(
+        Option<RenderImage>,
+        ImageFormat,
+        MaterialTextureRefUsage,
+        String,
+    )>,
     index: usize,
 }
 
@@ -21,20 +26,20 @@ impl RenderMaterial {
     pub fn from_images(
         instance: &GPUInstance,
         bind_group_layouts: &[&BindGroupLayout],
-        images: &[(MaterialTextureRefUsage, Image)],
+        images: &[(String, MaterialTextureRefUsage, Image)],
     ) -> Result<Self, PreviewError> {
-        let mut images: Vec<(Option<RenderImage>, ImageFormat, MaterialTextureRefUsage)> = images
+        let mut images: Vec<_> = images
             .iter()
-            .map(|(usage, image)| {
+            .map(|(name, usage, image)| {
                 let format = image.format();
                 let image = RenderImage::from_image(instance, bind_group_layouts, image);
                 let usage = *usage;
 
-                (image.ok(), format, usage)
+                (image.ok(), format, usage, name.clone())
             })
             .collect();
 
-        images.sort_by_key(|(image, _, usage)| {
+        images.sort_by_key(|(image, _, usage, _)| {
             let usage = *usage;
             let bounds = (
                 u32::MAX
@@ -102,6 +107,24 @@ impl RenderMaterial {
         self.images[self.index].2.to_string()
     }
 
+    /// Returns the name of the image.
+    pub fn name(&self) -> String {
+        if self.images.is_empty() {
+            return String::from("N/A");
+        }
+
+        self.images[self.index].3.clone()
+    }
+
+    /// Returns a label for each image, with its usage and name, in cycle order.
+    pub fn labels(&self) -> Vec<String> {
+        self.images
+            .iter()
+            .enumerate()
+            .map(|(index, (_, _, usage, name))| format!("{}. {}: {}", index + 1, usage, name))
+            .collect()
+    }
+
     /// The current material image index.
     pub fn index(&self) -> usize {
         self.index
@@ -142,13 +165,33 @@ impl RenderMaterial {
         }
     }
 
+    /// Goes back to the previous image.
+    pub fn previous(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+
+        if self.index == 0 {
+            self.index = self.len() - 1;
+        } else {
+            self.index -= 1;
+        }
+    }
+
+    /// Selects the image at the given index.
+    pub fn select(&mut self, index: usize) {
+        if index < self.len() {
+            self.index = index;
+        }
+    }
+
     /// Draws the material using the given render pass.
     pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
         if self.images.is_empty() {
             return;
         }
 
-        if let Some((Some(image), _, _)) = self.images.get(self.index) {
+        if let Some((Some(image), _, _, _)) = self.images.get(self.index) {
             image.draw(render_pass);
         }
     }
@@ -18,6 +18,42 @@ pub struct RenderMaterialTexture {
 
 /// Utility to allocate the fallback image for a material texture.
 fn default_image() -> Result<Image, PreviewError> {
+    solid_image(0xFFA1A1A1)
+}
+
+/// The size of the checker image, and of each checker cell, in pixels.
+const CHECKER_SIZE: u32 = 64;
+const CHECKER_CELL: u32 = 8;
+
+/// Utility to allocate a checker image used to inspect uv layouts.
+pub(crate) fn checker_image() -> Result<Image, PreviewError> {
+    let mut image = Image::new(CHECKER_SIZE, CHECKER_SIZE, ImageFormat::R8G8B8A8Unorm)
+        .map_err(|_| PreviewError::InvalidAsset)?;
+
+    let pixels: Vec<u32> = (0..CHECKER_SIZE * CHECKER_SIZE)
+        .map(|index| {
+            let x = (index % CHECKER_SIZE) / CHECKER_CELL;
+            let y = (index / CHECKER_SIZE) / CHECKER_CELL;
+
+            if (x + y).is_multiple_of(2) {
+                0xFFE0E0E0
+            } else {
+                0xFF404040
+            }
+        })
+        .collect();
+
+    image
+        .create_frame()
+        .map_err(|_| PreviewError::OutOfMemory)?
+        .buffer_mut()
+        .copy_from_slice(pixels.as_slice().as_this_slice());
+
+    Ok(image)
+}
+
+/// Utility to allocate a solid color image, the color is in ABGR order.
+pub(crate) fn solid_image(color: u32) -> Result<Image, PreviewError> {
     let mut image =
         Image::new(4, 4, ImageFormat::R8G8B8A8Unorm).map_err(|_| PreviewError::InvalidAsset)?;
 
@@ -25,11 +61,30 @@ fn default_image() -> Result<Image, PreviewError> {
         .create_frame()
         .map_err(|_| PreviewError::OutOfMemory)?
         .buffer_mut()
-        .copy_from_slice([0xFFA1A1A1u32; 4 * 4].as_slice().as_this_slice());
+        .copy_from_slice([color; 4 * 4].as_slice().as_this_slice());
 
     Ok(image)
 }
 
+/// Utility to pick a distinct color for the given mesh index, spreading hues by the golden ratio.
+pub(crate) fn mesh_id_color(index: usize) -> u32 {
+    let hue = (index as f32 * 0.618_034).fract() * 6.0;
+    let x = 1.0 - ((hue % 2.0) - 1.0).abs();
+
+    let (r, g, b) = match hue as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+
+    let channel = |value: f32| (value * 0.85 * 255.0 + 0.15 * 255.0) as u32;
+
+    0xFF000000 | (channel(b) << 16) | (channel(g) << 8) | channel(r)
+}
+
 impl RenderMaterialTexture {
     /// Constructs a new render material texture from the given image, or defaults to a 4x4 gray square.
     pub fn from_image_default(
@@ -17,62 +17,207 @@ use porter_utils::VecExt;
 
 use crate::PreviewError;
 use crate::RenderMaterialTexture;
+use crate::WireframeMode;
+
+/// The size of each vertex, a position, normal, and uv.
+const VERTEX_STRIDE: usize = (size_of::<Vector3>() * 2) + size_of::<Vector2>();
+
+/// The render pipelines shared by every mesh in a model, for each culling and wireframe variant.
+pub struct RenderMeshPipelines {
+    pipelines: [RenderPipeline; 4],
+    overlay_pipelines: [RenderPipeline; 2],
+}
+
+impl RenderMeshPipelines {
+    /// Constructs the render pipelines, the material layout must match the layout of every material texture.
+    pub fn new(
+        instance: &GPUInstance,
+        bind_group_layouts: &[&BindGroupLayout],
+        material_layout: &BindGroupLayout,
+    ) -> Self {
+        let render_pipeline_layout =
+            instance
+                .device()
+                .create_pipeline_layout(&PipelineLayoutDescriptor {
+                    label: None,
+                    bind_group_layouts: &[bind_group_layouts, &[material_layout]].concat(),
+                    push_constant_ranges: &[],
+                });
+
+        let create_pipeline = |culling: bool, wireframe: bool, overlay: bool| {
+            instance
+                .device()
+                .create_render_pipeline(&RenderPipelineDescriptor {
+                    label: None,
+                    layout: Some(&render_pipeline_layout),
+                    vertex: VertexState {
+                        module: instance.gpu_preview_shader(),
+                        entry_point: Some("vs_main"),
+                        buffers: &[VertexBufferLayout {
+                            array_stride: VERTEX_STRIDE as BufferAddress,
+                            step_mode: VertexStepMode::Vertex,
+                            attributes: &[
+                                VertexAttribute {
+                                    offset: 0,
+                                    shader_location: 0,
+                                    format: VertexFormat::Float32x3,
+                                },
+                                VertexAttribute {
+                                    offset: size_of::<Vector3>() as BufferAddress,
+                                    shader_location: 1,
+                                    format: VertexFormat::Float32x3,
+                                },
+                                VertexAttribute {
+                                    offset: (size_of::<Vector3>() * 2) as BufferAddress,
+                                    shader_location: 2,
+                                    format: VertexFormat::Float32x2,
+                                },
+                            ],
+                        }],
+                        compilation_options: Default::default(),
+                    },
+                    primitive: PrimitiveState {
+                        topology: PrimitiveTopology::TriangleList,
+                        strip_index_format: None,
+                        front_face: FrontFace::Cw,
+                        cull_mode: if culling { Some(Face::Back) } else { None },
+                        unclipped_depth: false,
+                        polygon_mode: if wireframe || overlay {
+                            PolygonMode::Line
+                        } else {
+                            PolygonMode::Fill
+                        },
+                        conservative: false,
+                    },
+                    // Overlay edges are pulled towards the camera, so they win against the faces they were drawn over.
+                    depth_stencil: Some(DepthStencilState {
+                        format: TextureFormat::Depth32Float,
+                        depth_write_enabled: !overlay,
+                        depth_compare: if overlay {
+                            CompareFunction::LessEqual
+                        } else {
+                            CompareFunction::Less
+                        },
+                        stencil: StencilState::default(),
+                        bias: if overlay {
+                            DepthBiasState {
+                                constant: -2,
+                                slope_scale: -1.0,
+                                clamp: 0.0,
+                            }
+                        } else {
+                            DepthBiasState::default()
+                        },
+                    }),
+                    multisample: MultisampleState {
+                        count: 4,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                    fragment: Some(FragmentState {
+                        module: instance.gpu_preview_shader(),
+                        entry_point: if overlay {
+                            Some("fs_wireframe_main")
+                        } else if culling {
+                            Some("fs_main")
+                        } else {
+                            Some("fs_main_nocull")
+                        },
+                        targets: &[Some(ColorTargetState {
+                            format: TextureFormat::Rgba8Unorm,
+                            blend: Some(BlendState::REPLACE),
+                            write_mask: ColorWrites::ALL,
+                        })],
+                        compilation_options: Default::default(),
+                    }),
+                    multiview: None,
+                    cache: None,
+                })
+        };
+
+        Self {
+            pipelines: [
+                create_pipeline(true, false, false),
+                create_pipeline(true, true, false),
+                create_pipeline(false, false, false),
+                create_pipeline(false, true, false),
+            ],
+            overlay_pipelines: [
+                create_pipeline(true, false, true),
+                create_pipeline(false, false, true),
+            ],
+        }
+    }
+
+    /// Gets the render pipeline for the given variant.
+    pub fn pipeline(&self, culling: bool, wireframe: bool) -> &RenderPipeline {
+        &self.pipelines[(!culling as usize) * 2 + wireframe as usize]
+    }
+
+    /// Gets the render pipeline which draws edges over faces that were already drawn.
+    pub fn overlay_pipeline(&self, culling: bool) -> &RenderPipeline {
+        &self.overlay_pipelines[!culling as usize]
+    }
+}
 
 /// A 3d render mesh.
 pub struct RenderMesh {
-    render_pipeline: RenderPipeline,
-    render_pipeline_wireframe: RenderPipeline,
-    vertex_buffer: Buffer,
+    pipelines: Arc<RenderMeshPipelines>,
+    vertex_buffers: Vec<(usize, Buffer)>,
+    uv_layer: usize,
     pub(crate) vertex_count: usize,
     face_buffer: Buffer,
     pub(crate) face_count: usize,
+    culling: bool,
+    pub(crate) source: Option<usize>,
+    pub(crate) material: Option<usize>,
     material_texture: Arc<RenderMaterialTexture>,
 }
 
-impl RenderMesh {
-    /// Constructs a new render mesh from the given mesh.
-    pub fn from_mesh(
-        instance: &GPUInstance,
-        bind_group_layouts: &[&BindGroupLayout],
-        mesh: &Mesh,
-        material_textures: &[Arc<RenderMaterialTexture>],
-        culling: bool,
-    ) -> Result<Self, PreviewError> {
-        let vertex_stride = (size_of::<Vector3>() * 2) + size_of::<Vector2>();
-
-        let material_texture = match mesh.material {
-            Some(index) => material_textures
-                .get(index)
-                .cloned()
-                .unwrap_or_else(|| material_textures[material_textures.len() - 1].clone()),
-            None => material_textures[material_textures.len() - 1].clone(),
-        };
+/// Utility to create a vertex buffer for the mesh, using the given uv layer.
+fn create_vertex_buffer(
+    instance: &GPUInstance,
+    mesh: &Mesh,
+    uv_layer: usize,
+) -> Result<Buffer, PreviewError> {
+    let vertex_buffer: Vec<u8> = Vec::try_with_exact_capacity(VERTEX_STRIDE * mesh.vertices.len())?;
 
-        let vertex_buffer: Vec<u8> =
-            Vec::try_with_exact_capacity(vertex_stride * mesh.vertices.len())?;
+    let mut vertex_buffer = Cursor::new(vertex_buffer);
 
-        let mut vertex_buffer = Cursor::new(vertex_buffer);
+    for v in 0..mesh.vertices.len() {
+        let vertex = mesh.vertices.vertex(v);
 
-        for v in 0..mesh.vertices.len() {
-            let vertex = mesh.vertices.vertex(v);
+        vertex_buffer.write_struct(vertex.position())?;
+        vertex_buffer.write_struct(vertex.normal())?;
 
-            vertex_buffer.write_struct(vertex.position())?;
-            vertex_buffer.write_struct(vertex.normal())?;
-
-            if mesh.vertices.uv_layers() > 0 {
-                vertex_buffer.write_struct(vertex.uv(0))?;
-            } else {
-                vertex_buffer.write_struct(Vector2::zero())?;
-            }
+        if uv_layer < mesh.vertices.uv_layers() {
+            vertex_buffer.write_struct(vertex.uv(uv_layer))?;
+        } else {
+            vertex_buffer.write_struct(Vector2::zero())?;
         }
+    }
 
-        let vertex_buffer = instance
-            .device()
-            .create_buffer_init(&util::BufferInitDescriptor {
-                label: None,
-                contents: &vertex_buffer.into_inner(),
-                usage: BufferUsages::VERTEX,
-            });
+    Ok(instance
+        .device()
+        .create_buffer_init(&util::BufferInitDescriptor {
+            label: None,
+            contents: &vertex_buffer.into_inner(),
+            usage: BufferUsages::VERTEX,
+        }))
+}
+
+impl RenderMesh {
+    /// Constructs a new render mesh from the given mesh, the source is the index of the model mesh it belongs to.
+    pub fn from_mesh(
+        instance: &GPUInstance,
+        pipelines: Arc<RenderMeshPipelines>,
+        mesh: &Mesh,
+        material_texture: Arc<RenderMaterialTexture>,
+        culling: bool,
+        uv_layer: usize,
+        source: Option<usize>,
+    ) -> Result<Self, PreviewError> {
+        let vertex_buffer = create_vertex_buffer(instance, mesh, uv_layer)?;
 
         let face_buffer = instance
             .device()
@@ -82,128 +227,102 @@ impl RenderMesh {
                 usage: BufferUsages::INDEX,
             });
 
-        let render_pipeline_layout =
-            instance
-                .device()
-                .create_pipeline_layout(&PipelineLayoutDescriptor {
-                    label: None,
-                    bind_group_layouts: &[
-                        bind_group_layouts,
-                        &[material_texture.bind_group_layout()],
-                    ]
-                    .concat(),
-                    push_constant_ranges: &[],
-                });
-
-        let render_pipeline_desc = RenderPipelineDescriptor {
-            label: None,
-            layout: Some(&render_pipeline_layout),
-            vertex: VertexState {
-                module: instance.gpu_preview_shader(),
-                entry_point: Some("vs_main"),
-                buffers: &[VertexBufferLayout {
-                    array_stride: vertex_stride as BufferAddress,
-                    step_mode: VertexStepMode::Vertex,
-                    attributes: &[
-                        VertexAttribute {
-                            offset: 0,
-                            shader_location: 0,
-                            format: VertexFormat::Float32x3,
-                        },
-                        VertexAttribute {
-                            offset: size_of::<Vector3>() as BufferAddress,
-                            shader_location: 1,
-                            format: VertexFormat::Float32x3,
-                        },
-                        VertexAttribute {
-                            offset: (size_of::<Vector3>() * 2) as BufferAddress,
-                            shader_location: 2,
-                            format: VertexFormat::Float32x2,
-                        },
-                    ],
-                }],
-                compilation_options: Default::default(),
-            },
-            primitive: PrimitiveState {
-                topology: PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: FrontFace::Cw,
-                cull_mode: if culling { Some(Face::Back) } else { None },
-                unclipped_depth: false,
-                polygon_mode: PolygonMode::Fill,
-                conservative: false,
-            },
-            depth_stencil: Some(DepthStencilState {
-                format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: CompareFunction::Less,
-                stencil: StencilState::default(),
-                bias: DepthBiasState::default(),
-            }),
-            multisample: MultisampleState {
-                count: 4,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            fragment: Some(FragmentState {
-                module: instance.gpu_preview_shader(),
-                entry_point: if culling {
-                    Some("fs_main")
-                } else {
-                    Some("fs_main_nocull")
-                },
-                targets: &[Some(ColorTargetState {
-                    format: TextureFormat::Rgba8Unorm,
-                    blend: Some(BlendState::REPLACE),
-                    write_mask: ColorWrites::ALL,
-                })],
-                compilation_options: Default::default(),
-            }),
-            multiview: None,
-            cache: None,
-        };
-
-        let render_pipeline = instance
-            .device()
-            .create_render_pipeline(&render_pipeline_desc);
-
-        let render_pipeline_wireframe =
-            instance
-                .device()
-                .create_render_pipeline(&RenderPipelineDescriptor {
-                    primitive: PrimitiveState {
-                        polygon_mode: PolygonMode::Line,
-                        ..render_pipeline_desc.primitive
-                    },
-                    ..render_pipeline_desc
-                });
-
         Ok(Self {
-            render_pipeline,
-            render_pipeline_wireframe,
-            vertex_buffer,
+            pipelines,
+            vertex_buffers: vec![(uv_layer, vertex_buffer)],
+            uv_layer,
             vertex_count: mesh.vertices.len(),
             face_buffer,
             face_count: mesh.faces.len(),
+            culling,
+            source,
+            material: mesh.material,
             material_texture,
         })
     }
 
-    /// Draws the mesh using the given render pass.
-    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, wireframe: bool) {
+    /// Whether or not a vertex buffer for the given uv layer has already been created.
+    pub fn has_uv_layer(&self, uv_layer: usize) -> bool {
+        self.vertex_buffers
+            .iter()
+            .any(|(layer, _)| *layer == uv_layer)
+    }
+
+    /// Draws with the given uv layer, creating and caching its vertex buffer from the mesh if necessary.
+    pub fn set_uv_layer(
+        &mut self,
+        instance: &GPUInstance,
+        mesh: Option<&Mesh>,
+        uv_layer: usize,
+    ) -> Result<(), PreviewError> {
+        if !self.has_uv_layer(uv_layer) {
+            let Some(mesh) = mesh else {
+                return Err(PreviewError::InvalidAsset);
+            };
+
+            let vertex_buffer = create_vertex_buffer(instance, mesh, uv_layer)?;
+
+            self.vertex_buffers.push((uv_layer, vertex_buffer));
+        }
+
+        self.uv_layer = uv_layer;
+
+        Ok(())
+    }
+
+    /// Replaces the vertices drawn with those of the given mesh, such as when the model is posed.
+    ///
+    /// Vertex buffers cached for other uv layers are dropped, since they no longer match.
+    pub fn set_vertices(
+        &mut self,
+        instance: &GPUInstance,
+        mesh: &Mesh,
+    ) -> Result<(), PreviewError> {
+        let vertex_buffer = create_vertex_buffer(instance, mesh, self.uv_layer)?;
+
+        self.vertex_buffers = vec![(self.uv_layer, vertex_buffer)];
+
+        Ok(())
+    }
+
+    /// Draws with the given material texture.
+    pub fn set_material_texture(&mut self, material_texture: Arc<RenderMaterialTexture>) {
+        self.material_texture = material_texture;
+    }
+
+    /// Draws the mesh using the given render pass, back faces are only culled when the mesh and the caller allow it.
+    pub fn draw<'a>(
+        &'a self,
+        render_pass: &mut RenderPass<'a>,
+        wireframe: WireframeMode,
+        culling: bool,
+    ) {
         if self.vertex_count == 0 || self.face_count == 0 {
             return;
         }
 
-        if wireframe {
-            render_pass.set_pipeline(&self.render_pipeline_wireframe);
-        } else {
-            render_pass.set_pipeline(&self.render_pipeline);
-        }
+        let Some((_, vertex_buffer)) = self
+            .vertex_buffers
+            .iter()
+            .find(|(layer, _)| *layer == self.uv_layer)
+        else {
+            return;
+        };
 
+        let culling = self.culling && culling;
+
+        render_pass.set_pipeline(
+            self.pipelines
+                .pipeline(culling, wireframe == WireframeMode::Wireframe),
+        );
         render_pass.set_bind_group(1, self.material_texture.bind_group(), &[]);
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
         render_pass.set_index_buffer(self.face_buffer.slice(..), IndexFormat::Uint32);
         render_pass.draw_indexed(0..self.face_count as u32 * 3, 0, 0..1);
+
+        if wireframe == WireframeMode::Overlay {
+            render_pass.set_pipeline(self.pipelines.overlay_pipeline(culling));
+            render_pass.draw_indexed(0..self.face_count as u32 * 3, 0, 0..1);
+        }
     }
 }
@@ -1,23 +1,38 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use wgpu::*;
 
 use porter_gpu::GPUInstance;
 
+use porter_math::Vector3;
+
+use porter_model::Aabb;
+use porter_model::Mesh;
 use porter_model::Model;
 
 use porter_texture::Image;
 
+use crate::ModelRenderMode;
 use crate::PreviewError;
 use crate::RenderMaterialTexture;
 use crate::RenderMesh;
+use crate::RenderMeshPipelines;
 use crate::RenderSkeleton;
+use crate::WireframeMode;
+use crate::checker_image;
+use crate::mesh_id_color;
+use crate::solid_image;
 
 /// A 3d render model.
 pub struct RenderModel {
     meshes: Vec<RenderMesh>,
+    material_textures: Vec<Arc<RenderMaterialTexture>>,
+    mesh_bounds: Vec<Aabb>,
+    bone_positions: Vec<Vector3>,
     skeleton: Option<RenderSkeleton>,
     srgb: bool,
+    generated_normals: bool,
 }
 
 impl RenderModel {
@@ -28,8 +43,17 @@ impl RenderModel {
         model: &Model,
         materials: &[Option<Image>],
         srgb: bool,
+        uv_layer: Option<usize>,
+        mode: ModelRenderMode,
     ) -> Result<Self, PreviewError> {
-        let materials: Vec<Arc<_>> = materials
+        let generated_normals = model.meshes.iter().any(|mesh| !mesh.vertices.has_normals());
+
+        let model = model
+            .generate_missing_normals()
+            .map_err(|_| PreviewError::OutOfMemory)?;
+
+        // The last material texture is the fallback for meshes without a material.
+        let material_textures: Vec<Arc<_>> = materials
             .iter()
             .map(|image| {
                 RenderMaterialTexture::from_image_default(instance, image)
@@ -41,24 +65,48 @@ impl RenderModel {
             .map(Arc::new)
             .collect();
 
-        Ok(Self {
-            meshes: model
+        let fallback = material_textures[material_textures.len() - 1].clone();
+
+        // Every material texture shares the same layout, so the pipelines are shared by every mesh.
+        let pipelines = Arc::new(RenderMeshPipelines::new(
+            instance,
+            bind_group_layouts,
+            fallback.bind_group_layout(),
+        ));
+
+        let mut meshes = Vec::with_capacity(model.meshes.len() + model.hairs.len());
+
+        for (part, culling, source) in render_parts(&model) {
+            meshes.push(RenderMesh::from_mesh(
+                instance,
+                pipelines.clone(),
+                &part,
+                fallback.clone(),
+                culling,
+                if source.is_some() {
+                    uv_layer.unwrap_or_default()
+                } else {
+                    0
+                },
+                source,
+            )?);
+        }
+
+        let mut render_model = Self {
+            meshes,
+            material_textures,
+            mesh_bounds: model
                 .meshes
                 .iter()
-                .map(|mesh| {
-                    RenderMesh::from_mesh(instance, bind_group_layouts, mesh, &materials, true)
-                })
-                .chain(model.hairs.iter().map(|hair| {
-                    RenderMesh::from_mesh(
-                        instance,
-                        bind_group_layouts,
-                        &hair.to_mesh(),
-                        &materials,
-                        false,
-                    )
-                }))
-                .collect::<Result<Vec<_>, _>>()?,
-            skeleton: if model.skeleton.bones.is_empty() {
+                .map(|mesh| mesh.bounding_box())
+                .collect(),
+            bone_positions: model
+                .skeleton
+                .bones
+                .iter()
+                .map(|bone| bone.world_position)
+                .collect(),
+            skeleton: if model.skeleton.bones.is_empty() && model.skeleton.sockets.is_empty() {
                 None
             } else {
                 Some(RenderSkeleton::from_skeleton(
@@ -68,7 +116,147 @@ impl RenderModel {
                 ))
             },
             srgb,
-        })
+            generated_normals,
+        };
+
+        render_model.set_render_mode(instance, &model, uv_layer, mode)?;
+
+        Ok(render_model)
+    }
+
+    /// Changes the material textures and uv layer drawn, reusing the existing buffers and pipelines.
+    ///
+    /// Vertex buffers are cached for each uv layer, so the model is only used when a new uv layer is drawn.
+    pub fn set_render_mode(
+        &mut self,
+        instance: &GPUInstance,
+        model: &Model,
+        uv_layer: Option<usize>,
+        mode: ModelRenderMode,
+    ) -> Result<(), PreviewError> {
+        let mesh_count = self.mesh_bounds.len();
+        let hair_count = self.meshes.iter().filter(|x| x.source.is_none()).count();
+
+        // Flat modes draw with solid colors, and inspecting a uv layer draws every mesh with the same checker image.
+        let overrides = match mode {
+            ModelRenderMode::MeshId => (0..mesh_count + hair_count)
+                .map(|index| solid_image(mesh_id_color(index)).map(Some))
+                .collect::<Result<Vec<_>, _>>()?,
+            ModelRenderMode::Silhouette => vec![Some(solid_image(0xFFFFFFFF)?)],
+            ModelRenderMode::Material => match uv_layer {
+                Some(_) => vec![Some(checker_image()?)],
+                None => Vec::new(),
+            },
+        };
+
+        let overrides: Vec<Arc<_>> = overrides
+            .iter()
+            .map(|image| RenderMaterialTexture::from_image_default(instance, image).map(Arc::new))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fallback = &self.material_textures[self.material_textures.len() - 1];
+
+        let mut hair_index = mesh_count;
+
+        for mesh in &mut self.meshes {
+            let id = mesh.source.unwrap_or_else(|| {
+                hair_index += 1;
+                hair_index - 1
+            });
+
+            let texture = match mode {
+                ModelRenderMode::MeshId => overrides.get(id),
+                _ if !overrides.is_empty() => overrides.first(),
+                _ => mesh
+                    .material
+                    .and_then(|material| self.material_textures.get(material)),
+            };
+
+            mesh.set_material_texture(texture.unwrap_or(fallback).clone());
+        }
+
+        // Flat modes ignore uvs, so the current vertex buffers are kept.
+        if mode.is_flat() {
+            return Ok(());
+        }
+
+        let uv_layer = uv_layer.unwrap_or_default();
+
+        let missing = self
+            .meshes
+            .iter()
+            .any(|mesh| mesh.source.is_some() && !mesh.has_uv_layer(uv_layer));
+
+        let model = if missing {
+            Some(
+                model
+                    .generate_missing_normals()
+                    .map_err(|_| PreviewError::OutOfMemory)?,
+            )
+        } else {
+            None
+        };
+
+        let parts = model.as_ref().map(|model| render_parts(model));
+
+        for (index, mesh) in self.meshes.iter_mut().enumerate() {
+            if mesh.source.is_none() {
+                continue;
+            }
+
+            let part = parts
+                .as_ref()
+                .and_then(|parts| parts.get(index))
+                .map(|(part, _, _)| part.as_ref());
+
+            mesh.set_uv_layer(instance, part, uv_layer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the vertices and bones to match the given posed model, reusing the existing textures and pipelines.
+    pub fn set_pose(&mut self, instance: &GPUInstance, model: &Model) -> Result<(), PreviewError> {
+        let model = model
+            .generate_missing_normals()
+            .map_err(|_| PreviewError::OutOfMemory)?;
+
+        for (mesh, (part, _, _)) in self.meshes.iter_mut().zip(render_parts(&model)) {
+            mesh.set_vertices(instance, &part)?;
+        }
+
+        self.mesh_bounds = model
+            .meshes
+            .iter()
+            .map(|mesh| mesh.bounding_box())
+            .collect();
+        self.bone_positions = model
+            .skeleton
+            .bones
+            .iter()
+            .map(|bone| bone.world_position)
+            .collect();
+
+        if let Some(skeleton) = &mut self.skeleton {
+            skeleton.set_skeleton(instance, &model.skeleton);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if any mesh in this model had its normals generated.
+    pub fn generated_normals(&self) -> bool {
+        self.generated_normals
+    }
+
+    /// Returns the bounding box of each source mesh in this model.
+    pub fn mesh_bounds(&self) -> &[Aabb] {
+        &self.mesh_bounds
+    }
+
+    /// Returns the world position of each bone in this model.
+    pub fn bone_positions(&self) -> &[Vector3] {
+        &self.bone_positions
     }
 
     /// Returns the mesh count for this model.
@@ -94,19 +282,81 @@ impl RenderModel {
             .unwrap_or_default()
     }
 
+    /// Returns the socket count for this model.
+    pub fn socket_count(&self) -> usize {
+        self.skeleton
+            .as_ref()
+            .map(|x| x.socket_count)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of bones driven by physics.
+    pub fn physics_bone_count(&self) -> usize {
+        self.skeleton
+            .as_ref()
+            .map(|x| x.physics_count)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of bones with a non-uniform world scale.
+    pub fn non_uniform_bone_count(&self) -> usize {
+        self.skeleton
+            .as_ref()
+            .map(|x| x.non_uniform_count)
+            .unwrap_or_default()
+    }
+
     /// Returns whether or not the models materials are in sRGB colorspace.
     pub fn srgb(&self) -> bool {
         self.srgb
     }
 
-    /// Draws the model using the given render pass.
-    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, show_bones: bool, wireframe: bool) {
+    /// Draws the model using the given render pass, skipping any hidden source meshes.
+    pub fn draw<'a>(
+        &'a self,
+        render_pass: &mut RenderPass<'a>,
+        hidden_meshes: &[bool],
+        show_bones: bool,
+        show_bone_axes: bool,
+        wireframe: WireframeMode,
+        culling: bool,
+    ) {
         for mesh in &self.meshes {
-            mesh.draw(render_pass, wireframe);
+            let hidden = mesh
+                .source
+                .is_some_and(|source| hidden_meshes.get(source).copied().unwrap_or_default());
+
+            if !hidden {
+                mesh.draw(render_pass, wireframe, culling);
+            }
         }
 
         if show_bones && let Some(skeleton) = &self.skeleton {
-            skeleton.draw(render_pass);
+            skeleton.draw(render_pass, show_bone_axes);
+        }
+    }
+}
+
+/// Utility to split the model into the meshes that are drawn, with whether or not they're culled, and their source mesh.
+fn render_parts(model: &Model) -> Vec<(Cow<'_, Mesh>, bool, Option<usize>)> {
+    let mut parts = Vec::with_capacity(model.meshes.len() + model.hairs.len());
+
+    for (index, mesh) in model.meshes.iter().enumerate() {
+        // Each render mesh draws with a single material texture.
+        if mesh.material_ranges.is_empty() {
+            parts.push((Cow::Borrowed(mesh), true, Some(index)));
+        } else {
+            parts.extend(
+                mesh.split_material_ranges()
+                    .into_iter()
+                    .map(|part| (Cow::Owned(part), true, Some(index))),
+            );
         }
     }
+
+    for hair in &model.hairs {
+        parts.push((Cow::Owned(hair.to_mesh()), false, None));
+    }
+
+    parts
 }
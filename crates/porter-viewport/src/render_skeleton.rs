@@ -1,16 +1,194 @@
+use std::mem::offset_of;
+
 use wgpu::util::*;
 use wgpu::*;
 
 use porter_gpu::GPUInstance;
+use porter_math::Quaternion;
 use porter_math::Vector3;
 use porter_model::Skeleton;
 use porter_utils::AsThisSlice;
 
+/// The length of each bone axis, relative to the size of the skeleton.
+const AXIS_LENGTH: f32 = 0.025;
+
+/// The per bone data used to draw the bone and its local axes as instances.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct BoneInstance {
+    position: Vector3,
+    parent: Vector3,
+    rotation: Quaternion,
+    axis_lengths: Vector3,
+    /// A bit for each axis that is scaled differently than the others.
+    non_uniform: u32,
+    /// Whether or not the bone is driven by physics.
+    physics: u32,
+}
+
 /// A 3d render skeleton.
 pub struct RenderSkeleton {
-    vertex_buffer: Buffer,
+    instance_buffer: Buffer,
     render_pipeline: RenderPipeline,
+    axis_render_pipeline: RenderPipeline,
     pub(crate) bone_count: usize,
+    pub(crate) socket_count: usize,
+    pub(crate) physics_count: usize,
+    pub(crate) non_uniform_count: usize,
+}
+
+/// Utility to create a line list pipeline for the skeleton.
+fn create_line_pipeline(
+    instance: &GPUInstance,
+    layout: &PipelineLayout,
+    entry_points: (&str, &str),
+    attributes: &[VertexAttribute],
+) -> RenderPipeline {
+    instance
+        .device()
+        .create_render_pipeline(&RenderPipelineDescriptor {
+            label: None,
+            layout: Some(layout),
+            vertex: VertexState {
+                module: instance.gpu_preview_shader(),
+                entry_point: Some(entry_points.0),
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<BoneInstance>() as BufferAddress,
+                    step_mode: VertexStepMode::Instance,
+                    attributes,
+                }],
+                compilation_options: Default::default(),
+            },
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::LineList,
+                strip_index_format: None,
+                front_face: FrontFace::Ccw,
+                cull_mode: Some(Face::Back),
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::Line,
+                conservative: false,
+            },
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Always,
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: 4,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            fragment: Some(FragmentState {
+                module: instance.gpu_preview_shader(),
+                entry_point: Some(entry_points.1),
+                targets: &[Some(ColorTargetState {
+                    format: TextureFormat::Rgba8Unorm,
+                    blend: Some(BlendState::REPLACE),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            multiview: None,
+            cache: None,
+        })
+}
+
+/// Utility to build the instance for each bone, then each socket, with the length of the local axis lines.
+fn bone_instances(skeleton: &Skeleton) -> Vec<BoneInstance> {
+    let mut min = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    let mut max = Vector3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    for bone in &skeleton.bones {
+        min = Vector3::new(
+            min.x.min(bone.world_position.x),
+            min.y.min(bone.world_position.y),
+            min.z.min(bone.world_position.z),
+        );
+        max = Vector3::new(
+            max.x.max(bone.world_position.x),
+            max.y.max(bone.world_position.y),
+            max.z.max(bone.world_position.z),
+        );
+    }
+
+    let extent = (max - min).length();
+    let length = if extent.is_finite() && extent > 0.0 {
+        extent * AXIS_LENGTH
+    } else {
+        1.0
+    };
+
+    let mut instances = Vec::with_capacity(skeleton.bones.len() + skeleton.sockets.len());
+
+    for bone in &skeleton.bones {
+        let scale = [
+            bone.world_scale.x.abs(),
+            bone.world_scale.y.abs(),
+            bone.world_scale.z.abs(),
+        ];
+
+        let max_scale = scale[0].max(scale[1]).max(scale[2]);
+        let non_uniform = bone.has_non_uniform_scale();
+
+        // Axes are shortened relative to the largest scale, so odd scales stand out.
+        let relative = scale.map(|scale| {
+            if max_scale > 0.0 {
+                scale / max_scale
+            } else {
+                1.0
+            }
+        });
+
+        let non_uniform = relative
+            .iter()
+            .enumerate()
+            .filter(|(_, relative)| non_uniform && **relative < 1.0)
+            .fold(0, |mask, (axis, _)| mask | (1 << axis));
+
+        let parent = if bone.parent > -1 {
+            skeleton.bones[bone.parent as usize].world_position
+        } else {
+            Vector3::zero()
+        };
+
+        instances.push(BoneInstance {
+            position: bone.world_position,
+            parent,
+            rotation: bone.world_rotation,
+            axis_lengths: Vector3::new(
+                length * relative[0],
+                length * relative[1],
+                length * relative[2],
+            ),
+            non_uniform,
+            physics: bone.physics as u32,
+        });
+    }
+
+    for socket in &skeleton.sockets {
+        let (position, rotation, _) = skeleton.socket_world_matrix(socket).decompose();
+
+        // Sockets are linked to their parent bone, or drawn as just their axes without one.
+        let parent = skeleton
+            .bones
+            .get(socket.parent.max(0) as usize)
+            .filter(|_| socket.parent > -1)
+            .map(|bone| bone.world_position)
+            .unwrap_or(position);
+
+        instances.push(BoneInstance {
+            position,
+            parent,
+            rotation,
+            axis_lengths: Vector3::new(length, length, length),
+            non_uniform: 0,
+            physics: 0,
+        });
+    }
+
+    instances
 }
 
 impl RenderSkeleton {
@@ -20,21 +198,9 @@ impl RenderSkeleton {
         bind_group_layouts: &[&BindGroupLayout],
         skeleton: &Skeleton,
     ) -> Self {
-        let mut vertex_buffer = Vec::new();
-
-        for bone in skeleton.bones.iter().rev() {
-            vertex_buffer.push(bone.world_position);
-
-            if bone.parent > -1 {
-                vertex_buffer.push(skeleton.bones[bone.parent as usize].world_position);
-            } else {
-                vertex_buffer.push(Vector3::zero());
-            }
-        }
-
-        let vertex_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+        let instance_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
             label: None,
-            contents: vertex_buffer.as_slice().as_this_slice(),
+            contents: bone_instances(skeleton).as_slice().as_this_slice(),
             usage: BufferUsages::VERTEX,
         });
 
@@ -47,71 +213,98 @@ impl RenderSkeleton {
                     push_constant_ranges: &[],
                 });
 
-        let render_pipeline = instance
-            .device()
-            .create_render_pipeline(&RenderPipelineDescriptor {
-                label: None,
-                layout: Some(&render_pipeline_layout),
-                vertex: VertexState {
-                    module: instance.gpu_preview_shader(),
-                    entry_point: Some("vs_bone_main"),
-                    buffers: &[VertexBufferLayout {
-                        array_stride: size_of::<Vector3>() as BufferAddress,
-                        step_mode: VertexStepMode::Vertex,
-                        attributes: &[VertexAttribute {
-                            offset: 0,
-                            shader_location: 0,
-                            format: VertexFormat::Float32x3,
-                        }],
-                    }],
-                    compilation_options: Default::default(),
+        let render_pipeline = create_line_pipeline(
+            instance,
+            &render_pipeline_layout,
+            ("vs_bone_main", "fs_bone_main"),
+            &[
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, position) as BufferAddress,
+                    shader_location: 0,
+                    format: VertexFormat::Float32x3,
                 },
-                primitive: PrimitiveState {
-                    topology: PrimitiveTopology::LineList,
-                    strip_index_format: None,
-                    front_face: FrontFace::Ccw,
-                    cull_mode: Some(Face::Back),
-                    unclipped_depth: false,
-                    polygon_mode: PolygonMode::Line,
-                    conservative: false,
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, parent) as BufferAddress,
+                    shader_location: 1,
+                    format: VertexFormat::Float32x3,
                 },
-                depth_stencil: Some(DepthStencilState {
-                    format: TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: CompareFunction::Always,
-                    stencil: StencilState::default(),
-                    bias: DepthBiasState::default(),
-                }),
-                multisample: MultisampleState {
-                    count: 4,
-                    mask: !0,
-                    alpha_to_coverage_enabled: false,
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, physics) as BufferAddress,
+                    shader_location: 5,
+                    format: VertexFormat::Uint32,
                 },
-                fragment: Some(FragmentState {
-                    module: instance.gpu_preview_shader(),
-                    entry_point: Some("fs_bone_main"),
-                    targets: &[Some(ColorTargetState {
-                        format: TextureFormat::Rgba8Unorm,
-                        blend: Some(BlendState::REPLACE),
-                        write_mask: ColorWrites::ALL,
-                    })],
-                    compilation_options: Default::default(),
-                }),
-                multiview: None,
-                cache: None,
-            });
+            ],
+        );
+
+        let axis_render_pipeline = create_line_pipeline(
+            instance,
+            &render_pipeline_layout,
+            ("vs_bone_axis_main", "fs_grid_main"),
+            &[
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, position) as BufferAddress,
+                    shader_location: 0,
+                    format: VertexFormat::Float32x3,
+                },
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, rotation) as BufferAddress,
+                    shader_location: 2,
+                    format: VertexFormat::Float32x4,
+                },
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, axis_lengths) as BufferAddress,
+                    shader_location: 3,
+                    format: VertexFormat::Float32x3,
+                },
+                VertexAttribute {
+                    offset: offset_of!(BoneInstance, non_uniform) as BufferAddress,
+                    shader_location: 4,
+                    format: VertexFormat::Uint32,
+                },
+            ],
+        );
 
         Self {
-            vertex_buffer,
+            instance_buffer,
             render_pipeline,
+            axis_render_pipeline,
             bone_count: skeleton.bones.len(),
+            socket_count: skeleton.sockets.len(),
+            physics_count: skeleton.bones.iter().filter(|bone| bone.physics).count(),
+            non_uniform_count: skeleton
+                .bones
+                .iter()
+                .filter(|bone| bone.has_non_uniform_scale())
+                .count(),
         }
     }
 
-    /// Draws the skeleton using the given render pass.
-    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+    /// Updates the bones drawn to match the given skeleton, which must have the same bones and sockets.
+    pub fn set_skeleton(&mut self, instance: &GPUInstance, skeleton: &Skeleton) {
+        self.instance_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bone_instances(skeleton).as_slice().as_this_slice(),
+            usage: BufferUsages::VERTEX,
+        });
+    }
+
+    /// Draws the skeleton using the given render pass, optionally with the local axes of each bone.
+    ///
+    /// Sockets always show their axes, since their orientation is what places an attachment.
+    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, show_axes: bool) {
+        let instance_count = (self.bone_count + self.socket_count) as u32;
+
+        render_pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+
         render_pass.set_pipeline(&self.render_pipeline);
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        render_pass.draw(0..self.bone_count as u32 * 2, 0..1);
+        render_pass.draw(0..2, 0..instance_count);
+
+        render_pass.set_pipeline(&self.axis_render_pipeline);
+
+        if show_axes {
+            render_pass.draw(0..3 * 2, 0..instance_count);
+        } else if self.socket_count > 0 {
+            render_pass.draw(0..3 * 2, self.bone_count as u32..instance_count);
+        }
     }
 }
@@ -4,6 +4,7 @@ use wgpu::*;
 use porter_gpu::GPUInstance;
 
 use porter_math::Matrix4x4;
+use porter_math::Vector2;
 use porter_math::Vector3;
 
 use porter_utils::AsByteSlice;
@@ -19,6 +20,14 @@ struct ViewportCameraUniform {
     inverse_model_matrix: Matrix4x4,
     default_shaded: u32,
     srgb: u32,
+    flat: u32,
+}
+
+/// Utility to transform a homogeneous position by the given matrix, the same way as the shader.
+fn transform4(matrix: &Matrix4x4, position: [f32; 4]) -> [f32; 4] {
+    let row = |i: usize| (0..4).map(|j| matrix[j * 4 + i] * position[j]).sum::<f32>();
+
+    [row(0), row(1), row(2), row(3)]
 }
 
 /// A 3d viewport camera.
@@ -48,6 +57,7 @@ impl ViewportCamera {
             inverse_model_matrix: Matrix4x4::new(),
             default_shaded: 0,
             srgb: 0,
+            flat: 0,
         };
 
         let uniform_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
@@ -101,6 +111,11 @@ impl ViewportCamera {
         self.fov_deg
     }
 
+    /// Sets the FOV in degrees.
+    pub fn set_fov_deg(&mut self, fov_deg: f32) {
+        self.fov_deg = fov_deg;
+    }
+
     /// Returns the uniform bind group.
     pub fn uniform_bind_group(&self) -> &BindGroup {
         &self.uniform_bind_group
@@ -128,6 +143,11 @@ impl ViewportCamera {
         }
     }
 
+    /// Returns the model matrix value.
+    pub fn model_matrix(&self) -> Matrix4x4 {
+        self.uniforms.model_matrix
+    }
+
     /// Set the model matrix value.
     pub fn set_model_matrix(&mut self, matrix: Matrix4x4) {
         self.uniforms.model_matrix = matrix;
@@ -142,6 +162,11 @@ impl ViewportCamera {
         };
     }
 
+    /// Sets whether or not meshes are drawn with flat, unlit colors.
+    pub fn set_flat(&mut self, flat: bool) {
+        self.uniforms.flat = if flat { 1 } else { 0 };
+    }
+
     /// Updates the current uniforms on the gpu.
     pub fn update(
         &mut self,
@@ -149,6 +174,7 @@ impl ViewportCamera {
         width: f32,
         height: f32,
         srgb: bool,
+        near_clip: f32,
         far_clip: f32,
     ) {
         if let Some((o_width, o_height, o_scale)) = self.orthographic {
@@ -166,7 +192,7 @@ impl ViewportCamera {
             self.uniforms.inverse_model_matrix = self.uniforms.model_matrix.inverse();
         } else {
             self.uniforms.projection_matrix =
-                Matrix4x4::perspective_fov(self.fov_deg, width / height, 0.1, far_clip);
+                Matrix4x4::perspective_fov(self.fov_deg, width / height, near_clip, far_clip);
             self.uniforms.view_matrix = Matrix4x4::look_at(
                 self.camera_position(),
                 self.uniforms.target,
@@ -274,6 +300,32 @@ impl ViewportCamera {
         self.uniforms.target += ((right * x) + (up * y)) * scale;
     }
 
+    /// Projects a model space position to a point in the viewport and its distance from the camera.
+    /// Returns none when the position is behind the camera.
+    pub fn project(&self, position: Vector3, width: f32, height: f32) -> Option<(Vector2, f32)> {
+        let position = [position.x, position.y, position.z, 1.0];
+
+        let clip = transform4(
+            &self.uniforms.projection_matrix,
+            transform4(
+                &self.uniforms.view_matrix,
+                transform4(&self.uniforms.model_matrix, position),
+            ),
+        );
+
+        if clip[3] <= 0.0 {
+            return None;
+        }
+
+        let x = clip[0] / clip[3];
+        let y = clip[1] / clip[3];
+
+        Some((
+            Vector2::new((x * 0.5 + 0.5) * width, (0.5 - y * 0.5) * height),
+            clip[3],
+        ))
+    }
+
     /// Returns the camera position.
     fn camera_position(&self) -> Vector3 {
         self.uniforms.target + self.to_cartesian()
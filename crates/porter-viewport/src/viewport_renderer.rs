@@ -1,11 +1,16 @@
+use std::mem::offset_of;
+
 use wgpu::util::*;
 use wgpu::*;
 
+use porter_animation::Animation;
+
+use porter_model::Aabb;
 use porter_model::MaterialTextureRefUsage;
 use porter_model::Model;
 
 use porter_gpu::GPUInstance;
-use porter_gpu::gpu_instance;
+use porter_gpu::try_gpu_instance;
 
 use porter_math::Angles;
 use porter_math::Axis;
@@ -18,25 +23,37 @@ use porter_utils::AsAligned;
 use porter_utils::AsThisSlice;
 
 use porter_texture::Image;
+use porter_texture::ImageFormat;
 use porter_texture::TextureExtensions;
+use porter_texture::TransformAlgorithm;
 
+use crate::ModelRenderMode;
+use crate::PreviewAsset;
+use crate::PreviewBackground;
 use crate::PreviewControlScheme;
 use crate::PreviewError;
+use crate::RenderAnimation;
 use crate::RenderImage;
 use crate::RenderMaterial;
 use crate::RenderModel;
 use crate::RenderType;
 use crate::ViewportCamera;
 use crate::ViewportKeyState;
+use crate::ViewportSelection;
+use crate::WireframeMode;
 
 /// Renders 'preview' versions of models, animations, images, and materials.
 pub struct ViewportRenderer {
     instance: &'static GPUInstance,
-    wireframe: bool,
+    wireframe: WireframeMode,
+    backface_culling: bool,
     show_bones: bool,
+    show_bone_axes: bool,
     show_grid: bool,
     width: f32,
     height: f32,
+    near_clip: Option<f32>,
+    auto_near_clip: f32,
     far_clip: f32,
     output_texture: Texture,
     output_texture_view: TextureView,
@@ -45,11 +62,28 @@ pub struct ViewportRenderer {
     depth_texture_view: TextureView,
     msaa_texture: Texture,
     msaa_texture_view: TextureView,
-    grid_size: u32,
+    grid_instances: u32,
     grid_render_buffer: Buffer,
     grid_render_pipeline: RenderPipeline,
+    background: PreviewBackground,
+    background_render_buffer: Buffer,
+    background_render_pipeline: RenderPipeline,
     render: Option<RenderType>,
     render_name: Option<String>,
+    preview_image: Option<Image>,
+    image_frames: Option<Image>,
+    image_frame: usize,
+    image_budget: u64,
+    image_mip: Option<(u32, u32, u32)>,
+    inspect_position: Option<Vector2>,
+    preview_model: Option<(Model, Vec<Option<Image>>, bool)>,
+    preview_animation: Option<RenderAnimation>,
+    uv_checker: Option<usize>,
+    model_render_mode: ModelRenderMode,
+    selection: Option<ViewportSelection>,
+    hidden_meshes: Vec<bool>,
+    premultiplied_hint: bool,
+    unpremultiply: bool,
     camera: ViewportCamera,
     scale: u32,
 }
@@ -57,11 +91,32 @@ pub struct ViewportRenderer {
 /// The minimum preview size.
 const MIN_SIZE: u32 = 256;
 
+/// The near clip distance used when there is no model to fit it to.
+const DEFAULT_NEAR_CLIP: f32 = 0.1;
+
+/// The memory budget for previewing an image, larger images preview a smaller mip.
+const DEFAULT_IMAGE_BUDGET: u64 = 256 * 1024 * 1024;
+
+/// The number of blocks along each side of the sample used to detect premultiplied alpha.
+const PREMULTIPLIED_SAMPLE_BLOCKS: u32 = 64;
+
+/// The distance in pixels from a bone that still picks it.
+const BONE_PICK_RADIUS: f32 = 8.0;
+
 /// The size of the grid.
 const GRID_SIZE: f32 = 120.0;
 /// The size of each subdivision.
 const GRID_STEP: f32 = 2.0;
 
+/// A pair of grid lines, one along each axis, at the given offset.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct GridInstance {
+    offset: f32,
+    extent: f32,
+    color: Vector3,
+}
+
 /// Utility to create the output texture.
 fn create_output_texture(instance: &GPUInstance, width: u32, height: u32) -> Texture {
     instance.device().create_texture(&TextureDescriptor {
@@ -129,6 +184,120 @@ fn create_output_buffer(instance: &GPUInstance, width: u32, height: u32) -> Buff
 }
 
 /// Utility to create the grid render resources.
+/// The colors of the background, drawn as a single triangle covering the viewport.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct BackgroundInstance {
+    top: [f32; 4],
+    bottom: [f32; 4],
+    checkerboard: u32,
+}
+
+impl BackgroundInstance {
+    /// Constructs the background instance for the given background.
+    fn new(background: PreviewBackground) -> Self {
+        let (top, bottom) = background.colors();
+
+        Self {
+            top,
+            bottom,
+            checkerboard: background.is_checkerboard() as u32,
+        }
+    }
+}
+
+fn create_background_render(
+    instance: &GPUInstance,
+    bind_group_layouts: &[&BindGroupLayout],
+    background: PreviewBackground,
+) -> (Buffer, RenderPipeline) {
+    let buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+        label: None,
+        contents: [BackgroundInstance::new(background)]
+            .as_slice()
+            .as_this_slice(),
+        usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+    });
+
+    let render_pipeline_layout =
+        instance
+            .device()
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: None,
+                bind_group_layouts,
+                push_constant_ranges: &[],
+            });
+
+    let render_pipeline = instance
+        .device()
+        .create_render_pipeline(&RenderPipelineDescriptor {
+            label: None,
+            layout: Some(&render_pipeline_layout),
+            vertex: VertexState {
+                module: instance.gpu_preview_shader(),
+                entry_point: Some("vs_background_main"),
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<BackgroundInstance>() as BufferAddress,
+                    step_mode: VertexStepMode::Instance,
+                    attributes: &[
+                        VertexAttribute {
+                            offset: offset_of!(BackgroundInstance, top) as BufferAddress,
+                            shader_location: 0,
+                            format: VertexFormat::Float32x4,
+                        },
+                        VertexAttribute {
+                            offset: offset_of!(BackgroundInstance, bottom) as BufferAddress,
+                            shader_location: 1,
+                            format: VertexFormat::Float32x4,
+                        },
+                        VertexAttribute {
+                            offset: offset_of!(BackgroundInstance, checkerboard) as BufferAddress,
+                            shader_location: 2,
+                            format: VertexFormat::Uint32,
+                        },
+                    ],
+                }],
+                compilation_options: Default::default(),
+            },
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::Fill,
+                conservative: false,
+            },
+            // The background is drawn first, and never hides anything drawn after it.
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::Always,
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: 4,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            fragment: Some(FragmentState {
+                module: instance.gpu_preview_shader(),
+                entry_point: Some("fs_background_main"),
+                targets: &[Some(ColorTargetState {
+                    format: TextureFormat::Rgba8Unorm,
+                    blend: Some(BlendState::REPLACE),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            multiview: None,
+            cache: None,
+        });
+
+    (buffer, render_pipeline)
+}
+
 fn create_grid_render(
     instance: &GPUInstance,
     bind_group_layouts: &[&BindGroupLayout],
@@ -147,19 +316,16 @@ fn create_grid_render(
             Vector3::new(0.70, 0.70, 0.70)
         };
 
-        buffer.push(Vector3::new(i, 0.0, size));
-        buffer.push(color);
-        buffer.push(Vector3::new(i, 0.0, min_size));
-        buffer.push(color);
-        buffer.push(Vector3::new(size, 0.0, i));
-        buffer.push(color);
-        buffer.push(Vector3::new(min_size, 0.0, i));
-        buffer.push(color);
+        buffer.push(GridInstance {
+            offset: i,
+            extent: size,
+            color,
+        });
 
         i += step;
     }
 
-    let size = (buffer.len() / 2) as u32;
+    let instances = buffer.len() as u32;
 
     let buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
         label: None,
@@ -185,17 +351,22 @@ fn create_grid_render(
                 module: instance.gpu_preview_shader(),
                 entry_point: Some("vs_grid_main"),
                 buffers: &[VertexBufferLayout {
-                    array_stride: (size_of::<Vector3>() * 2) as BufferAddress,
-                    step_mode: VertexStepMode::Vertex,
+                    array_stride: size_of::<GridInstance>() as BufferAddress,
+                    step_mode: VertexStepMode::Instance,
                     attributes: &[
                         VertexAttribute {
-                            offset: 0,
+                            offset: offset_of!(GridInstance, offset) as BufferAddress,
                             shader_location: 0,
-                            format: VertexFormat::Float32x3,
+                            format: VertexFormat::Float32,
                         },
                         VertexAttribute {
-                            offset: size_of::<Vector3>() as BufferAddress,
+                            offset: offset_of!(GridInstance, extent) as BufferAddress,
                             shader_location: 1,
+                            format: VertexFormat::Float32,
+                        },
+                        VertexAttribute {
+                            offset: offset_of!(GridInstance, color) as BufferAddress,
+                            shader_location: 2,
                             format: VertexFormat::Float32x3,
                         },
                     ],
@@ -237,13 +408,84 @@ fn create_grid_render(
             cache: None,
         });
 
-    (size, buffer, render_pipeline)
+    (instances, buffer, render_pipeline)
+}
+
+/// Utility to pick a near clip distance that fits the size of the model, to avoid z-fighting on tiny assets.
+fn auto_near_clip(model: &Model) -> f32 {
+    let aabb = model.bounding_box();
+    let radius = ((aabb.max - aabb.min) / 2.0).length();
+
+    if radius.is_finite() && radius > 0.0 {
+        (radius * 0.001).clamp(0.001, 10.0)
+    } else {
+        DEFAULT_NEAR_CLIP
+    }
+}
+
+/// Utility to combine bounding boxes, returns none when there are none, or they are empty.
+fn union_bounds(bounds: &[Aabb]) -> Option<Aabb> {
+    let mut result = *bounds.first()?;
+
+    for bounds in &bounds[1..] {
+        result.min = Vector3::new(
+            result.min.x.min(bounds.min.x),
+            result.min.y.min(bounds.min.y),
+            result.min.z.min(bounds.min.z),
+        );
+        result.max = Vector3::new(
+            result.max.x.max(bounds.max.x),
+            result.max.y.max(bounds.max.y),
+            result.max.z.max(bounds.max.z),
+        );
+    }
+
+    (result.min.x <= result.max.x).then_some(result)
+}
+
+/// Utility to get the largest number of uv layers in any mesh of the model.
+/// Utility to copy a single frame of an image array, so it can be previewed on its own.
+fn image_frame(image: &Image, index: usize) -> Result<Image, PreviewError> {
+    let frame = image
+        .frames()
+        .get(index)
+        .ok_or(PreviewError::InvalidAsset)?;
+
+    let mut result = Image::with_mipmaps(
+        image.width(),
+        image.height(),
+        image.mipmaps(),
+        image.format(),
+    )
+    .map_err(|_| PreviewError::InvalidAsset)?;
+
+    result
+        .create_frame()
+        .map_err(|_| PreviewError::OutOfMemory)?
+        .buffer_mut()
+        .copy_from_slice(frame.buffer());
+
+    Ok(result)
+}
+
+fn max_uv_layers(model: &Model) -> usize {
+    model
+        .meshes
+        .iter()
+        .map(|mesh| mesh.vertices.uv_layers())
+        .max()
+        .unwrap_or_default()
 }
 
 impl ViewportRenderer {
     /// Constructs a new instance of the viewport renderer.
     pub fn new() -> Self {
-        let instance = gpu_instance();
+        Self::try_new().expect("No suitable GPU adapter was found!")
+    }
+
+    /// Constructs a new instance of the viewport renderer, fails if no GPU adapter is available.
+    pub fn try_new() -> Result<Self, PreviewError> {
+        let instance = try_gpu_instance().ok_or(PreviewError::GPUUnavailable)?;
         let output_texture = create_output_texture(instance, MIN_SIZE, MIN_SIZE);
         let output_buffer = create_output_buffer(instance, MIN_SIZE, MIN_SIZE);
         let depth_texture = create_depth_texture(instance, MIN_SIZE, MIN_SIZE);
@@ -257,16 +499,25 @@ impl ViewportRenderer {
             65.0,
         );
 
-        let (grid_size, grid_render_buffer, grid_render_pipeline) =
+        let (grid_instances, grid_render_buffer, grid_render_pipeline) =
             create_grid_render(instance, &[camera.uniform_bind_group_layout()]);
 
-        Self {
+        let background = PreviewBackground::default();
+
+        let (background_render_buffer, background_render_pipeline) =
+            create_background_render(instance, &[camera.uniform_bind_group_layout()], background);
+
+        Ok(Self {
             instance,
-            wireframe: false,
+            wireframe: WireframeMode::Off,
+            backface_culling: true,
             show_bones: true,
+            show_bone_axes: false,
             show_grid: true,
             width: MIN_SIZE as f32,
             height: MIN_SIZE as f32,
+            near_clip: None,
+            auto_near_clip: DEFAULT_NEAR_CLIP,
             far_clip: 10000.0,
             output_texture_view: output_texture.create_view(&Default::default()),
             output_texture,
@@ -275,23 +526,54 @@ impl ViewportRenderer {
             depth_texture,
             msaa_texture_view: msaa_texture.create_view(&Default::default()),
             msaa_texture,
-            grid_size,
+            grid_instances,
             grid_render_buffer,
             grid_render_pipeline,
+            background,
+            background_render_buffer,
+            background_render_pipeline,
             render: None,
             render_name: None,
+            preview_image: None,
+            image_frames: None,
+            image_frame: 0,
+            image_budget: DEFAULT_IMAGE_BUDGET,
+            image_mip: None,
+            inspect_position: None,
+            preview_model: None,
+            preview_animation: None,
+            uv_checker: None,
+            model_render_mode: ModelRenderMode::Material,
+            selection: None,
+            hidden_meshes: Vec::new(),
+            premultiplied_hint: false,
+            unpremultiply: false,
             camera,
             scale: 100,
-        }
+        })
     }
 
     /// Sets the image asset to preview.
     pub fn set_preview_image(&mut self, name: String, image: Image) -> Result<(), PreviewError> {
-        let render_image = RenderImage::from_image(
-            self.instance,
-            &[self.camera.uniform_bind_group_layout()],
-            &image,
-        )?;
+        // Very large images are previewed at the first mip that fits the budget, exports are unaffected.
+        let level = image.mip_for_budget(self.image_budget);
+
+        let (image, image_mip) = if level > 0 {
+            let mip = image.mip(level).map_err(|_| PreviewError::InvalidAsset)?;
+
+            (mip, Some((level, image.width(), image.height())))
+        } else {
+            (image, None)
+        };
+
+        // Image arrays are previewed one frame at a time.
+        let (image, image_frames) = if image.frames().len() > 1 {
+            (image_frame(&image, 0)?, Some(image))
+        } else {
+            (image, None)
+        };
+
+        let render_image = self.create_render_image(&image)?;
 
         let scale = (self.width / image.width() as f32).min(self.height / image.height() as f32);
 
@@ -303,8 +585,11 @@ impl ViewportRenderer {
             self.scale as f32 / 100.0,
         )));
 
-        self.render = Some(RenderType::Image(render_image));
+        self.show_image(image, render_image);
         self.render_name = Some(name);
+        self.image_frames = image_frames;
+        self.image_frame = 0;
+        self.image_mip = image_mip;
 
         self.update_camera();
 
@@ -315,7 +600,7 @@ impl ViewportRenderer {
     pub fn set_preview_material(
         &mut self,
         name: String,
-        material: Vec<(MaterialTextureRefUsage, Image)>,
+        material: Vec<(String, MaterialTextureRefUsage, Image)>,
     ) -> Result<(), PreviewError> {
         let render_material = RenderMaterial::from_images(
             self.instance,
@@ -342,25 +627,9 @@ impl ViewportRenderer {
         Ok(())
     }
 
+    /// Focuses the camera on the whole model.
     pub fn focus_on_model(&mut self, model: &Model) {
-        let aabb = model.bounding_box();
-
-        let mut center = ((aabb.min + aabb.max) / 2.0).swizzle::<0, 2, 1>();
-        center.z *= -1.0;
-
-        let extents = (aabb.max - aabb.min) / 2.0;
-        let radius = extents.length();
-
-        let aspect = self.width / self.height;
-
-        let fov_rad = self.camera.fov_deg().to_radians();
-
-        let vertical = radius / (fov_rad / 2.0).tan();
-        let horizontal = radius / ((fov_rad * aspect) / 2.0).tan();
-
-        let distance = vertical.max(horizontal);
-
-        self.camera.focus_on(center, distance);
+        self.focus_on_bounds(model.bounding_box());
     }
 
     /// Sets the model asset to preview.
@@ -372,12 +641,21 @@ impl ViewportRenderer {
         srgb: bool,
         focus: bool,
     ) -> Result<(), PreviewError> {
+        if self
+            .uv_checker
+            .is_some_and(|layer| layer >= max_uv_layers(&model))
+        {
+            self.uv_checker = None;
+        }
+
         let render_model = RenderModel::from_model(
             self.instance,
             &[self.camera.uniform_bind_group_layout()],
             &model,
             &materials,
             srgb,
+            self.uv_checker,
+            self.model_render_mode,
         )?;
 
         let model_matrix = match model.up_axis {
@@ -393,12 +671,18 @@ impl ViewportRenderer {
         self.camera.set_orthographic(None);
         self.camera.set_model_matrix(model_matrix);
 
+        self.auto_near_clip = auto_near_clip(&model);
+        self.selection = None;
+        self.hidden_meshes = vec![false; model.meshes.len()];
+
         if focus {
             self.focus_on_model(&model);
         }
 
         self.render = Some(RenderType::Model(render_model));
         self.render_name = Some(name);
+        self.preview_model = Some((model, materials, srgb));
+        self.preview_animation = None;
 
         self.update_camera();
 
@@ -409,6 +693,15 @@ impl ViewportRenderer {
     pub fn clear_preview(&mut self) {
         self.render = None;
         self.render_name = None;
+        self.preview_image = None;
+        self.image_frames = None;
+        self.image_frame = 0;
+        self.image_mip = None;
+        self.preview_model = None;
+        self.preview_animation = None;
+        self.selection = None;
+        self.hidden_meshes.clear();
+        self.premultiplied_hint = false;
 
         self.camera.set_orthographic(None);
         self.update_camera();
@@ -419,6 +712,23 @@ impl ViewportRenderer {
         self.render.is_none()
     }
 
+    /// Sets the background drawn behind the asset.
+    pub fn set_background(&mut self, background: PreviewBackground) {
+        if self.background == background {
+            return;
+        }
+
+        self.background = background;
+
+        self.instance.queue().write_buffer(
+            &self.background_render_buffer,
+            0,
+            [BackgroundInstance::new(background)]
+                .as_slice()
+                .as_this_slice(),
+        );
+    }
+
     /// Resizes the renderer output.
     pub fn resize(&mut self, width: f32, height: f32, far_clip: f32) {
         let width = width.max(1.0);
@@ -454,24 +764,398 @@ impl ViewportRenderer {
         if let Some(RenderType::Material(material)) = &mut self.render {
             material.next();
 
-            let scale =
-                (self.width / material.width() as f32).min(self.height / material.height() as f32);
+            self.fit_material();
+        }
+    }
+
+    /// Cycles to the previous material in the list.
+    pub fn cycle_material_reverse(&mut self) {
+        if let Some(RenderType::Material(material)) = &mut self.render {
+            material.previous();
+
+            self.fit_material();
+        }
+    }
+
+    /// Selects the material image at the given index.
+    pub fn select_material(&mut self, index: usize) {
+        if let Some(RenderType::Material(material)) = &mut self.render {
+            material.select(index);
+
+            self.fit_material();
+        }
+    }
+
+    /// Returns the index of the current material image and the labels of every material image.
+    pub fn material_images(&self) -> Option<(usize, Vec<String>)> {
+        match &self.render {
+            Some(RenderType::Material(material)) => Some((material.index(), material.labels())),
+            _ => None,
+        }
+    }
+
+    /// Cycles to the next frame of an image array.
+    pub fn cycle_image_frame(&mut self) {
+        if let Some(image_frames) = &self.image_frames {
+            self.select_image_frame((self.image_frame + 1) % image_frames.frames().len());
+        }
+    }
+
+    /// Cycles to the previous frame of an image array.
+    pub fn cycle_image_frame_reverse(&mut self) {
+        if let Some(image_frames) = &self.image_frames {
+            let frames = image_frames.frames().len();
+
+            self.select_image_frame((self.image_frame + frames - 1) % frames);
+        }
+    }
+
+    /// Selects the frame of an image array to preview.
+    pub fn select_image_frame(&mut self, index: usize) {
+        let Some(image_frames) = &self.image_frames else {
+            return;
+        };
+
+        let Ok(image) = image_frame(image_frames, index) else {
+            return;
+        };
+
+        let Ok(render_image) = self.create_render_image(&image) else {
+            return;
+        };
+
+        self.show_image(image, render_image);
+        self.image_frame = index;
 
-            self.scale = 100.min((scale * 100.0) as u32);
+        self.update_camera();
+    }
+
+    /// Returns the index of the previewed frame and the number of frames, when previewing an image array.
+    pub fn image_frame(&self) -> Option<(usize, usize)> {
+        self.image_frames
+            .as_ref()
+            .map(|image_frames| (self.image_frame, image_frames.frames().len()))
+    }
 
-            self.camera.set_orthographic(Some((
-                material.width() as f32,
-                material.height() as f32,
-                self.scale as f32 / 100.0,
-            )));
+    /// Toggles previewing images with their color channels divided by alpha, to compare against straight alpha.
+    pub fn toggle_unpremultiply(&mut self) {
+        self.unpremultiply = !self.unpremultiply;
 
+        let Some(image) = &self.preview_image else {
+            return;
+        };
+
+        if let Ok(render_image) = self.create_render_image(image) {
+            self.render = Some(RenderType::Image(render_image));
             self.update_camera();
         }
     }
 
-    /// Toggles the wireframe view.
-    pub fn toggle_wireframe(&mut self) {
-        self.wireframe = !self.wireframe;
+    /// Cycles the uv layer drawn with a checker image, wrapping back to the materials after the last layer.
+    pub fn cycle_uv_checker(&mut self) {
+        let Some((model, _, _)) = &self.preview_model else {
+            return;
+        };
+
+        let Some(RenderType::Model(render_model)) = &mut self.render else {
+            return;
+        };
+
+        let uv_checker = match self.uv_checker {
+            None => Some(0),
+            Some(layer) => Some(layer + 1),
+        }
+        .filter(|layer| *layer < max_uv_layers(model));
+
+        if render_model
+            .set_render_mode(self.instance, model, uv_checker, self.model_render_mode)
+            .is_err()
+        {
+            return;
+        }
+
+        self.uv_checker = uv_checker;
+
+        let _ = self.update_animation_pose();
+    }
+
+    /// Cycles the way model meshes are colored, between materials, mesh ids, and a silhouette mask.
+    pub fn cycle_model_render_mode(&mut self) {
+        self.set_model_render_mode(self.model_render_mode.next());
+    }
+
+    /// Sets the way model meshes are colored.
+    pub fn set_model_render_mode(&mut self, mode: ModelRenderMode) {
+        if let Some((model, _, _)) = &self.preview_model
+            && let Some(RenderType::Model(render_model)) = &mut self.render
+            && render_model
+                .set_render_mode(self.instance, model, self.uv_checker, mode)
+                .is_err()
+        {
+            return;
+        }
+
+        self.model_render_mode = mode;
+        self.update_camera();
+
+        let _ = self.update_animation_pose();
+    }
+
+    /// Returns the way model meshes are colored.
+    pub fn model_render_mode(&self) -> ModelRenderMode {
+        self.model_render_mode
+    }
+
+    /// Plays the animation on the model being previewed, replacing any animation already playing.
+    pub fn set_preview_animation(
+        &mut self,
+        name: String,
+        animation: Animation,
+    ) -> Result<(), PreviewError> {
+        if self.preview_model.is_none() {
+            return Err(PreviewError::Unsupported);
+        }
+
+        self.preview_animation = Some(RenderAnimation::new(name, animation));
+
+        self.update_animation_pose()
+    }
+
+    /// Stops playing the animation, returning the model to its rest pose.
+    pub fn clear_preview_animation(&mut self) {
+        if self.preview_animation.take().is_none() {
+            return;
+        }
+
+        if let Some((model, _, _)) = &self.preview_model
+            && let Some(RenderType::Model(render_model)) = &mut self.render
+        {
+            let _ = render_model.set_pose(self.instance, model);
+        }
+    }
+
+    /// Returns the previewed frame, the number of frames, and whether or not the animation is playing.
+    pub fn animation_frame(&self) -> Option<(u32, u32, bool)> {
+        self.preview_animation.as_ref().map(|animation| {
+            (
+                animation.frame(),
+                animation.frame_count(),
+                animation.is_playing(),
+            )
+        })
+    }
+
+    /// Whether or not an animation is playing, and needs to be advanced.
+    pub fn is_animation_playing(&self) -> bool {
+        self.preview_animation
+            .as_ref()
+            .is_some_and(|animation| animation.is_playing())
+    }
+
+    /// Plays or pauses the animation.
+    pub fn toggle_animation_playback(&mut self) {
+        if let Some(animation) = &mut self.preview_animation {
+            animation.toggle_playing();
+        }
+
+        let _ = self.update_animation_pose();
+    }
+
+    /// Pauses the animation and steps the given number of frames, to scrub through it.
+    pub fn step_animation(&mut self, frames: i32) {
+        if let Some(animation) = &mut self.preview_animation {
+            animation.step(frames);
+        }
+
+        let _ = self.update_animation_pose();
+    }
+
+    /// Advances the playing animation by the given number of seconds, returns true if the model was posed.
+    pub fn advance_animation(&mut self, seconds: f32) -> bool {
+        let advanced = self
+            .preview_animation
+            .as_mut()
+            .is_some_and(|animation| animation.advance(seconds));
+
+        advanced && self.update_animation_pose().is_ok()
+    }
+
+    /// Utility to pose the previewed model at the current frame of the animation.
+    fn update_animation_pose(&mut self) -> Result<(), PreviewError> {
+        let (Some(animation), Some((model, _, _)), Some(RenderType::Model(render_model))) = (
+            &self.preview_animation,
+            &self.preview_model,
+            &mut self.render,
+        ) else {
+            return Ok(());
+        };
+
+        render_model.set_pose(self.instance, &animation.pose(model))
+    }
+
+    /// Selects the mesh or bone under the given position, or clears the selection if there is none.
+    /// Bones are picked first, then the mesh with the smallest bounds on screen, so inner meshes can be picked.
+    pub fn pick<P: Into<Vector2>>(&mut self, position: P) {
+        let position = position.into();
+
+        let Some(RenderType::Model(model)) = &self.render else {
+            return;
+        };
+
+        let project = |point: Vector3| self.camera.project(point, self.width, self.height);
+
+        let bone = if self.show_bones {
+            model
+                .bone_positions()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, bone)| {
+                    let (point, depth) = project(*bone)?;
+                    let distance = (point - position).length();
+
+                    (distance <= BONE_PICK_RADIUS).then_some((index, distance, depth))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+                .map(|(index, _, _)| ViewportSelection::Bone(index))
+        } else {
+            None
+        };
+
+        let mesh = || {
+            model
+                .mesh_bounds()
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !self.is_mesh_hidden(*index))
+                .filter_map(|(index, bounds)| {
+                    let mut min = Vector2::new(f32::INFINITY, f32::INFINITY);
+                    let mut max = Vector2::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+                    for corner in 0..8 {
+                        let corner = Vector3::new(
+                            if corner & 1 == 0 {
+                                bounds.min.x
+                            } else {
+                                bounds.max.x
+                            },
+                            if corner & 2 == 0 {
+                                bounds.min.y
+                            } else {
+                                bounds.max.y
+                            },
+                            if corner & 4 == 0 {
+                                bounds.min.z
+                            } else {
+                                bounds.max.z
+                            },
+                        );
+
+                        let (point, _) = project(corner)?;
+
+                        min = Vector2::new(min.x.min(point.x), min.y.min(point.y));
+                        max = Vector2::new(max.x.max(point.x), max.y.max(point.y));
+                    }
+
+                    let inside = position.x >= min.x
+                        && position.x <= max.x
+                        && position.y >= min.y
+                        && position.y <= max.y;
+
+                    inside.then_some((index, (max.x - min.x) * (max.y - min.y)))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(index, _)| ViewportSelection::Mesh(index))
+        };
+
+        self.selection = bone.or_else(mesh);
+    }
+
+    /// Returns the selected mesh or bone, if any.
+    pub fn selection(&self) -> Option<ViewportSelection> {
+        self.selection
+    }
+
+    /// Sets the selected mesh or bone.
+    pub fn select(&mut self, selection: Option<ViewportSelection>) {
+        self.selection = selection;
+    }
+
+    /// Sets whether or not the mesh at the given index is drawn.
+    pub fn set_mesh_visible(&mut self, index: usize, visible: bool) {
+        if let Some(hidden) = self.hidden_meshes.get_mut(index) {
+            *hidden = !visible;
+        }
+    }
+
+    /// Toggles whether or not the selected mesh is drawn.
+    pub fn toggle_selected_visibility(&mut self) {
+        if let Some(ViewportSelection::Mesh(index)) = self.selection {
+            self.set_mesh_visible(index, self.is_mesh_hidden(index));
+        }
+    }
+
+    /// Shows every hidden mesh.
+    pub fn show_all_meshes(&mut self) {
+        self.hidden_meshes.fill(false);
+    }
+
+    /// Whether or not the mesh at the given index is hidden.
+    pub fn is_mesh_hidden(&self, index: usize) -> bool {
+        self.hidden_meshes.get(index).copied().unwrap_or_default()
+    }
+
+    /// Gets whether or not each mesh of the preview model is visible.
+    pub fn visible_meshes(&self) -> Vec<bool> {
+        self.hidden_meshes.iter().map(|hidden| !hidden).collect()
+    }
+
+    /// Frames the camera on the selected mesh or bone, or the whole model when nothing is selected.
+    pub fn frame_selected(&mut self) {
+        let Some(RenderType::Model(model)) = &self.render else {
+            return;
+        };
+
+        if self.camera.is_orthographic() {
+            return;
+        }
+
+        let model_bounds = union_bounds(model.mesh_bounds());
+
+        let bounds = match self.selection {
+            Some(ViewportSelection::Mesh(index)) => model.mesh_bounds().get(index).copied(),
+            Some(ViewportSelection::Bone(index)) => {
+                model.bone_positions().get(index).map(|position| {
+                    // Bones have no volume, so frame a small area of the model around them.
+                    let radius = model_bounds
+                        .map(|bounds| (bounds.max - bounds.min).length() * 0.05)
+                        .filter(|radius| radius.is_finite() && *radius > 0.0)
+                        .unwrap_or(1.0);
+
+                    Aabb::new(*position - radius, *position + radius)
+                })
+            }
+            None => None,
+        };
+
+        if let Some(bounds) = bounds.or(model_bounds) {
+            self.focus_on_bounds(bounds);
+            self.update_camera();
+        }
+    }
+
+    /// Cycles the wireframe view, wrapping back to off after the last mode.
+    pub fn cycle_wireframe_mode(&mut self) {
+        self.wireframe = self.wireframe.next();
+    }
+
+    /// Returns the current wireframe view.
+    pub fn wireframe_mode(&self) -> WireframeMode {
+        self.wireframe
+    }
+
+    /// Toggles culling of back faces, showing flipped faces and the inside of open meshes when disabled.
+    pub fn toggle_backface_culling(&mut self) {
+        self.backface_culling = !self.backface_culling;
     }
 
     /// Toggles the bone view.
@@ -479,6 +1163,11 @@ impl ViewportRenderer {
         self.show_bones = !self.show_bones;
     }
 
+    /// Toggles drawing the local axes of each bone, when bones are shown.
+    pub fn toggle_bone_axes(&mut self) {
+        self.show_bone_axes = !self.show_bone_axes;
+    }
+
     /// Toggles the grid view.
     pub fn toggle_grid(&mut self) {
         self.show_grid = !self.show_grid;
@@ -523,12 +1212,29 @@ impl ViewportRenderer {
         self.update_camera();
     }
 
+    /// Updates the memory budget in bytes for previewing an image, this applies to the next image previewed.
+    pub fn image_budget(&mut self, budget: u64) {
+        self.image_budget = budget;
+    }
+
     /// Updates the far clip.
     pub fn far_clip(&mut self, far_clip: f32) {
         self.far_clip = far_clip;
         self.update_camera();
     }
 
+    /// Updates the near clip, or fits it to the size of the model when none.
+    pub fn near_clip(&mut self, near_clip: Option<f32>) {
+        self.near_clip = near_clip;
+        self.update_camera();
+    }
+
+    /// Updates the field of view in degrees.
+    pub fn fov(&mut self, fov_deg: f32) {
+        self.camera.set_fov_deg(fov_deg);
+        self.update_camera();
+    }
+
     /// Performs a mouse move operation.
     pub fn mouse_move<D: Into<Vector2>>(&mut self, delta: D, key_state: ViewportKeyState) {
         let delta = delta.into();
@@ -615,11 +1321,16 @@ impl ViewportRenderer {
         }
     }
 
+    /// Sets the cursor position over the viewport used to inspect image pixels, or none if the cursor left.
+    pub fn inspect<P: Into<Vector2>>(&mut self, position: Option<P>) {
+        self.inspect_position = position.map(Into::into);
+    }
+
     /// Returns the statistics for the current render assset.
     pub fn statistics(&self) -> Vec<(String, String)> {
         match &self.render {
             Some(RenderType::Model(model)) => {
-                vec![
+                let mut result = vec![
                     (
                         String::from("Name"),
                         self.render_name
@@ -630,10 +1341,80 @@ impl ViewportRenderer {
                     (String::from("Verts"), model.vertex_count().to_string()),
                     (String::from("Tris"), model.face_count().to_string()),
                     (String::from("Bones"), model.bone_count().to_string()),
-                ]
+                    (String::from("UV Checker"), self.uv_checker_label()),
+                    (
+                        String::from("Render Mode"),
+                        String::from(self.model_render_mode.label()),
+                    ),
+                ];
+
+                let hidden = self.hidden_meshes.iter().filter(|hidden| **hidden).count();
+
+                if hidden > 0 {
+                    result.push((String::from("Hidden"), format!("{hidden} meshes")));
+                }
+
+                if self.wireframe != WireframeMode::Off {
+                    result.push((
+                        String::from("Wireframe"),
+                        String::from(self.wireframe.label()),
+                    ));
+                }
+
+                if !self.backface_culling {
+                    result.push((String::from("Culling"), String::from("Off")));
+                }
+
+                if model.physics_bone_count() > 0 {
+                    result.push((
+                        String::from("Physics"),
+                        format!("{} bones", model.physics_bone_count()),
+                    ));
+                }
+
+                if model.socket_count() > 0 {
+                    result.push((String::from("Sockets"), model.socket_count().to_string()));
+                }
+
+                if let Some(selection) = self.selection_label() {
+                    result.push((String::from("Selected"), selection));
+                }
+
+                if let Some(animation) = &self.preview_animation {
+                    result.push((String::from("Animation"), animation.name().to_string()));
+                    result.push((
+                        String::from("Frame"),
+                        format!(
+                            "{} of {} ({})",
+                            animation.frame() + 1,
+                            animation.frame_count(),
+                            if animation.is_playing() {
+                                "Playing"
+                            } else {
+                                "Paused"
+                            }
+                        ),
+                    ));
+                }
+
+                if self.show_bone_axes && model.non_uniform_bone_count() > 0 {
+                    result.push((
+                        String::from("Non-Uniform Scale"),
+                        format!("{} bones", model.non_uniform_bone_count()),
+                    ));
+                }
+
+                if model.generated_normals() {
+                    result.push((
+                        String::from("Warning"),
+                        String::from("Normals were generated"),
+                    ));
+                }
+
+                result
             }
             Some(RenderType::Image(image)) => {
-                vec![
+                let mut result = vec![
                     (
                         String::from("Name"),
                         self.render_name
@@ -643,7 +1424,33 @@ impl ViewportRenderer {
                     (String::from("Width"), image.width().to_string()),
                     (String::from("Height"), image.height().to_string()),
                     (String::from("Scale"), format!("{}%", self.scale)),
-                ]
+                    (
+                        String::from("Alpha"),
+                        match (self.unpremultiply, self.premultiplied_hint) {
+                            (true, _) => String::from("Unpremultiplied"),
+                            (false, true) => String::from("Likely Premultiplied"),
+                            (false, false) => String::from("Straight"),
+                        },
+                    ),
+                ];
+
+                if let Some((frame, frames)) = self.image_frame() {
+                    result.push((
+                        String::from("Frame"),
+                        format!("{} of {}", frame + 1, frames),
+                    ));
+                }
+
+                if let Some((level, width, height)) = self.image_mip {
+                    result.push((
+                        String::from("Preview Mip"),
+                        format!("{} of {}x{}", level, width, height),
+                    ));
+                }
+
+                result.extend(self.pixel_statistics());
+
+                result
             }
             Some(RenderType::Material(material)) => {
                 let mut result = vec![
@@ -661,6 +1468,8 @@ impl ViewportRenderer {
                             format!("{} of {}", material.index() + 1, material.len())
                         },
                     ),
+                    (String::from("Texture"), material.name()),
+                    (String::from("Slot"), material.usage()),
                 ];
 
                 if material.is_error() {
@@ -670,9 +1479,10 @@ impl ViewportRenderer {
                     ));
                 } else {
                     result.extend([
-                        (String::from("Usage"), material.usage()),
-                        (String::from("Width"), material.width().to_string()),
-                        (String::from("Height"), material.height().to_string()),
+                        (
+                            String::from("Resolution"),
+                            format!("{}x{}", material.width(), material.height()),
+                        ),
                         (String::from("Scale"), format!("{}%", self.scale)),
                     ]);
                 }
@@ -720,11 +1530,15 @@ impl ViewportRenderer {
 
         render_pass.set_bind_group(0, self.camera.uniform_bind_group(), &[]);
 
+        render_pass.set_pipeline(&self.background_render_pipeline);
+        render_pass.set_vertex_buffer(0, self.background_render_buffer.slice(..));
+        render_pass.draw(0..3, 0..1);
+
         let mut draw_grid = || {
             if self.show_grid {
                 render_pass.set_pipeline(&self.grid_render_pipeline);
                 render_pass.set_vertex_buffer(0, self.grid_render_buffer.slice(..));
-                render_pass.draw(0..self.grid_size, 0..1);
+                render_pass.draw(0..4, 0..self.grid_instances);
             }
         };
 
@@ -732,7 +1546,14 @@ impl ViewportRenderer {
             Some(RenderType::Model(model)) => {
                 draw_grid();
 
-                model.draw(&mut render_pass, self.show_bones, self.wireframe);
+                model.draw(
+                    &mut render_pass,
+                    &self.hidden_meshes,
+                    self.show_bones,
+                    self.show_bone_axes,
+                    self.wireframe,
+                    self.backface_culling,
+                );
             }
             Some(RenderType::Image(image)) => {
                 image.draw(&mut render_pass);
@@ -819,6 +1640,250 @@ impl ViewportRenderer {
         (self.width as u32, self.height as u32, pixels)
     }
 
+    /// Renders the asset to an image of the given size, without requiring a window.
+    /// Models are framed the same way as the preview when they are first shown.
+    pub fn render_asset_to_image(
+        &mut self,
+        asset: PreviewAsset,
+        width: u32,
+        height: u32,
+    ) -> Result<Image, PreviewError> {
+        self.set_preview_asset(asset, width, height)?;
+        self.render_to_image()
+    }
+
+    /// Renders the asset from the given number of angles around the up axis, without requiring a window.
+    /// Images and materials don't rotate, so every frame is the same.
+    pub fn render_turntable(
+        &mut self,
+        asset: PreviewAsset,
+        width: u32,
+        height: u32,
+        frames: u32,
+    ) -> Result<Vec<Image>, PreviewError> {
+        self.set_preview_asset(asset, width, height)?;
+
+        let step = std::f32::consts::TAU / frames.max(1) as f32;
+
+        let mut result = Vec::with_capacity(frames as usize);
+
+        for _ in 0..frames {
+            result.push(self.render_to_image()?);
+
+            if !self.camera.is_orthographic() {
+                self.camera.rotate(step, 0.0);
+                self.update_camera();
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Resizes the output and sets the asset to preview, resetting the view.
+    fn set_preview_asset(
+        &mut self,
+        asset: PreviewAsset,
+        width: u32,
+        height: u32,
+    ) -> Result<(), PreviewError> {
+        self.resize(width as f32, height as f32, self.far_clip);
+
+        match asset {
+            PreviewAsset::Image(image) => self.set_preview_image(String::new(), image),
+            PreviewAsset::Material(material) => self.set_preview_material(String::new(), material),
+            PreviewAsset::Model(model, materials) => {
+                self.reset_view();
+                self.set_preview_model(String::new(), model, materials, false, true)
+            }
+        }
+    }
+
+    /// Renders the current asset to a new image.
+    fn render_to_image(&self) -> Result<Image, PreviewError> {
+        let (width, height, pixels) = self.render();
+
+        if pixels.is_empty() {
+            return Err(PreviewError::OutOfMemory);
+        }
+
+        let mut image = Image::new(width, height, ImageFormat::R8G8B8A8Unorm)
+            .map_err(|_| PreviewError::InvalidAsset)?;
+
+        image
+            .create_frame()
+            .map_err(|_| PreviewError::OutOfMemory)?
+            .buffer_mut()
+            .copy_from_slice(&pixels);
+
+        Ok(image)
+    }
+
+    /// Fits the current material image to the viewport.
+    fn fit_material(&mut self) {
+        let Some(RenderType::Material(material)) = &self.render else {
+            return;
+        };
+
+        let scale =
+            (self.width / material.width() as f32).min(self.height / material.height() as f32);
+
+        self.scale = 100.min((scale * 100.0) as u32);
+
+        self.camera.set_orthographic(Some((
+            material.width() as f32,
+            material.height() as f32,
+            self.scale as f32 / 100.0,
+        )));
+
+        self.update_camera();
+    }
+
+    /// Focuses the camera so that the given model space bounds fill the view.
+    fn focus_on_bounds(&mut self, bounds: Aabb) {
+        let center = ((bounds.min + bounds.max) / 2.0).transform(&self.camera.model_matrix());
+
+        let extents = (bounds.max - bounds.min) / 2.0;
+        let radius = extents.length();
+
+        let aspect = self.width / self.height;
+
+        let fov_rad = self.camera.fov_deg().to_radians();
+
+        let vertical = radius / (fov_rad / 2.0).tan();
+        let horizontal = radius / ((fov_rad * aspect) / 2.0).tan();
+
+        let distance = vertical.max(horizontal);
+
+        self.camera.focus_on(center, distance);
+    }
+
+    /// Gets the name of the selected mesh or bone.
+    fn selection_label(&self) -> Option<String> {
+        let (model, _, _) = self.preview_model.as_ref()?;
+
+        match self.selection? {
+            ViewportSelection::Mesh(index) => {
+                let mesh = model.meshes.get(index)?;
+
+                let label = match &mesh.name {
+                    Some(name) => format!("Mesh {index} ({name})"),
+                    None => format!("Mesh {index}"),
+                };
+
+                if self.is_mesh_hidden(index) {
+                    Some(format!("{label}, Hidden"))
+                } else {
+                    Some(label)
+                }
+            }
+            ViewportSelection::Bone(index) => {
+                let bone = model.skeleton.bones.get(index)?;
+
+                Some(match &bone.name {
+                    Some(name) => format!("Bone {index} ({name})"),
+                    None => format!("Bone {index}"),
+                })
+            }
+        }
+    }
+
+    /// Gets the name and semantic of the uv layer drawn with a checker image.
+    fn uv_checker_label(&self) -> String {
+        let (Some(layer), Some((model, _, _))) = (self.uv_checker, &self.preview_model) else {
+            return String::from("Off");
+        };
+
+        model
+            .meshes
+            .iter()
+            .find(|mesh| layer < mesh.vertices.uv_layers())
+            .map(|mesh| {
+                format!(
+                    "{} ({:?})",
+                    mesh.uv_layer_name(layer),
+                    mesh.uv_layer_semantic(layer)
+                )
+            })
+            .unwrap_or_else(|| String::from("Off"))
+    }
+
+    /// Gets the image pixel under the inspect position, if any.
+    fn inspected_pixel(&self) -> Option<(u32, u32)> {
+        let position = self.inspect_position?;
+        let image = self.preview_image.as_ref()?;
+
+        let scale = self.scale as f32 / 100.0;
+
+        if scale <= 0.0 {
+            return None;
+        }
+
+        let x = (position.x - (self.width - image.width() as f32 * scale) / 2.0) / scale;
+        let y = (position.y - (self.height - image.height() as f32 * scale) / 2.0) / scale;
+
+        if x < 0.0 || y < 0.0 || x >= image.width() as f32 || y >= image.height() as f32 {
+            return None;
+        }
+
+        Some((x as u32, y as u32))
+    }
+
+    /// Returns the statistics for the pixel under the inspect position.
+    fn pixel_statistics(&self) -> Vec<(String, String)> {
+        let Some(image) = &self.preview_image else {
+            return Vec::new();
+        };
+
+        let Some((x, y)) = self.inspected_pixel() else {
+            return Vec::new();
+        };
+
+        let mut result = vec![(String::from("Pixel"), format!("{x}, {y}"))];
+
+        if let Ok([r, g, b, a]) = image.pixel_to_rgba8(x, y) {
+            result.push((String::from("RGBA"), format!("{r}, {g}, {b}, {a}")));
+        }
+
+        // Converted formats lose precision, so show the stored value as well.
+        if image.pixel_rgba8(x, y).is_none()
+            && let Some(raw) = image.pixel(x, y)
+        {
+            let raw: String = raw.iter().rev().map(|x| format!("{x:02X}")).collect();
+
+            result.push((String::from("Raw"), format!("0x{raw}")));
+        }
+
+        result
+    }
+
+    /// Sets the image that is drawn, keeping it so hovered pixels can be inspected.
+    fn show_image(&mut self, image: Image, render_image: RenderImage) {
+        // Only a sample of the image is checked, converting the whole image stalls on very large textures.
+        self.premultiplied_hint = image
+            .sample_blocks(PREMULTIPLIED_SAMPLE_BLOCKS)
+            .and_then(|sample| sample.is_likely_premultiplied())
+            .unwrap_or_default();
+        self.render = Some(RenderType::Image(render_image));
+        self.preview_image = Some(image);
+    }
+
+    /// Creates the render image for the given image, applying the preview alpha conversion.
+    fn create_render_image(&self, image: &Image) -> Result<RenderImage, PreviewError> {
+        let bind_group_layouts = [self.camera.uniform_bind_group_layout()];
+
+        if !self.unpremultiply {
+            return RenderImage::from_image(self.instance, &bind_group_layouts, image);
+        }
+
+        let mut image = image.clone();
+
+        image
+            .transform(TransformAlgorithm::Unpremultiply)
+            .map_err(|_| PreviewError::Unsupported)?;
+
+        RenderImage::from_image(self.instance, &bind_group_layouts, &image)
+    }
+
     /// Updates the camera with current parameters.
     fn update_camera(&mut self) {
         let srgb = match &self.render {
@@ -828,8 +1893,18 @@ impl ViewportRenderer {
             None => false,
         };
 
-        self.camera
-            .update(self.instance, self.width, self.height, srgb, self.far_clip);
+        let flat =
+            matches!(self.render, Some(RenderType::Model(_))) && self.model_render_mode.is_flat();
+
+        self.camera.set_flat(flat);
+        self.camera.update(
+            self.instance,
+            self.width,
+            self.height,
+            srgb,
+            self.near_clip.unwrap_or(self.auto_near_clip),
+            self.far_clip,
+        );
     }
 }
 
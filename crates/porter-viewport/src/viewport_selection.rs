@@ -0,0 +1,6 @@
+/// An element of the previewed model that was picked in the viewport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewportSelection {
+    Mesh(usize),
+    Bone(usize),
+}
@@ -0,0 +1,31 @@
+/// The way the edges of a model are drawn in the preview.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WireframeMode {
+    /// Only the shaded faces are drawn.
+    #[default]
+    Off,
+    /// Only the edges are drawn.
+    Wireframe,
+    /// The edges are drawn over the shaded faces.
+    Overlay,
+}
+
+impl WireframeMode {
+    /// Returns the next wireframe mode, wrapping back to off after the last mode.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Wireframe,
+            Self::Wireframe => Self::Overlay,
+            Self::Overlay => Self::Off,
+        }
+    }
+
+    /// The display name of this wireframe mode.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Wireframe => "Wireframe",
+            Self::Overlay => "Wireframe on Shaded",
+        }
+    }
+}
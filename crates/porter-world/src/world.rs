@@ -5,6 +5,7 @@ use std::path::Path;
 use porter_cast::CastFile;
 use porter_cast::CastId;
 use porter_cast::CastNode;
+use porter_cast::CastOptions;
 use porter_cast::CastPropertyId;
 
 use porter_math::Axis;
@@ -43,7 +44,16 @@ impl World {
 
     /// Saves the world to the given file path using the cast format.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
-        let mut root = CastNode::root();
+        self.save_with_options(path, CastOptions::default())
+    }
+
+    /// Saves the world to the given file path using the cast format, with the given cast options.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: CastOptions,
+    ) -> Result<(), Error> {
+        let mut root = CastNode::root_with_hash_mode(options.hash_mode);
 
         let meta_node = root.create(CastId::Metadata);
 
@@ -77,7 +87,7 @@ impl World {
 
         let writer = File::create(path.as_ref().with_extension("cast"))?.buffer_write();
 
-        let mut file = CastFile::new();
+        let mut file = CastFile::with_options(options);
 
         file.push(root);
         file.write(writer)?;